@@ -0,0 +1,208 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Node attachments.
+//!
+//! Small artifacts such as diagrams or configuration snippets can be
+//! attached to a node. They are stored apart from the node, in the
+//! `attachments` column family, so they are never embedded or searched;
+//! inspecting the node lists them. Without persistence they are held in
+//! memory.
+//!
+//! Keys are the node ID followed by the attachment ID, so a node's
+//! attachments are one prefix scan. Values are the length of the
+//! attachment's JSON metadata as a big-endian `u32`, the metadata, then the
+//! attachment bytes.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard};
+
+use uuid::Uuid;
+
+use crate::models::AttachmentInfo;
+
+/// Largest attachment accepted, in bytes.
+pub const MAX_ATTACHMENT_BYTES: usize = 1024 * 1024;
+
+/// Largest attachment upload request accepted, leaving room for the
+/// multipart framing around the file.
+pub const MAX_ATTACHMENT_UPLOAD_BYTES: usize = MAX_ATTACHMENT_BYTES + 64 * 1024;
+
+/// Largest total size of the attachments of one node, in bytes.
+pub const MAX_NODE_ATTACHMENT_BYTES: usize = 16 * 1024 * 1024;
+
+/// Content type of attachments uploaded without one.
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Longest attachment name, in characters.
+const MAX_NAME_CHARS: usize = 255;
+
+/// Bytes of the metadata length prefix of a stored attachment.
+const LENGTH_PREFIX_BYTES: usize = 4;
+
+/// Key prefix of the attachments of a node.
+pub(crate) fn node_prefix(node_id: Uuid) -> Vec<u8> {
+    node_id.as_bytes().to_vec()
+}
+
+/// Key of an attachment.
+pub(crate) fn attachment_key(node_id: Uuid, id: Uuid) -> Vec<u8> {
+    let mut key = node_prefix(node_id);
+    key.extend_from_slice(id.as_bytes());
+    key
+}
+
+/// Encode an attachment for storage.
+pub(crate) fn encode(info: &AttachmentInfo, data: &[u8]) -> Vec<u8> {
+    let meta = serde_json::to_vec(info).expect("attachment metadata serializes");
+    let mut value = Vec::with_capacity(LENGTH_PREFIX_BYTES + meta.len() + data.len());
+    value.extend_from_slice(&(meta.len() as u32).to_be_bytes());
+    value.extend_from_slice(&meta);
+    value.extend_from_slice(data);
+    value
+}
+
+/// Decode a stored attachment into its metadata and bytes, or `None` if it
+/// is malformed.
+pub(crate) fn decode(value: &[u8]) -> Option<(AttachmentInfo, &[u8])> {
+    let prefix = value.get(..LENGTH_PREFIX_BYTES)?;
+    let len = u32::from_be_bytes(prefix.try_into().ok()?) as usize;
+    let end = LENGTH_PREFIX_BYTES.checked_add(len)?;
+    let info = serde_json::from_slice(value.get(LENGTH_PREFIX_BYTES..end)?).ok()?;
+    Some((info, &value[end..]))
+}
+
+/// Name of an uploaded attachment: its file name without directories.
+pub(crate) fn attachment_name(file_name: &str) -> Result<String, String> {
+    let name = file_name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    if name.is_empty() {
+        return Err("attachment name must not be empty".to_string());
+    }
+    if name.chars().count() > MAX_NAME_CHARS {
+        return Err(format!(
+            "attachment name is longer than {} characters",
+            MAX_NAME_CHARS
+        ));
+    }
+    if name.chars().any(char::is_control) {
+        return Err("attachment name must not contain control characters".to_string());
+    }
+    Ok(name.to_string())
+}
+
+/// Content type of an uploaded attachment, defaulting to
+/// [`DEFAULT_CONTENT_TYPE`]. It is sent back as a header, so it must be a
+/// printable ASCII `type/subtype`.
+pub(crate) fn attachment_content_type(content_type: Option<&str>) -> Result<String, String> {
+    let Some(content_type) = content_type.map(str::trim).filter(|t| !t.is_empty()) else {
+        return Ok(DEFAULT_CONTENT_TYPE.to_string());
+    };
+    let printable = content_type
+        .chars()
+        .all(|c| c == ' ' || c.is_ascii_graphic());
+    if !printable || !content_type.contains('/') || content_type.len() > MAX_NAME_CHARS {
+        return Err(format!(
+            "invalid attachment content type '{}'",
+            content_type
+        ));
+    }
+    Ok(content_type.to_string())
+}
+
+/// Attachments held in memory when there is no persistent store, keyed
+/// like the column family.
+#[derive(Debug, Default)]
+pub(crate) struct MemoryAttachments {
+    entries: Mutex<BTreeMap<Vec<u8>, Vec<u8>>>,
+}
+
+impl MemoryAttachments {
+    fn entries(&self) -> MutexGuard<'_, BTreeMap<Vec<u8>, Vec<u8>>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn put(&self, key: Vec<u8>, value: Vec<u8>) {
+        self.entries().insert(key, value);
+    }
+
+    /// Remove an entry, returning whether it existed.
+    pub(crate) fn delete(&self, key: &[u8]) -> bool {
+        self.entries().remove(key).is_some()
+    }
+
+    /// Entries whose keys start with `prefix`, in key order.
+    pub(crate) fn scan_prefix(&self, prefix: &[u8]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        self.entries()
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(node_id: Uuid) -> AttachmentInfo {
+        AttachmentInfo {
+            id: Uuid::new_v4(),
+            node_id,
+            name: "diagram.svg".to_string(),
+            content_type: "image/svg+xml".to_string(),
+            size: 5,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let info = info(Uuid::new_v4());
+        let value = encode(&info, b"<svg>");
+        let (decoded, data) = decode(&value).unwrap();
+        assert_eq!(decoded, info);
+        assert_eq!(data, b"<svg>");
+
+        assert!(decode(&value[..2]).is_none());
+        assert!(decode(&[0, 0, 1, 0, b'{']).is_none());
+    }
+
+    #[test]
+    fn test_attachment_names() {
+        assert_eq!(attachment_name("diagram.png").unwrap(), "diagram.png");
+        assert_eq!(attachment_name("/tmp/conf/app.toml").unwrap(), "app.toml");
+        assert_eq!(attachment_name("C:\\notes\\a b.txt").unwrap(), "a b.txt");
+        assert!(attachment_name("dir/").is_err());
+        assert!(attachment_name("bad\nname").is_err());
+        assert!(attachment_name(&"x".repeat(MAX_NAME_CHARS + 1)).is_err());
+
+        assert_eq!(attachment_content_type(None).unwrap(), DEFAULT_CONTENT_TYPE);
+        assert_eq!(
+            attachment_content_type(Some("text/plain; charset=utf-8")).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert!(attachment_content_type(Some("text")).is_err());
+        assert!(attachment_content_type(Some("text/plain\r\nx: y")).is_err());
+    }
+
+    #[test]
+    fn test_memory_scan_by_node() {
+        let memory = MemoryAttachments::default();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        for node_id in [a, a, b] {
+            let info = info(node_id);
+            memory.put(attachment_key(node_id, info.id), encode(&info, b"data"));
+        }
+        assert_eq!(memory.scan_prefix(&node_prefix(a)).len(), 2);
+        let (key, _) = memory.scan_prefix(&node_prefix(b)).remove(0);
+        assert_eq!(memory.scan_prefix(&key).len(), 1);
+        assert!(memory.delete(&key));
+        assert!(!memory.delete(&key));
+        assert!(memory.scan_prefix(&node_prefix(b)).is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Audit trail of mutations.
+//!
+//! Every mutation (a node created, updated, tagged or deleted, an edge
+//! created or reviewed, a transaction, an ingested document, an import or a
+//! prune) is recorded as an [`AuditEvent`] with its time, its actor (the
+//! `x-actor` request header, see [`crate::usage`]), the operation, the IDs it
+//! touched and a summary of the request. With persistence the trail is kept
+//! in the `audit` column family; it can also be appended to a JSON Lines
+//! file. The most recent events are also held in memory, which is all that
+//! is kept without persistence.
+//!
+//! Keys are the event time in milliseconds, big-endian, followed by the
+//! event ID, so the column family is in time order.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::models::{AuditEvent, AuditQuery};
+
+/// Longest excerpt of node content in an event summary, in characters.
+const SUMMARY_EXCERPT_CHARS: usize = 80;
+
+/// Audit trail settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditConfig {
+    /// JSON Lines file every event is appended to, if any.
+    pub log_path: Option<PathBuf>,
+
+    /// Most recent events kept in memory.
+    pub recent_events: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            log_path: None,
+            recent_events: 10_000,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AuditState {
+    config: Option<AuditConfig>,
+    file: Option<File>,
+    recent: VecDeque<AuditEvent>,
+}
+
+impl Default for AuditState {
+    fn default() -> Self {
+        Self {
+            config: Some(AuditConfig::default()),
+            file: None,
+            recent: VecDeque::new(),
+        }
+    }
+}
+
+/// Recorded mutations; enabled with the default settings until configured
+/// otherwise.
+#[derive(Debug, Default)]
+pub(crate) struct AuditLog {
+    state: Mutex<AuditState>,
+}
+
+impl AuditLog {
+    fn state(&self) -> MutexGuard<'_, AuditState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Enable auditing with the given settings, or disable it with `None`.
+    /// Fails if the log file cannot be opened.
+    pub(crate) fn configure(&self, config: Option<AuditConfig>) -> std::io::Result<()> {
+        let file = match config.as_ref().and_then(|config| config.log_path.as_ref()) {
+            Some(path) => Some(OpenOptions::new().create(true).append(true).open(path)?),
+            None => None,
+        };
+        let mut state = self.state();
+        if let Some(config) = &config {
+            while state.recent.len() > config.recent_events {
+                state.recent.pop_front();
+            }
+        }
+        state.config = config;
+        state.file = file;
+        Ok(())
+    }
+
+    /// Record an event, returning the audit entry to persist. Nothing is
+    /// recorded while disabled.
+    pub(crate) fn record(&self, event: AuditEvent) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut state = self.state();
+        let recent_events = state.config.as_ref()?.recent_events;
+        let value = serde_json::to_vec(&event).ok()?;
+
+        if let Some(file) = &mut state.file {
+            let written = file.write_all(&value).and_then(|_| file.write_all(b"\n"));
+            if let Err(e) = written {
+                tracing::warn!("Failed to append to the audit log file: {}", e);
+            }
+        }
+        let key = event_key(&event);
+        if recent_events > 0 {
+            if state.recent.len() >= recent_events {
+                state.recent.pop_front();
+            }
+            state.recent.push_back(event);
+        }
+        Some((key, value))
+    }
+
+    /// Events held in memory, oldest first.
+    pub(crate) fn recent(&self) -> Vec<AuditEvent> {
+        self.state().recent.iter().cloned().collect()
+    }
+}
+
+/// Whether an event matches the filters of a query.
+pub(crate) fn matches(query: &AuditQuery, event: &AuditEvent) -> bool {
+    query.since.map_or(true, |since| event.at >= since)
+        && query.until.map_or(true, |until| event.at < until)
+        && query
+            .actor
+            .as_ref()
+            .map_or(true, |actor| event.actor.as_ref() == Some(actor))
+}
+
+/// Quoted start of a node's content for an event summary.
+pub(crate) fn excerpt(content: &str) -> String {
+    let mut chars = content.chars();
+    let mut excerpt: String = chars.by_ref().take(SUMMARY_EXCERPT_CHARS).collect();
+    if chars.next().is_some() {
+        excerpt.push('…');
+    }
+    format!("{:?}", excerpt)
+}
+
+fn event_key(event: &AuditEvent) -> Vec<u8> {
+    let mut key = (event.at.timestamp_millis().max(0) as u64).to_be_bytes().to_vec();
+    key.extend_from_slice(event.id.as_bytes());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AuditOperation;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    fn event(actor: Option<&str>, minutes_ago: i64) -> AuditEvent {
+        AuditEvent {
+            id: Uuid::new_v4(),
+            at: Utc::now() - Duration::minutes(minutes_ago),
+            actor: actor.map(str::to_string),
+            operation: AuditOperation::CreateNode,
+            targets: vec![Uuid::new_v4()],
+            summary: "concept \"Paris\"".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_records_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let log = AuditLog::default();
+        log.configure(Some(AuditConfig {
+            log_path: Some(path.clone()),
+            recent_events: 2,
+        }))
+        .unwrap();
+
+        let events = [event(None, 3), event(Some("agent-1"), 2), event(Some("cli"), 1)];
+        let entries: Vec<_> = events.iter().map(|e| log.record(e.clone()).unwrap()).collect();
+        // Keys sort in time order
+        assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(log.recent(), events[1..].to_vec());
+
+        let lines: Vec<AuditEvent> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines, events.to_vec());
+
+        log.configure(None).unwrap();
+        assert!(log.record(event(None, 0)).is_none());
+    }
+
+    #[test]
+    fn test_matches() {
+        let event = event(Some("agent-1"), 10);
+        let query = |since: Option<i64>, until: Option<i64>, actor: Option<&str>| AuditQuery {
+            since: since.map(|m| Utc::now() - Duration::minutes(m)),
+            until: until.map(|m| Utc::now() - Duration::minutes(m)),
+            actor: actor.map(str::to_string),
+            limit: None,
+        };
+        assert!(matches(&query(None, None, None), &event));
+        assert!(matches(&query(Some(20), Some(5), Some("agent-1")), &event));
+        assert!(!matches(&query(Some(5), None, None), &event));
+        assert!(!matches(&query(None, Some(20), None), &event));
+        assert!(!matches(&query(None, None, Some("cli")), &event));
+    }
+
+    #[test]
+    fn test_excerpt() {
+        assert_eq!(excerpt("Paris"), "\"Paris\"");
+        let long = "x".repeat(SUMMARY_EXCERPT_CHARS + 1);
+        assert_eq!(excerpt(&long).chars().count(), SUMMARY_EXCERPT_CHARS + 3);
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Incrementally maintained database counters.
+//!
+//! Node counts per type and the number of embedded nodes are kept by
+//! [`NodeTable`] as nodes are inserted and removed; edge counts per relation
+//! are kept by [`EdgeCounts`] as edges are added to the graph. Reading them
+//! is O(1) in the size of the database, so `/stats` can be polled freely.
+//!
+//! [`NodeTable`] also indexes nodes by their external ID, for upserts, and
+//! by tag, for tag queries and tag facets. While a reindex rebuilds it
+//! from storage, it records which nodes were written meanwhile.
+//!
+//! Both also notify the query cache and the materialized views, if
+//! attached, of every change they see, so cached results and views are
+//! invalidated by the same writes that keep the counts.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use synton_core::{Edge, Node, NodeType, Relation};
+use uuid::Uuid;
+
+use crate::query_cache::QueryCache;
+use crate::views::ViewRegistry;
+
+/// The in-memory node lookup, with per-type and embedding counts and
+/// external ID and tag indexes.
+///
+/// Reads go through the underlying map; writes must use the methods below
+/// so the counts and index stay in sync.
+#[derive(Debug, Default)]
+pub(crate) struct NodeTable {
+    nodes: HashMap<Uuid, Node>,
+    by_type: HashMap<NodeType, usize>,
+    by_external_id: HashMap<String, Uuid>,
+    by_tag: HashMap<String, HashSet<Uuid>>,
+    embedded: usize,
+    cache: Option<Arc<QueryCache>>,
+    views: Option<Arc<ViewRegistry>>,
+    changed: Option<HashSet<Uuid>>,
+}
+
+impl NodeTable {
+    /// An empty table that reports changes to the query cache.
+    pub(crate) fn with_cache(cache: Arc<QueryCache>) -> Self {
+        Self {
+            cache: Some(cache),
+            ..Default::default()
+        }
+    }
+
+    /// Also report changes to the materialized views.
+    pub(crate) fn with_views(mut self, views: Arc<ViewRegistry>) -> Self {
+        self.views = Some(views);
+        self
+    }
+
+    /// Insert or replace a node, returning the previous one.
+    pub(crate) fn insert(&mut self, id: Uuid, node: Node) -> Option<Node> {
+        self.count(&node, true);
+        self.notify(&node);
+        let external_id = node.meta.external_id.clone();
+        let tags = node.tags.clone();
+        let previous = self.nodes.insert(id, node);
+        if let Some(previous) = &previous {
+            self.count(previous, false);
+            self.unindex(previous);
+            self.notify(previous);
+        }
+        if let Some(external_id) = external_id {
+            self.by_external_id.insert(external_id, id);
+        }
+        for tag in tags {
+            self.by_tag.entry(tag).or_default().insert(id);
+        }
+        if let Some(changed) = &mut self.changed {
+            changed.insert(id);
+        }
+        previous
+    }
+
+    /// Remove a node, returning it if it was present.
+    pub(crate) fn remove(&mut self, id: &Uuid) -> Option<Node> {
+        let removed = self.nodes.remove(id);
+        if let Some(removed) = &removed {
+            self.count(removed, false);
+            self.unindex(removed);
+            self.notify(removed);
+        }
+        if let Some(changed) = &mut self.changed {
+            changed.insert(*id);
+        }
+        removed
+    }
+
+    /// Start recording the IDs of inserted and removed nodes.
+    pub(crate) fn track_changes(&mut self) {
+        self.changed = Some(HashSet::new());
+    }
+
+    /// Stop recording changes, returning the IDs recorded since
+    /// [`NodeTable::track_changes`].
+    pub(crate) fn take_changes(&mut self) -> HashSet<Uuid> {
+        self.changed.take().unwrap_or_default()
+    }
+
+    /// Mutable access to a node's metadata. The node type, embedding,
+    /// external ID and tags must not be changed through it; use [`NodeTable::insert`]
+    /// instead.
+    pub(crate) fn get_mut(&mut self, id: &Uuid) -> Option<&mut Node> {
+        self.nodes.get_mut(id)
+    }
+
+    /// Number of nodes of each type.
+    pub(crate) fn by_type(&self) -> &HashMap<NodeType, usize> {
+        &self.by_type
+    }
+
+    /// Number of nodes with an embedding.
+    pub(crate) fn embedded(&self) -> usize {
+        self.embedded
+    }
+
+    /// ID of the loaded node with the given external ID.
+    pub(crate) fn by_external_id(&self, external_id: &str) -> Option<Uuid> {
+        self.by_external_id.get(external_id).copied()
+    }
+
+    /// IDs of the loaded nodes carrying a normalized tag.
+    pub(crate) fn tagged(&self, tag: &str) -> impl Iterator<Item = Uuid> + '_ {
+        self.by_tag.get(tag).into_iter().flatten().copied()
+    }
+
+    /// Number of nodes carrying each tag.
+    pub(crate) fn by_tag(&self) -> HashMap<String, usize> {
+        self.by_tag
+            .iter()
+            .map(|(tag, ids)| (tag.clone(), ids.len()))
+            .collect()
+    }
+
+    fn notify(&self, node: &Node) {
+        if let Some(cache) = &self.cache {
+            cache.node_changed(node);
+        }
+        if let Some(views) = &self.views {
+            views.node_changed(node);
+        }
+    }
+
+    fn unindex(&mut self, node: &Node) {
+        if let Some(external_id) = &node.meta.external_id {
+            if self.by_external_id.get(external_id) == Some(&node.id) {
+                self.by_external_id.remove(external_id);
+            }
+        }
+        for tag in &node.tags {
+            if let Some(ids) = self.by_tag.get_mut(tag) {
+                ids.remove(&node.id);
+                if ids.is_empty() {
+                    self.by_tag.remove(tag);
+                }
+            }
+        }
+    }
+
+    fn count(&mut self, node: &Node, added: bool) {
+        let count = self.by_type.entry(node.node_type).or_default();
+        let embedded = usize::from(node.embedding.is_some());
+        if added {
+            *count += 1;
+            self.embedded += embedded;
+        } else {
+            *count = count.saturating_sub(1);
+            self.embedded = self.embedded.saturating_sub(embedded);
+        }
+    }
+}
+
+impl Deref for NodeTable {
+    type Target = HashMap<Uuid, Node>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.nodes
+    }
+}
+
+/// Number of graph edges per relation.
+#[derive(Debug, Default)]
+pub(crate) struct EdgeCounts {
+    by_relation: HashMap<Relation, usize>,
+    total: usize,
+    cache: Option<Arc<QueryCache>>,
+    views: Option<Arc<ViewRegistry>>,
+}
+
+impl EdgeCounts {
+    /// Empty counts that report added edges to the query cache.
+    pub(crate) fn with_cache(cache: Arc<QueryCache>) -> Self {
+        Self {
+            cache: Some(cache),
+            ..Default::default()
+        }
+    }
+
+    /// Also report added edges to the materialized views.
+    pub(crate) fn with_views(mut self, views: Arc<ViewRegistry>) -> Self {
+        self.views = Some(views);
+        self
+    }
+
+    /// Count an edge added to the graph.
+    pub(crate) fn record(&mut self, edge: &Edge) {
+        *self.by_relation.entry(edge.relation.clone()).or_default() += 1;
+        self.total += 1;
+        if let Some(cache) = &self.cache {
+            cache.edge_added();
+        }
+        if let Some(views) = &self.views {
+            views.edge_added(edge);
+        }
+    }
+
+    /// Uncount an edge removed with one of its nodes; the node's removal
+    /// already invalidated the cached results and views it was in.
+    pub(crate) fn forget(&mut self, edge: &Edge) {
+        let count = self.by_relation.get_mut(&edge.relation);
+        if let Some(count) = count.filter(|count| **count > 0) {
+            *count -= 1;
+            self.total -= 1;
+        }
+    }
+
+    /// Number of edges of each relation.
+    pub(crate) fn by_relation(&self) -> &HashMap<Relation, usize> {
+        &self.by_relation
+    }
+
+    /// Total number of edges.
+    pub(crate) fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_table_counts() {
+        let mut table = NodeTable::default();
+        let fact = Node::new("Water boils at 100C", NodeType::Fact);
+        let mut concept = Node::new("Boiling", NodeType::Concept);
+        concept.embedding = Some(vec![0.1, 0.2]);
+
+        table.insert(fact.id, fact.clone());
+        table.insert(concept.id, concept.clone());
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.by_type()[&NodeType::Fact], 1);
+        assert_eq!(table.embedded(), 1);
+
+        // Replacing a node moves it between types
+        let mut retyped = fact.clone();
+        retyped.node_type = NodeType::Concept;
+        retyped.embedding = Some(vec![0.3, 0.4]);
+        assert!(table.insert(fact.id, retyped).is_some());
+        assert_eq!(table.by_type()[&NodeType::Fact], 0);
+        assert_eq!(table.by_type()[&NodeType::Concept], 2);
+        assert_eq!(table.embedded(), 2);
+
+        table.remove(&concept.id);
+        assert!(table.remove(&concept.id).is_none());
+        assert_eq!(table.by_type()[&NodeType::Concept], 1);
+        assert_eq!(table.embedded(), 1);
+    }
+
+    #[test]
+    fn test_node_table_external_ids() {
+        let mut table = NodeTable::default();
+        let ticket = Node::new("Login fails on Safari", NodeType::Fact).with_external_id("JIRA-42");
+        table.insert(ticket.id, ticket.clone());
+        assert_eq!(table.by_external_id("JIRA-42"), Some(ticket.id));
+
+        // Replacing the node keeps it indexed; removing it drops the entry
+        let mut updated = ticket.clone();
+        updated.content = "Login fails on Safari 17".into();
+        table.insert(ticket.id, updated);
+        assert_eq!(table.by_external_id("JIRA-42"), Some(ticket.id));
+
+        table.remove(&ticket.id);
+        assert_eq!(table.by_external_id("JIRA-42"), None);
+    }
+
+    #[test]
+    fn test_node_table_tags() {
+        let mut table = NodeTable::default();
+        let node = Node::new("Storage engine", NodeType::Concept).with_tags(["db", "architecture"]);
+        let other = Node::new("Query planner", NodeType::Concept).with_tags(["db"]);
+        table.insert(node.id, node.clone());
+        table.insert(other.id, other.clone());
+        assert_eq!(table.by_tag()["db"], 2);
+        assert_eq!(table.tagged("architecture").collect::<Vec<_>>(), vec![node.id]);
+
+        // Retagging a node moves it between tags; unused tags disappear
+        let retagged = node.clone().with_tags(["storage"]);
+        let mut untagged = retagged.clone();
+        untagged.tags.remove("architecture");
+        table.insert(node.id, untagged);
+        assert!(!table.by_tag().contains_key("architecture"));
+        assert_eq!(table.by_tag()["storage"], 1);
+
+        table.remove(&other.id);
+        assert_eq!(table.tagged("db").collect::<Vec<_>>(), vec![node.id]);
+    }
+
+    #[test]
+    fn test_node_table_tracks_changes() {
+        let mut table = NodeTable::default();
+        let (a, b) = (Node::new("a", NodeType::Fact), Node::new("b", NodeType::Fact));
+        table.insert(a.id, a.clone());
+
+        table.track_changes();
+        table.insert(b.id, b.clone());
+        table.remove(&a.id);
+        assert_eq!(table.take_changes(), HashSet::from([a.id, b.id]));
+
+        // Nothing is recorded once tracking stops
+        table.remove(&b.id);
+        assert!(table.take_changes().is_empty());
+    }
+
+    #[test]
+    fn test_edge_counts() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut counts = EdgeCounts::default();
+        counts.record(&Edge::new(a, b, Relation::Causes));
+        counts.record(&Edge::new(b, a, Relation::Causes));
+        counts.record(&Edge::new(a, b, Relation::IsA));
+
+        assert_eq!(counts.total(), 3);
+        assert_eq!(counts.by_relation()[&Relation::Causes], 2);
+        assert_eq!(counts.by_relation()[&Relation::IsA], 1);
+
+        counts.forget(&Edge::new(a, b, Relation::IsA));
+        counts.forget(&Edge::new(a, b, Relation::IsA));
+        counts.forget(&Edge::new(a, b, Relation::IsPartOf));
+        assert_eq!(counts.total(), 2);
+        assert_eq!(counts.by_relation()[&Relation::IsA], 0);
+    }
+}
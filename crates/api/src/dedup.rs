@@ -0,0 +1,187 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Short-term deduplication of absorbed nodes.
+//!
+//! Agents tend to absorb the same message several times in a burst. While
+//! the window is enabled, each created node is remembered for a few
+//! minutes; a later node whose content is identical or near-identical (by
+//! word overlap), with the same type and namespace, is coalesced into the
+//! remembered one instead of being created.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use synton_core::NodeType;
+use uuid::Uuid;
+
+/// Node attribute counting how many times a node's content was absorbed.
+pub const OCCURRENCES_ATTRIBUTE: &str = "occurrences";
+
+/// Most nodes remembered at once; the oldest are forgotten first.
+const MAX_ENTRIES: usize = 4096;
+
+/// Absorb deduplication settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbsorbDedupConfig {
+    /// How long a created node can absorb duplicates.
+    pub window: Duration,
+
+    /// Minimum word-set overlap (Jaccard, 0.0-1.0) for two contents to be
+    /// near-identical. 1.0 only coalesces content that is identical after
+    /// normalising case, punctuation and whitespace.
+    pub similarity: f32,
+}
+
+impl Default for AbsorbDedupConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(300),
+            similarity: 0.9,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    id: Uuid,
+    node_type: NodeType,
+    namespace: Option<String>,
+    normalized: String,
+    words: HashSet<String>,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct DedupState {
+    config: Option<AbsorbDedupConfig>,
+    entries: VecDeque<Entry>,
+}
+
+/// Recently created nodes that can absorb duplicates; disabled until
+/// configured.
+#[derive(Debug, Default)]
+pub(crate) struct AbsorbDedup {
+    state: Mutex<DedupState>,
+}
+
+impl AbsorbDedup {
+    fn state(&self) -> MutexGuard<'_, DedupState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Enable the window with the given settings, or disable it with `None`.
+    /// Remembered nodes are forgotten.
+    pub(crate) fn configure(&self, config: Option<AbsorbDedupConfig>) {
+        *self.state() = DedupState {
+            config,
+            entries: VecDeque::new(),
+        };
+    }
+
+    /// The most recently remembered node that `content` duplicates.
+    pub(crate) fn find(&self, content: &str, node_type: NodeType, namespace: Option<&str>) -> Option<Uuid> {
+        let mut state = self.state();
+        let config = state.config?;
+        let now = Instant::now();
+        while state.entries.front().is_some_and(|entry| entry.expires_at <= now) {
+            state.entries.pop_front();
+        }
+
+        let normalized = normalize(content);
+        let words = word_set(&normalized);
+        state
+            .entries
+            .iter()
+            .rev()
+            .find(|entry| {
+                entry.node_type == node_type
+                    && entry.namespace.as_deref() == namespace
+                    && (entry.normalized == normalized
+                        || jaccard(&entry.words, &words) >= config.similarity)
+            })
+            .map(|entry| entry.id)
+    }
+
+    /// Remember a newly created node.
+    pub(crate) fn record(&self, id: Uuid, content: &str, node_type: NodeType, namespace: Option<&str>) {
+        let mut state = self.state();
+        let Some(config) = state.config else {
+            return;
+        };
+        if state.entries.len() >= MAX_ENTRIES {
+            state.entries.pop_front();
+        }
+        let normalized = normalize(content);
+        state.entries.push_back(Entry {
+            id,
+            node_type,
+            namespace: namespace.map(str::to_string),
+            words: word_set(&normalized),
+            normalized,
+            expires_at: Instant::now() + config.window,
+        });
+    }
+}
+
+/// Lowercased alphanumeric words separated by single spaces.
+fn normalize(content: &str) -> String {
+    content
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn word_set(normalized: &str) -> HashSet<String> {
+    normalized.split(' ').filter(|w| !w.is_empty()).map(str::to_string).collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_identical_and_near_identical_content() {
+        let dedup = AbsorbDedup::default();
+        let id = Uuid::new_v4();
+        dedup.record(id, "The deploy failed at 10:42", NodeType::Fact, None);
+        assert_eq!(dedup.find("the deploy failed at 10:42", NodeType::Fact, None), None);
+
+        dedup.configure(Some(AbsorbDedupConfig {
+            window: Duration::from_secs(60),
+            similarity: 0.8,
+        }));
+        dedup.record(id, "The deploy failed at 10:42", NodeType::Fact, None);
+
+        assert_eq!(dedup.find("the deploy FAILED at 10:42!", NodeType::Fact, None), Some(id));
+        // 6 of 7 distinct words shared
+        assert_eq!(dedup.find("The deploy failed at 10:42 again", NodeType::Fact, None), Some(id));
+        assert_eq!(dedup.find("The deploy succeeded", NodeType::Fact, None), None);
+        assert_eq!(dedup.find("The deploy failed at 10:42", NodeType::Concept, None), None);
+        assert_eq!(dedup.find("The deploy failed at 10:42", NodeType::Fact, Some("ops")), None);
+    }
+
+    #[test]
+    fn test_entries_expire() {
+        let dedup = AbsorbDedup::default();
+        dedup.configure(Some(AbsorbDedupConfig {
+            window: Duration::ZERO,
+            similarity: 1.0,
+        }));
+        dedup.record(Uuid::new_v4(), "heartbeat", NodeType::RawChunk, None);
+        assert_eq!(dedup.find("heartbeat", NodeType::RawChunk, None), None);
+        assert!(dedup.state().entries.is_empty());
+    }
+}
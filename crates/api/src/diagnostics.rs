@@ -0,0 +1,176 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Runtime diagnostics.
+//!
+//! Background work is spread over the Tokio runtime: hook deliveries, the
+//! job queue, re-embedding batches and the periodic maintenance tasks. Their
+//! queue depths, how late each periodic task ticks against its schedule and
+//! how many embedding requests are in flight are collected at all times,
+//! as they cost a few atomic updates. The `runtime-diagnostics` feature
+//! exposes them, with the runtime's own metrics, in the Prometheus text
+//! format at `GET /metrics`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, MutexGuard};
+
+use tokio::time::Instant;
+
+use crate::models::{RuntimeDiagnostics, SchedulerLag};
+
+/// Tick lag of the periodic background tasks.
+#[derive(Debug, Default)]
+pub(crate) struct SchedulerLags {
+    lags: Mutex<BTreeMap<&'static str, SchedulerLag>>,
+}
+
+impl SchedulerLags {
+    fn lags(&self) -> MutexGuard<'_, BTreeMap<&'static str, SchedulerLag>> {
+        self.lags.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Record a tick of `scheduler` that was due at `due`.
+    pub(crate) fn record(&self, scheduler: &'static str, due: Instant) {
+        let lag_ms = Instant::now().saturating_duration_since(due).as_secs_f64() * 1000.0;
+        let mut lags = self.lags();
+        let entry = lags.entry(scheduler).or_insert_with(|| SchedulerLag {
+            scheduler: scheduler.to_string(),
+            ..Default::default()
+        });
+        entry.ticks += 1;
+        entry.last_lag_ms = lag_ms;
+        entry.max_lag_ms = entry.max_lag_ms.max(lag_ms);
+    }
+
+    /// Lag of every task that has ticked, by name.
+    pub(crate) fn snapshot(&self) -> Vec<SchedulerLag> {
+        self.lags().values().cloned().collect()
+    }
+}
+
+/// Render diagnostics in the Prometheus text exposition format.
+#[cfg_attr(not(feature = "runtime-diagnostics"), allow(dead_code))]
+pub(crate) fn render_prometheus(diagnostics: &RuntimeDiagnostics) -> String {
+    let mut out = String::new();
+    let mut gauge = |name: &str, help: &str, samples: &[(String, f64)]| {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} gauge", name);
+        for (labels, value) in samples {
+            let _ = writeln!(out, "{}{} {}", name, labels, value);
+        }
+    };
+    let plain = |value: usize| vec![(String::new(), value as f64)];
+
+    gauge(
+        "synton_runtime_workers",
+        "Tokio worker threads.",
+        &plain(diagnostics.workers),
+    );
+    gauge(
+        "synton_runtime_alive_tasks",
+        "Tasks alive on the runtime.",
+        &plain(diagnostics.alive_tasks),
+    );
+    gauge(
+        "synton_runtime_global_queue_depth",
+        "Tasks waiting in the runtime's global queue.",
+        &plain(diagnostics.global_queue_depth),
+    );
+    let queues: Vec<_> = diagnostics
+        .queues
+        .iter()
+        .map(|q| (format!("{{queue=\"{}\"}}", q.queue), q.depth as f64))
+        .collect();
+    gauge(
+        "synton_task_queue_depth",
+        "Items waiting in an internal work queue.",
+        &queues,
+    );
+
+    let per_scheduler = |value: fn(&SchedulerLag) -> f64| -> Vec<_> {
+        diagnostics
+            .schedulers
+            .iter()
+            .map(|s| (format!("{{scheduler=\"{}\"}}", s.scheduler), value(s)))
+            .collect()
+    };
+    gauge(
+        "synton_scheduler_lag_seconds",
+        "Lag of the latest tick of a periodic task.",
+        &per_scheduler(|s| s.last_lag_ms / 1000.0),
+    );
+    gauge(
+        "synton_scheduler_max_lag_seconds",
+        "Largest tick lag of a periodic task.",
+        &per_scheduler(|s| s.max_lag_ms / 1000.0),
+    );
+    gauge(
+        "synton_scheduler_ticks",
+        "Ticks run by a periodic task.",
+        &per_scheduler(|s| s.ticks as f64),
+    );
+
+    gauge(
+        "synton_embedding_requests_in_flight",
+        "Embedding backend requests in progress.",
+        &plain(diagnostics.embedding_in_flight),
+    );
+    gauge(
+        "synton_embedding_requests_peak_in_flight",
+        "Most embedding backend requests in progress at once.",
+        &plain(diagnostics.embedding_peak_in_flight),
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::QueueDepth;
+    use std::time::Duration;
+
+    #[test]
+    fn test_scheduler_lag() {
+        let lags = SchedulerLags::default();
+        let now = Instant::now();
+        lags.record("vector_tiering", now - Duration::from_millis(200));
+        lags.record("vector_tiering", now + Duration::from_secs(1));
+        lags.record("expiry_digest", now);
+
+        let snapshot = lags.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].scheduler, "expiry_digest");
+        let tiering = &snapshot[1];
+        assert_eq!(tiering.ticks, 2);
+        assert_eq!(tiering.last_lag_ms, 0.0);
+        assert!(tiering.max_lag_ms >= 200.0);
+    }
+
+    #[test]
+    fn test_render_prometheus() {
+        let diagnostics = RuntimeDiagnostics {
+            workers: 4,
+            queues: vec![QueueDepth {
+                queue: "jobs".to_string(),
+                depth: 2,
+            }],
+            schedulers: vec![SchedulerLag {
+                scheduler: "reembedding".to_string(),
+                ticks: 3,
+                last_lag_ms: 1500.0,
+                max_lag_ms: 2500.0,
+            }],
+            embedding_in_flight: 1,
+            ..Default::default()
+        };
+
+        let text = render_prometheus(&diagnostics);
+        assert!(text.contains("# TYPE synton_runtime_workers gauge\nsynton_runtime_workers 4\n"));
+        assert!(text.contains("synton_task_queue_depth{queue=\"jobs\"} 2\n"));
+        assert!(text.contains("synton_scheduler_lag_seconds{scheduler=\"reembedding\"} 1.5\n"));
+        assert!(text.contains("synton_scheduler_max_lag_seconds{scheduler=\"reembedding\"} 2.5\n"));
+        assert!(text.contains("synton_embedding_requests_in_flight 1\n"));
+    }
+}
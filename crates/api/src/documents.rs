@@ -0,0 +1,262 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Bookkeeping for ingested documents and their chunks.
+//!
+//! Document nodes carry a `document` attribute; chunk nodes record their
+//! document and position in [`NodeMeta`](synton_core::NodeMeta), their
+//! hierarchy level, type and character range in a `chunk` attribute, and
+//! the chunker metadata in a `provenance` attribute for citations. Summary
+//! nodes record their document and carry a `summary` attribute naming the
+//! model that wrote them.
+
+use serde_json::json;
+use uuid::Uuid;
+
+use synton_chunking::{Chunk, ChunkMetadata};
+use synton_core::{Node, PROVENANCE_ATTRIBUTE};
+use synton_memory::{NAMESPACE_ATTRIBUTE, SUMMARY_ATTRIBUTE};
+
+/// Attribute key marking a node as an ingested document.
+pub(crate) const DOCUMENT_ATTR: &str = "document";
+
+/// Attribute key holding the position of a chunk within its document.
+pub(crate) const CHUNK_ATTR: &str = synton_graphrag::CHUNK_ATTRIBUTE;
+
+/// Attributes for a document node.
+pub(crate) fn document_attributes(title: &str, chunking: &str) -> serde_json::Value {
+    json!({
+        DOCUMENT_ATTR: {
+            "title": title,
+            "chunking": chunking,
+        }
+    })
+}
+
+/// Title and chunking strategy of a document node, if it is one.
+pub(crate) fn document_info(node: &Node) -> Option<(Option<String>, Option<String>)> {
+    let document = node.attributes.get(DOCUMENT_ATTR)?.as_object()?;
+    let field = |key: &str| document.get(key).and_then(|v| v.as_str()).map(str::to_string);
+    Some((field("title"), field("chunking")))
+}
+
+/// Record a namespace on a document or chunk node.
+pub(crate) fn set_namespace(node: &mut Node, namespace: &str) {
+    if let Some(attributes) = node.attributes.as_object_mut() {
+        attributes.insert(NAMESPACE_ATTRIBUTE.to_string(), namespace.into());
+    }
+}
+
+/// Provenance attribute value for chunks chunked with `metadata`.
+fn provenance(metadata: &ChunkMetadata) -> serde_json::Value {
+    let mut provenance = serde_json::Map::new();
+    let fields = [
+        ("source", &metadata.source),
+        ("title", &metadata.title),
+        ("content_type", &metadata.content_type),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            provenance.insert(key.to_string(), value.clone().into());
+        }
+    }
+    if !metadata.custom.is_null() {
+        provenance.insert("metadata".to_string(), metadata.custom.clone());
+    }
+    provenance.into()
+}
+
+/// Build the node stored for a chunk at `position` in the chunker output.
+pub(crate) fn chunk_node(
+    document_id: Uuid,
+    position: usize,
+    chunk: &Chunk,
+    metadata: &ChunkMetadata,
+) -> Node {
+    let mut node = Node::new(chunk.content.clone(), synton_core::NodeType::Concept)
+        .with_document_id(document_id)
+        .with_chunk_index(position);
+    node.attributes = json!({
+        CHUNK_ATTR: {
+            "level": chunk.level,
+            "type": chunk.chunk_type,
+            "range": [chunk.range.0, chunk.range.1],
+        },
+        PROVENANCE_ATTRIBUTE: provenance(metadata),
+    });
+    node
+}
+
+/// Build the node stored for the summary of a document, written by `model`.
+pub(crate) fn summary_node(
+    document_id: Uuid,
+    summary: String,
+    model: &str,
+    metadata: &ChunkMetadata,
+) -> Node {
+    let mut node = Node::new(summary, synton_core::NodeType::Concept).with_document_id(document_id);
+    node.attributes = json!({
+        SUMMARY_ATTRIBUTE: { "model": model },
+        PROVENANCE_ATTRIBUTE: provenance(metadata),
+    });
+    node
+}
+
+/// Whether a node is a model-written summary of a document.
+pub(crate) fn is_summary_node(node: &Node) -> bool {
+    node.attributes.get(SUMMARY_ATTRIBUTE).is_some()
+}
+
+/// A stored chunk of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChunkRecord {
+    pub(crate) node_id: Uuid,
+    pub(crate) index: usize,
+    pub(crate) level: usize,
+    pub(crate) chunk_type: String,
+    pub(crate) range: (usize, usize),
+    pub(crate) content: String,
+}
+
+impl ChunkRecord {
+    /// Read the chunk record of a node belonging to `document_id`.
+    pub(crate) fn from_node(node: &Node, document_id: Uuid) -> Option<Self> {
+        if node.meta.document_id != Some(document_id) {
+            return None;
+        }
+        let chunk = node.attributes.get(CHUNK_ATTR)?;
+        let range = chunk.get("range").and_then(|r| r.as_array());
+        let bound = |i: usize| {
+            range
+                .and_then(|r| r.get(i))
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as usize
+        };
+
+        Some(Self {
+            node_id: node.id,
+            index: node.meta.chunk_index?,
+            level: chunk.get("level").and_then(|v| v.as_u64()).unwrap_or(0) as usize,
+            chunk_type: chunk
+                .get("type")
+                .and_then(|v| v.as_str())
+                .unwrap_or("custom")
+                .to_string(),
+            range: (bound(0), bound(1)),
+            content: node.content.to_string(),
+        })
+    }
+
+    /// Whether this chunk is a generated summary rather than source text.
+    fn is_summary(&self) -> bool {
+        self.chunk_type == "document"
+    }
+}
+
+/// Hierarchy level used when the caller does not pick one: the shallowest
+/// level holding source text (summaries are skipped when possible).
+pub(crate) fn default_level(chunks: &[ChunkRecord]) -> Option<usize> {
+    chunks
+        .iter()
+        .filter(|c| !c.is_summary())
+        .map(|c| c.level)
+        .min()
+        .or_else(|| chunks.iter().map(|c| c.level).min())
+}
+
+/// Join chunks (already ordered by index) back into document text.
+///
+/// Overlapping character ranges, as produced by fixed-size chunking, are
+/// trimmed; contiguous fixed-size chunks are concatenated as-is; other
+/// chunks are separated by a space (sentences) or a blank line.
+pub(crate) fn reassemble(chunks: &[ChunkRecord]) -> String {
+    let mut text = String::new();
+    let mut previous: Option<&ChunkRecord> = None;
+
+    for chunk in chunks {
+        let Some(prev) = previous else {
+            text.push_str(&chunk.content);
+            previous = Some(chunk);
+            continue;
+        };
+
+        let (start, prev_start, prev_end) = (chunk.range.0, prev.range.0, prev.range.1);
+        if start > prev_start && start < prev_end {
+            text.extend(chunk.content.chars().skip(prev_end - start));
+        } else if chunk.chunk_type == "custom" && start == prev_end {
+            text.push_str(&chunk.content);
+        } else {
+            text.push_str(if chunk.chunk_type == "sentence" { " " } else { "\n\n" });
+            text.push_str(&chunk.content);
+        }
+        previous = Some(chunk);
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(index: usize, level: usize, chunk_type: &str, range: (usize, usize), content: &str) -> ChunkRecord {
+        ChunkRecord {
+            node_id: Uuid::new_v4(),
+            index,
+            level,
+            chunk_type: chunk_type.to_string(),
+            range,
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_reassemble_trims_fixed_overlap() {
+        let chunks = vec![
+            record(0, 0, "custom", (0, 10), "The quick "),
+            record(1, 0, "custom", (4, 15), "quick brown"),
+            record(2, 0, "custom", (15, 19), " fox"),
+        ];
+        assert_eq!(reassemble(&chunks), "The quick brown fox");
+    }
+
+    #[test]
+    fn test_default_level_skips_summary() {
+        let chunks = vec![
+            record(0, 0, "document", (0, 5), "Summ..."),
+            record(1, 1, "paragraph", (0, 9), "First one."),
+            record(2, 1, "paragraph", (9, 20), "Second one."),
+            record(3, 2, "sentence", (0, 10), "First one."),
+        ];
+        assert_eq!(default_level(&chunks), Some(1));
+
+        let paragraphs: Vec<_> = chunks.into_iter().filter(|c| c.level == 1).collect();
+        assert_eq!(reassemble(&paragraphs), "First one.\n\nSecond one.");
+    }
+
+    #[test]
+    fn test_chunk_node_roundtrip() {
+        let document_id = Uuid::new_v4();
+        let chunk = Chunk::new("Hello.".to_string(), 4, (10, 16), synton_chunking::ChunkType::Sentence)
+            .with_level(2);
+
+        let metadata = ChunkMetadata::new()
+            .with_source("https://example.com/guide")
+            .with_title("Guide");
+        let node = chunk_node(document_id, 7, &chunk, &metadata);
+        assert_eq!(
+            node.attributes[PROVENANCE_ATTRIBUTE],
+            json!({ "source": "https://example.com/guide", "title": "Guide" })
+        );
+        let record = ChunkRecord::from_node(&node, document_id).unwrap();
+        assert_eq!(record.index, 7);
+        assert_eq!(record.level, 2);
+        assert_eq!(record.chunk_type, "sentence");
+        assert_eq!(record.range, (10, 16));
+        assert!(ChunkRecord::from_node(&node, Uuid::new_v4()).is_none());
+
+        let document = Node::new("Doc", synton_core::NodeType::Concept);
+        assert!(document_info(&document).is_none());
+    }
+}
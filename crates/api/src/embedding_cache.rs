@@ -0,0 +1,181 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Embedding cache persisted in the metadata column family.
+//!
+//! Each embedding is stored under `embedding_cache:<key>` (see
+//! [`synton_ml::cache::cache_key`]) with an `embedding_cache_order:` entry
+//! keyed by its write time, so the oldest entries can be found without
+//! reading the embeddings. When the cache grows past its bound the oldest
+//! tenth is evicted in one batch.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use synton_ml::cache::{decode_embedding, encode_embedding};
+use synton_ml::PersistentEmbeddingCache;
+use synton_storage::{ColumnFamily, Store, StorageResult, WriteOp};
+use tokio::sync::Mutex;
+
+/// Key prefix of cached embeddings in the metadata column family.
+const ENTRY_KEY_PREFIX: &str = "embedding_cache:";
+
+/// Key prefix of the write-time index of cached embeddings.
+const ORDER_KEY_PREFIX: &str = "embedding_cache_order:";
+
+/// Embedding cache stored in the metadata column family, bounded to a
+/// number of entries.
+pub struct StoreEmbeddingCache {
+    store: Arc<dyn Store>,
+    max_entries: usize,
+    entries: AtomicUsize,
+    eviction: Mutex<()>,
+}
+
+impl StoreEmbeddingCache {
+    /// Open the cache kept in a store, counting the entries it holds.
+    pub async fn open(store: Arc<dyn Store>, max_entries: usize) -> StorageResult<Self> {
+        let entries = store
+            .scan_prefix(ColumnFamily::Metadata, ORDER_KEY_PREFIX.as_bytes())
+            .await?
+            .len();
+        let cache = Self {
+            store,
+            max_entries: max_entries.max(1),
+            entries: AtomicUsize::new(entries),
+            eviction: Mutex::new(()),
+        };
+        cache.evict().await;
+        Ok(cache)
+    }
+
+    /// Number of cached embeddings.
+    pub fn len(&self) -> usize {
+        self.entries.load(Ordering::Relaxed)
+    }
+
+    /// Whether the cache holds no embeddings.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evict the oldest entries once the cache is over its bound, down to
+    /// nine tenths of it.
+    async fn evict(&self) {
+        if self.len() <= self.max_entries {
+            return;
+        }
+        // Another task is already evicting
+        let Ok(_guard) = self.eviction.try_lock() else {
+            return;
+        };
+
+        let order = match self
+            .store
+            .scan_prefix(ColumnFamily::Metadata, ORDER_KEY_PREFIX.as_bytes())
+            .await
+        {
+            Ok(order) => order,
+            Err(e) => {
+                tracing::warn!("Failed to scan the embedding cache: {}", e);
+                return;
+            }
+        };
+        let keep = self.max_entries - self.max_entries / 10;
+        let excess = order.len().saturating_sub(keep);
+
+        // Order keys sort by write time, so the oldest come first
+        let mut ops = Vec::with_capacity(excess * 2);
+        for (order_key, key) in order.into_iter().take(excess) {
+            ops.push(WriteOp::Delete {
+                cf: ColumnFamily::Metadata,
+                key: order_key,
+            });
+            ops.push(WriteOp::Delete {
+                cf: ColumnFamily::Metadata,
+                key: entry_key(&String::from_utf8_lossy(&key)),
+            });
+        }
+        match self.store.batch_write(ops).await {
+            Ok(()) => {
+                self.entries.fetch_sub(excess, Ordering::Relaxed);
+                tracing::debug!("Evicted {} entries from the embedding cache", excess);
+            }
+            Err(e) => tracing::warn!("Failed to evict from the embedding cache: {}", e),
+        }
+    }
+}
+
+#[async_trait]
+impl PersistentEmbeddingCache for StoreEmbeddingCache {
+    async fn get(&self, key: &str) -> Option<Vec<f32>> {
+        match self.store.get_metadata(&format!("{}{}", ENTRY_KEY_PREFIX, key)).await {
+            Ok(value) => value.and_then(|bytes| decode_embedding(&bytes)),
+            Err(e) => {
+                tracing::warn!("Failed to read the embedding cache: {}", e);
+                None
+            }
+        }
+    }
+
+    async fn put(&self, key: &str, embedding: &[f32]) {
+        let millis = chrono::Utc::now().timestamp_millis().max(0) as u64;
+        let mut order_key = ORDER_KEY_PREFIX.as_bytes().to_vec();
+        order_key.extend_from_slice(&millis.to_be_bytes());
+        order_key.extend_from_slice(key.as_bytes());
+
+        let ops = vec![
+            WriteOp::Put {
+                cf: ColumnFamily::Metadata,
+                key: entry_key(key),
+                value: encode_embedding(embedding),
+            },
+            WriteOp::Put {
+                cf: ColumnFamily::Metadata,
+                key: order_key,
+                value: key.as_bytes().to_vec(),
+            },
+        ];
+        if let Err(e) = self.store.batch_write(ops).await {
+            tracing::warn!("Failed to write the embedding cache: {}", e);
+            return;
+        }
+        self.entries.fetch_add(1, Ordering::Relaxed);
+        self.evict().await;
+    }
+}
+
+fn entry_key(key: &str) -> Vec<u8> {
+    format!("{}{}", ENTRY_KEY_PREFIX, key).into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_storage::sqlite::SqliteStore;
+
+    #[tokio::test]
+    async fn test_store_embedding_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("synton.db");
+        let store: Arc<dyn Store> = Arc::new(SqliteStore::open_path(&path).unwrap());
+
+        let cache = StoreEmbeddingCache::open(Arc::clone(&store), 10).await.unwrap();
+        assert!(cache.is_empty());
+        for i in 0..11 {
+            cache.put(&format!("key-{:02}", i), &[i as f32]).await;
+        }
+        // Past the bound, the oldest entries are evicted down to nine tenths of it
+        assert_eq!(cache.len(), 9);
+        assert_eq!(cache.get("key-00").await, None);
+        assert_eq!(cache.get("key-10").await, Some(vec![10.0]));
+
+        // Entries survive reopening the store
+        drop(cache);
+        let cache = StoreEmbeddingCache::open(store, 10).await.unwrap();
+        assert_eq!(cache.len(), 9);
+        assert_eq!(cache.get("key-05").await, Some(vec![5.0]));
+    }
+}
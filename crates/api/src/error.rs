@@ -4,6 +4,8 @@
 
 use std::fmt;
 
+use synton_error::{Coded, ErrorBody, ErrorCode, FieldError, SyntonError};
+
 /// API errors.
 #[derive(Debug, Clone)]
 pub enum ApiError {
@@ -16,9 +18,52 @@ pub enum ApiError {
     /// Trace not found.
     TraceNotFound(String),
 
+    /// Prepared query handle not found.
+    PreparedQueryNotFound(uuid::Uuid),
+
+    /// Query ID unknown or no longer tracked for feedback.
+    QueryNotFound(uuid::Uuid),
+
+    /// Vector store not found.
+    VectorStoreNotFound(String),
+
+    /// Uploaded file not found.
+    FileNotFound(String),
+
+    /// No edge with this ID awaits review.
+    PendingEdgeNotFound(String),
+
+    /// No ingestion pipeline with this name is configured.
+    PipelineNotFound(String),
+
+    /// No background job with this ID.
+    JobNotFound(uuid::Uuid),
+
+    /// No materialized view with this name.
+    ViewNotFound(String),
+
+    /// No attachment with this ID on the node.
+    AttachmentNotFound(uuid::Uuid),
+
+    /// The ingestion pipeline is already running.
+    PipelineRunning(String),
+
+    /// The node changed since the version an update expected.
+    VersionConflict {
+        /// Node ID.
+        id: uuid::Uuid,
+        /// Version the update expected.
+        expected: u64,
+        /// Current version of the node.
+        current: u64,
+    },
+
     /// Invalid request.
     InvalidRequest(String),
 
+    /// Request fields that failed validation.
+    Validation(Vec<FieldError>),
+
     /// Internal service error.
     Internal(String),
 
@@ -30,6 +75,25 @@ pub enum ApiError {
 
     /// Not implemented.
     NotImplemented(String),
+
+    /// Estimated query cost exceeds the caller's limit.
+    CostLimitExceeded {
+        /// Estimated cost.
+        estimated: u64,
+        /// Maximum accepted cost.
+        limit: u64,
+    },
+
+    /// Error from a lower layer, keeping its code and cause chain.
+    Upstream(SyntonError),
+
+    /// Too many in-flight requests for a route class.
+    Overloaded {
+        /// Route class that hit its limit.
+        class: String,
+        /// Seconds the client should wait before retrying.
+        retry_after_secs: u64,
+    },
 }
 
 impl fmt::Display for ApiError {
@@ -37,24 +101,110 @@ impl fmt::Display for ApiError {
         match self {
             Self::NodeNotFound(id) => write!(f, "Node not found: {}", id),
             Self::InvalidRequest(msg) => write!(f, "Invalid request: {}", msg),
+            Self::Validation(fields) => {
+                let fields: Vec<String> = fields.iter().map(ToString::to_string).collect();
+                write!(f, "Invalid request: {}", fields.join("; "))
+            }
             Self::Internal(msg) => write!(f, "Internal error: {}", msg),
             Self::Storage(msg) => write!(f, "Storage error: {}", msg),
             Self::Serialization(msg) => write!(f, "Serialization error: {}", msg),
             Self::NotImplemented(msg) => write!(f, "Not implemented: {}", msg),
             Self::InvalidTraceId(id) => write!(f, "Invalid trace ID: {}", id),
             Self::TraceNotFound(id) => write!(f, "Trace not found: {}", id),
+            Self::PreparedQueryNotFound(id) => write!(f, "Prepared query not found: {}", id),
+            Self::QueryNotFound(id) => write!(f, "Query not found: {}", id),
+            Self::VectorStoreNotFound(id) => write!(f, "Vector store not found: {}", id),
+            Self::FileNotFound(id) => write!(f, "File not found: {}", id),
+            Self::PendingEdgeNotFound(id) => write!(f, "Pending edge not found: {}", id),
+            Self::PipelineNotFound(name) => write!(f, "Pipeline not found: {}", name),
+            Self::JobNotFound(id) => write!(f, "Job not found: {}", id),
+            Self::ViewNotFound(name) => write!(f, "View not found: {}", name),
+            Self::AttachmentNotFound(id) => write!(f, "Attachment not found: {}", id),
+            Self::PipelineRunning(name) => write!(f, "Pipeline already running: {}", name),
+            Self::VersionConflict {
+                id,
+                expected,
+                current,
+            } => write!(
+                f,
+                "Version conflict on node {}: expected version {}, current version is {}",
+                id, expected, current
+            ),
+            Self::CostLimitExceeded { estimated, limit } => write!(
+                f,
+                "Estimated cost {} exceeds max_estimated_cost {}",
+                estimated, limit
+            ),
+            Self::Upstream(e) => write!(f, "{}", e),
+            Self::Overloaded { class, retry_after_secs } => write!(
+                f,
+                "Too many {} requests in flight, retry after {}s",
+                class, retry_after_secs
+            ),
         }
     }
 }
 
-impl std::error::Error for ApiError {}
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Upstream(e) => std::error::Error::source(e),
+            _ => None,
+        }
+    }
+}
+
+impl Coded for ApiError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::NodeNotFound(_)
+            | Self::TraceNotFound(_)
+            | Self::PreparedQueryNotFound(_)
+            | Self::QueryNotFound(_)
+            | Self::VectorStoreNotFound(_)
+            | Self::FileNotFound(_)
+            | Self::PendingEdgeNotFound(_)
+            | Self::PipelineNotFound(_)
+            | Self::JobNotFound(_)
+            | Self::ViewNotFound(_)
+            | Self::AttachmentNotFound(_) => ErrorCode::NotFound,
+            Self::PipelineRunning(_) | Self::VersionConflict { .. } => ErrorCode::Conflict,
+            Self::InvalidRequest(_) | Self::Validation(_) | Self::InvalidTraceId(_) => {
+                ErrorCode::InvalidInput
+            }
+            Self::Storage(_) => ErrorCode::Storage,
+            Self::Serialization(_) => ErrorCode::Serialization,
+            Self::NotImplemented(_) => ErrorCode::NotImplemented,
+            Self::CostLimitExceeded { .. } => ErrorCode::LimitExceeded,
+            Self::Overloaded { .. } => ErrorCode::Overloaded,
+            Self::Internal(_) => ErrorCode::Internal,
+            Self::Upstream(e) => e.code(),
+        }
+    }
+}
+
+impl ApiError {
+    /// Convert into the shared error type, unwrapping upstream errors.
+    pub fn into_synton(self) -> SyntonError {
+        match self {
+            Self::Upstream(e) => e,
+            other => SyntonError::from(other),
+        }
+    }
+}
+
+impl From<SyntonError> for ApiError {
+    fn from(e: SyntonError) -> Self {
+        Self::Upstream(e)
+    }
+}
 
 /// Result type for API operations.
 pub type ApiResult<T> = Result<T, ApiError>;
 
 impl From<synton_graph::GraphError> for ApiError {
     fn from(e: synton_graph::GraphError) -> Self {
-        Self::Storage(e.to_string())
+        Self::Upstream(e.into())
     }
 }
 
@@ -66,7 +216,13 @@ impl From<synton_graphrag::GraphRagError> for ApiError {
 
 impl From<synton_storage::StorageError> for ApiError {
     fn from(e: synton_storage::StorageError) -> Self {
-        Self::Storage(e.to_string())
+        Self::Upstream(e.into())
+    }
+}
+
+impl From<synton_ingest::IngestError> for ApiError {
+    fn from(e: synton_ingest::IngestError) -> Self {
+        Self::Upstream(e.into())
     }
 }
 
@@ -82,30 +238,55 @@ impl From<synton_memory::MemoryError> for ApiError {
     }
 }
 
+impl From<synton_vector::VectorError> for ApiError {
+    fn from(e: synton_vector::VectorError) -> Self {
+        Self::Upstream(e.into())
+    }
+}
+
 impl From<synton_core::CoreError> for ApiError {
     fn from(e: synton_core::CoreError) -> Self {
-        Self::Internal(e.to_string())
+        Self::Upstream(e.into())
     }
 }
 
 impl axum::response::IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
-        let (status, message) = match self {
-            ApiError::NodeNotFound(_) => (axum::http::StatusCode::NOT_FOUND, self.to_string()),
-            ApiError::InvalidRequest(_) => (axum::http::StatusCode::BAD_REQUEST, self.to_string()),
-            ApiError::Internal(_) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
-            ApiError::Storage(_) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
-            ApiError::Serialization(_) => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
-            ApiError::NotImplemented(_) => (axum::http::StatusCode::NOT_IMPLEMENTED, self.to_string()),
-            ApiError::InvalidTraceId(_) => (axum::http::StatusCode::BAD_REQUEST, self.to_string()),
-            ApiError::TraceNotFound(_) => (axum::http::StatusCode::NOT_FOUND, self.to_string()),
+        let retry_after = match &self {
+            ApiError::Overloaded { retry_after_secs, .. } => Some(*retry_after_secs),
+            _ => None,
+        };
+        let fields = match &self {
+            ApiError::Validation(fields) => fields.clone(),
+            _ => Vec::new(),
         };
+        let current_version = match &self {
+            ApiError::VersionConflict { current, .. } => Some(*current),
+            _ => None,
+        };
+
+        let mut error = self.into_synton();
+        if error.trace_id().is_none() {
+            if let Some(context) = synton_instrument::TraceContext::current() {
+                error = error.with_trace_id(context.trace_id.to_string());
+            }
+        }
 
-        let body = axum::Json(serde_json::json!({
-            "error": message,
-        }));
+        let status = axum::http::StatusCode::from_u16(error.code().http_status())
+            .unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+        let body = axum::Json(ErrorBody {
+            fields,
+            current_version,
+            ..ErrorBody::from(&error)
+        });
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        if let Some(secs) = retry_after {
+            response
+                .headers_mut()
+                .insert(axum::http::header::RETRY_AFTER, axum::http::HeaderValue::from(secs));
+        }
+        response
     }
 }
 
@@ -129,4 +310,67 @@ mod tests {
         // Should return BAD_REQUEST status
         assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
     }
+
+    #[tokio::test]
+    async fn test_upstream_error_keeps_code_and_causes() {
+        let io = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+        let error: ApiError = synton_storage::StorageError::from(io).into();
+        assert_eq!(error.code(), ErrorCode::Storage);
+
+        let missing: ApiError = synton_graph::GraphError::NodeNotFound(uuid::Uuid::nil()).into();
+        let response = missing.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: ErrorBody = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.code, ErrorCode::NotFound);
+        assert!(body.error.contains("Node not found"));
+    }
+
+    #[tokio::test]
+    async fn test_validation_response_lists_fields() {
+        let error = ApiError::Validation(vec![
+            FieldError::new("max_depth", "must be between 1 and 5 (got 0)"),
+            FieldError::new("weight", "must be between 0 and 1 (got 2)"),
+        ]);
+        assert!(error.to_string().contains("max_depth: must be between 1 and 5"));
+
+        let response = error.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: ErrorBody = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.code, ErrorCode::InvalidInput);
+        assert_eq!(body.fields.len(), 2);
+        assert_eq!(body.fields[0].field, "max_depth");
+    }
+
+    #[test]
+    fn test_overloaded_response_sets_retry_after() {
+        let error = ApiError::Overloaded {
+            class: "ingest".to_string(),
+            retry_after_secs: 2,
+        };
+        let response = error.into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[axum::http::header::RETRY_AFTER], "2");
+    }
+
+    #[tokio::test]
+    async fn test_version_conflict_response_has_current_version() {
+        let error = ApiError::VersionConflict {
+            id: uuid::Uuid::nil(),
+            expected: 2,
+            current: 3,
+        };
+        let response = error.into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::CONFLICT);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: ErrorBody = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.code, ErrorCode::Conflict);
+        assert_eq!(body.current_version, Some(3));
+    }
 }
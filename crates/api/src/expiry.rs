@@ -0,0 +1,36 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Knowledge expiry digests.
+//!
+//! Unless accessed, a node's score decays towards the minimum score of its
+//! decay profile, where it becomes a pruning candidate. The expiry digest
+//! lists the nodes predicted to get there within a number of days, so people
+//! can rescue the knowledge that matters by accessing it. A scheduled job
+//! delivers the digest to the mutation hooks as an `expiry_digest` event.
+
+use std::time::Duration;
+
+/// Scheduled expiry digest settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpiryDigestConfig {
+    /// Days ahead the digest looks.
+    pub days: u32,
+
+    /// Most nodes listed in a digest.
+    pub limit: usize,
+
+    /// Interval between digests.
+    pub interval: Duration,
+}
+
+impl Default for ExpiryDigestConfig {
+    fn default() -> Self {
+        Self {
+            days: 7,
+            limit: 100,
+            interval: Duration::from_secs(24 * 3600),
+        }
+    }
+}
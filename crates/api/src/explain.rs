@@ -0,0 +1,325 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Match explanations for query results.
+//!
+//! When a query asks for metadata, each returned node is annotated with the
+//! query terms found in its content (as character offsets, for
+//! highlighting), its similarity to the query and the filter predicates it
+//! satisfies.
+
+use std::cmp::Ordering;
+
+use synton_core::Node;
+use synton_paql::{ComparisonOp, Filter, FilterField, FilterValue, Query, QueryNode, Stage};
+
+use crate::models::TermMatch;
+
+/// Occurrences of the query terms in the content, as non-overlapping
+/// character ranges in content order. A phrase that does not occur as a
+/// whole is matched word by word.
+pub(crate) fn term_matches(content: &str, terms: &[String]) -> Vec<TermMatch> {
+    // Lowercase per character, remembering which original character each
+    // lowered one came from, so offsets refer to the unmodified content.
+    let mut lowered = Vec::new();
+    let mut origin = Vec::new();
+    for (i, c) in content.chars().enumerate() {
+        for l in c.to_lowercase() {
+            lowered.push(l);
+            origin.push(i);
+        }
+    }
+
+    let mut found = Vec::new();
+    for term in terms {
+        let phrase = find_all(&lowered, &origin, term);
+        if phrase.is_empty() {
+            for word in term.split_whitespace() {
+                found.extend(find_all(&lowered, &origin, word));
+            }
+        } else {
+            found.extend(phrase);
+        }
+    }
+
+    found.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    let mut matches: Vec<TermMatch> = Vec::with_capacity(found.len());
+    for m in found {
+        let overlaps = matches.last().is_some_and(|last| m.start < last.end);
+        if !overlaps {
+            matches.push(m);
+        }
+    }
+    matches
+}
+
+/// Every non-overlapping occurrence of `term` in the lowered content.
+fn find_all(lowered: &[char], origin: &[usize], term: &str) -> Vec<TermMatch> {
+    let needle: Vec<char> = term.chars().collect();
+    let mut matches = Vec::new();
+    if needle.is_empty() || needle.len() > lowered.len() {
+        return matches;
+    }
+
+    let mut i = 0;
+    while i + needle.len() <= lowered.len() {
+        if lowered[i..i + needle.len()] == needle[..] {
+            matches.push(TermMatch {
+                term: term.to_string(),
+                start: origin[i],
+                end: origin[i + needle.len() - 1] + 1,
+            });
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+/// Filter conditions anywhere in the query tree and its filter stages.
+pub(crate) fn collect_filters(query: &Query) -> Vec<&Filter> {
+    let mut filters = Vec::new();
+    let mut stack = vec![&query.root];
+
+    while let Some(node) = stack.pop() {
+        match node {
+            QueryNode::Filter { input, filters: own } => {
+                filters.extend(own);
+                stack.push(input);
+            }
+            QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+                stack.push(left);
+                stack.push(right);
+            }
+            QueryNode::Not { input } => stack.push(input),
+            _ => {}
+        }
+    }
+    for stage in &query.stages {
+        if let Stage::Filter(own) = stage {
+            filters.extend(own);
+        }
+    }
+
+    filters
+}
+
+/// Human-readable predicates among `filters` that the node satisfies.
+pub(crate) fn satisfied_filters(node: &Node, filters: &[&Filter]) -> Vec<String> {
+    filters
+        .iter()
+        .filter(|filter| satisfies(node, filter))
+        .map(|filter| describe(filter))
+        .collect()
+}
+
+/// A field value read from a node.
+enum FieldValue {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+fn field_value(node: &Node, field: &FilterField) -> Option<FieldValue> {
+    match field {
+        FilterField::Content => Some(FieldValue::Text(node.content().to_string())),
+        FilterField::NodeType => Some(FieldValue::Text(node.node_type.to_string())),
+        FilterField::AccessScore => Some(FieldValue::Number(node.meta.access_score as f64)),
+        FilterField::Confidence => Some(FieldValue::Number(node.meta.confidence as f64)),
+        FilterField::CreatedAt => Some(FieldValue::Number(
+            node.meta.created_at.timestamp_millis() as f64 / 1000.0,
+        )),
+        FilterField::Tag => None,
+        FilterField::Custom(name) => match node.attributes.get(name)? {
+            serde_json::Value::String(s) => Some(FieldValue::Text(s.clone())),
+            serde_json::Value::Number(n) => n.as_f64().map(FieldValue::Number),
+            serde_json::Value::Bool(b) => Some(FieldValue::Bool(*b)),
+            _ => None,
+        },
+    }
+}
+
+/// Whether the node satisfies a single filter condition. Text comparisons
+/// are case-insensitive; timestamps compare as RFC 3339 strings or Unix
+/// seconds.
+pub(crate) fn satisfies(node: &Node, filter: &Filter) -> bool {
+    if filter.field == FilterField::Tag {
+        return satisfies_tag(node, filter);
+    }
+    let Some(actual) = field_value(node, &filter.field) else {
+        return false;
+    };
+
+    match filter.op {
+        ComparisonOp::In => match &filter.value {
+            FilterValue::List(values) => values.iter().any(|v| compare(&actual, v) == Some(Ordering::Equal)),
+            _ => false,
+        },
+        ComparisonOp::Contains => match (&actual, &filter.value) {
+            (FieldValue::Text(text), FilterValue::String(needle)) => {
+                text.to_lowercase().contains(&needle.to_lowercase())
+            }
+            _ => false,
+        },
+        op => compare(&actual, &filter.value).is_some_and(|ordering| match op {
+            ComparisonOp::Eq => ordering == Ordering::Equal,
+            ComparisonOp::Ne => ordering != Ordering::Equal,
+            ComparisonOp::Gt => ordering == Ordering::Greater,
+            ComparisonOp::Ge => ordering != Ordering::Less,
+            ComparisonOp::Lt => ordering == Ordering::Less,
+            ComparisonOp::Le => ordering != Ordering::Greater,
+            ComparisonOp::Contains | ComparisonOp::In => false,
+        }),
+    }
+}
+
+/// Tag filters test membership: `=` and `contains` hold when the node
+/// carries the tag, `!=` when it does not, `in` when it carries any of them.
+fn satisfies_tag(node: &Node, filter: &Filter) -> bool {
+    let has_tag = |value: &FilterValue| match value {
+        FilterValue::String(tag) => node.has_tag(tag),
+        FilterValue::Integer(tag) => node.has_tag(&tag.to_string()),
+        _ => false,
+    };
+    match (filter.op, &filter.value) {
+        (ComparisonOp::Eq | ComparisonOp::Contains, value) => has_tag(value),
+        (ComparisonOp::Ne, value) => !has_tag(value),
+        (ComparisonOp::In, FilterValue::List(values)) => values.iter().any(has_tag),
+        _ => false,
+    }
+}
+
+/// Order of a node value relative to a filter value, if they are comparable.
+fn compare(actual: &FieldValue, expected: &FilterValue) -> Option<Ordering> {
+    match (actual, expected) {
+        (FieldValue::Text(a), FilterValue::String(b)) => Some(a.to_lowercase().cmp(&b.to_lowercase())),
+        (FieldValue::Number(a), FilterValue::Integer(b)) => a.partial_cmp(&(*b as f64)),
+        (FieldValue::Number(a), FilterValue::Float(b)) => a.partial_cmp(b),
+        (FieldValue::Number(a), FilterValue::String(b)) => {
+            let b = chrono::DateTime::parse_from_rfc3339(b).ok()?;
+            a.partial_cmp(&(b.timestamp_millis() as f64 / 1000.0))
+        }
+        (FieldValue::Bool(a), FilterValue::Boolean(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// Predicate text, e.g. `confidence >= 0.8` or `content contains "rust"`.
+fn describe(filter: &Filter) -> String {
+    let field = match &filter.field {
+        FilterField::Content => "content",
+        FilterField::NodeType => "node_type",
+        FilterField::AccessScore => "access_score",
+        FilterField::Confidence => "confidence",
+        FilterField::CreatedAt => "created_at",
+        FilterField::Tag => "tag",
+        FilterField::Custom(name) => name.as_str(),
+    };
+    let op = match filter.op {
+        ComparisonOp::Eq => "=",
+        ComparisonOp::Ne => "!=",
+        ComparisonOp::Gt => ">",
+        ComparisonOp::Ge => ">=",
+        ComparisonOp::Lt => "<",
+        ComparisonOp::Le => "<=",
+        ComparisonOp::Contains => "contains",
+        ComparisonOp::In => "in",
+    };
+    format!("{} {} {}", field, op, describe_value(&filter.value))
+}
+
+fn describe_value(value: &FilterValue) -> String {
+    match value {
+        FilterValue::String(s) => format!("{:?}", s),
+        FilterValue::Integer(i) => i.to_string(),
+        FilterValue::Float(f) => f.to_string(),
+        FilterValue::Boolean(b) => b.to_string(),
+        FilterValue::List(values) => format!(
+            "[{}]",
+            values.iter().map(describe_value).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    #[test]
+    fn test_term_matches_use_character_offsets() {
+        let terms = vec!["rust".to_string()];
+        let matches = term_matches("Über Rust and rust", &terms);
+
+        let spans: Vec<(usize, usize)> = matches.iter().map(|m| (m.start, m.end)).collect();
+        assert_eq!(spans, vec![(5, 9), (14, 18)]);
+
+        // A phrase that does not occur whole is matched word by word
+        let terms = vec!["memory graph".to_string()];
+        let matches = term_matches("A graph of memory", &terms);
+        let words: Vec<&str> = matches.iter().map(|m| m.term.as_str()).collect();
+        assert_eq!(words, vec!["graph", "memory"]);
+    }
+
+    #[test]
+    fn test_satisfied_filters() {
+        let mut node = Node::new("Rust is a systems language", NodeType::Fact);
+        node.meta.confidence = 0.9;
+        node.attributes = serde_json::json!({ "lang": "en" });
+
+        let filters = [
+            Filter::new(
+                FilterField::Content,
+                ComparisonOp::Contains,
+                FilterValue::String("rust".to_string()),
+            ),
+            Filter::new(FilterField::Confidence, ComparisonOp::Ge, FilterValue::Float(0.8)),
+            Filter::new(
+                FilterField::NodeType,
+                ComparisonOp::In,
+                FilterValue::List(vec![FilterValue::String("entity".to_string())]),
+            ),
+            Filter::new(
+                FilterField::Custom("lang".to_string()),
+                ComparisonOp::Eq,
+                FilterValue::String("en".to_string()),
+            ),
+        ];
+        let query = Query::new(QueryNode::Filter {
+            input: Box::new(QueryNode::TextSearch {
+                query: "rust".to_string(),
+            }),
+            filters: filters[..3].to_vec(),
+        })
+        .with_stage(Stage::Filter(filters[3..].to_vec()));
+
+        let collected = collect_filters(&query);
+        assert_eq!(
+            satisfied_filters(&node, &collected),
+            vec![
+                "content contains \"rust\"".to_string(),
+                "confidence >= 0.8".to_string(),
+                "lang = \"en\"".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tag_filters() {
+        let node = Node::new("Storage engine", NodeType::Concept).with_tags(["architecture"]);
+        let tag = |op, value| Filter::new(FilterField::Tag, op, value);
+        let text = |tag: &str| FilterValue::String(tag.to_string());
+
+        assert!(satisfies(&node, &tag(ComparisonOp::Eq, text("Architecture"))));
+        assert!(!satisfies(&node, &tag(ComparisonOp::Ne, text("architecture"))));
+        assert!(satisfies(&node, &tag(ComparisonOp::Ne, text("db"))));
+        assert!(satisfies(
+            &node,
+            &tag(ComparisonOp::In, FilterValue::List(vec![text("db"), text("architecture")]))
+        ));
+        assert!(!satisfies(&node, &tag(ComparisonOp::Gt, text("a"))));
+        assert_eq!(describe(&tag(ComparisonOp::Eq, text("db"))), "tag = \"db\"");
+    }
+}
@@ -0,0 +1,344 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Snapshot export archives.
+//!
+//! An archive is a JSON Lines stream of records read from one graph
+//! snapshot, so writes made while it streams are either entirely in it or
+//! not at all. The first record is a header naming the format, its version
+//! and the record counts; node records follow, then edge records, then,
+//! if requested, one embedding record per embedded node. Embeddings are
+//! kept out of the node records so archives without them stay small.
+//!
+//! Archived nodes are not part of the graph and are not exported, nor are
+//! nodes out of the namespace scope of the exporting request, with their
+//! edges.
+
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use synton_core::{Edge, Node};
+use synton_graph::GraphSnapshot;
+use uuid::Uuid;
+
+use crate::namespaces::NamespaceScope;
+use crate::{ApiError, ApiResult};
+
+/// Format named in the header of every archive.
+pub const EXPORT_FORMAT: &str = "synton-export";
+
+/// Current archive version; archives of later versions are rejected.
+pub const EXPORT_VERSION: u32 = 1;
+
+/// Content type of an archive.
+pub const EXPORT_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Largest archive accepted for import.
+pub const MAX_IMPORT_BYTES: usize = 1024 * 1024 * 1024;
+
+/// Bytes of records sent to the client at a time.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Chunks buffered ahead of a slow client.
+const CHUNK_BUFFER: usize = 4;
+
+/// First record of an archive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportHeader {
+    /// Always [`EXPORT_FORMAT`].
+    pub format: String,
+
+    /// Archive version.
+    pub version: u32,
+
+    /// When the snapshot was taken.
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+
+    /// Number of node records.
+    pub nodes: usize,
+
+    /// Number of edge records.
+    pub edges: usize,
+
+    /// Number of embedding records.
+    pub embeddings: usize,
+}
+
+/// Embedding of a node, exported apart from the node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeEmbedding {
+    /// Embedded node.
+    pub node_id: Uuid,
+
+    /// Embedding vector.
+    pub vector: Vec<f32>,
+}
+
+/// A line of an archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+pub enum ExportRecord {
+    /// The archive header.
+    Header(ExportHeader),
+
+    /// A node, without its embedding.
+    Node(Node),
+
+    /// An edge.
+    Edge(Edge),
+
+    /// The embedding of a node.
+    Embedding(NodeEmbedding),
+}
+
+/// Nodes and edges read back from an archive, embeddings attached.
+#[derive(Debug, Clone)]
+pub struct Archive {
+    /// The archive header.
+    pub header: ExportHeader,
+
+    /// Nodes, in archive order.
+    pub nodes: Vec<Node>,
+
+    /// Edges, in archive order.
+    pub edges: Vec<Edge>,
+}
+
+/// Write the records of a snapshot as JSON Lines, handing them to `emit`
+/// in chunks, leaving out nodes out of `scope` and their edges. Stops
+/// early once `emit` returns false.
+pub fn write_archive(
+    snapshot: &GraphSnapshot,
+    include_embeddings: bool,
+    scope: Option<&NamespaceScope>,
+    mut emit: impl FnMut(Vec<u8>) -> bool,
+) {
+    let hidden: HashSet<Uuid> = scope
+        .map(|scope| {
+            snapshot
+                .nodes()
+                .filter(|node| !scope.allows(node))
+                .map(|node| node.id)
+                .collect()
+        })
+        .unwrap_or_default();
+    let hidden = &hidden;
+    let visible_nodes = move || {
+        snapshot
+            .nodes()
+            .filter(move |node| !hidden.contains(&node.id))
+    };
+    let visible_edges = move || {
+        snapshot
+            .all_edges()
+            .filter(move |edge| !hidden.contains(&edge.source) && !hidden.contains(&edge.target))
+    };
+
+    let header = ExportHeader {
+        format: EXPORT_FORMAT.to_string(),
+        version: EXPORT_VERSION,
+        exported_at: chrono::Utc::now(),
+        nodes: visible_nodes().count(),
+        edges: visible_edges().count(),
+        embeddings: if include_embeddings {
+            visible_nodes()
+                .filter(|node| node.embedding.is_some())
+                .count()
+        } else {
+            0
+        },
+    };
+
+    let nodes = visible_nodes().map(|node| {
+        ExportRecord::Node(Node {
+            embedding: None,
+            ..node.clone()
+        })
+    });
+    let edges = visible_edges().cloned().map(ExportRecord::Edge);
+    let embeddings = visible_nodes()
+        .filter(|_| include_embeddings)
+        .filter_map(|node| {
+            let vector = node.embedding.clone()?;
+            Some(ExportRecord::Embedding(NodeEmbedding {
+                node_id: node.id,
+                vector,
+            }))
+        });
+    let records = std::iter::once(ExportRecord::Header(header))
+        .chain(nodes)
+        .chain(edges)
+        .chain(embeddings);
+
+    let mut chunk = Vec::with_capacity(CHUNK_BYTES);
+    for record in records {
+        match serde_json::to_vec(&record) {
+            Ok(line) => {
+                chunk.extend_from_slice(&line);
+                chunk.push(b'\n');
+            }
+            Err(e) => {
+                tracing::warn!("Failed to serialize export record: {}", e);
+                continue;
+            }
+        }
+        if chunk.len() >= CHUNK_BYTES {
+            let full = std::mem::replace(&mut chunk, Vec::with_capacity(CHUNK_BYTES));
+            if !emit(full) {
+                return;
+            }
+        }
+    }
+    if !chunk.is_empty() {
+        emit(chunk);
+    }
+}
+
+/// Stream the archive of a snapshot. Records are serialized on a blocking
+/// thread as the client reads them; a client that disconnects stops it.
+pub fn stream_archive(
+    snapshot: GraphSnapshot,
+    include_embeddings: bool,
+    scope: Option<NamespaceScope>,
+) -> impl Stream<Item = Result<Vec<u8>, Infallible>> + Send + 'static {
+    let (sender, receiver) = tokio::sync::mpsc::channel(CHUNK_BUFFER);
+    tokio::task::spawn_blocking(move || {
+        write_archive(&snapshot, include_embeddings, scope.as_ref(), |chunk| {
+            sender.blocking_send(chunk).is_ok()
+        });
+    });
+
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        let chunk = receiver.recv().await?;
+        Some((Ok(chunk), receiver))
+    })
+}
+
+/// Read an archive, attaching each embedding record to its node.
+///
+/// The header must come first and name a version no later than
+/// [`EXPORT_VERSION`]; blank lines are skipped.
+pub fn read_archive(data: &str) -> ApiResult<Archive> {
+    let mut lines = data
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let header = match lines.next().map(|(_, line)| serde_json::from_str(line)) {
+        Some(Ok(ExportRecord::Header(header))) => header,
+        _ => {
+            return Err(ApiError::InvalidRequest(
+                "archive must start with a header record".to_string(),
+            ))
+        }
+    };
+    if header.format != EXPORT_FORMAT {
+        return Err(ApiError::InvalidRequest(format!(
+            "unknown archive format {}",
+            header.format
+        )));
+    }
+    if header.version > EXPORT_VERSION {
+        return Err(ApiError::InvalidRequest(format!(
+            "archive version {} is newer than the supported version {}",
+            header.version, EXPORT_VERSION
+        )));
+    }
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut embeddings = HashMap::new();
+    for (number, line) in lines {
+        let record = serde_json::from_str(line).map_err(|e| {
+            ApiError::InvalidRequest(format!("invalid archive record on line {}: {}", number + 1, e))
+        })?;
+        match record {
+            ExportRecord::Header(_) => {
+                return Err(ApiError::InvalidRequest(format!(
+                    "unexpected header record on line {}",
+                    number + 1
+                )))
+            }
+            ExportRecord::Node(node) => nodes.push(node),
+            ExportRecord::Edge(edge) => edges.push(edge),
+            ExportRecord::Embedding(embedding) => {
+                embeddings.insert(embedding.node_id, embedding.vector);
+            }
+        }
+    }
+
+    for node in &mut nodes {
+        if let Some(vector) = embeddings.remove(&node.id) {
+            node.embedding = Some(vector);
+        }
+    }
+
+    Ok(Archive {
+        header,
+        nodes,
+        edges,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::{NodeType, Relation};
+    use synton_graph::MemoryGraph;
+
+    fn snapshot() -> (GraphSnapshot, Node, Node) {
+        let embedded = Node::new("Pumps", NodeType::Concept).with_embedding(vec![1.0, 0.0]);
+        let plain = Node::new("Valves", NodeType::Concept);
+        let edge = Edge::new(embedded.id, plain.id, Relation::Causes);
+        let graph = MemoryGraph::from_parts(vec![embedded.clone(), plain.clone()], vec![edge]).unwrap();
+        (graph.snapshot(), embedded, plain)
+    }
+
+    fn archive(snapshot: &GraphSnapshot, include_embeddings: bool) -> String {
+        let mut data = Vec::new();
+        write_archive(snapshot, include_embeddings, None, |chunk| {
+            data.extend(chunk);
+            true
+        });
+        String::from_utf8(data).unwrap()
+    }
+
+    #[test]
+    fn test_archive_roundtrip() {
+        let (snapshot, embedded, plain) = snapshot();
+
+        let data = archive(&snapshot, true);
+        assert_eq!(data.lines().count(), 5);
+        assert!(data.lines().skip(1).take(2).all(|line| !line.contains("\"embedding\":[")));
+
+        let restored = read_archive(&data).unwrap();
+        assert_eq!((restored.header.nodes, restored.header.edges), (2, 1));
+        assert_eq!(restored.header.embeddings, 1);
+        assert_eq!(restored.edges[0].source, embedded.id);
+        let by_id: HashMap<Uuid, &Node> = restored.nodes.iter().map(|node| (node.id, node)).collect();
+        assert_eq!(by_id[&embedded.id].embedding, embedded.embedding);
+        assert_eq!(by_id[&plain.id].embedding, None);
+
+        let without = read_archive(&archive(&snapshot, false)).unwrap();
+        assert_eq!(without.header.embeddings, 0);
+        assert!(without.nodes.iter().all(|node| node.embedding.is_none()));
+    }
+
+    #[test]
+    fn test_read_archive_rejects_invalid() {
+        let (snapshot, _, _) = snapshot();
+        let data = archive(&snapshot, false);
+
+        let headless: String = data.lines().skip(1).map(|line| format!("{}\n", line)).collect();
+        assert!(read_archive(&headless).is_err());
+
+        let newer = data.replacen("\"version\":1", "\"version\":2", 1);
+        assert!(read_archive(&newer).is_err());
+
+        let truncated = format!("{}{{\"record\":\"node\"}}\n", data);
+        assert!(matches!(read_archive(&truncated), Err(ApiError::InvalidRequest(_))));
+    }
+}
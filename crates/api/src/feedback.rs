@@ -0,0 +1,184 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Relevance feedback bookkeeping for retrieval results.
+//!
+//! With persistence the feedback counts and learned relation weights are
+//! kept in the metadata column family under `feedback` and reloaded at
+//! startup. The results of tracked queries are not: feedback on a query
+//! answered before a restart is rejected.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+use synton_graphrag::Scorer;
+
+use crate::models::FeedbackStats;
+
+/// Default number of queries whose results can still receive feedback.
+pub(crate) const DEFAULT_TRACKED_QUERIES: usize = 1024;
+
+/// Storage metadata key of the persisted feedback.
+pub(crate) const FEEDBACK_KEY: &str = "feedback";
+
+/// Feedback counts and learned relation weights, as persisted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PersistedFeedback {
+    pub useful: usize,
+    pub not_useful: usize,
+    pub relation_weights: HashMap<String, f32>,
+}
+
+/// Tracks returned results per query and the learned relation weights.
+///
+/// Only the most recent queries are remembered; feedback for an evicted
+/// query is rejected.
+#[derive(Debug)]
+pub(crate) struct FeedbackTracker {
+    capacity: usize,
+    results: HashMap<Uuid, Vec<Uuid>>,
+    order: VecDeque<Uuid>,
+    useful: usize,
+    not_useful: usize,
+    scorer: Scorer,
+}
+
+impl FeedbackTracker {
+    /// Create a tracker remembering at most `capacity` queries.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            results: HashMap::new(),
+            order: VecDeque::new(),
+            useful: 0,
+            not_useful: 0,
+            scorer: Scorer::with_weights(0.6, 0.4).with_hop_decay(0.5),
+        }
+    }
+
+    /// Remember the nodes returned by a query and return its ID.
+    pub(crate) fn track(&mut self, node_ids: Vec<Uuid>) -> Uuid {
+        while self.order.len() >= self.capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            self.results.remove(&oldest);
+        }
+
+        let query_id = Uuid::new_v4();
+        self.results.insert(query_id, node_ids);
+        self.order.push_back(query_id);
+        query_id
+    }
+
+    /// Nodes returned by a tracked query.
+    pub(crate) fn results(&self, query_id: Uuid) -> Option<&[Uuid]> {
+        self.results.get(&query_id).map(Vec::as_slice)
+    }
+
+    /// Count a feedback event and adjust the weights of the relations that
+    /// connected the rated node to the rest of the result set.
+    pub(crate) fn record(&mut self, useful: bool, relations: &[String]) -> HashMap<String, f32> {
+        if useful {
+            self.useful += 1;
+        } else {
+            self.not_useful += 1;
+        }
+
+        relations
+            .iter()
+            .map(|relation| {
+                let weight = self.scorer.record_relation_feedback(relation, useful);
+                (relation.clone(), weight)
+            })
+            .collect()
+    }
+
+    /// Scorer carrying the learned relation weights.
+    pub(crate) fn scorer(&self) -> &Scorer {
+        &self.scorer
+    }
+
+    /// Counts and learned weights to persist.
+    pub(crate) fn persisted(&self) -> PersistedFeedback {
+        PersistedFeedback {
+            useful: self.useful,
+            not_useful: self.not_useful,
+            relation_weights: self.scorer.relation_weights.clone(),
+        }
+    }
+
+    /// Restore the counts and learned weights of an earlier run.
+    pub(crate) fn restore(&mut self, persisted: PersistedFeedback) {
+        self.useful = persisted.useful;
+        self.not_useful = persisted.not_useful;
+        self.scorer.relation_weights = persisted.relation_weights;
+    }
+
+    /// Aggregate feedback statistics.
+    pub(crate) fn stats(&self) -> FeedbackStats {
+        let total = self.useful + self.not_useful;
+        FeedbackStats {
+            total,
+            useful: self.useful,
+            not_useful: self.not_useful,
+            useful_rate: if total == 0 {
+                0.0
+            } else {
+                self.useful as f64 / total as f64
+            },
+            tracked_queries: self.results.len(),
+            relation_weights: self.scorer.relation_weights.clone(),
+        }
+    }
+}
+
+impl Default for FeedbackTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRACKED_QUERIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track_evicts_oldest_query() {
+        let mut tracker = FeedbackTracker::new(2);
+        let node = Uuid::new_v4();
+
+        let a = tracker.track(vec![node]);
+        let b = tracker.track(vec![node]);
+        let c = tracker.track(Vec::new());
+
+        assert!(tracker.results(a).is_none());
+        assert_eq!(tracker.results(b), Some(&[node][..]));
+        assert!(tracker.results(c).is_some());
+        assert_eq!(tracker.stats().tracked_queries, 2);
+    }
+
+    #[test]
+    fn test_record_updates_stats_and_weights() {
+        let mut tracker = FeedbackTracker::default();
+        let relations = vec!["causes".to_string()];
+
+        let updated = tracker.record(true, &relations);
+        assert!(updated["causes"] > 1.0);
+        tracker.record(false, &[]);
+
+        let stats = tracker.stats();
+        assert_eq!(stats.total, 2);
+        assert_eq!(stats.useful, 1);
+        assert_eq!(stats.not_useful, 1);
+        assert_eq!(stats.useful_rate, 0.5);
+        assert!(stats.relation_weights.contains_key("causes"));
+
+        let mut restored = FeedbackTracker::default();
+        restored.restore(tracker.persisted());
+        assert_eq!(restored.persisted(), tracker.persisted());
+        assert_eq!(restored.stats().total, 2);
+    }
+}
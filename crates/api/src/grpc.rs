@@ -2,19 +2,30 @@
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 
+use std::future::Future;
 use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use uuid::Uuid;
 
 use crate::{
     models::{
         AddEdgeRequest as ApiAddEdgeRequest, AddNodeRequest as ApiAddNodeRequest,
-        DeleteNodeRequest, GetNodeRequest, QueryRequest as ApiQueryRequest,
-        TraverseRequest as ApiTraverseRequest,
+        DeleteNodeRequest, GetNodeRequest, HybridSearchRequest as ApiHybridSearchRequest,
+        IngestDocumentRequest as ApiIngestDocumentRequest, IngestRecord,
+        QueryRequest as ApiQueryRequest, TraverseRequest as ApiTraverseRequest,
     },
-    SyntonDbService,
+    validation::check,
+    ApiError, ConcurrencyLimits, SyntonDbService,
 };
-use synton_core::{Edge as CoreEdge, Node as CoreNode, NodeType as CoreNodeType, Relation as CoreRelation};
+use synton_core::{
+    Edge as CoreEdge, EdgeCreator as CoreEdgeCreator, EdgeProvenance as CoreEdgeProvenance,
+    Node as CoreNode, NodeType as CoreNodeType, Relation as CoreRelation,
+};
+use synton_error::{Coded, ErrorCode};
 use synton_graph::{Graph, TraverseDirection as GraphTraverseDirection};
+use synton_instrument::{TraceCollector, TraceContext, TRACEPARENT_HEADER, TRACE_ID_HEADER};
+use synton_storage::Durability;
 
 // Include the generated proto code
 pub mod synton {
@@ -23,6 +34,14 @@ pub mod synton {
 
 use synton::synton_db_server::{SyntonDb as SyntonDbTrait, SyntonDbServer};
 
+/// Most streamed records written in one storage batch.
+const STREAM_INGEST_BATCH: usize = 256;
+
+/// Streamed records read ahead of the batch being written, and acks held
+/// for the client. Reading stops while either buffer is full, so a slow
+/// client or a slow store holds back the sender.
+const STREAM_INGEST_BUFFER: usize = 1024;
+
 /// gRPC service implementation.
 pub struct GrpcService {
     inner: Arc<SyntonDbService>,
@@ -39,366 +58,621 @@ impl GrpcService {
 impl SyntonDbTrait for GrpcService {
     async fn health(
         &self,
-        _request: tonic::Request<synton::HealthRequest>,
+        request: tonic::Request<synton::HealthRequest>,
     ) -> Result<tonic::Response<synton::HealthResponse>, tonic::Status> {
-        let health = self.inner.health();
-        Ok(tonic::Response::new(synton::HealthResponse {
-            status: health.status,
-            version: health.version,
-            uptime_secs: health.uptime_secs,
-        }))
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/Health", async move {
+            let health = self.inner.health();
+            Ok(tonic::Response::new(synton::HealthResponse {
+                status: health.status,
+                version: health.version,
+                uptime_secs: health.uptime_secs,
+            }))
+        })
+        .await
     }
 
     async fn add_node(
         &self,
         request: tonic::Request<synton::AddNodeRequest>,
     ) -> Result<tonic::Response<synton::AddNodeResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let node_type = match req.node_type {
-            1 => CoreNodeType::Entity,   // NODE_TYPE_ENTITY
-            2 => CoreNodeType::Concept,  // NODE_TYPE_CONCEPT
-            3 => CoreNodeType::Fact,     // NODE_TYPE_FACT
-            4 => CoreNodeType::RawChunk, // NODE_TYPE_RAW_CHUNK
-            _ => CoreNodeType::Concept,
-        };
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/AddNode", async move {
+            let req = request.into_inner();
+            let node_type = match req.node_type {
+                1 => CoreNodeType::Entity,   // NODE_TYPE_ENTITY
+                2 => CoreNodeType::Concept,  // NODE_TYPE_CONCEPT
+                3 => CoreNodeType::Fact,     // NODE_TYPE_FACT
+                4 => CoreNodeType::RawChunk, // NODE_TYPE_RAW_CHUNK
+                _ => CoreNodeType::Concept,
+            };
 
-        let api_request = ApiAddNodeRequest {
-            content: req.content,
-            node_type,
-            embedding: if req.embedding.is_empty() {
-                None
-            } else {
-                Some(req.embedding)
-            },
-            attributes: if req.attributes.is_empty() {
-                None
-            } else {
-                Some(serde_json::to_value(req.attributes).unwrap_or_default())
-            },
-        };
+            let api_request = ApiAddNodeRequest {
+                content: req.content,
+                node_type,
+                embedding: if req.embedding.is_empty() {
+                    None
+                } else {
+                    Some(req.embedding)
+                },
+                attributes: if req.attributes.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::to_value(req.attributes).unwrap_or_default())
+                },
+                external_id: None,
+                tags: Vec::new(),
+            };
 
-        match self.inner.add_node(api_request).await {
-            Ok(response) => {
-                let proto_node = core_node_to_proto(response.node);
-                Ok(tonic::Response::new(synton::AddNodeResponse {
-                    node: Some(proto_node),
-                    created: response.created,
-                }))
+            check(&api_request).map_err(api_error_status)?;
+
+            match self.inner.add_node(api_request).await {
+                Ok(response) => {
+                    let proto_node = core_node_to_proto(response.node);
+                    Ok(tonic::Response::new(synton::AddNodeResponse {
+                        node: Some(proto_node),
+                        created: response.created,
+                    }))
+                }
+                Err(e) => Err(api_error_status(e)),
             }
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        })
+        .await
     }
 
     async fn get_node(
         &self,
         request: tonic::Request<synton::GetNodeRequest>,
     ) -> Result<tonic::Response<synton::GetNodeResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let id = parse_uuid(&req.id)?;
-
-        let api_request = GetNodeRequest { id };
-        match self.inner.get_node(api_request).await {
-            Ok(response) => {
-                let proto_node = response.node.map(core_node_to_proto);
-                Ok(tonic::Response::new(synton::GetNodeResponse { node: proto_node }))
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/GetNode", async move {
+            let req = request.into_inner();
+            let id = parse_uuid(&req.id)?;
+
+            let api_request = GetNodeRequest { id };
+            match self.inner.get_node(api_request).await {
+                Ok(response) => {
+                    let proto_node = response.node.map(core_node_to_proto);
+                    Ok(tonic::Response::new(synton::GetNodeResponse { node: proto_node }))
+                }
+                Err(e) => Err(api_error_status(e)),
             }
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        })
+        .await
     }
 
     async fn delete_node(
         &self,
         request: tonic::Request<synton::DeleteNodeRequest>,
     ) -> Result<tonic::Response<synton::DeleteNodeResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let id = parse_uuid(&req.id)?;
-
-        let api_request = DeleteNodeRequest { id };
-        match self.inner.delete_node(api_request).await {
-            Ok(response) => Ok(tonic::Response::new(synton::DeleteNodeResponse {
-                deleted: response.deleted,
-                id: response.id.to_string(),
-            })),
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/DeleteNode", async move {
+            let req = request.into_inner();
+            let id = parse_uuid(&req.id)?;
+
+            let api_request = DeleteNodeRequest { id };
+            match self.inner.delete_node(api_request).await {
+                Ok(response) => Ok(tonic::Response::new(synton::DeleteNodeResponse {
+                    deleted: response.deleted,
+                    id: response.id.to_string(),
+                })),
+                Err(e) => Err(api_error_status(e)),
+            }
+        })
+        .await
     }
 
     async fn list_nodes(
         &self,
         request: tonic::Request<synton::ListNodesRequest>,
     ) -> Result<tonic::Response<synton::ListNodesResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let mut nodes = self.inner.all_nodes().await;
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/ListNodes", async move {
+            let req = request.into_inner();
+            let mut nodes = self.inner.all_nodes().await;
 
-        let total_count = nodes.len();
-        let offset = req.offset as usize;
-        let limit = req.limit as usize;
+            let total_count = nodes.len();
+            let offset = req.offset as usize;
+            let limit = req.limit as usize;
 
-        if offset < nodes.len() {
-            nodes = nodes.into_iter().skip(offset).take(limit).collect();
-        } else {
-            nodes.clear();
-        }
+            if offset < nodes.len() {
+                nodes = nodes.into_iter().skip(offset).take(limit).collect();
+            } else {
+                nodes.clear();
+            }
 
-        let proto_nodes: Vec<synton::Node> = nodes.into_iter().map(core_node_to_proto).collect();
+            let proto_nodes: Vec<synton::Node> = nodes.into_iter().map(core_node_to_proto).collect();
 
-        Ok(tonic::Response::new(synton::ListNodesResponse {
-            nodes: proto_nodes,
-            total_count: total_count as u32,
-        }))
+            Ok(tonic::Response::new(synton::ListNodesResponse {
+                nodes: proto_nodes,
+                total_count: total_count as u32,
+            }))
+        })
+        .await
     }
 
     async fn add_edge(
         &self,
         request: tonic::Request<synton::AddEdgeRequest>,
     ) -> Result<tonic::Response<synton::AddEdgeResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let relation = match req.relation {
-            1 => CoreRelation::IsA,           // IS_A
-            2 => CoreRelation::IsPartOf,       // PART_OF
-            3 => CoreRelation::Causes,         // CAUSES
-            4 => CoreRelation::SimilarTo,      // SIMILAR_TO
-            5 => CoreRelation::Contradicts,    // CONTRADICTS
-            6 => CoreRelation::HappenedAfter,  // HAPPENED_AFTER
-            7 => CoreRelation::BelongsTo,     // BELONGS_TO
-            _ => CoreRelation::SimilarTo,
-        };
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/AddEdge", async move {
+            let req = request.into_inner();
+            let relation = match req.relation {
+                1 => CoreRelation::IsA,           // IS_A
+                2 => CoreRelation::IsPartOf,       // PART_OF
+                3 => CoreRelation::Causes,         // CAUSES
+                4 => CoreRelation::SimilarTo,      // SIMILAR_TO
+                5 => CoreRelation::Contradicts,    // CONTRADICTS
+                6 => CoreRelation::HappenedAfter,  // HAPPENED_AFTER
+                7 => CoreRelation::BelongsTo,     // BELONGS_TO
+                _ => CoreRelation::SimilarTo,
+            };
 
-        let source = parse_uuid(&req.source)?;
-        let target = parse_uuid(&req.target)?;
+            let source = parse_uuid(&req.source)?;
+            let target = parse_uuid(&req.target)?;
 
-        let api_request = ApiAddEdgeRequest {
-            source,
-            target,
-            relation,
-            weight: req.weight,
-            vector: if req.vector.is_empty() {
-                None
-            } else {
-                Some(req.vector)
-            },
-        };
+            let api_request = ApiAddEdgeRequest {
+                source,
+                target,
+                relation,
+                weight: req.weight,
+                vector: if req.vector.is_empty() {
+                    None
+                } else {
+                    Some(req.vector)
+                },
+                provenance: req.provenance.map(proto_provenance_to_core).unwrap_or_default(),
+            };
+
+            check(&api_request).map_err(api_error_status)?;
 
-        match self.inner.add_edge(api_request).await {
-            Ok(response) => {
-                let proto_edge = core_edge_to_proto(response.edge);
-                Ok(tonic::Response::new(synton::AddEdgeResponse { edge: Some(proto_edge) }))
+            match self.inner.add_edge(api_request).await {
+                Ok(response) => {
+                    let proto_edge = core_edge_to_proto(response.edge);
+                    Ok(tonic::Response::new(synton::AddEdgeResponse { edge: Some(proto_edge) }))
+                }
+                Err(e) => Err(api_error_status(e)),
             }
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        })
+        .await
     }
 
     async fn get_edges(
         &self,
         request: tonic::Request<synton::GetEdgesRequest>,
     ) -> Result<tonic::Response<synton::GetEdgesResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let id = parse_uuid(&req.node_id)?;
-
-        // Get edges from the graph
-        let graph = self.inner.graph().await;
-        let graph_guard = graph.read().await;
-        let edges = match Graph::edges(&*graph_guard, id, GraphTraverseDirection::Forward).await {
-            Ok(edges) => edges,
-            Err(_) => return Ok(tonic::Response::new(synton::GetEdgesResponse { edges: vec![] })),
-        };
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/GetEdges", async move {
+            let req = request.into_inner();
+            let id = parse_uuid(&req.node_id)?;
+
+            // Get edges from the graph
+            let graph = self.inner.graph().await;
+            let graph_guard = graph.read().await;
+            let edges = match Graph::edges(&*graph_guard, id, GraphTraverseDirection::Forward).await {
+                Ok(edges) => edges,
+                Err(_) => return Ok(tonic::Response::new(synton::GetEdgesResponse { edges: vec![] })),
+            };
 
-        let proto_edges: Vec<synton::Edge> = edges.into_iter().map(core_edge_to_proto).collect();
-        Ok(tonic::Response::new(synton::GetEdgesResponse { edges: proto_edges }))
+            let proto_edges: Vec<synton::Edge> = edges.into_iter().map(core_edge_to_proto).collect();
+            Ok(tonic::Response::new(synton::GetEdgesResponse { edges: proto_edges }))
+        })
+        .await
     }
 
     async fn query(
         &self,
         request: tonic::Request<synton::QueryRequest>,
     ) -> Result<tonic::Response<synton::QueryResponse>, tonic::Status> {
-        let req = request.into_inner();
-
-        let api_request = ApiQueryRequest {
-            query: req.query,
-            limit: if req.limit == 0 { None } else { Some(req.limit as usize) },
-            include_metadata: req.include_metadata,
-        };
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/Query", async move {
+            let req = request.into_inner();
+
+            let api_request = ApiQueryRequest {
+                query: req.query,
+                limit: if req.limit == 0 { None } else { Some(req.limit as usize) },
+                include_metadata: req.include_metadata,
+                include_archived: req.include_archived,
+                count_only: req.count_only,
+                sample: if req.sample == 0 { None } else { Some(req.sample as usize) },
+                rank: if req.rank.is_empty() { None } else { Some(req.rank) },
+                min_confidence: if req.min_confidence > 0.0 { Some(req.min_confidence) } else { None },
+                recency_weight: if req.recency_weight > 0.0 { Some(req.recency_weight) } else { None },
+                namespace: None,
+            };
 
-        match self.inner.query(api_request).await {
-            Ok(response) => {
-                let nodes: Vec<synton::Node> = response.nodes.into_iter().map(core_node_to_proto).collect();
-                Ok(tonic::Response::new(synton::QueryResponse {
-                    nodes,
-                    total_count: response.total_count as u32,
-                    execution_time_ms: response.execution_time_ms,
-                    truncated: response.truncated,
-                }))
+            check(&api_request).map_err(api_error_status)?;
+
+            match self.inner.query(api_request).await {
+                Ok(response) => {
+                    let nodes: Vec<synton::Node> = response.nodes.into_iter().map(core_node_to_proto).collect();
+                    Ok(tonic::Response::new(synton::QueryResponse {
+                        nodes,
+                        total_count: response.total_count as u32,
+                        execution_time_ms: response.execution_time_ms,
+                        truncated: response.truncated,
+                        approximate: response.approximate,
+                    }))
+                }
+                Err(e) => Err(api_error_status(e)),
             }
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        })
+        .await
     }
 
     async fn traverse(
         &self,
         request: tonic::Request<synton::TraverseRequest>,
     ) -> Result<tonic::Response<synton::TraverseResponse>, tonic::Status> {
-        let req = request.into_inner();
-        let start_id = parse_uuid(&req.start_id)?;
-
-        let direction = match req.direction {
-            1 => crate::models::TraverseDirection::Forward,  // FORWARD
-            2 => crate::models::TraverseDirection::Backward, // BACKWARD
-            3 => crate::models::TraverseDirection::Both,     // BOTH
-            _ => crate::models::TraverseDirection::Forward,
-        };
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/Traverse", async move {
+            let req = request.into_inner();
+            let start_id = parse_uuid(&req.start_id)?;
+
+            let direction = match req.direction {
+                1 => crate::models::TraverseDirection::Backward, // BACKWARD
+                2 => crate::models::TraverseDirection::Both,     // BOTH
+                _ => crate::models::TraverseDirection::Forward,  // FORWARD
+            };
 
-        let api_request = ApiTraverseRequest {
-            start_id,
-            max_depth: req.max_depth as usize,
-            max_nodes: req.max_nodes as usize,
-            direction,
-        };
+            let api_request = ApiTraverseRequest {
+                start_id,
+                max_depth: req.max_depth as usize,
+                max_nodes: req.max_nodes as usize,
+                direction,
+                created_by: req
+                    .created_by
+                    .into_iter()
+                    .map(proto_creator_to_core)
+                    .collect(),
+                max_estimated_cost: if req.max_estimated_cost == 0 { None } else { Some(req.max_estimated_cost) },
+                follow_inverses: req.follow_inverses,
+                min_confidence: if req.min_confidence > 0.0 { Some(req.min_confidence) } else { None },
+                supernode_strategy: Default::default(),
+                namespace: None,
+            };
 
-        match self.inner.traverse(api_request).await {
-            Ok(response) => {
-                let nodes: Vec<synton::Node> = response.nodes.into_iter().map(core_node_to_proto).collect();
-                let edges: Vec<synton::Edge> = response.edges.into_iter().map(core_edge_to_proto).collect();
-                Ok(tonic::Response::new(synton::TraverseResponse {
-                    nodes,
-                    edges,
-                    depth: response.depth as u32,
-                    truncated: response.truncated,
-                }))
+            check(&api_request).map_err(api_error_status)?;
+
+            match self.inner.traverse(api_request).await {
+                Ok(response) => {
+                    let nodes: Vec<synton::Node> = response.nodes.into_iter().map(core_node_to_proto).collect();
+                    let edges: Vec<synton::Edge> = response.edges.into_iter().map(core_edge_to_proto).collect();
+                    Ok(tonic::Response::new(synton::TraverseResponse {
+                        nodes,
+                        edges,
+                        depth: response.depth as u32,
+                        truncated: response.truncated,
+                    }))
+                }
+                Err(e) => Err(api_error_status(e)),
             }
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        })
+        .await
+    }
+
+    async fn hybrid_search(
+        &self,
+        request: tonic::Request<synton::HybridSearchRequest>,
+    ) -> Result<tonic::Response<synton::HybridSearchResponse>, tonic::Status> {
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/HybridSearch", async move {
+            let req = request.into_inner();
+            let k = if req.k == 0 { 10 } else { req.k as usize };
+            check(&ApiHybridSearchRequest::new(req.query.clone(), k)).map_err(api_error_status)?;
+
+            match self.inner.hybrid_search(&req.query, k).await {
+                Ok(nodes) => {
+                    let query_id = self.inner.track_results(&nodes).await;
+                    let count = nodes.len() as u32;
+                    let nodes: Vec<synton::Node> = nodes.into_iter().map(core_node_to_proto).collect();
+                    Ok(tonic::Response::new(synton::HybridSearchResponse {
+                        nodes,
+                        count,
+                        query_id: query_id.to_string(),
+                    }))
+                }
+                Err(e) => Err(api_error_status(e)),
+            }
+        })
+        .await
+    }
+
+    async fn ingest_document(
+        &self,
+        request: tonic::Request<synton::IngestDocumentRequest>,
+    ) -> Result<tonic::Response<synton::IngestDocumentResponse>, tonic::Status> {
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/IngestDocument", async move {
+            let req = request.into_inner();
+
+            let api_request = ApiIngestDocumentRequest {
+                title: if req.title.is_empty() { None } else { Some(req.title) },
+                content: req.content,
+                chunking: None,
+                embed: !req.skip_embedding,
+                metadata: if req.metadata.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::to_value(req.metadata).unwrap_or_default())
+                },
+                namespace: None,
+                summarize: false,
+                durability: Durability::default(),
+            };
+
+            check(&api_request).map_err(api_error_status)?;
+
+            match self.inner.ingest_document(api_request).await {
+                Ok(response) => Ok(tonic::Response::new(synton::IngestDocumentResponse {
+                    document_id: response.document_id.to_string(),
+                    chunk_count: response.chunk_count as u32,
+                    chunk_ids: response.chunks.iter().map(|c| c.id.to_string()).collect(),
+                    embedded: response.embedded,
+                    processing_time_ms: response.processing_time_ms,
+                })),
+                Err(e) => Err(api_error_status(e)),
+            }
+        })
+        .await
     }
 
     async fn stats(
         &self,
-        _request: tonic::Request<synton::StatsRequest>,
+        request: tonic::Request<synton::StatsRequest>,
     ) -> Result<tonic::Response<synton::StatsResponse>, tonic::Status> {
-        match self.inner.stats().await {
-            Ok(stats) => Ok(tonic::Response::new(synton::StatsResponse {
-                node_count: stats.node_count as u32,
-                edge_count: stats.edge_count as u32,
-                embedded_count: stats.embedded_count as u32,
-                memory_stats: Some(synton::MemoryStats {
-                    total_nodes: stats.memory_stats.total_nodes as u32,
-                    active_nodes: stats.memory_stats.active_nodes as u32,
-                    decayed_nodes: stats.memory_stats.decayed_nodes as u32,
-                    average_score: stats.memory_stats.average_score,
-                    load_factor: stats.memory_stats.load_factor,
-                }),
-            })),
-            Err(e) => Err(tonic::Status::internal(e.to_string())),
-        }
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/Stats", async move {
+            match self.inner.stats().await {
+                Ok(stats) => Ok(tonic::Response::new(synton::StatsResponse {
+                    node_count: stats.node_count as u32,
+                    edge_count: stats.edge_count as u32,
+                    embedded_count: stats.embedded_count as u32,
+                    memory_stats: Some(synton::MemoryStats {
+                        total_nodes: stats.memory_stats.total_nodes as u32,
+                        active_nodes: stats.memory_stats.active_nodes as u32,
+                        decayed_nodes: stats.memory_stats.decayed_nodes as u32,
+                        average_score: stats.memory_stats.average_score,
+                        load_factor: stats.memory_stats.load_factor,
+                    }),
+                })),
+                Err(e) => Err(api_error_status(e)),
+            }
+        })
+        .await
     }
 
     async fn bulk_operation(
         &self,
         request: tonic::Request<synton::BulkOperationRequest>,
     ) -> Result<tonic::Response<synton::BulkOperationResponse>, tonic::Status> {
-        let req = request.into_inner();
-
-        let mut node_requests = Vec::new();
-        for node_req in req.nodes {
-            let node_type = match node_req.node_type {
-                1 => CoreNodeType::Entity,   // NODE_TYPE_ENTITY
-                2 => CoreNodeType::Concept,  // NODE_TYPE_CONCEPT
-                3 => CoreNodeType::Fact,     // NODE_TYPE_FACT
-                4 => CoreNodeType::RawChunk, // NODE_TYPE_RAW_CHUNK
-                _ => CoreNodeType::Concept,
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/BulkOperation", async move {
+            let req = request.into_inner();
+
+            let node_requests = req.nodes.into_iter().map(bulk_node_to_api).collect();
+            let edge_requests = req
+                .edges
+                .into_iter()
+                .filter_map(|edge_req| bulk_edge_to_api(edge_req).ok())
+                .collect();
+
+            let bulk_request = crate::models::BulkOperationRequest {
+                nodes: node_requests,
+                edges: edge_requests,
+                durability: Durability::default(),
             };
-            node_requests.push(ApiAddNodeRequest {
-                content: node_req.content,
-                node_type,
-                embedding: if node_req.embedding.is_empty() {
-                    None
-                } else {
-                    Some(node_req.embedding)
-                },
-                attributes: if node_req.attributes.is_empty() {
-                    None
-                } else {
-                    Some(serde_json::to_value(node_req.attributes).unwrap_or_default())
-                },
-            });
-        }
 
-        let mut edge_requests = Vec::new();
-        for edge_req in req.edges {
-            let relation = match edge_req.relation {
-                1 => CoreRelation::IsA,           // IS_A
-                2 => CoreRelation::IsPartOf,       // PART_OF
-                3 => CoreRelation::Causes,         // CAUSES
-                4 => CoreRelation::SimilarTo,      // SIMILAR_TO
-                5 => CoreRelation::Contradicts,    // CONTRADICTS
-                6 => CoreRelation::HappenedAfter,  // HAPPENED_AFTER
-                7 => CoreRelation::BelongsTo,     // BELONGS_TO
-                _ => CoreRelation::SimilarTo,
-            };
-            let source = match parse_uuid(&edge_req.source) {
-                Ok(id) => id,
-                Err(_) => continue,
-            };
-            let target = match parse_uuid(&edge_req.target) {
-                Ok(id) => id,
-                Err(_) => continue,
-            };
-            edge_requests.push(ApiAddEdgeRequest {
-                source,
-                target,
-                relation,
-                weight: edge_req.weight,
-                vector: None,
-            });
-        }
-
-        let bulk_request = crate::models::BulkOperationRequest {
-            nodes: node_requests,
-            edges: edge_requests,
-        };
+            // Process bulk operation
+            let mut node_ids = Vec::new();
+            let mut edge_ids = Vec::new();
+            let mut success_count = 0;
+            let mut failure_count = 0;
+            let mut errors = Vec::new();
+
+            for node_req in bulk_request.nodes {
+                match self.inner.add_node(node_req).await {
+                    Ok(resp) => {
+                        node_ids.push(resp.node.id.to_string());
+                        success_count += 1;
+                    }
+                    Err(e) => {
+                        errors.push(format!("Node creation failed: {}", e));
+                        failure_count += 1;
+                    }
+                }
+            }
 
-        // Process bulk operation
-        let mut node_ids = Vec::new();
-        let mut edge_ids = Vec::new();
-        let mut success_count = 0;
-        let mut failure_count = 0;
-        let mut errors = Vec::new();
-
-        for node_req in bulk_request.nodes {
-            match self.inner.add_node(node_req).await {
-                Ok(resp) => {
-                    node_ids.push(resp.node.id.to_string());
-                    success_count += 1;
+            for edge_req in bulk_request.edges {
+                match self.inner.add_edge(edge_req).await {
+                    Ok(resp) => {
+                        edge_ids.push(resp.edge.id());
+                        success_count += 1;
+                    }
+                    Err(e) => {
+                        errors.push(format!("Edge creation failed: {}", e));
+                        failure_count += 1;
+                    }
                 }
-                Err(e) => {
-                    errors.push(format!("Node creation failed: {}", e));
-                    failure_count += 1;
+            }
+
+            Ok(tonic::Response::new(synton::BulkOperationResponse {
+                node_ids,
+                edge_ids,
+                success_count: success_count as u32,
+                failure_count: failure_count as u32,
+                errors,
+            }))
+        })
+        .await
+    }
+
+    type StreamIngestStream = ReceiverStream<Result<synton::IngestAck, tonic::Status>>;
+
+    async fn stream_ingest(
+        &self,
+        request: tonic::Request<tonic::Streaming<synton::IngestRecord>>,
+    ) -> Result<tonic::Response<Self::StreamIngestStream>, tonic::Status> {
+        let context = trace_context_from_metadata(request.metadata());
+        traced(context, "SyntonDb/StreamIngest", async move {
+            let mut stream = request.into_inner();
+            let (record_tx, record_rx) = mpsc::channel(STREAM_INGEST_BUFFER);
+            let (ack_tx, ack_rx) = mpsc::channel(STREAM_INGEST_BUFFER);
+
+            // Read ahead while the current batch is written
+            tokio::spawn(async move {
+                while let Some(record) = stream.message().await.transpose() {
+                    let failed = record.is_err();
+                    if record_tx.send(record).await.is_err() || failed {
+                        break;
+                    }
                 }
+            });
+            tokio::spawn(ingest_stream(self.inner.clone(), record_rx, ack_tx));
+
+            Ok(tonic::Response::new(ReceiverStream::new(ack_rx)))
+        })
+        .await
+    }
+}
+
+/// Write streamed records in batches, acknowledging each in order.
+///
+/// A batch is whatever has been read ahead when the previous one is done,
+/// up to [`STREAM_INGEST_BATCH`] records. Stops at the end of the stream,
+/// after passing on a stream error, or when the client stops listening.
+async fn ingest_stream(
+    service: Arc<SyntonDbService>,
+    mut records: mpsc::Receiver<Result<synton::IngestRecord, tonic::Status>>,
+    acks: mpsc::Sender<Result<synton::IngestAck, tonic::Status>>,
+) {
+    while let Some(first) = records.recv().await {
+        let mut batch = vec![first];
+        while batch.len() < STREAM_INGEST_BATCH {
+            match records.try_recv() {
+                Ok(record) => batch.push(record),
+                Err(_) => break,
             }
         }
 
-        for edge_req in bulk_request.edges {
-            match self.inner.add_edge(edge_req).await {
-                Ok(resp) => {
-                    edge_ids.push(resp.edge.id());
-                    success_count += 1;
+        let mut failure = None;
+        let mut sequences = Vec::with_capacity(batch.len());
+        let mut rejected = Vec::with_capacity(batch.len());
+        let mut valid = Vec::new();
+        for record in batch {
+            let record = match record {
+                Ok(record) => record,
+                Err(status) => {
+                    failure = Some(status);
+                    break;
                 }
-                Err(e) => {
-                    errors.push(format!("Edge creation failed: {}", e));
-                    failure_count += 1;
+            };
+            sequences.push(record.sequence);
+            match proto_record_to_api(record.record) {
+                Ok(record) => {
+                    valid.push(record);
+                    rejected.push(None);
                 }
+                Err(status) => rejected.push(Some(status.message().to_string())),
             }
         }
 
-        Ok(tonic::Response::new(synton::BulkOperationResponse {
-            node_ids,
-            edge_ids,
-            success_count: success_count as u32,
-            failure_count: failure_count as u32,
-            errors,
-        }))
+        let mut written = service.ingest_records(valid).await.into_iter();
+        for (sequence, rejection) in sequences.into_iter().zip(rejected) {
+            let outcome = match rejection {
+                Some(error) => Err(error),
+                None => written
+                    .next()
+                    .expect("an outcome per ingested record")
+                    .map_err(|e| e.to_string()),
+            };
+            let ack = match outcome {
+                Ok(id) => synton::IngestAck {
+                    sequence,
+                    id,
+                    error: String::new(),
+                },
+                Err(error) => synton::IngestAck {
+                    sequence,
+                    id: String::new(),
+                    error,
+                },
+            };
+            if acks.send(Ok(ack)).await.is_err() {
+                return;
+            }
+        }
+
+        if let Some(status) = failure {
+            let _ = acks.send(Err(status)).await;
+            return;
+        }
     }
 }
 
 /// Parse UUID from string.
+/// Build a trace context from `traceparent` / `x-trace-id` request metadata.
+fn trace_context_from_metadata(metadata: &tonic::metadata::MetadataMap) -> TraceContext {
+    let value = |key: &str| metadata.get(key).and_then(|v| v.to_str().ok());
+    TraceContext::from_headers(value(TRACEPARENT_HEADER), value(TRACE_ID_HEADER))
+}
+
+/// Run a gRPC handler inside a trace context.
+///
+/// Records a root span for the call and returns the trace ID in the
+/// `x-trace-id` and `traceparent` response metadata.
+async fn traced<T, F>(
+    context: TraceContext,
+    name: &str,
+    handler: F,
+) -> Result<tonic::Response<T>, tonic::Status>
+where
+    F: Future<Output = Result<tonic::Response<T>, tonic::Status>>,
+{
+    let collector = TraceCollector::global();
+    let context = collector.start_request(context, name);
+    let start = std::time::Instant::now();
+
+    let result = context.scope(handler).await;
+
+    let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+    if let Some(span_id) = context.span_id {
+        match &result {
+            Ok(_) => collector.complete_span(span_id, None, duration_ms),
+            Err(status) => collector.fail_span(span_id, status.message().to_string(), duration_ms),
+        }
+    }
+
+    result.map(|mut response| {
+        let metadata = response.metadata_mut();
+        if let Ok(value) = context.trace_id.to_string().parse() {
+            metadata.insert(TRACE_ID_HEADER, value);
+        }
+        if let Ok(value) = context.to_traceparent().parse() {
+            metadata.insert(TRACEPARENT_HEADER, value);
+        }
+        response
+    })
+}
+
+/// Map an API error to the gRPC status matching its error code.
+fn api_error_status(e: ApiError) -> tonic::Status {
+    let message = e.to_string();
+    match e.code() {
+        ErrorCode::NotFound => tonic::Status::not_found(message),
+        ErrorCode::InvalidInput => tonic::Status::invalid_argument(message),
+        ErrorCode::Conflict => tonic::Status::already_exists(message),
+        ErrorCode::LimitExceeded => tonic::Status::failed_precondition(message),
+        ErrorCode::Overloaded => tonic::Status::resource_exhausted(message),
+        ErrorCode::Unavailable => tonic::Status::unavailable(message),
+        ErrorCode::NotImplemented => tonic::Status::unimplemented(message),
+        ErrorCode::Storage | ErrorCode::Serialization | ErrorCode::Internal => {
+            tonic::Status::internal(message)
+        }
+    }
+}
+
 fn parse_uuid(s: &str) -> Result<Uuid, tonic::Status> {
     Uuid::parse_str(s).map_err(|_| tonic::Status::invalid_argument("Invalid UUID format"))
 }
@@ -428,6 +702,104 @@ fn core_node_to_proto(node: CoreNode) -> synton::Node {
                     .collect()
             })
             .unwrap_or_default(),
+        tags: node.tags.into_iter().collect(),
+        version: node.meta.version,
+    }
+}
+
+/// Convert a proto node type to the core type.
+fn proto_node_type_to_core(node_type: i32) -> CoreNodeType {
+    match node_type {
+        1 => CoreNodeType::Entity,   // NODE_TYPE_ENTITY
+        2 => CoreNodeType::Concept,  // NODE_TYPE_CONCEPT
+        3 => CoreNodeType::Fact,     // NODE_TYPE_FACT
+        4 => CoreNodeType::RawChunk, // NODE_TYPE_RAW_CHUNK
+        _ => CoreNodeType::Concept,
+    }
+}
+
+/// Convert a proto relation to the core type.
+fn proto_relation_to_core(relation: i32) -> CoreRelation {
+    match relation {
+        1 => CoreRelation::IsA,           // IS_A
+        2 => CoreRelation::IsPartOf,      // PART_OF
+        3 => CoreRelation::Causes,        // CAUSES
+        4 => CoreRelation::SimilarTo,     // SIMILAR_TO
+        5 => CoreRelation::Contradicts,   // CONTRADICTS
+        6 => CoreRelation::HappenedAfter, // HAPPENED_AFTER
+        7 => CoreRelation::BelongsTo,     // BELONGS_TO
+        _ => CoreRelation::SimilarTo,
+    }
+}
+
+/// Convert a bulk or streamed node to an add node request.
+fn bulk_node_to_api(node: synton::BulkNodeRequest) -> ApiAddNodeRequest {
+    ApiAddNodeRequest {
+        content: node.content,
+        node_type: proto_node_type_to_core(node.node_type),
+        embedding: if node.embedding.is_empty() {
+            None
+        } else {
+            Some(node.embedding)
+        },
+        attributes: if node.attributes.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_value(node.attributes).unwrap_or_default())
+        },
+        external_id: None,
+        tags: Vec::new(),
+    }
+}
+
+/// Convert a bulk or streamed edge to an add edge request.
+fn bulk_edge_to_api(edge: synton::BulkEdgeRequest) -> Result<ApiAddEdgeRequest, tonic::Status> {
+    Ok(ApiAddEdgeRequest {
+        source: parse_uuid(&edge.source)?,
+        target: parse_uuid(&edge.target)?,
+        relation: proto_relation_to_core(edge.relation),
+        weight: edge.weight,
+        vector: None,
+        provenance: edge.provenance.map(proto_provenance_to_core).unwrap_or_default(),
+    })
+}
+
+/// Convert a streamed record, checking it like the unary calls do.
+fn proto_record_to_api(
+    record: Option<synton::ingest_record::Record>,
+) -> Result<IngestRecord, tonic::Status> {
+    match record {
+        Some(synton::ingest_record::Record::Node(node)) => {
+            let request = bulk_node_to_api(node);
+            check(&request).map_err(api_error_status)?;
+            Ok(IngestRecord::Node(request))
+        }
+        Some(synton::ingest_record::Record::Edge(edge)) => {
+            let request = bulk_edge_to_api(edge)?;
+            check(&request).map_err(api_error_status)?;
+            Ok(IngestRecord::Edge(request))
+        }
+        None => Err(tonic::Status::invalid_argument(
+            "Record has neither a node nor an edge",
+        )),
+    }
+}
+
+/// Convert a proto edge creator to the core type.
+fn proto_creator_to_core(creator: i32) -> CoreEdgeCreator {
+    match creator {
+        1 => CoreEdgeCreator::Tool,               // TOOL
+        2 => CoreEdgeCreator::ExtractionPipeline, // EXTRACTION_PIPELINE
+        _ => CoreEdgeCreator::User,
+    }
+}
+
+/// Convert proto edge provenance to the core type.
+fn proto_provenance_to_core(provenance: synton::EdgeProvenance) -> CoreEdgeProvenance {
+    CoreEdgeProvenance {
+        created_by: proto_creator_to_core(provenance.created_by),
+        source_document: Uuid::parse_str(&provenance.source_document).ok(),
+        confidence: provenance.confidence,
     }
 }
 
@@ -461,6 +833,22 @@ fn core_edge_to_proto(edge: CoreEdge) -> synton::Edge {
                     .collect()
             })
             .unwrap_or_default(),
+        provenance: Some(synton::EdgeProvenance {
+            created_by: match edge.provenance.created_by {
+                CoreEdgeCreator::User => synton::EdgeCreator::User as i32,
+                CoreEdgeCreator::Tool => synton::EdgeCreator::Tool as i32,
+                CoreEdgeCreator::ExtractionPipeline => {
+                    synton::EdgeCreator::ExtractionPipeline as i32
+                }
+            },
+            source_document: edge
+                .provenance
+                .source_document
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            confidence: edge.provenance.confidence,
+        }),
+        pending_review: edge.pending_review,
     }
 }
 
@@ -470,6 +858,16 @@ pub fn create_grpc_router(service: Arc<SyntonDbService>) -> SyntonDbServer<GrpcS
     SyntonDbServer::new(grpc_service)
 }
 
+/// Create a tonic server builder applying the gRPC concurrency limits.
+pub fn grpc_server_builder(limits: &ConcurrencyLimits) -> tonic::transport::Server {
+    let builder = tonic::transport::Server::builder();
+    if limits.grpc_concurrency_per_connection > 0 {
+        builder.concurrency_limit_per_connection(limits.grpc_concurrency_per_connection)
+    } else {
+        builder
+    }
+}
+
 /// Start the gRPC server.
 pub async fn run_grpc_server(
     addr: impl tokio::net::ToSocketAddrs,
@@ -480,7 +878,7 @@ pub async fn run_grpc_server(
     let listener = tokio::net::TcpListener::bind(addr).await?;
     tracing::info!("gRPC server listening on {}", listener.local_addr()?);
 
-    tonic::transport::Server::builder()
+    grpc_server_builder(&ConcurrencyLimits::default())
         .add_service(SyntonDbServer::new(grpc_service))
         .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
         .await?;
@@ -504,4 +902,106 @@ mod tests {
         let result = parse_uuid("invalid");
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_request_validation() {
+        let service = GrpcService::new(Arc::new(SyntonDbService::new()));
+
+        let request = tonic::Request::new(synton::TraverseRequest {
+            start_id: Uuid::new_v4().to_string(),
+            max_depth: 9,
+            max_nodes: 10,
+            ..Default::default()
+        });
+        let status = service.traverse(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("max_depth: must be between 1 and 5"));
+
+        let request = tonic::Request::new(synton::AddNodeRequest {
+            content: "  ".to_string(),
+            ..Default::default()
+        });
+        let status = service.add_node(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("content"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_ingest() {
+        let service = Arc::new(SyntonDbService::new());
+        let shortage = service
+            .add_node(ApiAddNodeRequest::new("Supply shortage".to_string(), CoreNodeType::Fact))
+            .await
+            .unwrap()
+            .node;
+        let node = |sequence, content: &str| synton::IngestRecord {
+            sequence,
+            record: Some(synton::ingest_record::Record::Node(synton::BulkNodeRequest {
+                content: content.to_string(),
+                node_type: synton::NodeType::Fact as i32,
+                ..Default::default()
+            })),
+        };
+        let edge = |sequence, target: &str| synton::IngestRecord {
+            sequence,
+            record: Some(synton::ingest_record::Record::Edge(synton::BulkEdgeRequest {
+                source: shortage.id.to_string(),
+                target: target.to_string(),
+                relation: synton::Relation::Causes as i32,
+                weight: 1.0,
+                ..Default::default()
+            })),
+        };
+
+        let (record_tx, record_rx) = mpsc::channel(8);
+        let (ack_tx, mut ack_rx) = mpsc::channel(8);
+        tokio::spawn(ingest_stream(service.clone(), record_rx, ack_tx));
+
+        record_tx.send(Ok(node(1, "Production delay"))).await.unwrap();
+        let ack = ack_rx.recv().await.unwrap().unwrap();
+        assert_eq!((ack.sequence, ack.error.as_str()), (1, ""));
+        let delay = ack.id;
+
+        for record in [
+            node(2, "  "),
+            edge(3, &delay),
+            edge(4, &Uuid::new_v4().to_string()),
+            edge(5, "invalid"),
+        ] {
+            record_tx.send(Ok(record)).await.unwrap();
+        }
+        record_tx.send(Err(tonic::Status::aborted("reset"))).await.unwrap();
+
+        let mut acks = Vec::new();
+        while let Some(Ok(ack)) = ack_rx.recv().await {
+            acks.push(ack);
+        }
+        let sequences: Vec<u64> = acks.iter().map(|ack| ack.sequence).collect();
+        assert_eq!(sequences, vec![2, 3, 4, 5]);
+        assert!(acks[0].error.contains("content"));
+        assert!(acks[1].error.is_empty() && !acks[1].id.is_empty());
+        assert!(acks[2].error.contains("not found"));
+        assert!(acks[3].error.contains("Invalid UUID"));
+        // The stream error ended the ingestion
+        assert!(ack_rx.recv().await.is_none());
+
+        let stats = service.stats().await.unwrap();
+        assert_eq!((stats.node_count, stats.edge_count), (2, 1));
+    }
+
+    #[tokio::test]
+    async fn test_trace_id_propagation() {
+        let service = GrpcService::new(Arc::new(SyntonDbService::new()));
+        let trace_id = Uuid::new_v4();
+
+        let mut request = tonic::Request::new(synton::HealthRequest {});
+        request
+            .metadata_mut()
+            .insert(TRACE_ID_HEADER, trace_id.to_string().parse().unwrap());
+
+        let response = service.health(request).await.unwrap();
+        let echoed = response.metadata().get(TRACE_ID_HEADER).unwrap();
+        assert_eq!(echoed.to_str().unwrap(), trace_id.to_string());
+        assert!(TraceCollector::global().resolve_root(trace_id).is_some());
+    }
 }
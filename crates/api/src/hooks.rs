@@ -0,0 +1,423 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Mutation event hooks.
+//!
+//! Plugins implement [`MutationHook`] and register with the service's
+//! [`HookRegistry`]. Each event is delivered to every interested hook on its
+//! own background task, so a slow or failing hook never delays the write that
+//! produced the event. [`WebhookHook`] forwards events to an HTTP endpoint.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use synton_core::{Edge, Node};
+
+use crate::{models::ExpiryDigest, ApiError, ApiResult};
+
+/// Kind of mutation event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MutationEventKind {
+    /// A node was created
+    NodeAdded,
+    /// A node was updated in place
+    NodeUpdated,
+    /// An edge was created
+    EdgeAdded,
+    /// A document was chunked and stored
+    DocumentIngested,
+    /// A scheduled digest of nodes about to decay was compiled
+    ExpiryDigest,
+}
+
+impl fmt::Display for MutationEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NodeAdded => write!(f, "node_added"),
+            Self::NodeUpdated => write!(f, "node_updated"),
+            Self::EdgeAdded => write!(f, "edge_added"),
+            Self::DocumentIngested => write!(f, "document_ingested"),
+            Self::ExpiryDigest => write!(f, "expiry_digest"),
+        }
+    }
+}
+
+impl FromStr for MutationEventKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "node_added" => Ok(Self::NodeAdded),
+            "node_updated" => Ok(Self::NodeUpdated),
+            "edge_added" => Ok(Self::EdgeAdded),
+            "document_ingested" => Ok(Self::DocumentIngested),
+            "expiry_digest" => Ok(Self::ExpiryDigest),
+            _ => Err(format!("Unknown event kind: {}", s)),
+        }
+    }
+}
+
+/// A mutation applied to the database.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum MutationEvent {
+    /// A node was created.
+    NodeAdded {
+        /// The new node
+        node: Node,
+    },
+    /// A node was updated in place.
+    NodeUpdated {
+        /// The node after the update
+        node: Node,
+    },
+    /// An edge was created.
+    EdgeAdded {
+        /// The new edge
+        edge: Edge,
+    },
+    /// A document was chunked and stored.
+    DocumentIngested {
+        /// ID of the document node
+        document_id: Uuid,
+        /// Document title
+        title: String,
+        /// IDs of the stored chunk nodes, in document order
+        chunk_ids: Vec<Uuid>,
+    },
+    /// A scheduled digest of nodes about to decay was compiled.
+    ExpiryDigest {
+        /// The digest
+        digest: ExpiryDigest,
+    },
+}
+
+impl MutationEvent {
+    /// Kind of this event.
+    pub fn kind(&self) -> MutationEventKind {
+        match self {
+            Self::NodeAdded { .. } => MutationEventKind::NodeAdded,
+            Self::NodeUpdated { .. } => MutationEventKind::NodeUpdated,
+            Self::EdgeAdded { .. } => MutationEventKind::EdgeAdded,
+            Self::DocumentIngested { .. } => MutationEventKind::DocumentIngested,
+            Self::ExpiryDigest { .. } => MutationEventKind::ExpiryDigest,
+        }
+    }
+}
+
+/// Error returned by a hook.
+pub type HookError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Async callback invoked after mutations.
+#[async_trait]
+pub trait MutationHook: Send + Sync {
+    /// Unique name of the hook, used for logging and unregistering.
+    fn name(&self) -> &str;
+
+    /// Whether the hook wants events of the given kind (all by default).
+    fn handles(&self, _kind: MutationEventKind) -> bool {
+        true
+    }
+
+    /// Handle an event. Errors are logged; the mutation is not affected.
+    async fn on_event(&self, event: &MutationEvent) -> Result<(), HookError>;
+}
+
+/// Registry of mutation hooks.
+#[derive(Default)]
+pub struct HookRegistry {
+    hooks: RwLock<Vec<Arc<dyn MutationHook>>>,
+    /// Deliveries not yet finished, retries included.
+    pending: Arc<AtomicUsize>,
+}
+
+impl HookRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hook, replacing any hook with the same name.
+    pub fn register(&self, hook: Arc<dyn MutationHook>) {
+        let mut hooks = self.hooks.write().unwrap_or_else(|e| e.into_inner());
+        hooks.retain(|h| h.name() != hook.name());
+        hooks.push(hook);
+    }
+
+    /// Remove a hook by name. Returns whether it was registered.
+    pub fn unregister(&self, name: &str) -> bool {
+        let mut hooks = self.hooks.write().unwrap_or_else(|e| e.into_inner());
+        let before = hooks.len();
+        hooks.retain(|h| h.name() != name);
+        hooks.len() != before
+    }
+
+    /// Names of the registered hooks, in registration order.
+    pub fn names(&self) -> Vec<String> {
+        let hooks = self.hooks.read().unwrap_or_else(|e| e.into_inner());
+        hooks.iter().map(|h| h.name().to_string()).collect()
+    }
+
+    /// Check if no hooks are registered.
+    pub fn is_empty(&self) -> bool {
+        self.hooks.read().unwrap_or_else(|e| e.into_inner()).is_empty()
+    }
+
+    /// Number of event deliveries not yet finished.
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::Relaxed)
+    }
+
+    /// Deliver an event to every interested hook on background tasks.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn emit(&self, event: MutationEvent) {
+        let kind = event.kind();
+        let targets: Vec<_> = {
+            let hooks = self.hooks.read().unwrap_or_else(|e| e.into_inner());
+            hooks.iter().filter(|h| h.handles(kind)).cloned().collect()
+        };
+        if targets.is_empty() {
+            return;
+        }
+
+        let event = Arc::new(event);
+        for hook in targets {
+            let event = event.clone();
+            let pending = Arc::clone(&self.pending);
+            pending.fetch_add(1, Ordering::Relaxed);
+            tokio::spawn(async move {
+                if let Err(e) = hook.on_event(&event).await {
+                    tracing::warn!("Hook '{}' failed on {}: {}", hook.name(), kind, e);
+                }
+                pending.fetch_sub(1, Ordering::Relaxed);
+            });
+        }
+    }
+}
+
+impl fmt::Debug for HookRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HookRegistry")
+            .field("hooks", &self.names())
+            .finish()
+    }
+}
+
+/// Webhook delivery settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookConfig {
+    /// Endpoint receiving a JSON POST per event.
+    pub url: String,
+
+    /// Event kinds to deliver (empty delivers all).
+    pub events: Vec<MutationEventKind>,
+
+    /// Retries after the first failed attempt.
+    pub max_retries: u32,
+
+    /// Delay before the first retry; doubles on each further retry.
+    pub retry_backoff_ms: u64,
+
+    /// Timeout of a single delivery attempt.
+    pub timeout_ms: u64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            events: Vec::new(),
+            max_retries: 3,
+            retry_backoff_ms: 500,
+            timeout_ms: 5000,
+        }
+    }
+}
+
+/// Body of a webhook delivery.
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    timestamp: DateTime<Utc>,
+    #[serde(flatten)]
+    event: &'a MutationEvent,
+}
+
+/// Hook posting events as JSON to an HTTP endpoint.
+///
+/// Network errors, 5xx and 429 responses are retried with exponential
+/// backoff; other 4xx responses fail immediately.
+pub struct WebhookHook {
+    name: String,
+    config: WebhookConfig,
+    client: reqwest::Client,
+}
+
+impl WebhookHook {
+    /// Create a webhook hook.
+    pub fn new(config: WebhookConfig) -> ApiResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .map_err(|e| ApiError::Internal(e.to_string()))?;
+
+        Ok(Self {
+            name: format!("webhook:{}", config.url),
+            config,
+            client,
+        })
+    }
+
+    /// Delivery settings.
+    pub fn config(&self) -> &WebhookConfig {
+        &self.config
+    }
+}
+
+#[async_trait]
+impl MutationHook for WebhookHook {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn handles(&self, kind: MutationEventKind) -> bool {
+        self.config.events.is_empty() || self.config.events.contains(&kind)
+    }
+
+    async fn on_event(&self, event: &MutationEvent) -> Result<(), HookError> {
+        let payload = WebhookPayload {
+            timestamp: Utc::now(),
+            event,
+        };
+
+        let mut attempt = 0;
+        loop {
+            let error = match self.client.post(&self.config.url).json(&payload).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_client_error() && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+                        return Err(format!("webhook rejected event: HTTP {}", status).into());
+                    }
+                    format!("HTTP {}", status)
+                }
+                Err(e) => e.to_string(),
+            };
+
+            if attempt >= self.config.max_retries {
+                return Err(format!("webhook failed after {} attempts: {}", attempt + 1, error).into());
+            }
+            let backoff = self.config.retry_backoff_ms.saturating_mul(1 << attempt.min(16));
+            tokio::time::sleep(Duration::from_millis(backoff)).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+    use tokio::sync::mpsc;
+
+    struct ChannelHook {
+        name: String,
+        kinds: Vec<MutationEventKind>,
+        tx: mpsc::UnboundedSender<MutationEventKind>,
+    }
+
+    #[async_trait]
+    impl MutationHook for ChannelHook {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn handles(&self, kind: MutationEventKind) -> bool {
+            self.kinds.contains(&kind)
+        }
+
+        async fn on_event(&self, event: &MutationEvent) -> Result<(), HookError> {
+            self.tx.send(event.kind())?;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registry_filters_by_kind() {
+        let registry = HookRegistry::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        registry.register(Arc::new(ChannelHook {
+            name: "edges".to_string(),
+            kinds: vec![MutationEventKind::EdgeAdded],
+            tx,
+        }));
+
+        registry.emit(MutationEvent::NodeAdded {
+            node: Node::new("ignored", NodeType::Entity),
+        });
+        registry.emit(MutationEvent::EdgeAdded {
+            edge: Edge::new(Uuid::new_v4(), Uuid::new_v4(), synton_core::Relation::Causes),
+        });
+
+        assert_eq!(rx.recv().await, Some(MutationEventKind::EdgeAdded));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_register_replaces_and_unregisters_by_name() {
+        let registry = HookRegistry::new();
+        let (tx, _rx) = mpsc::unbounded_channel();
+        for _ in 0..2 {
+            registry.register(Arc::new(ChannelHook {
+                name: "audit".to_string(),
+                kinds: Vec::new(),
+                tx: tx.clone(),
+            }));
+        }
+
+        assert_eq!(registry.names(), vec!["audit".to_string()]);
+        assert!(registry.unregister("audit"));
+        assert!(!registry.unregister("audit"));
+        assert!(registry.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_gives_up_after_retries() {
+        let hook = WebhookHook::new(WebhookConfig {
+            url: "http://127.0.0.1:9/hook".to_string(),
+            events: vec![MutationEventKind::DocumentIngested],
+            max_retries: 2,
+            retry_backoff_ms: 1,
+            timeout_ms: 200,
+        })
+        .unwrap();
+        assert!(!hook.handles(MutationEventKind::NodeAdded));
+
+        let event = MutationEvent::DocumentIngested {
+            document_id: Uuid::new_v4(),
+            title: "Notes".to_string(),
+            chunk_ids: Vec::new(),
+        };
+        let error = hook.on_event(&event).await.unwrap_err();
+        assert!(error.to_string().contains("after 3 attempts"));
+
+        let payload = serde_json::to_value(WebhookPayload {
+            timestamp: Utc::now(),
+            event: &event,
+        })
+        .unwrap();
+        assert_eq!(payload["event"], "document_ingested");
+        assert_eq!(payload["title"], "Notes");
+    }
+}
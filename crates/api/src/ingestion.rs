@@ -0,0 +1,378 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Declarative ingestion pipelines.
+//!
+//! A pipeline names a source of documents (URLs, files or a directory), the
+//! chunking strategy, enrichment steps applied to every document, whether
+//! chunks are embedded and the namespace they land in. Pipelines are
+//! defined in the server configuration and run on demand; each run ingests
+//! every document of the source and is kept in a bounded history.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+use synton_storage::Durability;
+
+use crate::models::{ChunkingStrategy, PipelineRun};
+
+/// Runs kept per pipeline; older runs are dropped.
+const MAX_RUN_HISTORY: usize = 50;
+
+/// A named ingestion pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineDefinition {
+    /// Unique name, used in `/pipelines/{name}/run`.
+    pub name: String,
+
+    /// Where the documents come from.
+    pub source: PipelineSource,
+
+    /// Chunking strategy (the default strategy when unset).
+    #[serde(default)]
+    pub chunking: Option<ChunkingStrategy>,
+
+    /// Enrichment steps, applied in order.
+    #[serde(default)]
+    pub enrich: Vec<EnrichmentStep>,
+
+    /// Whether to generate embeddings for chunks.
+    #[serde(default = "default_embed")]
+    pub embed: bool,
+
+    /// Namespace recorded on the documents and their chunks.
+    #[serde(default)]
+    pub namespace: Option<String>,
+
+    /// Durability of the document and chunk writes.
+    #[serde(default)]
+    pub durability: Durability,
+}
+
+fn default_embed() -> bool {
+    true
+}
+
+/// Source of the documents of a pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PipelineSource {
+    /// Web pages or remote documents, fetched on each run.
+    Url {
+        /// URLs to fetch (http or https).
+        urls: Vec<String>,
+    },
+
+    /// Files readable by the server.
+    File {
+        /// File paths.
+        paths: Vec<PathBuf>,
+    },
+
+    /// Files of a directory and its subdirectories, hidden ones excepted.
+    Directory {
+        /// Directory path.
+        path: PathBuf,
+
+        /// File extensions to ingest, without the dot (all when empty).
+        #[serde(default)]
+        extensions: Vec<String>,
+    },
+}
+
+/// Step enriching every document of a pipeline before it is stored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EnrichmentStep {
+    /// Attach metadata to every chunk; later steps override earlier keys.
+    Metadata {
+        /// Metadata entries.
+        values: serde_json::Map<String, serde_json::Value>,
+    },
+
+    /// Tag the document and its chunks.
+    Tags {
+        /// Tags to add.
+        tags: Vec<String>,
+    },
+}
+
+/// A document to ingest, resolved from a pipeline source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SourceItem {
+    Url(String),
+    File(PathBuf),
+}
+
+impl SourceItem {
+    /// The URL or path, as recorded in run errors.
+    pub(crate) fn location(&self) -> String {
+        match self {
+            Self::Url(url) => url.clone(),
+            Self::File(path) => path.display().to_string(),
+        }
+    }
+}
+
+impl PipelineDefinition {
+    /// Check the definition, describing the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() || self.name.contains('/') {
+            return Err(format!("invalid pipeline name {:?}", self.name));
+        }
+        let empty = match &self.source {
+            PipelineSource::Url { urls } => urls.is_empty(),
+            PipelineSource::File { paths } => paths.is_empty(),
+            PipelineSource::Directory { .. } => false,
+        };
+        if empty {
+            return Err(format!("pipeline {:?} has no source documents", self.name));
+        }
+        for tag in self.tags() {
+            synton_core::normalize_tag(&tag)
+                .map_err(|e| format!("pipeline {:?} has an invalid tag: {}", self.name, e))?;
+        }
+        if let PipelineSource::Url { urls } = &self.source {
+            if let Some(url) = urls
+                .iter()
+                .find(|url| !url.starts_with("http://") && !url.starts_with("https://"))
+            {
+                return Err(format!("pipeline {:?} has a non-http URL {:?}", self.name, url));
+            }
+        }
+        Ok(())
+    }
+
+    /// Metadata merged from the metadata steps, if any.
+    pub(crate) fn metadata(&self) -> Option<serde_json::Value> {
+        let mut merged = serde_json::Map::new();
+        let mut any = false;
+        for step in &self.enrich {
+            if let EnrichmentStep::Metadata { values } = step {
+                merged.extend(values.clone());
+                any = true;
+            }
+        }
+        any.then_some(serde_json::Value::Object(merged))
+    }
+
+    /// Tags from the tag steps.
+    pub(crate) fn tags(&self) -> Vec<String> {
+        self.enrich
+            .iter()
+            .filter_map(|step| match step {
+                EnrichmentStep::Tags { tags } => Some(tags.iter().cloned()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Documents of the source, in a stable order.
+    pub(crate) fn items(&self) -> std::io::Result<Vec<SourceItem>> {
+        match &self.source {
+            PipelineSource::Url { urls } => Ok(urls.iter().cloned().map(SourceItem::Url).collect()),
+            PipelineSource::File { paths } => {
+                Ok(paths.iter().cloned().map(SourceItem::File).collect())
+            }
+            PipelineSource::Directory { path, extensions } => {
+                let extensions: HashSet<String> =
+                    extensions.iter().map(|e| e.trim_start_matches('.').to_lowercase()).collect();
+                let mut files = Vec::new();
+                collect_files(path, &extensions, &mut files)?;
+                files.sort();
+                Ok(files.into_iter().map(SourceItem::File).collect())
+            }
+        }
+    }
+}
+
+/// Add the non-hidden files under `dir` with one of `extensions` (any when
+/// empty) to `files`.
+fn collect_files(dir: &Path, extensions: &HashSet<String>, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&path, extensions, files)?;
+        } else if file_type.is_file() {
+            let extension = path
+                .extension()
+                .map(|e| e.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            if extensions.is_empty() || extensions.contains(&extension) {
+                files.push(path);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct PipelineState {
+    definitions: BTreeMap<String, PipelineDefinition>,
+    runs: HashMap<String, VecDeque<PipelineRun>>,
+    running: BTreeSet<String>,
+}
+
+/// Configured pipelines and their run history.
+#[derive(Debug, Default)]
+pub(crate) struct PipelineRegistry {
+    state: Mutex<PipelineState>,
+}
+
+impl PipelineRegistry {
+    fn state(&self) -> MutexGuard<'_, PipelineState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replace the configured pipelines. The history of pipelines still
+    /// configured is kept.
+    pub(crate) fn configure(&self, definitions: Vec<PipelineDefinition>) -> Result<(), String> {
+        let mut configured = BTreeMap::new();
+        for definition in definitions {
+            definition.validate()?;
+            let name = definition.name.clone();
+            if configured.insert(name.clone(), definition).is_some() {
+                return Err(format!("duplicate pipeline name {:?}", name));
+            }
+        }
+
+        let mut state = self.state();
+        state.runs.retain(|name, _| configured.contains_key(name));
+        state.definitions = configured;
+        Ok(())
+    }
+
+    /// Configured pipelines, by name.
+    pub(crate) fn definitions(&self) -> Vec<PipelineDefinition> {
+        self.state().definitions.values().cloned().collect()
+    }
+
+    /// A configured pipeline.
+    pub(crate) fn get(&self, name: &str) -> Option<PipelineDefinition> {
+        self.state().definitions.get(name).cloned()
+    }
+
+    /// Mark a pipeline as running until the guard drops, or `None` if it
+    /// already is.
+    pub(crate) fn start(&self, name: &str) -> Option<RunGuard<'_>> {
+        self.state().running.insert(name.to_string()).then(|| RunGuard {
+            registry: self,
+            name: name.to_string(),
+        })
+    }
+
+    /// Record a finished run.
+    pub(crate) fn record(&self, run: PipelineRun) {
+        let mut state = self.state();
+        let runs = state.runs.entry(run.pipeline.clone()).or_default();
+        runs.push_front(run);
+        runs.truncate(MAX_RUN_HISTORY);
+    }
+
+    /// Runs of a pipeline, newest first.
+    pub(crate) fn runs(&self, name: &str) -> Vec<PipelineRun> {
+        self.state()
+            .runs
+            .get(name)
+            .map(|runs| runs.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Marks a pipeline as running while alive.
+pub(crate) struct RunGuard<'a> {
+    registry: &'a PipelineRegistry,
+    name: String,
+}
+
+impl Drop for RunGuard<'_> {
+    fn drop(&mut self) {
+        self.registry.state().running.remove(&self.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handbook() -> PipelineDefinition {
+        serde_json::from_value(serde_json::json!({
+            "name": "handbook",
+            "namespace": "docs",
+            "source": {"type": "directory", "path": "./handbook"},
+            "chunking": {"fixed": {"chunk_size": 500, "overlap": 50}},
+            "enrich": [
+                {"type": "metadata", "values": {"team": "platform", "reviewed": false}},
+                {"type": "tags", "tags": ["handbook"]},
+                {"type": "metadata", "values": {"reviewed": true}}
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_definition() {
+        let definition = handbook();
+        assert!(definition.validate().is_ok());
+        assert!(definition.embed);
+        assert_eq!(definition.namespace.as_deref(), Some("docs"));
+        assert_eq!(
+            definition.metadata(),
+            Some(serde_json::json!({"team": "platform", "reviewed": true}))
+        );
+        assert_eq!(definition.tags(), vec!["handbook"]);
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("guides/.drafts")).unwrap();
+        for file in ["b.md", "guides/a.TXT", "guides/.drafts/c.md", "image.png"] {
+            std::fs::write(dir.path().join(file), "text").unwrap();
+        }
+        let definition = PipelineDefinition {
+            source: PipelineSource::Directory {
+                path: dir.path().to_path_buf(),
+                extensions: vec!["md".to_string(), ".TXT".to_string()],
+            },
+            ..definition
+        };
+        assert_eq!(
+            definition.items().unwrap(),
+            vec![
+                SourceItem::File(dir.path().join("b.md")),
+                SourceItem::File(dir.path().join("guides/a.TXT")),
+            ]
+        );
+
+        let invalid = PipelineDefinition {
+            source: PipelineSource::Url {
+                urls: vec!["ftp://example.com/a".to_string()],
+            },
+            ..definition
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        let definition = handbook();
+        let registry = PipelineRegistry::default();
+        assert!(registry
+            .configure(vec![definition.clone(), definition.clone()])
+            .is_err());
+        registry.configure(vec![definition]).unwrap();
+        assert!(registry.get("handbook").is_some());
+
+        let guard = registry.start("handbook").unwrap();
+        assert!(registry.start("handbook").is_none());
+        drop(guard);
+        assert!(registry.start("handbook").is_some());
+    }
+}
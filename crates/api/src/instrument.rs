@@ -5,19 +5,28 @@
 //! - Timeline views
 //! - Statistics
 //! - Export (JSON/Mermaid)
+//! - Live tail of finished spans (SSE)
+//!
+//! and the middleware that binds each request to a trace context.
 
 use axum::{
     extract::{Path, State, Query},
     routing::{get, Router},
     Json,
+    response::sse::{Event, KeepAlive, Sse},
     response::IntoResponse,
 };
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
 use uuid::Uuid;
 
 use crate::rest::AppState;
 use crate::ApiError;
-use synton_instrument::{ExportFormat, LifecycleView, Statistics, TimelineView};
+use synton_instrument::{
+    ExportFormat, LifecycleView, SpanKind, SpanStatus, Statistics, TimelineView, TraceCollector,
+    TraceContext, TraceSpan, TRACEPARENT_HEADER, TRACE_ID_HEADER,
+};
 
 /// Path parameters for trace endpoints.
 #[derive(Debug, Deserialize)]
@@ -89,6 +98,100 @@ pub struct ExportParams {
     format: Option<String>,
 }
 
+/// Live trace query parameters; every given filter must match.
+#[derive(Debug, Default, Deserialize)]
+pub struct LiveTraceParams {
+    /// Only spans whose name contains this text.
+    span_name: Option<String>,
+
+    /// Only spans that took at least this long.
+    min_duration_ms: Option<f64>,
+
+    /// Only spans of this kind (e.g. `database_query`).
+    kind: Option<String>,
+
+    /// Only spans with this outcome: `completed` or `failed`.
+    status: Option<String>,
+}
+
+impl LiveTraceParams {
+    /// Whether a finished span passes the filters.
+    fn matches(&self, span: &TraceSpan) -> bool {
+        if let Some(name) = &self.span_name {
+            if !span.name.contains(name.as_str()) {
+                return false;
+            }
+        }
+        if let Some(min) = self.min_duration_ms {
+            if span.duration_ms.unwrap_or(0.0) < min {
+                return false;
+            }
+        }
+        if let Some(kind) = &self.kind {
+            if span.metadata.kind != SpanKind::from(kind.as_str()) {
+                return false;
+            }
+        }
+        match self.status.as_deref() {
+            Some("completed") => span.status == SpanStatus::Completed,
+            Some("failed") => span.status.is_failure(),
+            Some(_) => false,
+            None => true,
+        }
+    }
+}
+
+/// Middleware that propagates a per-request trace context.
+///
+/// Reads `traceparent` or `x-trace-id` from the request (generating a new
+/// trace ID otherwise), records a root span for the request, runs the handler
+/// inside the trace context so its spans join the trace, and echoes the trace
+/// ID back in the `x-trace-id` and `traceparent` response headers.
+pub async fn trace_context_middleware(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let context = {
+        let headers = request.headers();
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        TraceContext::from_headers(header(TRACEPARENT_HEADER), header(TRACE_ID_HEADER))
+    };
+
+    let collector = TraceCollector::global();
+    let name = format!("{} {}", request.method(), request.uri().path());
+    let context = collector.start_request(context, name);
+    let start = std::time::Instant::now();
+
+    let mut response = context.scope(next.run(request)).await;
+
+    let duration_ms = start.elapsed().as_secs_f64() * 1000.0;
+    let status = response.status();
+    if let Some(span_id) = context.span_id {
+        if status.is_server_error() {
+            collector.fail_span(span_id, status.to_string(), duration_ms);
+        } else {
+            collector.complete_span(
+                span_id,
+                Some(serde_json::json!({ "status": status.as_u16() })),
+                duration_ms,
+            );
+        }
+    }
+
+    set_trace_headers(response.headers_mut(), &context);
+    response
+}
+
+/// Write the trace ID headers for a context into a header map.
+fn set_trace_headers(headers: &mut axum::http::HeaderMap, context: &TraceContext) {
+    if let Ok(value) = axum::http::HeaderValue::from_str(&context.trace_id.to_string()) {
+        headers.insert(TRACE_ID_HEADER, value);
+    }
+    if let Ok(value) = axum::http::HeaderValue::from_str(&context.to_traceparent()) {
+        headers.insert(TRACEPARENT_HEADER, value);
+    }
+}
+
 /// Create instrumentation router.
 pub fn create_instrument_router() -> Router<AppState> {
     Router::new()
@@ -100,9 +203,24 @@ pub fn create_instrument_router() -> Router<AppState> {
         .route("/stats", get(statistics))
         // Export trace data
         .route("/export/:trace_id", get(export_trace))
+        // Stream finished spans as they happen
+        .route("/traces/live", get(live_traces))
 }
 
 /// Get lifecycle view for a trace.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instr/lifecycle/{trace_id}",
+    params(
+        ("trace_id" = String, Path, description = "Trace UUID")
+    ),
+    responses(
+        (status = 200, description = "Trace lifecycle retrieved successfully", body = crate::openapi::LifecycleResponse),
+        (status = 400, description = "Invalid trace ID"),
+        (status = 404, description = "Trace not found")
+    ),
+    tag = "instrumentation"
+)]
 async fn lifecycle_view(
     State(state): State<AppState>,
     Path(trace_id): Path<String>,
@@ -122,6 +240,19 @@ async fn lifecycle_view(
 }
 
 /// Get timeline view for a trace.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instr/timeline/{trace_id}",
+    params(
+        ("trace_id" = String, Path, description = "Trace UUID")
+    ),
+    responses(
+        (status = 200, description = "Trace timeline retrieved successfully", body = crate::openapi::TimelineResponse),
+        (status = 400, description = "Invalid trace ID"),
+        (status = 404, description = "Trace not found")
+    ),
+    tag = "instrumentation"
+)]
 async fn timeline_view(
     State(state): State<AppState>,
     Path(trace_id): Path<String>,
@@ -141,6 +272,14 @@ async fn timeline_view(
 }
 
 /// Get instrumentation statistics.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instr/stats",
+    responses(
+        (status = 200, description = "Statistics retrieved successfully", body = crate::openapi::StatisticsResponse)
+    ),
+    tag = "instrumentation"
+)]
 async fn statistics(State(state): State<AppState>) -> impl IntoResponse {
     let collector = &state.service.collector;
     let stats = collector.get_statistics();
@@ -148,6 +287,20 @@ async fn statistics(State(state): State<AppState>) -> impl IntoResponse {
 }
 
 /// Export trace data in specified format.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instr/export/{trace_id}",
+    params(
+        ("trace_id" = String, Path, description = "Trace UUID"),
+        ("format" = Option<String>, Query, description = "Export format: json (default), mermaid or text")
+    ),
+    responses(
+        (status = 200, description = "Trace exported successfully", body = crate::openapi::ExportResponse),
+        (status = 400, description = "Invalid trace ID"),
+        (status = 404, description = "Trace not found")
+    ),
+    tag = "instrumentation"
+)]
 async fn export_trace(
     State(state): State<AppState>,
     Path(trace_id): Path<String>,
@@ -213,3 +366,107 @@ async fn export_trace(
         data,
     }.into_response()
 }
+
+/// Stream spans as they complete or fail, as server-sent events.
+///
+/// Each matching span is sent as a `span` event with the span as JSON. A
+/// client too slow to keep up gets a `lagged` event with the number of
+/// spans it missed.
+#[utoipa::path(
+    get,
+    path = "/api/v1/instr/traces/live",
+    params(
+        ("span_name" = Option<String>, Query, description = "Only spans whose name contains this text"),
+        ("min_duration_ms" = Option<f64>, Query, description = "Only spans that took at least this long"),
+        ("kind" = Option<String>, Query, description = "Only spans of this kind, e.g. database_query"),
+        ("status" = Option<String>, Query, description = "Only spans with this outcome: completed or failed")
+    ),
+    responses(
+        (status = 200, description = "Stream of span and lagged events", content_type = "text/event-stream", body = String),
+        (status = 400, description = "Invalid status filter")
+    ),
+    tag = "instrumentation"
+)]
+async fn live_traces(
+    State(state): State<AppState>,
+    Query(params): Query<LiveTraceParams>,
+) -> impl IntoResponse {
+    if let Some(status) = params.status.as_deref() {
+        if !matches!(status, "completed" | "failed") {
+            return ApiError::InvalidRequest(format!(
+                "status must be completed or failed, got {}",
+                status
+            ))
+            .into_response();
+        }
+    }
+
+    let receiver = state.service.collector.subscribe();
+    Sse::new(live_span_events(receiver, params))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// SSE events for the spans a subscriber receives that pass the filters.
+fn live_span_events(
+    receiver: tokio::sync::broadcast::Receiver<TraceSpan>,
+    params: LiveTraceParams,
+) -> impl Stream<Item = Result<Event, axum::Error>> {
+    futures::stream::unfold((receiver, params), |(mut receiver, params)| async move {
+        loop {
+            let event = match receiver.recv().await {
+                Ok(span) if params.matches(&span) => {
+                    Event::default().event("span").json_data(&span)
+                }
+                Ok(_) => continue,
+                Err(RecvError::Lagged(missed)) => {
+                    Ok(Event::default().event("lagged").data(missed.to_string()))
+                }
+                Err(RecvError::Closed) => return None,
+            };
+            return Some((event, (receiver, params)));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn finished(name: &str, kind: SpanKind, duration_ms: f64, status: SpanStatus) -> TraceSpan {
+        let mut span = TraceSpan::new(name, Uuid::new_v4(), None, kind, HashMap::new());
+        span.duration_ms = Some(duration_ms);
+        span.status = status;
+        span
+    }
+
+    #[test]
+    fn test_live_trace_filters() {
+        let query = |duration_ms| {
+            finished("storage.get_node", SpanKind::DatabaseQuery, duration_ms, SpanStatus::Completed)
+        };
+        let (slow_query, fast_query) = (query(120.0), query(2.0));
+        let failed_call =
+            finished("embed", SpanKind::ExternalCall, 80.0, SpanStatus::Failed("timeout".into()));
+
+        assert!(LiveTraceParams::default().matches(&fast_query));
+
+        let slow_db = LiveTraceParams {
+            span_name: Some("get_node".into()),
+            min_duration_ms: Some(50.0),
+            kind: Some("database".into()),
+            ..Default::default()
+        };
+        assert!(slow_db.matches(&slow_query));
+        assert!(!slow_db.matches(&fast_query));
+        assert!(!slow_db.matches(&failed_call));
+
+        let failures = LiveTraceParams {
+            status: Some("failed".into()),
+            ..Default::default()
+        };
+        assert!(failures.matches(&failed_call));
+        assert!(!failures.matches(&slow_query));
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Background jobs.
+//!
+//! Long operations (importing an archive, optimizing the vector index) can
+//! run as jobs instead of holding an HTTP request open: the request returns
+//! a [`Job`] at once and `GET /jobs/{id}` reports its progress. Jobs run one
+//! at a time, in submission order.
+//!
+//! With persistence each job is kept in the metadata column family under
+//! `job:<id>`, and its input under `job_input:<id>` until it finishes. Jobs
+//! a restart interrupted are run again from their input; both operations
+//! skip work already done, so running again resumes them.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use uuid::Uuid;
+
+use crate::models::{Job, JobKind, JobStatus};
+
+/// Key prefix of jobs in the metadata column family.
+pub(crate) const JOB_KEY_PREFIX: &str = "job:";
+
+/// Key prefix of job inputs in the metadata column family.
+const INPUT_KEY_PREFIX: &str = "job_input:";
+
+/// Finished jobs kept; older ones are forgotten.
+const FINISHED_JOBS: usize = 1_000;
+
+/// Storage key of a job.
+pub(crate) fn job_key(id: Uuid) -> String {
+    format!("{}{}", JOB_KEY_PREFIX, id)
+}
+
+/// Storage key of a job's input.
+pub(crate) fn input_key(id: Uuid) -> String {
+    format!("{}{}", INPUT_KEY_PREFIX, id)
+}
+
+/// Submitted jobs and the slot they take turns running in.
+#[derive(Debug, Default)]
+pub(crate) struct Jobs {
+    jobs: Mutex<HashMap<Uuid, Job>>,
+    /// Held by the running job; waiters are served in order.
+    slot: tokio::sync::Mutex<()>,
+}
+
+impl Jobs {
+    fn jobs(&self) -> MutexGuard<'_, HashMap<Uuid, Job>> {
+        self.jobs.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Add a queued job.
+    pub(crate) fn create(&self, kind: JobKind) -> Job {
+        let job = Job {
+            id: Uuid::new_v4(),
+            kind,
+            status: JobStatus::Queued,
+            done: 0,
+            total: None,
+            percent: None,
+            created_at: chrono::Utc::now(),
+            started_at: None,
+            finished_at: None,
+            resumed: 0,
+            result: None,
+            error: None,
+        };
+        self.jobs().insert(job.id, job.clone());
+        job
+    }
+
+    /// Add a job loaded from storage.
+    pub(crate) fn restore(&self, job: Job) {
+        self.jobs().insert(job.id, job);
+    }
+
+    /// Number of jobs waiting to run.
+    pub(crate) fn queued(&self) -> usize {
+        self.jobs()
+            .values()
+            .filter(|job| job.status == JobStatus::Queued)
+            .count()
+    }
+
+    /// Wait for the running job, if any, to finish. The next job runs while
+    /// the guard is held.
+    pub(crate) async fn slot(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.slot.lock().await
+    }
+
+    /// Mark a job running, resetting its progress.
+    pub(crate) fn start(&self, id: Uuid) -> Option<Job> {
+        let mut jobs = self.jobs();
+        let job = jobs.get_mut(&id)?;
+        job.status = JobStatus::Running;
+        job.started_at = Some(chrono::Utc::now());
+        job.done = 0;
+        job.total = None;
+        job.percent = None;
+        Some(job.clone())
+    }
+
+    /// Record the work items a running job has done out of its total.
+    pub(crate) fn progress(&self, id: Uuid, done: u64, total: u64) {
+        if let Some(job) = self.jobs().get_mut(&id) {
+            job.done = done;
+            job.total = Some(total);
+            job.percent = Some(if total == 0 {
+                100.0
+            } else {
+                (done as f64 / total as f64 * 100.0) as f32
+            });
+        }
+    }
+
+    /// Finish a job with its result or error. Returns the job and the IDs of
+    /// finished jobs forgotten to make room.
+    pub(crate) fn finish(
+        &self,
+        id: Uuid,
+        result: Result<serde_json::Value, String>,
+    ) -> (Option<Job>, Vec<Uuid>) {
+        let mut jobs = self.jobs();
+        let Some(job) = jobs.get_mut(&id) else {
+            return (None, Vec::new());
+        };
+        job.finished_at = Some(chrono::Utc::now());
+        match result {
+            Ok(result) => {
+                job.status = JobStatus::Succeeded;
+                if let Some(total) = job.total {
+                    job.done = total;
+                }
+                job.percent = Some(100.0);
+                job.result = Some(result);
+            }
+            Err(error) => {
+                job.status = JobStatus::Failed;
+                job.error = Some(error);
+            }
+        }
+        let job = job.clone();
+
+        let mut finished: Vec<_> = jobs
+            .values()
+            .filter(|job| job.status.is_finished())
+            .map(|job| (job.created_at, job.id))
+            .collect();
+        let excess = finished.len().saturating_sub(FINISHED_JOBS);
+        finished.sort_unstable();
+        let forgotten: Vec<_> = finished.into_iter().take(excess).map(|(_, id)| id).collect();
+        for id in &forgotten {
+            jobs.remove(id);
+        }
+        (Some(job), forgotten)
+    }
+
+    /// A job by ID.
+    pub(crate) fn get(&self, id: Uuid) -> Option<Job> {
+        self.jobs().get(&id).cloned()
+    }
+
+    /// All jobs, newest first.
+    pub(crate) fn list(&self) -> Vec<Job> {
+        let mut jobs: Vec<_> = self.jobs().values().cloned().collect();
+        jobs.sort_by_key(|job| std::cmp::Reverse(job.created_at));
+        jobs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_lifecycle() {
+        let jobs = Jobs::default();
+        let job = jobs.create(JobKind::Import);
+        assert_eq!(job.status, JobStatus::Queued);
+
+        let running = jobs.start(job.id).unwrap();
+        assert_eq!(running.status, JobStatus::Running);
+        assert!(running.started_at.is_some());
+
+        jobs.progress(job.id, 1, 4);
+        let progress = jobs.get(job.id).unwrap();
+        assert_eq!((progress.done, progress.total, progress.percent), (1, Some(4), Some(25.0)));
+
+        let (finished, forgotten) = jobs.finish(job.id, Ok(serde_json::json!({"nodes": 4})));
+        let finished = finished.unwrap();
+        assert_eq!(finished.status, JobStatus::Succeeded);
+        assert_eq!((finished.done, finished.percent), (4, Some(100.0)));
+        assert!(forgotten.is_empty());
+
+        let failed = jobs.create(JobKind::OptimizeVector);
+        jobs.start(failed.id);
+        let failed = jobs.finish(failed.id, Err("No vector index configured".to_string())).0.unwrap();
+        assert_eq!(failed.status, JobStatus::Failed);
+        assert_eq!(failed.error.as_deref(), Some("No vector index configured"));
+        assert_eq!(jobs.list().len(), 2);
+    }
+
+    #[test]
+    fn test_finished_jobs_are_bounded() {
+        let jobs = Jobs::default();
+        let first = jobs.create(JobKind::OptimizeVector);
+        jobs.finish(first.id, Ok(serde_json::Value::Null));
+        let mut forgotten = Vec::new();
+        for _ in 0..FINISHED_JOBS {
+            let job = jobs.create(JobKind::OptimizeVector);
+            forgotten.extend(jobs.finish(job.id, Ok(serde_json::Value::Null)).1);
+        }
+        assert_eq!(forgotten, vec![first.id]);
+        assert!(jobs.get(first.id).is_none());
+        assert_eq!(jobs.list().len(), FINISHED_JOBS);
+    }
+
+    #[test]
+    fn test_job_keys() {
+        let id = Uuid::new_v4();
+        assert!(job_key(id).starts_with(JOB_KEY_PREFIX));
+        assert!(!input_key(id).starts_with(JOB_KEY_PREFIX));
+    }
+}
@@ -10,9 +10,42 @@
 #![warn(clippy::all)]
 
 pub mod error;
+mod attachments;
+mod audit;
+mod counters;
+mod dedup;
+mod diagnostics;
+mod documents;
+#[cfg(feature = "ml")]
+mod embedding_cache;
+mod explain;
+mod expiry;
+mod export;
+mod feedback;
 mod grpc;
+mod hooks;
+mod ingestion;
 mod instrument;
+mod jobs;
+mod limits;
+mod locks;
+mod metering;
 mod models;
+mod namespaces;
+mod pipeline;
+mod prepared;
+mod query_cache;
+mod ranking;
+// Only the status is reported without an embedding service
+#[cfg_attr(not(feature = "ml"), allow(dead_code))]
+mod reembed;
+mod review;
+mod sampling;
+mod scrub;
+mod seed;
+mod similarity_links;
+mod usage;
+pub mod validation;
 
 /// OpenAPI documentation.
 pub mod openapi;
@@ -21,12 +54,53 @@ pub mod openapi;
 pub mod rest;
 
 mod service;
+#[cfg(feature = "ml")]
+mod summarize;
+#[cfg(feature = "ml")]
+mod translate;
+mod vector_stores;
+mod views;
 
+pub use attachments::{MAX_ATTACHMENT_BYTES, MAX_ATTACHMENT_UPLOAD_BYTES, MAX_NODE_ATTACHMENT_BYTES};
+pub use audit::AuditConfig;
+pub use dedup::{AbsorbDedupConfig, OCCURRENCES_ATTRIBUTE};
+#[cfg(feature = "ml")]
+pub use embedding_cache::StoreEmbeddingCache;
 pub use error::{ApiError, ApiResult};
-pub use grpc::create_grpc_router;
+pub use expiry::ExpiryDigestConfig;
+pub use export::{
+    ExportHeader, ExportRecord, NodeEmbedding, EXPORT_CONTENT_TYPE, EXPORT_FORMAT, EXPORT_VERSION,
+    MAX_IMPORT_BYTES,
+};
+pub use grpc::{create_grpc_router, grpc_server_builder};
+pub use hooks::{
+    HookError, HookRegistry, MutationEvent, MutationEventKind, MutationHook, WebhookConfig,
+    WebhookHook,
+};
+pub use ingestion::{EnrichmentStep, PipelineDefinition, PipelineSource};
+pub use instrument::{create_instrument_router, trace_context_middleware};
+pub use limits::{limit_middleware, ConcurrencyLimits, RequestLimiter, RouteClass, RouteLimits};
+pub use metering::{metering_middleware, MeteringConfig, ANONYMOUS_ACCOUNT, API_KEY_HEADER};
 pub use models::*;
-pub use rest::{AppState, create_router, run_server};
+pub use namespaces::{
+    NamespaceConfig, NamespaceInheritance, NamespaceRule, NAMESPACE_SEPARATOR,
+};
+pub use query_cache::QueryCacheConfig;
+pub use reembed::ReembedConfig;
+pub use review::EdgeReviewConfig;
+pub use scrub::{ScrubConfig, SecretRule, SECRET_TAG};
+pub use seed::{Ontology, OntologyConcept, STARTER_NAMESPACE};
+pub use similarity_links::SimilarityLinkConfig;
+pub use rest::{
+    AppState, create_router, create_router_with_limits, run_server, run_server_with_limits,
+    VECTOR_CHECKPOINT_HEADER,
+};
 pub use service::SyntonDbService;
+#[cfg(feature = "ml")]
+pub use summarize::SummaryConfig;
+pub use usage::{actor_middleware, UsageConfig, ACTOR_HEADER};
+pub use validation::{ValidJson, Validate};
+pub use views::ViewDefinition;
 
 /// Re-exports commonly used types
 pub mod prelude {
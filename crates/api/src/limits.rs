@@ -0,0 +1,422 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! In-flight request limiting and backpressure.
+//!
+//! Requests are grouped into route classes (read, write, ingest), each with
+//! its own in-flight budget and wait queue. Requests beyond the budget wait in
+//! the queue up to a timeout; once the queue is full, or the wait times out,
+//! they are rejected with `429 Too Many Requests` and a `Retry-After` header.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::models::{LimiterStats, RouteClassStats};
+use crate::rest::AppState;
+use crate::ApiError;
+
+/// Limits for a single route class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteLimits {
+    /// Maximum requests executing concurrently (0 = unlimited).
+    pub max_in_flight: usize,
+
+    /// Maximum requests waiting for a slot before new ones are rejected.
+    pub max_queued: usize,
+}
+
+impl RouteLimits {
+    /// Create limits for a route class.
+    pub const fn new(max_in_flight: usize, max_queued: usize) -> Self {
+        Self {
+            max_in_flight,
+            max_queued,
+        }
+    }
+
+    /// Limits that never reject.
+    pub const fn unlimited() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+/// Concurrency limits for the REST and gRPC servers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConcurrencyLimits {
+    /// Queries, traversals and lookups.
+    pub read: RouteLimits,
+
+    /// Node/edge mutations and feedback.
+    pub write: RouteLimits,
+
+    /// Document ingestion and bulk loads.
+    pub ingest: RouteLimits,
+
+    /// How long a queued request waits for a slot (milliseconds).
+    pub queue_timeout_ms: u64,
+
+    /// Value of the `Retry-After` header on rejected requests (seconds).
+    pub retry_after_secs: u64,
+
+    /// Maximum concurrent gRPC requests per connection (0 = unlimited).
+    pub grpc_concurrency_per_connection: usize,
+}
+
+impl Default for ConcurrencyLimits {
+    fn default() -> Self {
+        Self {
+            read: RouteLimits::new(256, 1024),
+            write: RouteLimits::new(64, 256),
+            ingest: RouteLimits::new(4, 16),
+            queue_timeout_ms: 5000,
+            retry_after_secs: 1,
+            grpc_concurrency_per_connection: 32,
+        }
+    }
+}
+
+impl ConcurrencyLimits {
+    /// Limits that never reject any request.
+    pub fn unlimited() -> Self {
+        Self {
+            read: RouteLimits::unlimited(),
+            write: RouteLimits::unlimited(),
+            ingest: RouteLimits::unlimited(),
+            grpc_concurrency_per_connection: 0,
+            ..Default::default()
+        }
+    }
+}
+
+/// Class of a REST route for concurrency limiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteClass {
+    /// Read-only requests.
+    Read,
+
+    /// Mutating requests.
+    Write,
+
+    /// Document ingestion and bulk loads.
+    Ingest,
+}
+
+impl RouteClass {
+    /// Read-only endpoints that are invoked with `POST` because they take a body.
+    const READ_POSTS: &'static [&'static str] = &[
+        "/query",
+        "/query/prepare",
+        "/query/execute",
+        "/query/structured",
+        "/traverse",
+        "/traverse/explain",
+        "/reason/paths",
+        "/graph/match",
+        "/hybrid_search",
+    ];
+
+    /// Classify a request, or `None` if it is exempt from limiting.
+    ///
+    /// Health checks, limiter stats and API docs stay reachable under load.
+    pub fn classify(method: &axum::http::Method, path: &str) -> Option<Self> {
+        let path = path.trim_end_matches('/');
+        if path == "/health"
+            || path == "/admin/limits"
+            || path == "/openapi.json"
+            || path.starts_with("/api-docs")
+        {
+            return None;
+        }
+
+        if path == "/bulk"
+            || path == "/import"
+            || path == "/transactions"
+            || path == "/nodes/update"
+            || (path.starts_with("/documents") && method == axum::http::Method::POST)
+            || path.starts_with("/ingest/")
+            || (path.starts_with("/pipelines/") && method == axum::http::Method::POST)
+            || (path.starts_with("/v1/") && path.ends_with("/files") && method == axum::http::Method::POST)
+        {
+            return Some(Self::Ingest);
+        }
+
+        if method == axum::http::Method::GET
+            || method == axum::http::Method::HEAD
+            || method == axum::http::Method::OPTIONS
+            || Self::READ_POSTS.contains(&path)
+            || (path.starts_with("/v1/vector_stores/") && path.ends_with("/search"))
+        {
+            Some(Self::Read)
+        } else {
+            Some(Self::Write)
+        }
+    }
+}
+
+impl fmt::Display for RouteClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Write => write!(f, "write"),
+            Self::Ingest => write!(f, "ingest"),
+        }
+    }
+}
+
+/// In-flight budget and counters for one route class.
+#[derive(Debug)]
+struct Gate {
+    limits: RouteLimits,
+    semaphore: Option<Arc<Semaphore>>,
+    queued: AtomicUsize,
+    admitted: AtomicU64,
+    rejected: AtomicU64,
+}
+
+impl Gate {
+    fn new(limits: RouteLimits) -> Self {
+        Self {
+            limits,
+            semaphore: (limits.max_in_flight > 0)
+                .then(|| Arc::new(Semaphore::new(limits.max_in_flight))),
+            queued: AtomicUsize::new(0),
+            admitted: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    fn stats(&self) -> RouteClassStats {
+        let in_flight = self
+            .semaphore
+            .as_ref()
+            .map(|s| self.limits.max_in_flight - s.available_permits())
+            .unwrap_or(0);
+
+        RouteClassStats {
+            max_in_flight: self.limits.max_in_flight,
+            max_queued: self.limits.max_queued,
+            in_flight,
+            queued: self.queued.load(Ordering::Relaxed),
+            admitted: self.admitted.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Decrements the queue length when a waiting request leaves the queue,
+/// including when the client disconnects while waiting.
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Per-route-class concurrency limiter.
+#[derive(Debug)]
+pub struct RequestLimiter {
+    read: Gate,
+    write: Gate,
+    ingest: Gate,
+    queue_timeout: Duration,
+    retry_after_secs: u64,
+}
+
+impl RequestLimiter {
+    /// Create a limiter from the given limits.
+    pub fn new(limits: &ConcurrencyLimits) -> Self {
+        Self {
+            read: Gate::new(limits.read),
+            write: Gate::new(limits.write),
+            ingest: Gate::new(limits.ingest),
+            queue_timeout: Duration::from_millis(limits.queue_timeout_ms),
+            retry_after_secs: limits.retry_after_secs,
+        }
+    }
+
+    fn gate(&self, class: RouteClass) -> &Gate {
+        match class {
+            RouteClass::Read => &self.read,
+            RouteClass::Write => &self.write,
+            RouteClass::Ingest => &self.ingest,
+        }
+    }
+
+    /// Wait for an in-flight slot for the given route class.
+    ///
+    /// Returns `None` when the class is unlimited. The slot is released when
+    /// the returned permit is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApiError::Overloaded`] if the queue is full or the wait
+    /// times out.
+    pub async fn acquire(&self, class: RouteClass) -> Result<Option<OwnedSemaphorePermit>, ApiError> {
+        let gate = self.gate(class);
+        let Some(semaphore) = gate.semaphore.clone() else {
+            gate.admitted.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        };
+
+        if let Ok(permit) = semaphore.clone().try_acquire_owned() {
+            gate.admitted.fetch_add(1, Ordering::Relaxed);
+            return Ok(Some(permit));
+        }
+
+        if gate.queued.fetch_add(1, Ordering::Relaxed) >= gate.limits.max_queued {
+            gate.queued.fetch_sub(1, Ordering::Relaxed);
+            return Err(self.reject(class));
+        }
+
+        let waited = {
+            let _slot = QueueSlot(&gate.queued);
+            tokio::time::timeout(self.queue_timeout, semaphore.acquire_owned()).await
+        };
+
+        match waited {
+            Ok(Ok(permit)) => {
+                gate.admitted.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(permit))
+            }
+            _ => Err(self.reject(class)),
+        }
+    }
+
+    fn reject(&self, class: RouteClass) -> ApiError {
+        self.gate(class).rejected.fetch_add(1, Ordering::Relaxed);
+        tracing::warn!("Rejected {} request: concurrency limit reached", class);
+        ApiError::Overloaded {
+            class: class.to_string(),
+            retry_after_secs: self.retry_after_secs,
+        }
+    }
+
+    /// Current limiter counters.
+    pub fn stats(&self) -> LimiterStats {
+        LimiterStats {
+            read: self.read.stats(),
+            write: self.write.stats(),
+            ingest: self.ingest.stats(),
+            queue_timeout_ms: self.queue_timeout.as_millis() as u64,
+            retry_after_secs: self.retry_after_secs,
+        }
+    }
+}
+
+impl Default for RequestLimiter {
+    fn default() -> Self {
+        Self::new(&ConcurrencyLimits::default())
+    }
+}
+
+/// Axum middleware applying the [`RequestLimiter`] of the application state.
+///
+/// Holds an in-flight slot for the route class of the request until the
+/// response has been produced.
+pub async fn limit_middleware(
+    axum::extract::State(state): axum::extract::State<AppState>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    let Some(class) = RouteClass::classify(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    match state.limiter.acquire(class).await {
+        Ok(_permit) => next.run(request).await,
+        Err(e) => e.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Method;
+
+    #[test]
+    fn test_classify_routes() {
+        assert_eq!(RouteClass::classify(&Method::GET, "/health"), None);
+        assert_eq!(RouteClass::classify(&Method::GET, "/openapi.json"), None);
+        assert_eq!(RouteClass::classify(&Method::GET, "/nodes"), Some(RouteClass::Read));
+        assert_eq!(RouteClass::classify(&Method::POST, "/query"), Some(RouteClass::Read));
+        assert_eq!(RouteClass::classify(&Method::POST, "/graph/match"), Some(RouteClass::Read));
+        assert_eq!(RouteClass::classify(&Method::POST, "/nodes"), Some(RouteClass::Write));
+        assert_eq!(
+            RouteClass::classify(&Method::DELETE, "/nodes/abc"),
+            Some(RouteClass::Write)
+        );
+        assert_eq!(RouteClass::classify(&Method::POST, "/documents"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/bulk"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/import"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/transactions"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/nodes/update"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/ingest/url"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/ingest/file"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/pipelines/docs/run"), Some(RouteClass::Ingest));
+        assert_eq!(RouteClass::classify(&Method::POST, "/v1/files"), Some(RouteClass::Ingest));
+        assert_eq!(
+            RouteClass::classify(&Method::POST, "/v1/vector_stores/vs_1/files"),
+            Some(RouteClass::Ingest)
+        );
+        assert_eq!(
+            RouteClass::classify(&Method::POST, "/v1/vector_stores/vs_1/search"),
+            Some(RouteClass::Read)
+        );
+        assert_eq!(
+            RouteClass::classify(&Method::PATCH, "/nodes/abc"),
+            Some(RouteClass::Write)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_acquire_queues_then_rejects() {
+        let limits = ConcurrencyLimits {
+            ingest: RouteLimits::new(1, 1),
+            queue_timeout_ms: 20,
+            retry_after_secs: 3,
+            ..ConcurrencyLimits::unlimited()
+        };
+        let limiter = RequestLimiter::new(&limits);
+
+        let held = limiter.acquire(RouteClass::Ingest).await.unwrap();
+        assert!(held.is_some());
+        assert_eq!(limiter.stats().ingest.in_flight, 1);
+
+        // The single queue slot times out
+        let err = limiter.acquire(RouteClass::Ingest).await.unwrap_err();
+        assert!(matches!(err, ApiError::Overloaded { retry_after_secs: 3, .. }));
+
+        drop(held);
+        assert!(limiter.acquire(RouteClass::Ingest).await.is_ok());
+        assert!(limiter.acquire(RouteClass::Read).await.unwrap().is_none());
+
+        let stats = limiter.stats().ingest;
+        assert_eq!(stats.in_flight, 0);
+        assert_eq!(stats.queued, 0);
+        assert_eq!(stats.admitted, 2);
+        assert_eq!(stats.rejected, 1);
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_rejects_immediately() {
+        let limits = ConcurrencyLimits {
+            write: RouteLimits::new(1, 0),
+            ..ConcurrencyLimits::unlimited()
+        };
+        let limiter = RequestLimiter::new(&limits);
+
+        let _held = limiter.acquire(RouteClass::Write).await.unwrap();
+        let started = std::time::Instant::now();
+        assert!(limiter.acquire(RouteClass::Write).await.is_err());
+        assert!(started.elapsed() < Duration::from_millis(limits.queue_timeout_ms));
+    }
+}
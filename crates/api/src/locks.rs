@@ -0,0 +1,95 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Locks held per key while a value is read, changed and written back.
+//!
+//! Updates of one node (or upserts of one external ID) must not interleave,
+//! or a version check could pass for two writers at once. A single lock for
+//! all of them would also serialize updates of unrelated nodes, so each key
+//! gets its own lock, created on first use and dropped once nobody holds or
+//! waits for it.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, Weak};
+
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+/// A lock per key.
+#[derive(Debug)]
+pub(crate) struct KeyedLocks<K> {
+    locks: Mutex<HashMap<K, Weak<AsyncMutex<()>>>>,
+}
+
+impl<K> Default for KeyedLocks<K> {
+    fn default() -> Self {
+        Self {
+            locks: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash> KeyedLocks<K> {
+    /// Wait for the lock of `key`; it is released when the guard drops.
+    pub(crate) async fn lock(&self, key: K) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+            // Forget locks nobody holds or waits for any more
+            locks.retain(|_, lock| lock.strong_count() > 0);
+            match locks.get(&key).and_then(Weak::upgrade) {
+                Some(lock) => lock,
+                None => {
+                    let lock = Arc::new(AsyncMutex::new(()));
+                    locks.insert(key, Arc::downgrade(&lock));
+                    lock
+                }
+            }
+        };
+        lock.lock_owned().await
+    }
+
+    /// Number of keys whose lock is held or waited for.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        let locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+        locks
+            .values()
+            .filter(|lock| lock.strong_count() > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_locks_are_per_key() {
+        let locks = Arc::new(KeyedLocks::default());
+        let held = locks.lock(1).await;
+
+        // Another key is free while the first is held
+        let other = tokio::time::timeout(Duration::from_millis(100), locks.lock(2)).await;
+        assert!(other.is_ok());
+        drop(other);
+
+        // The same key waits for the holder
+        let waiter = tokio::spawn({
+            let locks = Arc::clone(&locks);
+            async move {
+                let _guard = locks.lock(1).await;
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+        drop(held);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(locks.len(), 0);
+    }
+}
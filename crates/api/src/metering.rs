@@ -0,0 +1,400 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Usage metering per API key.
+//!
+//! Requests are metered to an account: the tenant their `x-api-key` header
+//! is mapped to in the configuration, the key itself for an unmapped key
+//! (shown by its last characters only), or `anonymous` without a key. Per
+//! account and UTC day the meter counts the nodes created, the tokens
+//! embedded (estimated at four characters per token), the queries run and
+//! the bytes stored (node content and embeddings), for chargeback and quota
+//! decisions. With persistence each day's counts are kept in the metadata
+//! column family under `metering:` and reloaded at startup.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use axum::extract::State;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use synton_core::Node;
+
+use crate::models::UsageExportQuery;
+use crate::rest::AppState;
+
+/// Request header naming the API key requests are metered to.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Account of requests without an API key.
+pub const ANONYMOUS_ACCOUNT: &str = "anonymous";
+
+/// Metadata key prefix of the daily counts.
+pub(crate) const METERING_KEY_PREFIX: &str = "metering:";
+
+/// Rough characters per token of embedded text.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Characters of an unmapped API key kept in its account name.
+const KEY_SUFFIX_CHARS: usize = 4;
+
+/// Endpoints counted as queries when they succeed.
+const QUERY_ROUTES: &[&str] = &[
+    "/query",
+    "/query/execute",
+    "/traverse",
+    "/reason/paths",
+    "/graph/match",
+    "/hybrid_search",
+];
+
+/// Header of the CSV export.
+const CSV_HEADER: &str = "date,account,nodes_created,tokens_embedded,queries,bytes_stored";
+
+tokio::task_local! {
+    static API_KEY: Option<String>;
+}
+
+/// Middleware that meters a request to the API key in its `x-api-key`
+/// header, counting a query when a query endpoint succeeds.
+pub async fn metering_middleware(
+    State(state): State<AppState>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let api_key = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(str::to_string);
+    let query =
+        request.method() == axum::http::Method::POST && is_query_route(request.uri().path());
+
+    with_api_key(api_key, async move {
+        let response = next.run(request).await;
+        if query && response.status().is_success() {
+            state.service.meter(MeteredUsage::query()).await;
+        }
+        response
+    })
+    .await
+}
+
+/// Run `future` on behalf of the holder of `api_key`.
+pub(crate) async fn with_api_key<F: std::future::Future>(
+    api_key: Option<String>,
+    future: F,
+) -> F::Output {
+    API_KEY.scope(api_key, future).await
+}
+
+/// API key of the current request, if it named one.
+pub(crate) fn current_api_key() -> Option<String> {
+    API_KEY.try_with(Clone::clone).ok().flatten()
+}
+
+fn is_query_route(path: &str) -> bool {
+    let path = path.trim_end_matches('/');
+    QUERY_ROUTES.contains(&path)
+        || (path.starts_with("/v1/vector_stores/") && path.ends_with("/search"))
+}
+
+/// Metering settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MeteringConfig {
+    /// Tenant of each API key; usage of unmapped keys is metered per key.
+    pub tenants: HashMap<String, String>,
+}
+
+/// Usage counted for one account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct MeteredUsage {
+    pub nodes_created: u64,
+    pub tokens_embedded: u64,
+    pub queries: u64,
+    pub bytes_stored: u64,
+}
+
+impl MeteredUsage {
+    /// A node created and stored.
+    pub(crate) fn node(node: &Node) -> Self {
+        let embedding = node
+            .embedding
+            .as_ref()
+            .map_or(0, |e| e.len() * std::mem::size_of::<f32>());
+        Self {
+            nodes_created: 1,
+            bytes_stored: (node.content().len() + embedding) as u64,
+            ..Default::default()
+        }
+    }
+
+    /// Text embedded.
+    pub(crate) fn embedding(text: &str) -> Self {
+        Self {
+            tokens_embedded: text.chars().count().div_ceil(CHARS_PER_TOKEN) as u64,
+            ..Default::default()
+        }
+    }
+
+    /// A query run.
+    pub(crate) fn query() -> Self {
+        Self {
+            queries: 1,
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.nodes_created += other.nodes_created;
+        self.tokens_embedded += other.tokens_embedded;
+        self.queries += other.queries;
+        self.bytes_stored += other.bytes_stored;
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Usage of one account on one day, as persisted and exported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct UsageRecord {
+    pub date: NaiveDate,
+    pub account: String,
+    #[serde(flatten)]
+    pub usage: MeteredUsage,
+}
+
+#[derive(Debug)]
+struct MeterState {
+    config: Option<MeteringConfig>,
+    days: BTreeMap<(NaiveDate, String), MeteredUsage>,
+}
+
+impl Default for MeterState {
+    fn default() -> Self {
+        Self {
+            config: Some(MeteringConfig::default()),
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+/// Daily usage per account; enabled with the default settings until
+/// configured otherwise.
+#[derive(Debug, Default)]
+pub(crate) struct Meter {
+    state: Mutex<MeterState>,
+}
+
+impl Meter {
+    fn state(&self) -> MutexGuard<'_, MeterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Enable metering with the given settings, or disable it with `None`.
+    /// Counted usage is kept.
+    pub(crate) fn configure(&self, config: Option<MeteringConfig>) {
+        self.state().config = config;
+    }
+
+    /// Add usage by the holder of `api_key` at `at`, returning the updated
+    /// day's metadata entry to persist. Nothing is counted while disabled.
+    pub(crate) fn record(
+        &self,
+        api_key: Option<&str>,
+        usage: MeteredUsage,
+        at: DateTime<Utc>,
+    ) -> Option<(String, Vec<u8>)> {
+        if usage.is_empty() {
+            return None;
+        }
+        let mut state = self.state();
+        let account = account(state.config.as_ref()?, api_key);
+        let date = at.date_naive();
+        let day = state.days.entry((date, account.clone())).or_default();
+        day.add(&usage);
+        let record = UsageRecord {
+            date,
+            account,
+            usage: *day,
+        };
+        let value = serde_json::to_vec(&record).ok()?;
+        Some((record_key(&record), value))
+    }
+
+    /// Tenant `api_key` is mapped to, if metering is enabled and maps it.
+    pub(crate) fn tenant(&self, api_key: Option<&str>) -> Option<String> {
+        let state = self.state();
+        state.config.as_ref()?.tenants.get(api_key?).cloned()
+    }
+
+    /// Restore persisted daily counts, returning how many were loaded.
+    /// Malformed entries are skipped.
+    pub(crate) fn load(&self, entries: Vec<(Vec<u8>, Vec<u8>)>) -> usize {
+        let mut state = self.state();
+        let mut loaded = 0;
+        for (_, value) in entries {
+            if let Ok(record) = serde_json::from_slice::<UsageRecord>(&value) {
+                state
+                    .days
+                    .insert((record.date, record.account), record.usage);
+                loaded += 1;
+            }
+        }
+        loaded
+    }
+
+    /// Daily usage matching a query, by date then account.
+    pub(crate) fn records(&self, query: &UsageExportQuery) -> Vec<UsageRecord> {
+        self.state()
+            .days
+            .iter()
+            .filter(|((date, account), _)| {
+                query.since.map_or(true, |since| *date >= since)
+                    && query.until.map_or(true, |until| *date <= until)
+                    && query.account.as_deref().map_or(true, |a| a == account)
+            })
+            .map(|((date, account), usage)| UsageRecord {
+                date: *date,
+                account: account.clone(),
+                usage: *usage,
+            })
+            .collect()
+    }
+}
+
+/// Account usage by the holder of `api_key` is metered to.
+fn account(config: &MeteringConfig, api_key: Option<&str>) -> String {
+    let Some(key) = api_key else {
+        return ANONYMOUS_ACCOUNT.to_string();
+    };
+    if let Some(tenant) = config.tenants.get(key) {
+        return tenant.clone();
+    }
+    let chars: Vec<char> = key.chars().collect();
+    let suffix: String = chars[chars.len().saturating_sub(KEY_SUFFIX_CHARS)..]
+        .iter()
+        .collect();
+    format!("key...{}", suffix)
+}
+
+fn record_key(record: &UsageRecord) -> String {
+    format!("{}{}:{}", METERING_KEY_PREFIX, record.date, record.account)
+}
+
+/// Usage records as CSV, with a header line.
+pub(crate) fn to_csv(records: &[UsageRecord]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for record in records {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            record.date,
+            csv_field(&record.account),
+            record.usage.nodes_created,
+            record.usage.tokens_embedded,
+            record.usage.queries,
+            record.usage.bytes_stored
+        ));
+    }
+    csv
+}
+
+/// A CSV field, quoted if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    fn day(date: &str) -> DateTime<Utc> {
+        format!("{}T12:00:00Z", date).parse().unwrap()
+    }
+
+    #[test]
+    fn test_accounts() {
+        let config = MeteringConfig {
+            tenants: HashMap::from([("sk-team-a-123456".to_string(), "team-a".to_string())]),
+        };
+        assert_eq!(account(&config, None), ANONYMOUS_ACCOUNT);
+        assert_eq!(account(&config, Some("sk-team-a-123456")), "team-a");
+        assert_eq!(account(&config, Some("sk-other-9876")), "key...9876");
+        assert_eq!(account(&config, Some("ab")), "key...ab");
+    }
+
+    #[test]
+    fn test_aggregates_daily() {
+        let meter = Meter::default();
+        let node = Node::new("Twelve bytes", NodeType::Concept).with_embedding(vec![0.0; 4]);
+
+        meter.record(None, MeteredUsage::node(&node), day("2025-03-01"));
+        meter.record(
+            None,
+            MeteredUsage::embedding("nine char"),
+            day("2025-03-01"),
+        );
+        meter.record(Some("key-1234"), MeteredUsage::query(), day("2025-03-01"));
+        let (key, _) = meter
+            .record(None, MeteredUsage::query(), day("2025-03-02"))
+            .unwrap();
+        assert_eq!(key, "metering:2025-03-02:anonymous");
+
+        let records = meter.records(&UsageExportQuery::default());
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records[0].usage,
+            MeteredUsage {
+                nodes_created: 1,
+                tokens_embedded: 3,
+                queries: 0,
+                bytes_stored: 28,
+            }
+        );
+        assert_eq!(records[1].account, "key...1234");
+
+        let query = UsageExportQuery {
+            since: Some("2025-03-02".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(meter.records(&query).len(), 1);
+
+        meter.configure(None);
+        assert!(meter
+            .record(None, MeteredUsage::query(), day("2025-03-02"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_load_and_csv() {
+        let meter = Meter::default();
+        let mut entries = Vec::new();
+        for at in ["2025-03-01", "2025-03-01"] {
+            let (key, value) = meter
+                .record(Some("a,\"b\""), MeteredUsage::query(), day(at))
+                .unwrap();
+            entries.push((key.into_bytes(), value));
+        }
+        entries.push((b"metering:bad".to_vec(), b"{}".to_vec()));
+
+        let restored = Meter::default();
+        assert_eq!(restored.load(entries), 2);
+        let records = restored.records(&UsageExportQuery::default());
+        assert_eq!(records, meter.records(&UsageExportQuery::default()));
+        assert_eq!(
+            to_csv(&records),
+            format!("{}\n2025-03-01,\"key...,\"\"b\"\"\",0,0,2,0\n", CSV_HEADER)
+        );
+    }
+}
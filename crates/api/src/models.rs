@@ -3,9 +3,13 @@
 // Licensed under the Apache License, Version 2.0 (the "License");
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
-use synton_core::{Edge, Node, NodeType, Relation};
+use synton_core::{
+    Edge, EdgeCreator, EdgeProvenance, Filter, Node, NodeType, ReasoningPath, Relation,
+};
+use synton_storage::Durability;
 
 /// Request to add a node to the database.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +25,15 @@ pub struct AddNodeRequest {
 
     /// Optional attributes.
     pub attributes: Option<serde_json::Value>,
+
+    /// Identifier in an external system. A node already stored under it is
+    /// updated in place instead of a new node being created.
+    #[serde(default)]
+    pub external_id: Option<String>,
+
+    /// Tags; normalized to lowercase.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl AddNodeRequest {
@@ -31,6 +44,8 @@ impl AddNodeRequest {
             node_type,
             embedding: None,
             attributes: None,
+            external_id: None,
+            tags: Vec::new(),
         }
     }
 
@@ -45,6 +60,18 @@ impl AddNodeRequest {
         self.attributes = Some(attributes);
         self
     }
+
+    /// Upsert by an external system identifier.
+    pub fn with_external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Tag the node.
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags.extend(tags.into_iter().map(Into::into));
+        self
+    }
 }
 
 /// Response from adding a node.
@@ -55,6 +82,48 @@ pub struct AddNodeResponse {
 
     /// Whether the node was newly created or already existed.
     pub created: bool,
+
+    /// Possible secrets found in the content, if scrubbing is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scrub: Option<ScrubReport>,
+}
+
+/// What to do with content containing possible secrets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrubAction {
+    /// Refuse the content.
+    Reject,
+
+    /// Replace each secret with a `[REDACTED:<rule>]` placeholder.
+    #[default]
+    Redact,
+
+    /// Store the content unchanged and tag it `contains-secret`.
+    Tag,
+}
+
+/// A possible secret found in content; the value itself is not reported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretFinding {
+    /// Rule that found it, e.g. `aws_access_key` or `high_entropy`.
+    pub rule: String,
+
+    /// Offset of the first character in the submitted content.
+    pub start: usize,
+
+    /// Offset just past the last character in the submitted content.
+    pub end: usize,
+}
+
+/// Possible secrets found in submitted content and what was done.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScrubReport {
+    /// Action taken.
+    pub action: ScrubAction,
+
+    /// What was found, in order.
+    pub findings: Vec<SecretFinding>,
 }
 
 /// Request to add an edge to the database.
@@ -75,6 +144,10 @@ pub struct AddEdgeRequest {
 
     /// Optional relation vector.
     pub vector: Option<Vec<f32>>,
+
+    /// Who created the edge and where it came from.
+    #[serde(default)]
+    pub provenance: EdgeProvenance,
 }
 
 impl Default for AddEdgeRequest {
@@ -85,6 +158,7 @@ impl Default for AddEdgeRequest {
             relation: Relation::SimilarTo,
             weight: default_weight(),
             vector: None,
+            provenance: EdgeProvenance::default(),
         }
     }
 }
@@ -96,8 +170,43 @@ fn default_weight() -> f32 {
 /// Response from adding an edge.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddEdgeResponse {
-    /// The created edge.
+    /// The created edge; `pending_review` is set if it was held for review
+    /// instead of joining the graph.
+    pub edge: Edge,
+}
+
+/// Response listing the edges awaiting review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEdgesResponse {
+    /// Pending edges, oldest first.
+    pub edges: Vec<Edge>,
+
+    /// Number of pending edges.
+    pub count: usize,
+}
+
+/// Request to approve or reject a pending edge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEdgeRequest {
+    /// Source node ID.
+    pub source: Uuid,
+
+    /// Target node ID.
+    pub target: Uuid,
+
+    /// Relation type.
+    pub relation: Relation,
+}
+
+/// Outcome of reviewing a pending edge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEdgeResponse {
+    /// The reviewed edge.
     pub edge: Edge,
+
+    /// Whether the edge was approved into the graph (or rejected and
+    /// deleted).
+    pub approved: bool,
 }
 
 /// Request to query the database.
@@ -109,8 +218,73 @@ pub struct QueryRequest {
     /// Maximum number of results.
     pub limit: Option<usize>,
 
-    /// Whether to include metadata in results.
+    /// Whether to include match explanations in results.
+    #[serde(default)]
+    pub include_metadata: bool,
+
+    /// Whether to also search archived (cold tier) nodes.
+    #[serde(default)]
+    pub include_archived: bool,
+
+    /// Only estimate the number of matches; no nodes are returned.
+    #[serde(default)]
+    pub count_only: bool,
+
+    /// Return a uniform random sample of this many matches instead of the
+    /// top results, with an estimated total count.
+    #[serde(default)]
+    pub sample: Option<usize>,
+
+    /// Ranking expression, e.g. `0.6*similarity + 0.4*recency`; overrides
+    /// any `rank by` clause in the query.
+    #[serde(default)]
+    pub rank: Option<String>,
+
+    /// Only return nodes whose confidence is at least this value.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Share of the score (0.0 - 1.0) taken from each node's current memory
+    /// strength, so recently used nodes outrank stale matches.
+    #[serde(default)]
+    pub recency_weight: Option<f32>,
+
+    /// Only return nodes in this namespace or, as configured, the
+    /// namespaces below or above it (`org/team` includes `org/team/api`).
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// Request to run a query given as a PaQL AST instead of text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredQueryRequest {
+    /// The query, as the PaQL parser would produce it.
+    pub query: synton_paql::Query,
+
+    /// Maximum number of results.
+    #[serde(default)]
+    pub limit: Option<usize>,
+
+    /// Whether to include match explanations in results.
+    #[serde(default)]
     pub include_metadata: bool,
+
+    /// Whether to also search archived (cold tier) nodes.
+    #[serde(default)]
+    pub include_archived: bool,
+
+    /// Only return nodes whose confidence is at least this value.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+
+    /// Share of the score (0.0 - 1.0) taken from each node's current memory
+    /// strength, so recently used nodes outrank stale matches.
+    #[serde(default)]
+    pub recency_weight: Option<f32>,
+
+    /// Only return nodes in this namespace or, as configured, the
+    /// namespaces below or above it (`org/team` includes `org/team/api`).
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 /// Response from a database query.
@@ -127,6 +301,182 @@ pub struct QueryResponse {
 
     /// Whether results were truncated.
     pub truncated: bool,
+
+    /// ID used to submit relevance feedback for these results.
+    pub query_id: Uuid,
+
+    /// Whether `total_count` is an estimate from a partial scan.
+    #[serde(default)]
+    pub approximate: bool,
+
+    /// Why each returned node matched, in result order; only filled in when
+    /// `include_metadata` is set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches: Vec<MatchExplanation>,
+
+    /// Whether the query was natural language the PaQL parser rejected and
+    /// was translated into PaQL before running.
+    #[serde(default)]
+    pub translated: bool,
+
+    /// PaQL the query was translated into, when `translated` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paql: Option<String>,
+
+    /// Immediate neighbors of each returned node, in result order; only
+    /// filled in when `?expand=1` is passed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub neighbors: Vec<ResultNeighbors>,
+}
+
+/// Query parameters attaching the graph neighborhood of each result.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExpandQuery {
+    /// Hops of neighborhood attached to each result: `0` (default) for
+    /// none or `1` for its immediate neighbors.
+    #[serde(default)]
+    pub expand: usize,
+
+    /// Most neighbors attached per result, strongest edges first
+    /// (default 5).
+    #[serde(default)]
+    pub expand_limit: Option<usize>,
+}
+
+/// Immediate neighbors of a result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultNeighbors {
+    /// The result node.
+    pub node_id: Uuid,
+
+    /// Its neighbors, strongest edge first.
+    pub neighbors: Vec<NeighborPreview>,
+}
+
+/// A node one edge away from a result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborPreview {
+    /// Node ID.
+    pub node_id: Uuid,
+
+    /// Node type.
+    pub node_type: NodeType,
+
+    /// Node content.
+    pub content: String,
+
+    /// Relation of the edge joining it to the result.
+    pub relation: Relation,
+
+    /// Whether the edge points from the result to the neighbor.
+    pub outgoing: bool,
+
+    /// Weight of the edge.
+    pub weight: f32,
+}
+
+/// Why a node matched a query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchExplanation {
+    /// The matched node.
+    pub node_id: Uuid,
+
+    /// Query terms found in the content.
+    pub terms: Vec<TermMatch>,
+
+    /// Similarity to the query (0.0 - 1.0).
+    pub similarity: Option<f32>,
+
+    /// Space in which the similarity was measured.
+    pub space: Option<MatchSpace>,
+
+    /// Filter predicates the node satisfies.
+    pub filters: Vec<String>,
+}
+
+/// A query term found in node content, as a character range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TermMatch {
+    /// The query term that matched.
+    pub term: String,
+
+    /// Offset of the first matched character.
+    pub start: usize,
+
+    /// Offset just past the last matched character.
+    pub end: usize,
+}
+
+/// Space in which a result's similarity to the query was measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchSpace {
+    /// Cosine similarity of the query and node embeddings.
+    Embedding,
+    /// Share of the query words found in the content.
+    Lexical,
+}
+
+/// Request to prepare a parameterized PaQL query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepareQueryRequest {
+    /// Query template with `$name` placeholders.
+    pub query: String,
+}
+
+/// Response from preparing a query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepareQueryResponse {
+    /// Handle used to execute the prepared query.
+    pub handle: Uuid,
+
+    /// Placeholder names that must be bound on execution.
+    pub params: Vec<String>,
+}
+
+/// Read consistency of a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Consistency {
+    /// Execute the query, seeing every acknowledged write.
+    Strong,
+    /// Serve a cached result, which may miss writes for up to the cache TTL.
+    #[default]
+    Eventual,
+}
+
+/// Query parameters of query execution.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsistencyQuery {
+    /// Read consistency; the server's configured default if unset.
+    pub consistency: Option<Consistency>,
+}
+
+/// Request to execute a prepared query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecuteQueryRequest {
+    /// Handle returned by the prepare call.
+    pub handle: Uuid,
+
+    /// Values bound to the template placeholders.
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+
+    /// Maximum number of results.
+    pub limit: Option<usize>,
+
+    /// Whether to also search archived (cold tier) nodes.
+    #[serde(default)]
+    pub include_archived: bool,
+
+    /// Whether to include match explanations in results.
+    #[serde(default)]
+    pub include_metadata: bool,
+
+    /// Only return nodes in this namespace or, as configured, the
+    /// namespaces below or above it (`org/team` includes `org/team/api`).
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 /// Request for graph traversal.
@@ -143,6 +493,58 @@ pub struct TraverseRequest {
 
     /// Traversal direction.
     pub direction: TraverseDirection,
+
+    /// Only follow edges made by these creators (empty = all).
+    #[serde(default)]
+    pub created_by: Vec<EdgeCreator>,
+
+    /// Reject the traversal if its estimated cost (edges examined) exceeds
+    /// this value.
+    #[serde(default)]
+    pub max_estimated_cost: Option<u64>,
+
+    /// Follow incoming edges as their inverse relation (e.g. `has_part`
+    /// for an incoming `is_part_of`) when traversing backward.
+    #[serde(default)]
+    pub follow_inverses: bool,
+
+    /// Only follow edges whose extraction confidence is at least this value.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+
+    /// How to expand nodes with more edges than the graph's maximum degree.
+    #[serde(default)]
+    pub supernode_strategy: SupernodeStrategy,
+
+    /// Only return nodes in this namespace or, as configured, the
+    /// namespaces below or above it.
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// How a traversal expands a supernode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SupernodeStrategy {
+    /// Follow every edge.
+    Expand,
+
+    /// Follow only the edges within the maximum degree.
+    #[default]
+    Sample,
+
+    /// Reach the supernode without expanding it.
+    Skip,
+}
+
+impl From<SupernodeStrategy> for synton_graph::SupernodeStrategy {
+    fn from(strategy: SupernodeStrategy) -> Self {
+        match strategy {
+            SupernodeStrategy::Expand => synton_graph::SupernodeStrategy::Expand,
+            SupernodeStrategy::Sample => synton_graph::SupernodeStrategy::Sample,
+            SupernodeStrategy::Skip => synton_graph::SupernodeStrategy::Skip,
+        }
+    }
 }
 
 /// Direction for graph traversal.
@@ -192,150 +594,1753 @@ pub struct TraverseResponse {
 
     /// Whether traversal was truncated due to limits.
     pub truncated: bool,
+
+    /// Supernodes whose expansion was sampled or skipped.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supernodes: Vec<SupernodeWarning>,
 }
 
-/// Request to get a node by ID.
+/// A supernode a traversal did not fully expand.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GetNodeRequest {
-    /// Node ID.
+pub struct SupernodeWarning {
+    /// Supernode ID.
     pub id: Uuid,
-}
 
-/// Response with a node.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GetNodeResponse {
-    /// The node if found.
-    pub node: Option<Node>,
+    /// Number of edges beyond the maximum degree in the traversal direction.
+    pub bundled_edges: usize,
+
+    /// Number of those edges per relation.
+    pub relation_counts: HashMap<String, usize>,
 }
 
-/// Request to delete a node.
+/// Dry-run cost estimate of a traversal.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteNodeRequest {
-    /// Node ID.
-    pub id: Uuid,
+pub struct TraverseEstimate {
+    /// Estimated newly reached nodes per depth, starting at depth 1.
+    pub nodes_per_depth: Vec<u64>,
+
+    /// Estimated number of nodes returned.
+    pub estimated_nodes: u64,
+
+    /// Estimated cost: the number of edges examined.
+    pub estimated_cost: u64,
+
+    /// Whether the estimate was cut off by `max_nodes`.
+    pub capped: bool,
+
+    /// Whether the estimate exceeds the request's `max_estimated_cost`.
+    pub exceeds_limit: bool,
 }
 
-/// Response from deleting a node.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteNodeResponse {
-    /// Whether the node was found and deleted.
-    pub deleted: bool,
+/// Query parameters for the graph visualization export.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphVizQuery {
+    /// Node to center the view on; without it the most accessed nodes are
+    /// shown.
+    #[serde(default)]
+    pub center: Option<Uuid>,
 
-    /// ID of the deleted node.
-    pub id: Uuid,
+    /// Hops from the center node in either direction.
+    #[serde(default)]
+    pub depth: Option<usize>,
+
+    /// Maximum number of nodes returned.
+    #[serde(default)]
+    pub limit: Option<usize>,
 }
 
-/// Database statistics.
+/// A node in force-graph format.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DatabaseStats {
-    /// Total number of nodes.
-    pub node_count: usize,
+pub struct VizNode {
+    /// Node ID.
+    pub id: Uuid,
 
-    /// Total number of edges.
-    pub edge_count: usize,
+    /// Short label taken from the node content.
+    pub label: String,
 
-    /// Number of nodes with embeddings.
-    pub embedded_count: usize,
+    /// Node type, used to color nodes by group.
+    pub group: String,
 
-    /// Memory statistics.
-    pub memory_stats: MemoryStats,
+    /// Access score, used to size nodes.
+    pub value: f32,
 }
 
-/// Memory statistics.
+/// A link in force-graph format.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MemoryStats {
-    /// Total nodes being tracked.
-    pub total_nodes: usize,
-
-    /// Active nodes (above threshold).
-    pub active_nodes: usize,
+pub struct VizLink {
+    /// Source node ID.
+    pub source: Uuid,
 
-    /// Decayed nodes (below threshold).
-    pub decayed_nodes: usize,
+    /// Target node ID.
+    pub target: Uuid,
 
-    /// Average access score.
-    pub average_score: f32,
+    /// Relation name.
+    pub relation: String,
 
-    /// Memory load factor.
-    pub load_factor: f32,
+    /// Edge weight.
+    pub weight: f32,
 }
 
-/// Health check response.
+/// Graph neighbourhood in the force-graph JSON format (`nodes` / `links`).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HealthResponse {
-    /// Service status.
-    pub status: String,
+pub struct GraphVizResponse {
+    /// Nodes to draw.
+    pub nodes: Vec<VizNode>,
 
-    /// Version.
-    pub version: String,
+    /// Links between the returned nodes.
+    pub links: Vec<VizLink>,
 
-    /// Uptime in seconds.
-    pub uptime_secs: u64,
+    /// Whether nodes or links were left out to stay within the limits.
+    pub truncated: bool,
 }
 
-/// Bulk operation request.
+/// Request for reasoning paths ending at a target node.
+///
+/// The chains start either at `source` or at the nodes best matching
+/// `query`; exactly one of the two must be given.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BulkOperationRequest {
-    /// Nodes to add.
-    pub nodes: Vec<AddNodeRequest>,
+pub struct ReasonPathsRequest {
+    /// Starting node ID.
+    #[serde(default)]
+    pub source: Option<Uuid>,
 
-    /// Edges to add.
-    pub edges: Vec<AddEdgeRequest>,
-}
+    /// Query whose best matches are used as starting nodes.
+    #[serde(default)]
+    pub query: Option<String>,
 
-/// Bulk operation response.
+    /// Node the chains must end at.
+    pub target: Uuid,
+
+    /// Maximum number of hops per path.
+    #[serde(default = "default_max_hops")]
+    pub max_hops: usize,
+
+    /// Maximum number of paths to return.
+    #[serde(default = "default_path_limit")]
+    pub limit: usize,
+
+    /// Only return paths whose propagated confidence is at least this value.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+
+    /// Whether to also render the paths as Markdown.
+    #[serde(default)]
+    pub markdown: bool,
+}
+
+fn default_max_hops() -> usize {
+    4
+}
+
+fn default_path_limit() -> usize {
+    5
+}
+
+/// Response with ranked reasoning paths.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BulkOperationResponse {
-    /// IDs of created nodes.
-    pub node_ids: Vec<Uuid>,
+pub struct ReasonPathsResponse {
+    /// Paths ordered from most to least confident.
+    pub paths: Vec<ReasoningPath>,
 
-    /// IDs of created edges.
-    pub edge_ids: Vec<String>,
+    /// Starting nodes that were searched.
+    pub sources: Vec<Uuid>,
 
-    /// Number of successful operations.
+    /// Markdown rendering of the paths, if requested.
+    pub markdown: Option<String>,
+}
+
+/// Request to find the bindings of a graph pattern (motif query).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchPatternRequest {
+    /// Pattern such as `(a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c)`.
+    pub pattern: String,
+
+    /// Maximum number of matches to return.
+    #[serde(default = "default_match_limit")]
+    pub limit: usize,
+}
+
+fn default_match_limit() -> usize {
+    100
+}
+
+/// One binding of a pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternMatchResult {
+    /// Node bound to each pattern variable.
+    pub bindings: BTreeMap<String, Node>,
+
+    /// Edge matched by each pattern edge, in pattern order.
+    pub edges: Vec<Edge>,
+}
+
+/// Response with the bindings of a graph pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchPatternResponse {
+    /// Matches found.
+    pub matches: Vec<PatternMatchResult>,
+
+    /// Whether matching stopped at the limit or its work budget.
+    pub truncated: bool,
+}
+
+/// Request to get a node by ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetNodeRequest {
+    /// Node ID.
+    pub id: Uuid,
+}
+
+/// Response with a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetNodeResponse {
+    /// The node if found.
+    pub node: Option<Node>,
+}
+
+/// Request to delete a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteNodeRequest {
+    /// Node ID.
+    pub id: Uuid,
+}
+
+/// Response from deleting a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteNodeResponse {
+    /// Whether the node was found and deleted.
+    pub deleted: bool,
+
+    /// ID of the deleted node.
+    pub id: Uuid,
+}
+
+/// How the attributes of a node update are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeUpdateMode {
+    /// Set the given top-level keys, removing those set to `null`.
+    #[default]
+    Merge,
+
+    /// Replace the attributes entirely.
+    Replace,
+}
+
+/// Request to update a node in place, keeping its ID and edges.
+///
+/// Fields left unset are not changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateNodeRequest {
+    /// ID of the node; taken from the path for `PATCH /nodes/{id}`.
+    #[serde(default)]
+    pub id: Uuid,
+
+    /// New content; the node is re-embedded when it changes.
+    #[serde(default)]
+    pub content: Option<String>,
+
+    /// New node type.
+    #[serde(default)]
+    pub node_type: Option<NodeType>,
+
+    /// Attributes to merge or replace.
+    #[serde(default)]
+    pub attributes: Option<serde_json::Value>,
+
+    /// How `attributes` is applied.
+    #[serde(default)]
+    pub attribute_mode: AttributeUpdateMode,
+
+    /// Pin the node, exempting it from decay and pruning, or unpin it.
+    #[serde(default)]
+    pub pinned: Option<bool>,
+
+    /// Version the node must still have; the update is rejected with a
+    /// conflict if the node changed since.
+    #[serde(default)]
+    pub expected_version: Option<u64>,
+}
+
+impl UpdateNodeRequest {
+    /// Create an update for a node that changes nothing yet.
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    /// Set the new content.
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Set the new node type.
+    pub fn with_node_type(mut self, node_type: NodeType) -> Self {
+        self.node_type = Some(node_type);
+        self
+    }
+
+    /// Merge attributes into the existing ones.
+    pub fn with_attributes(mut self, attributes: serde_json::Value) -> Self {
+        self.attributes = Some(attributes);
+        self.attribute_mode = AttributeUpdateMode::Merge;
+        self
+    }
+
+    /// Replace the existing attributes.
+    pub fn replacing_attributes(mut self, attributes: serde_json::Value) -> Self {
+        self.attributes = Some(attributes);
+        self.attribute_mode = AttributeUpdateMode::Replace;
+        self
+    }
+
+    /// Pin or unpin the node.
+    pub fn with_pinned(mut self, pinned: bool) -> Self {
+        self.pinned = Some(pinned);
+        self
+    }
+
+    /// Apply the update only if the node is still at `version`.
+    pub fn with_expected_version(mut self, version: u64) -> Self {
+        self.expected_version = Some(version);
+        self
+    }
+}
+
+/// Response from updating a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateNodeResponse {
+    /// The updated node.
+    pub node: Node,
+
+    /// Whether the content changed and the node was re-embedded.
+    pub reembedded: bool,
+}
+
+/// Request to update several nodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateNodesRequest {
+    /// Updates to apply, in order.
+    pub updates: Vec<UpdateNodeRequest>,
+}
+
+/// Response from a bulk node update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateNodesResponse {
+    /// The updated nodes.
+    pub nodes: Vec<Node>,
+
+    /// Number of successful updates.
     pub success_count: usize,
 
-    /// Number of failed operations.
+    /// Number of failed updates.
     pub failure_count: usize,
 
     /// Any errors that occurred.
     pub errors: Vec<String>,
 }
 
-/// Hybrid search request.
+/// Database statistics.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HybridSearchRequest {
-    /// Query string.
-    pub query: String,
+pub struct DatabaseStats {
+    /// Total number of nodes.
+    pub node_count: usize,
 
-    /// Maximum number of results.
-    #[serde(default = "default_k")]
-    pub k: usize,
+    /// Total number of edges.
+    pub edge_count: usize,
+
+    /// Number of nodes with embeddings.
+    pub embedded_count: usize,
+
+    /// Number of nodes of each type.
+    #[serde(default)]
+    pub nodes_by_type: HashMap<String, usize>,
+
+    /// Number of edges of each relation.
+    #[serde(default)]
+    pub edges_by_relation: HashMap<String, usize>,
+
+    /// Number of nodes carrying each tag.
+    #[serde(default)]
+    pub nodes_by_tag: HashMap<String, usize>,
+
+    /// Memory statistics.
+    pub memory_stats: MemoryStats,
 }
 
-fn default_k() -> usize {
-    10
+/// Memory statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Total nodes being tracked.
+    pub total_nodes: usize,
+
+    /// Active nodes (above threshold).
+    pub active_nodes: usize,
+
+    /// Decayed nodes (below threshold).
+    pub decayed_nodes: usize,
+
+    /// Average access score.
+    pub average_score: f32,
+
+    /// Memory load factor.
+    pub load_factor: f32,
 }
 
-impl HybridSearchRequest {
-    /// Create a new hybrid search request.
-    pub fn new(query: String, k: usize) -> Self {
-        Self { query, k }
+impl From<synton_memory::MemoryStats> for MemoryStats {
+    fn from(stats: synton_memory::MemoryStats) -> Self {
+        Self {
+            total_nodes: stats.total_nodes,
+            active_nodes: stats.active_nodes,
+            decayed_nodes: stats.decayed_nodes,
+            average_score: stats.average_score,
+            load_factor: stats.load_factor,
+        }
     }
 }
 
-/// Hybrid search response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HybridSearchResponse {
-    /// Retrieved nodes.
-    pub nodes: Vec<Node>,
+/// Memory statistics of one decay profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryProfileStats {
+    /// Profile name: `default`, `namespace:<name>` or `node_type:<type>`.
+    pub profile: String,
+
+    /// Statistics of the nodes assigned to the profile.
+    #[serde(flatten)]
+    pub stats: MemoryStats,
+}
+
+/// Memory statistics with a breakdown per decay profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryStatsResponse {
+    /// Statistics over all tracked nodes.
+    pub total: MemoryStats,
+
+    /// Statistics per decay profile, sorted by profile name.
+    pub profiles: Vec<MemoryProfileStats>,
+}
+
+/// Query parameters for the expiring nodes digest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExpiringQuery {
+    /// Days ahead to look.
+    #[serde(default)]
+    pub days: Option<u32>,
+
+    /// Maximum number of nodes listed.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// A node predicted to decay to its minimum score soon.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiringNode {
+    /// Node ID.
+    pub id: Uuid,
+
+    /// Node content.
+    pub content: String,
+
+    /// Node type.
+    pub node_type: NodeType,
+
+    /// Decay profile of the node.
+    pub profile: String,
+
+    /// Current decayed score.
+    pub current_score: f32,
+
+    /// When the score is predicted to reach the profile's minimum.
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Nodes predicted to become pruning candidates within a number of days,
+/// soonest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpiryDigest {
+    /// When the digest was compiled.
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+
+    /// Days ahead the digest looks.
+    pub days: u32,
+
+    /// Number of expiring nodes, including those beyond the limit.
+    pub total: usize,
+
+    /// Expiring nodes, up to the limit.
+    pub nodes: Vec<ExpiringNode>,
+}
+
+/// A recently executed query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryActivity {
+    /// Query string (or prepared template).
+    pub query: String,
+
+    /// Number of nodes returned.
+    pub result_count: usize,
+
+    /// Query execution time in milliseconds.
+    pub execution_time_ms: u64,
+
+    /// When the query was executed.
+    pub executed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Embedding cache statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingCacheStats {
+    /// Total number of embedding requests.
+    pub total_embeddings: usize,
+
+    /// Number of requests served from the cache.
+    pub cache_hits: usize,
+
+    /// Cache hit rate (0.0-1.0).
+    pub cache_hit_rate: f64,
+
+    /// Number of cache hits served by the persistent cache.
+    #[serde(default)]
+    pub persistent_cache_hits: usize,
+}
+
+/// Recent database activity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityResponse {
+    /// Most recent queries, newest first.
+    pub recent_queries: Vec<QueryActivity>,
+
+    /// Embedding cache statistics, if an embedding service is configured.
+    pub embedding_cache: Option<EmbeddingCacheStats>,
+
+    /// Query result cache statistics, if the cache is enabled.
+    #[serde(default)]
+    pub query_cache: Option<QueryCacheStats>,
+}
+
+/// Runtime and background task metrics.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeDiagnostics {
+    /// Tokio worker threads.
+    pub workers: usize,
+
+    /// Tasks alive on the runtime.
+    pub alive_tasks: usize,
+
+    /// Tasks waiting in the runtime's global queue.
+    pub global_queue_depth: usize,
+
+    /// Depth of each internal work queue.
+    pub queues: Vec<QueueDepth>,
+
+    /// Tick lag of each periodic background task that has run.
+    pub schedulers: Vec<SchedulerLag>,
+
+    /// Embedding backend requests in progress.
+    pub embedding_in_flight: usize,
+
+    /// Most embedding backend requests in progress at once.
+    pub embedding_peak_in_flight: usize,
+}
+
+/// Items waiting in an internal work queue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueDepth {
+    /// Queue name.
+    pub queue: String,
+
+    /// Items waiting or in progress.
+    pub depth: usize,
+}
+
+/// How late a periodic background task ran against its schedule.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchedulerLag {
+    /// Task name.
+    pub scheduler: String,
+
+    /// Ticks run.
+    pub ticks: u64,
+
+    /// Lag of the latest tick in milliseconds.
+    pub last_lag_ms: f64,
+
+    /// Largest lag of a tick in milliseconds.
+    pub max_lag_ms: f64,
+}
+
+/// Query result cache statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryCacheStats {
+    /// Number of cached results.
+    pub entries: usize,
+
+    /// Queries answered from the cache.
+    pub hits: u64,
+
+    /// Queries that had to be executed.
+    pub misses: u64,
+
+    /// Cache hit rate (0.0-1.0).
+    pub hit_rate: f64,
+
+    /// Results dropped because a mutation could change them.
+    pub invalidations: u64,
+
+    /// Results dropped to make room for new ones.
+    pub evictions: u64,
+}
+
+/// Health check response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthResponse {
+    /// Service status.
+    pub status: String,
+
+    /// Version.
+    pub version: String,
+
+    /// Uptime in seconds.
+    pub uptime_secs: u64,
+}
+
+/// Bulk operation request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkOperationRequest {
+    /// Nodes to add.
+    pub nodes: Vec<AddNodeRequest>,
+
+    /// Edges to add.
+    pub edges: Vec<AddEdgeRequest>,
+
+    /// Durability of the writes: `no_wal` trades crash safety for load
+    /// speed, `sync` fsyncs every write.
+    #[serde(default)]
+    pub durability: Durability,
+}
+
+/// Bulk operation response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkOperationResponse {
+    /// IDs of created nodes.
+    pub node_ids: Vec<Uuid>,
+
+    /// IDs of created edges.
+    pub edge_ids: Vec<String>,
+
+    /// Number of successful operations.
+    pub success_count: usize,
+
+    /// Number of failed operations.
+    pub failure_count: usize,
+
+    /// Any errors that occurred.
+    pub errors: Vec<String>,
+}
+
+/// A node or edge streamed in for ingestion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IngestRecord {
+    /// Add a node.
+    Node(AddNodeRequest),
+
+    /// Add an edge between stored nodes or nodes streamed in earlier.
+    Edge(AddEdgeRequest),
+}
+
+/// Reference to a node from a transaction operation.
+///
+/// Either the ID of an existing node or the temporary ID given to a node
+/// added earlier in the same transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NodeRef {
+    /// Existing node ID.
+    Id(Uuid),
+    /// Temporary ID of a node added in the transaction.
+    Temp(String),
+}
+
+/// A single operation in a transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum TransactionOp {
+    /// Add a node.
+    AddNode {
+        /// Temporary ID later operations use to refer to this node.
+        #[serde(default)]
+        temp_id: Option<String>,
+
+        /// Node content.
+        content: String,
+
+        /// Node type.
+        node_type: NodeType,
+
+        /// Optional attributes.
+        #[serde(default)]
+        attributes: Option<serde_json::Value>,
+    },
+
+    /// Add an edge.
+    AddEdge {
+        /// Source node.
+        source: NodeRef,
+
+        /// Target node.
+        target: NodeRef,
+
+        /// Relation type.
+        relation: Relation,
+
+        /// Edge weight (0.0 - 1.0).
+        #[serde(default = "default_weight")]
+        weight: f32,
+
+        /// Who created the edge and where it came from.
+        #[serde(default)]
+        provenance: EdgeProvenance,
+    },
+}
+
+/// Request to apply several node and edge operations atomically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionRequest {
+    /// Operations to apply, in order.
+    pub operations: Vec<TransactionOp>,
+}
+
+/// Response from a committed transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionResponse {
+    /// Created nodes, in operation order.
+    pub nodes: Vec<Node>,
+
+    /// Created edges, in operation order.
+    pub edges: Vec<Edge>,
+
+    /// IDs assigned to the request's temporary node IDs.
+    pub temp_ids: HashMap<String, Uuid>,
+}
+
+/// Hybrid search request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridSearchRequest {
+    /// Query string.
+    pub query: String,
+
+    /// Maximum number of results.
+    #[serde(default = "default_k")]
+    pub k: usize,
+
+    /// Minimum similarity of vector matches; the calibrated threshold of
+    /// the vector index applies if omitted.
+    #[serde(default)]
+    pub min_relevance: Option<f32>,
+
+    /// Only search nodes of this type.
+    #[serde(default)]
+    pub node_type: Option<NodeType>,
+
+    /// Only search nodes in this namespace.
+    #[serde(default)]
+    pub namespace: Option<String>,
+
+    /// Only search nodes carrying every one of these tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn default_k() -> usize {
+    10
+}
+
+impl HybridSearchRequest {
+    /// Create a new hybrid search request.
+    pub fn new(query: String, k: usize) -> Self {
+        Self {
+            query,
+            k,
+            min_relevance: None,
+            node_type: None,
+            namespace: None,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Only search nodes of a type.
+    pub fn with_node_type(mut self, node_type: NodeType) -> Self {
+        self.node_type = Some(node_type);
+        self
+    }
+
+    /// Only search nodes in a namespace.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Only search nodes carrying a tag, in addition to any others.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Filter for the node type, namespace and tags, if any is set.
+    pub fn filter(&self) -> Option<Filter> {
+        let mut filters = Vec::new();
+        if let Some(node_type) = self.node_type {
+            filters.push(Filter::equals("node_type", node_type.to_string()));
+        }
+        if let Some(namespace) = &self.namespace {
+            filters.push(Filter::equals("namespace", namespace.as_str()));
+        }
+        for tag in &self.tags {
+            filters.push(Filter::equals("tags", tag.trim().to_lowercase()));
+        }
+        match filters.len() {
+            0 => None,
+            1 => filters.pop(),
+            _ => Some(Filter::and(filters)),
+        }
+    }
+}
+
+/// Hybrid search response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridSearchResponse {
+    /// Retrieved nodes.
+    pub nodes: Vec<Node>,
+
+    /// Number of results.
+    pub count: usize,
+
+    /// ID used to submit relevance feedback for these results.
+    pub query_id: Uuid,
+
+    /// Immediate neighbors of each returned node, in result order; only
+    /// filled in when `?expand=1` is passed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub neighbors: Vec<ResultNeighbors>,
+}
+
+/// Relevance feedback for a single retrieved node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackRequest {
+    /// ID of the query that returned the node.
+    pub query_id: Uuid,
+
+    /// The node being rated.
+    pub node_id: Uuid,
+
+    /// Whether the node was useful for the query.
+    pub useful: bool,
+}
+
+/// Response from submitting feedback.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackResponse {
+    /// The node's access score after applying the feedback.
+    pub access_score: f32,
+
+    /// Relation weights that were adjusted, keyed by relation type.
+    pub relation_weights: HashMap<String, f32>,
+}
+
+/// Aggregate relevance feedback statistics.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeedbackStats {
+    /// Total feedback events received.
+    pub total: usize,
+
+    /// Number of results marked useful.
+    pub useful: usize,
+
+    /// Number of results marked not useful.
+    pub not_useful: usize,
+
+    /// Fraction of feedback that was positive (0.0 - 1.0).
+    pub useful_rate: f64,
+
+    /// Number of queries whose results can still receive feedback.
+    pub tracked_queries: usize,
+
+    /// Current learned relation weights, keyed by relation type.
+    pub relation_weights: HashMap<String, f32>,
+}
+
+/// How a node was accessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessKind {
+    /// Returned by a query or search.
+    Query,
+
+    /// Visited by a traversal.
+    Traversal,
+
+    /// Fetched by ID.
+    Get,
+}
+
+/// A recorded access of a node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccessEvent {
+    /// How the node was accessed.
+    pub kind: AccessKind,
+
+    /// Who accessed it, from the `x-actor` request header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+
+    /// When it was accessed.
+    pub accessed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Usage statistics of a node, aggregated from the access log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeUsage {
+    /// Node ID.
+    pub node_id: Uuid,
+
+    /// Accesses of any kind.
+    pub total: u64,
+
+    /// Times the node was returned by a query or search.
+    pub queries: u64,
+
+    /// Times the node was visited by a traversal.
+    pub traversals: u64,
+
+    /// Times the node was fetched by ID.
+    pub gets: u64,
+
+    /// First recorded access.
+    pub first_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Most recent access.
+    pub last_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Accesses per actor; accesses without an actor are not counted here.
+    pub actors: HashMap<String, u64>,
+
+    /// Most recent accesses, newest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent: Vec<AccessEvent>,
+}
+
+/// Query parameters for the top-accessed nodes report.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TopAccessedQuery {
+    /// Maximum number of nodes returned.
+    #[serde(default)]
+    pub limit: Option<usize>,
+
+    /// Rank by accesses of this kind only.
+    #[serde(default)]
+    pub kind: Option<AccessKind>,
+}
+
+/// Most accessed nodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopAccessedResponse {
+    /// Node usage, most accessed first.
+    pub nodes: Vec<NodeUsage>,
+}
+
+/// Kind of mutation recorded in the audit trail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    /// A node was created.
+    CreateNode,
+
+    /// A node was updated.
+    UpdateNode,
+
+    /// A node was deleted.
+    DeleteNode,
+
+    /// Tags were added to a node.
+    TagNode,
+
+    /// A tag was removed from a node.
+    UntagNode,
+
+    /// An edge was created, or held for review.
+    CreateEdge,
+
+    /// A pending edge was approved.
+    ApproveEdge,
+
+    /// A pending edge was rejected.
+    RejectEdge,
+
+    /// A transaction was applied.
+    Transaction,
+
+    /// A document was chunked and stored.
+    IngestDocument,
+
+    /// An export archive was imported.
+    Import,
+
+    /// Decayed nodes were pruned.
+    Prune,
+
+    /// Dangling edges were deleted by an integrity repair.
+    RepairEdges,
+
+    /// Possible secrets were found in absorbed or ingested content.
+    ScrubContent,
+
+    /// A file was attached to a node.
+    AddAttachment,
+
+    /// An attachment was deleted.
+    DeleteAttachment,
+
+    /// A batch of nodes and edges was streamed in.
+    StreamIngest,
+}
+
+/// A mutation recorded in the audit trail.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// Event ID.
+    pub id: Uuid,
+
+    /// When the mutation was applied.
+    pub at: chrono::DateTime<chrono::Utc>,
+
+    /// Who applied it, from the `x-actor` request header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+
+    /// What was done.
+    pub operation: AuditOperation,
+
+    /// IDs of the nodes affected; an edge's source and target.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<Uuid>,
+
+    /// Summary of the request.
+    pub summary: String,
+}
+
+/// Query parameters for the audit trail.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditQuery {
+    /// Only mutations applied at or after this time.
+    #[serde(default)]
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Only mutations applied before this time.
+    #[serde(default)]
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Only mutations applied by this actor.
+    #[serde(default)]
+    pub actor: Option<String>,
+
+    /// Maximum number of events returned.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Audit events matching a query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditResponse {
+    /// Number of matching events, including those beyond the limit.
+    pub total: usize,
+
+    /// Matching events, newest first, up to the limit.
+    pub events: Vec<AuditEvent>,
+}
+
+/// Query parameters for the usage export.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageExportQuery {
+    /// Only usage on or after this day (UTC).
+    #[serde(default)]
+    pub since: Option<chrono::NaiveDate>,
+
+    /// Only usage on or before this day (UTC).
+    #[serde(default)]
+    pub until: Option<chrono::NaiveDate>,
+
+    /// Only usage of this account.
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+/// Query parameters for listing nodes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListNodesQuery {
+    /// Only nodes carrying this tag.
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// Request to tag a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagNodeRequest {
+    /// Tags to add; normalized to lowercase.
+    pub tags: Vec<String>,
+}
+
+/// Tags of a node after a tag change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeTagsResponse {
+    /// Tagged node.
+    pub node_id: Uuid,
+
+    /// All tags of the node, sorted.
+    pub tags: Vec<String>,
+}
+
+/// Query parameters for a node inspection.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InspectNodeQuery {
+    /// Number of similar nodes returned.
+    #[serde(default)]
+    pub similar: Option<usize>,
+}
+
+/// Memory state of an inspected node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeMemory {
+    /// Decay profile the node was assigned.
+    pub profile: String,
+
+    /// Access score as of the last access.
+    pub access_score: f32,
+
+    /// Access score decayed to now.
+    pub current_score: f32,
+
+    /// Current memory strength (0.0 - 1.0).
+    pub strength: f32,
+
+    /// Last access that reinforced the node.
+    pub accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Whether the node is exempt from decay and pruning.
+    pub pinned: bool,
+}
+
+/// A node similar to an inspected node.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarNode {
+    /// Node ID.
+    pub node_id: Uuid,
+
+    /// Node content.
+    pub content: String,
+
+    /// Similarity score from the vector index.
+    pub score: f32,
+}
+
+/// A node with its memory state, edges, similar nodes and usage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeInspection {
+    /// The node.
+    pub node: Node,
+
+    /// Memory state of the node.
+    pub memory: NodeMemory,
+
+    /// Outgoing edges, grouped by relation.
+    pub outgoing: BTreeMap<String, Vec<Edge>>,
+
+    /// Incoming edges, grouped by relation.
+    pub incoming: BTreeMap<String, Vec<Edge>>,
+
+    /// Nearest neighbours in the vector index, most similar first.
+    pub similar: Vec<SimilarNode>,
+
+    /// Usage of the node, with its recent accesses.
+    pub usage: NodeUsage,
+
+    /// Attachments of the node, oldest first.
+    #[serde(default)]
+    pub attachments: Vec<AttachmentInfo>,
+}
+
+/// A file attached to a node, read from a multipart form.
+#[derive(Debug, Clone)]
+pub struct AttachNodeRequest {
+    /// Node to attach the file to.
+    pub node_id: Uuid,
+
+    /// Name of the uploaded file.
+    pub file_name: String,
+
+    /// MIME type sent with the file.
+    pub content_type: Option<String>,
+
+    /// File contents.
+    pub data: Vec<u8>,
+}
+
+/// Metadata of an attachment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentInfo {
+    /// Attachment ID.
+    pub id: Uuid,
+
+    /// Node the attachment belongs to.
+    pub node_id: Uuid,
+
+    /// File name.
+    pub name: String,
+
+    /// MIME type.
+    pub content_type: String,
+
+    /// Size in bytes.
+    pub size: usize,
+
+    /// When the attachment was added.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Attachments of a node.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttachmentsResponse {
+    /// Attachments, oldest first.
+    pub attachments: Vec<AttachmentInfo>,
+
+    /// Total size of the attachments in bytes.
+    pub total_bytes: usize,
+}
+
+/// Response from deleting an attachment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteAttachmentResponse {
+    /// Whether the attachment was found and deleted.
+    pub deleted: bool,
+
+    /// ID of the deleted attachment.
+    pub id: Uuid,
+}
+
+/// Query parameters for a snapshot export.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportQuery {
+    /// Include node embeddings in the archive.
+    #[serde(default)]
+    pub include_embeddings: bool,
+}
+
+/// Result of importing an export archive.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportResponse {
+    /// Nodes restored.
+    pub nodes: usize,
+
+    /// Edges restored.
+    pub edges: usize,
+
+    /// Restored nodes that carried an embedding.
+    pub embeddings: usize,
+
+    /// Nodes skipped because a node with the same ID exists.
+    pub skipped_nodes: usize,
+
+    /// Edges skipped because the same edge exists.
+    pub skipped_edges: usize,
+
+    /// Edges that could not be restored, with the reason.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+/// Result of rebuilding the in-memory state from storage.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReindexResponse {
+    /// Active nodes loaded.
+    pub nodes: usize,
+
+    /// Archived nodes registered with the memory manager.
+    pub archived_nodes: usize,
+
+    /// Edges loaded into the graph.
+    pub edges: usize,
+
+    /// Embeddings written to the vector index.
+    pub vectors: usize,
+
+    /// Vectors removed because their node is no longer stored.
+    pub removed_vectors: usize,
+
+    /// Nodes and edges written while the rebuild ran, carried over when
+    /// the rebuilt structures were swapped in.
+    pub caught_up: usize,
+
+    /// Time taken in milliseconds.
+    pub duration_ms: u64,
+}
+
+/// Request to run vector index maintenance.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OptimizeVectorRequest {
+    /// Run all maintenance steps regardless of thresholds.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Response from vector index maintenance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizeVectorResponse {
+    /// Outcome of the maintenance run.
+    pub report: synton_vector::MaintenanceReport,
+}
+
+/// Request to calibrate the similarity threshold of the vector index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalibrateVectorRequest {
+    /// Embedded nodes sampled (default: 1000).
+    #[serde(default)]
+    pub sample: Option<usize>,
+
+    /// Percentile of the pair scores taken as the threshold (default: 0.95).
+    #[serde(default)]
+    pub percentile: Option<f32>,
+}
+
+/// Calibrated similarity threshold of the vector index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorCalibration {
+    /// Score distribution of the sampled pairs and the derived threshold.
+    #[serde(flatten)]
+    pub calibration: synton_vector::SimilarityCalibration,
+
+    /// When the calibration ran.
+    pub calibrated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Request to evaluate the recall of the vector index.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EvaluateVectorRequest {
+    /// Neighbors retrieved per query (default: 10).
+    #[serde(default)]
+    pub k: Option<usize>,
+
+    /// Embedded nodes used as queries (default: 100).
+    #[serde(default)]
+    pub queries: Option<usize>,
+}
+
+/// Measured recall of the vector index against an exact scan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorEvaluation {
+    /// Recall@k and search latency of the index.
+    #[serde(flatten)]
+    pub evaluation: synton_vector::RecallEvaluation,
+
+    /// When the evaluation ran.
+    pub evaluated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Query parameters for a vector index delta export.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VectorDeltaQuery {
+    /// Checkpoint returned with the previous delta, as `<epoch>:<version>`.
+    /// Without one every vector is exported.
+    #[serde(default)]
+    pub since: Option<String>,
+}
+
+/// Result of applying a vector index delta.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplyVectorDeltaResponse {
+    /// Whether the delta replaced the contents of the index.
+    pub full: bool,
+
+    /// Vectors written.
+    pub upserted: usize,
+
+    /// Vectors removed.
+    pub removed: usize,
+
+    /// Checkpoint of the source index now reached, to export the next
+    /// delta from.
+    pub checkpoint: String,
+
+    /// Vectors in the index afterwards.
+    pub vector_count: usize,
+}
+
+/// Request to compact persistent storage.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompactStorageRequest {
+    /// Column family to compact (all if omitted).
+    #[serde(default)]
+    pub column_family: Option<String>,
+
+    /// Wait for the compaction to finish instead of running it in the
+    /// background.
+    #[serde(default)]
+    pub wait: bool,
+}
+
+/// Response from a compaction request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactStorageResponse {
+    /// Whether this request started a compaction (false if one was
+    /// already running).
+    pub started: bool,
+
+    /// Whether the compaction finished before the response was sent.
+    pub completed: bool,
+
+    /// Compaction time in milliseconds, if it was waited for.
+    pub duration_ms: Option<u64>,
+}
+
+/// Response from a storage migration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrateStorageResponse {
+    /// Format versions and the values rewritten.
+    #[serde(flatten)]
+    pub migration: synton_storage::MigrationReport,
+
+    /// Migration time in milliseconds.
+    pub duration_ms: u64,
+}
+
+/// Request to check stored edges for missing endpoints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntegrityCheckRequest {
+    /// Delete the dangling edges found.
+    #[serde(default)]
+    pub repair: bool,
+}
+
+/// Response from an integrity check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityCheckResponse {
+    /// Stored edges whose source or target node is missing.
+    pub dangling_edges: Vec<Edge>,
+
+    /// Whether the dangling edges were deleted.
+    pub repaired: bool,
+}
+
+/// Request to seed an ontology.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeedRequest {
+    /// Ontology to seed (the bundled starter ontology if omitted).
+    #[serde(default)]
+    pub ontology: Option<crate::Ontology>,
+
+    /// Namespace to seed into (default: `starter`).
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// Namespace of seeded nodes to remove.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeedQuery {
+    /// Namespace (default: `starter`).
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// Response from seeding an ontology.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedResponse {
+    /// Namespace seeded into.
+    pub namespace: String,
+
+    /// Name of the ontology.
+    pub ontology: String,
+
+    /// Whether the ontology was seeded (false if the namespace already
+    /// held seeded nodes).
+    pub seeded: bool,
+
+    /// Nodes created.
+    pub nodes: usize,
+
+    /// Edges created.
+    pub edges: usize,
+}
+
+/// Response from removing seeded nodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnseedResponse {
+    /// Namespace the nodes were seeded into.
+    pub namespace: String,
+
+    /// Nodes removed.
+    pub nodes_removed: usize,
+
+    /// Stored edges to or from them removed.
+    pub edges_removed: usize,
+}
+
+/// Query parameters of operations that can run as a background job.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackgroundQuery {
+    /// Enqueue the operation as a job and respond at once with the job
+    /// instead of waiting for the result.
+    #[serde(default)]
+    pub background: bool,
+}
+
+/// Long-running operation run as a background job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    /// Restore an export archive.
+    Import,
+
+    /// Compact and retrain the vector index.
+    OptimizeVector,
+
+    /// Rebuild the in-memory graph and indexes from storage.
+    Reindex,
+}
+
+/// State of a background job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting for an earlier job to finish.
+    Queued,
+
+    /// Running.
+    Running,
+
+    /// Finished with a result.
+    Succeeded,
+
+    /// Finished with an error.
+    Failed,
+}
+
+impl JobStatus {
+    /// Whether the job has finished.
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
+/// A background job and its progress.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    /// Job ID.
+    pub id: Uuid,
+
+    /// Operation run.
+    pub kind: JobKind,
+
+    /// Current state.
+    pub status: JobStatus,
+
+    /// Work items done, such as archive records imported.
+    pub done: u64,
+
+    /// Work items in total, once known.
+    pub total: Option<u64>,
+
+    /// Percent complete, once the total is known.
+    pub percent: Option<f32>,
+
+    /// When the job was enqueued.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+
+    /// When the job last started running.
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// When the job finished.
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Times the job was resumed after a restart interrupted it.
+    pub resumed: u32,
+
+    /// Result of the operation, as its synchronous endpoint returns it.
+    pub result: Option<serde_json::Value>,
+
+    /// Why the job failed.
+    pub error: Option<String>,
+}
+
+/// Response listing background jobs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobsResponse {
+    /// Jobs, newest first.
+    pub jobs: Vec<Job>,
+
+    /// Number of jobs.
+    pub count: usize,
+}
+
+/// Progress of background re-embedding after an embedding model change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReembedStatus {
+    /// Whether stale embeddings are being regenerated.
+    pub running: bool,
+
+    /// Stale embeddings still queued.
+    pub pending: usize,
+
+    /// Embeddings regenerated so far.
+    pub reembedded: u64,
+
+    /// Embeddings that could not be regenerated.
+    pub failed: u64,
+
+    /// Current embedding model per language route (`""` for the default
+    /// model).
+    pub models: HashMap<String, String>,
+}
+
+/// Storage engine statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageStatsResponse {
+    /// Per column family statistics.
+    pub column_families: Vec<synton_storage::ColumnFamilyStats>,
+
+    /// Total estimated number of keys.
+    pub estimated_keys: u64,
+
+    /// Total size of the SST files in bytes.
+    pub sst_bytes: u64,
+
+    /// Total estimated bytes awaiting compaction.
+    pub pending_compaction_bytes: u64,
+
+    /// Compactions the engine is running right now.
+    pub running_compactions: u64,
+
+    /// Whether a compaction requested through the API is in progress.
+    pub compaction_in_progress: bool,
+
+    /// Sizes of the nodes stored compressed.
+    pub compression: synton_storage::CompressionStats,
+
+    /// Uncompressed over stored size of the compressed nodes.
+    pub compression_ratio: f64,
+}
+
+/// Self-check of the server's embedding backend and vector index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfCheckResponse {
+    /// Server version.
+    pub version: String,
+
+    /// Whether nodes are persisted to storage.
+    pub persistence: bool,
+
+    /// Nodes with an embedding.
+    pub embedded_nodes: usize,
+
+    /// Embedding backend check, absent without an embedding backend.
+    pub embedding: Option<EmbeddingCheck>,
+
+    /// Vector index check, absent without a vector index.
+    pub vector_index: Option<VectorIndexCheck>,
+}
+
+/// Check of the embedding backend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingCheck {
+    /// Backend type (local, openai, ollama).
+    pub backend: String,
+
+    /// Default embedding model.
+    pub model: String,
+
+    /// Dimension of the embeddings.
+    pub dimension: usize,
+
+    /// Why embedding a test text failed, if it did.
+    pub error: Option<String>,
+}
+
+/// Check of the vector index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorIndexCheck {
+    /// Whether the index accepts searches.
+    pub ready: bool,
+
+    /// Dimension of the indexed vectors.
+    pub dimension: usize,
+
+    /// Vectors in the index, absent if counting them failed.
+    pub vectors: Option<usize>,
+
+    /// Why counting the vectors failed, if it did.
+    pub error: Option<String>,
+}
+
+/// Outcome of preloading hot nodes from storage at startup.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PreloadReport {
+    /// Nodes loaded for their access score.
+    pub hot_nodes: usize,
+
+    /// Nodes loaded as 1-hop neighbors of hot nodes.
+    pub neighbor_nodes: usize,
+
+    /// Edges loaded.
+    pub edges: usize,
+
+    /// Time taken (milliseconds).
+    pub duration_ms: u64,
+}
+
+/// Counters for one route class of the request limiter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RouteClassStats {
+    /// Configured in-flight budget (0 = unlimited).
+    pub max_in_flight: usize,
+
+    /// Configured queue length.
+    pub max_queued: usize,
+
+    /// Requests currently executing.
+    pub in_flight: usize,
+
+    /// Requests currently waiting for a slot.
+    pub queued: usize,
+
+    /// Requests admitted since startup.
+    pub admitted: u64,
+
+    /// Requests rejected with 429 since startup.
+    pub rejected: u64,
+}
+
+/// Request limiter statistics.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LimiterStats {
+    /// Read route class (queries, traversals, lookups).
+    pub read: RouteClassStats,
 
-    /// Number of results.
-    pub count: usize,
+    /// Write route class (node/edge mutations).
+    pub write: RouteClassStats,
+
+    /// Ingest route class (documents, bulk loads).
+    pub ingest: RouteClassStats,
+
+    /// How long queued requests wait for a slot (milliseconds).
+    pub queue_timeout_ms: u64,
+
+    /// `Retry-After` value sent with rejected requests (seconds).
+    pub retry_after_secs: u64,
 }
 
 /// Chunking strategy for document ingestion.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ChunkingStrategy {
     /// Fixed-size chunking by character count.
@@ -355,6 +2360,17 @@ pub enum ChunkingStrategy {
     },
 }
 
+impl ChunkingStrategy {
+    /// Short name of the strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fixed { .. } => "fixed",
+            Self::Semantic { .. } => "semantic",
+            Self::Hierarchical { .. } => "hierarchical",
+        }
+    }
+}
+
 impl Default for ChunkingStrategy {
     fn default() -> Self {
         Self::Semantic {
@@ -386,12 +2402,86 @@ pub struct IngestDocumentRequest {
     /// Optional metadata to attach to all chunks.
     #[serde(default)]
     pub metadata: Option<serde_json::Value>,
+
+    /// Namespace recorded on the document and its chunks.
+    #[serde(default)]
+    pub namespace: Option<String>,
+
+    /// Whether to store a summary of the document, written by the
+    /// configured completion model, if it is long enough.
+    #[serde(default)]
+    pub summarize: bool,
+
+    /// Durability of the document and chunk writes.
+    #[serde(default)]
+    pub durability: Durability,
 }
 
 fn default_embed() -> bool {
     true
 }
 
+/// Request to ingest a web page or remote document (HTML, PDF or text).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestUrlRequest {
+    /// URL to fetch (http or https).
+    pub url: String,
+
+    /// Document title (defaults to the page title, then the URL).
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Chunking strategy.
+    #[serde(default)]
+    pub chunking: Option<ChunkingStrategy>,
+
+    /// Whether to generate embeddings for chunks.
+    #[serde(default = "default_embed")]
+    pub embed: bool,
+
+    /// Whether to store a summary of the document, written by the
+    /// configured completion model, if it is long enough.
+    #[serde(default)]
+    pub summarize: bool,
+
+    /// Durability of the document and chunk writes.
+    #[serde(default)]
+    pub durability: Durability,
+}
+
+/// An uploaded file to ingest, read from a multipart form.
+#[derive(Debug, Clone)]
+pub struct IngestFileRequest {
+    /// Name of the uploaded file.
+    pub file_name: String,
+
+    /// MIME type sent with the file.
+    pub content_type: Option<String>,
+
+    /// File contents.
+    pub data: Vec<u8>,
+
+    /// Document title (defaults to the document's own title, then the
+    /// file name).
+    pub title: Option<String>,
+
+    /// Chunking strategy.
+    pub chunking: Option<ChunkingStrategy>,
+
+    /// Whether to generate embeddings for chunks.
+    pub embed: bool,
+
+    /// Namespace recorded on the document and its chunks.
+    pub namespace: Option<String>,
+
+    /// Whether to store a summary of the document, written by the
+    /// configured completion model, if it is long enough.
+    pub summarize: bool,
+
+    /// Durability of the document and chunk writes.
+    pub durability: Durability,
+}
+
 /// Information about a single chunk.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChunkInfo {
@@ -434,6 +2524,475 @@ pub struct IngestDocumentResponse {
 
     /// Processing time in milliseconds.
     pub processing_time_ms: u64,
+
+    /// Summary node of the document, if one was requested and written.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary_id: Option<Uuid>,
+
+    /// Possible secrets found in the content, if scrubbing is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scrub: Option<ScrubReport>,
+}
+
+/// Outcome of an ingestion pipeline run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineRunStatus {
+    /// Every document was ingested.
+    Succeeded,
+
+    /// Some documents were ingested and some failed.
+    PartiallyFailed,
+
+    /// No document could be ingested.
+    Failed,
+}
+
+/// A document a pipeline run failed to ingest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineRunError {
+    /// URL or file path of the document, or the pipeline name when its
+    /// source could not be listed.
+    pub source: String,
+
+    /// Why ingestion failed.
+    pub error: String,
+}
+
+/// A run of an ingestion pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineRun {
+    /// Run ID.
+    pub id: Uuid,
+
+    /// Pipeline name.
+    pub pipeline: String,
+
+    /// When the run started.
+    pub started_at: chrono::DateTime<chrono::Utc>,
+
+    /// When the run finished.
+    pub finished_at: chrono::DateTime<chrono::Utc>,
+
+    /// Outcome.
+    pub status: PipelineRunStatus,
+
+    /// Documents ingested, in source order.
+    pub document_ids: Vec<Uuid>,
+
+    /// Chunks created across the documents.
+    pub chunk_count: usize,
+
+    /// Documents that failed.
+    pub errors: Vec<PipelineRunError>,
+}
+
+/// A configured ingestion pipeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineInfo {
+    /// Pipeline definition.
+    #[serde(flatten)]
+    pub definition: crate::PipelineDefinition,
+
+    /// Latest run, if any.
+    pub last_run: Option<PipelineRun>,
+}
+
+/// Response listing the configured ingestion pipelines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelinesResponse {
+    /// Pipelines, by name.
+    pub pipelines: Vec<PipelineInfo>,
+
+    /// Number of pipelines.
+    pub count: usize,
+}
+
+/// A materialized traversal view with its current members.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterializedView {
+    /// View definition.
+    #[serde(flatten)]
+    pub definition: crate::ViewDefinition,
+
+    /// Member nodes, in traversal order.
+    pub nodes: Vec<Node>,
+
+    /// Edges between member nodes.
+    pub edges: Vec<Edge>,
+
+    /// When the membership was last materialized.
+    pub refreshed_at: chrono::DateTime<chrono::Utc>,
+
+    /// Whether this read traversed the view again after a change.
+    pub refreshed: bool,
+}
+
+/// A materialized view's definition and size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewSummary {
+    /// View definition.
+    #[serde(flatten)]
+    pub definition: crate::ViewDefinition,
+
+    /// Member nodes as last materialized.
+    pub node_count: usize,
+
+    /// Edges between members as last materialized.
+    pub edge_count: usize,
+
+    /// Whether a write changed the view since it was last materialized.
+    pub stale: bool,
+
+    /// When the membership was last materialized, if ever.
+    pub refreshed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Response listing the materialized views.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewsResponse {
+    /// Views, by name.
+    pub views: Vec<ViewSummary>,
+
+    /// Number of views.
+    pub count: usize,
+}
+
+/// Run history of an ingestion pipeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRunsResponse {
+    /// Pipeline name.
+    pub pipeline: String,
+
+    /// Runs, newest first.
+    pub runs: Vec<PipelineRun>,
+
+    /// Number of runs.
+    pub count: usize,
+}
+
+/// Query parameters for reconstructing a document.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetDocumentQuery {
+    /// Hierarchy level to reconstruct from (defaults to the shallowest
+    /// level holding source text).
+    #[serde(default)]
+    pub level: Option<usize>,
+}
+
+/// A chunk of a reconstructed document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentChunk {
+    /// Node ID of the chunk.
+    pub node_id: Uuid,
+
+    /// Position of the chunk within the document.
+    pub index: usize,
+
+    /// Hierarchy level (0 = document, 1 = paragraph, 2 = sentence).
+    pub level: usize,
+
+    /// Chunk type.
+    pub chunk_type: String,
+
+    /// Chunk content.
+    pub content: String,
+}
+
+/// A document reconstructed from its chunks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentResponse {
+    /// Document ID (root node).
+    pub document_id: Uuid,
+
+    /// Document title.
+    pub title: Option<String>,
+
+    /// Hierarchy level the content was reconstructed from.
+    pub level: Option<usize>,
+
+    /// Hierarchy levels available for this document.
+    pub levels: Vec<usize>,
+
+    /// Reconstructed document text.
+    pub content: String,
+
+    /// Number of chunks used for the reconstruction.
+    pub chunk_count: usize,
+
+    /// Chunks in document order.
+    pub chunks: Vec<DocumentChunk>,
+}
+
+/// Summary of an ingested document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentSummary {
+    /// Document ID (root node).
+    pub document_id: Uuid,
+
+    /// Document title.
+    pub title: Option<String>,
+
+    /// Chunking strategy used at ingestion.
+    pub chunking: Option<String>,
+
+    /// Number of chunks linked to the document.
+    pub chunk_count: usize,
+
+    /// Ingestion time.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Response listing ingested documents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListDocumentsResponse {
+    /// Documents, most recently ingested first.
+    pub documents: Vec<DocumentSummary>,
+
+    /// Number of documents.
+    pub count: usize,
+}
+
+/// Request to create an OpenAI-compatible vector store.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateVectorStoreRequest {
+    /// Store name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Uploaded files to add to the store.
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+
+    /// Caller-defined key/value pairs.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// An OpenAI-compatible vector store, backed by a namespace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStore {
+    /// Store ID (`vs_...`), also the namespace of its nodes.
+    pub id: String,
+
+    /// Always `vector_store`.
+    pub object: String,
+
+    /// Creation time (Unix seconds).
+    pub created_at: i64,
+
+    /// Store name.
+    pub name: Option<String>,
+
+    /// Total size of the files in the store, in bytes.
+    pub usage_bytes: usize,
+
+    /// Number of files by processing status.
+    pub file_counts: VectorStoreFileCounts,
+
+    /// Always `completed`; files are processed synchronously.
+    pub status: String,
+
+    /// Caller-defined key/value pairs.
+    pub metadata: HashMap<String, String>,
+}
+
+/// Number of files in a vector store by processing status.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VectorStoreFileCounts {
+    /// Files being processed.
+    pub in_progress: usize,
+
+    /// Files ingested successfully.
+    pub completed: usize,
+
+    /// Files that could not be ingested.
+    pub failed: usize,
+
+    /// Files whose processing was cancelled.
+    pub cancelled: usize,
+
+    /// All files.
+    pub total: usize,
+}
+
+/// An uploaded file, held until it is added to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileObject {
+    /// File ID (`file-...`).
+    pub id: String,
+
+    /// Always `file`.
+    pub object: String,
+
+    /// File size in bytes.
+    pub bytes: usize,
+
+    /// Upload time (Unix seconds).
+    pub created_at: i64,
+
+    /// Name of the uploaded file.
+    pub filename: String,
+
+    /// Intended purpose, e.g. `assistants`.
+    pub purpose: String,
+}
+
+/// A file upload, read from a multipart form.
+#[derive(Debug, Clone)]
+pub struct UploadFileRequest {
+    /// Name of the uploaded file.
+    pub file_name: String,
+
+    /// MIME type sent with the file.
+    pub content_type: Option<String>,
+
+    /// File contents.
+    pub data: Vec<u8>,
+
+    /// Intended purpose.
+    pub purpose: String,
+}
+
+/// Request to add an uploaded file to a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateVectorStoreFileRequest {
+    /// ID of the uploaded file.
+    pub file_id: String,
+
+    /// Key/value pairs returned with search results from this file.
+    #[serde(default)]
+    pub attributes: HashMap<String, serde_json::Value>,
+}
+
+/// A file in a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreFile {
+    /// ID of the uploaded file.
+    pub id: String,
+
+    /// Always `vector_store.file`.
+    pub object: String,
+
+    /// Time the file was added (Unix seconds).
+    pub created_at: i64,
+
+    /// Store the file belongs to.
+    pub vector_store_id: String,
+
+    /// File size in bytes.
+    pub usage_bytes: usize,
+
+    /// Always `completed`; files are processed synchronously.
+    pub status: String,
+
+    /// Key/value pairs returned with search results from this file.
+    pub attributes: HashMap<String, serde_json::Value>,
+
+    /// Document node the file was ingested as.
+    pub document_id: Uuid,
+}
+
+/// A page of OpenAI-style list results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListResponse<T> {
+    /// Always `list`.
+    pub object: String,
+
+    /// Items, oldest first.
+    pub data: Vec<T>,
+
+    /// ID of the first item.
+    pub first_id: Option<String>,
+
+    /// ID of the last item.
+    pub last_id: Option<String>,
+
+    /// Always `false`; every item is returned.
+    pub has_more: bool,
+}
+
+/// Response from deleting an OpenAI-style object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedObject {
+    /// ID of the deleted object.
+    pub id: String,
+
+    /// Type of the deleted object, e.g. `vector_store.deleted`.
+    pub object: String,
+
+    /// Always `true`.
+    pub deleted: bool,
+}
+
+/// One or more search queries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SearchQuery {
+    /// A single query.
+    Single(String),
+    /// Several queries; a chunk's score is its best match.
+    Multiple(Vec<String>),
+}
+
+/// Request to search a vector store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreSearchRequest {
+    /// Search query.
+    pub query: SearchQuery,
+
+    /// Maximum number of results (1 - 50).
+    #[serde(default = "default_k")]
+    pub max_num_results: usize,
+}
+
+/// A page of vector store search results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreSearchResponse {
+    /// Always `vector_store.search_results.page`.
+    pub object: String,
+
+    /// The queries searched for.
+    pub search_query: Vec<String>,
+
+    /// Matching chunks, best first.
+    pub data: Vec<VectorStoreSearchResult>,
+
+    /// Always `false`; every result is returned.
+    pub has_more: bool,
+
+    /// Always `None`.
+    pub next_page: Option<String>,
+}
+
+/// A chunk matching a vector store search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreSearchResult {
+    /// File the chunk came from.
+    pub file_id: String,
+
+    /// Name of that file.
+    pub filename: String,
+
+    /// Similarity to the query (0.0 - 1.0).
+    pub score: f32,
+
+    /// Attributes of the file.
+    pub attributes: HashMap<String, serde_json::Value>,
+
+    /// Chunk text.
+    pub content: Vec<SearchResultContent>,
+}
+
+/// A piece of search result content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResultContent {
+    /// Always `text`.
+    #[serde(rename = "type")]
+    pub content_type: String,
+
+    /// Chunk text.
+    pub text: String,
 }
 
 #[cfg(test)]
@@ -452,6 +3011,13 @@ mod tests {
             query: "find AI".to_string(),
             limit: Some(10),
             include_metadata: false,
+            include_archived: false,
+            count_only: false,
+            sample: None,
+            rank: None,
+            min_confidence: None,
+            recency_weight: None,
+            namespace: None,
         };
         assert_eq!(req.query, "find AI");
     }
@@ -0,0 +1,274 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Hierarchical namespaces.
+//!
+//! A namespace is a `/`-separated path such as `org/team/project`. A query
+//! or traversal restricted to a namespace also matches related namespaces
+//! as the configured inheritance allows: by default the namespaces below
+//! it, so a query at `org/team` includes the knowledge of every project of
+//! the team. Access rules restrict namespaces to tenants, the names API keys
+//! are mapped to by the metering settings (see [`crate::metering`]); the
+//! unauthenticated `x-actor` header grants nothing. The rule of the deepest
+//! level at or above a node's namespace decides whether the tenant may read
+//! it, and nodes it may not read are left out of results, whether or not a
+//! namespace was requested.
+
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+use synton_core::Node;
+use synton_memory::NAMESPACE_ATTRIBUTE;
+
+/// Separator of namespace levels.
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// Actor name granting a rule to every actor, including anonymous ones.
+const ANY_ACTOR: &str = "*";
+
+/// Which namespaces a namespace-restricted read also matches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NamespaceInheritance {
+    /// Only the namespace itself.
+    Exact,
+
+    /// The namespace and every namespace below it.
+    #[default]
+    Descendants,
+
+    /// The namespace and every namespace above it.
+    Ancestors,
+
+    /// The namespace and every namespace above or below it.
+    Both,
+}
+
+/// Tenants allowed to read a namespace and the namespaces below it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamespaceRule {
+    /// Namespace the rule applies to.
+    pub namespace: String,
+
+    /// Tenants allowed to read; `*` allows everyone.
+    pub actors: Vec<String>,
+}
+
+/// Namespace resolution settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NamespaceConfig {
+    /// Which related namespaces a restricted read matches.
+    pub inheritance: NamespaceInheritance,
+
+    /// Access rules; namespaces without a rule at or above them are open.
+    pub rules: Vec<NamespaceRule>,
+}
+
+/// Which nodes a read by one actor may return.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct NamespaceScope {
+    namespace: Option<String>,
+    inheritance: NamespaceInheritance,
+    rules: Arc<Vec<NamespaceRule>>,
+    actor: Option<String>,
+}
+
+impl NamespaceScope {
+    /// Whether a node is in scope.
+    pub(crate) fn allows(&self, node: &Node) -> bool {
+        let namespace = node_namespace(node);
+        let matches = match (&self.namespace, namespace) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(requested), Some(namespace)) => match self.inheritance {
+                NamespaceInheritance::Exact => namespace == requested.as_str(),
+                NamespaceInheritance::Descendants => is_within(namespace, requested),
+                NamespaceInheritance::Ancestors => is_within(requested, namespace),
+                NamespaceInheritance::Both => related(namespace, requested),
+            },
+        };
+        matches && namespace.map_or(true, |namespace| self.readable(namespace))
+    }
+
+    /// Whether the actor may read `namespace`.
+    fn readable(&self, namespace: &str) -> bool {
+        let rule = self
+            .rules
+            .iter()
+            .filter(|rule| is_within(namespace, &rule.namespace))
+            .max_by_key(|rule| rule.namespace.len());
+        rule.map_or(true, |rule| {
+            rule.actors
+                .iter()
+                .any(|actor| actor == ANY_ACTOR || self.actor.as_deref() == Some(actor.as_str()))
+        })
+    }
+}
+
+/// Current namespace settings.
+#[derive(Debug, Default)]
+pub(crate) struct Namespaces {
+    config: RwLock<(NamespaceInheritance, Arc<Vec<NamespaceRule>>)>,
+}
+
+impl Namespaces {
+    /// Replace the settings.
+    pub(crate) fn configure(&self, config: NamespaceConfig) {
+        let rules = config
+            .rules
+            .into_iter()
+            .map(|rule| NamespaceRule {
+                namespace: normalize(&rule.namespace).to_string(),
+                actors: rule.actors,
+            })
+            .collect();
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) =
+            (config.inheritance, Arc::new(rules));
+    }
+
+    /// Scope of a read by `actor` restricted to `namespace`, or `None` if
+    /// it may return every node.
+    pub(crate) fn scope(
+        &self,
+        namespace: Option<&str>,
+        actor: Option<String>,
+    ) -> Option<NamespaceScope> {
+        let (inheritance, rules) = self
+            .config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        let namespace = namespace.map(|namespace| normalize(namespace).to_string());
+        if namespace.is_none() && rules.is_empty() {
+            return None;
+        }
+        // Without rules the actor makes no difference, so it is left out
+        // of the scope and of query cache keys derived from it
+        let actor = actor.filter(|_| !rules.is_empty());
+        Some(NamespaceScope {
+            namespace,
+            inheritance,
+            rules,
+            actor,
+        })
+    }
+}
+
+/// Namespace attribute of a node, normalized.
+fn node_namespace(node: &Node) -> Option<&str> {
+    node.attributes
+        .get(NAMESPACE_ATTRIBUTE)
+        .and_then(|v| v.as_str())
+        .map(normalize)
+}
+
+/// A namespace without surrounding separators.
+fn normalize(namespace: &str) -> &str {
+    namespace.trim_matches(NAMESPACE_SEPARATOR)
+}
+
+/// Whether `namespace` is `ancestor` or below it.
+pub(crate) fn is_within(namespace: &str, ancestor: &str) -> bool {
+    let (namespace, ancestor) = (normalize(namespace), normalize(ancestor));
+    ancestor.is_empty()
+        || namespace == ancestor
+        || namespace
+            .strip_prefix(ancestor)
+            .is_some_and(|rest| rest.starts_with(NAMESPACE_SEPARATOR))
+}
+
+/// Whether one of two namespaces is within the other.
+pub(crate) fn related(a: &str, b: &str) -> bool {
+    is_within(a, b) || is_within(b, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    fn node(namespace: &str) -> Node {
+        Node::new(namespace.to_string(), NodeType::Concept)
+            .with_attributes(serde_json::json!({ "namespace": namespace }))
+    }
+
+    fn allowed(scope: &NamespaceScope, namespaces: &[&str]) -> Vec<String> {
+        namespaces
+            .iter()
+            .filter(|namespace| scope.allows(&node(namespace)))
+            .map(|namespace| namespace.to_string())
+            .collect()
+    }
+
+    const TREE: &[&str] = &["org", "org/team", "org/team/api", "org/teamwork", "other"];
+
+    #[test]
+    fn test_is_within() {
+        assert!(is_within("org/team/api", "org/team"));
+        assert!(is_within("org/team/", "/org/team"));
+        assert!(!is_within("org/teamwork", "org/team"));
+        assert!(!is_within("org", "org/team"));
+        assert!(related("org", "org/team"));
+        assert!(!related("org/team", "org/teamwork"));
+    }
+
+    #[test]
+    fn test_inheritance() {
+        let namespaces = Namespaces::default();
+        assert!(namespaces.scope(None, Some("agent".to_string())).is_none());
+
+        let scope = namespaces.scope(Some("org/team"), None).unwrap();
+        assert_eq!(allowed(&scope, TREE), vec!["org/team", "org/team/api"]);
+        assert!(!scope.allows(&Node::new("no namespace".to_string(), NodeType::Concept)));
+
+        for (inheritance, expected) in [
+            (NamespaceInheritance::Exact, vec!["org/team"]),
+            (NamespaceInheritance::Ancestors, vec!["org", "org/team"]),
+            (
+                NamespaceInheritance::Both,
+                vec!["org", "org/team", "org/team/api"],
+            ),
+        ] {
+            namespaces.configure(NamespaceConfig {
+                inheritance,
+                rules: Vec::new(),
+            });
+            let scope = namespaces.scope(Some("org/team"), None).unwrap();
+            assert_eq!(allowed(&scope, TREE), expected, "{:?}", inheritance);
+        }
+    }
+
+    #[test]
+    fn test_access_rules() {
+        let namespaces = Namespaces::default();
+        namespaces.configure(NamespaceConfig {
+            inheritance: NamespaceInheritance::Descendants,
+            rules: vec![
+                NamespaceRule {
+                    namespace: "org/team".to_string(),
+                    actors: vec!["team-bot".to_string()],
+                },
+                NamespaceRule {
+                    namespace: "org/team/api/".to_string(),
+                    actors: vec![ANY_ACTOR.to_string()],
+                },
+            ],
+        });
+
+        // Rules apply without a requested namespace; the deepest one decides
+        let anonymous = namespaces.scope(None, None).unwrap();
+        assert_eq!(
+            allowed(&anonymous, TREE),
+            vec!["org", "org/team/api", "org/teamwork", "other"]
+        );
+        let member = namespaces
+            .scope(Some("org"), Some("team-bot".to_string()))
+            .unwrap();
+        assert_eq!(
+            allowed(&member, TREE),
+            vec!["org", "org/team", "org/team/api", "org/teamwork"]
+        );
+        assert!(anonymous.allows(&Node::new("no namespace".to_string(), NodeType::Concept)));
+    }
+}
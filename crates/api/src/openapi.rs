@@ -25,42 +25,265 @@ use uuid::Uuid;
     paths(
         crate::rest::health_check,
         crate::rest::stats,
+        crate::rest::memory_stats,
+        crate::rest::expiring_nodes,
+        crate::rest::activity,
         crate::rest::add_node,
         crate::rest::get_node,
+        crate::rest::get_node_by_external_id,
+        crate::rest::node_usage,
+        crate::rest::top_accessed,
+        crate::rest::inspect_node,
+        crate::rest::attach_file,
+        crate::rest::list_attachments,
+        crate::rest::get_attachment,
+        crate::rest::delete_attachment,
+        crate::rest::tag_node,
+        crate::rest::untag_node,
         crate::rest::get_all_nodes,
         crate::rest::delete_node,
+        crate::rest::update_node,
+        crate::rest::update_nodes,
         crate::rest::add_edge,
+        crate::rest::review_edges,
+        crate::rest::approve_edge,
+        crate::rest::reject_edge,
         crate::rest::query,
+        crate::rest::prepare_query,
+        crate::rest::execute_query,
+        crate::rest::structured_query,
         crate::rest::traverse,
+        crate::rest::explain_traverse,
+        crate::rest::list_views,
+        crate::rest::create_view,
+        crate::rest::get_view,
+        crate::rest::delete_view,
+        crate::rest::reason_paths,
+        crate::rest::match_pattern,
+        crate::rest::graph_viz,
         crate::rest::hybrid_search,
+        crate::rest::submit_feedback,
+        crate::rest::feedback_stats,
         crate::rest::bulk_operation,
+        crate::rest::apply_transaction,
         crate::rest::ingest_document,
+        crate::rest::ingest_url,
+        crate::rest::ingest_file,
+        crate::rest::list_pipelines,
+        crate::rest::run_pipeline,
+        crate::rest::pipeline_runs,
+        crate::rest::list_documents,
+        crate::rest::get_document,
+        crate::rest::optimize_vector_index,
+        crate::rest::calibrate_vectors,
+        crate::rest::evaluate_vectors,
+        crate::rest::export_vector_delta,
+        crate::rest::apply_vector_delta,
+        crate::rest::storage_stats,
+        crate::rest::self_check,
+        crate::rest::reembed_status,
+        crate::rest::compact_storage,
+        crate::rest::migrate_storage,
+        crate::rest::seed,
+        crate::rest::unseed,
+        crate::rest::check_integrity,
+        crate::rest::reindex,
+        crate::rest::limits_stats,
+        crate::rest::audit_events,
+        crate::rest::usage_export,
+        crate::rest::export_archive,
+        crate::rest::import_archive,
+        crate::rest::list_jobs,
+        crate::rest::get_job,
+        crate::rest::upload_file,
+        crate::rest::delete_file,
+        crate::rest::create_vector_store,
+        crate::rest::list_vector_stores,
+        crate::rest::get_vector_store,
+        crate::rest::delete_vector_store,
+        crate::rest::create_vector_store_file,
+        crate::rest::list_vector_store_files,
+        crate::rest::search_vector_store,
+        crate::instrument::lifecycle_view,
+        crate::instrument::timeline_view,
+        crate::instrument::statistics,
+        crate::instrument::export_trace,
+        crate::instrument::live_traces,
     ),
     components(
         schemas(
             HealthResponse,
             DatabaseStats,
+            MemoryStats,
+            MemoryProfileStats,
+            MemoryStatsResponse,
+            ExpiringNode,
+            ExpiryDigest,
+            QueryActivity,
+            EmbeddingCacheStats,
+            QueryCacheStats,
+            ActivityResponse,
+            NodeType,
             NodeInfo,
             AddNodeRequest,
             AddNodeResponse,
+            ScrubAction,
+            SecretFinding,
+            ScrubReport,
             GetNodeResponse,
             DeleteNodeRequest,
             DeleteNodeResponse,
+            AccessKind,
+            AccessEvent,
+            NodeUsage,
+            TopAccessedResponse,
+            NodeMemory,
+            SimilarNode,
+            NodeInspection,
+            AttachmentForm,
+            AttachmentInfo,
+            AttachmentsResponse,
+            DeleteAttachmentResponse,
+            TagNodeRequest,
+            NodeTagsResponse,
+            AttributeUpdateMode,
+            UpdateNodeRequest,
+            UpdateNodeResponse,
+            BulkUpdateNodesRequest,
+            BulkUpdateNodesResponse,
             EdgeInfo,
+            EdgeCreator,
+            EdgeProvenance,
             AddEdgeRequest,
             AddEdgeResponse,
+            ReviewEdgesResponse,
+            ReviewEdgeRequest,
+            ReviewEdgeResponse,
             QueryRequest,
             QueryResponse,
+            ResultNeighbors,
+            NeighborPreview,
+            MatchExplanation,
+            TermMatch,
+            MatchSpace,
+            PrepareQueryRequest,
+            PrepareQueryResponse,
+            ExecuteQueryRequest,
+            StructuredQueryRequest,
+            PaqlQuery,
+            PaqlQueryNode,
+            PaqlStage,
+            PaqlDirection,
+            PaqlFilter,
+            PaqlFilterField,
+            PaqlComparisonOp,
+            PaqlSortField,
+            PaqlSortFieldType,
+            PaqlSortOrder,
+            PaqlRankExpr,
+            PaqlRankVar,
+            PaqlRankOp,
+            Consistency,
+            TraverseDirection,
+            SupernodeStrategy,
             TraverseRequest,
             TraverseResponse,
+            SupernodeWarning,
+            TraverseEstimate,
+            ViewDefinition,
+            MaterializedView,
+            ViewSummary,
+            ViewsResponse,
+            ReasonPathsRequest,
+            ReasoningPathInfo,
+            ReasonPathsResponse,
+            MatchPatternRequest,
+            PatternMatchInfo,
+            MatchPatternResponse,
+            VizNode,
+            VizLink,
+            GraphVizResponse,
             HybridSearchRequest,
             HybridSearchResponse,
+            FeedbackRequest,
+            FeedbackResponse,
+            FeedbackStats,
+            Durability,
             BulkOperationRequest,
             BulkOperationResponse,
+            TransactionOp,
+            TransactionRequest,
+            TransactionResponse,
             ChunkingStrategy,
             ChunkInfo,
             IngestDocumentRequest,
             IngestDocumentResponse,
+            IngestUrlRequest,
+            IngestFileForm,
+            PipelineInfo,
+            PipelinesResponse,
+            PipelineRunStatus,
+            PipelineRunError,
+            PipelineRun,
+            PipelineRunsResponse,
+            DocumentChunk,
+            DocumentResponse,
+            DocumentSummary,
+            ListDocumentsResponse,
+            OptimizeVectorRequest,
+            OptimizeVectorResponse,
+            MaintenanceReport,
+            CalibrateVectorRequest,
+            VectorCalibration,
+            EvaluateVectorRequest,
+            VectorEvaluation,
+            ApplyVectorDeltaResponse,
+            CompactStorageRequest,
+            CompactStorageResponse,
+            MigrateStorageResponse,
+            SeedRequest,
+            SeedResponse,
+            UnseedResponse,
+            IntegrityCheckRequest,
+            IntegrityCheckResponse,
+            ImportResponse,
+            JobKind,
+            JobStatus,
+            Job,
+            JobsResponse,
+            ColumnFamilyStats,
+            CompressionStats,
+            StorageStatsResponse,
+            SelfCheckResponse,
+            EmbeddingCheck,
+            VectorIndexCheck,
+            ReembedStatus,
+            RouteClassStats,
+            LimiterStats,
+            AuditOperation,
+            AuditEvent,
+            AuditResponse,
+            UploadFileForm,
+            FileObject,
+            CreateVectorStoreRequest,
+            VectorStore,
+            VectorStoreFileCounts,
+            VectorStoreList,
+            CreateVectorStoreFileRequest,
+            VectorStoreFile,
+            VectorStoreFileList,
+            DeletedObject,
+            VectorStoreSearchRequest,
+            VectorStoreSearchResult,
+            SearchResultContent,
+            VectorStoreSearchResponse,
+            LifecycleView,
+            LifecycleResponse,
+            TimelineView,
+            TimelineResponse,
+            InstrumentStatistics,
+            StatisticsResponse,
+            ExportResponse,
         )
     ),
     tags(
@@ -70,10 +293,18 @@ use uuid::Uuid;
         (name = "query", description = "Query and search endpoints"),
         (name = "graph", description = "Graph traversal endpoints"),
         (name = "documents", description = "Document ingestion endpoints"),
+        (name = "admin", description = "Administrative and maintenance endpoints"),
+        (name = "openai", description = "OpenAI-compatible vector store endpoints"),
+        (name = "instrumentation", description = "Trace inspection endpoints"),
     )
 )]
 pub struct ApiDoc;
 
+/// The OpenAPI document of the REST API, as served at `/openapi.json`.
+pub fn openapi_spec() -> utoipa::openapi::OpenApi {
+    <ApiDoc as utoipa::OpenApi>::openapi()
+}
+
 /// Health check response schema.
 #[derive(utoipa::ToSchema, serde::Serialize)]
 pub struct HealthResponse {
@@ -92,6 +323,151 @@ pub struct DatabaseStats {
     pub edge_count: usize,
     /// Number of embedded nodes
     pub embedded_count: usize,
+    /// Number of nodes of each type
+    pub nodes_by_type: std::collections::HashMap<String, usize>,
+    /// Number of edges of each relation
+    pub edges_by_relation: std::collections::HashMap<String, usize>,
+    /// Number of nodes carrying each tag
+    pub nodes_by_tag: std::collections::HashMap<String, usize>,
+}
+
+/// Memory statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MemoryStats {
+    /// Total nodes being tracked
+    pub total_nodes: usize,
+    /// Nodes above their profile's minimum score
+    pub active_nodes: usize,
+    /// Nodes below their profile's minimum score
+    pub decayed_nodes: usize,
+    /// Average access score
+    pub average_score: f32,
+    /// Memory load factor (active / total)
+    pub load_factor: f32,
+}
+
+/// Memory statistics of one decay profile schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MemoryProfileStats {
+    /// Profile name: default, namespace:<name> or node_type:<type>
+    pub profile: String,
+    /// Total nodes assigned to the profile
+    pub total_nodes: usize,
+    /// Nodes above the profile's minimum score
+    pub active_nodes: usize,
+    /// Nodes below the profile's minimum score
+    pub decayed_nodes: usize,
+    /// Average access score
+    pub average_score: f32,
+    /// Memory load factor (active / total)
+    pub load_factor: f32,
+}
+
+/// Memory statistics response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MemoryStatsResponse {
+    /// Statistics over all tracked nodes
+    pub total: MemoryStats,
+    /// Statistics per decay profile
+    pub profiles: Vec<MemoryProfileStats>,
+}
+
+/// Expiring node schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ExpiringNode {
+    /// Node ID
+    pub id: Uuid,
+    /// Node content
+    pub content: String,
+    /// Node type
+    pub node_type: NodeType,
+    /// Decay profile: `default`, `namespace:<name>` or `node_type:<type>`
+    pub profile: String,
+    /// Current decayed score
+    pub current_score: f32,
+    /// When the score is predicted to reach the profile's minimum
+    pub expires_at: String,
+}
+
+/// Expiry digest schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ExpiryDigest {
+    /// When the digest was compiled
+    pub generated_at: String,
+    /// Days ahead the digest looks
+    pub days: u32,
+    /// Number of expiring nodes, including those beyond the limit
+    pub total: usize,
+    /// Expiring nodes, soonest first
+    pub nodes: Vec<ExpiringNode>,
+}
+
+/// Recently executed query schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct QueryActivity {
+    /// Query string (or prepared template)
+    pub query: String,
+    /// Number of nodes returned
+    pub result_count: usize,
+    /// Query execution time in milliseconds
+    pub execution_time_ms: u64,
+    /// Execution timestamp
+    pub executed_at: String,
+}
+
+/// Embedding cache statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct EmbeddingCacheStats {
+    /// Total number of embedding requests
+    pub total_embeddings: usize,
+    /// Number of requests served from the cache
+    pub cache_hits: usize,
+    /// Cache hit rate (0.0-1.0)
+    pub cache_hit_rate: f64,
+    /// Number of cache hits served by the persistent cache
+    pub persistent_cache_hits: usize,
+}
+
+/// Recent activity response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ActivityResponse {
+    /// Most recent queries, newest first
+    pub recent_queries: Vec<QueryActivity>,
+    /// Embedding cache statistics (absent without an embedding service)
+    pub embedding_cache: Option<EmbeddingCacheStats>,
+    /// Query result cache statistics (absent while the cache is disabled)
+    pub query_cache: Option<QueryCacheStats>,
+}
+
+/// Query result cache statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct QueryCacheStats {
+    /// Number of cached results
+    pub entries: usize,
+    /// Queries answered from the cache
+    pub hits: u64,
+    /// Queries that had to be executed
+    pub misses: u64,
+    /// Cache hit rate (0.0-1.0)
+    pub hit_rate: f64,
+    /// Results dropped because a mutation could change them
+    pub invalidations: u64,
+    /// Results dropped to make room for new ones
+    pub evictions: u64,
+}
+
+/// Node type schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeType {
+    /// A named thing (person, company, place)
+    Entity,
+    /// An abstract idea
+    Concept,
+    /// A statement about entities or concepts
+    Fact,
+    /// An unstructured text segment
+    RawChunk,
 }
 
 /// Node information schema.
@@ -102,9 +478,13 @@ pub struct NodeInfo {
     /// Node content
     pub content: String,
     /// Node type
-    pub node_type: String,
+    pub node_type: NodeType,
     /// Creation timestamp
     pub created_at: String,
+    /// Tags, normalized to lowercase; omitted when empty
+    pub tags: Vec<String>,
+    /// Version, starting at 1 and bumped by every update
+    pub version: u64,
 }
 
 /// Edge information schema.
@@ -120,6 +500,34 @@ pub struct EdgeInfo {
     pub relation: String,
     /// Edge weight
     pub weight: f32,
+    /// Who created the edge and where it came from
+    pub provenance: EdgeProvenance,
+    /// Whether the edge awaits review before joining the graph
+    pub pending_review: bool,
+}
+
+/// Edge creator schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeCreator {
+    /// Created directly by a user
+    User,
+    /// Created by an agent through a tool call
+    Tool,
+    /// Created by an ingestion or extraction pipeline
+    ExtractionPipeline,
+}
+
+/// Edge provenance schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+pub struct EdgeProvenance {
+    /// Edge creator
+    pub created_by: EdgeCreator,
+    /// Document the edge was extracted from
+    pub source_document: Option<Uuid>,
+    /// Extraction confidence (0.0 - 1.0)
+    #[schema(minimum = 0.0, maximum = 1.0)]
+    pub confidence: Option<f32>,
 }
 
 /// Add node request schema.
@@ -128,13 +536,17 @@ pub struct AddNodeRequest {
     /// Node content/text
     #[schema(example = "Machine learning is a subset of AI")]
     pub content: String,
-    /// Node type (entity, concept, fact, raw_chunk)
-    #[schema(example = "concept")]
-    pub node_type: String,
+    /// Node type
+    pub node_type: NodeType,
     /// Optional embedding vector (384 dims for default model)
     pub embedding: Option<Vec<f32>>,
     /// Optional attributes as JSON
     pub attributes: Option<serde_json::Value>,
+    /// Optional external system ID; an existing node with it is updated
+    #[schema(example = "JIRA-1234")]
+    pub external_id: Option<String>,
+    /// Tags; normalized to lowercase
+    pub tags: Option<Vec<String>>,
 }
 
 /// Add node response schema.
@@ -144,6 +556,40 @@ pub struct AddNodeResponse {
     pub node: NodeInfo,
     /// Whether the node was newly created
     pub created: bool,
+    /// Possible secrets found in the content, if scrubbing is enabled
+    pub scrub: Option<ScrubReport>,
+}
+
+/// Secret scrubbing action schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrubAction {
+    /// The content was refused
+    Reject,
+    /// Each secret was replaced with a `[REDACTED:<rule>]` placeholder
+    Redact,
+    /// The content was stored unchanged and tagged `contains-secret`
+    Tag,
+}
+
+/// Possible secret schema; the value itself is not reported.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct SecretFinding {
+    /// Rule that found it, e.g. `aws_access_key` or `high_entropy`
+    pub rule: String,
+    /// Offset of the first character in the submitted content
+    pub start: usize,
+    /// Offset just past the last character in the submitted content
+    pub end: usize,
+}
+
+/// Secret scrubbing report schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ScrubReport {
+    /// Action taken
+    pub action: ScrubAction,
+    /// What was found, in order
+    pub findings: Vec<SecretFinding>,
 }
 
 /// Get node response schema.
@@ -169,6 +615,230 @@ pub struct DeleteNodeResponse {
     pub id: Uuid,
 }
 
+/// Node access kind schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessKind {
+    /// Returned by a query or search
+    Query,
+    /// Visited by a traversal
+    Traversal,
+    /// Fetched by ID
+    Get,
+}
+
+/// Recorded node access schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct AccessEvent {
+    /// How the node was accessed
+    pub kind: AccessKind,
+    /// Who accessed it, from the x-actor request header
+    pub actor: Option<String>,
+    /// When it was accessed
+    pub accessed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Node usage schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct NodeUsage {
+    /// Node ID
+    pub node_id: Uuid,
+    /// Accesses of any kind
+    pub total: u64,
+    /// Times the node was returned by a query or search
+    pub queries: u64,
+    /// Times the node was visited by a traversal
+    pub traversals: u64,
+    /// Times the node was fetched by ID
+    pub gets: u64,
+    /// First recorded access
+    pub first_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Most recent access
+    pub last_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Accesses per actor
+    pub actors: std::collections::HashMap<String, u64>,
+    /// Most recent accesses, newest first
+    pub recent: Vec<AccessEvent>,
+}
+
+/// Top accessed nodes response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct TopAccessedResponse {
+    /// Node usage, most accessed first
+    pub nodes: Vec<NodeUsage>,
+}
+
+/// Node memory state schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct NodeMemory {
+    /// Decay profile the node was assigned
+    #[schema(example = "default")]
+    pub profile: String,
+    /// Access score as of the last access
+    pub access_score: f32,
+    /// Access score decayed to now
+    pub current_score: f32,
+    /// Current memory strength (0.0 - 1.0)
+    #[schema(minimum = 0.0, maximum = 1.0)]
+    pub strength: f32,
+    /// Last access that reinforced the node
+    pub accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the node is exempt from decay and pruning
+    pub pinned: bool,
+}
+
+/// Similar node schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct SimilarNode {
+    /// Node ID
+    pub node_id: Uuid,
+    /// Node content
+    pub content: String,
+    /// Similarity score from the vector index
+    pub score: f32,
+}
+
+/// Node inspection schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct NodeInspection {
+    /// The node
+    pub node: NodeInfo,
+    /// Memory state of the node
+    pub memory: NodeMemory,
+    /// Outgoing edges, grouped by relation
+    pub outgoing: std::collections::BTreeMap<String, Vec<EdgeInfo>>,
+    /// Incoming edges, grouped by relation
+    pub incoming: std::collections::BTreeMap<String, Vec<EdgeInfo>>,
+    /// Nearest neighbours in the vector index, most similar first
+    pub similar: Vec<SimilarNode>,
+    /// Usage of the node, with its recent accesses
+    pub usage: NodeUsage,
+    /// Attachments of the node, oldest first
+    pub attachments: Vec<AttachmentInfo>,
+}
+
+/// Attachment upload multipart form schema.
+#[derive(utoipa::ToSchema)]
+pub struct AttachmentForm {
+    /// File to attach, at most 1 MiB
+    #[schema(format = Binary)]
+    pub file: String,
+}
+
+/// Attachment metadata schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct AttachmentInfo {
+    /// Attachment ID
+    pub id: Uuid,
+    /// Node the attachment belongs to
+    pub node_id: Uuid,
+    /// File name
+    #[schema(example = "architecture.svg")]
+    pub name: String,
+    /// MIME type
+    #[schema(example = "image/svg+xml")]
+    pub content_type: String,
+    /// Size in bytes
+    pub size: usize,
+    /// When the attachment was added
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Node attachments response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct AttachmentsResponse {
+    /// Attachments, oldest first
+    pub attachments: Vec<AttachmentInfo>,
+    /// Total size of the attachments in bytes
+    pub total_bytes: usize,
+}
+
+/// Delete attachment response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct DeleteAttachmentResponse {
+    /// Whether the attachment was deleted
+    pub deleted: bool,
+    /// ID of the deleted attachment
+    pub id: Uuid,
+}
+
+/// Tag node request schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+pub struct TagNodeRequest {
+    /// Tags to add; normalized to lowercase
+    #[schema(example = json!(["architecture", "storage"]))]
+    pub tags: Vec<String>,
+}
+
+/// Node tags response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct NodeTagsResponse {
+    /// Tagged node ID
+    pub node_id: Uuid,
+    /// All tags of the node, sorted
+    pub tags: Vec<String>,
+}
+
+/// Attribute update mode schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeUpdateMode {
+    /// Set the given top-level keys, removing those set to null
+    Merge,
+    /// Replace the attributes entirely
+    Replace,
+}
+
+/// Update node request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct UpdateNodeRequest {
+    /// Node ID (bulk updates only; taken from the path for PATCH)
+    pub id: Option<Uuid>,
+    /// New content; the node is re-embedded when it changes
+    #[schema(example = "Machine learning is a subset of AI")]
+    pub content: Option<String>,
+    /// New node type
+    pub node_type: Option<NodeType>,
+    /// Attributes to merge or replace
+    pub attributes: Option<serde_json::Value>,
+    /// How attributes are applied (defaults to merge)
+    pub attribute_mode: Option<AttributeUpdateMode>,
+    /// Pin the node, exempting it from decay and pruning, or unpin it
+    pub pinned: Option<bool>,
+    /// Apply the update only if the node is still at this version; a
+    /// mismatch is rejected with 409 and the current version
+    pub expected_version: Option<u64>,
+}
+
+/// Update node response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct UpdateNodeResponse {
+    /// The updated node
+    pub node: NodeInfo,
+    /// Whether the content changed and the node was re-embedded
+    pub reembedded: bool,
+}
+
+/// Bulk node update request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct BulkUpdateNodesRequest {
+    /// Updates to apply, in order
+    pub updates: Vec<UpdateNodeRequest>,
+}
+
+/// Bulk node update response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct BulkUpdateNodesResponse {
+    /// The updated nodes
+    pub nodes: Vec<NodeInfo>,
+    /// Number of successful updates
+    pub success_count: usize,
+    /// Number of failed updates
+    pub failure_count: usize,
+    /// Error messages
+    pub errors: Vec<String>,
+}
+
 /// Add edge request schema.
 #[derive(utoipa::ToSchema, serde::Deserialize)]
 pub struct AddEdgeRequest {
@@ -182,13 +852,46 @@ pub struct AddEdgeRequest {
     /// Edge weight (0.0 - 1.0)
     #[schema(example = 0.8, minimum = 0.0, maximum = 1.0)]
     pub weight: f32,
+    /// Edge provenance (defaults to user-created)
+    #[serde(default)]
+    pub provenance: Option<EdgeProvenance>,
 }
 
 /// Add edge response schema.
 #[derive(utoipa::ToSchema, serde::Serialize)]
 pub struct AddEdgeResponse {
-    /// The created edge
+    /// The created edge; `pending_review` is set if it was held for review
+    pub edge: EdgeInfo,
+}
+
+/// Pending edges response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ReviewEdgesResponse {
+    /// Pending edges, oldest first
+    pub edges: Vec<EdgeInfo>,
+    /// Number of pending edges
+    pub count: usize,
+}
+
+/// Edge review request schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+pub struct ReviewEdgeRequest {
+    /// Source node ID
+    pub source: Uuid,
+    /// Target node ID
+    pub target: Uuid,
+    /// Relation type
+    #[schema(example = "causes")]
+    pub relation: String,
+}
+
+/// Edge review response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ReviewEdgeResponse {
+    /// The reviewed edge
     pub edge: EdgeInfo,
+    /// Whether the edge was approved (or rejected and deleted)
+    pub approved: bool,
 }
 
 /// Query request schema.
@@ -199,8 +902,36 @@ pub struct QueryRequest {
     pub query: String,
     /// Maximum number of results
     pub limit: Option<usize>,
-    /// Include metadata in results
+    /// Explain why each result matched
+    #[serde(default)]
     pub include_metadata: bool,
+    /// Also search archived (cold tier) nodes
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Only estimate the number of matches
+    #[serde(default)]
+    pub count_only: bool,
+    /// Return a random sample of this many matches
+    #[serde(default)]
+    pub sample: Option<usize>,
+    /// Ranking expression over similarity, recency, centrality, confidence
+    /// and access_score
+    #[serde(default)]
+    #[schema(example = "0.6*similarity + 0.3*recency + 0.1*centrality")]
+    pub rank: Option<String>,
+    /// Only return nodes with at least this confidence
+    #[schema(example = 0.7, minimum = 0.0, maximum = 1.0)]
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Share of the score taken from each node's current memory strength
+    #[schema(example = 0.3, minimum = 0.0, maximum = 1.0)]
+    #[serde(default)]
+    pub recency_weight: Option<f32>,
+    /// Only return nodes in this namespace or, as configured, the namespaces
+    /// below or above it (`org/team` includes `org/team/api`)
+    #[schema(example = "support")]
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 /// Query response schema.
@@ -214,6 +945,412 @@ pub struct QueryResponse {
     pub execution_time_ms: u64,
     /// Whether results were truncated
     pub truncated: bool,
+    /// ID used to submit relevance feedback
+    pub query_id: Uuid,
+    /// Whether total_count is an estimate
+    pub approximate: bool,
+    /// Why each node matched, when include_metadata is set
+    pub matches: Vec<MatchExplanation>,
+    /// Whether natural language was translated into PaQL
+    pub translated: bool,
+    /// PaQL the query was translated into
+    pub paql: Option<String>,
+    /// Immediate neighbors of each node, when ?expand=1 is passed
+    pub neighbors: Vec<ResultNeighbors>,
+}
+
+/// Result neighbors schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ResultNeighbors {
+    /// The result node
+    pub node_id: Uuid,
+    /// Its neighbors, strongest edge first
+    pub neighbors: Vec<NeighborPreview>,
+}
+
+/// Neighbor preview schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct NeighborPreview {
+    /// Node ID
+    pub node_id: Uuid,
+    /// Node type
+    pub node_type: NodeType,
+    /// Node content
+    pub content: String,
+    /// Relation of the edge joining it to the result
+    #[schema(example = "causes")]
+    pub relation: String,
+    /// Whether the edge points from the result to the neighbor
+    pub outgoing: bool,
+    /// Weight of the edge
+    pub weight: f32,
+}
+
+/// Match explanation schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MatchExplanation {
+    /// Matched node ID
+    pub node_id: Uuid,
+    /// Query terms found in the content
+    pub terms: Vec<TermMatch>,
+    /// Similarity to the query (0.0 - 1.0)
+    pub similarity: Option<f32>,
+    /// Space the similarity was measured in
+    pub space: Option<MatchSpace>,
+    /// Filter predicates the node satisfies
+    pub filters: Vec<String>,
+}
+
+/// Matched term schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct TermMatch {
+    /// Query term that matched
+    pub term: String,
+    /// Character offset of the match start
+    pub start: usize,
+    /// Character offset just past the match end
+    pub end: usize,
+}
+
+/// Similarity space schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchSpace {
+    /// Cosine similarity of embeddings
+    Embedding,
+    /// Share of query words found in the content
+    Lexical,
+}
+
+/// Prepare query request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct PrepareQueryRequest {
+    /// Query template with `$name` placeholders
+    #[schema(example = "find $topic")]
+    pub query: String,
+}
+
+/// Prepare query response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PrepareQueryResponse {
+    /// Handle used to execute the prepared query
+    pub handle: Uuid,
+    /// Placeholder names that must be bound
+    pub params: Vec<String>,
+}
+
+/// Read consistency schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Consistency {
+    /// Execute the query, seeing every acknowledged write
+    Strong,
+    /// Serve a cached result, which may miss writes for up to the cache TTL
+    Eventual,
+}
+
+/// Execute prepared query request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct ExecuteQueryRequest {
+    /// Handle returned by the prepare call
+    pub handle: Uuid,
+    /// Values bound to the template placeholders
+    #[serde(default)]
+    pub params: std::collections::HashMap<String, String>,
+    /// Maximum number of results
+    pub limit: Option<usize>,
+    /// Also search archived (cold tier) nodes
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Explain why each result matched
+    #[serde(default)]
+    pub include_metadata: bool,
+    /// Only return nodes in this namespace or, as configured, the namespaces
+    /// below or above it (`org/team` includes `org/team/api`)
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// Structured query request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct StructuredQueryRequest {
+    /// The query as a PaQL AST
+    pub query: PaqlQuery,
+    /// Maximum number of results
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Explain why each result matched
+    #[serde(default)]
+    pub include_metadata: bool,
+    /// Also search archived (cold tier) nodes
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Only return nodes with at least this confidence
+    #[schema(example = 0.7, minimum = 0.0, maximum = 1.0)]
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Share of the score taken from each node's current memory strength
+    #[schema(example = 0.3, minimum = 0.0, maximum = 1.0)]
+    #[serde(default)]
+    pub recency_weight: Option<f32>,
+    /// Only return nodes in this namespace or, as configured, the namespaces
+    /// below or above it (`org/team` includes `org/team/api`)
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// PaQL query schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct PaqlQuery {
+    /// Node selecting the initial results
+    pub root: PaqlQueryNode,
+    /// Maximum number of results
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Sort order of the results
+    #[serde(default)]
+    pub sort_fields: Vec<PaqlSortField>,
+    /// Ranking expression replacing the default access-score order
+    #[serde(default)]
+    pub rank: Option<PaqlRankExpr>,
+    /// Pipeline stages applied in order to the results of the root
+    #[serde(default)]
+    pub stages: Vec<PaqlStage>,
+}
+
+/// PaQL query node schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlQueryNode {
+    /// Matches nothing
+    Empty,
+    /// Text search
+    TextSearch { query: String },
+    /// Vector similarity search
+    SemanticSearch { embedding: Vec<f32> },
+    /// Text and vector search; an empty embedding is computed from the query
+    HybridSearch { query: String, embedding: Vec<f32> },
+    /// Nodes carrying a tag
+    Tagged { tag: String },
+    /// Nodes reachable from a seed node
+    GraphTraversal {
+        seed_id: Uuid,
+        direction: PaqlDirection,
+        max_hops: usize,
+    },
+    /// Nodes bound to `var`, or to any named variable, by a graph pattern
+    Match {
+        pattern: String,
+        var: Option<String>,
+    },
+    /// Input results satisfying every filter
+    Filter {
+        input: Box<PaqlQueryNode>,
+        filters: Vec<PaqlFilter>,
+    },
+    /// Results of both sides
+    And {
+        left: Box<PaqlQueryNode>,
+        right: Box<PaqlQueryNode>,
+    },
+    /// Results of either side
+    Or {
+        left: Box<PaqlQueryNode>,
+        right: Box<PaqlQueryNode>,
+    },
+    /// Nodes not in the input results
+    Not { input: Box<PaqlQueryNode> },
+}
+
+/// PaQL pipeline stage schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlStage {
+    /// Replace the results with the nodes reachable from them
+    Traverse {
+        relations: Vec<String>,
+        direction: PaqlDirection,
+        max_hops: usize,
+    },
+    /// Keep the results satisfying every filter
+    Filter(Vec<PaqlFilter>),
+    /// Order the results
+    Sort(Vec<PaqlSortField>),
+    /// Keep the first results
+    Limit(usize),
+}
+
+/// PaQL traversal direction schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlDirection {
+    /// Follow outgoing edges
+    Forward,
+    /// Follow incoming edges
+    Backward,
+    /// Follow edges in both directions
+    Both,
+}
+
+/// PaQL filter schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct PaqlFilter {
+    /// Field to compare
+    pub field: PaqlFilterField,
+    /// Comparison
+    pub op: PaqlComparisonOp,
+    /// String, number, boolean or list of them
+    pub value: serde_json::Value,
+}
+
+/// PaQL filter field schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlFilterField {
+    /// Node content
+    Content,
+    /// Node type
+    NodeType,
+    /// Access score
+    AccessScore,
+    /// Confidence
+    Confidence,
+    /// Creation time
+    CreatedAt,
+    /// Node tags; `eq` and `ne` test membership
+    Tag,
+    /// Node attribute
+    Custom(String),
+}
+
+/// PaQL comparison schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlComparisonOp {
+    /// Equal
+    Eq,
+    /// Not equal
+    Ne,
+    /// Greater than
+    Gt,
+    /// Greater than or equal
+    Ge,
+    /// Less than
+    Lt,
+    /// Less than or equal
+    Le,
+    /// Text contains
+    Contains,
+    /// In a list
+    In,
+}
+
+/// PaQL sort field schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct PaqlSortField {
+    /// Field to sort by
+    pub field: PaqlSortFieldType,
+    /// Sort order
+    pub order: PaqlSortOrder,
+}
+
+/// PaQL sort key schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlSortFieldType {
+    /// Relevance score
+    Relevance,
+    /// Access score
+    AccessScore,
+    /// Confidence
+    Confidence,
+    /// Creation time
+    CreatedAt,
+    /// Node tags
+    Tag,
+    /// Node attribute
+    Custom(String),
+}
+
+/// PaQL sort order schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlSortOrder {
+    /// Ascending
+    Asc,
+    /// Descending
+    Desc,
+}
+
+/// PaQL ranking expression schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlRankExpr {
+    /// Numeric literal
+    Number(f64),
+    /// Ranking signal
+    Var(PaqlRankVar),
+    /// Negation
+    Neg(Box<PaqlRankExpr>),
+    /// Arithmetic on two expressions
+    Binary {
+        op: PaqlRankOp,
+        left: Box<PaqlRankExpr>,
+        right: Box<PaqlRankExpr>,
+    },
+}
+
+/// PaQL ranking signal schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlRankVar {
+    /// Query/content similarity (0.0 - 1.0)
+    Similarity,
+    /// Freshness, decaying with age (0.0 - 1.0)
+    Recency,
+    /// Connectedness relative to the other candidates (0.0 - 1.0)
+    Centrality,
+    /// Node confidence (0.0 - 1.0)
+    Confidence,
+    /// Node access score (0.0 - 10.0)
+    AccessScore,
+}
+
+/// PaQL ranking operator schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaqlRankOp {
+    /// Addition
+    Add,
+    /// Subtraction
+    Sub,
+    /// Multiplication
+    Mul,
+    /// Division, yielding 0 for a zero divisor
+    Div,
+}
+
+/// Traversal direction schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+pub enum TraverseDirection {
+    /// Follow outgoing edges
+    Forward,
+    /// Follow incoming edges
+    Backward,
+    /// Follow edges in both directions
+    Both,
+}
+
+/// Supernode strategy schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SupernodeStrategy {
+    /// Follow every edge
+    Expand,
+    /// Follow only the edges within the maximum degree (default)
+    Sample,
+    /// Reach the supernode without expanding it
+    Skip,
 }
 
 /// Traverse request schema.
@@ -227,9 +1364,30 @@ pub struct TraverseRequest {
     /// Maximum nodes to return
     #[schema(example = 100)]
     pub max_nodes: usize,
-    /// Traversal direction (forward, backward, both)
-    #[schema(example = "forward")]
-    pub direction: String,
+    /// Traversal direction
+    pub direction: TraverseDirection,
+    /// Only follow edges made by these creators (empty = all)
+    #[serde(default)]
+    pub created_by: Vec<EdgeCreator>,
+    /// Reject the traversal if its estimated cost (edges examined) exceeds this
+    #[schema(example = 10000)]
+    pub max_estimated_cost: Option<u64>,
+    /// Follow incoming edges as their inverse relation (e.g. has_part for an
+    /// incoming is_part_of) when traversing backward
+    #[serde(default)]
+    pub follow_inverses: bool,
+    /// Only follow edges with at least this extraction confidence
+    #[schema(example = 0.7, minimum = 0.0, maximum = 1.0)]
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// How to expand nodes with more edges than the graph's maximum degree
+    #[serde(default)]
+    pub supernode_strategy: Option<SupernodeStrategy>,
+    /// Only return nodes in this namespace or, as configured, the namespaces
+    /// below or above it
+    #[schema(example = "org/team")]
+    #[serde(default)]
+    pub namespace: Option<String>,
 }
 
 /// Traverse response schema.
@@ -243,35 +1401,331 @@ pub struct TraverseResponse {
     pub depth: usize,
     /// Whether traversal was truncated
     pub truncated: bool,
+    /// Supernodes whose expansion was sampled or skipped (omitted when none)
+    pub supernodes: Vec<SupernodeWarning>,
 }
 
-/// Hybrid search request schema (GraphRAG).
-#[derive(utoipa::ToSchema, serde::Deserialize)]
-pub struct HybridSearchRequest {
-    /// Query string for hybrid search
-    #[schema(example = "neural network architecture")]
-    pub query: String,
-    /// Number of results to return (k)
-    #[schema(example = 10, minimum = 1, maximum = 100)]
-    pub k: usize,
+/// Supernode warning schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct SupernodeWarning {
+    /// Supernode ID
+    pub id: Uuid,
+    /// Number of edges beyond the maximum degree in the traversal direction
+    pub bundled_edges: usize,
+    /// Number of those edges per relation
+    pub relation_counts: std::collections::HashMap<String, usize>,
 }
 
-/// Hybrid search response schema.
+/// Traversal cost estimate schema.
 #[derive(utoipa::ToSchema, serde::Serialize)]
-pub struct HybridSearchResponse {
-    /// Relevant nodes found
-    pub nodes: Vec<NodeInfo>,
-    /// Number of results
-    pub count: usize,
+pub struct TraverseEstimate {
+    /// Estimated newly reached nodes per depth, starting at depth 1
+    pub nodes_per_depth: Vec<u64>,
+    /// Estimated number of nodes returned
+    pub estimated_nodes: u64,
+    /// Estimated cost (edges examined)
+    pub estimated_cost: u64,
+    /// Whether the estimate was cut off by max_nodes
+    pub capped: bool,
+    /// Whether the estimate exceeds max_estimated_cost
+    pub exceeds_limit: bool,
 }
 
-/// Bulk operation request schema.
-#[derive(utoipa::ToSchema, serde::Deserialize)]
-pub struct BulkOperationRequest {
-    /// Nodes to add
-    pub nodes: Vec<AddNodeRequest>,
-    /// Edges to add
+/// Materialized view definition schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+pub struct ViewDefinition {
+    /// Unique view name
+    #[schema(example = "service-map")]
+    pub name: String,
+    /// Nodes the traversal starts from
+    #[serde(default)]
+    pub start_ids: Vec<Uuid>,
+    /// Also start from every node carrying one of these tags
+    #[schema(example = json!(["service"]))]
+    #[serde(default)]
+    pub start_tags: Vec<String>,
+    /// Maximum traversal depth
+    #[schema(example = 3, minimum = 1, maximum = 5)]
+    pub max_depth: usize,
+    /// Maximum number of member nodes, start nodes included (default 500)
+    #[schema(example = 200, minimum = 1, maximum = 500)]
+    pub max_nodes: Option<usize>,
+    /// Traversal direction (default Forward)
+    pub direction: Option<TraverseDirection>,
+    /// Only follow edges of these relations (empty = all)
+    #[schema(example = json!(["is_part_of"]))]
+    #[serde(default)]
+    pub relations: Vec<String>,
+    /// Only follow edges made by these creators (empty = all)
+    #[serde(default)]
+    pub created_by: Vec<EdgeCreator>,
+    /// Only follow edges with at least this extraction confidence
+    #[schema(example = 0.7, minimum = 0.0, maximum = 1.0)]
+    pub min_confidence: Option<f32>,
+}
+
+/// Materialized view schema: the definition's fields plus the members.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MaterializedView {
+    /// View definition
+    #[serde(flatten)]
+    pub definition: ViewDefinition,
+    /// Member nodes, in traversal order
+    pub nodes: Vec<NodeInfo>,
+    /// Edges between member nodes
+    pub edges: Vec<EdgeInfo>,
+    /// When the membership was last materialized
+    pub refreshed_at: chrono::DateTime<chrono::Utc>,
+    /// Whether this read traversed the view again after a change
+    pub refreshed: bool,
+}
+
+/// Materialized view summary schema: the definition's fields plus its size.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ViewSummary {
+    /// View definition
+    #[serde(flatten)]
+    pub definition: ViewDefinition,
+    /// Member nodes as last materialized
+    pub node_count: usize,
+    /// Edges between members as last materialized
+    pub edge_count: usize,
+    /// Whether a write changed the view since it was last materialized
+    pub stale: bool,
+    /// When the membership was last materialized, if ever
+    pub refreshed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Materialized views response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ViewsResponse {
+    /// Views, by name
+    pub views: Vec<ViewSummary>,
+    /// Number of views
+    pub count: usize,
+}
+
+/// Reasoning paths request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct ReasonPathsRequest {
+    /// Starting node ID (exclusive with query)
+    pub source: Option<Uuid>,
+    /// Query whose best matches are used as starting nodes (exclusive with source)
+    #[schema(example = "supply shortage")]
+    pub query: Option<String>,
+    /// Node the paths must end at
+    pub target: Uuid,
+    /// Maximum hops per path
+    #[schema(example = 4, minimum = 1, maximum = 8)]
+    #[serde(default)]
+    pub max_hops: Option<usize>,
+    /// Maximum number of paths to return
+    #[schema(example = 5)]
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Only return paths whose propagated confidence (product of edge
+    /// weight times confidence) is at least this value
+    #[schema(example = 0.5, minimum = 0.0, maximum = 1.0)]
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+    /// Also render the paths as Markdown
+    #[serde(default)]
+    pub markdown: bool,
+}
+
+/// Reasoning path schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ReasoningPathInfo {
+    /// Nodes along the path, in order
+    pub nodes: Vec<NodeInfo>,
+    /// Edges connecting consecutive nodes
+    pub edges: Vec<EdgeInfo>,
+    /// Product of edge weights along the path
+    pub confidence: f32,
+    /// Natural language explanation of the path
+    pub explanation: String,
+    /// Path type (causal, hierarchical, temporal, associative, hybrid)
+    #[schema(example = "causal")]
+    pub path_type: String,
+}
+
+/// Reasoning paths response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ReasonPathsResponse {
+    /// Paths ordered from most to least confident
+    pub paths: Vec<ReasoningPathInfo>,
+    /// Starting nodes that were searched
+    pub sources: Vec<Uuid>,
+    /// Markdown rendering of the paths, if requested
+    pub markdown: Option<String>,
+}
+
+/// Graph pattern match request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct MatchPatternRequest {
+    /// Pattern of typed node variables joined by relations; chains
+    /// separated by commas share their variables
+    #[schema(example = "(a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c)")]
+    pub pattern: String,
+    /// Maximum number of matches to return
+    #[schema(example = 100, minimum = 1, maximum = 1000)]
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Graph pattern match schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PatternMatchInfo {
+    /// Node bound to each pattern variable
+    pub bindings: std::collections::BTreeMap<String, NodeInfo>,
+    /// Edge matched by each pattern edge, in pattern order
+    pub edges: Vec<EdgeInfo>,
+}
+
+/// Graph pattern match response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MatchPatternResponse {
+    /// Matches found
+    pub matches: Vec<PatternMatchInfo>,
+    /// Whether matching stopped at the limit or its work budget
+    pub truncated: bool,
+}
+
+/// Graph visualization node schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VizNode {
+    /// Node ID
+    pub id: Uuid,
+    /// Short label taken from the node content
+    #[schema(example = "Tesla builds grid-scale batteries")]
+    pub label: String,
+    /// Node type, for coloring by group
+    #[schema(example = "entity")]
+    pub group: String,
+    /// Access score, for sizing
+    #[schema(example = 1.0)]
+    pub value: f32,
+}
+
+/// Graph visualization link schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VizLink {
+    /// Source node ID
+    pub source: Uuid,
+    /// Target node ID
+    pub target: Uuid,
+    /// Relation name
+    #[schema(example = "causes")]
+    pub relation: String,
+    /// Edge weight
+    #[schema(example = 0.8)]
+    pub weight: f32,
+}
+
+/// Graph visualization response schema (force-graph JSON).
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct GraphVizResponse {
+    /// Nodes to draw
+    pub nodes: Vec<VizNode>,
+    /// Links between the returned nodes
+    pub links: Vec<VizLink>,
+    /// Whether nodes or links were left out to stay within the limits
+    pub truncated: bool,
+}
+
+/// Hybrid search request schema (GraphRAG).
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct HybridSearchRequest {
+    /// Query string for hybrid search
+    #[schema(example = "neural network architecture")]
+    pub query: String,
+    /// Number of results to return (k)
+    #[schema(example = 10, minimum = 1, maximum = 100)]
+    pub k: usize,
+    /// Minimum similarity of vector matches (calibrated threshold if omitted)
+    #[schema(minimum = 0.0, maximum = 1.0)]
+    pub min_relevance: Option<f32>,
+    /// Only search nodes of this type
+    pub node_type: Option<NodeType>,
+    /// Only search nodes in this namespace
+    #[schema(example = "org/team")]
+    pub namespace: Option<String>,
+    /// Only search nodes carrying every one of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Hybrid search response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct HybridSearchResponse {
+    /// Relevant nodes found
+    pub nodes: Vec<NodeInfo>,
+    /// Number of results
+    pub count: usize,
+    /// ID used to submit relevance feedback
+    pub query_id: Uuid,
+    /// Immediate neighbors of each node, when ?expand=1 is passed
+    pub neighbors: Vec<ResultNeighbors>,
+}
+
+/// Relevance feedback request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct FeedbackRequest {
+    /// ID of the query that returned the node
+    pub query_id: Uuid,
+    /// Node being rated
+    pub node_id: Uuid,
+    /// Whether the node was useful
+    pub useful: bool,
+}
+
+/// Relevance feedback response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct FeedbackResponse {
+    /// Node access score after the feedback
+    pub access_score: f32,
+    /// Adjusted relation weights by relation type
+    pub relation_weights: std::collections::HashMap<String, f32>,
+}
+
+/// Aggregate feedback statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct FeedbackStats {
+    /// Total feedback events
+    pub total: usize,
+    /// Results marked useful
+    pub useful: usize,
+    /// Results marked not useful
+    pub not_useful: usize,
+    /// Fraction of positive feedback
+    pub useful_rate: f64,
+    /// Queries that can still receive feedback
+    pub tracked_queries: usize,
+    /// Learned relation weights by relation type
+    pub relation_weights: std::collections::HashMap<String, f32>,
+}
+
+/// Write durability schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Durability {
+    /// The server's configured write options
+    Default,
+    /// Fsync the write-ahead log before acknowledging
+    Sync,
+    /// Skip the write-ahead log; writes may be lost on a crash
+    NoWal,
+}
+
+/// Bulk operation request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct BulkOperationRequest {
+    /// Nodes to add
+    pub nodes: Vec<AddNodeRequest>,
+    /// Edges to add
     pub edges: Vec<AddEdgeRequest>,
+    /// Durability of the writes
+    pub durability: Option<Durability>,
 }
 
 /// Bulk operation response schema.
@@ -289,8 +1743,56 @@ pub struct BulkOperationResponse {
     pub errors: Vec<String>,
 }
 
-/// Chunking strategy schema.
+/// Transaction operation schema.
+///
+/// `add_node` uses `temp_id`, `content`, `node_type` and `attributes`;
+/// `add_edge` uses `source`, `target`, `relation`, `weight` and `provenance`.
 #[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct TransactionOp {
+    /// Operation (add_node, add_edge)
+    #[schema(example = "add_node")]
+    pub op: String,
+    /// Temporary ID later operations use to refer to the new node
+    #[schema(example = "tesla")]
+    pub temp_id: Option<String>,
+    /// Node content/text
+    pub content: Option<String>,
+    /// Node type
+    pub node_type: Option<NodeType>,
+    /// Optional attributes as JSON
+    pub attributes: Option<serde_json::Value>,
+    /// Source node: an existing node ID or a temporary ID
+    pub source: Option<String>,
+    /// Target node: an existing node ID or a temporary ID
+    pub target: Option<String>,
+    /// Relation type
+    pub relation: Option<String>,
+    /// Edge weight (0.0 - 1.0)
+    pub weight: Option<f32>,
+    /// Edge provenance (defaults to user-created)
+    pub provenance: Option<EdgeProvenance>,
+}
+
+/// Transaction request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct TransactionRequest {
+    /// Operations to apply atomically, in order
+    pub operations: Vec<TransactionOp>,
+}
+
+/// Transaction response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct TransactionResponse {
+    /// Created nodes, in operation order
+    pub nodes: Vec<NodeInfo>,
+    /// Created edges, in operation order
+    pub edges: Vec<EdgeInfo>,
+    /// IDs assigned to the temporary node IDs
+    pub temp_ids: std::collections::HashMap<String, Uuid>,
+}
+
+/// Chunking strategy schema.
+#[derive(utoipa::ToSchema, serde::Serialize, serde::Deserialize)]
 pub enum ChunkingStrategy {
     /// Fixed-size chunking by character count
     #[schema(rename = "fixed")]
@@ -355,6 +1857,56 @@ pub struct IngestDocumentRequest {
     pub embed: bool,
     /// Optional metadata to attach to all chunks
     pub metadata: Option<serde_json::Value>,
+    /// Namespace recorded on the document and its chunks
+    pub namespace: Option<String>,
+    /// Whether to store a model-written summary of the document, if it is long enough
+    #[schema(default = false)]
+    pub summarize: bool,
+    /// Durability of the document and chunk writes
+    pub durability: Option<Durability>,
+}
+
+/// URL ingestion request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct IngestUrlRequest {
+    /// URL of a web page, PDF or text document (http or https)
+    #[schema(example = "https://en.wikipedia.org/wiki/Knowledge_graph")]
+    pub url: String,
+    /// Document title (defaults to the page title, then the URL)
+    pub title: Option<String>,
+    /// Chunking strategy
+    pub chunking: Option<ChunkingStrategy>,
+    /// Whether to generate embeddings for chunks
+    #[schema(default = true)]
+    pub embed: bool,
+    /// Whether to store a model-written summary of the document, if it is long enough
+    #[schema(default = false)]
+    pub summarize: bool,
+    /// Durability of the document and chunk writes
+    pub durability: Option<Durability>,
+}
+
+/// File ingestion multipart form schema.
+#[derive(utoipa::ToSchema)]
+pub struct IngestFileForm {
+    /// HTML, PDF or text file
+    #[schema(format = Binary)]
+    pub file: String,
+    /// Document title (defaults to the document's own title, then the file name)
+    pub title: Option<String>,
+    /// Chunking strategy as JSON
+    #[schema(example = r#"{"fixed": {"chunk_size": 512, "overlap": 64}}"#)]
+    pub chunking: Option<String>,
+    /// Whether to generate embeddings for chunks
+    #[schema(default = true)]
+    pub embed: Option<bool>,
+    /// Namespace recorded on the document and its chunks
+    pub namespace: Option<String>,
+    /// Whether to store a model-written summary of the document, if it is long enough
+    #[schema(default = false)]
+    pub summarize: Option<bool>,
+    /// Durability of the document and chunk writes
+    pub durability: Option<Durability>,
 }
 
 /// Document ingestion response schema.
@@ -370,5 +1922,1024 @@ pub struct IngestDocumentResponse {
     pub embedded: bool,
     /// Processing time in milliseconds
     pub processing_time_ms: u64,
+    /// Summary node of the document, if one was requested and written
+    pub summary_id: Option<Uuid>,
+    /// Possible secrets found in the content, if scrubbing is enabled
+    pub scrub: Option<ScrubReport>,
+}
+
+/// Ingestion pipeline schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PipelineInfo {
+    /// Unique pipeline name
+    #[schema(example = "handbook")]
+    pub name: String,
+    /// Document source: `url` with `urls`, `file` with `paths`, or `directory` with `path` and optional `extensions`
+    #[schema(example = json!({"type": "directory", "path": "./handbook", "extensions": ["md"]}))]
+    pub source: serde_json::Value,
+    /// Chunking strategy
+    pub chunking: Option<ChunkingStrategy>,
+    /// Enrichment steps: `metadata` with `values`, or `tags` with `tags`
+    #[schema(example = json!([{"type": "tags", "tags": ["handbook"]}]))]
+    pub enrich: Vec<serde_json::Value>,
+    /// Whether chunks are embedded
+    pub embed: bool,
+    /// Namespace recorded on the documents and their chunks
+    pub namespace: Option<String>,
+    /// Durability of the document and chunk writes
+    pub durability: Durability,
+    /// Latest run
+    pub last_run: Option<PipelineRun>,
+}
+
+/// Ingestion pipelines response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PipelinesResponse {
+    /// Pipelines, by name
+    pub pipelines: Vec<PipelineInfo>,
+    /// Number of pipelines
+    pub count: usize,
+}
+
+/// Pipeline run outcome schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineRunStatus {
+    /// Every document was ingested
+    Succeeded,
+    /// Some documents were ingested and some failed
+    PartiallyFailed,
+    /// No document could be ingested
+    Failed,
+}
+
+/// Pipeline run error schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PipelineRunError {
+    /// URL or file path of the document, or the pipeline name when its source could not be listed
+    pub source: String,
+    /// Why ingestion failed
+    pub error: String,
+}
+
+/// Pipeline run schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PipelineRun {
+    /// Run ID
+    pub id: Uuid,
+    /// Pipeline name
+    pub pipeline: String,
+    /// When the run started
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    /// When the run finished
+    pub finished_at: chrono::DateTime<chrono::Utc>,
+    /// Outcome
+    pub status: PipelineRunStatus,
+    /// Documents ingested, in source order
+    pub document_ids: Vec<Uuid>,
+    /// Chunks created across the documents
+    pub chunk_count: usize,
+    /// Documents that failed
+    pub errors: Vec<PipelineRunError>,
+}
+
+/// Pipeline run history schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct PipelineRunsResponse {
+    /// Pipeline name
+    pub pipeline: String,
+    /// Runs, newest first
+    pub runs: Vec<PipelineRun>,
+    /// Number of runs
+    pub count: usize,
+}
+
+/// Chunk of a reconstructed document schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct DocumentChunk {
+    /// Node ID of the chunk
+    pub node_id: Uuid,
+    /// Position of the chunk within the document
+    pub index: usize,
+    /// Hierarchy level (0 = document, 1 = paragraph, 2 = sentence)
+    pub level: usize,
+    /// Chunk type
+    pub chunk_type: String,
+    /// Chunk content
+    pub content: String,
+}
+
+/// Reconstructed document schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct DocumentResponse {
+    /// Document ID (root node)
+    pub document_id: Uuid,
+    /// Document title
+    pub title: Option<String>,
+    /// Hierarchy level the content was reconstructed from
+    pub level: Option<usize>,
+    /// Hierarchy levels available for this document
+    pub levels: Vec<usize>,
+    /// Reconstructed document text
+    pub content: String,
+    /// Number of chunks used for the reconstruction
+    pub chunk_count: usize,
+    /// Chunks in document order
+    pub chunks: Vec<DocumentChunk>,
+}
+
+/// Ingested document summary schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct DocumentSummary {
+    /// Document ID (root node)
+    pub document_id: Uuid,
+    /// Document title
+    pub title: Option<String>,
+    /// Chunking strategy used at ingestion
+    pub chunking: Option<String>,
+    /// Number of chunks linked to the document
+    pub chunk_count: usize,
+    /// Ingestion time
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Document list response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ListDocumentsResponse {
+    /// Documents, most recently ingested first
+    pub documents: Vec<DocumentSummary>,
+    /// Number of documents
+    pub count: usize,
+}
+
+/// Vector index maintenance request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct OptimizeVectorRequest {
+    /// Run all maintenance steps regardless of thresholds
+    #[schema(default = false)]
+    pub force: bool,
+}
+
+/// Vector index maintenance response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct OptimizeVectorResponse {
+    /// Outcome of the maintenance run
+    pub report: MaintenanceReport,
+}
+
+/// Vector index maintenance report schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MaintenanceReport {
+    /// Number of data fragments before the run
+    pub fragments_before: usize,
+    /// Number of data fragments after the run
+    pub fragments_after: usize,
+    /// Whether fragments were compacted
+    pub compacted: bool,
+    /// Whether IVF centroids were retrained
+    pub ivf_retrained: bool,
+    /// Whether the HNSW graph was rebuilt
+    pub hnsw_rebuilt: bool,
+    /// Number of vectors in the index
+    pub vector_count: usize,
+    /// Time spent in milliseconds
+    pub elapsed_ms: u64,
+}
+
+/// Similarity threshold calibration request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct CalibrateVectorRequest {
+    /// Embedded nodes sampled
+    #[schema(default = 1000, maximum = 10000)]
+    pub sample: Option<usize>,
+    /// Percentile of the pair scores taken as the threshold
+    #[schema(default = 0.95, minimum = 0.0, maximum = 1.0)]
+    pub percentile: Option<f32>,
+}
+
+/// Calibrated similarity threshold schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorCalibration {
+    /// Vectors sampled
+    pub vectors: usize,
+    /// Vector pairs scored
+    pub pairs: usize,
+    /// Mean pair score
+    pub mean: f32,
+    /// Standard deviation of the pair scores
+    pub std_dev: f32,
+    /// Median pair score
+    pub p50: f32,
+    /// 90th percentile pair score
+    pub p90: f32,
+    /// 99th percentile pair score
+    pub p99: f32,
+    /// Percentile the threshold was taken at
+    pub percentile: f32,
+    /// Minimum relevance of vector matches when a search sets none
+    #[schema(example = 0.42)]
+    pub threshold: f32,
+    /// When the calibration ran
+    pub calibrated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Recall evaluation request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct EvaluateVectorRequest {
+    /// Neighbors retrieved per query
+    #[schema(default = 10, maximum = 100)]
+    pub k: Option<usize>,
+    /// Embedded nodes used as queries
+    #[schema(default = 100, maximum = 1000)]
+    pub queries: Option<usize>,
+}
+
+/// Vector index recall evaluation schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorEvaluation {
+    /// Stored vectors searched
+    pub vectors: usize,
+    /// Queries run
+    pub queries: usize,
+    /// Neighbors retrieved per query
+    pub k: usize,
+    /// Mean recall@k over the queries
+    #[schema(example = 0.97)]
+    pub recall: f32,
+    /// Lowest recall@k of a single query
+    pub min_recall: f32,
+    /// Queries whose results held the whole exact top k
+    pub perfect_queries: usize,
+    /// Mean index search latency in milliseconds
+    pub mean_latency_ms: f64,
+    /// Median index search latency in milliseconds
+    pub p50_latency_ms: f64,
+    /// 99th percentile index search latency in milliseconds
+    pub p99_latency_ms: f64,
+    /// Mean latency of the exact scan in milliseconds
+    pub exact_latency_ms: f64,
+    /// When the evaluation ran
+    pub evaluated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Vector delta apply response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ApplyVectorDeltaResponse {
+    /// Whether the delta replaced the contents of the index
+    pub full: bool,
+    /// Vectors written
+    pub upserted: usize,
+    /// Vectors removed
+    pub removed: usize,
+    /// Checkpoint of the source index now reached, as <epoch>:<version>
+    pub checkpoint: String,
+    /// Vectors in the index afterwards
+    pub vector_count: usize,
+}
+
+/// Storage compaction request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct CompactStorageRequest {
+    /// Column family to compact (all if omitted)
+    pub column_family: Option<String>,
+    /// Wait for the compaction to finish
+    #[schema(default = false)]
+    pub wait: bool,
+}
+
+/// Storage compaction response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct CompactStorageResponse {
+    /// Whether this request started a compaction
+    pub started: bool,
+    /// Whether the compaction finished before the response was sent
+    pub completed: bool,
+    /// Compaction time in milliseconds, if waited for
+    pub duration_ms: Option<u64>,
+}
+
+/// Storage migration response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct MigrateStorageResponse {
+    /// Format version nodes are stored in
+    pub node_version: u8,
+    /// Format version edges are stored in
+    pub edge_version: u8,
+    /// Active and archived nodes read
+    pub nodes_scanned: u64,
+    /// Nodes rewritten from an older version
+    pub nodes_migrated: u64,
+    /// Edges read
+    pub edges_scanned: u64,
+    /// Edges rewritten from an older version
+    pub edges_migrated: u64,
+    /// Migration time in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Seed request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct SeedRequest {
+    /// Ontology to seed: a `name` and `concepts`, each with a `name`, an
+    /// optional `description` and the names of the concepts it `is_a` or
+    /// is `part_of` (the bundled starter ontology if omitted)
+    pub ontology: Option<serde_json::Value>,
+    /// Namespace to seed into
+    #[schema(example = "starter")]
+    pub namespace: Option<String>,
+}
+
+/// Seed response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct SeedResponse {
+    /// Namespace seeded into
+    pub namespace: String,
+    /// Name of the ontology
+    pub ontology: String,
+    /// Whether the ontology was seeded (false if the namespace already held seeded nodes)
+    pub seeded: bool,
+    /// Nodes created
+    pub nodes: usize,
+    /// Edges created
+    pub edges: usize,
+}
+
+/// Unseed response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct UnseedResponse {
+    /// Namespace the nodes were seeded into
+    pub namespace: String,
+    /// Nodes removed
+    pub nodes_removed: usize,
+    /// Stored edges to or from them removed
+    pub edges_removed: usize,
+}
+
+/// Integrity check request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct IntegrityCheckRequest {
+    /// Delete the dangling edges found
+    #[schema(default = false)]
+    pub repair: bool,
+}
+
+/// Integrity check response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct IntegrityCheckResponse {
+    /// Stored edges whose source or target node is missing
+    pub dangling_edges: Vec<EdgeInfo>,
+    /// Whether the dangling edges were deleted
+    pub repaired: bool,
+}
+
+/// Archive import response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ImportResponse {
+    /// Nodes restored
+    pub nodes: usize,
+    /// Edges restored
+    pub edges: usize,
+    /// Restored nodes that carried an embedding
+    pub embeddings: usize,
+    /// Nodes skipped because a node with the same ID exists
+    pub skipped_nodes: usize,
+    /// Edges skipped because the same edge exists
+    pub skipped_edges: usize,
+    /// Edges that could not be restored, with the reason
+    pub errors: Vec<String>,
+}
+
+/// Background job kind schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    /// Restore an export archive
+    Import,
+    /// Compact and retrain the vector index
+    OptimizeVector,
+    /// Rebuild the in-memory graph and indexes from storage
+    Reindex,
+}
+
+/// Background job status schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Waiting for an earlier job to finish
+    Queued,
+    /// Running
+    Running,
+    /// Finished with a result
+    Succeeded,
+    /// Finished with an error
+    Failed,
+}
+
+/// Background job schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct Job {
+    /// Job ID
+    pub id: Uuid,
+    /// Operation run
+    pub kind: JobKind,
+    /// Current state
+    pub status: JobStatus,
+    /// Work items done, such as archive records imported
+    pub done: u64,
+    /// Work items in total, once known
+    pub total: Option<u64>,
+    /// Percent complete, once the total is known
+    pub percent: Option<f32>,
+    /// When the job was enqueued
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When the job last started running
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// When the job finished
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Times the job was resumed after a restart interrupted it
+    pub resumed: u32,
+    /// Result of the operation, as its synchronous endpoint returns it
+    pub result: Option<serde_json::Value>,
+    /// Why the job failed
+    pub error: Option<String>,
+}
+
+/// Background jobs response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct JobsResponse {
+    /// Jobs, newest first
+    pub jobs: Vec<Job>,
+    /// Number of jobs
+    pub count: usize,
+}
+
+/// Column family statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ColumnFamilyStats {
+    /// Column family name
+    pub name: String,
+    /// Estimated number of keys
+    pub estimated_keys: u64,
+    /// Total size of the SST files in bytes
+    pub sst_bytes: u64,
+    /// Size of unflushed memtables in bytes
+    pub memtable_bytes: u64,
+    /// Estimated bytes awaiting compaction
+    pub pending_compaction_bytes: u64,
+}
+
+/// Compressed node statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct CompressionStats {
+    /// Number of stored nodes that are compressed
+    pub compressed_nodes: u64,
+    /// Size of the compressed nodes as stored in bytes
+    pub compressed_bytes: u64,
+    /// Size of the compressed nodes before compression in bytes
+    pub uncompressed_bytes: u64,
+}
+
+/// Storage statistics response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct StorageStatsResponse {
+    /// Per column family statistics
+    pub column_families: Vec<ColumnFamilyStats>,
+    /// Total estimated number of keys
+    pub estimated_keys: u64,
+    /// Total size of the SST files in bytes
+    pub sst_bytes: u64,
+    /// Total estimated bytes awaiting compaction
+    pub pending_compaction_bytes: u64,
+    /// Compactions the engine is running right now
+    pub running_compactions: u64,
+    /// Whether a compaction requested through the API is in progress
+    pub compaction_in_progress: bool,
+    /// Sizes of the nodes stored compressed
+    pub compression: CompressionStats,
+    /// Uncompressed over stored size of the compressed nodes
+    pub compression_ratio: f64,
+}
+
+/// Self-check response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct SelfCheckResponse {
+    /// Server version
+    pub version: String,
+    /// Whether nodes are persisted to storage
+    pub persistence: bool,
+    /// Nodes with an embedding
+    pub embedded_nodes: usize,
+    /// Embedding backend check, absent without an embedding backend
+    pub embedding: Option<EmbeddingCheck>,
+    /// Vector index check, absent without a vector index
+    pub vector_index: Option<VectorIndexCheck>,
+}
+
+/// Embedding backend check schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct EmbeddingCheck {
+    /// Backend type (local, openai, ollama)
+    pub backend: String,
+    /// Default embedding model
+    pub model: String,
+    /// Dimension of the embeddings
+    pub dimension: usize,
+    /// Why embedding a test text failed, if it did
+    pub error: Option<String>,
+}
+
+/// Vector index check schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorIndexCheck {
+    /// Whether the index accepts searches
+    pub ready: bool,
+    /// Dimension of the indexed vectors
+    pub dimension: usize,
+    /// Vectors in the index, absent if counting them failed
+    pub vectors: Option<usize>,
+    /// Why counting the vectors failed, if it did
+    pub error: Option<String>,
+}
+
+/// Re-embedding progress schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ReembedStatus {
+    /// Whether stale embeddings are being regenerated
+    pub running: bool,
+    /// Stale embeddings still queued
+    pub pending: usize,
+    /// Embeddings regenerated so far
+    pub reembedded: u64,
+    /// Embeddings that could not be regenerated
+    pub failed: u64,
+    /// Current embedding model per language route ("" for the default model)
+    pub models: std::collections::HashMap<String, String>,
+}
+
+/// Request limiter counters for one route class schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct RouteClassStats {
+    /// Configured in-flight budget (0 = unlimited)
+    pub max_in_flight: usize,
+    /// Configured queue length
+    pub max_queued: usize,
+    /// Requests currently executing
+    pub in_flight: usize,
+    /// Requests currently waiting for a slot
+    pub queued: usize,
+    /// Requests admitted since startup
+    pub admitted: u64,
+    /// Requests rejected with 429 since startup
+    pub rejected: u64,
+}
+
+/// Request limiter statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct LimiterStats {
+    /// Read route class (queries, traversals, lookups)
+    pub read: RouteClassStats,
+    /// Write route class (node/edge mutations)
+    pub write: RouteClassStats,
+    /// Ingest route class (documents, bulk loads)
+    pub ingest: RouteClassStats,
+    /// How long queued requests wait for a slot (milliseconds)
+    pub queue_timeout_ms: u64,
+    /// Retry-After value sent with rejected requests (seconds)
+    pub retry_after_secs: u64,
+}
+
+/// Audited mutation kind schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    /// A node was created
+    CreateNode,
+    /// A node was updated
+    UpdateNode,
+    /// A node was deleted
+    DeleteNode,
+    /// Tags were added to a node
+    TagNode,
+    /// A tag was removed from a node
+    UntagNode,
+    /// An edge was created, or held for review
+    CreateEdge,
+    /// A pending edge was approved
+    ApproveEdge,
+    /// A pending edge was rejected
+    RejectEdge,
+    /// A transaction was applied
+    Transaction,
+    /// A document was chunked and stored
+    IngestDocument,
+    /// An export archive was imported
+    Import,
+    /// Decayed nodes were pruned
+    Prune,
+    /// Dangling edges were deleted by an integrity repair
+    RepairEdges,
+    /// Possible secrets were found in absorbed or ingested content
+    ScrubContent,
+    /// A file was attached to a node
+    AddAttachment,
+    /// An attachment was deleted
+    DeleteAttachment,
+    /// A batch of nodes and edges was streamed in
+    StreamIngest,
+}
+
+/// Audit event schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct AuditEvent {
+    /// Event ID
+    pub id: Uuid,
+    /// When the mutation was applied
+    pub at: chrono::DateTime<chrono::Utc>,
+    /// Who applied it, from the x-actor request header
+    pub actor: Option<String>,
+    /// What was done
+    pub operation: AuditOperation,
+    /// IDs of the nodes affected; an edge's source and target
+    pub targets: Vec<Uuid>,
+    /// Summary of the request
+    pub summary: String,
+}
+
+/// Audit trail response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct AuditResponse {
+    /// Number of matching events, including those beyond the limit
+    pub total: usize,
+    /// Matching events, newest first, up to the limit
+    pub events: Vec<AuditEvent>,
+}
+
+/// File upload multipart form schema.
+#[derive(utoipa::ToSchema)]
+pub struct UploadFileForm {
+    /// HTML, PDF or text file
+    #[schema(format = Binary)]
+    pub file: String,
+    /// Intended purpose
+    #[schema(example = "assistants")]
+    pub purpose: Option<String>,
+}
+
+/// Uploaded file schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct FileObject {
+    /// File ID
+    #[schema(example = "file-6f1c2a9e0b7d4c1e8a3b5d7f9e2c4a6b")]
+    pub id: String,
+    /// Always "file"
+    pub object: String,
+    /// File size in bytes
+    pub bytes: usize,
+    /// Upload time (Unix seconds)
+    pub created_at: i64,
+    /// Name of the uploaded file
+    pub filename: String,
+    /// Intended purpose
+    pub purpose: String,
+}
+
+/// Vector store creation request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct CreateVectorStoreRequest {
+    /// Store name
+    #[schema(example = "Support articles")]
+    pub name: Option<String>,
+    /// Uploaded files to add to the store
+    #[serde(default)]
+    pub file_ids: Vec<String>,
+    /// Caller-defined key/value pairs
+    #[serde(default)]
+    pub metadata: std::collections::HashMap<String, String>,
+}
+
+/// Vector store schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStore {
+    /// Store ID, also the namespace of its nodes
+    #[schema(example = "vs_3e5b7d9f1a2c4e6b8d0f2a4c6e8b0d2f")]
+    pub id: String,
+    /// Always "vector_store"
+    pub object: String,
+    /// Creation time (Unix seconds)
+    pub created_at: i64,
+    /// Store name
+    pub name: Option<String>,
+    /// Total size of the files in the store, in bytes
+    pub usage_bytes: usize,
+    /// Number of files by processing status
+    pub file_counts: VectorStoreFileCounts,
+    /// Always "completed"
+    pub status: String,
+    /// Caller-defined key/value pairs
+    pub metadata: std::collections::HashMap<String, String>,
+}
+
+/// Vector store file counts schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStoreFileCounts {
+    /// Files being processed
+    pub in_progress: usize,
+    /// Files ingested successfully
+    pub completed: usize,
+    /// Files that could not be ingested
+    pub failed: usize,
+    /// Files whose processing was cancelled
+    pub cancelled: usize,
+    /// All files
+    pub total: usize,
+}
+
+/// Vector store list schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStoreList {
+    /// Always "list"
+    pub object: String,
+    /// Stores, oldest first
+    pub data: Vec<VectorStore>,
+    /// ID of the first store
+    pub first_id: Option<String>,
+    /// ID of the last store
+    pub last_id: Option<String>,
+    /// Always false
+    pub has_more: bool,
+}
+
+/// Vector store file creation request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct CreateVectorStoreFileRequest {
+    /// ID of the uploaded file
+    pub file_id: String,
+    /// Key/value pairs returned with search results from this file
+    #[serde(default)]
+    pub attributes: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// Vector store file schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStoreFile {
+    /// ID of the uploaded file
+    pub id: String,
+    /// Always "vector_store.file"
+    pub object: String,
+    /// Time the file was added (Unix seconds)
+    pub created_at: i64,
+    /// Store the file belongs to
+    pub vector_store_id: String,
+    /// File size in bytes
+    pub usage_bytes: usize,
+    /// Always "completed"
+    pub status: String,
+    /// Key/value pairs returned with search results from this file
+    pub attributes: std::collections::HashMap<String, serde_json::Value>,
+    /// Document node the file was ingested as
+    pub document_id: Uuid,
+}
+
+/// Vector store file list schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStoreFileList {
+    /// Always "list"
+    pub object: String,
+    /// Files, oldest first
+    pub data: Vec<VectorStoreFile>,
+    /// ID of the first file
+    pub first_id: Option<String>,
+    /// ID of the last file
+    pub last_id: Option<String>,
+    /// Always false
+    pub has_more: bool,
 }
 
+/// Deleted object schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct DeletedObject {
+    /// ID of the deleted object
+    pub id: String,
+    /// Type of the deleted object, e.g. "vector_store.deleted"
+    pub object: String,
+    /// Always true
+    pub deleted: bool,
+}
+
+/// Vector store search request schema.
+#[derive(utoipa::ToSchema, serde::Deserialize)]
+pub struct VectorStoreSearchRequest {
+    /// Search query, or an array of queries
+    #[schema(example = "How do I reset my password?")]
+    pub query: serde_json::Value,
+    /// Maximum number of results (1 - 50)
+    #[schema(default = 10, minimum = 1, maximum = 50)]
+    pub max_num_results: Option<usize>,
+}
+
+/// Vector store search result schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStoreSearchResult {
+    /// File the chunk came from
+    pub file_id: String,
+    /// Name of that file
+    pub filename: String,
+    /// Similarity to the query (0.0 - 1.0)
+    pub score: f32,
+    /// Attributes of the file
+    pub attributes: std::collections::HashMap<String, serde_json::Value>,
+    /// Chunk text
+    pub content: Vec<SearchResultContent>,
+}
+
+/// Search result content schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct SearchResultContent {
+    /// Always "text"
+    #[serde(rename = "type")]
+    pub content_type: String,
+    /// Chunk text
+    pub text: String,
+}
+
+/// Vector store search response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct VectorStoreSearchResponse {
+    /// Always "vector_store.search_results.page"
+    pub object: String,
+    /// The queries searched for
+    pub search_query: Vec<String>,
+    /// Matching chunks, best first
+    pub data: Vec<VectorStoreSearchResult>,
+    /// Always false
+    pub has_more: bool,
+    /// Always null
+    pub next_page: Option<String>,
+}
+
+/// Span lifecycle schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct LifecycleView {
+    /// Span ID
+    pub id: String,
+    /// Span name (typically the function name)
+    pub name: String,
+    /// Start time (RFC 3339)
+    pub start_time: String,
+    /// End time (RFC 3339), if completed
+    pub end_time: Option<String>,
+    /// Duration in milliseconds
+    pub duration_ms: f64,
+    /// Span status
+    #[schema(example = "completed")]
+    pub status: String,
+    /// Nested spans
+    pub children: Vec<LifecycleView>,
+    /// Arguments passed to the function
+    pub args: Option<serde_json::Value>,
+    /// Return value, if completed
+    pub result: Option<serde_json::Value>,
+}
+
+/// Trace lifecycle response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct LifecycleResponse {
+    /// Root span of the trace with its nested spans
+    pub lifecycle: LifecycleView,
+}
+
+/// Trace timeline schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct TimelineView {
+    /// Trace ID
+    pub trace_id: String,
+    /// Events in chronological order, tagged by type (enter, exit,
+    /// checkpoint, error, custom)
+    pub events: Vec<serde_json::Value>,
+    /// Total duration of the trace in milliseconds
+    pub total_duration_ms: f64,
+}
+
+/// Trace timeline response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct TimelineResponse {
+    /// Events of the trace
+    pub timeline: TimelineView,
+}
+
+/// Instrumentation statistics schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct InstrumentStatistics {
+    /// Spans created
+    pub spans_created: u64,
+    /// Spans completed successfully
+    pub spans_completed: u64,
+    /// Spans that failed
+    pub spans_failed: u64,
+    /// Checkpoints recorded
+    pub checkpoints: u64,
+    /// Spans currently running
+    pub active_spans: usize,
+    /// Events recorded
+    pub total_events: usize,
+}
+
+/// Instrumentation statistics response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct StatisticsResponse {
+    /// Collector counters
+    pub statistics: InstrumentStatistics,
+}
+
+/// Trace export response schema.
+#[derive(utoipa::ToSchema, serde::Serialize)]
+pub struct ExportResponse {
+    /// Export format (json, mermaid, text)
+    #[schema(example = "json")]
+    pub format: String,
+    /// Exported trace: the trace as JSON, or the diagram source as a string
+    pub data: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_refs<'a>(value: &'a serde_json::Value, refs: &mut Vec<&'a str>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    match (key.as_str(), value.as_str()) {
+                        ("$ref", Some(reference)) => refs.push(reference),
+                        _ => collect_refs(value, refs),
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_schema_refs_resolve() {
+        let spec = serde_json::to_value(openapi_spec()).unwrap();
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+
+        let mut refs = Vec::new();
+        collect_refs(&spec, &mut refs);
+        assert!(!refs.is_empty());
+        for reference in refs {
+            let name = reference.strip_prefix("#/components/schemas/").unwrap();
+            assert!(schemas.contains_key(name), "unregistered schema {}", name);
+        }
+    }
+
+    #[test]
+    fn test_paths_cover_routes() {
+        let spec = openapi_spec();
+        for path in [
+            "/documents",
+            "/ingest/file",
+            "/pipelines",
+            "/pipelines/{name}/run",
+            "/pipelines/{name}/runs",
+            "/memory/expiring",
+            "/query/structured",
+            "/traverse",
+            "/views",
+            "/views/{name}",
+            "/graph/match",
+            "/rag/feedback",
+            "/nodes/{id}/usage",
+            "/nodes/{id}/inspect",
+            "/nodes/{id}/attachments",
+            "/nodes/{id}/attachments/{attachment_id}",
+            "/nodes/{id}/tags",
+            "/nodes/{id}/tags/{tag}",
+            "/review/edges",
+            "/review/edges/approve",
+            "/export",
+            "/admin/vector/calibrate",
+            "/admin/vector/evaluate",
+            "/admin/vector/delta",
+            "/admin/audit",
+            "/admin/usage",
+            "/admin/storage/migrate",
+            "/admin/self-check",
+            "/admin/seed",
+            "/admin/storage/integrity",
+            "/admin/reindex",
+            "/jobs",
+            "/jobs/{id}",
+            "/api/v1/instr/traces/live",
+        ] {
+            assert!(spec.paths.paths.contains_key(path), "undocumented path {}", path);
+        }
+
+        let traverse = serde_json::to_value(&spec.components.unwrap().schemas["TraverseRequest"]).unwrap();
+        assert_eq!(
+            traverse["properties"]["direction"]["$ref"],
+            "#/components/schemas/TraverseDirection"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Evaluation of PaQL searches and pipeline stages.
+//!
+//! A query's search is a predicate over nodes: text leaves match content,
+//! `And`, `Or` and `Not` combine their inputs and filters narrow theirs.
+//! Leaves that are not decided by a node alone (similarity searches,
+//! traversals from a seed, graph patterns and fact roles) are resolved to
+//! node sets before the predicate runs. The matching nodes then pass through the query's stages in order.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use synton_core::Node;
+use synton_paql::{Query, QueryNode, SortField, SortFieldType, SortOrder, Stage, TraverseDirection};
+use uuid::Uuid;
+
+use crate::explain;
+
+/// Node sets of the search leaves resolved ahead of matching.
+#[derive(Debug, Default)]
+pub(crate) struct ResolvedLeaves {
+    /// Nearest neighbours of each `similar to` query text.
+    pub(crate) similar: HashMap<String, HashSet<Uuid>>,
+    /// Nodes reachable from each traversal seed, by direction and hops.
+    pub(crate) reachable: HashMap<(Uuid, TraverseDirection, usize), HashSet<Uuid>>,
+    /// Nodes bound by each graph pattern, by returned variable.
+    pub(crate) matched: HashMap<(String, Option<String>), HashSet<Uuid>>,
+    /// Facts each participant plays each role in.
+    pub(crate) roles: HashMap<(String, Uuid), HashSet<Uuid>>,
+}
+
+/// Leaves of a search that need resolving before matching.
+pub(crate) fn unresolved_leaves(query: &QueryNode) -> Vec<&QueryNode> {
+    let mut leaves = Vec::new();
+    let mut stack = vec![query];
+
+    while let Some(node) = stack.pop() {
+        match node {
+            QueryNode::HybridSearch { .. }
+            | QueryNode::GraphTraversal { .. }
+            | QueryNode::Match { .. }
+            | QueryNode::Role { .. } => leaves.push(node),
+            QueryNode::Filter { input, .. } | QueryNode::Not { input } => stack.push(input),
+            QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+                stack.push(left);
+                stack.push(right);
+            }
+            _ => {}
+        }
+    }
+
+    leaves
+}
+
+/// Whether a query's results depend on edges.
+pub(crate) fn uses_edges(query: &Query) -> bool {
+    query.stages.iter().any(|stage| matches!(stage, Stage::Traverse { .. }))
+        || unresolved_leaves(&query.root).iter().any(|leaf| {
+            matches!(
+                leaf,
+                QueryNode::GraphTraversal { .. } | QueryNode::Match { .. } | QueryNode::Role { .. }
+            )
+        })
+}
+
+/// Whether a node matches a search. Text matching is case-insensitive; a
+/// `similar to` leaf also matches content containing its text. Semantic
+/// leaves without text match nothing.
+pub(crate) fn matches(node: &Node, query: &QueryNode, resolved: &ResolvedLeaves) -> bool {
+    match query {
+        QueryNode::Empty | QueryNode::SemanticSearch { .. } => false,
+        QueryNode::TextSearch { query } => contains_text(node, query),
+        QueryNode::Tagged { tag } => node.has_tag(tag),
+        QueryNode::HybridSearch { query, .. } => {
+            resolved.similar.get(query).is_some_and(|ids| ids.contains(&node.id))
+                || contains_text(node, query)
+        }
+        QueryNode::GraphTraversal {
+            seed_id,
+            direction,
+            max_hops,
+        } => resolved
+            .reachable
+            .get(&(*seed_id, *direction, *max_hops))
+            .is_some_and(|ids| ids.contains(&node.id)),
+        QueryNode::Match { pattern, var } => resolved
+            .matched
+            .get(&(pattern.clone(), var.clone()))
+            .is_some_and(|ids| ids.contains(&node.id)),
+        QueryNode::Role { role, participant } => resolved
+            .roles
+            .get(&(role.clone(), *participant))
+            .is_some_and(|ids| ids.contains(&node.id)),
+        QueryNode::Filter { input, filters } => {
+            matches(node, input, resolved) && filters.iter().all(|f| explain::satisfies(node, f))
+        }
+        QueryNode::And { left, right } => {
+            matches(node, left, resolved) && matches(node, right, resolved)
+        }
+        QueryNode::Or { left, right } => {
+            matches(node, left, resolved) || matches(node, right, resolved)
+        }
+        QueryNode::Not { input } => !matches(node, input, resolved),
+    }
+}
+
+fn contains_text(node: &Node, text: &str) -> bool {
+    node.content().to_lowercase().contains(&text.to_lowercase())
+}
+
+/// Sort nodes by the given fields, the first deciding. Relevance is the
+/// order the nodes are already in; the sort is stable.
+pub(crate) fn sort_nodes(nodes: &mut [Node], fields: &[SortField]) {
+    nodes.sort_by(|a, b| {
+        fields
+            .iter()
+            .map(|field| {
+                let ordering = compare_field(a, b, &field.field);
+                match field.order {
+                    SortOrder::Asc => ordering,
+                    SortOrder::Desc => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+fn compare_field(a: &Node, b: &Node, field: &SortFieldType) -> Ordering {
+    match field {
+        SortFieldType::Relevance => Ordering::Equal,
+        SortFieldType::AccessScore => a.meta.access_score.total_cmp(&b.meta.access_score),
+        SortFieldType::Confidence => a.meta.confidence.total_cmp(&b.meta.confidence),
+        SortFieldType::CreatedAt => a.meta.created_at.cmp(&b.meta.created_at),
+        SortFieldType::Custom(name) => {
+            let (a, b) = (a.attributes.get(name), b.attributes.get(name));
+            match (a.and_then(|v| v.as_f64()), b.and_then(|v| v.as_f64())) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                _ => {
+                    let text = |v: Option<&serde_json::Value>| v.and_then(|v| v.as_str());
+                    text(a).cmp(&text(b))
+                }
+            }
+        }
+    }
+}
+
+/// Graph direction of a query traversal.
+pub(crate) fn graph_direction(direction: TraverseDirection) -> synton_graph::TraverseDirection {
+    match direction {
+        TraverseDirection::Forward => synton_graph::TraverseDirection::Forward,
+        TraverseDirection::Backward => synton_graph::TraverseDirection::Backward,
+        TraverseDirection::Both => synton_graph::TraverseDirection::Both,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+    use synton_paql::Parser;
+
+    fn node(content: &'static str, confidence: f32) -> Node {
+        let mut node = Node::new(content, NodeType::Fact);
+        node.meta.confidence = confidence;
+        node
+    }
+
+    #[test]
+    fn test_matches_combinators() {
+        let pumps = node("Pumps move water", 0.9);
+        let valves = node("Valves stop water", 0.4);
+        let resolved = ResolvedLeaves::default();
+        let search = |text: &str| Parser::new().parse(text).unwrap().root;
+
+        let and = search("water and pumps");
+        assert!(matches(&pumps, &and, &resolved));
+        assert!(!matches(&valves, &and, &resolved));
+
+        let not = search("not pumps");
+        assert!(!matches(&pumps, &not, &resolved));
+        assert!(matches(&valves, &not, &resolved));
+
+        let filtered = search("water where confidence > 0.5");
+        assert!(matches(&pumps, &filtered, &resolved));
+        assert!(!matches(&valves, &filtered, &resolved));
+
+        let mut resolved = ResolvedLeaves::default();
+        resolved
+            .similar
+            .insert("fluids".to_string(), HashSet::from([valves.id]));
+        let similar = search("similar to fluids");
+        assert!(matches(&valves, &similar, &resolved));
+        assert!(!matches(&pumps, &similar, &resolved));
+        assert_eq!(unresolved_leaves(&similar), vec![&similar]);
+
+        let participant = Uuid::new_v4();
+        resolved
+            .roles
+            .insert(("agent".to_string(), participant), HashSet::from([pumps.id]));
+        let role = search(&format!("role Agent {}", participant));
+        assert!(matches(&pumps, &role, &resolved));
+        assert!(!matches(&valves, &role, &resolved));
+        assert_eq!(unresolved_leaves(&role), vec![&role]);
+    }
+
+    #[test]
+    fn test_sort_nodes() {
+        let mut nodes = vec![node("a", 0.5), node("b", 0.9), node("c", 0.5)];
+        nodes[0].attributes = serde_json::json!({ "rank": 2 });
+        nodes[2].attributes = serde_json::json!({ "rank": 1 });
+
+        sort_nodes(
+            &mut nodes,
+            &[
+                SortField::new(SortFieldType::Confidence, SortOrder::Desc),
+                SortField::new(SortFieldType::Custom("rank".to_string()), SortOrder::Asc),
+            ],
+        );
+        let order: Vec<&str> = nodes.iter().map(Node::content).collect();
+        assert_eq!(order, vec!["b", "c", "a"]);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Server-side cache of compiled PaQL query templates.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use synton_paql::PreparedQuery;
+
+/// Default maximum number of cached prepared queries.
+pub(crate) const DEFAULT_PREPARED_CAPACITY: usize = 1024;
+
+/// Bounded cache of prepared queries keyed by handle.
+///
+/// Preparing the same template twice returns the existing handle. When the
+/// cache is full the oldest entry is evicted.
+#[derive(Debug)]
+pub(crate) struct PreparedQueryCache {
+    capacity: usize,
+    by_handle: HashMap<Uuid, Arc<PreparedQuery>>,
+    by_template: HashMap<String, Uuid>,
+    order: VecDeque<Uuid>,
+}
+
+impl PreparedQueryCache {
+    /// Create an empty cache holding at most `capacity` entries.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            by_handle: HashMap::new(),
+            by_template: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Look up the handle of an already prepared template.
+    pub(crate) fn handle_for(&self, template: &str) -> Option<Uuid> {
+        self.by_template.get(template).copied()
+    }
+
+    /// Get a prepared query by handle.
+    pub(crate) fn get(&self, handle: Uuid) -> Option<Arc<PreparedQuery>> {
+        self.by_handle.get(&handle).cloned()
+    }
+
+    /// Insert a prepared query and return its handle.
+    pub(crate) fn insert(&mut self, prepared: PreparedQuery) -> Uuid {
+        if let Some(handle) = self.handle_for(prepared.template()) {
+            return handle;
+        }
+
+        while self.order.len() >= self.capacity {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(evicted) = self.by_handle.remove(&oldest) {
+                self.by_template.remove(evicted.template());
+            }
+        }
+
+        let handle = Uuid::new_v4();
+        self.by_template.insert(prepared.template().to_string(), handle);
+        self.by_handle.insert(handle, Arc::new(prepared));
+        self.order.push_back(handle);
+        handle
+    }
+
+    /// Number of cached queries.
+    pub(crate) fn len(&self) -> usize {
+        self.by_handle.len()
+    }
+}
+
+impl Default for PreparedQueryCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_PREPARED_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_paql::Parser;
+
+    #[test]
+    fn test_insert_dedupes_templates() {
+        let parser = Parser::new();
+        let mut cache = PreparedQueryCache::default();
+
+        let first = cache.insert(parser.prepare("find $topic").unwrap());
+        let second = cache.insert(parser.prepare("find $topic").unwrap());
+
+        assert_eq!(first, second);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(first).is_some());
+    }
+
+    #[test]
+    fn test_evicts_oldest_when_full() {
+        let parser = Parser::new();
+        let mut cache = PreparedQueryCache::new(2);
+
+        let a = cache.insert(parser.prepare("find $a").unwrap());
+        let b = cache.insert(parser.prepare("find $b").unwrap());
+        let c = cache.insert(parser.prepare("find $c").unwrap());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(a).is_none());
+        assert!(cache.handle_for("find $a").is_none());
+        assert!(cache.get(b).is_some());
+        assert!(cache.get(c).is_some());
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Result cache for repeated queries.
+//!
+//! Entries are keyed by the parsed query and the options that shape its
+//! results, and expire after a TTL. They are also dropped as soon as a
+//! mutation could change them: a node that was among the results, or whose
+//! content contains one of the query's terms, was inserted, replaced or
+//! removed; or, for queries ranked by centrality or traversing edges, an
+//! edge was added. Access score changes do not invalidate entries, so result
+//! order may lag by up to the TTL.
+//!
+//! Invalidation is conservative, but a write racing a lookup can still be
+//! missed until the TTL. Queries run with [`Consistency::Strong`] skip the
+//! lookup and refresh the cached result instead.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use synton_core::Node;
+use synton_memory::NAMESPACE_ATTRIBUTE;
+use uuid::Uuid;
+
+use crate::models::{Consistency, MatchExplanation, QueryCacheStats};
+use crate::namespaces;
+
+/// Query result cache settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryCacheConfig {
+    /// Maximum number of cached results; the oldest is evicted when full.
+    pub capacity: usize,
+
+    /// How long a cached result may be served.
+    pub ttl: Duration,
+
+    /// Consistency of queries that do not request one.
+    pub default_consistency: Consistency,
+}
+
+impl Default for QueryCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            ttl: Duration::from_secs(30),
+            default_consistency: Consistency::Eventual,
+        }
+    }
+}
+
+/// A cached query result.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedQuery {
+    pub(crate) nodes: Vec<Node>,
+    pub(crate) total_count: usize,
+    pub(crate) truncated: bool,
+    pub(crate) matches: Vec<MatchExplanation>,
+}
+
+/// What a cached query depends on, used to decide which mutations
+/// invalidate it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CacheScope {
+    /// Lowercased text terms; a node containing any of them may match.
+    pub(crate) terms: Vec<String>,
+    /// Namespace the results are restricted to.
+    pub(crate) namespace: Option<String>,
+    /// Whether the results or their ranking depend on edges.
+    pub(crate) uses_edges: bool,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    result: Arc<CachedQuery>,
+    scope: CacheScope,
+    node_ids: HashSet<Uuid>,
+    expires_at: Instant,
+    seq: u64,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    config: Option<QueryCacheConfig>,
+    entries: HashMap<String, CacheEntry>,
+    order: VecDeque<(String, u64)>,
+    seq: u64,
+    generation: u64,
+    hits: u64,
+    misses: u64,
+    invalidations: u64,
+    evictions: u64,
+}
+
+/// Shared, internally synchronized query result cache; disabled until
+/// configured.
+#[derive(Debug, Default)]
+pub(crate) struct QueryCache {
+    state: Mutex<CacheState>,
+}
+
+impl QueryCache {
+    fn state(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Enable the cache with the given settings, or disable it with `None`.
+    /// Cached results and statistics are cleared.
+    pub(crate) fn configure(&self, config: Option<QueryCacheConfig>) {
+        let mut state = self.state();
+        let generation = state.generation + 1;
+        *state = CacheState {
+            config: config.map(|c| QueryCacheConfig {
+                capacity: c.capacity.max(1),
+                ..c
+            }),
+            generation,
+            ..Default::default()
+        };
+    }
+
+    /// Whether results are being cached.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.state().config.is_some()
+    }
+
+    /// Consistency of queries that do not request one; strong while the
+    /// cache is disabled.
+    pub(crate) fn default_consistency(&self) -> Consistency {
+        self.state()
+            .config
+            .map_or(Consistency::Strong, |config| config.default_consistency)
+    }
+
+    /// Counter bumped by every mutation notification. Take it before
+    /// computing a result and pass it to [`QueryCache::insert`], so a result
+    /// computed while the data changed is not cached.
+    pub(crate) fn generation(&self) -> u64 {
+        self.state().generation
+    }
+
+    /// Look up a live cached result.
+    pub(crate) fn get(&self, key: &str) -> Option<Arc<CachedQuery>> {
+        let mut state = self.state();
+        state.config?;
+
+        let live = match state.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(Arc::clone(&entry.result)),
+            Some(_) => {
+                state.entries.remove(key);
+                None
+            }
+            None => None,
+        };
+        match live {
+            Some(_) => state.hits += 1,
+            None => state.misses += 1,
+        }
+        live
+    }
+
+    /// Cache a result computed at `generation`, unless the data changed
+    /// since.
+    pub(crate) fn insert(&self, key: String, generation: u64, scope: CacheScope, result: CachedQuery) {
+        let mut guard = self.state();
+        let state = &mut *guard;
+        let Some(config) = state.config else {
+            return;
+        };
+        if state.generation != generation {
+            return;
+        }
+
+        // Forget the order of invalidated entries once they pile up
+        if state.order.len() > 2 * config.capacity {
+            let entries = &state.entries;
+            state
+                .order
+                .retain(|(key, seq)| entries.get(key).is_some_and(|e| e.seq == *seq));
+        }
+
+        state.entries.remove(&key);
+        while state.entries.len() >= config.capacity {
+            let Some((oldest, seq)) = state.order.pop_front() else {
+                break;
+            };
+            // Skip keys already invalidated or re-inserted since
+            if state.entries.get(&oldest).is_some_and(|e| e.seq == seq) {
+                state.entries.remove(&oldest);
+                state.evictions += 1;
+            }
+        }
+
+        state.seq += 1;
+        let seq = state.seq;
+        state.order.push_back((key.clone(), seq));
+        state.entries.insert(
+            key,
+            CacheEntry {
+                node_ids: result.nodes.iter().map(|node| node.id).collect(),
+                result: Arc::new(result),
+                scope,
+                expires_at: Instant::now() + config.ttl,
+                seq,
+            },
+        );
+    }
+
+    /// Drop results a node insert, update or removal could change.
+    pub(crate) fn node_changed(&self, node: &Node) {
+        let mut state = self.state();
+        state.generation += 1;
+        if state.entries.is_empty() {
+            return;
+        }
+
+        let content = node.content().to_lowercase();
+        let namespace = node.attributes.get(NAMESPACE_ATTRIBUTE).and_then(|v| v.as_str());
+        let before = state.entries.len();
+        state.entries.retain(|_, entry| {
+            // Any inheritance direction may relate the two namespaces
+            let in_namespace = match (entry.scope.namespace.as_deref(), namespace) {
+                (None, _) => true,
+                (Some(scope), Some(namespace)) => namespaces::related(scope, namespace),
+                (Some(_), None) => false,
+            };
+            let affected = entry.node_ids.contains(&node.id)
+                || (in_namespace && entry.scope.terms.iter().any(|t| content.contains(t.as_str())));
+            !affected
+        });
+        state.invalidations += (before - state.entries.len()) as u64;
+    }
+
+    /// Drop results that depend on edges.
+    pub(crate) fn edge_added(&self) {
+        let mut state = self.state();
+        state.generation += 1;
+        let before = state.entries.len();
+        state.entries.retain(|_, entry| !entry.scope.uses_edges);
+        state.invalidations += (before - state.entries.len()) as u64;
+    }
+
+    /// Cache statistics, if the cache is enabled.
+    pub(crate) fn stats(&self) -> Option<QueryCacheStats> {
+        let state = self.state();
+        state.config?;
+        let lookups = state.hits + state.misses;
+        Some(QueryCacheStats {
+            entries: state.entries.len(),
+            hits: state.hits,
+            misses: state.misses,
+            hit_rate: if lookups == 0 {
+                0.0
+            } else {
+                state.hits as f64 / lookups as f64
+            },
+            invalidations: state.invalidations,
+            evictions: state.evictions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    fn cached(nodes: Vec<Node>) -> CachedQuery {
+        CachedQuery {
+            total_count: nodes.len(),
+            nodes,
+            truncated: false,
+            matches: Vec::new(),
+        }
+    }
+
+    fn scope(term: &str) -> CacheScope {
+        CacheScope {
+            terms: vec![term.to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hits_misses_and_ttl() {
+        let cache = QueryCache::default();
+        cache.insert("q".to_string(), cache.generation(), scope("rust"), cached(Vec::new()));
+        assert!(cache.get("q").is_none(), "disabled cache stores nothing");
+
+        cache.configure(Some(QueryCacheConfig::default()));
+        cache.insert("q".to_string(), cache.generation(), scope("rust"), cached(Vec::new()));
+        assert!(cache.get("q").is_some());
+        assert!(cache.get("other").is_none());
+
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+
+        cache.configure(Some(QueryCacheConfig {
+            ttl: Duration::ZERO,
+            ..Default::default()
+        }));
+        cache.insert("q".to_string(), cache.generation(), scope("rust"), cached(Vec::new()));
+        assert!(cache.get("q").is_none());
+    }
+
+    #[test]
+    fn test_invalidates_only_affected_results() {
+        let cache = QueryCache::default();
+        cache.configure(Some(QueryCacheConfig::default()));
+
+        let rust = Node::new("Rust ownership", NodeType::Concept);
+        cache.insert("rust".to_string(), cache.generation(), scope("rust"), cached(vec![rust.clone()]));
+        cache.insert("python".to_string(), cache.generation(), scope("python"), cached(Vec::new()));
+        cache.insert(
+            "ranked".to_string(),
+            cache.generation(),
+            CacheScope {
+                uses_edges: true,
+                ..scope("go")
+            },
+            cached(Vec::new()),
+        );
+
+        // A new node matching a term invalidates that query only
+        cache.node_changed(&Node::new("Python typing", NodeType::Concept));
+        assert!(cache.get("python").is_none());
+        assert!(cache.get("rust").is_some());
+
+        // A changed result invalidates even if it no longer matches
+        let mut renamed = rust.clone();
+        renamed.content = "Ownership".into();
+        cache.node_changed(&renamed);
+        assert!(cache.get("rust").is_none());
+
+        cache.edge_added();
+        assert!(cache.get("ranked").is_none());
+        assert_eq!(cache.stats().unwrap().invalidations, 3);
+    }
+
+    #[test]
+    fn test_skips_results_computed_during_a_change() {
+        let cache = QueryCache::default();
+        cache.configure(Some(QueryCacheConfig {
+            capacity: 1,
+            ..Default::default()
+        }));
+
+        let generation = cache.generation();
+        cache.node_changed(&Node::new("Unrelated", NodeType::Fact));
+        cache.insert("stale".to_string(), generation, scope("rust"), cached(Vec::new()));
+        assert!(cache.get("stale").is_none());
+
+        cache.insert("a".to_string(), cache.generation(), scope("a"), cached(Vec::new()));
+        cache.insert("b".to_string(), cache.generation(), scope("b"), cached(Vec::new()));
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert_eq!(cache.stats().unwrap().evictions, 1);
+    }
+}
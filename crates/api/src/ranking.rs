@@ -0,0 +1,157 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Signals for user-defined ranking expressions.
+//!
+//! Each query candidate gets a [`RankVars`] built from its metadata, its
+//! similarity to the query and its degree in the graph; the expression is
+//! evaluated over them and the candidates are ordered by the result. A
+//! recency weight blends the score with the candidate's current memory
+//! strength.
+
+use chrono::{DateTime, Utc};
+use synton_core::Node;
+use synton_paql::RankVars;
+
+/// Age at which a node's recency drops to one half.
+const RECENCY_HALF_LIFE_HOURS: f64 = 7.0 * 24.0;
+
+/// Recency of a node: 1.0 when it was just created, updated or accessed,
+/// halving every [`RECENCY_HALF_LIFE_HOURS`].
+pub(crate) fn recency(node: &Node, now: DateTime<Utc>) -> f64 {
+    let meta = &node.meta;
+    let last_touched = meta
+        .accessed_at
+        .map_or(meta.updated_at, |accessed| accessed.max(meta.updated_at));
+    let hours = (now - last_touched).num_seconds().max(0) as f64 / 3600.0;
+    0.5f64.powf(hours / RECENCY_HALF_LIFE_HOURS)
+}
+
+/// Fraction of the query's words that occur in the content.
+pub(crate) fn lexical_similarity(terms: &[String], content: &str) -> f64 {
+    let words: Vec<&str> = terms.iter().flat_map(|t| t.split_whitespace()).collect();
+    if words.is_empty() {
+        return 0.0;
+    }
+    let content = content.to_lowercase();
+    let matched = words.iter().filter(|w| content.contains(*w)).count();
+    matched as f64 / words.len() as f64
+}
+
+/// Cosine similarity of two embeddings, clamped to 0.0 - 1.0.
+pub(crate) fn embedding_similarity(a: &[f32], b: &[f32]) -> Option<f64> {
+    if a.len() != b.len() || a.is_empty() {
+        return None;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f64, 0.0f64, 0.0f64);
+    for (x, y) in a.iter().zip(b) {
+        let (x, y) = (*x as f64, *y as f64);
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    let denom = norm_a.sqrt() * norm_b.sqrt();
+    (denom > 0.0).then(|| (dot / denom).clamp(0.0, 1.0))
+}
+
+/// Ranking variables of a candidate; `degree` is divided by the largest
+/// degree among the candidates to give its centrality, and `access_score`
+/// is the decayed score at query time rather than the stored one.
+pub(crate) fn rank_vars(
+    node: &Node,
+    similarity: f64,
+    degree: usize,
+    max_degree: usize,
+    access_score: f64,
+    now: DateTime<Utc>,
+) -> RankVars {
+    RankVars {
+        similarity,
+        recency: recency(node, now),
+        centrality: if max_degree == 0 {
+            0.0
+        } else {
+            degree as f64 / max_degree as f64
+        },
+        confidence: node.meta.confidence as f64,
+        access_score,
+    }
+}
+
+/// Blend a relevance score with a memory strength (0.0 - 1.0); `weight` is
+/// the share given to the strength.
+pub(crate) fn blend_recency(relevance: f64, strength: f64, weight: f64) -> f64 {
+    (1.0 - weight) * relevance + weight * strength
+}
+
+/// Order nodes by their scores, highest first. Ties keep the default
+/// access-score order.
+pub(crate) fn sort_by_score(nodes: Vec<Node>, scores: Vec<f64>) -> Vec<Node> {
+    let mut scored: Vec<(f64, Node)> = scores.into_iter().zip(nodes).collect();
+    scored.sort_by(|(a, x), (b, y)| {
+        b.total_cmp(a)
+            .then_with(|| y.meta.access_score.total_cmp(&x.meta.access_score))
+    });
+    scored.into_iter().map(|(_, node)| node).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    #[test]
+    fn test_recency_halves_per_half_life() {
+        let now = Utc::now();
+        let mut node = Node::new("fact", NodeType::Fact);
+        node.meta.updated_at = now;
+        assert!((recency(&node, now) - 1.0).abs() < 1e-9);
+
+        node.meta.updated_at = now - chrono::Duration::hours(7 * 24);
+        assert!((recency(&node, now) - 0.5).abs() < 1e-9);
+
+        // Accessing a node makes it fresh again
+        node.meta.accessed_at = Some(now);
+        assert!((recency(&node, now) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_similarity_signals() {
+        let terms = vec!["graph database".to_string()];
+        assert_eq!(lexical_similarity(&terms, "A Graph store"), 0.5);
+        assert_eq!(lexical_similarity(&[], "anything"), 0.0);
+
+        assert_eq!(embedding_similarity(&[1.0, 0.0], &[1.0, 0.0]), Some(1.0));
+        assert_eq!(embedding_similarity(&[1.0, 0.0], &[-1.0, 0.0]), Some(0.0));
+        assert_eq!(embedding_similarity(&[1.0], &[1.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_sort_by_rank() {
+        let now = Utc::now();
+        let nodes = vec![
+            Node::new("low confidence", NodeType::Fact).with_confidence(0.2),
+            Node::new("high confidence", NodeType::Fact).with_confidence(0.9),
+        ];
+        let vars: Vec<_> = nodes.iter().map(|n| rank_vars(n, 0.0, 0, 0, 1.0, now)).collect();
+        let scores = |expr: &str| {
+            let rank = synton_paql::RankExpr::parse(expr).unwrap();
+            vars.iter().map(|v| rank.eval(v)).collect()
+        };
+
+        let ranked = sort_by_score(nodes.clone(), scores("confidence"));
+        assert_eq!(ranked[0].content(), "high confidence");
+
+        let ranked = sort_by_score(nodes, scores("-confidence"));
+        assert_eq!(ranked[0].content(), "low confidence");
+    }
+
+    #[test]
+    fn test_blend_recency() {
+        // Equal relevance: the stronger memory wins
+        assert!(blend_recency(0.8, 0.9, 0.3) > blend_recency(0.8, 0.1, 0.3));
+        assert_eq!(blend_recency(0.8, 0.1, 0.0), 0.8);
+        assert_eq!(blend_recency(0.8, 0.1, 1.0), 0.1);
+    }
+}
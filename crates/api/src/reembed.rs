@@ -0,0 +1,202 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Background re-embedding after an embedding model change.
+//!
+//! Every embedding is tagged with the model that produced it. When the
+//! configured model of a language route changes, embeddings tagged with
+//! another model are stale; the re-embedder regenerates them a small batch
+//! at a time, pausing between batches so foreground traffic keeps priority.
+//! Until it finishes, searches serve a mix of old- and new-model vectors and
+//! each result's `meta.embedding_model` tells them apart.
+//!
+//! Embeddings stored before tagging are attributed to the models recorded
+//! in storage under [`EMBEDDING_MODELS_KEY`]. The current models are
+//! recorded once no stale embedding is left.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use synton_core::Node;
+use uuid::Uuid;
+
+/// Storage metadata key of the embedding models in use, as a JSON object
+/// keyed by language route (`""` for the default model).
+pub(crate) const EMBEDDING_MODELS_KEY: &str = "embedding_models";
+
+/// Re-embedding throttle settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReembedConfig {
+    /// Nodes re-embedded per batch.
+    pub batch_size: usize,
+
+    /// Pause between batches.
+    pub interval: Duration,
+}
+
+impl Default for ReembedConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 32,
+            interval: Duration::from_secs(1),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ReembedState {
+    /// Current model per route.
+    current: HashMap<String, String>,
+    /// Model that produced untagged embeddings, per route.
+    legacy: HashMap<String, String>,
+    queue: VecDeque<Uuid>,
+    failed_ids: HashSet<Uuid>,
+    running: bool,
+    reembedded: u64,
+    failed: u64,
+}
+
+/// Progress of the background re-embedding.
+#[derive(Debug, Default)]
+pub(crate) struct Reembedder {
+    state: Mutex<ReembedState>,
+}
+
+impl Reembedder {
+    fn state(&self) -> MutexGuard<'_, ReembedState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Set the current model per route, and the models recorded before
+    /// this run; with none recorded, untagged embeddings count as current.
+    pub(crate) fn set_models(
+        &self,
+        current: HashMap<String, String>,
+        recorded: Option<HashMap<String, String>>,
+    ) {
+        let mut state = self.state();
+        state.legacy = recorded.unwrap_or_else(|| current.clone());
+        state.current = current;
+    }
+
+    /// Current model per route.
+    pub(crate) fn current_models(&self) -> HashMap<String, String> {
+        self.state().current.clone()
+    }
+
+    /// Whether the embedding of a node on `route` was produced by a model
+    /// other than the route's current one. Nodes that already failed to
+    /// re-embed in this run are skipped.
+    pub(crate) fn is_stale(&self, node: &Node, route: &str) -> bool {
+        if node.embedding.is_none() {
+            return false;
+        }
+        let state = self.state();
+        if state.failed_ids.contains(&node.id) {
+            return false;
+        }
+        let Some(current) = state.current.get(route) else {
+            return false;
+        };
+        node.meta
+            .embedding_model
+            .as_ref()
+            .or_else(|| state.legacy.get(route))
+            .is_some_and(|model| model != current)
+    }
+
+    /// Queue stale nodes for re-embedding.
+    pub(crate) fn enqueue(&self, ids: Vec<Uuid>) {
+        let mut state = self.state();
+        state.queue.extend(ids);
+        state.running = true;
+    }
+
+    /// Take the next batch of queued nodes.
+    pub(crate) fn next_batch(&self, size: usize) -> Vec<Uuid> {
+        let mut state = self.state();
+        let size = size.min(state.queue.len());
+        state.queue.drain(..size).collect()
+    }
+
+    /// Count a node re-embedded, or failed.
+    pub(crate) fn record(&self, id: Uuid, reembedded: bool) {
+        let mut state = self.state();
+        if reembedded {
+            state.reembedded += 1;
+        } else {
+            state.failed += 1;
+            state.failed_ids.insert(id);
+        }
+    }
+
+    /// Stop running, returning whether every node was re-embedded.
+    pub(crate) fn finish(&self) -> bool {
+        let mut state = self.state();
+        state.running = false;
+        state.queue.clear();
+        state.failed_ids.is_empty()
+    }
+
+    /// Whether running, queued nodes, re-embedded and failed counts.
+    pub(crate) fn progress(&self) -> (bool, usize, u64, u64) {
+        let state = self.state();
+        (state.running, state.queue.len(), state.reembedded, state.failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    fn models(model: &str) -> HashMap<String, String> {
+        HashMap::from([(String::new(), model.to_string())])
+    }
+
+    #[test]
+    fn test_staleness() {
+        let reembedder = Reembedder::default();
+        reembedder.set_models(models("local:new"), Some(models("local:old")));
+
+        let embedded = |model: Option<&str>| {
+            let mut node = Node::new("Pumps", NodeType::Concept).with_embedding(vec![0.1, 0.2]);
+            node.meta.embedding_model = model.map(str::to_string);
+            node
+        };
+        assert!(reembedder.is_stale(&embedded(Some("local:old")), ""));
+        assert!(!reembedder.is_stale(&embedded(Some("local:new")), ""));
+        // Untagged embeddings were produced by the recorded model
+        assert!(reembedder.is_stale(&embedded(None), ""));
+        assert!(!reembedder.is_stale(&Node::new("No vector", NodeType::Fact), ""));
+        assert!(!reembedder.is_stale(&embedded(Some("local:old")), "zh"));
+
+        // Without recorded models untagged embeddings are current
+        reembedder.set_models(models("local:new"), None);
+        assert!(!reembedder.is_stale(&embedded(None), ""));
+    }
+
+    #[test]
+    fn test_progress() {
+        let reembedder = Reembedder::default();
+        reembedder.set_models(models("local:new"), Some(models("local:old")));
+        let ids: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+        reembedder.enqueue(ids.clone());
+
+        let batch = reembedder.next_batch(3);
+        assert_eq!(batch, ids[..3]);
+        reembedder.record(batch[0], true);
+        reembedder.record(batch[1], false);
+        assert_eq!(reembedder.progress(), (true, 2, 1, 1));
+
+        // A node that failed is not picked up again in this run
+        let mut failed = Node::new("Valves", NodeType::Concept).with_embedding(vec![0.3]);
+        failed.id = batch[1];
+        assert!(!reembedder.is_stale(&failed, ""));
+
+        assert!(!reembedder.finish());
+        assert_eq!(reembedder.progress(), (false, 0, 1, 1));
+    }
+}
@@ -2,350 +2,2309 @@
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 
-use axum::extract::{Path as AxumPath, State};
+use axum::extract::{Path as AxumPath, Query as AxumQuery, State};
 use std::sync::Arc;
+use synton_storage::Durability;
 use uuid::Uuid;
 
 use crate::{
     models::{
-        AddEdgeRequest, AddEdgeResponse, AddNodeRequest, AddNodeResponse,
-        DeleteNodeRequest, DeleteNodeResponse, GetNodeRequest, GetNodeResponse, HealthResponse,
-        HybridSearchRequest, HybridSearchResponse, IngestDocumentRequest, IngestDocumentResponse,
-        QueryRequest, QueryResponse, TraverseRequest, TraverseResponse,
+        ActivityResponse, AddEdgeRequest, AddEdgeResponse, AddNodeRequest, AddNodeResponse,
+        AttachNodeRequest, AttachmentInfo, AttachmentsResponse, DeleteAttachmentResponse,
+        AuditQuery, AuditResponse, BackgroundQuery, CalibrateVectorRequest, CompactStorageRequest, CompactStorageResponse, ConsistencyQuery, DeleteNodeRequest, ExpandQuery, DeleteNodeResponse, DocumentResponse, EvaluateVectorRequest, ExecuteQueryRequest,
+        ExpiringQuery, ExpiryDigest, ExportQuery, FeedbackRequest, FeedbackResponse, FeedbackStats, GetDocumentQuery, GetNodeRequest,
+        GetNodeResponse, GraphVizQuery, GraphVizResponse, HealthResponse, HybridSearchRequest, HybridSearchResponse,
+        IngestDocumentRequest, IngestDocumentResponse, IngestFileRequest, IngestUrlRequest,
+        IntegrityCheckRequest, IntegrityCheckResponse, Job, JobsResponse,
+        LimiterStats, ListDocumentsResponse, ListNodesQuery, MatchPatternRequest,
+        MatchPatternResponse, MemoryStatsResponse, MigrateStorageResponse, NodeTagsResponse,
+        OptimizeVectorRequest, PipelineRun, PipelineRunsResponse,
+        PipelinesResponse, PrepareQueryRequest,
+        PrepareQueryResponse, QueryRequest, QueryResponse, ReasonPathsRequest,
+        ReasonPathsResponse, ReembedStatus, ReviewEdgeRequest, ReviewEdgeResponse,
+        ReviewEdgesResponse, SeedQuery, SeedRequest, SeedResponse, SelfCheckResponse,
+        StorageStatsResponse,
+        StructuredQueryRequest,
+        UnseedResponse, InspectNodeQuery, NodeInspection,
+        NodeUsage, TagNodeRequest, TopAccessedQuery,
+        TopAccessedResponse, TraverseEstimate, TraverseRequest,
+        TraverseResponse, BulkUpdateNodesRequest, BulkUpdateNodesResponse, TransactionRequest, TransactionResponse,
+        UpdateNodeRequest, UpdateNodeResponse, UsageExportQuery,
+        CreateVectorStoreFileRequest, CreateVectorStoreRequest, DeletedObject, FileObject,
+        ListResponse, UploadFileRequest, VectorCalibration, VectorEvaluation, VectorStore, VectorStoreFile,
+        VectorDeltaQuery, ApplyVectorDeltaResponse,
+        VectorStoreSearchRequest, VectorStoreSearchResponse,
+        MaterializedView, ViewSummary, ViewsResponse,
     },
-    ApiResult, SyntonDbService,
+    limits::{ConcurrencyLimits, RequestLimiter},
+    service::{with_consistency, with_durability},
+    validation::ValidJson,
+    ApiError, ApiResult, SyntonDbService, ViewDefinition,
 };
 
-// Import OpenApi trait for utoipa
-use utoipa::OpenApi;
+// Re-export for utoipa
+pub use crate::openapi::{
+    ActivityResponse as OpenApiActivityResponse, AddEdgeRequest as OpenApiAddEdgeRequest, AddNodeRequest as OpenApiAddNodeRequest,
+    AttachmentForm as OpenApiAttachmentForm, AttachmentInfo as OpenApiAttachmentInfo,
+    AttachmentsResponse as OpenApiAttachmentsResponse,
+    DeleteAttachmentResponse as OpenApiDeleteAttachmentResponse,
+    AuditResponse as OpenApiAuditResponse,
+    BulkOperationRequest as OpenApiBulkOperationRequest,
+    BulkOperationResponse as OpenApiBulkOperationResponse,
+    CalibrateVectorRequest as OpenApiCalibrateVectorRequest,
+    CompactStorageRequest as OpenApiCompactStorageRequest,
+    CompactStorageResponse as OpenApiCompactStorageResponse, Consistency as OpenApiConsistency,
+    DatabaseStats as OpenApiDatabaseStats,
+    DeleteNodeRequest as OpenApiDeleteNodeRequest,
+    DeleteNodeResponse as OpenApiDeleteNodeResponse,
+    DocumentResponse as OpenApiDocumentResponse, EdgeInfo,
+    EvaluateVectorRequest as OpenApiEvaluateVectorRequest,
+    ApplyVectorDeltaResponse as OpenApiApplyVectorDeltaResponse,
+    StructuredQueryRequest as OpenApiStructuredQueryRequest,
+    ExecuteQueryRequest as OpenApiExecuteQueryRequest,
+    FeedbackRequest as OpenApiFeedbackRequest, FeedbackResponse as OpenApiFeedbackResponse,
+    FeedbackStats as OpenApiFeedbackStats, GraphVizResponse as OpenApiGraphVizResponse,
+    HealthResponse as OpenApiHealthResponse,
+    HybridSearchRequest as OpenApiHybridSearchRequest,
+    HybridSearchResponse as OpenApiHybridSearchResponse,
+    ImportResponse as OpenApiImportResponse,
+    IngestFileForm as OpenApiIngestFileForm, IngestUrlRequest as OpenApiIngestUrlRequest,
+    IntegrityCheckRequest as OpenApiIntegrityCheckRequest,
+    IntegrityCheckResponse as OpenApiIntegrityCheckResponse,
+    Job as OpenApiJob, JobsResponse as OpenApiJobsResponse,
+    LimiterStats as OpenApiLimiterStats,
+    ListDocumentsResponse as OpenApiListDocumentsResponse,
+    MatchPatternRequest as OpenApiMatchPatternRequest,
+    MatchPatternResponse as OpenApiMatchPatternResponse,
+    ExpiryDigest as OpenApiExpiryDigest,
+    MemoryStatsResponse as OpenApiMemoryStatsResponse,
+    MigrateStorageResponse as OpenApiMigrateStorageResponse, NodeInfo,
+    SeedRequest as OpenApiSeedRequest, SeedResponse as OpenApiSeedResponse,
+    UnseedResponse as OpenApiUnseedResponse,
+    NodeInspection as OpenApiNodeInspection, NodeTagsResponse as OpenApiNodeTagsResponse,
+    NodeUsage as OpenApiNodeUsage, TagNodeRequest as OpenApiTagNodeRequest,
+    OptimizeVectorRequest as OpenApiOptimizeVectorRequest,
+    OptimizeVectorResponse as OpenApiOptimizeVectorResponse,
+    PipelineRun as OpenApiPipelineRun, PipelineRunsResponse as OpenApiPipelineRunsResponse,
+    PipelinesResponse as OpenApiPipelinesResponse,
+    PrepareQueryRequest as OpenApiPrepareQueryRequest,
+    PrepareQueryResponse as OpenApiPrepareQueryResponse,
+    QueryRequest as OpenApiQueryRequest, QueryResponse as OpenApiQueryResponse,
+    ReviewEdgeRequest as OpenApiReviewEdgeRequest,
+    ReviewEdgeResponse as OpenApiReviewEdgeResponse,
+    ReviewEdgesResponse as OpenApiReviewEdgesResponse,
+    ReasonPathsRequest as OpenApiReasonPathsRequest,
+    ReasonPathsResponse as OpenApiReasonPathsResponse,
+    ReembedStatus as OpenApiReembedStatus,
+    StorageStatsResponse as OpenApiStorageStatsResponse,
+    SelfCheckResponse as OpenApiSelfCheckResponse,
+    TopAccessedResponse as OpenApiTopAccessedResponse,
+    TraverseEstimate as OpenApiTraverseEstimate, TraverseRequest as OpenApiTraverseRequest,
+    TransactionRequest as OpenApiTransactionRequest,
+    TransactionResponse as OpenApiTransactionResponse,
+    TraverseResponse as OpenApiTraverseResponse,
+    BulkUpdateNodesRequest as OpenApiBulkUpdateNodesRequest,
+    BulkUpdateNodesResponse as OpenApiBulkUpdateNodesResponse,
+    UpdateNodeRequest as OpenApiUpdateNodeRequest, UpdateNodeResponse as OpenApiUpdateNodeResponse,
+    CreateVectorStoreFileRequest as OpenApiCreateVectorStoreFileRequest,
+    CreateVectorStoreRequest as OpenApiCreateVectorStoreRequest,
+    DeletedObject as OpenApiDeletedObject, FileObject as OpenApiFileObject,
+    UploadFileForm as OpenApiUploadFileForm, VectorCalibration as OpenApiVectorCalibration,
+    VectorEvaluation as OpenApiVectorEvaluation,
+    VectorStore as OpenApiVectorStore,
+    VectorStoreFile as OpenApiVectorStoreFile, VectorStoreFileList as OpenApiVectorStoreFileList,
+    VectorStoreList as OpenApiVectorStoreList,
+    VectorStoreSearchRequest as OpenApiVectorStoreSearchRequest,
+    VectorStoreSearchResponse as OpenApiVectorStoreSearchResponse,
+    MaterializedView as OpenApiMaterializedView, ViewDefinition as OpenApiViewDefinition,
+    ViewSummary as OpenApiViewSummary, ViewsResponse as OpenApiViewsResponse,
+};
+
+/// Application state for the REST API.
+#[derive(Clone)]
+pub struct AppState {
+    /// The database service.
+    pub service: Arc<SyntonDbService>,
+
+    /// Per-route-class concurrency limiter.
+    pub limiter: Arc<RequestLimiter>,
+}
+
+impl AppState {
+    /// Create a new application state with the default concurrency limits.
+    pub fn new(service: Arc<SyntonDbService>) -> Self {
+        Self::with_limits(service, &ConcurrencyLimits::default())
+    }
+
+    /// Create a new application state with the given concurrency limits.
+    pub fn with_limits(service: Arc<SyntonDbService>, limits: &ConcurrencyLimits) -> Self {
+        Self {
+            service,
+            limiter: Arc::new(RequestLimiter::new(limits)),
+        }
+    }
+}
+
+/// Health check handler.
+///
+/// Returns the health status and version of the API.
+#[utoipa::path(
+    get,
+    path = "/health",
+    responses(
+        (status = 200, description = "Service is healthy", body = OpenApiHealthResponse)
+    ),
+    tag = "health"
+)]
+pub async fn health_check() -> axum::Json<HealthResponse> {
+    // Get a dummy service for health check
+    let service = SyntonDbService::new();
+    axum::Json(service.health())
+}
+
+/// Get statistics handler.
+///
+/// Returns database statistics including node and edge counts.
+#[utoipa::path(
+    get,
+    path = "/stats",
+    responses(
+        (status = 200, description = "Statistics retrieved successfully", body = OpenApiDatabaseStats)
+    ),
+    tag = "health"
+)]
+pub async fn stats(
+    State(state): State<AppState>,
+) -> ApiResult<axum::Json<crate::models::DatabaseStats>> {
+    let stats = state.service.stats().await?;
+    Ok(axum::Json(stats))
+}
+
+/// Memory statistics handler.
+///
+/// Returns memory decay statistics overall and per decay profile.
+#[utoipa::path(
+    get,
+    path = "/memory/stats",
+    responses(
+        (status = 200, description = "Memory statistics retrieved successfully", body = OpenApiMemoryStatsResponse)
+    ),
+    tag = "health"
+)]
+pub async fn memory_stats(State(state): State<AppState>) -> axum::Json<MemoryStatsResponse> {
+    axum::Json(state.service.memory_stats().await)
+}
+
+/// Expiring nodes handler.
+///
+/// Returns the nodes predicted to decay to their minimum score, and so
+/// become pruning candidates, within the coming days.
+#[utoipa::path(
+    get,
+    path = "/memory/expiring",
+    params(
+        ("days" = Option<u32>, Query, description = "Days ahead to look (default 7, max 365)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of nodes (default 100, max 1000)")
+    ),
+    responses(
+        (status = 200, description = "Expiry digest compiled successfully", body = OpenApiExpiryDigest)
+    ),
+    tag = "health"
+)]
+pub async fn expiring_nodes(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<ExpiringQuery>,
+) -> axum::Json<ExpiryDigest> {
+    axum::Json(state.service.expiring_nodes(query).await)
+}
+
+/// Query parameters for the activity endpoint.
+#[derive(Debug, serde::Deserialize)]
+pub struct ActivityParams {
+    /// Maximum number of recent queries to return.
+    limit: Option<usize>,
+}
+
+/// Recent activity handler.
+///
+/// Returns the most recent queries and embedding cache statistics.
+#[utoipa::path(
+    get,
+    path = "/activity",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of recent queries (default 10)")
+    ),
+    responses(
+        (status = 200, description = "Activity retrieved successfully", body = OpenApiActivityResponse)
+    ),
+    tag = "health"
+)]
+pub async fn activity(
+    State(state): State<AppState>,
+    AxumQuery(params): AxumQuery<ActivityParams>,
+) -> axum::Json<ActivityResponse> {
+    let response = state.service.activity(params.limit.unwrap_or(10)).await;
+    axum::Json(response)
+}
+
+/// Runtime metrics handler.
+///
+/// Returns runtime metrics, internal queue depths, periodic task lag and
+/// embedding request concurrency in the Prometheus text format.
+#[cfg(feature = "runtime-diagnostics")]
+pub async fn metrics(State(state): State<AppState>) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    let diagnostics = state.service.runtime_diagnostics().await;
+    (
+        [(axum::http::header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        crate::diagnostics::render_prometheus(&diagnostics),
+    )
+        .into_response()
+}
+
+/// Routes of the runtime diagnostics endpoints; empty unless built with the
+/// `runtime-diagnostics` feature.
+pub fn diagnostics_routes() -> axum::Router<AppState> {
+    let routes = axum::Router::new();
+    #[cfg(feature = "runtime-diagnostics")]
+    let routes = routes.route("/metrics", axum::routing::get(metrics));
+    routes
+}
+
+/// Add a node handler.
+///
+/// Creates a new node in the database with the provided content and type.
+#[utoipa::path(
+    post,
+    path = "/nodes",
+    request_body = OpenApiAddNodeRequest,
+    responses(
+        (status = 200, description = "Node created successfully", body = AddNodeResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn add_node(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<AddNodeRequest>,
+) -> ApiResult<axum::Json<AddNodeResponse>> {
+    let response = state.service.add_node(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Get a node by ID handler.
+///
+/// Retrieves a node from the database by its UUID.
+#[utoipa::path(
+    get,
+    path = "/nodes/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID")
+    ),
+    responses(
+        (status = 200, description = "Node retrieved successfully", body = GetNodeResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn get_node(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+) -> ApiResult<axum::Json<GetNodeResponse>> {
+    let request = GetNodeRequest { id };
+    let response = state.service.get_node(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Get a node by external ID handler.
+///
+/// Resolves the ID a node was upserted with by an external system.
+#[utoipa::path(
+    get,
+    path = "/nodes/by-external-id/{external_id}",
+    params(
+        ("external_id" = String, Path, description = "External system ID")
+    ),
+    responses(
+        (status = 200, description = "Node retrieved successfully", body = GetNodeResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn get_node_by_external_id(
+    State(state): State<AppState>,
+    AxumPath(external_id): AxumPath<String>,
+) -> ApiResult<axum::Json<GetNodeResponse>> {
+    let response = state.service.get_node_by_external_id(&external_id).await?;
+    Ok(axum::Json(response))
+}
+
+/// Node usage handler.
+///
+/// Returns how often a node was returned by queries, visited by traversals
+/// and fetched by ID, and by whom.
+#[utoipa::path(
+    get,
+    path = "/nodes/{id}/usage",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID")
+    ),
+    responses(
+        (status = 200, description = "Node usage retrieved successfully", body = OpenApiNodeUsage),
+        (status = 404, description = "Node not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn node_usage(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+) -> ApiResult<axum::Json<NodeUsage>> {
+    let response = state.service.node_usage(id).await?;
+    Ok(axum::Json(response))
+}
+
+/// Node inspection handler.
+///
+/// Returns a node with its memory state, edges grouped by relation, most
+/// similar nodes and recent accesses. Inspecting is not recorded as an
+/// access.
+#[utoipa::path(
+    get,
+    path = "/nodes/{id}/inspect",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID"),
+        ("similar" = Option<usize>, Query, description = "Number of similar nodes (default 5, max 100)")
+    ),
+    responses(
+        (status = 200, description = "Node inspected successfully", body = OpenApiNodeInspection),
+        (status = 404, description = "Node not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn inspect_node(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+    AxumQuery(query): AxumQuery<InspectNodeQuery>,
+) -> ApiResult<axum::Json<NodeInspection>> {
+    let response = state.service.inspect_node(id, query).await?;
+    Ok(axum::Json(response))
+}
+
+/// Attach file handler.
+///
+/// Attaches the file of a multipart form to a node. The attachment is
+/// stored apart from the node and never embedded.
+#[utoipa::path(
+    post,
+    path = "/nodes/{id}/attachments",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID")
+    ),
+    request_body(content = OpenApiAttachmentForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "File attached successfully", body = OpenApiAttachmentInfo),
+        (status = 400, description = "Missing, empty or oversized file"),
+        (status = 404, description = "Node not found"),
+        (status = 413, description = "Upload too large")
+    ),
+    tag = "nodes"
+)]
+pub async fn attach_file(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+    mut multipart: axum::extract::Multipart,
+) -> ApiResult<axum::Json<AttachmentInfo>> {
+    let invalid = |e: axum::extract::multipart::MultipartError| ApiError::InvalidRequest(e.to_string());
+
+    while let Some(field) = multipart.next_field().await.map_err(invalid)? {
+        if field.name() == Some("file") {
+            let request = AttachNodeRequest {
+                node_id: id,
+                file_name: field.file_name().unwrap_or("attachment").to_string(),
+                content_type: field.content_type().map(str::to_string),
+                data: field.bytes().await.map_err(invalid)?.to_vec(),
+            };
+            let response = state.service.attach_file(request).await?;
+            return Ok(axum::Json(response));
+        }
+    }
+    Err(ApiError::InvalidRequest("missing 'file' field".to_string()))
+}
+
+/// List attachments handler.
+///
+/// Lists the attachments of a node, without their contents.
+#[utoipa::path(
+    get,
+    path = "/nodes/{id}/attachments",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID")
+    ),
+    responses(
+        (status = 200, description = "Attachments listed successfully", body = OpenApiAttachmentsResponse),
+        (status = 404, description = "Node not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn list_attachments(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+) -> ApiResult<axum::Json<AttachmentsResponse>> {
+    let response = state.service.list_attachments(id).await?;
+    Ok(axum::Json(response))
+}
+
+/// Get attachment handler.
+///
+/// Returns the contents of an attachment with its content type.
+#[utoipa::path(
+    get,
+    path = "/nodes/{id}/attachments/{attachment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID"),
+        ("attachment_id" = Uuid, Path, description = "Attachment UUID")
+    ),
+    responses(
+        (status = 200, description = "Attachment contents", content_type = "application/octet-stream", body = String),
+        (status = 404, description = "Attachment not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn get_attachment(
+    State(state): State<AppState>,
+    AxumPath((id, attachment_id)): AxumPath<(Uuid, Uuid)>,
+) -> ApiResult<axum::response::Response> {
+    use axum::http::{header, HeaderValue};
+    use axum::response::IntoResponse;
+
+    let (info, data) = state.service.get_attachment(id, attachment_id).await?;
+    let mut response = data.into_response();
+    let headers = response.headers_mut();
+    if let Ok(content_type) = HeaderValue::from_str(&info.content_type) {
+        headers.insert(header::CONTENT_TYPE, content_type);
+    }
+    // Names that are not printable ASCII are left to the client
+    let file_name = info.name.replace(['"', '\\'], "_");
+    let disposition = format!("attachment; filename=\"{}\"", file_name);
+    if let Ok(disposition) = HeaderValue::from_str(&disposition) {
+        headers.insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    Ok(response)
+}
+
+/// Delete attachment handler.
+///
+/// Deletes an attachment of a node.
+#[utoipa::path(
+    delete,
+    path = "/nodes/{id}/attachments/{attachment_id}",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID"),
+        ("attachment_id" = Uuid, Path, description = "Attachment UUID")
+    ),
+    responses(
+        (status = 200, description = "Attachment deleted successfully", body = OpenApiDeleteAttachmentResponse),
+        (status = 404, description = "Attachment not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    AxumPath((id, attachment_id)): AxumPath<(Uuid, Uuid)>,
+) -> ApiResult<axum::Json<DeleteAttachmentResponse>> {
+    let response = state.service.delete_attachment(id, attachment_id).await?;
+    Ok(axum::Json(response))
+}
+
+/// Tag node handler.
+///
+/// Adds tags to a node. Tags are trimmed and lowercased; tags the node
+/// already carries are kept once.
+#[utoipa::path(
+    post,
+    path = "/nodes/{id}/tags",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID")
+    ),
+    request_body = OpenApiTagNodeRequest,
+    responses(
+        (status = 200, description = "Node tagged successfully", body = OpenApiNodeTagsResponse),
+        (status = 400, description = "Invalid tag"),
+        (status = 404, description = "Node not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn tag_node(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+    ValidJson(request): ValidJson<TagNodeRequest>,
+) -> ApiResult<axum::Json<NodeTagsResponse>> {
+    let response = state.service.tag_node(id, request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Untag node handler.
+///
+/// Removes a tag from a node.
+#[utoipa::path(
+    delete,
+    path = "/nodes/{id}/tags/{tag}",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID"),
+        ("tag" = String, Path, description = "Tag to remove")
+    ),
+    responses(
+        (status = 200, description = "Tag removed successfully", body = OpenApiNodeTagsResponse),
+        (status = 404, description = "Node not found")
+    ),
+    tag = "nodes"
+)]
+pub async fn untag_node(
+    State(state): State<AppState>,
+    AxumPath((id, tag)): AxumPath<(Uuid, String)>,
+) -> ApiResult<axum::Json<NodeTagsResponse>> {
+    let response = state.service.untag_node(id, &tag).await?;
+    Ok(axum::Json(response))
+}
+
+/// Top accessed nodes handler.
+///
+/// Returns the most accessed nodes from the access log.
+#[utoipa::path(
+    get,
+    path = "/nodes/top-accessed",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of nodes (default 10, max 1000)"),
+        ("kind" = Option<String>, Query, description = "Count only accesses of this kind: query, traversal or get")
+    ),
+    responses(
+        (status = 200, description = "Top accessed nodes retrieved successfully", body = OpenApiTopAccessedResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn top_accessed(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<TopAccessedQuery>,
+) -> axum::Json<TopAccessedResponse> {
+    axum::Json(state.service.top_accessed(query))
+}
+
+/// Delete a node handler.
+///
+/// Deletes a node from the database by its ID.
+#[utoipa::path(
+    delete,
+    path = "/nodes/{id}",
+    request_body = OpenApiDeleteNodeRequest,
+    responses(
+        (status = 200, description = "Node deleted successfully", body = OpenApiDeleteNodeResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn delete_node(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<DeleteNodeRequest>,
+) -> ApiResult<axum::Json<DeleteNodeResponse>> {
+    let response = state.service.delete_node(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Update a node handler.
+///
+/// Changes a node's content, type or attributes in place, keeping its ID
+/// and edges. Changed content is re-embedded. With `expected_version`, the
+/// update applies only if the node is still at that version.
+#[utoipa::path(
+    patch,
+    path = "/nodes/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Node UUID")
+    ),
+    request_body = OpenApiUpdateNodeRequest,
+    responses(
+        (status = 200, description = "Node updated successfully", body = OpenApiUpdateNodeResponse),
+        (status = 404, description = "Node not found"),
+        (status = 409, description = "Node is no longer at the expected version")
+    ),
+    tag = "nodes"
+)]
+pub async fn update_node(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+    ValidJson(mut request): ValidJson<UpdateNodeRequest>,
+) -> ApiResult<axum::Json<UpdateNodeResponse>> {
+    request.id = id;
+    let response = state.service.update_node(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Bulk node update handler.
+///
+/// Applies several node updates in order; a failed update does not stop
+/// the others.
+#[utoipa::path(
+    post,
+    path = "/nodes/update",
+    request_body = OpenApiBulkUpdateNodesRequest,
+    responses(
+        (status = 200, description = "Updates applied", body = OpenApiBulkUpdateNodesResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn update_nodes(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<BulkUpdateNodesRequest>,
+) -> axum::Json<BulkUpdateNodesResponse> {
+    axum::Json(state.service.update_nodes(request).await)
+}
+
+/// Add an edge handler.
+///
+/// Creates a new edge (relationship) between two nodes.
+#[utoipa::path(
+    post,
+    path = "/edges",
+    request_body = OpenApiAddEdgeRequest,
+    responses(
+        (status = 200, description = "Edge created successfully", body = AddEdgeResponse)
+    ),
+    tag = "edges"
+)]
+pub async fn add_edge(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<AddEdgeRequest>,
+) -> ApiResult<axum::Json<AddEdgeResponse>> {
+    let response = state.service.add_edge(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Pending edges handler.
+///
+/// Lists the low-confidence edges held out of the graph until reviewed.
+#[utoipa::path(
+    get,
+    path = "/review/edges",
+    responses(
+        (status = 200, description = "Edges awaiting review", body = OpenApiReviewEdgesResponse)
+    ),
+    tag = "edges"
+)]
+pub async fn review_edges(State(state): State<AppState>) -> axum::Json<ReviewEdgesResponse> {
+    axum::Json(state.service.review_edges())
+}
+
+/// Approve edge handler.
+///
+/// Adds a pending edge to the graph.
+#[utoipa::path(
+    post,
+    path = "/review/edges/approve",
+    request_body = OpenApiReviewEdgeRequest,
+    responses(
+        (status = 200, description = "Edge approved", body = OpenApiReviewEdgeResponse),
+        (status = 404, description = "No such edge awaits review")
+    ),
+    tag = "edges"
+)]
+pub async fn approve_edge(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<ReviewEdgeRequest>,
+) -> ApiResult<axum::Json<ReviewEdgeResponse>> {
+    let response = state.service.approve_edge(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Reject edge handler.
+///
+/// Deletes a pending edge.
+#[utoipa::path(
+    post,
+    path = "/review/edges/reject",
+    request_body = OpenApiReviewEdgeRequest,
+    responses(
+        (status = 200, description = "Edge rejected", body = OpenApiReviewEdgeResponse),
+        (status = 404, description = "No such edge awaits review")
+    ),
+    tag = "edges"
+)]
+pub async fn reject_edge(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<ReviewEdgeRequest>,
+) -> ApiResult<axum::Json<ReviewEdgeResponse>> {
+    let response = state.service.reject_edge(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Query handler.
+///
+/// Performs a text-based search query on the database.
+#[utoipa::path(
+    post,
+    path = "/query",
+    params(
+        ("consistency" = Option<OpenApiConsistency>, Query, description = "Read consistency: `strong` skips the query cache (default from the server's configuration)"),
+        ("expand" = Option<usize>, Query, description = "1 attaches the immediate neighbors of each result"),
+        ("expand_limit" = Option<usize>, Query, description = "Most neighbors attached per result, strongest edges first (default 5, at most 50)")
+    ),
+    request_body = OpenApiQueryRequest,
+    responses(
+        (status = 200, description = "Query completed successfully", body = OpenApiQueryResponse)
+    ),
+    tag = "query"
+)]
+pub async fn query(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<ConsistencyQuery>,
+    AxumQuery(expand): AxumQuery<ExpandQuery>,
+    ValidJson(request): ValidJson<QueryRequest>,
+) -> ApiResult<axum::Json<QueryResponse>> {
+    let namespace = request.namespace.clone();
+    let mut response = with_consistency(query.consistency, state.service.query(request)).await?;
+    response.neighbors = state
+        .service
+        .expand_results(&response.nodes, namespace.as_deref(), &expand)
+        .await?;
+    Ok(axum::Json(response))
+}
+
+/// Prepare query handler.
+///
+/// Compiles a PaQL template with `$placeholders` and returns a handle for it.
+#[utoipa::path(
+    post,
+    path = "/query/prepare",
+    request_body = OpenApiPrepareQueryRequest,
+    responses(
+        (status = 200, description = "Query prepared successfully", body = OpenApiPrepareQueryResponse),
+        (status = 400, description = "Invalid query template")
+    ),
+    tag = "query"
+)]
+pub async fn prepare_query(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<PrepareQueryRequest>,
+) -> ApiResult<axum::Json<PrepareQueryResponse>> {
+    let response = state.service.prepare_query(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Structured query handler.
+///
+/// Runs a query given as a PaQL AST, bypassing the text parser. The AST is
+/// validated and executed like a parsed query.
+#[utoipa::path(
+    post,
+    path = "/query/structured",
+    params(
+        ("consistency" = Option<OpenApiConsistency>, Query, description = "Read consistency: `strong` skips the query cache (default from the server's configuration)"),
+        ("expand" = Option<usize>, Query, description = "1 attaches the immediate neighbors of each result"),
+        ("expand_limit" = Option<usize>, Query, description = "Most neighbors attached per result, strongest edges first (default 5, at most 50)")
+    ),
+    request_body = OpenApiStructuredQueryRequest,
+    responses(
+        (status = 200, description = "Query completed successfully", body = OpenApiQueryResponse),
+        (status = 400, description = "Invalid query AST")
+    ),
+    tag = "query"
+)]
+pub async fn structured_query(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<ConsistencyQuery>,
+    AxumQuery(expand): AxumQuery<ExpandQuery>,
+    ValidJson(request): ValidJson<StructuredQueryRequest>,
+) -> ApiResult<axum::Json<QueryResponse>> {
+    let namespace = request.namespace.clone();
+    let mut response =
+        with_consistency(query.consistency, state.service.structured_query(request)).await?;
+    response.neighbors = state
+        .service
+        .expand_results(&response.nodes, namespace.as_deref(), &expand)
+        .await?;
+    Ok(axum::Json(response))
+}
+
+/// Execute prepared query handler.
+///
+/// Binds values to a prepared query's placeholders and runs it.
+#[utoipa::path(
+    post,
+    path = "/query/execute",
+    params(
+        ("consistency" = Option<OpenApiConsistency>, Query, description = "Read consistency: `strong` skips the query cache (default from the server's configuration)"),
+        ("expand" = Option<usize>, Query, description = "1 attaches the immediate neighbors of each result"),
+        ("expand_limit" = Option<usize>, Query, description = "Most neighbors attached per result, strongest edges first (default 5, at most 50)")
+    ),
+    request_body = OpenApiExecuteQueryRequest,
+    responses(
+        (status = 200, description = "Query completed successfully", body = OpenApiQueryResponse),
+        (status = 400, description = "Missing or unknown parameters"),
+        (status = 404, description = "Prepared query not found")
+    ),
+    tag = "query"
+)]
+pub async fn execute_query(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<ConsistencyQuery>,
+    AxumQuery(expand): AxumQuery<ExpandQuery>,
+    ValidJson(request): ValidJson<ExecuteQueryRequest>,
+) -> ApiResult<axum::Json<QueryResponse>> {
+    let namespace = request.namespace.clone();
+    let mut response =
+        with_consistency(query.consistency, state.service.execute_prepared_query(request)).await?;
+    response.neighbors = state
+        .service
+        .expand_results(&response.nodes, namespace.as_deref(), &expand)
+        .await?;
+    Ok(axum::Json(response))
+}
+
+/// Hybrid search handler (GraphRAG).
+///
+/// Performs a hybrid search combining vector similarity and graph traversal,
+/// over the nodes of a type, namespace or tags if the request names them.
+#[utoipa::path(
+    post,
+    path = "/hybrid_search",
+    params(
+        ("expand" = Option<usize>, Query, description = "1 attaches the immediate neighbors of each result"),
+        ("expand_limit" = Option<usize>, Query, description = "Most neighbors attached per result, strongest edges first (default 5, at most 50)")
+    ),
+    request_body = OpenApiHybridSearchRequest,
+    responses(
+        (status = 200, description = "Hybrid search completed successfully", body = OpenApiHybridSearchResponse)
+    ),
+    tag = "query"
+)]
+pub async fn hybrid_search(
+    State(state): State<AppState>,
+    AxumQuery(expand): AxumQuery<ExpandQuery>,
+    ValidJson(request): ValidJson<HybridSearchRequest>,
+) -> ApiResult<axum::Json<HybridSearchResponse>> {
+    let nodes = state
+        .service
+        .hybrid_search_filtered(
+            &request.query,
+            request.k,
+            request.min_relevance,
+            request.filter(),
+        )
+        .await?;
+    let count = nodes.len();
+    let query_id = state.service.track_results(&nodes).await;
+    let neighbors = state.service.expand_results(&nodes, None, &expand).await?;
+    Ok(axum::Json(HybridSearchResponse {
+        nodes,
+        count,
+        query_id,
+        neighbors,
+    }))
+}
+
+/// Relevance feedback handler.
+///
+/// Marks a node returned by a query or hybrid search as useful or not,
+/// adjusting its access score and the learned relation weights.
+#[utoipa::path(
+    post,
+    path = "/rag/feedback",
+    request_body = OpenApiFeedbackRequest,
+    responses(
+        (status = 200, description = "Feedback recorded", body = OpenApiFeedbackResponse),
+        (status = 400, description = "Node was not part of the query results"),
+        (status = 404, description = "Query ID unknown or expired")
+    ),
+    tag = "query"
+)]
+pub async fn submit_feedback(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<FeedbackRequest>,
+) -> ApiResult<axum::Json<FeedbackResponse>> {
+    let response = state.service.submit_feedback(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Feedback statistics handler.
+///
+/// Returns aggregate relevance feedback and the learned relation weights.
+#[utoipa::path(
+    get,
+    path = "/rag/feedback/stats",
+    responses(
+        (status = 200, description = "Feedback statistics retrieved successfully", body = OpenApiFeedbackStats)
+    ),
+    tag = "query"
+)]
+pub async fn feedback_stats(State(state): State<AppState>) -> axum::Json<FeedbackStats> {
+    axum::Json(state.service.feedback_stats().await)
+}
+
+/// Ingest document handler.
+///
+/// Processes a document with automatic chunking and optional embedding generation.
+#[utoipa::path(
+    post,
+    path = "/documents",
+    request_body = IngestDocumentRequest,
+    responses(
+        (status = 200, description = "Document ingested successfully", body = IngestDocumentResponse)
+    ),
+    tag = "documents"
+)]
+pub async fn ingest_document(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<IngestDocumentRequest>,
+) -> ApiResult<axum::Json<IngestDocumentResponse>> {
+    let response = state.service.ingest_document(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Ingest URL handler.
+///
+/// Fetches a web page, PDF or text document and ingests its text; the URL
+/// is recorded as the source of the document and its chunks.
+#[utoipa::path(
+    post,
+    path = "/ingest/url",
+    request_body = OpenApiIngestUrlRequest,
+    responses(
+        (status = 200, description = "Document fetched and ingested successfully", body = IngestDocumentResponse),
+        (status = 400, description = "Invalid URL, unsupported document type or no extractable text"),
+        (status = 503, description = "The URL could not be fetched")
+    ),
+    tag = "documents"
+)]
+pub async fn ingest_url(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<IngestUrlRequest>,
+) -> ApiResult<axum::Json<IngestDocumentResponse>> {
+    let response = state.service.ingest_url(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Ingest file handler.
+///
+/// Reads an uploaded HTML, PDF or text file from a multipart form and
+/// ingests its text; the file name is recorded as the source.
+#[utoipa::path(
+    post,
+    path = "/ingest/file",
+    request_body(content = OpenApiIngestFileForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "File ingested successfully", body = IngestDocumentResponse),
+        (status = 400, description = "Missing file, unsupported document type or no extractable text"),
+        (status = 413, description = "File too large")
+    ),
+    tag = "documents"
+)]
+pub async fn ingest_file(
+    State(state): State<AppState>,
+    mut multipart: axum::extract::Multipart,
+) -> ApiResult<axum::Json<IngestDocumentResponse>> {
+    let invalid = |e: axum::extract::multipart::MultipartError| ApiError::InvalidRequest(e.to_string());
+
+    let mut request = IngestFileRequest {
+        file_name: String::new(),
+        content_type: None,
+        data: Vec::new(),
+        title: None,
+        chunking: None,
+        embed: true,
+        namespace: None,
+        summarize: false,
+        durability: Durability::default(),
+    };
+    let mut has_file = false;
+
+    while let Some(field) = multipart.next_field().await.map_err(invalid)? {
+        match field.name() {
+            Some("file") => {
+                request.file_name = field.file_name().unwrap_or("upload").to_string();
+                request.content_type = field.content_type().map(str::to_string);
+                request.data = field.bytes().await.map_err(invalid)?.to_vec();
+                has_file = true;
+            }
+            Some("title") => request.title = Some(field.text().await.map_err(invalid)?),
+            Some("namespace") => request.namespace = Some(field.text().await.map_err(invalid)?),
+            Some("chunking") => {
+                let text = field.text().await.map_err(invalid)?;
+                request.chunking = Some(serde_json::from_str(&text).map_err(|e| {
+                    ApiError::InvalidRequest(format!("invalid chunking strategy: {}", e))
+                })?);
+            }
+            Some("embed") => {
+                let text = field.text().await.map_err(invalid)?;
+                request.embed = text.trim().parse().map_err(|_| {
+                    ApiError::InvalidRequest(format!("embed must be true or false, got '{}'", text))
+                })?;
+            }
+            Some("summarize") => {
+                let text = field.text().await.map_err(invalid)?;
+                request.summarize = text.trim().parse().map_err(|_| {
+                    ApiError::InvalidRequest(format!(
+                        "summarize must be true or false, got '{}'",
+                        text
+                    ))
+                })?;
+            }
+            Some("durability") => {
+                let text = field.text().await.map_err(invalid)?;
+                request.durability = serde_json::from_value(serde_json::Value::String(
+                    text.trim().to_string(),
+                ))
+                .map_err(|_| ApiError::InvalidRequest(format!("invalid durability '{}'", text)))?;
+            }
+            _ => {}
+        }
+    }
+
+    if !has_file {
+        return Err(ApiError::InvalidRequest("missing 'file' field".to_string()));
+    }
+
+    let response = state.service.ingest_file(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// List pipelines handler.
+///
+/// Lists the configured ingestion pipelines with their latest run.
+#[utoipa::path(
+    get,
+    path = "/pipelines",
+    responses(
+        (status = 200, description = "Pipelines listed successfully", body = OpenApiPipelinesResponse)
+    ),
+    tag = "documents"
+)]
+pub async fn list_pipelines(State(state): State<AppState>) -> axum::Json<PipelinesResponse> {
+    axum::Json(state.service.pipelines())
+}
+
+/// Run pipeline handler.
+///
+/// Ingests every document of a pipeline's source; documents that fail are
+/// listed in the returned run.
+#[utoipa::path(
+    post,
+    path = "/pipelines/{name}/run",
+    params(("name" = String, Path, description = "Pipeline name")),
+    responses(
+        (status = 200, description = "Pipeline run finished", body = OpenApiPipelineRun),
+        (status = 404, description = "Pipeline not found"),
+        (status = 409, description = "Pipeline already running")
+    ),
+    tag = "documents"
+)]
+pub async fn run_pipeline(
+    State(state): State<AppState>,
+    AxumPath(name): AxumPath<String>,
+) -> ApiResult<axum::Json<PipelineRun>> {
+    let response = state.service.run_pipeline(&name).await?;
+    Ok(axum::Json(response))
+}
+
+/// Pipeline runs handler.
+///
+/// Lists the recent runs of a pipeline, newest first.
+#[utoipa::path(
+    get,
+    path = "/pipelines/{name}/runs",
+    params(("name" = String, Path, description = "Pipeline name")),
+    responses(
+        (status = 200, description = "Pipeline runs listed successfully", body = OpenApiPipelineRunsResponse),
+        (status = 404, description = "Pipeline not found")
+    ),
+    tag = "documents"
+)]
+pub async fn pipeline_runs(
+    State(state): State<AppState>,
+    AxumPath(name): AxumPath<String>,
+) -> ApiResult<axum::Json<PipelineRunsResponse>> {
+    let response = state.service.pipeline_runs(&name)?;
+    Ok(axum::Json(response))
+}
+
+/// List documents handler.
+///
+/// Lists ingested documents with their chunk counts.
+#[utoipa::path(
+    get,
+    path = "/documents",
+    responses(
+        (status = 200, description = "Documents listed successfully", body = OpenApiListDocumentsResponse)
+    ),
+    tag = "documents"
+)]
+pub async fn list_documents(
+    State(state): State<AppState>,
+) -> ApiResult<axum::Json<ListDocumentsResponse>> {
+    let response = state.service.list_documents().await?;
+    Ok(axum::Json(response))
+}
+
+/// Get document handler.
+///
+/// Reconstructs an ingested document by walking its `IsPartOf` edges and
+/// joining the chunks in order.
+#[utoipa::path(
+    get,
+    path = "/documents/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Document UUID"),
+        ("level" = Option<usize>, Query, description = "Hierarchy level to reconstruct from (0 = summary, 1 = paragraph, 2 = sentence)")
+    ),
+    responses(
+        (status = 200, description = "Document reconstructed successfully", body = OpenApiDocumentResponse),
+        (status = 400, description = "Node is not an ingested document"),
+        (status = 404, description = "Document not found")
+    ),
+    tag = "documents"
+)]
+pub async fn get_document(
+    State(state): State<AppState>,
+    AxumPath(id): AxumPath<Uuid>,
+    AxumQuery(query): AxumQuery<GetDocumentQuery>,
+) -> ApiResult<axum::Json<DocumentResponse>> {
+    let response = state.service.get_document(id, query).await?;
+    Ok(axum::Json(response))
+}
+
+/// Traverse handler.
+///
+/// Performs graph traversal (BFS) starting from a given node.
+#[utoipa::path(
+    post,
+    path = "/traverse",
+    request_body = OpenApiTraverseRequest,
+    responses(
+        (status = 200, description = "Traversal completed successfully", body = OpenApiTraverseResponse),
+        (status = 422, description = "Estimated cost exceeds max_estimated_cost")
+    ),
+    tag = "graph"
+)]
+pub async fn traverse(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<TraverseRequest>,
+) -> ApiResult<axum::Json<TraverseResponse>> {
+    let response = state.service.traverse(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Traversal cost estimate handler.
+///
+/// Predicts how many nodes and edges a traversal would touch, without
+/// running it, from cached degree statistics.
+#[utoipa::path(
+    post,
+    path = "/traverse/explain",
+    request_body = OpenApiTraverseRequest,
+    responses(
+        (status = 200, description = "Traversal cost estimated", body = OpenApiTraverseEstimate),
+        (status = 404, description = "Start node not found")
+    ),
+    tag = "graph"
+)]
+pub async fn explain_traverse(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<TraverseRequest>,
+) -> ApiResult<axum::Json<TraverseEstimate>> {
+    let estimate = state.service.explain_traverse(request).await?;
+    Ok(axum::Json(estimate))
+}
+
+/// List views handler.
+///
+/// Lists the materialized traversal views with their size.
+#[utoipa::path(
+    get,
+    path = "/views",
+    responses(
+        (status = 200, description = "Views listed successfully", body = OpenApiViewsResponse)
+    ),
+    tag = "graph"
+)]
+pub async fn list_views(State(state): State<AppState>) -> axum::Json<ViewsResponse> {
+    axum::Json(state.service.views())
+}
+
+/// Create view handler.
+///
+/// Defines or redefines a materialized traversal view and returns its
+/// members.
+#[utoipa::path(
+    post,
+    path = "/views",
+    request_body = OpenApiViewDefinition,
+    responses(
+        (status = 200, description = "View materialized", body = OpenApiMaterializedView),
+        (status = 400, description = "Invalid view definition")
+    ),
+    tag = "graph"
+)]
+pub async fn create_view(
+    State(state): State<AppState>,
+    ValidJson(definition): ValidJson<ViewDefinition>,
+) -> ApiResult<axum::Json<MaterializedView>> {
+    let response = state.service.create_view(definition).await?;
+    Ok(axum::Json(response))
+}
+
+/// Get view handler.
+///
+/// Returns a view's member nodes and the edges between them, traversing it
+/// again first only if a write touched it since it was last materialized.
+#[utoipa::path(
+    get,
+    path = "/views/{name}",
+    params(("name" = String, Path, description = "View name")),
+    responses(
+        (status = 200, description = "View retrieved", body = OpenApiMaterializedView),
+        (status = 404, description = "View not found")
+    ),
+    tag = "graph"
+)]
+pub async fn get_view(
+    State(state): State<AppState>,
+    AxumPath(name): AxumPath<String>,
+) -> ApiResult<axum::Json<MaterializedView>> {
+    let response = state.service.view(&name).await?;
+    Ok(axum::Json(response))
+}
+
+/// Delete view handler.
+///
+/// Drops a materialized view; the graph is unchanged.
+#[utoipa::path(
+    delete,
+    path = "/views/{name}",
+    params(("name" = String, Path, description = "View name")),
+    responses(
+        (status = 200, description = "View deleted", body = OpenApiViewSummary),
+        (status = 404, description = "View not found")
+    ),
+    tag = "graph"
+)]
+pub async fn delete_view(
+    State(state): State<AppState>,
+    AxumPath(name): AxumPath<String>,
+) -> ApiResult<axum::Json<ViewSummary>> {
+    let response = state.service.delete_view(&name)?;
+    Ok(axum::Json(response))
+}
+
+/// Reasoning paths handler.
+///
+/// Finds and ranks multi-hop reasoning chains from a source node (or the
+/// best matches of a query) to a target node.
+#[utoipa::path(
+    post,
+    path = "/reason/paths",
+    request_body = OpenApiReasonPathsRequest,
+    responses(
+        (status = 200, description = "Reasoning paths found", body = OpenApiReasonPathsResponse),
+        (status = 400, description = "Invalid source, query or hop limit"),
+        (status = 404, description = "Source or target node not found")
+    ),
+    tag = "graph"
+)]
+pub async fn reason_paths(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<ReasonPathsRequest>,
+) -> ApiResult<axum::Json<ReasonPathsResponse>> {
+    let response = state.service.reason_paths(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Graph pattern match handler.
+///
+/// Finds every binding of a motif such as
+/// `(a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c)`, up to a limit.
+#[utoipa::path(
+    post,
+    path = "/graph/match",
+    request_body = OpenApiMatchPatternRequest,
+    responses(
+        (status = 200, description = "Pattern matched", body = OpenApiMatchPatternResponse),
+        (status = 400, description = "Invalid pattern or limit")
+    ),
+    tag = "graph"
+)]
+pub async fn match_pattern(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<MatchPatternRequest>,
+) -> ApiResult<axum::Json<MatchPatternResponse>> {
+    let response = state.service.match_pattern(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Graph visualization handler.
+///
+/// Exports the neighbourhood of a center node, or the most accessed nodes,
+/// as force-graph JSON (`nodes` and `links`).
+#[utoipa::path(
+    get,
+    path = "/graph/viz",
+    params(
+        ("center" = Option<Uuid>, Query, description = "Node to center the view on (default: most accessed nodes)"),
+        ("depth" = Option<usize>, Query, description = "Hops around the center node (default 2, max 5)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of nodes (default 200, max 1000)")
+    ),
+    responses(
+        (status = 200, description = "Graph exported", body = OpenApiGraphVizResponse),
+        (status = 404, description = "Center node not found")
+    ),
+    tag = "graph"
+)]
+pub async fn graph_viz(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<GraphVizQuery>,
+) -> ApiResult<axum::Json<GraphVizResponse>> {
+    let response = state.service.graph_viz(query).await?;
+    Ok(axum::Json(response))
+}
+
+/// Get all nodes handler.
+///
+/// Returns all nodes in the database, or only those carrying a tag.
+#[utoipa::path(
+    get,
+    path = "/nodes",
+    params(
+        ("tag" = Option<String>, Query, description = "Only nodes carrying this tag")
+    ),
+    responses(
+        (status = 200, description = "All nodes retrieved successfully", body = Vec<NodeInfo>)
+    ),
+    tag = "nodes"
+)]
+pub async fn get_all_nodes(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<ListNodesQuery>,
+) -> ApiResult<axum::Json<Vec<synton_core::Node>>> {
+    let nodes = state.service.list_nodes(query).await?;
+    Ok(axum::Json(nodes))
+}
+
+/// Bulk operation handler.
+///
+/// Performs multiple node and edge operations in a single request.
+#[utoipa::path(
+    post,
+    path = "/bulk",
+    request_body = OpenApiBulkOperationRequest,
+    responses(
+        (status = 200, description = "Bulk operation completed successfully", body = OpenApiBulkOperationResponse)
+    ),
+    tag = "nodes"
+)]
+pub async fn bulk_operation(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<crate::models::BulkOperationRequest>,
+) -> ApiResult<axum::Json<crate::models::BulkOperationResponse>> {
+    let mut node_ids = Vec::new();
+    let mut edge_ids = Vec::new();
+    let mut success_count = 0;
+    let mut failure_count = 0;
+    let mut errors = Vec::new();
+
+    with_durability(request.durability, async {
+        // Add nodes
+        for node_req in request.nodes {
+            match state.service.add_node(node_req).await {
+                Ok(resp) => {
+                    node_ids.push(resp.node.id);
+                    success_count += 1;
+                }
+                Err(e) => {
+                    errors.push(format!("Node creation failed: {}", e));
+                    failure_count += 1;
+                }
+            }
+        }
+
+        // Add edges
+        for edge_req in request.edges {
+            match state.service.add_edge(edge_req).await {
+                Ok(resp) => {
+                    edge_ids.push(resp.edge.id());
+                    success_count += 1;
+                }
+                Err(e) => {
+                    errors.push(format!("Edge creation failed: {}", e));
+                    failure_count += 1;
+                }
+            }
+        }
+    })
+    .await;
+
+    let response = crate::models::BulkOperationResponse {
+        node_ids,
+        edge_ids,
+        success_count,
+        failure_count,
+        errors,
+    };
+
+    Ok(axum::Json(response))
+}
+
+/// Transaction handler.
+///
+/// Applies node and edge operations atomically: either all of them are
+/// stored or none is.
+#[utoipa::path(
+    post,
+    path = "/transactions",
+    request_body = OpenApiTransactionRequest,
+    responses(
+        (status = 200, description = "Transaction committed", body = OpenApiTransactionResponse),
+        (status = 400, description = "Invalid operation or unknown temporary ID"),
+        (status = 404, description = "Referenced node not found"),
+        (status = 500, description = "Storage failure; nothing was applied")
+    ),
+    tag = "nodes"
+)]
+pub async fn apply_transaction(
+    State(state): State<AppState>,
+    ValidJson(request): ValidJson<TransactionRequest>,
+) -> ApiResult<axum::Json<TransactionResponse>> {
+    let response = state.service.apply_transaction(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Optimize vector index handler.
+///
+/// Compacts fragments and retrains/rebuilds the vector index when due, or
+/// enqueues a job doing so with `background=true`.
+#[utoipa::path(
+    post,
+    path = "/admin/vector/optimize",
+    params(
+        ("background" = Option<bool>, Query, description = "Run as a background job and return it at once (default false)")
+    ),
+    request_body = OpenApiOptimizeVectorRequest,
+    responses(
+        (status = 200, description = "Maintenance completed successfully", body = OpenApiOptimizeVectorResponse),
+        (status = 202, description = "Maintenance job enqueued", body = OpenApiJob),
+        (status = 400, description = "No vector index configured")
+    ),
+    tag = "admin"
+)]
+pub async fn optimize_vector_index(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<BackgroundQuery>,
+    request: Option<axum::Json<OptimizeVectorRequest>>,
+) -> ApiResult<axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let request = request.map(|r| r.0).unwrap_or_default();
+    if query.background {
+        let job = state.service.submit_vector_optimize_job(request).await?;
+        return Ok((axum::http::StatusCode::ACCEPTED, axum::Json(job)).into_response());
+    }
+    let response = state.service.optimize_vector_index(request).await?;
+    Ok(axum::Json(response).into_response())
+}
+
+/// Calibrate vector similarity threshold handler.
+///
+/// Derives the default minimum relevance of vector matches from the score
+/// distribution of a sample of stored embeddings.
+#[utoipa::path(
+    post,
+    path = "/admin/vector/calibrate",
+    request_body = OpenApiCalibrateVectorRequest,
+    responses(
+        (status = 200, description = "Calibration completed successfully", body = OpenApiVectorCalibration),
+        (status = 400, description = "No vector index configured or too few embedded nodes")
+    ),
+    tag = "admin"
+)]
+pub async fn calibrate_vectors(
+    State(state): State<AppState>,
+    request: Option<axum::Json<CalibrateVectorRequest>>,
+) -> ApiResult<axum::Json<VectorCalibration>> {
+    let request = request.map(|r| r.0).unwrap_or_default();
+    let calibration = state.service.calibrate_vectors(request).await?;
+    Ok(axum::Json(calibration))
+}
+
+/// Evaluate vector index recall handler.
+///
+/// Measures the recall@k of the vector index with its current parameters
+/// against an exact scan of the stored embeddings.
+#[utoipa::path(
+    post,
+    path = "/admin/vector/evaluate",
+    request_body = OpenApiEvaluateVectorRequest,
+    responses(
+        (status = 200, description = "Evaluation completed successfully", body = OpenApiVectorEvaluation),
+        (status = 400, description = "No vector index configured or no embedded nodes")
+    ),
+    tag = "admin"
+)]
+pub async fn evaluate_vectors(
+    State(state): State<AppState>,
+    request: Option<axum::Json<EvaluateVectorRequest>>,
+) -> ApiResult<axum::Json<VectorEvaluation>> {
+    let request = request.map(|r| r.0).unwrap_or_default();
+    let evaluation = state.service.evaluate_vectors(request).await?;
+    Ok(axum::Json(evaluation))
+}
+
+/// Response header carrying the checkpoint a vector delta brings its target
+/// up to.
+pub const VECTOR_CHECKPOINT_HEADER: &str = "x-vector-checkpoint";
+
+/// Vector index delta export handler.
+///
+/// Returns the vectors written and removed since the checkpoint of a
+/// previous delta, or every vector without one, in the binary delta format.
+#[utoipa::path(
+    get,
+    path = "/admin/vector/delta",
+    params(
+        ("since" = Option<String>, Query, description = "Checkpoint of the previous delta, as <epoch>:<version>; a full delta is exported without one or when the index cannot continue from it")
+    ),
+    responses(
+        (status = 200, description = "Vector delta; its checkpoint is also sent in the x-vector-checkpoint header", content_type = "application/octet-stream", body = String),
+        (status = 400, description = "No vector index configured or malformed checkpoint"),
+        (status = 501, description = "The vector index does not track versions")
+    ),
+    tag = "admin"
+)]
+pub async fn export_vector_delta(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<VectorDeltaQuery>,
+) -> ApiResult<axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let delta = state.service.export_vector_delta(query).await?;
+    Ok((
+        [
+            (axum::http::header::CONTENT_TYPE.as_str(), "application/octet-stream".to_string()),
+            (VECTOR_CHECKPOINT_HEADER, delta.checkpoint.to_string()),
+        ],
+        delta.encode(),
+    )
+        .into_response())
+}
+
+/// Vector index delta apply handler.
+///
+/// Applies a delta exported by another server to the vector index.
+#[utoipa::path(
+    post,
+    path = "/admin/vector/delta",
+    request_body(content = String, description = "Vector delta", content_type = "application/octet-stream"),
+    responses(
+        (status = 200, description = "Delta applied", body = OpenApiApplyVectorDeltaResponse),
+        (status = 400, description = "No vector index configured, malformed delta or dimension mismatch"),
+        (status = 501, description = "The vector index cannot apply the delta")
+    ),
+    tag = "admin"
+)]
+pub async fn apply_vector_delta(
+    State(state): State<AppState>,
+    body: axum::body::Bytes,
+) -> ApiResult<axum::Json<ApplyVectorDeltaResponse>> {
+    let response = state.service.apply_vector_delta(&body).await?;
+    Ok(axum::Json(response))
+}
+
+/// Re-embedding status handler.
+///
+/// Reports the progress of regenerating embeddings after an embedding model
+/// change.
+#[utoipa::path(
+    get,
+    path = "/admin/embeddings/reembed",
+    responses(
+        (status = 200, description = "Re-embedding status retrieved successfully", body = OpenApiReembedStatus)
+    ),
+    tag = "admin"
+)]
+pub async fn reembed_status(State(state): State<AppState>) -> axum::Json<ReembedStatus> {
+    axum::Json(state.service.reembed_status())
+}
+
+/// Storage statistics handler.
+///
+/// Reports estimated keys, SST sizes and pending compaction bytes per column
+/// family.
+#[utoipa::path(
+    get,
+    path = "/admin/storage/stats",
+    responses(
+        (status = 200, description = "Storage statistics retrieved successfully", body = OpenApiStorageStatsResponse),
+        (status = 400, description = "No persistent storage configured")
+    ),
+    tag = "admin"
+)]
+pub async fn storage_stats(
+    State(state): State<AppState>,
+) -> ApiResult<axum::Json<StorageStatsResponse>> {
+    let response = state.service.storage_stats().await?;
+    Ok(axum::Json(response))
+}
+
+/// Self-check handler.
+///
+/// Embeds a test text with the embedding backend and checks the vector
+/// index, for diagnosing a deployment.
+#[utoipa::path(
+    get,
+    path = "/admin/self-check",
+    responses(
+        (status = 200, description = "Self-check completed", body = OpenApiSelfCheckResponse)
+    ),
+    tag = "admin"
+)]
+pub async fn self_check(State(state): State<AppState>) -> axum::Json<SelfCheckResponse> {
+    axum::Json(state.service.self_check().await)
+}
+
+/// Storage compaction handler.
+///
+/// Compacts one or all column families, in the background unless the
+/// request waits for it.
+#[utoipa::path(
+    post,
+    path = "/admin/storage/compact",
+    request_body = OpenApiCompactStorageRequest,
+    responses(
+        (status = 200, description = "Compaction started or completed", body = OpenApiCompactStorageResponse),
+        (status = 400, description = "No persistent storage configured or unknown column family")
+    ),
+    tag = "admin"
+)]
+pub async fn compact_storage(
+    State(state): State<AppState>,
+    request: Option<axum::Json<CompactStorageRequest>>,
+) -> ApiResult<axum::Json<CompactStorageResponse>> {
+    let request = request.map(|r| r.0).unwrap_or_default();
+    let response = state.service.compact_storage(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Storage migration handler.
+///
+/// Rewrites stored nodes and edges written in an older format version in
+/// the current one.
+#[utoipa::path(
+    post,
+    path = "/admin/storage/migrate",
+    responses(
+        (status = 200, description = "Stored values migrated", body = OpenApiMigrateStorageResponse),
+        (status = 400, description = "No persistent storage configured")
+    ),
+    tag = "admin"
+)]
+pub async fn migrate_storage(
+    State(state): State<AppState>,
+) -> ApiResult<axum::Json<MigrateStorageResponse>> {
+    let response = state.service.migrate_storage().await?;
+    Ok(axum::Json(response))
+}
+
+/// Seed handler.
+///
+/// Seeds an ontology of concepts and their `is_a` and `is_part_of` edges
+/// into a namespace: the bundled starter ontology unless the request
+/// brings one. A namespace already seeded is left as is.
+#[utoipa::path(
+    post,
+    path = "/admin/seed",
+    request_body = OpenApiSeedRequest,
+    responses(
+        (status = 200, description = "Ontology seeded, or namespace already seeded", body = OpenApiSeedResponse),
+        (status = 400, description = "Invalid ontology or namespace")
+    ),
+    tag = "admin"
+)]
+pub async fn seed(
+    State(state): State<AppState>,
+    request: Option<axum::Json<SeedRequest>>,
+) -> ApiResult<axum::Json<SeedResponse>> {
+    let request = request.map(|r| r.0).unwrap_or_default();
+    let response = state.service.seed(request).await?;
+    Ok(axum::Json(response))
+}
+
+/// Unseed handler.
+///
+/// Removes the nodes seeded into a namespace and the stored edges to or
+/// from them.
+#[utoipa::path(
+    delete,
+    path = "/admin/seed",
+    params(
+        ("namespace" = Option<String>, Query, description = "Seeded namespace (default starter)")
+    ),
+    responses(
+        (status = 200, description = "Seeded nodes removed", body = OpenApiUnseedResponse),
+        (status = 400, description = "Invalid namespace")
+    ),
+    tag = "admin"
+)]
+pub async fn unseed(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<SeedQuery>,
+) -> ApiResult<axum::Json<UnseedResponse>> {
+    let response = state.service.unseed(query).await?;
+    Ok(axum::Json(response))
+}
 
-// Re-export for utoipa
-pub use crate::openapi::{
-    AddEdgeRequest as OpenApiAddEdgeRequest, AddNodeRequest as OpenApiAddNodeRequest,
-    BulkOperationRequest as OpenApiBulkOperationRequest,
-    BulkOperationResponse as OpenApiBulkOperationResponse, DatabaseStats as OpenApiDatabaseStats,
-    DeleteNodeRequest as OpenApiDeleteNodeRequest,
-    DeleteNodeResponse as OpenApiDeleteNodeResponse, EdgeInfo, HealthResponse as OpenApiHealthResponse,
-    HybridSearchRequest as OpenApiHybridSearchRequest,
-    HybridSearchResponse as OpenApiHybridSearchResponse, NodeInfo,
-    QueryRequest as OpenApiQueryRequest, QueryResponse as OpenApiQueryResponse,
-    TraverseRequest as OpenApiTraverseRequest,
-    TraverseResponse as OpenApiTraverseResponse,
-};
+/// Storage integrity handler.
+///
+/// Lists stored edges whose source or target node is missing, and deletes
+/// them if the request asks for a repair.
+#[utoipa::path(
+    post,
+    path = "/admin/storage/integrity",
+    request_body = OpenApiIntegrityCheckRequest,
+    responses(
+        (status = 200, description = "Dangling edges found, and removed on repair", body = OpenApiIntegrityCheckResponse),
+        (status = 400, description = "No persistent storage configured")
+    ),
+    tag = "admin"
+)]
+pub async fn check_integrity(
+    State(state): State<AppState>,
+    request: Option<axum::Json<IntegrityCheckRequest>>,
+) -> ApiResult<axum::Json<IntegrityCheckResponse>> {
+    let request = request.map(|r| r.0).unwrap_or_default();
+    let response = state.service.check_integrity(request).await?;
+    Ok(axum::Json(response))
+}
 
-/// Application state for the REST API.
-#[derive(Clone)]
-pub struct AppState {
-    /// The database service.
-    pub service: Arc<SyntonDbService>,
+/// Reindex handler.
+///
+/// Enqueues a job rebuilding the in-memory graph, node table, memory
+/// manager registrations and vector index from storage.
+#[utoipa::path(
+    post,
+    path = "/admin/reindex",
+    responses(
+        (status = 202, description = "Reindex job enqueued", body = OpenApiJob),
+        (status = 400, description = "Persistent storage is not enabled")
+    ),
+    tag = "admin"
+)]
+pub async fn reindex(State(state): State<AppState>) -> ApiResult<(axum::http::StatusCode, axum::Json<Job>)> {
+    let job = state.service.submit_reindex_job().await?;
+    Ok((axum::http::StatusCode::ACCEPTED, axum::Json(job)))
 }
 
-impl AppState {
-    /// Create a new application state.
-    pub fn new(service: Arc<SyntonDbService>) -> Self {
-        Self { service }
+/// Snapshot export handler.
+///
+/// Streams a JSON Lines archive of a consistent snapshot: a header record,
+/// then nodes, edges and, if requested, node embeddings.
+#[utoipa::path(
+    get,
+    path = "/export",
+    params(
+        ("include_embeddings" = Option<bool>, Query, description = "Include node embeddings (default false)")
+    ),
+    responses(
+        (status = 200, description = "Export archive, one record per line", content_type = "application/x-ndjson", body = String)
+    ),
+    tag = "admin"
+)]
+pub async fn export_archive(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<ExportQuery>,
+) -> axum::response::Response {
+    use axum::response::IntoResponse;
+
+    let stream = state.service.export_archive(query).await;
+    (
+        [(axum::http::header::CONTENT_TYPE, crate::export::EXPORT_CONTENT_TYPE)],
+        axum::body::Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+/// Archive import handler.
+///
+/// Restores the nodes, edges and embeddings of an export archive, skipping
+/// those already present, or enqueues a job doing so with `background=true`.
+#[utoipa::path(
+    post,
+    path = "/import",
+    params(
+        ("background" = Option<bool>, Query, description = "Run as a background job and return it at once (default false)")
+    ),
+    request_body(content = String, description = "Export archive", content_type = "application/x-ndjson"),
+    responses(
+        (status = 200, description = "Archive imported", body = OpenApiImportResponse),
+        (status = 202, description = "Import job enqueued", body = OpenApiJob),
+        (status = 400, description = "Malformed archive or unsupported version")
+    ),
+    tag = "admin"
+)]
+pub async fn import_archive(
+    State(state): State<AppState>,
+    AxumQuery(query): AxumQuery<BackgroundQuery>,
+    body: String,
+) -> ApiResult<axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    if query.background {
+        let job = state.service.submit_import_job(body).await?;
+        return Ok((axum::http::StatusCode::ACCEPTED, axum::Json(job)).into_response());
     }
+    let response = state.service.import_archive(&body).await?;
+    Ok(axum::Json(response).into_response())
 }
 
-/// Health check handler.
+/// List jobs handler.
 ///
-/// Returns the health status and version of the API.
+/// Lists background jobs, newest first.
 #[utoipa::path(
     get,
-    path = "/health",
+    path = "/jobs",
     responses(
-        (status = 200, description = "Service is healthy", body = OpenApiHealthResponse)
+        (status = 200, description = "Jobs listed successfully", body = OpenApiJobsResponse)
     ),
-    tag = "health"
+    tag = "admin"
 )]
-pub async fn health_check() -> axum::Json<HealthResponse> {
-    // Get a dummy service for health check
-    let service = SyntonDbService::new();
-    axum::Json(service.health())
+pub async fn list_jobs(State(state): State<AppState>) -> axum::Json<JobsResponse> {
+    axum::Json(state.service.jobs())
 }
 
-/// Get statistics handler.
+/// Get job handler.
 ///
-/// Returns database statistics including node and edge counts.
+/// Reports the status, progress and, once finished, the result or error of
+/// a background job.
 #[utoipa::path(
     get,
-    path = "/stats",
+    path = "/jobs/{id}",
+    params(("id" = Uuid, Path, description = "Job ID")),
     responses(
-        (status = 200, description = "Statistics retrieved successfully", body = OpenApiDatabaseStats)
+        (status = 200, description = "Job retrieved successfully", body = OpenApiJob),
+        (status = 404, description = "Job not found")
     ),
-    tag = "health"
+    tag = "admin"
 )]
-pub async fn stats(
+pub async fn get_job(
     State(state): State<AppState>,
-) -> ApiResult<axum::Json<crate::models::DatabaseStats>> {
-    let stats = state.service.stats().await?;
-    Ok(axum::Json(stats))
+    AxumPath(id): AxumPath<Uuid>,
+) -> ApiResult<axum::Json<Job>> {
+    let job = state.service.job(id)?;
+    Ok(axum::Json(job))
 }
 
-/// Add a node handler.
+/// Request limiter statistics handler.
 ///
-/// Creates a new node in the database with the provided content and type.
+/// Reports in-flight, queued, admitted and rejected requests per route class.
 #[utoipa::path(
-    post,
-    path = "/nodes",
-    request_body = OpenApiAddNodeRequest,
+    get,
+    path = "/admin/limits",
     responses(
-        (status = 200, description = "Node created successfully", body = AddNodeResponse)
+        (status = 200, description = "Limiter statistics retrieved successfully", body = OpenApiLimiterStats)
     ),
-    tag = "nodes"
+    tag = "admin"
 )]
-pub async fn add_node(
-    State(state): State<AppState>,
-    axum::Json(request): axum::Json<AddNodeRequest>,
-) -> ApiResult<axum::Json<AddNodeResponse>> {
-    let response = state.service.add_node(request).await?;
-    Ok(axum::Json(response))
+pub async fn limits_stats(State(state): State<AppState>) -> axum::Json<LimiterStats> {
+    axum::Json(state.limiter.stats())
 }
 
-/// Get a node by ID handler.
+/// Audit trail handler.
 ///
-/// Retrieves a node from the database by its UUID.
+/// Lists recorded mutations, newest first, optionally within a time range
+/// and by one actor.
 #[utoipa::path(
     get,
-    path = "/nodes/{id}",
+    path = "/admin/audit",
     params(
-        ("id" = Uuid, Path, description = "Node UUID")
+        ("since" = Option<String>, Query, description = "Only mutations at or after this RFC 3339 time"),
+        ("until" = Option<String>, Query, description = "Only mutations before this RFC 3339 time"),
+        ("actor" = Option<String>, Query, description = "Only mutations by this actor (x-actor header)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of events (default 100, max 1000)")
     ),
     responses(
-        (status = 200, description = "Node retrieved successfully", body = GetNodeResponse)
+        (status = 200, description = "Audit events retrieved successfully", body = OpenApiAuditResponse),
+        (status = 400, description = "since is after until")
     ),
-    tag = "nodes"
+    tag = "admin"
 )]
-pub async fn get_node(
+pub async fn audit_events(
     State(state): State<AppState>,
-    AxumPath(id): AxumPath<Uuid>,
-) -> ApiResult<axum::Json<GetNodeResponse>> {
-    let request = GetNodeRequest { id };
-    let response = state.service.get_node(request).await?;
+    AxumQuery(query): AxumQuery<AuditQuery>,
+) -> ApiResult<axum::Json<AuditResponse>> {
+    let response = state.service.audit_events(query).await?;
     Ok(axum::Json(response))
 }
 
-/// Delete a node handler.
+/// Usage export handler.
 ///
-/// Deletes a node from the database by its ID.
+/// Exports the daily usage of each API key or tenant as CSV, optionally
+/// within a range of days and for one account.
 #[utoipa::path(
-    delete,
-    path = "/nodes/{id}",
-    request_body = OpenApiDeleteNodeRequest,
+    get,
+    path = "/admin/usage",
+    params(
+        ("since" = Option<String>, Query, description = "Only usage on or after this day (YYYY-MM-DD, UTC)"),
+        ("until" = Option<String>, Query, description = "Only usage on or before this day (YYYY-MM-DD, UTC)"),
+        ("account" = Option<String>, Query, description = "Only usage of this tenant or key account")
+    ),
     responses(
-        (status = 200, description = "Node deleted successfully", body = OpenApiDeleteNodeResponse)
+        (status = 200, description = "Daily usage, one account and day per line", content_type = "text/csv", body = String),
+        (status = 400, description = "since is after until")
     ),
-    tag = "nodes"
+    tag = "admin"
 )]
-pub async fn delete_node(
+pub async fn usage_export(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<DeleteNodeRequest>,
-) -> ApiResult<axum::Json<DeleteNodeResponse>> {
-    let response = state.service.delete_node(request).await?;
-    Ok(axum::Json(response))
+    AxumQuery(query): AxumQuery<UsageExportQuery>,
+) -> ApiResult<axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let csv = state.service.usage_export(query).await?;
+    Ok(([(axum::http::header::CONTENT_TYPE, "text/csv")], csv).into_response())
 }
 
-/// Add an edge handler.
+/// Upload file handler (OpenAI-compatible).
 ///
-/// Creates a new edge (relationship) between two nodes.
+/// Holds an uploaded file until it is added to a vector store.
 #[utoipa::path(
     post,
-    path = "/edges",
-    request_body = OpenApiAddEdgeRequest,
+    path = "/v1/files",
+    request_body(content = OpenApiUploadFileForm, content_type = "multipart/form-data"),
     responses(
-        (status = 200, description = "Edge created successfully", body = AddEdgeResponse)
+        (status = 200, description = "File uploaded successfully", body = OpenApiFileObject),
+        (status = 400, description = "Missing file"),
+        (status = 413, description = "File too large")
     ),
-    tag = "edges"
+    tag = "openai"
 )]
-pub async fn add_edge(
+pub async fn upload_file(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<AddEdgeRequest>,
-) -> ApiResult<axum::Json<AddEdgeResponse>> {
-    let response = state.service.add_edge(request).await?;
+    mut multipart: axum::extract::Multipart,
+) -> ApiResult<axum::Json<FileObject>> {
+    let invalid = |e: axum::extract::multipart::MultipartError| ApiError::InvalidRequest(e.to_string());
+
+    let mut request = UploadFileRequest {
+        file_name: String::new(),
+        content_type: None,
+        data: Vec::new(),
+        purpose: "assistants".to_string(),
+    };
+    let mut has_file = false;
+
+    while let Some(field) = multipart.next_field().await.map_err(invalid)? {
+        match field.name() {
+            Some("file") => {
+                request.file_name = field.file_name().unwrap_or("upload").to_string();
+                request.content_type = field.content_type().map(str::to_string);
+                request.data = field.bytes().await.map_err(invalid)?.to_vec();
+                has_file = true;
+            }
+            Some("purpose") => request.purpose = field.text().await.map_err(invalid)?,
+            _ => {}
+        }
+    }
+
+    if !has_file {
+        return Err(ApiError::InvalidRequest("missing 'file' field".to_string()));
+    }
+
+    Ok(axum::Json(state.service.upload_file(request).await))
+}
+
+/// Delete file handler (OpenAI-compatible).
+#[utoipa::path(
+    delete,
+    path = "/v1/files/{file_id}",
+    params(("file_id" = String, Path, description = "File ID")),
+    responses(
+        (status = 200, description = "File deleted successfully", body = OpenApiDeletedObject),
+        (status = 404, description = "File not found")
+    ),
+    tag = "openai"
+)]
+pub async fn delete_file(
+    State(state): State<AppState>,
+    AxumPath(file_id): AxumPath<String>,
+) -> ApiResult<axum::Json<DeletedObject>> {
+    let response = state.service.delete_file(&file_id).await?;
     Ok(axum::Json(response))
 }
 
-/// Query handler.
+/// Create vector store handler (OpenAI-compatible).
 ///
-/// Performs a text-based search query on the database.
+/// Creates a store backed by a new namespace and ingests the given files.
 #[utoipa::path(
     post,
-    path = "/query",
-    request_body = OpenApiQueryRequest,
+    path = "/v1/vector_stores",
+    request_body = OpenApiCreateVectorStoreRequest,
     responses(
-        (status = 200, description = "Query completed successfully", body = OpenApiQueryResponse)
+        (status = 200, description = "Vector store created successfully", body = OpenApiVectorStore),
+        (status = 404, description = "File not found")
     ),
-    tag = "query"
+    tag = "openai"
 )]
-pub async fn query(
+pub async fn create_vector_store(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<QueryRequest>,
-) -> ApiResult<axum::Json<QueryResponse>> {
-    let response = state.service.query(request).await?;
+    ValidJson(request): ValidJson<CreateVectorStoreRequest>,
+) -> ApiResult<axum::Json<VectorStore>> {
+    let response = state.service.create_vector_store(request).await?;
     Ok(axum::Json(response))
 }
 
-/// Hybrid search handler (GraphRAG).
-///
-/// Performs a hybrid search combining vector similarity and graph traversal.
+/// List vector stores handler (OpenAI-compatible).
 #[utoipa::path(
-    post,
-    path = "/hybrid_search",
-    request_body = OpenApiHybridSearchRequest,
+    get,
+    path = "/v1/vector_stores",
     responses(
-        (status = 200, description = "Hybrid search completed successfully", body = OpenApiHybridSearchResponse)
+        (status = 200, description = "Vector stores listed successfully", body = OpenApiVectorStoreList)
     ),
-    tag = "query"
+    tag = "openai"
 )]
-pub async fn hybrid_search(
+pub async fn list_vector_stores(State(state): State<AppState>) -> axum::Json<ListResponse<VectorStore>> {
+    axum::Json(state.service.list_vector_stores().await)
+}
+
+/// Get vector store handler (OpenAI-compatible).
+#[utoipa::path(
+    get,
+    path = "/v1/vector_stores/{id}",
+    params(("id" = String, Path, description = "Vector store ID")),
+    responses(
+        (status = 200, description = "Vector store retrieved successfully", body = OpenApiVectorStore),
+        (status = 404, description = "Vector store not found")
+    ),
+    tag = "openai"
+)]
+pub async fn get_vector_store(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<HybridSearchRequest>,
-) -> ApiResult<axum::Json<HybridSearchResponse>> {
-    let nodes = state.service.hybrid_search(&request.query, request.k).await?;
-    let count = nodes.len();
-    Ok(axum::Json(HybridSearchResponse { nodes, count }))
+    AxumPath(id): AxumPath<String>,
+) -> ApiResult<axum::Json<VectorStore>> {
+    let response = state.service.get_vector_store(&id).await?;
+    Ok(axum::Json(response))
 }
 
-/// Ingest document handler.
+/// Delete vector store handler (OpenAI-compatible).
 ///
-/// Processes a document with automatic chunking and optional embedding generation.
+/// Deletes the store and the documents ingested into it.
 #[utoipa::path(
-    post,
-    path = "/documents",
-    request_body = IngestDocumentRequest,
+    delete,
+    path = "/v1/vector_stores/{id}",
+    params(("id" = String, Path, description = "Vector store ID")),
     responses(
-        (status = 200, description = "Document ingested successfully", body = IngestDocumentResponse)
+        (status = 200, description = "Vector store deleted successfully", body = OpenApiDeletedObject),
+        (status = 404, description = "Vector store not found")
     ),
-    tag = "documents"
+    tag = "openai"
 )]
-pub async fn ingest_document(
+pub async fn delete_vector_store(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<IngestDocumentRequest>,
-) -> ApiResult<axum::Json<IngestDocumentResponse>> {
-    let response = state.service.ingest_document(request).await?;
+    AxumPath(id): AxumPath<String>,
+) -> ApiResult<axum::Json<DeletedObject>> {
+    let response = state.service.delete_vector_store(&id).await?;
     Ok(axum::Json(response))
 }
 
-/// Traverse handler.
+/// Create vector store file handler (OpenAI-compatible).
 ///
-/// Performs graph traversal (BFS) starting from a given node.
+/// Ingests an uploaded file into the store's namespace; processing has
+/// finished when the response is sent.
 #[utoipa::path(
     post,
-    path = "/traverse",
-    request_body = OpenApiTraverseRequest,
+    path = "/v1/vector_stores/{id}/files",
+    params(("id" = String, Path, description = "Vector store ID")),
+    request_body = OpenApiCreateVectorStoreFileRequest,
     responses(
-        (status = 200, description = "Traversal completed successfully", body = OpenApiTraverseResponse)
+        (status = 200, description = "File ingested successfully", body = OpenApiVectorStoreFile),
+        (status = 400, description = "File already in the store, unsupported document type or no extractable text"),
+        (status = 404, description = "Vector store or file not found")
     ),
-    tag = "graph"
+    tag = "openai"
 )]
-pub async fn traverse(
+pub async fn create_vector_store_file(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<TraverseRequest>,
-) -> ApiResult<axum::Json<TraverseResponse>> {
-    let response = state.service.traverse(request).await?;
+    AxumPath(id): AxumPath<String>,
+    ValidJson(request): ValidJson<CreateVectorStoreFileRequest>,
+) -> ApiResult<axum::Json<VectorStoreFile>> {
+    let response = state.service.add_vector_store_file(&id, request).await?;
     Ok(axum::Json(response))
 }
 
-/// Get all nodes handler.
-///
-/// Returns all nodes in the database.
+/// List vector store files handler (OpenAI-compatible).
 #[utoipa::path(
     get,
-    path = "/nodes",
+    path = "/v1/vector_stores/{id}/files",
+    params(("id" = String, Path, description = "Vector store ID")),
     responses(
-        (status = 200, description = "All nodes retrieved successfully", body = Vec<NodeInfo>)
+        (status = 200, description = "Files listed successfully", body = OpenApiVectorStoreFileList),
+        (status = 404, description = "Vector store not found")
     ),
-    tag = "nodes"
+    tag = "openai"
 )]
-pub async fn get_all_nodes(
+pub async fn list_vector_store_files(
     State(state): State<AppState>,
-) -> axum::Json<Vec<synton_core::Node>> {
-    let nodes = state.service.all_nodes().await;
-    axum::Json(nodes)
+    AxumPath(id): AxumPath<String>,
+) -> ApiResult<axum::Json<ListResponse<VectorStoreFile>>> {
+    let response = state.service.list_vector_store_files(&id).await?;
+    Ok(axum::Json(response))
 }
 
-/// Bulk operation handler.
+/// Search vector store handler (OpenAI-compatible).
 ///
-/// Performs multiple node and edge operations in a single request.
+/// Returns the store's chunks most similar to the query.
 #[utoipa::path(
     post,
-    path = "/bulk",
-    request_body = OpenApiBulkOperationRequest,
+    path = "/v1/vector_stores/{id}/search",
+    params(("id" = String, Path, description = "Vector store ID")),
+    request_body = OpenApiVectorStoreSearchRequest,
     responses(
-        (status = 200, description = "Bulk operation completed successfully", body = OpenApiBulkOperationResponse)
+        (status = 200, description = "Search completed successfully", body = OpenApiVectorStoreSearchResponse),
+        (status = 400, description = "max_num_results out of range"),
+        (status = 404, description = "Vector store not found")
     ),
-    tag = "nodes"
+    tag = "openai"
 )]
-pub async fn bulk_operation(
+pub async fn search_vector_store(
     State(state): State<AppState>,
-    axum::Json(request): axum::Json<crate::models::BulkOperationRequest>,
-) -> ApiResult<axum::Json<crate::models::BulkOperationResponse>> {
-    let mut node_ids = Vec::new();
-    let mut edge_ids = Vec::new();
-    let mut success_count = 0;
-    let mut failure_count = 0;
-    let mut errors = Vec::new();
-
-    // Add nodes
-    for node_req in request.nodes {
-        match state.service.add_node(node_req).await {
-            Ok(resp) => {
-                node_ids.push(resp.node.id);
-                success_count += 1;
-            }
-            Err(e) => {
-                errors.push(format!("Node creation failed: {}", e));
-                failure_count += 1;
-            }
-        }
-    }
-
-    // Add edges
-    for edge_req in request.edges {
-        match state.service.add_edge(edge_req).await {
-            Ok(resp) => {
-                edge_ids.push(resp.edge.id());
-                success_count += 1;
-            }
-            Err(e) => {
-                errors.push(format!("Edge creation failed: {}", e));
-                failure_count += 1;
-            }
-        }
-    }
-
-    let response = crate::models::BulkOperationResponse {
-        node_ids,
-        edge_ids,
-        success_count,
-        failure_count,
-        errors,
-    };
-
+    AxumPath(id): AxumPath<String>,
+    ValidJson(request): ValidJson<VectorStoreSearchRequest>,
+) -> ApiResult<axum::Json<VectorStoreSearchResponse>> {
+    let response = state.service.search_vector_store(&id, request).await?;
     Ok(axum::Json(response))
 }
 
-/// Create the REST API router.
+/// Create the REST API router with the default concurrency limits.
 pub fn create_router() -> axum::Router {
+    create_router_with_limits(&ConcurrencyLimits::default())
+}
+
+/// Create the REST API router with the given concurrency limits.
+pub fn create_router_with_limits(limits: &ConcurrencyLimits) -> axum::Router {
     let service = Arc::new(SyntonDbService::new());
-    let state = AppState::new(service);
+    let state = AppState::with_limits(service, limits);
 
     // API routes
     let api_routes = axum::Router::new()
         .route("/health", axum::routing::get(health_check))
         .route("/stats", axum::routing::get(stats))
+        .route("/memory/stats", axum::routing::get(memory_stats))
+        .route("/memory/expiring", axum::routing::get(expiring_nodes))
+        .route("/activity", axum::routing::get(activity))
+        .merge(diagnostics_routes())
         .route("/nodes", axum::routing::post(add_node))
         .route("/nodes", axum::routing::get(get_all_nodes))
         .route("/nodes/:id", axum::routing::get(get_node))
         .route("/nodes/:id", axum::routing::delete(delete_node))
+        .route("/nodes/:id", axum::routing::patch(update_node))
+        .route("/nodes/update", axum::routing::post(update_nodes))
+        .route("/nodes/top-accessed", axum::routing::get(top_accessed))
+        .route("/nodes/:id/usage", axum::routing::get(node_usage))
+        .route("/nodes/:id/inspect", axum::routing::get(inspect_node))
+        .route(
+            "/nodes/:id/attachments",
+            axum::routing::post(attach_file)
+                .layer(axum::extract::DefaultBodyLimit::max(crate::MAX_ATTACHMENT_UPLOAD_BYTES)),
+        )
+        .route("/nodes/:id/attachments", axum::routing::get(list_attachments))
+        .route(
+            "/nodes/:id/attachments/:attachment_id",
+            axum::routing::get(get_attachment).delete(delete_attachment),
+        )
+        .route("/nodes/:id/tags", axum::routing::post(tag_node))
+        .route("/nodes/:id/tags/:tag", axum::routing::delete(untag_node))
+        .route(
+            "/nodes/by-external-id/:external_id",
+            axum::routing::get(get_node_by_external_id),
+        )
         .route("/edges", axum::routing::post(add_edge))
+        .route("/review/edges", axum::routing::get(review_edges))
+        .route("/review/edges/approve", axum::routing::post(approve_edge))
+        .route("/review/edges/reject", axum::routing::post(reject_edge))
         .route("/query", axum::routing::post(query))
+        .route("/query/prepare", axum::routing::post(prepare_query))
+        .route("/query/execute", axum::routing::post(execute_query))
+        .route("/query/structured", axum::routing::post(structured_query))
         .route("/traverse", axum::routing::post(traverse))
+        .route("/traverse/explain", axum::routing::post(explain_traverse))
+        .route("/views", axum::routing::get(list_views))
+        .route("/views", axum::routing::post(create_view))
+        .route("/views/:name", axum::routing::get(get_view))
+        .route("/views/:name", axum::routing::delete(delete_view))
+        .route("/reason/paths", axum::routing::post(reason_paths))
+        .route("/graph/match", axum::routing::post(match_pattern))
+        .route("/graph/viz", axum::routing::get(graph_viz))
         .route("/hybrid_search", axum::routing::post(hybrid_search))
+        .route("/rag/feedback", axum::routing::post(submit_feedback))
+        .route("/rag/feedback/stats", axum::routing::get(feedback_stats))
         .route("/bulk", axum::routing::post(bulk_operation))
+        .route("/transactions", axum::routing::post(apply_transaction))
         .route("/documents", axum::routing::post(ingest_document))
+        .route("/documents", axum::routing::get(list_documents))
+        .route("/documents/:id", axum::routing::get(get_document))
+        .route("/ingest/url", axum::routing::post(ingest_url))
+        .route(
+            "/ingest/file",
+            axum::routing::post(ingest_file)
+                .layer(axum::extract::DefaultBodyLimit::max(synton_ingest::MAX_DOCUMENT_BYTES)),
+        )
+        .route("/pipelines", axum::routing::get(list_pipelines))
+        .route("/pipelines/:name/run", axum::routing::post(run_pipeline))
+        .route("/pipelines/:name/runs", axum::routing::get(pipeline_runs))
+        .route("/admin/vector/optimize", axum::routing::post(optimize_vector_index))
+        .route("/admin/vector/calibrate", axum::routing::post(calibrate_vectors))
+        .route("/admin/vector/evaluate", axum::routing::post(evaluate_vectors))
+        .route("/admin/vector/delta", axum::routing::get(export_vector_delta))
+        .route(
+            "/admin/vector/delta",
+            axum::routing::post(apply_vector_delta)
+                .layer(axum::extract::DefaultBodyLimit::max(crate::export::MAX_IMPORT_BYTES)),
+        )
+        .route("/admin/storage/stats", axum::routing::get(storage_stats))
+        .route("/admin/self-check", axum::routing::get(self_check))
+        .route("/admin/embeddings/reembed", axum::routing::get(reembed_status))
+        .route("/admin/storage/compact", axum::routing::post(compact_storage))
+        .route("/admin/storage/migrate", axum::routing::post(migrate_storage))
+        .route("/admin/storage/integrity", axum::routing::post(check_integrity))
+        .route("/admin/seed", axum::routing::post(seed).delete(unseed))
+        .route("/admin/reindex", axum::routing::post(reindex))
+        .route("/admin/limits", axum::routing::get(limits_stats))
+        .route("/admin/audit", axum::routing::get(audit_events))
+        .route("/admin/usage", axum::routing::get(usage_export))
+        .route("/jobs", axum::routing::get(list_jobs))
+        .route("/jobs/:id", axum::routing::get(get_job))
+        .route("/export", axum::routing::get(export_archive))
+        .route(
+            "/import",
+            axum::routing::post(import_archive)
+                .layer(axum::extract::DefaultBodyLimit::max(crate::export::MAX_IMPORT_BYTES)),
+        )
+        // OpenAI-compatible vector store endpoints
+        .route(
+            "/v1/files",
+            axum::routing::post(upload_file)
+                .layer(axum::extract::DefaultBodyLimit::max(synton_ingest::MAX_DOCUMENT_BYTES)),
+        )
+        .route("/v1/files/:file_id", axum::routing::delete(delete_file))
+        .route("/v1/vector_stores", axum::routing::post(create_vector_store))
+        .route("/v1/vector_stores", axum::routing::get(list_vector_stores))
+        .route("/v1/vector_stores/:id", axum::routing::get(get_vector_store))
+        .route("/v1/vector_stores/:id", axum::routing::delete(delete_vector_store))
+        .route("/v1/vector_stores/:id/files", axum::routing::post(create_vector_store_file))
+        .route("/v1/vector_stores/:id/files", axum::routing::get(list_vector_store_files))
+        .route("/v1/vector_stores/:id/search", axum::routing::post(search_vector_store))
         // OpenAPI JSON endpoint
+        .route("/openapi.json", axum::routing::get(openapi_json))
         .route("/api-docs/openapi.json", axum::routing::get(openapi_json))
+        // Trace lookup endpoints
+        .nest("/api/v1/instr", crate::instrument::create_instrument_router())
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::limits::limit_middleware,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::metering::metering_middleware,
+        ))
         .with_state(state);
 
     // Serve static files from web/dist directory
@@ -356,6 +2315,10 @@ pub fn create_router() -> axum::Router {
         .nest("/", api_routes)
         .nest_service("/assets", static_files.clone())
         .fallback_service(static_files)
+        .layer(axum::middleware::from_fn(crate::usage::actor_middleware))
+        .layer(axum::middleware::from_fn(
+            crate::instrument::trace_context_middleware,
+        ))
         .layer(
             tower_http::cors::CorsLayer::new()
                 .allow_origin(tower_http::cors::Any)
@@ -366,13 +2329,21 @@ pub fn create_router() -> axum::Router {
 }
 
 /// Serve the OpenAPI JSON specification.
-async fn openapi_json() -> axum::Json<utoipa::openapi::OpenApi> {
-    axum::Json(crate::openapi::ApiDoc::openapi())
+pub async fn openapi_json() -> axum::Json<utoipa::openapi::OpenApi> {
+    axum::Json(crate::openapi::openapi_spec())
 }
 
 /// Start the REST API server.
 pub async fn run_server(addr: impl tokio::net::ToSocketAddrs) -> Result<(), Box<dyn std::error::Error>> {
-    let app = create_router();
+    run_server_with_limits(addr, &ConcurrencyLimits::default()).await
+}
+
+/// Start the REST API server with the given concurrency limits.
+pub async fn run_server_with_limits(
+    addr: impl tokio::net::ToSocketAddrs,
+    limits: &ConcurrencyLimits,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let app = create_router_with_limits(limits);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
     tracing::info!("REST API server listening on {}", listener.local_addr()?);
@@ -406,9 +2377,80 @@ mod tests {
 
         let request = AddNodeRequest::new("Test node".to_string(), NodeType::Concept);
 
-        let result = add_node(State(state), axum::Json(request)).await;
+        let result = add_node(State(state), ValidJson(request)).await;
         assert!(result.is_ok());
         let response = result.unwrap();
         assert!(response.0.created);
     }
+
+    #[tokio::test]
+    async fn test_trace_id_header_roundtrip() {
+        use tower::ServiceExt;
+
+        let app = axum::Router::new()
+            .route("/health", axum::routing::get(health_check))
+            .layer(axum::middleware::from_fn(
+                crate::instrument::trace_context_middleware,
+            ));
+
+        let trace_id = Uuid::new_v4();
+        let request = axum::http::Request::builder()
+            .uri("/health")
+            .header(synton_instrument::TRACE_ID_HEADER, trace_id.to_string())
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        let echoed = response
+            .headers()
+            .get(synton_instrument::TRACE_ID_HEADER)
+            .unwrap();
+        assert_eq!(echoed.to_str().unwrap(), trace_id.to_string());
+        assert!(response
+            .headers()
+            .contains_key(synton_instrument::TRACEPARENT_HEADER));
+    }
+
+    #[tokio::test]
+    async fn test_limit_middleware_rejects_with_retry_after() {
+        use crate::limits::{RouteClass, RouteLimits};
+        use tower::ServiceExt;
+
+        let limits = ConcurrencyLimits {
+            write: RouteLimits::new(1, 0),
+            retry_after_secs: 7,
+            ..ConcurrencyLimits::unlimited()
+        };
+        let state = AppState::with_limits(Arc::new(SyntonDbService::new()), &limits);
+        let app = axum::Router::new()
+            .route("/nodes", axum::routing::post(add_node))
+            .route("/health", axum::routing::get(health_check))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                crate::limits::limit_middleware,
+            ))
+            .with_state(state.clone());
+
+        // Occupy the only write slot
+        let _held = state.limiter.acquire(RouteClass::Write).await.unwrap();
+
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/nodes")
+            .header("content-type", "application/json")
+            .body(axum::body::Body::from(r#"{"content":"x","node_type":"concept"}"#))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[axum::http::header::RETRY_AFTER], "7");
+
+        // Health checks are never limited
+        let request = axum::http::Request::builder()
+            .uri("/health")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(state.limiter.stats().write.rejected, 1);
+    }
 }
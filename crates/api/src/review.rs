@@ -0,0 +1,155 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Review queue of low-confidence edges.
+//!
+//! An edge created by a tool or an extraction pipeline whose extraction
+//! confidence is below the configured threshold does not join the graph.
+//! It is persisted with `pending_review` set and held here instead, so
+//! traversals, reasoning paths and RAG never follow it. Approving the edge
+//! adds it to the graph; rejecting it deletes it.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use synton_core::Edge;
+use uuid::Uuid;
+
+/// Edge review settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeReviewConfig {
+    /// Extraction confidence (0.0-1.0) below which machine-created edges
+    /// are held for review.
+    pub min_confidence: f32,
+}
+
+impl Default for EdgeReviewConfig {
+    fn default() -> Self {
+        Self { min_confidence: 0.5 }
+    }
+}
+
+#[derive(Debug)]
+struct ReviewState {
+    config: Option<EdgeReviewConfig>,
+    pending: HashMap<String, Edge>,
+}
+
+impl Default for ReviewState {
+    fn default() -> Self {
+        Self {
+            config: Some(EdgeReviewConfig::default()),
+            pending: HashMap::new(),
+        }
+    }
+}
+
+/// Edges awaiting review, keyed by edge ID; enabled with the default
+/// settings until configured otherwise.
+#[derive(Debug, Default)]
+pub(crate) struct ReviewQueue {
+    state: Mutex<ReviewState>,
+}
+
+impl ReviewQueue {
+    fn state(&self) -> MutexGuard<'_, ReviewState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Hold low-confidence edges with the given settings, or stop holding
+    /// new edges with `None`. Edges already pending stay pending.
+    pub(crate) fn configure(&self, config: Option<EdgeReviewConfig>) {
+        self.state().config = config;
+    }
+
+    /// Whether a new edge must be reviewed before joining the graph.
+    /// Edges created by users, or without an extraction confidence, never
+    /// are.
+    pub(crate) fn needs_review(&self, edge: &Edge) -> bool {
+        let Some(config) = self.state().config else {
+            return false;
+        };
+        !edge.created_by().is_human()
+            && edge
+                .provenance
+                .confidence
+                .is_some_and(|confidence| confidence < config.min_confidence)
+    }
+
+    /// Hold an edge for review.
+    pub(crate) fn hold(&self, edge: Edge) {
+        self.state().pending.insert(edge.id(), edge);
+    }
+
+    /// Stop holding an edge, returning it.
+    pub(crate) fn take(&self, id: &str) -> Option<Edge> {
+        self.state().pending.remove(id)
+    }
+
+    /// Pending edges, oldest first.
+    pub(crate) fn pending(&self) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = self.state().pending.values().cloned().collect();
+        edges.sort_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| a.id().cmp(&b.id())));
+        edges
+    }
+
+    /// Drop the pending edges of a deleted node, returning them.
+    pub(crate) fn forget_node(&self, id: Uuid) -> Vec<Edge> {
+        let mut state = self.state();
+        let ids: Vec<String> = state
+            .pending
+            .iter()
+            .filter(|(_, edge)| edge.source == id || edge.target == id)
+            .map(|(key, _)| key.clone())
+            .collect();
+        ids.iter().filter_map(|key| state.pending.remove(key)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::{EdgeCreator, EdgeProvenance, Relation};
+
+    fn extracted(confidence: f32) -> Edge {
+        Edge::new(Uuid::new_v4(), Uuid::new_v4(), Relation::Causes)
+            .with_provenance(EdgeProvenance::new(EdgeCreator::ExtractionPipeline).with_confidence(confidence))
+    }
+
+    #[test]
+    fn test_needs_review() {
+        let queue = ReviewQueue::default();
+        assert!(queue.needs_review(&extracted(0.3)));
+        assert!(!queue.needs_review(&extracted(0.8)));
+        // User-created edges and edges without a confidence are trusted
+        let by_user = extracted(0.3).with_provenance(EdgeProvenance::default().with_confidence(0.3));
+        assert!(!queue.needs_review(&by_user));
+        let unscored = extracted(0.3).with_provenance(EdgeProvenance::new(EdgeCreator::Tool));
+        assert!(!queue.needs_review(&unscored));
+
+        queue.configure(Some(EdgeReviewConfig { min_confidence: 0.9 }));
+        assert!(queue.needs_review(&extracted(0.8)));
+        queue.configure(None);
+        assert!(!queue.needs_review(&extracted(0.1)));
+    }
+
+    #[test]
+    fn test_queue() {
+        let queue = ReviewQueue::default();
+        let first = extracted(0.2);
+        let mut second = extracted(0.4);
+        second.created_at = first.created_at + chrono::Duration::seconds(1);
+        queue.hold(second.clone());
+        queue.hold(first.clone());
+
+        let pending: Vec<String> = queue.pending().iter().map(Edge::id).collect();
+        assert_eq!(pending, vec![first.id(), second.id()]);
+
+        assert_eq!(queue.take(&first.id()), Some(first.clone()));
+        assert_eq!(queue.take(&first.id()), None);
+
+        assert_eq!(queue.forget_node(second.target), vec![second]);
+        assert!(queue.pending().is_empty());
+    }
+}
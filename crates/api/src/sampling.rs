@@ -0,0 +1,136 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Approximate counts and sampled previews for large result sets.
+//!
+//! Instead of materializing every match, a bounded number of nodes is
+//! scanned; the total is extrapolated from the scanned fraction of the node
+//! count and a uniform sample of the matches is kept with reservoir sampling.
+
+use synton_paql::QueryNode;
+
+/// Maximum number of nodes examined for an approximate count.
+pub(crate) const COUNT_SCAN_LIMIT: usize = 100_000;
+
+/// Fixed-size uniform sample of a stream (Algorithm R).
+#[derive(Debug)]
+pub(crate) struct Reservoir<T> {
+    capacity: usize,
+    seen: usize,
+    items: Vec<T>,
+    rng: fastrand::Rng,
+}
+
+impl<T> Reservoir<T> {
+    /// Create a reservoir holding at most `capacity` items.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self::with_rng(capacity, fastrand::Rng::new())
+    }
+
+    fn with_rng(capacity: usize, rng: fastrand::Rng) -> Self {
+        Self {
+            capacity,
+            seen: 0,
+            items: Vec::with_capacity(capacity.min(1024)),
+            rng,
+        }
+    }
+
+    /// Offer the next stream element. `make` is only called when the
+    /// element is kept, so unselected items are never cloned.
+    pub(crate) fn offer(&mut self, make: impl FnOnce() -> T) {
+        self.seen += 1;
+        if self.items.len() < self.capacity {
+            self.items.push(make());
+        } else if self.capacity > 0 {
+            let slot = self.rng.usize(0..self.seen);
+            if slot < self.capacity {
+                self.items[slot] = make();
+            }
+        }
+    }
+
+    /// Number of elements offered so far.
+    pub(crate) fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// The sampled items.
+    pub(crate) fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Extrapolate a match count from a partial scan.
+///
+/// Returns the estimate and whether it is approximate (the scan stopped
+/// before covering the whole population).
+pub(crate) fn estimate_total(matched: usize, scanned: usize, population: usize) -> (usize, bool) {
+    if scanned == 0 || scanned >= population {
+        return (matched, false);
+    }
+    let estimate = (matched as f64 * population as f64 / scanned as f64).round() as usize;
+    (estimate, true)
+}
+
+/// Lowercased text terms of a query; a node matches if its content contains
+/// any of them, mirroring how text search combines sub-queries.
+pub(crate) fn text_terms(query: &QueryNode) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut stack = vec![query];
+
+    while let Some(node) = stack.pop() {
+        match node {
+            QueryNode::TextSearch { query } | QueryNode::HybridSearch { query, .. } => {
+                terms.push(query.to_lowercase())
+            }
+            QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+                stack.push(left);
+                stack.push(right);
+            }
+            QueryNode::Filter { input, .. } => stack.push(input),
+            _ => {}
+        }
+    }
+
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reservoir_keeps_capacity_and_counts() {
+        let mut reservoir = Reservoir::with_rng(5, fastrand::Rng::with_seed(7));
+        let mut made = 0;
+        for i in 0..1000 {
+            reservoir.offer(|| {
+                made += 1;
+                i
+            });
+        }
+
+        assert_eq!(reservoir.seen(), 1000);
+        assert!(made < 1000);
+        let items = reservoir.into_items();
+        assert_eq!(items.len(), 5);
+        assert!(items.iter().all(|i| *i < 1000));
+    }
+
+    #[test]
+    fn test_estimate_total() {
+        assert_eq!(estimate_total(10, 50, 50), (10, false));
+        assert_eq!(estimate_total(10, 100, 1000), (100, true));
+        assert_eq!(estimate_total(0, 0, 0), (0, false));
+    }
+
+    #[test]
+    fn test_text_terms_collects_union() {
+        let query = synton_paql::Parser::new().parse("rust OR python").unwrap();
+        let mut terms = text_terms(&query.root);
+        terms.sort();
+        assert_eq!(terms, vec!["python".to_string(), "rust".to_string()]);
+    }
+}
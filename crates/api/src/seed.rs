@@ -0,0 +1,238 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Starter ontologies.
+//!
+//! A fresh database gives agents nothing to link new knowledge to. Seeding
+//! loads an ontology of concepts joined by `is_a` and `is_part_of` edges:
+//! the bundled starter ontology of common software engineering concepts,
+//! or one read from a file. Seeded nodes carry the namespace they were
+//! seeded into, `starter` unless chosen otherwise, so reads can be scoped
+//! around them and unseeding the namespace removes them again.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use synton_core::{EdgeProvenance, NodeType, Relation};
+use synton_memory::NAMESPACE_ATTRIBUTE;
+
+use crate::models::{NodeRef, TransactionOp, TransactionRequest};
+use crate::namespaces::NAMESPACE_SEPARATOR;
+
+/// Namespace ontologies are seeded into by default.
+pub const STARTER_NAMESPACE: &str = "starter";
+
+/// Metadata key prefix recording the nodes seeded into a namespace.
+pub(crate) const SEED_KEY_PREFIX: &str = "seed:";
+
+/// Attribute of a seeded node holding its concept's description.
+const DESCRIPTION_ATTRIBUTE: &str = "description";
+
+/// Attribute of a seeded node naming the ontology it came from.
+const ONTOLOGY_ATTRIBUTE: &str = "ontology";
+
+/// The bundled starter ontology.
+const STARTER_ONTOLOGY: &str = include_str!("../assets/starter_ontology.json");
+
+/// Concepts and the hierarchy between them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ontology {
+    /// Name of the ontology, recorded on its nodes.
+    pub name: String,
+
+    /// Concepts, each naming its parents.
+    pub concepts: Vec<OntologyConcept>,
+}
+
+/// A concept of an ontology.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OntologyConcept {
+    /// Name, stored as the node content and unique within the ontology.
+    pub name: String,
+
+    /// Short description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Concepts this one is a kind of.
+    #[serde(default)]
+    pub is_a: Vec<String>,
+
+    /// Concepts this one is part of.
+    #[serde(default)]
+    pub part_of: Vec<String>,
+}
+
+impl Ontology {
+    /// The bundled starter ontology of software engineering concepts.
+    pub fn starter() -> Self {
+        serde_json::from_str(STARTER_ONTOLOGY).expect("bundled starter ontology is valid")
+    }
+
+    /// Read an ontology from a JSON file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read ontology {}: {}", path.display(), e))?;
+        serde_json::from_str(&json)
+            .map_err(|e| format!("invalid ontology {}: {}", path.display(), e))
+    }
+
+    /// Check the ontology.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.concepts.is_empty() {
+            return Err(format!("ontology {:?} has no concepts", self.name));
+        }
+        let mut names = std::collections::HashSet::new();
+        for concept in &self.concepts {
+            if concept.name.trim().is_empty() {
+                return Err(format!(
+                    "ontology {:?} has a concept without a name",
+                    self.name
+                ));
+            }
+            if !names.insert(concept.name.as_str()) {
+                return Err(format!("duplicate concept {:?}", concept.name));
+            }
+        }
+        for concept in &self.concepts {
+            for parent in concept.is_a.iter().chain(&concept.part_of) {
+                if !names.contains(parent.as_str()) {
+                    return Err(format!(
+                        "concept {:?} refers to unknown concept {:?}",
+                        concept.name, parent
+                    ));
+                }
+                if *parent == concept.name {
+                    return Err(format!("concept {:?} refers to itself", concept.name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Transaction adding the concepts to `namespace`, then their edges.
+    pub(crate) fn transaction(&self, namespace: &str) -> TransactionRequest {
+        let mut operations: Vec<TransactionOp> = self
+            .concepts
+            .iter()
+            .map(|concept| {
+                let mut attributes = serde_json::json!({
+                    NAMESPACE_ATTRIBUTE: namespace,
+                    ONTOLOGY_ATTRIBUTE: self.name,
+                });
+                if let Some(description) = &concept.description {
+                    attributes[DESCRIPTION_ATTRIBUTE] = description.clone().into();
+                }
+                TransactionOp::AddNode {
+                    temp_id: Some(concept.name.clone()),
+                    content: concept.name.clone(),
+                    node_type: NodeType::Concept,
+                    attributes: Some(attributes),
+                }
+            })
+            .collect();
+        for concept in &self.concepts {
+            let parents = concept
+                .is_a
+                .iter()
+                .map(|parent| (parent, Relation::IsA))
+                .chain(
+                    concept
+                        .part_of
+                        .iter()
+                        .map(|parent| (parent, Relation::IsPartOf)),
+                );
+            for (parent, relation) in parents {
+                operations.push(TransactionOp::AddEdge {
+                    source: NodeRef::Temp(concept.name.clone()),
+                    target: NodeRef::Temp(parent.clone()),
+                    relation,
+                    weight: 1.0,
+                    provenance: EdgeProvenance::default(),
+                });
+            }
+        }
+        TransactionRequest { operations }
+    }
+}
+
+/// Namespace to seed into or unseed, defaulting to the starter namespace.
+pub(crate) fn seed_namespace(namespace: Option<&str>) -> Result<String, String> {
+    let namespace = namespace
+        .unwrap_or(STARTER_NAMESPACE)
+        .trim()
+        .trim_matches(NAMESPACE_SEPARATOR);
+    if namespace.is_empty() {
+        return Err("seed namespace must not be empty".to_string());
+    }
+    Ok(namespace.to_string())
+}
+
+/// Metadata key recording the nodes seeded into `namespace`.
+pub(crate) fn seed_key(namespace: &str) -> String {
+    format!("{}{}", SEED_KEY_PREFIX, namespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn concept(name: &str, is_a: &[&str], part_of: &[&str]) -> OntologyConcept {
+        OntologyConcept {
+            name: name.to_string(),
+            description: None,
+            is_a: is_a.iter().map(|s| s.to_string()).collect(),
+            part_of: part_of.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_starter_ontology() {
+        let starter = Ontology::starter();
+        starter.validate().unwrap();
+        assert!(starter.concepts.len() > 50);
+
+        let transaction = starter.transaction(STARTER_NAMESPACE);
+        let edges = transaction
+            .operations
+            .iter()
+            .filter(|op| matches!(op, TransactionOp::AddEdge { .. }))
+            .count();
+        assert_eq!(transaction.operations.len(), starter.concepts.len() + edges);
+        let TransactionOp::AddNode { attributes, .. } = &transaction.operations[0] else {
+            panic!("expected the concepts first");
+        };
+        assert_eq!(
+            attributes.as_ref().unwrap()[NAMESPACE_ATTRIBUTE],
+            STARTER_NAMESPACE
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut ontology = Ontology {
+            name: "tiny".to_string(),
+            concepts: vec![
+                concept("Vehicle", &[], &[]),
+                concept("Car", &["Vehicle"], &[]),
+                concept("Wheel", &[], &["Car"]),
+            ],
+        };
+        assert!(ontology.validate().is_ok());
+
+        ontology.concepts.push(concept("Boat", &["Vessel"], &[]));
+        assert!(ontology.validate().unwrap_err().contains("Vessel"));
+        ontology.concepts.pop();
+        ontology.concepts.push(concept("Car", &[], &[]));
+        assert!(ontology.validate().unwrap_err().contains("duplicate"));
+        ontology.concepts.pop();
+        ontology.concepts.push(concept("Loop", &["Loop"], &[]));
+        assert!(ontology.validate().is_err());
+
+        assert_eq!(seed_namespace(None).unwrap(), STARTER_NAMESPACE);
+        assert_eq!(seed_namespace(Some("/org/seed/")).unwrap(), "org/seed");
+        assert!(seed_namespace(Some("/")).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Similarity auto-linking.
+//!
+//! Nodes ingested without explicit relations are islands in the graph that
+//! traversals and graph expansion never reach. A periodic pass looks up the
+//! nearest neighbors of every embedded node and links pairs above a high
+//! similarity threshold that are not yet connected with a `similar_to` edge
+//! weighted by their similarity. Each node gets a bounded number of such
+//! edges, strongest first, so dense clusters do not become supernodes.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use uuid::Uuid;
+
+/// Similarity auto-linking settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimilarityLinkConfig {
+    /// Lowest similarity of a linked pair.
+    pub threshold: f32,
+
+    /// Most `similar_to` edges per node, counting existing ones.
+    pub max_links_per_node: usize,
+
+    /// Interval between passes.
+    pub interval: Duration,
+}
+
+impl Default for SimilarityLinkConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.9,
+            max_links_per_node: 5,
+            interval: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// The unordered pair of two nodes.
+pub(crate) fn node_pair(a: Uuid, b: Uuid) -> (Uuid, Uuid) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Choose the pairs to link among `(node, neighbor, similarity)`
+/// candidates, strongest first. Pairs below the threshold or already
+/// connected are skipped, as are nodes that reached the link bound counting
+/// their existing `similar_to` edges in `links`.
+pub(crate) fn select_links(
+    candidates: Vec<(Uuid, Uuid, f32)>,
+    connected: &HashSet<(Uuid, Uuid)>,
+    mut links: HashMap<Uuid, usize>,
+    config: &SimilarityLinkConfig,
+) -> Vec<(Uuid, Uuid, f32)> {
+    let mut candidates: Vec<_> = candidates
+        .into_iter()
+        .filter(|&(a, b, score)| a != b && score >= config.threshold)
+        .map(|(a, b, score)| {
+            let (a, b) = node_pair(a, b);
+            (a, b, score)
+        })
+        .collect();
+    candidates.sort_by(|x, y| y.2.total_cmp(&x.2).then_with(|| (x.0, x.1).cmp(&(y.0, y.1))));
+
+    let mut linked = HashSet::new();
+    let mut selected = Vec::new();
+    for (a, b, score) in candidates {
+        if connected.contains(&(a, b)) || !linked.insert((a, b)) {
+            continue;
+        }
+        let full = |id| links.get(&id).copied().unwrap_or(0) >= config.max_links_per_node;
+        if full(a) || full(b) {
+            continue;
+        }
+        *links.entry(a).or_default() += 1;
+        *links.entry(b).or_default() += 1;
+        selected.push((a, b, score));
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_links() {
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let config = SimilarityLinkConfig {
+            max_links_per_node: 1,
+            ..Default::default()
+        };
+        let candidates = vec![
+            (ids[0], ids[1], 0.95),
+            // Found again from the other end
+            (ids[1], ids[0], 0.95),
+            // Weaker than the link ids[0] already gets
+            (ids[0], ids[2], 0.93),
+            (ids[2], ids[3], 0.97),
+            (ids[1], ids[3], 0.5),
+            (ids[3], ids[3], 1.0),
+        ];
+
+        let selected = select_links(candidates.clone(), &HashSet::new(), HashMap::new(), &config);
+        let pairs: HashSet<_> = selected.iter().map(|&(a, b, _)| (a, b)).collect();
+        assert_eq!(
+            pairs,
+            HashSet::from([node_pair(ids[2], ids[3]), node_pair(ids[0], ids[1])])
+        );
+        assert_eq!(selected[0].2, 0.97);
+
+        // Connected pairs and nodes at the bound are skipped
+        let connected = HashSet::from([node_pair(ids[2], ids[3])]);
+        let links = HashMap::from([(ids[1], 1)]);
+        let selected = select_links(candidates, &connected, links, &config);
+        assert_eq!(
+            selected.iter().map(|&(a, b, _)| (a, b)).collect::<Vec<_>>(),
+            vec![node_pair(ids[0], ids[2])]
+        );
+    }
+}
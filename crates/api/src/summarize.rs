@@ -0,0 +1,101 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Summary nodes for ingested documents.
+//!
+//! A document ingested with `summarize` and at least `min_chars` long is
+//! summarized by the configured completion backend. The summary is stored
+//! as its own node, linked to the document with `IsPartOf` and embedded
+//! like a chunk, so retrieval can match a question against what a whole
+//! document is about and not only against its raw chunks. Summary nodes
+//! carry the [`SUMMARY_ATTRIBUTE`](synton_memory::SUMMARY_ATTRIBUTE) and
+//! decay with the `summary` profile.
+
+use synton_ml::CompletionBackend;
+
+use crate::{ApiError, ApiResult};
+
+/// Instruction for the summary of one document.
+const SYSTEM_PROMPT: &str = "You summarize documents for a knowledge base. Reply with a summary of the document in one paragraph of at most 150 words: its subject, main points and conclusions, in the document's language. Do not add a heading, a preamble or anything not stated in the document.";
+
+/// Document summarization settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SummaryConfig {
+    /// Shortest document, in characters, that is summarized; shorter ones
+    /// are covered well enough by their chunks.
+    pub min_chars: usize,
+
+    /// Most characters of a document sent to the model; the rest is cut.
+    pub max_input_chars: usize,
+}
+
+impl Default for SummaryConfig {
+    fn default() -> Self {
+        Self {
+            min_chars: 4000,
+            max_input_chars: 16_000,
+        }
+    }
+}
+
+/// Ask `backend` for a summary of a document, of which only the first
+/// `max_input_chars` characters are sent.
+pub(crate) async fn summarize(
+    backend: &dyn CompletionBackend,
+    title: &str,
+    content: &str,
+    max_input_chars: usize,
+) -> ApiResult<String> {
+    let end = content
+        .char_indices()
+        .nth(max_input_chars)
+        .map_or(content.len(), |(i, _)| i);
+    let prompt = format!("Title: {}\n\n{}", title, &content[..end]);
+
+    let reply = backend
+        .complete(SYSTEM_PROMPT, &prompt)
+        .await
+        .map_err(|e| ApiError::Internal(format!("Failed to summarize {:?}: {}", title, e)))?;
+    let summary = reply.trim();
+    if summary.is_empty() {
+        return Err(ApiError::Internal(format!(
+            "Failed to summarize {:?}: empty reply",
+            title
+        )));
+    }
+
+    tracing::debug!("Summarized {:?} with {}", title, backend.model());
+    Ok(summary.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use synton_ml::MlResult;
+
+    /// Replies with the length of the prompt it was sent.
+    struct Counting;
+
+    #[async_trait]
+    impl CompletionBackend for Counting {
+        async fn complete(&self, _system: &str, prompt: &str) -> MlResult<String> {
+            Ok(format!("  {} chars\n", prompt.chars().count()))
+        }
+
+        fn model(&self) -> &str {
+            "counting"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summarize_truncates_input() {
+        let content = "é".repeat(100);
+        let summary = summarize(&Counting, "Doc", &content, 10).await.unwrap();
+        assert_eq!(summary, format!("{} chars", "Title: Doc\n\n".len() + 10));
+
+        let summary = summarize(&Counting, "Doc", "short", 1000).await.unwrap();
+        assert_eq!(summary, format!("{} chars", "Title: Doc\n\nshort".len()));
+    }
+}
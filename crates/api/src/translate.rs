@@ -0,0 +1,123 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Natural-language fallback for PaQL.
+//!
+//! A query the PaQL parser rejects is sent to the configured completion
+//! backend with a prompt describing the grammar. The reply is stripped of
+//! code fences and labels and parsed again; only a reply that parses is
+//! executed, so the model can never run anything PaQL cannot express.
+
+use synton_ml::CompletionBackend;
+use synton_paql::{ParseError, Parser, Query};
+
+use crate::{ApiError, ApiResult};
+
+/// Instruction constraining the completion to a single PaQL query.
+const SYSTEM_PROMPT: &str = r#"You translate questions about a knowledge graph into PaQL, its query language. Reply with exactly one PaQL query on a single line and nothing else: no explanation, no quotes around the whole query, no code fence.
+
+PaQL:
+- Plain words search node content: `rust ownership`
+- `similar to "text"` searches by meaning: `find concepts similar to "supply shortage"`
+- `a and b`, `a or b` and `not a` combine searches
+- `tagged "name"` finds nodes carrying a tag
+- `role <name> <node id>` finds facts in which a node plays a role (agent, recipient, ...)
+- `<search> where <conditions>` filters; conditions are `field op value` joined by `and`, over content, type, confidence, access_score, created_at, tag or any attribute, with =, !=, >, >=, <, <=, contains and in [a, b]
+- Stages chain with `|`: `traverse [relations] [N hops] [forward|backward|both]`, `filter <conditions>`, `sort by <field> [asc|desc]`, `limit N`
+- `rank by <expression>` over similarity, recency, centrality, confidence and access_score orders the results
+
+Examples:
+Which concepts are like supply shortages, and what do they cause? -> find concepts similar to "supply shortage" | traverse causes 2 hops
+What do we know about Kubernetes, most confident first? -> kubernetes | sort by confidence desc
+Show architecture notes created since January 2025 -> tagged "architecture" | filter created_at >= "2025-01-01""#;
+
+/// Translate a question the parser rejected with `error` into PaQL,
+/// returning the generated query and its parse.
+pub(crate) async fn translate(
+    backend: &dyn CompletionBackend,
+    question: &str,
+    error: ParseError,
+) -> ApiResult<(String, Query)> {
+    let failed = |reason: String| {
+        ApiError::InvalidRequest(format!("{}; natural-language translation failed: {}", error, reason))
+    };
+
+    let reply = backend
+        .complete(SYSTEM_PROMPT, question)
+        .await
+        .map_err(|e| failed(e.to_string()))?;
+    let paql = extract_query(&reply).ok_or_else(|| failed("empty reply".to_string()))?;
+    let parsed = Parser::new()
+        .parse(&paql)
+        .map_err(|e| failed(format!("{} in {:?}", e, paql)))?;
+
+    tracing::debug!("Translated {:?} into PaQL {:?} with {}", question, paql, backend.model());
+    Ok((paql, parsed))
+}
+
+/// The query in a completion: the first non-empty line, without code
+/// fences, a `PaQL:` label or backticks around it.
+fn extract_query(reply: &str) -> Option<String> {
+    let line = reply
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("```"))?;
+    let line = line
+        .strip_prefix("PaQL:")
+        .or_else(|| line.strip_prefix("paql:"))
+        .unwrap_or(line)
+        .trim();
+    let line = line
+        .strip_prefix('`')
+        .and_then(|line| line.strip_suffix('`'))
+        .unwrap_or(line)
+        .trim();
+    (!line.is_empty()).then(|| line.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use synton_ml::MlResult;
+
+    struct Canned(&'static str);
+
+    #[async_trait]
+    impl CompletionBackend for Canned {
+        async fn complete(&self, _system: &str, _prompt: &str) -> MlResult<String> {
+            Ok(self.0.to_string())
+        }
+
+        fn model(&self) -> &str {
+            "canned"
+        }
+    }
+
+    #[test]
+    fn test_extract_query() {
+        assert_eq!(extract_query("tagged rust").as_deref(), Some("tagged rust"));
+        assert_eq!(
+            extract_query("```paql\nrust | limit 5\n```").as_deref(),
+            Some("rust | limit 5")
+        );
+        assert_eq!(extract_query("PaQL: `tagged rust`").as_deref(), Some("tagged rust"));
+        assert_eq!(extract_query("  \n```\n```"), None);
+    }
+
+    #[tokio::test]
+    async fn test_translate() {
+        let error = ParseError::InvalidSyntax("invalid tag: rust crates".to_string());
+        let (paql, parsed) = translate(&Canned("```\ntagged \"rust\"\n```"), "tagged rust crates", error.clone())
+            .await
+            .unwrap();
+        assert_eq!(paql, "tagged \"rust\"");
+        assert!(parsed.stages.is_empty());
+
+        let result = translate(&Canned("tagged two words"), "tagged rust crates", error).await;
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("invalid tag: rust crates"));
+        assert!(message.contains("translation failed"));
+    }
+}
@@ -0,0 +1,332 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Per-node usage analytics.
+//!
+//! Every access of a node (a query returning it, a traversal visiting it or
+//! a get by ID) is appended to the access log with its time and actor, the
+//! value of the `x-actor` request header. With persistence the log is kept
+//! in the `access_log` column family and replayed at startup; in memory it
+//! is aggregated into per-node counts, so usage reports need no scan.
+//!
+//! Log keys are the node ID followed by the access time in milliseconds and
+//! a sequence number, both big-endian, so a node's entries are contiguous
+//! and in time order.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{AccessEvent, AccessKind, NodeUsage};
+
+/// Request header naming the actor of the request's accesses.
+pub const ACTOR_HEADER: &str = "x-actor";
+
+tokio::task_local! {
+    static ACTOR: Option<String>;
+}
+
+/// Middleware that attributes the accesses a request makes to the actor in
+/// its `x-actor` header.
+pub async fn actor_middleware(
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let actor = request
+        .headers()
+        .get(ACTOR_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|actor| !actor.is_empty())
+        .map(str::to_string);
+    with_actor(actor, next.run(request)).await
+}
+
+/// Run `future` on behalf of `actor`.
+pub(crate) async fn with_actor<F: std::future::Future>(
+    actor: Option<String>,
+    future: F,
+) -> F::Output {
+    ACTOR.scope(actor, future).await
+}
+
+/// Actor of the current request, if it named one.
+pub(crate) fn current_actor() -> Option<String> {
+    ACTOR.try_with(Clone::clone).ok().flatten()
+}
+
+/// Usage tracking settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsageConfig {
+    /// Most recent accesses kept per node for usage reports.
+    pub recent_events: usize,
+}
+
+impl Default for UsageConfig {
+    fn default() -> Self {
+        Self { recent_events: 20 }
+    }
+}
+
+#[derive(Debug, Default)]
+struct NodeStats {
+    counts: HashMap<AccessKind, u64>,
+    first: Option<DateTime<Utc>>,
+    last: Option<DateTime<Utc>>,
+    actors: HashMap<String, u64>,
+    recent: VecDeque<AccessEvent>,
+}
+
+impl NodeStats {
+    fn add(&mut self, event: AccessEvent, recent_events: usize) {
+        *self.counts.entry(event.kind).or_default() += 1;
+        let at = event.accessed_at;
+        self.first = Some(self.first.map_or(at, |first| first.min(at)));
+        self.last = Some(self.last.map_or(at, |last| last.max(at)));
+        if let Some(actor) = &event.actor {
+            *self.actors.entry(actor.clone()).or_default() += 1;
+        }
+        if recent_events > 0 {
+            if self.recent.len() >= recent_events {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(event);
+        }
+    }
+
+    fn count(&self, kind: Option<AccessKind>) -> u64 {
+        match kind {
+            Some(kind) => self.counts.get(&kind).copied().unwrap_or(0),
+            None => self.counts.values().sum(),
+        }
+    }
+
+    fn usage(&self, node_id: Uuid) -> NodeUsage {
+        let count = |kind| self.count(Some(kind));
+        NodeUsage {
+            node_id,
+            total: self.count(None),
+            queries: count(AccessKind::Query),
+            traversals: count(AccessKind::Traversal),
+            gets: count(AccessKind::Get),
+            first_accessed_at: self.first,
+            last_accessed_at: self.last,
+            actors: self.actors.clone(),
+            recent: self.recent.iter().rev().cloned().collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct UsageState {
+    config: Option<UsageConfig>,
+    nodes: HashMap<Uuid, NodeStats>,
+    sequence: u64,
+}
+
+impl Default for UsageState {
+    fn default() -> Self {
+        Self {
+            config: Some(UsageConfig::default()),
+            nodes: HashMap::new(),
+            sequence: 0,
+        }
+    }
+}
+
+/// Aggregated node accesses; enabled with the default settings until
+/// configured otherwise.
+#[derive(Debug, Default)]
+pub(crate) struct UsageTracker {
+    state: Mutex<UsageState>,
+}
+
+impl UsageTracker {
+    fn state(&self) -> MutexGuard<'_, UsageState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Enable tracking with the given settings, or disable it with `None`.
+    /// Aggregated usage is kept.
+    pub(crate) fn configure(&self, config: Option<UsageConfig>) {
+        self.state().config = config;
+    }
+
+    /// Whether accesses are recorded.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.state().config.is_some()
+    }
+
+    /// Record an access of each node, returning the access log entries to
+    /// persist. Nothing is recorded while disabled.
+    pub(crate) fn record(
+        &self,
+        ids: &[Uuid],
+        kind: AccessKind,
+        actor: Option<String>,
+        at: DateTime<Utc>,
+    ) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut state = self.state();
+        let Some(config) = state.config else {
+            return Vec::new();
+        };
+
+        let mut entries = Vec::with_capacity(ids.len());
+        for &id in ids {
+            let event = AccessEvent {
+                kind,
+                actor: actor.clone(),
+                accessed_at: at,
+            };
+            state.sequence += 1;
+            let key = log_key(id, at, state.sequence);
+            if let Ok(value) = serde_json::to_vec(&event) {
+                entries.push((key, value));
+            }
+            state
+                .nodes
+                .entry(id)
+                .or_default()
+                .add(event, config.recent_events);
+        }
+        entries
+    }
+
+    /// Aggregate persisted access log entries, returning how many were
+    /// loaded. Malformed entries are skipped.
+    pub(crate) fn load(&self, entries: Vec<(Vec<u8>, Vec<u8>)>) -> usize {
+        let mut state = self.state();
+        let recent_events = state.config.unwrap_or_default().recent_events;
+
+        let mut loaded = 0;
+        for (key, value) in entries {
+            let Some(id) = key.get(..16).and_then(|id| Uuid::from_slice(id).ok()) else {
+                continue;
+            };
+            let Ok(event) = serde_json::from_slice::<AccessEvent>(&value) else {
+                continue;
+            };
+            state.nodes.entry(id).or_default().add(event, recent_events);
+            loaded += 1;
+        }
+        loaded
+    }
+
+    /// Usage of a node; zero counts if it was never accessed.
+    pub(crate) fn usage(&self, id: Uuid) -> NodeUsage {
+        let state = self.state();
+        state
+            .nodes
+            .get(&id)
+            .unwrap_or(&NodeStats::default())
+            .usage(id)
+    }
+
+    /// The `limit` most accessed nodes, counting only accesses of `kind`
+    /// if given. Ties go to the most recently accessed node.
+    pub(crate) fn top(&self, limit: usize, kind: Option<AccessKind>) -> Vec<NodeUsage> {
+        let state = self.state();
+        let mut ranked: Vec<(&Uuid, &NodeStats)> = state
+            .nodes
+            .iter()
+            .filter(|(_, stats)| stats.count(kind) > 0)
+            .collect();
+        ranked.sort_by(|(a_id, a), (b_id, b)| {
+            b.count(kind)
+                .cmp(&a.count(kind))
+                .then_with(|| b.last.cmp(&a.last))
+                .then_with(|| a_id.cmp(b_id))
+        });
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(id, stats)| stats.usage(*id))
+            .collect()
+    }
+
+    /// Drop the usage of a deleted node.
+    pub(crate) fn forget(&self, id: Uuid) {
+        self.state().nodes.remove(&id);
+    }
+}
+
+/// Access log key prefix of a node's entries.
+pub(crate) fn log_prefix(id: Uuid) -> Vec<u8> {
+    id.as_bytes().to_vec()
+}
+
+fn log_key(id: Uuid, at: DateTime<Utc>, sequence: u64) -> Vec<u8> {
+    let mut key = log_prefix(id);
+    key.extend_from_slice(&(at.timestamp_millis().max(0) as u64).to_be_bytes());
+    key.extend_from_slice(&sequence.to_be_bytes());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregates_accesses() {
+        let tracker = UsageTracker::default();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let now = Utc::now();
+        let earlier = now - chrono::Duration::minutes(5);
+
+        tracker.record(&[a, b], AccessKind::Query, None, earlier);
+        tracker.record(&[a], AccessKind::Get, Some("agent-1".to_string()), now);
+        tracker.record(&[b], AccessKind::Traversal, Some("agent-1".to_string()), now);
+        tracker.record(&[b], AccessKind::Traversal, None, now);
+
+        let usage = tracker.usage(a);
+        assert_eq!((usage.total, usage.queries, usage.gets, usage.traversals), (2, 1, 1, 0));
+        assert_eq!(usage.first_accessed_at, Some(earlier));
+        assert_eq!(usage.last_accessed_at, Some(now));
+        assert_eq!(usage.actors.get("agent-1"), Some(&1));
+        assert_eq!(usage.recent[0].kind, AccessKind::Get);
+
+        let top: Vec<Uuid> = tracker.top(10, None).iter().map(|u| u.node_id).collect();
+        assert_eq!(top, vec![b, a]);
+        let gets: Vec<Uuid> = tracker.top(10, Some(AccessKind::Get)).iter().map(|u| u.node_id).collect();
+        assert_eq!(gets, vec![a]);
+
+        tracker.forget(b);
+        assert_eq!(tracker.usage(b).total, 0);
+
+        tracker.configure(None);
+        assert!(tracker.record(&[a], AccessKind::Get, None, now).is_empty());
+        assert_eq!(tracker.usage(a).total, 2);
+    }
+
+    #[tokio::test]
+    async fn test_current_actor() {
+        assert_eq!(current_actor(), None);
+        let actor = ACTOR.scope(Some("agent-1".to_string()), async { current_actor() }).await;
+        assert_eq!(actor.as_deref(), Some("agent-1"));
+    }
+
+    #[test]
+    fn test_log_roundtrip() {
+        let tracker = UsageTracker::default();
+        tracker.configure(Some(UsageConfig { recent_events: 2 }));
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let mut entries = tracker.record(&[id], AccessKind::Query, None, now);
+        entries.extend(tracker.record(&[id], AccessKind::Query, None, now));
+        entries.extend(tracker.record(&[id], AccessKind::Get, Some("cli".to_string()), now));
+        assert!(entries.iter().all(|(key, _)| key.starts_with(&log_prefix(id))));
+        // Same-millisecond accesses get distinct, increasing keys
+        assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        let restored = UsageTracker::default();
+        restored.configure(Some(UsageConfig { recent_events: 2 }));
+        entries.push((b"bad".to_vec(), b"{}".to_vec()));
+        assert_eq!(restored.load(entries), 3);
+        assert_eq!(restored.usage(id), tracker.usage(id));
+        assert_eq!(restored.usage(id).recent.len(), 2);
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Request validation.
+//!
+//! REST bodies are decoded with [`ValidJson`], which reports the path of a
+//! field that fails to decode (missing, wrong type, malformed UUID, unknown
+//! enum value) and then applies the request's [`Validate`] rules. Numeric
+//! ranges match the MCP tool schemas. The gRPC handlers run the same rules
+//! on the requests they build. Failures are returned as a 400 whose body
+//! lists every offending field.
+
+use std::fmt;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRequest, Request};
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+use synton_error::FieldError;
+
+use crate::models::{
+    AddEdgeRequest, AddNodeRequest, BulkOperationRequest, BulkUpdateNodesRequest,
+    CreateVectorStoreFileRequest, CreateVectorStoreRequest, DeleteNodeRequest, ExecuteQueryRequest,
+    FeedbackRequest, HybridSearchRequest, IngestDocumentRequest, IngestUrlRequest,
+    MatchPatternRequest, PrepareQueryRequest, QueryRequest, ReasonPathsRequest, ReviewEdgeRequest,
+    StructuredQueryRequest, TagNodeRequest, TransactionRequest, TraverseRequest, UpdateNodeRequest,
+    VectorStoreSearchRequest,
+};
+use crate::{ApiError, ApiResult};
+
+/// Largest query `limit`, as in the `synton_query` tool.
+const MAX_QUERY_LIMIT: usize = 100;
+
+/// Largest hybrid search `k`, as in the `synton_hybrid_search` tool.
+const MAX_HYBRID_K: usize = 50;
+
+/// Largest traversal depth, as in the `synton_traverse` tool.
+pub(crate) const MAX_TRAVERSE_DEPTH: usize = 5;
+
+/// Largest traversal node count, as in the `synton_traverse` tool.
+pub(crate) const MAX_TRAVERSE_NODES: usize = 500;
+
+/// Rules a request must follow beyond what decoding checks.
+pub trait Validate {
+    /// Fields breaking a rule; empty if the request is valid.
+    fn validate(&self) -> Vec<FieldError> {
+        Vec::new()
+    }
+}
+
+/// Fail with [`ApiError::Validation`] if the request breaks a rule.
+pub fn check<T: Validate>(request: &T) -> ApiResult<()> {
+    let errors = request.validate();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ApiError::Validation(errors))
+    }
+}
+
+/// Collects the fields of a request that break a rule.
+#[derive(Debug, Default)]
+pub struct Checks {
+    errors: Vec<FieldError>,
+}
+
+impl Checks {
+    /// Text must have a non-whitespace character.
+    pub fn not_blank(&mut self, field: &str, value: &str) -> &mut Self {
+        if value.trim().is_empty() {
+            self.errors.push(FieldError::new(field, "must not be empty"));
+        }
+        self
+    }
+
+    /// A list must have an item.
+    pub fn not_empty<T>(&mut self, field: &str, values: &[T]) -> &mut Self {
+        if values.is_empty() {
+            self.errors.push(FieldError::new(field, "must not be empty"));
+        }
+        self
+    }
+
+    /// A number must lie in `min..=max`.
+    pub fn range<N>(&mut self, field: &str, value: N, min: N, max: N) -> &mut Self
+    where
+        N: PartialOrd + fmt::Display,
+    {
+        if !(min <= value && value <= max) {
+            self.errors.push(FieldError::new(
+                field,
+                format!("must be between {} and {} (got {})", min, max, value),
+            ));
+        }
+        self
+    }
+
+    /// An optional number, if given, must lie in `min..=max`.
+    pub fn optional_range<N>(&mut self, field: &str, value: Option<N>, min: N, max: N) -> &mut Self
+    where
+        N: PartialOrd + fmt::Display,
+    {
+        if let Some(value) = value {
+            self.range(field, value, min, max);
+        }
+        self
+    }
+
+    /// A number must be at least `min`.
+    pub fn at_least<N>(&mut self, field: &str, value: N, min: N) -> &mut Self
+    where
+        N: PartialOrd + fmt::Display,
+    {
+        if value < min {
+            self.errors.push(FieldError::new(
+                field,
+                format!("must be at least {} (got {})", min, value),
+            ));
+        }
+        self
+    }
+
+    /// The collected errors.
+    pub fn finish(&mut self) -> Vec<FieldError> {
+        std::mem::take(&mut self.errors)
+    }
+}
+
+/// JSON body extractor that decodes and validates a request.
+///
+/// Syntax and decoding errors become [`ApiError::InvalidRequest`] and
+/// [`ApiError::Validation`] instead of Axum's plain-text 422s. Missing or
+/// wrong content types and oversized bodies are rejected as by
+/// [`axum::Json`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidJson<T>(pub T);
+
+#[axum::async_trait]
+impl<T, S> FromRequest<S> for ValidJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = match axum::Json::<serde_json::Value>::from_request(req, state).await {
+            Ok(axum::Json(value)) => value,
+            Err(JsonRejection::JsonSyntaxError(e)) => {
+                return Err(ApiError::InvalidRequest(e.body_text()).into_response());
+            }
+            Err(rejection) => return Err(rejection.into_response()),
+        };
+        let request = decode(value).map_err(IntoResponse::into_response)?;
+        check(&request).map_err(IntoResponse::into_response)?;
+        Ok(Self(request))
+    }
+}
+
+/// Decode a JSON value, naming the field that failed.
+pub fn decode<T: DeserializeOwned>(value: serde_json::Value) -> ApiResult<T> {
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let path = e.path().to_string();
+        let message = e.into_inner().to_string();
+        let missing = message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'));
+        let error = match (missing, path.as_str()) {
+            (Some(field), ".") => FieldError::new(field, "is required"),
+            (Some(field), parent) => FieldError::new(format!("{}.{}", parent, field), "is required"),
+            (None, ".") => FieldError::new("body", message),
+            (None, _) => FieldError::new(path, message),
+        };
+        ApiError::Validation(vec![error])
+    })
+}
+
+impl Validate for AddNodeRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default().not_blank("content", &self.content).finish()
+    }
+}
+
+impl Validate for UpdateNodeRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut checks = Checks::default();
+        if let Some(content) = &self.content {
+            checks.not_blank("content", content);
+        }
+        checks.finish()
+    }
+}
+
+impl Validate for AddEdgeRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default()
+            .range("weight", self.weight, 0.0, 1.0)
+            .optional_range("provenance.confidence", self.provenance.confidence, 0.0, 1.0)
+            .finish()
+    }
+}
+
+impl Validate for QueryRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        let mut checks = Checks::default();
+        checks
+            .optional_range("limit", self.limit, 1, MAX_QUERY_LIMIT)
+            .optional_range("min_confidence", self.min_confidence, 0.0, 1.0)
+            .optional_range("recency_weight", self.recency_weight, 0.0, 1.0);
+        if let Some(sample) = self.sample {
+            checks.at_least("sample", sample, 1);
+        }
+        checks.finish()
+    }
+}
+
+impl Validate for StructuredQueryRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default()
+            .optional_range("limit", self.limit, 1, MAX_QUERY_LIMIT)
+            .optional_range("min_confidence", self.min_confidence, 0.0, 1.0)
+            .optional_range("recency_weight", self.recency_weight, 0.0, 1.0)
+            .finish()
+    }
+}
+
+impl Validate for ExecuteQueryRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default()
+            .optional_range("limit", self.limit, 1, MAX_QUERY_LIMIT)
+            .finish()
+    }
+}
+
+impl Validate for HybridSearchRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default().range("k", self.k, 1, MAX_HYBRID_K).finish()
+    }
+}
+
+impl Validate for TraverseRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default()
+            .range("max_depth", self.max_depth, 1, MAX_TRAVERSE_DEPTH)
+            .range("max_nodes", self.max_nodes, 1, MAX_TRAVERSE_NODES)
+            .optional_range("min_confidence", self.min_confidence, 0.0, 1.0)
+            .finish()
+    }
+}
+
+impl Validate for ReasonPathsRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default()
+            .at_least("limit", self.limit, 1)
+            .optional_range("min_confidence", self.min_confidence, 0.0, 1.0)
+            .finish()
+    }
+}
+
+impl Validate for MatchPatternRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default().not_blank("pattern", &self.pattern).finish()
+    }
+}
+
+impl Validate for TagNodeRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default().not_empty("tags", &self.tags).finish()
+    }
+}
+
+impl Validate for IngestDocumentRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default().not_blank("content", &self.content).finish()
+    }
+}
+
+impl Validate for IngestUrlRequest {
+    fn validate(&self) -> Vec<FieldError> {
+        Checks::default().not_blank("url", &self.url).finish()
+    }
+}
+
+// Fully checked by decoding
+impl Validate for BulkOperationRequest {}
+// Updates are checked one by one, so one bad update does not fail the rest
+impl Validate for BulkUpdateNodesRequest {}
+impl Validate for CreateVectorStoreFileRequest {}
+impl Validate for CreateVectorStoreRequest {}
+impl Validate for DeleteNodeRequest {}
+impl Validate for FeedbackRequest {}
+impl Validate for PrepareQueryRequest {}
+impl Validate for ReviewEdgeRequest {}
+impl Validate for TransactionRequest {}
+impl Validate for VectorStoreSearchRequest {}
+impl Validate for crate::ViewDefinition {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn decode_errors<T: DeserializeOwned + Validate>(body: serde_json::Value) -> Vec<FieldError> {
+        match decode::<T>(body).and_then(|request| check(&request)) {
+            Ok(()) => Vec::new(),
+            Err(ApiError::Validation(errors)) => errors,
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_decode_names_failing_field() {
+        let errors = decode_errors::<AddNodeRequest>(json!({"node_type": "concept"}));
+        assert_eq!(errors, vec![FieldError::new("content", "is required")]);
+
+        let errors = decode_errors::<AddNodeRequest>(json!({"content": "x", "node_type": "thing"}));
+        assert_eq!(errors[0].field, "node_type");
+        assert!(errors[0].message.contains("unknown variant"));
+
+        let errors = decode_errors::<AddEdgeRequest>(json!({
+            "source": "not-a-uuid",
+            "target": uuid::Uuid::new_v4(),
+            "relation": "causes"
+        }));
+        assert_eq!(errors[0].field, "source");
+
+        let errors = decode_errors::<AddEdgeRequest>(json!({
+            "source": uuid::Uuid::new_v4(),
+            "target": uuid::Uuid::new_v4(),
+            "relation": "causes",
+            "provenance": {"confidence": "high"}
+        }));
+        assert_eq!(errors[0].field, "provenance.confidence");
+    }
+
+    #[test]
+    fn test_rules_report_every_field() {
+        let errors = decode_errors::<TraverseRequest>(json!({
+            "start_id": uuid::Uuid::new_v4(),
+            "max_depth": 0,
+            "max_nodes": 1000,
+            "direction": "Forward",
+            "min_confidence": 0.5
+        }));
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["max_depth", "max_nodes"]);
+        assert_eq!(errors[0].message, "must be between 1 and 5 (got 0)");
+
+        let errors = decode_errors::<AddEdgeRequest>(json!({
+            "source": uuid::Uuid::new_v4(),
+            "target": uuid::Uuid::new_v4(),
+            "relation": "causes",
+            "weight": 1.5
+        }));
+        assert_eq!(errors[0].field, "weight");
+
+        let errors = decode_errors::<UpdateNodeRequest>(json!({"content": "  "}));
+        assert_eq!(errors, vec![FieldError::new("content", "must not be empty")]);
+
+        assert!(decode_errors::<QueryRequest>(json!({"query": "rust", "limit": 10})).is_empty());
+        assert_eq!(
+            decode_errors::<QueryRequest>(json!({"query": "rust", "limit": 101}))[0].field,
+            "limit"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Registry behind the OpenAI-compatible vector store API.
+//!
+//! A vector store is a namespace: each file added to it is ingested as a
+//! document whose nodes carry the store ID as their `namespace` attribute,
+//! so the same knowledge is reachable through `/query` and the graph
+//! endpoints. Uploaded files are held in memory until they are deleted, and
+//! the registry itself is not persisted; the ingested nodes are.
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::models::{
+    FileObject, ListResponse, UploadFileRequest, VectorStore, VectorStoreFile, VectorStoreFileCounts,
+};
+
+/// An uploaded file waiting to be added to vector stores.
+#[derive(Debug, Clone)]
+pub(crate) struct UploadedFile {
+    pub(crate) object: FileObject,
+    pub(crate) content_type: Option<String>,
+    pub(crate) data: Vec<u8>,
+}
+
+/// A file ingested into a store, with the name search results report.
+#[derive(Debug, Clone)]
+pub(crate) struct StoreFile {
+    pub(crate) file: VectorStoreFile,
+    pub(crate) filename: String,
+}
+
+#[derive(Debug)]
+struct StoreEntry {
+    id: String,
+    name: Option<String>,
+    created_at: i64,
+    metadata: HashMap<String, String>,
+    files: Vec<StoreFile>,
+}
+
+impl StoreEntry {
+    fn to_store(&self) -> VectorStore {
+        let count = self.files.len();
+        VectorStore {
+            id: self.id.clone(),
+            object: "vector_store".to_string(),
+            created_at: self.created_at,
+            name: self.name.clone(),
+            usage_bytes: self.files.iter().map(|f| f.file.usage_bytes).sum(),
+            file_counts: VectorStoreFileCounts {
+                completed: count,
+                total: count,
+                ..Default::default()
+            },
+            status: "completed".to_string(),
+            metadata: self.metadata.clone(),
+        }
+    }
+}
+
+/// Vector stores in creation order, and pending uploads.
+#[derive(Debug, Default)]
+pub(crate) struct VectorStoreRegistry {
+    stores: Vec<StoreEntry>,
+    uploads: HashMap<String, UploadedFile>,
+}
+
+impl VectorStoreRegistry {
+    /// Hold an uploaded file.
+    pub(crate) fn upload(&mut self, request: UploadFileRequest) -> FileObject {
+        let object = FileObject {
+            id: format!("file-{}", Uuid::new_v4().simple()),
+            object: "file".to_string(),
+            bytes: request.data.len(),
+            created_at: chrono::Utc::now().timestamp(),
+            filename: request.file_name,
+            purpose: request.purpose,
+        };
+        self.uploads.insert(
+            object.id.clone(),
+            UploadedFile {
+                object: object.clone(),
+                content_type: request.content_type,
+                data: request.data,
+            },
+        );
+        object
+    }
+
+    /// An uploaded file, if it has not been deleted.
+    pub(crate) fn upload_for(&self, file_id: &str) -> Option<&UploadedFile> {
+        self.uploads.get(file_id)
+    }
+
+    /// Forget an uploaded file. Stores it was added to keep its contents.
+    pub(crate) fn delete_upload(&mut self, file_id: &str) -> bool {
+        self.uploads.remove(file_id).is_some()
+    }
+
+    /// Register an empty store.
+    pub(crate) fn create(&mut self, name: Option<String>, metadata: HashMap<String, String>) -> VectorStore {
+        let entry = StoreEntry {
+            id: format!("vs_{}", Uuid::new_v4().simple()),
+            name,
+            created_at: chrono::Utc::now().timestamp(),
+            metadata,
+            files: Vec::new(),
+        };
+        let store = entry.to_store();
+        self.stores.push(entry);
+        store
+    }
+
+    fn entry(&self, store_id: &str) -> Option<&StoreEntry> {
+        self.stores.iter().find(|entry| entry.id == store_id)
+    }
+
+    /// A store with its current file counts.
+    pub(crate) fn store(&self, store_id: &str) -> Option<VectorStore> {
+        self.entry(store_id).map(StoreEntry::to_store)
+    }
+
+    /// All stores, oldest first.
+    pub(crate) fn stores(&self) -> Vec<VectorStore> {
+        self.stores.iter().map(StoreEntry::to_store).collect()
+    }
+
+    /// Unregister a store, returning its files.
+    pub(crate) fn remove(&mut self, store_id: &str) -> Option<Vec<StoreFile>> {
+        let position = self.stores.iter().position(|entry| entry.id == store_id)?;
+        Some(self.stores.remove(position).files)
+    }
+
+    /// Files in a store, oldest first.
+    pub(crate) fn files(&self, store_id: &str) -> Option<&[StoreFile]> {
+        self.entry(store_id).map(|entry| entry.files.as_slice())
+    }
+
+    /// Record a file ingested into a store. Returns `false` if the store no
+    /// longer exists.
+    pub(crate) fn add_file(&mut self, store_id: &str, file: StoreFile) -> bool {
+        match self.stores.iter_mut().find(|entry| entry.id == store_id) {
+            Some(entry) => {
+                entry.files.push(file);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Wrap items in an OpenAI-style list page.
+pub(crate) fn list_page<T>(data: Vec<T>, id: impl Fn(&T) -> &str) -> ListResponse<T> {
+    ListResponse {
+        object: "list".to_string(),
+        first_id: data.first().map(|item| id(item).to_string()),
+        last_id: data.last().map(|item| id(item).to_string()),
+        data,
+        has_more: false,
+    }
+}
+
+/// Lowercased words of a search query, without punctuation.
+pub(crate) fn query_words(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(name: &str) -> UploadFileRequest {
+        UploadFileRequest {
+            file_name: name.to_string(),
+            content_type: Some("text/plain".to_string()),
+            data: b"Batteries stabilise the grid.".to_vec(),
+            purpose: "assistants".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_registry_tracks_stores_and_files() {
+        let mut registry = VectorStoreRegistry::default();
+        let file = registry.upload(upload("grid.txt"));
+        assert!(file.id.starts_with("file-"));
+        assert_eq!(file.bytes, 29);
+
+        let first = registry.create(Some("Grid".to_string()), HashMap::new());
+        let second = registry.create(None, HashMap::new());
+        assert!(first.id.starts_with("vs_"));
+        let ids: Vec<String> = registry.stores().into_iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![first.id.clone(), second.id.clone()]);
+
+        let stored = StoreFile {
+            file: VectorStoreFile {
+                id: file.id.clone(),
+                object: "vector_store.file".to_string(),
+                created_at: 0,
+                vector_store_id: first.id.clone(),
+                usage_bytes: file.bytes,
+                status: "completed".to_string(),
+                attributes: HashMap::new(),
+                document_id: Uuid::new_v4(),
+            },
+            filename: file.filename.clone(),
+        };
+        let added = registry.add_file(&first.id, stored.clone());
+        assert!(added);
+        let store = registry.store(&first.id).unwrap();
+        assert_eq!((store.file_counts.total, store.usage_bytes), (1, 29));
+
+        // Deleting the upload keeps the store's copy
+        assert!(registry.delete_upload(&file.id));
+        assert!(registry.upload_for(&file.id).is_none());
+        assert_eq!(registry.files(&first.id).unwrap().len(), 1);
+
+        assert_eq!(registry.remove(&first.id).unwrap().len(), 1);
+        assert!(registry.store(&first.id).is_none());
+        assert!(!registry.add_file(&first.id, stored));
+    }
+
+    #[test]
+    fn test_query_words() {
+        assert_eq!(query_words("How do I reset my password?"), vec!["how", "do", "i", "reset", "my", "password"]);
+        let page = list_page(vec!["a".to_string(), "b".to_string()], |s| s.as_str());
+        assert_eq!((page.first_id.as_deref(), page.last_id.as_deref()), (Some("a"), Some("b")));
+    }
+}
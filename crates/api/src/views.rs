@@ -0,0 +1,368 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Materialized traversal views.
+//!
+//! A view is a named traversal (start nodes, depth, direction and edge
+//! filters) whose member nodes and the edges between them are kept, so
+//! dashboards reading the same neighborhood over and over get it in time
+//! proportional to its size. Every node and edge write is checked against
+//! the views as it happens: a write touching a member, or a node becoming
+//! a start node, marks the view stale, and the next read traverses it
+//! again. Writes elsewhere in the graph leave it as is.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use synton_core::{Edge, EdgeCreator, Node, Relation};
+use synton_graph::TraversalConfig;
+use uuid::Uuid;
+
+use crate::models::TraverseDirection;
+use crate::validation::{MAX_TRAVERSE_DEPTH, MAX_TRAVERSE_NODES};
+
+/// A named traversal whose result the server maintains.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewDefinition {
+    /// Unique name, used in `/views/{name}`.
+    pub name: String,
+
+    /// Nodes the traversal starts from.
+    #[serde(default)]
+    pub start_ids: Vec<Uuid>,
+
+    /// Also start from every node carrying one of these tags.
+    #[serde(default)]
+    pub start_tags: Vec<String>,
+
+    /// Maximum traversal depth.
+    pub max_depth: usize,
+
+    /// Maximum number of member nodes, start nodes included.
+    #[serde(default = "default_max_nodes")]
+    pub max_nodes: usize,
+
+    /// Traversal direction.
+    #[serde(default = "default_direction")]
+    pub direction: TraverseDirection,
+
+    /// Only follow edges of these relations (empty = all).
+    #[serde(default)]
+    pub relations: Vec<Relation>,
+
+    /// Only follow edges made by these creators (empty = all).
+    #[serde(default)]
+    pub created_by: Vec<EdgeCreator>,
+
+    /// Only follow edges whose extraction confidence is at least this value.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
+}
+
+fn default_max_nodes() -> usize {
+    MAX_TRAVERSE_NODES
+}
+
+fn default_direction() -> TraverseDirection {
+    TraverseDirection::Forward
+}
+
+impl ViewDefinition {
+    /// Check the definition.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() || self.name.contains('/') {
+            return Err(format!("invalid view name {:?}", self.name));
+        }
+        if self.start_ids.is_empty() && self.start_tags.is_empty() {
+            return Err(format!(
+                "view {:?} has no start_ids or start_tags",
+                self.name
+            ));
+        }
+        if !(1..=MAX_TRAVERSE_DEPTH).contains(&self.max_depth) {
+            return Err(format!(
+                "view {:?} has max_depth {} (expected 1 to {})",
+                self.name, self.max_depth, MAX_TRAVERSE_DEPTH
+            ));
+        }
+        if !(1..=MAX_TRAVERSE_NODES).contains(&self.max_nodes) {
+            return Err(format!(
+                "view {:?} has max_nodes {} (expected 1 to {})",
+                self.name, self.max_nodes, MAX_TRAVERSE_NODES
+            ));
+        }
+        if self
+            .min_confidence
+            .is_some_and(|c| !(0.0..=1.0).contains(&c))
+        {
+            return Err(format!(
+                "view {:?} has min_confidence outside 0.0 to 1.0",
+                self.name
+            ));
+        }
+        for tag in &self.start_tags {
+            synton_core::normalize_tag(tag)
+                .map_err(|e| format!("view {:?} has an invalid start tag: {}", self.name, e))?;
+        }
+        Ok(())
+    }
+
+    /// Start tags, normalized.
+    pub(crate) fn tags(&self) -> Vec<String> {
+        self.start_tags
+            .iter()
+            .filter_map(|tag| synton_core::normalize_tag(tag).ok())
+            .collect()
+    }
+
+    /// Configuration of the traversal from each start node.
+    pub(crate) fn traversal_config(&self) -> TraversalConfig {
+        let mut config = TraversalConfig::with_depth(self.max_depth)
+            .with_max_nodes(self.max_nodes)
+            .with_direction(self.direction.into())
+            .with_include_start(true);
+        for relation in &self.relations {
+            config = config.with_relation(relation.clone());
+        }
+        for creator in &self.created_by {
+            config = config.with_creator(*creator);
+        }
+        if let Some(min_confidence) = self.min_confidence {
+            config = config.with_min_confidence(min_confidence);
+        }
+        config
+    }
+}
+
+/// A view's membership as last materialized.
+#[derive(Debug, Clone)]
+pub(crate) struct ViewSnapshot {
+    /// What the view traverses.
+    pub definition: ViewDefinition,
+    /// Member node IDs, in traversal order.
+    pub members: Vec<Uuid>,
+    /// Edges between members.
+    pub edges: Vec<Edge>,
+    /// Version of the view the membership reflects, if materialized.
+    pub materialized: Option<u64>,
+    /// Current version, bumped by every write affecting the view.
+    pub version: u64,
+    /// When the membership was last materialized.
+    pub refreshed_at: Option<DateTime<Utc>>,
+}
+
+impl ViewSnapshot {
+    /// Whether the membership may be out of date.
+    pub(crate) fn is_stale(&self) -> bool {
+        self.materialized != Some(self.version)
+    }
+}
+
+#[derive(Debug)]
+struct ViewState {
+    snapshot: ViewSnapshot,
+    member_set: HashSet<Uuid>,
+    tags: Vec<String>,
+}
+
+impl ViewState {
+    fn new(definition: ViewDefinition) -> Self {
+        Self {
+            tags: definition.tags(),
+            member_set: HashSet::new(),
+            snapshot: ViewSnapshot {
+                definition,
+                members: Vec::new(),
+                edges: Vec::new(),
+                materialized: None,
+                version: 0,
+                refreshed_at: None,
+            },
+        }
+    }
+}
+
+/// Defined views and their membership.
+#[derive(Debug, Default)]
+pub(crate) struct ViewRegistry {
+    views: Mutex<BTreeMap<String, ViewState>>,
+}
+
+impl ViewRegistry {
+    fn views(&self) -> MutexGuard<'_, BTreeMap<String, ViewState>> {
+        self.views.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replace every view with `definitions`, to be materialized on their
+    /// first read.
+    pub(crate) fn configure(&self, definitions: Vec<ViewDefinition>) -> Result<(), String> {
+        let mut views = BTreeMap::new();
+        for definition in definitions {
+            definition.validate()?;
+            let name = definition.name.clone();
+            if views
+                .insert(name.clone(), ViewState::new(definition))
+                .is_some()
+            {
+                return Err(format!("duplicate view name {:?}", name));
+            }
+        }
+        *self.views() = views;
+        Ok(())
+    }
+
+    /// Define or redefine a view. Returns whether it replaced one.
+    pub(crate) fn define(&self, definition: ViewDefinition) -> Result<bool, String> {
+        definition.validate()?;
+        let name = definition.name.clone();
+        Ok(self
+            .views()
+            .insert(name, ViewState::new(definition))
+            .is_some())
+    }
+
+    /// Drop a view, returning its last membership.
+    pub(crate) fn remove(&self, name: &str) -> Option<ViewSnapshot> {
+        self.views().remove(name).map(|state| state.snapshot)
+    }
+
+    /// A view's current membership.
+    pub(crate) fn get(&self, name: &str) -> Option<ViewSnapshot> {
+        self.views().get(name).map(|state| state.snapshot.clone())
+    }
+
+    /// Every view's current membership, by name.
+    pub(crate) fn all(&self) -> Vec<ViewSnapshot> {
+        self.views()
+            .values()
+            .map(|state| state.snapshot.clone())
+            .collect()
+    }
+
+    /// Store the membership of a view traversed as `definition` at
+    /// `version`. A write since then leaves the view stale; a view
+    /// redefined meanwhile is left alone.
+    pub(crate) fn store(
+        &self,
+        definition: &ViewDefinition,
+        version: u64,
+        members: Vec<Uuid>,
+        edges: Vec<Edge>,
+    ) {
+        let mut views = self.views();
+        let Some(state) = views
+            .get_mut(&definition.name)
+            .filter(|state| state.snapshot.definition == *definition)
+        else {
+            return;
+        };
+        state.member_set = members.iter().copied().collect();
+        state.snapshot.members = members;
+        state.snapshot.edges = edges;
+        state.snapshot.materialized = Some(version);
+        state.snapshot.refreshed_at = Some(Utc::now());
+    }
+
+    /// Mark stale the views a node insert, update or removal may change.
+    pub(crate) fn node_changed(&self, node: &Node) {
+        for state in self.views().values_mut() {
+            let affected = state.member_set.contains(&node.id)
+                || state.snapshot.definition.start_ids.contains(&node.id)
+                || state.tags.iter().any(|tag| node.tags.contains(tag));
+            if affected {
+                state.snapshot.version += 1;
+            }
+        }
+    }
+
+    /// Mark stale the views an added edge may extend.
+    pub(crate) fn edge_added(&self, edge: &Edge) {
+        for state in self.views().values_mut() {
+            if state.member_set.contains(&edge.source) || state.member_set.contains(&edge.target) {
+                state.snapshot.version += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    fn definition(name: &str, start: Uuid) -> ViewDefinition {
+        ViewDefinition {
+            name: name.to_string(),
+            start_ids: vec![start],
+            start_tags: vec!["Dashboard".to_string()],
+            max_depth: 3,
+            max_nodes: default_max_nodes(),
+            direction: default_direction(),
+            relations: Vec::new(),
+            created_by: Vec::new(),
+            min_confidence: None,
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let start = Uuid::new_v4();
+        assert!(definition("team", start).validate().is_ok());
+
+        let mut invalid = definition("team/a", start);
+        assert!(invalid.validate().is_err());
+        invalid.name = "team".to_string();
+        invalid.max_depth = MAX_TRAVERSE_DEPTH + 1;
+        assert!(invalid.validate().is_err());
+        invalid.max_depth = 1;
+        invalid.start_ids.clear();
+        invalid.start_tags.clear();
+        assert!(invalid.validate().is_err());
+
+        let registry = ViewRegistry::default();
+        let twice = vec![definition("team", start), definition("team", start)];
+        assert!(registry.configure(twice).is_err());
+    }
+
+    #[test]
+    fn test_writes_mark_affected_views_stale() {
+        let registry = ViewRegistry::default();
+        let start = Node::new("Service map", NodeType::Concept);
+        let member = Node::new("Gateway", NodeType::Concept);
+        let outsider = Node::new("Unrelated", NodeType::Concept);
+        let services = definition("services", start.id);
+        registry.define(services.clone()).unwrap();
+        assert!(registry.get("services").unwrap().is_stale());
+
+        registry.store(&services, 0, vec![start.id, member.id], Vec::new());
+        assert!(!registry.get("services").unwrap().is_stale());
+
+        // Writes away from the view leave it fresh
+        registry.node_changed(&outsider);
+        registry.edge_added(&Edge::new(outsider.id, Uuid::new_v4(), Relation::Causes));
+        assert!(!registry.get("services").unwrap().is_stale());
+
+        registry.edge_added(&Edge::new(member.id, outsider.id, Relation::Causes));
+        let stale = registry.get("services").unwrap();
+        assert!(stale.is_stale());
+
+        // A write during the refresh keeps the view stale
+        registry.node_changed(&member);
+        registry.store(&services, stale.version, vec![start.id], Vec::new());
+        assert!(registry.get("services").unwrap().is_stale());
+
+        // A node gaining a start tag becomes a start node
+        let version = registry.get("services").unwrap().version;
+        registry.store(&services, version, vec![start.id], Vec::new());
+        let mut tagged = outsider.clone();
+        tagged.tags.insert("dashboard".to_string());
+        registry.node_changed(&tagged);
+        assert!(registry.get("services").unwrap().is_stale());
+
+        assert!(registry.remove("services").is_some());
+        assert!(registry.get("services").is_none());
+    }
+}
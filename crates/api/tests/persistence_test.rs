@@ -292,3 +292,182 @@ async fn test_persistence_complex_graph() {
     let retrieved = store.get_node(center.id).await.expect("Query failed");
     assert!(retrieved.is_some());
 }
+
+#[tokio::test]
+async fn test_storage_compaction_and_stats() {
+    use std::sync::Arc;
+    use synton_api::{AddNodeRequest, ApiError, CompactStorageRequest, SyntonDbService};
+
+    let (store, _temp_dir) = create_temp_store().await;
+    let service = SyntonDbService::with_store(Arc::new(store));
+
+    for i in 0..20 {
+        service
+            .add_node(AddNodeRequest::new(format!("Stored {}", i), NodeType::Fact))
+            .await
+            .expect("Failed to add node");
+    }
+
+    let response = service
+        .compact_storage(CompactStorageRequest {
+            column_family: Some("nodes".to_string()),
+            wait: true,
+        })
+        .await
+        .expect("Compaction failed");
+    assert!(response.started && response.completed);
+
+    let stats = service.storage_stats().await.expect("Stats failed");
+    assert!(!stats.compaction_in_progress);
+    let nodes = stats
+        .column_families
+        .iter()
+        .find(|cf| cf.name == "nodes")
+        .unwrap();
+    assert!(nodes.sst_bytes > 0);
+    assert!(stats.sst_bytes >= nodes.sst_bytes);
+
+    let unknown = service
+        .compact_storage(CompactStorageRequest {
+            column_family: Some("vectors".to_string()),
+            wait: true,
+        })
+        .await;
+    assert!(matches!(unknown, Err(ApiError::InvalidRequest(_))));
+
+    let in_memory = SyntonDbService::new();
+    assert!(matches!(
+        in_memory.storage_stats().await,
+        Err(ApiError::InvalidRequest(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_preload_hot_nodes() {
+    use std::sync::Arc;
+    use synton_api::{GetNodeRequest, SyntonDbService};
+
+    let (store, _temp_dir) = create_temp_store().await;
+
+    // A hot node with one neighbor, plus cold nodes
+    let mut hot = Node::new("Hot", NodeType::Concept);
+    hot.meta.access_score = 9.0;
+    let mut neighbor = Node::new("Neighbor", NodeType::Fact);
+    neighbor.meta.access_score = 0.5;
+    store.put_node(&hot).await.unwrap();
+    store.put_node(&neighbor).await.unwrap();
+    store
+        .put_edge(&Edge::new(hot.id, neighbor.id, synton_core::Relation::Causes))
+        .await
+        .unwrap();
+    let mut cold = Vec::new();
+    for i in 0..5 {
+        let mut node = Node::new(format!("Cold {}", i), NodeType::Fact);
+        node.meta.access_score = 1.0;
+        store.put_node(&node).await.unwrap();
+        cold.push(node);
+    }
+
+    let service = SyntonDbService::with_store(Arc::new(store));
+    let report = service.preload_hot_nodes(1, 4).await.expect("Preload failed");
+    assert_eq!(report.hot_nodes, 1);
+    assert_eq!(report.neighbor_nodes, 1);
+    assert_eq!(report.edges, 1);
+
+    let stats = service.stats().await.expect("Stats failed");
+    assert_eq!(stats.node_count, 2);
+    assert_eq!(stats.edge_count, 1);
+
+    // Cold nodes are still loaded on demand
+    let response = service
+        .get_node(GetNodeRequest { id: cold[0].id })
+        .await
+        .expect("Get failed");
+    assert!(response.node.is_some());
+}
+
+#[tokio::test]
+async fn test_access_log_survives_restart() {
+    use std::sync::Arc;
+    use synton_api::{
+        AccessKind, AddNodeRequest, DeleteNodeRequest, GetNodeRequest, SyntonDbService,
+        TopAccessedQuery,
+    };
+
+    let (store, temp_dir) = create_temp_store().await;
+    let (kept, deleted) = {
+        let service = SyntonDbService::with_store(Arc::new(store));
+        let kept = service
+            .add_node(AddNodeRequest::new("Kept".to_string(), NodeType::Concept))
+            .await
+            .unwrap()
+            .node
+            .id;
+        let deleted = service
+            .add_node(AddNodeRequest::new("Deleted".to_string(), NodeType::Concept))
+            .await
+            .unwrap()
+            .node
+            .id;
+        for id in [kept, kept, deleted] {
+            service.get_node(GetNodeRequest { id }).await.unwrap();
+        }
+        service
+            .delete_node(DeleteNodeRequest { id: deleted })
+            .await
+            .unwrap();
+        (kept, deleted)
+    };
+
+    let config = RocksdbConfig {
+        path: temp_dir.path().to_str().unwrap().to_string(),
+        ..Default::default()
+    };
+    let store = RocksdbStore::open(config).expect("Failed to reopen store");
+    let service = SyntonDbService::with_store(Arc::new(store));
+    service.initialize_from_store().await.unwrap();
+
+    let usage = service.node_usage(kept).await.unwrap();
+    assert_eq!(usage.gets, 2);
+    assert!(service.node_usage(deleted).await.is_err());
+
+    let top = service.top_accessed(TopAccessedQuery {
+        limit: None,
+        kind: Some(AccessKind::Get),
+    });
+    let ids: Vec<_> = top.nodes.iter().map(|usage| usage.node_id).collect();
+    assert_eq!(ids, vec![kept]);
+}
+
+#[tokio::test]
+async fn test_ingest_durability_survives_restart() {
+    use std::sync::Arc;
+    use synton_api::{IngestDocumentRequest, SyntonDbService};
+    use synton_storage::Durability;
+
+    let (store, temp_dir) = create_temp_store().await;
+    let document_id = {
+        let service = SyntonDbService::with_store(Arc::new(store));
+        service
+            .ingest_document(IngestDocumentRequest {
+                title: Some("Runbook".to_string()),
+                content: "Failover must be confirmed by two operators.".to_string(),
+                chunking: None,
+                embed: false,
+                metadata: None,
+                namespace: None,
+                summarize: false,
+                durability: Durability::Sync,
+            })
+            .await
+            .unwrap()
+            .document_id
+    };
+
+    let config = RocksdbConfig {
+        path: temp_dir.path().to_str().unwrap().to_string(),
+        ..Default::default()
+    };
+    let store = RocksdbStore::open(config).expect("Failed to reopen store");
+    assert!(store.get_node(document_id).await.unwrap().is_some());
+}
@@ -6,7 +6,7 @@
 
 use synton_api::{
     AddEdgeRequest, AddNodeRequest, DeleteNodeRequest, GetNodeRequest, QueryRequest,
-    SyntonDbService, TraverseRequest, TraverseDirection,
+    SyntonDbService, TraverseRequest, TraverseDirection, UpdateNodeRequest,
 };
 use synton_core::NodeType;
 use std::sync::Arc;
@@ -322,6 +322,13 @@ async fn test_query_simple() {
         query: "machine".to_string(),
         limit: Some(10),
         include_metadata: false,
+        include_archived: false,
+        count_only: false,
+        sample: None,
+        rank: None,
+        min_confidence: None,
+        recency_weight: None,
+        namespace: None,
     };
 
     let response = service.query(query).await.unwrap();
@@ -343,6 +350,13 @@ async fn test_query_case_insensitive() {
         query: "test content".to_string(), // Lowercase
         limit: Some(10),
         include_metadata: false,
+        include_archived: false,
+        count_only: false,
+        sample: None,
+        rank: None,
+        min_confidence: None,
+        recency_weight: None,
+        namespace: None,
     };
 
     let response = service.query(query).await.unwrap();
@@ -365,6 +379,13 @@ async fn test_query_no_results() {
         query: "nonexistent term".to_string(),
         limit: Some(10),
         include_metadata: false,
+        include_archived: false,
+        count_only: false,
+        sample: None,
+        rank: None,
+        min_confidence: None,
+        recency_weight: None,
+        namespace: None,
     };
 
     let response = service.query(query).await.unwrap();
@@ -386,6 +407,13 @@ async fn test_query_with_limit() {
         query: "Node".to_string(),
         limit: Some(3),
         include_metadata: false,
+        include_archived: false,
+        count_only: false,
+        sample: None,
+        rank: None,
+        min_confidence: None,
+        recency_weight: None,
+        namespace: None,
     };
 
     let response = service.query(query).await.unwrap();
@@ -441,6 +469,12 @@ async fn test_traverse_forward() {
         max_depth: 2,
         max_nodes: 10,
         direction: TraverseDirection::Forward,
+        created_by: Vec::new(),
+        max_estimated_cost: None,
+        follow_inverses: false,
+        min_confidence: None,
+        supernode_strategy: Default::default(),
+        namespace: None,
     };
 
     let response = service.traverse(traverse_request).await.unwrap();
@@ -479,6 +513,12 @@ async fn test_traverse_backward() {
         max_depth: 1,
         max_nodes: 10,
         direction: TraverseDirection::Backward,
+        created_by: Vec::new(),
+        max_estimated_cost: None,
+        follow_inverses: false,
+        min_confidence: None,
+        supernode_strategy: Default::default(),
+        namespace: None,
     };
 
     let response = service.traverse(traverse_request).await.unwrap();
@@ -531,6 +571,12 @@ async fn test_traverse_both_directions() {
         max_depth: 1,
         max_nodes: 10,
         direction: TraverseDirection::Both,
+        created_by: Vec::new(),
+        max_estimated_cost: None,
+        follow_inverses: false,
+        min_confidence: None,
+        supernode_strategy: Default::default(),
+        namespace: None,
     };
 
     let response = service.traverse(traverse_request).await.unwrap();
@@ -549,6 +595,12 @@ async fn test_traverse_nonexistent_start() {
         max_depth: 1,
         max_nodes: 10,
         direction: TraverseDirection::Forward,
+        created_by: Vec::new(),
+        max_estimated_cost: None,
+        follow_inverses: false,
+        min_confidence: None,
+        supernode_strategy: Default::default(),
+        namespace: None,
     };
 
     let result = service.traverse(traverse_request).await;
@@ -601,6 +653,55 @@ async fn test_stats_with_edges() {
     assert_eq!(stats.edge_count, 2);
 }
 
+#[tokio::test]
+async fn test_stats_breakdown_tracks_mutations() {
+    let service = SyntonDbService::new();
+
+    let fact = service
+        .add_node(AddNodeRequest::new("Fact".to_string(), NodeType::Fact))
+        .await
+        .unwrap()
+        .node;
+    let entity = service
+        .add_node(AddNodeRequest::new("Entity".to_string(), NodeType::Entity))
+        .await
+        .unwrap()
+        .node;
+    service
+        .add_edge(AddEdgeRequest {
+            source: fact.id,
+            target: entity.id,
+            relation: synton_core::Relation::IsA,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let stats = service.stats().await.unwrap();
+    assert_eq!(stats.nodes_by_type.get("fact"), Some(&1));
+    assert_eq!(stats.nodes_by_type.get("entity"), Some(&1));
+    assert_eq!(stats.edges_by_relation.get("is_a"), Some(&1));
+
+    // Retyping and deleting nodes update the counts without a rescan
+    service
+        .update_node(UpdateNodeRequest {
+            node_type: Some(NodeType::Concept),
+            ..UpdateNodeRequest::new(fact.id)
+        })
+        .await
+        .unwrap();
+    service
+        .delete_node(DeleteNodeRequest { id: entity.id })
+        .await
+        .unwrap();
+
+    let stats = service.stats().await.unwrap();
+    assert_eq!(stats.node_count, 1);
+    assert_eq!(stats.nodes_by_type.get("concept"), Some(&1));
+    assert!(!stats.nodes_by_type.contains_key("fact"));
+    assert!(!stats.nodes_by_type.contains_key("entity"));
+}
+
 // ========== Complex Scenarios ==========
 
 #[tokio::test]
@@ -661,6 +762,13 @@ async fn test_graph_workflow() {
         query: "learning".to_string(),
         limit: Some(10),
         include_metadata: false,
+        include_archived: false,
+        count_only: false,
+        sample: None,
+        rank: None,
+        min_confidence: None,
+        recency_weight: None,
+        namespace: None,
     };
     let query_result = service.query(query).await.unwrap();
     assert!(!query_result.nodes.is_empty());
@@ -671,6 +779,12 @@ async fn test_graph_workflow() {
         max_depth: 2,
         max_nodes: 10,
         direction: TraverseDirection::Forward,
+        created_by: Vec::new(),
+        max_estimated_cost: None,
+        follow_inverses: false,
+        min_confidence: None,
+        supernode_strategy: Default::default(),
+        namespace: None,
     };
     let traverse_result = service.traverse(traverse_request).await.unwrap();
     assert_eq!(traverse_result.nodes.len(), 2); // DL and NN
@@ -704,3 +818,92 @@ async fn test_concurrent_operations() {
     let stats = service.stats().await.unwrap();
     assert_eq!(stats.node_count, 20);
 }
+
+// ========== Fault Injection Tests ==========
+
+#[cfg(feature = "ml")]
+#[tokio::test]
+async fn test_search_degrades_to_text_when_embedding_fails() {
+    use synton_core::{FaultConfig, FaultInjector};
+    use synton_ml::{EmbeddingConfig, EmbeddingService};
+
+    let faults = FaultConfig {
+        error_rate: 1.0,
+        ..Default::default()
+    };
+    let embedding = EmbeddingService::from_config(EmbeddingConfig::ollama())
+        .await
+        .unwrap()
+        .with_fault_injection(Arc::new(FaultInjector::new(faults, "embedding")));
+    let service = SyntonDbService::with_embedding(Arc::new(embedding));
+
+    // Nodes are still stored, just without embeddings
+    let response = service
+        .add_node(AddNodeRequest::new("Graph databases store edges".to_string(), NodeType::Fact))
+        .await
+        .unwrap();
+    assert!(response.node.embedding.is_none());
+    service
+        .add_node(AddNodeRequest::new("Unrelated fact".to_string(), NodeType::Fact))
+        .await
+        .unwrap();
+
+    let results = service.hybrid_search("graph", 5).await.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, response.node.id);
+}
+
+#[tokio::test]
+async fn test_transaction_is_all_or_nothing_when_storage_fails() {
+    use synton_api::{NodeRef, TransactionOp, TransactionRequest};
+    use synton_core::{FaultConfig, FaultInjector, Relation};
+    use synton_storage::rocksdb::{RocksdbConfig, RocksdbStore};
+    use synton_storage::FaultyStore;
+
+    let dir = tempfile::tempdir().unwrap();
+    let store = RocksdbStore::open(RocksdbConfig {
+        path: dir.path().to_str().unwrap().to_string(),
+        ..Default::default()
+    })
+    .unwrap();
+    let faults = FaultConfig {
+        error_rate: 1.0,
+        ..Default::default()
+    };
+    let store = FaultyStore::new(
+        Arc::new(store),
+        Arc::new(FaultInjector::new(faults, "store")),
+    );
+    let service = SyntonDbService::with_store(Arc::new(store));
+
+    let request = TransactionRequest {
+        operations: vec![
+            TransactionOp::AddNode {
+                temp_id: Some("a".to_string()),
+                content: "Solar panels".to_string(),
+                node_type: NodeType::Entity,
+                attributes: None,
+            },
+            TransactionOp::AddNode {
+                temp_id: Some("b".to_string()),
+                content: "Renewable energy".to_string(),
+                node_type: NodeType::Concept,
+                attributes: None,
+            },
+            TransactionOp::AddEdge {
+                source: NodeRef::Temp("a".to_string()),
+                target: NodeRef::Temp("b".to_string()),
+                relation: Relation::IsA,
+                weight: 1.0,
+                provenance: Default::default(),
+            },
+        ],
+    };
+    assert!(service.apply_transaction(request).await.is_err());
+
+    // Nothing from the failed batch is visible in memory
+    let stats = service.stats().await.unwrap();
+    assert_eq!(stats.node_count, 0);
+    assert_eq!(stats.edge_count, 0);
+    assert!(service.all_nodes().await.is_empty());
+}
@@ -113,6 +113,13 @@ async fn test_complete_knowledge_graph_workflow() {
             query: "learning".to_string(),
             limit: Some(10),
             include_metadata: false,
+            include_archived: false,
+            count_only: false,
+            sample: None,
+            rank: None,
+            min_confidence: None,
+            recency_weight: None,
+            namespace: None,
         })
         .await
         .unwrap();
@@ -127,6 +134,12 @@ async fn test_complete_knowledge_graph_workflow() {
             max_depth: 2,
             max_nodes: 10,
             direction: TraverseDirection::Forward,
+            created_by: Vec::new(),
+            max_estimated_cost: None,
+            follow_inverses: false,
+            min_confidence: None,
+            supernode_strategy: Default::default(),
+            namespace: None,
         })
         .await
         .unwrap();
@@ -156,6 +169,13 @@ async fn test_query_with_no_results() {
             query: "quantum physics".to_string(),
             limit: Some(10),
             include_metadata: false,
+            include_archived: false,
+            count_only: false,
+            sample: None,
+            rank: None,
+            min_confidence: None,
+            recency_weight: None,
+            namespace: None,
         })
         .await
         .unwrap();
@@ -173,6 +193,12 @@ async fn test_traverse_nonexistent_start() {
             max_depth: 1,
             max_nodes: 10,
             direction: TraverseDirection::Forward,
+            created_by: Vec::new(),
+            max_estimated_cost: None,
+            follow_inverses: false,
+            min_confidence: None,
+            supernode_strategy: Default::default(),
+            namespace: None,
         })
         .await;
 
@@ -224,6 +250,12 @@ async fn test_bulk_operations() {
             max_depth: 2,
             max_nodes: 10,
             direction: TraverseDirection::Forward,
+            created_by: Vec::new(),
+            max_estimated_cost: None,
+            follow_inverses: false,
+            min_confidence: None,
+            supernode_strategy: Default::default(),
+            namespace: None,
         })
         .await
         .unwrap();
@@ -252,6 +284,13 @@ async fn test_case_insensitive_query() {
                 query: query.to_string(),
                 limit: Some(10),
                 include_metadata: false,
+                include_archived: false,
+                count_only: false,
+                sample: None,
+                rank: None,
+                min_confidence: None,
+                recency_weight: None,
+                namespace: None,
             })
             .await
             .unwrap();
@@ -279,6 +318,13 @@ async fn test_delete_and_query() {
             query: "temporary".to_string(),
             limit: Some(10),
             include_metadata: false,
+            include_archived: false,
+            count_only: false,
+            sample: None,
+            rank: None,
+            min_confidence: None,
+            recency_weight: None,
+            namespace: None,
         })
         .await
         .unwrap();
@@ -296,6 +342,13 @@ async fn test_delete_and_query() {
             query: "temporary".to_string(),
             limit: Some(10),
             include_metadata: false,
+            include_archived: false,
+            count_only: false,
+            sample: None,
+            rank: None,
+            min_confidence: None,
+            recency_weight: None,
+            namespace: None,
         })
         .await
         .unwrap();
@@ -362,6 +415,12 @@ async fn test_circular_relationships() {
             max_depth: 3,
             max_nodes: 10,
             direction: TraverseDirection::Forward,
+            created_by: Vec::new(),
+            max_estimated_cost: None,
+            follow_inverses: false,
+            min_confidence: None,
+            supernode_strategy: Default::default(),
+            namespace: None,
         })
         .await
         .unwrap();
@@ -403,6 +462,13 @@ async fn test_query_limit() {
             query: "Test".to_string(),
             limit: Some(5),
             include_metadata: false,
+            include_archived: false,
+            count_only: false,
+            sample: None,
+            rank: None,
+            min_confidence: None,
+            recency_weight: None,
+            namespace: None,
         })
         .await
         .unwrap();
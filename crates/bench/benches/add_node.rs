@@ -0,0 +1,63 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! `add_node` throughput on the in-memory service.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use synton_api::{AddNodeRequest, SyntonDbService};
+use synton_bench::{Dataset, DatasetConfig};
+use tokio::runtime::Runtime;
+
+const BATCH: usize = 1_000;
+
+fn requests(dataset: &Dataset) -> Vec<AddNodeRequest> {
+    dataset
+        .nodes
+        .iter()
+        .map(|node| {
+            let request = AddNodeRequest::new(node.content.to_string(), node.node_type);
+            match &node.embedding {
+                Some(embedding) => request.with_embedding(embedding.clone()),
+                None => request,
+            }
+        })
+        .collect()
+}
+
+fn bench_add_node(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let datasets = [("text", 0), ("with_embedding", 384)].map(|(name, dimension)| {
+        let config = DatasetConfig {
+            nodes: BATCH,
+            dimension,
+            ..Default::default()
+        };
+        (name, Dataset::generate(&config))
+    });
+
+    let mut group = c.benchmark_group("add_node");
+    group.throughput(Throughput::Elements(BATCH as u64));
+    group.sample_size(20);
+
+    for (name, dataset) in &datasets {
+        group.bench_function(*name, |b| {
+            b.iter_batched(
+                || (SyntonDbService::new(), requests(dataset)),
+                |(service, requests)| {
+                    runtime.block_on(async {
+                        for request in requests {
+                            service.add_node(request).await.unwrap();
+                        }
+                    })
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_add_node);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! PaQL parse latency across query shapes.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use synton_paql::Parser;
+
+const QUERIES: &[(&str, &str)] = &[
+    ("text", "battery storage"),
+    ("limit", "find grid concepts limit 10"),
+    ("boolean", "solar AND wind OR battery NOT coal"),
+    ("sort", "find turbine failures sort by relevance"),
+    ("rank", "find AI concepts rank by 0.6*similarity + 0.3*recency + 0.1*centrality limit 5"),
+    (
+        "traversal",
+        "traverse from 6f1c2b7e-3d4a-4c5b-9e8f-1a2b3c4d5e6f forward depth 3",
+    ),
+];
+
+fn bench_parse(c: &mut Criterion) {
+    let parser = Parser::new();
+    let mut group = c.benchmark_group("paql_parse");
+
+    for (name, query) in QUERIES {
+        group.bench_function(*name, |b| b.iter(|| parser.parse(black_box(query))));
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
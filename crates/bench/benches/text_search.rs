@@ -0,0 +1,56 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Text search through `/query` at 10k, 100k and 1M nodes.
+//!
+//! Loading the 1M-node service takes a while; set `SYNTON_BENCH_SIZES` to
+//! run a subset.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use synton_api::{AddNodeRequest, QueryRequest, SyntonDbService};
+use synton_bench::{bench_sizes, Dataset, DatasetConfig};
+use tokio::runtime::Runtime;
+
+fn query_request(query: &str) -> QueryRequest {
+    serde_json::from_value(serde_json::json!({ "query": query, "limit": 10 })).unwrap()
+}
+
+fn bench_text_search(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let queries: Vec<QueryRequest> = Dataset::queries(64, 7)
+        .iter()
+        .map(|query| query_request(query))
+        .collect();
+    let mut group = c.benchmark_group("text_search");
+    group.sample_size(10);
+
+    for size in bench_sizes(&[10_000, 100_000, 1_000_000]) {
+        let dataset = Dataset::generate(&DatasetConfig {
+            nodes: size,
+            edges_per_node: 0,
+            ..Default::default()
+        });
+        let service = SyntonDbService::new();
+        runtime.block_on(async {
+            for node in dataset.nodes {
+                let request = AddNodeRequest::new(node.content.into_owned(), node.node_type);
+                service.add_node(request).await.unwrap();
+            }
+        });
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &service, |b, service| {
+            let mut next = 0;
+            b.iter(|| {
+                let request = queries[next % queries.len()].clone();
+                next += 1;
+                runtime.block_on(service.query(black_box(request))).unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_text_search);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Depth-3 breadth-first traversal on synthetic graphs.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use synton_bench::{bench_sizes, Dataset, DatasetConfig, Rng};
+use synton_graph::{Graph, MemoryGraph, TraversalConfig};
+use tokio::runtime::Runtime;
+
+fn bench_bfs(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("bfs_depth3");
+
+    for size in bench_sizes(&[10_000, 100_000]) {
+        let dataset = Dataset::generate(&DatasetConfig {
+            nodes: size,
+            edges_per_node: 4,
+            ..Default::default()
+        });
+        let mut rng = Rng::new(7);
+        let starts: Vec<_> = (0..64)
+            .map(|_| dataset.nodes[rng.below(size)].id)
+            .collect();
+        let graph = MemoryGraph::from_parts(dataset.nodes, dataset.edges).unwrap();
+        let config = TraversalConfig::with_depth(3).with_max_nodes(10_000);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &graph, |b, graph| {
+            let mut next = 0;
+            b.iter(|| {
+                let start = starts[next % starts.len()];
+                next += 1;
+                runtime
+                    .block_on(graph.bfs(black_box(start), config.clone()))
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_bfs);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Top-k search on the in-memory vector index.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use synton_bench::{bench_sizes, vectors};
+use synton_vector::{MemoryVectorIndex, VectorIndex};
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+const DIMENSION: usize = 384;
+
+fn bench_vector_search(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let queries = vectors(64, DIMENSION, 7);
+    let mut group = c.benchmark_group("vector_search");
+    group.sample_size(20);
+
+    for size in bench_sizes(&[10_000, 100_000]) {
+        let index = MemoryVectorIndex::new(DIMENSION);
+        let batch = vectors(size, DIMENSION, 42)
+            .into_iter()
+            .map(|vector| (Uuid::new_v4(), vector))
+            .collect();
+        runtime.block_on(index.insert_batch(batch)).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("top10", size), &index, |b, index| {
+            let mut next = 0;
+            b.iter(|| {
+                let query = &queries[next % queries.len()];
+                next += 1;
+                runtime.block_on(index.search(black_box(query), 10)).unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_vector_search);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Benchmark support for SYNTON-DB.
+//!
+//! Deterministic synthetic datasets shared by the criterion suite in
+//! `benches/` and `synton-cli bench`, and the report format the CLI saves
+//! and compares against a baseline to catch regressions.
+
+#![warn(missing_docs)]
+#![warn(clippy::all)]
+
+mod report;
+mod synthetic;
+
+pub use report::{BenchReport, OperationStats, Regression};
+pub use synthetic::{bench_sizes, vectors, Dataset, DatasetConfig, Rng};
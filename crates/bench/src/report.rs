@@ -0,0 +1,167 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Benchmark reports and regression checks.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::synthetic::DatasetConfig;
+
+/// Latency summary for one benchmarked operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperationStats {
+    /// Operation name (e.g. `add_node`).
+    pub name: String,
+
+    /// Number of timed calls.
+    pub count: usize,
+
+    /// Mean latency in microseconds.
+    pub mean_us: f64,
+
+    /// Median latency in microseconds.
+    pub p50_us: f64,
+
+    /// 95th percentile latency in microseconds.
+    pub p95_us: f64,
+
+    /// 99th percentile latency in microseconds.
+    pub p99_us: f64,
+
+    /// Calls per second, run back to back.
+    pub throughput: f64,
+}
+
+impl OperationStats {
+    /// Summarise the latencies of `samples`.
+    pub fn from_samples(name: impl Into<String>, samples: &[Duration]) -> Self {
+        let mut micros: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1e6).collect();
+        micros.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let total: f64 = micros.iter().sum();
+
+        Self {
+            name: name.into(),
+            count: micros.len(),
+            mean_us: if micros.is_empty() { 0.0 } else { total / micros.len() as f64 },
+            p50_us: percentile(&micros, 0.50),
+            p95_us: percentile(&micros, 0.95),
+            p99_us: percentile(&micros, 0.99),
+            throughput: if total > 0.0 { micros.len() as f64 * 1e6 / total } else { 0.0 },
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (fraction * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// An operation whose median latency grew past the allowed margin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Regression {
+    /// Operation name.
+    pub name: String,
+
+    /// Baseline median latency in microseconds.
+    pub baseline_p50_us: f64,
+
+    /// Current median latency in microseconds.
+    pub p50_us: f64,
+
+    /// Relative slowdown (0.25 = 25% slower).
+    pub change: f64,
+}
+
+/// Results of one benchmark run, as saved for later comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    /// Dataset the run loaded.
+    pub dataset: DatasetConfig,
+
+    /// Per-operation latencies, in the order they ran.
+    pub operations: Vec<OperationStats>,
+}
+
+impl BenchReport {
+    /// Operations whose median is more than `max_regression` (a fraction)
+    /// slower than in `baseline`. Operations missing from the baseline are
+    /// not compared.
+    pub fn regressions(&self, baseline: &BenchReport, max_regression: f64) -> Vec<Regression> {
+        self.operations
+            .iter()
+            .filter_map(|op| {
+                let base = baseline.operations.iter().find(|b| b.name == op.name)?;
+                if base.p50_us <= 0.0 {
+                    return None;
+                }
+                let change = op.p50_us / base.p50_us - 1.0;
+                (change > max_regression).then(|| Regression {
+                    name: op.name.clone(),
+                    baseline_p50_us: base.p50_us,
+                    p50_us: op.p50_us,
+                    change,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(name: &str, p50_us: f64) -> OperationStats {
+        OperationStats {
+            name: name.to_string(),
+            count: 1,
+            mean_us: p50_us,
+            p50_us,
+            p95_us: p50_us,
+            p99_us: p50_us,
+            throughput: 1e6 / p50_us,
+        }
+    }
+
+    #[test]
+    fn test_operation_stats() {
+        let samples: Vec<Duration> = (1..=100).map(Duration::from_micros).collect();
+        let stats = OperationStats::from_samples("query", &samples);
+
+        assert_eq!(stats.count, 100);
+        assert!((stats.mean_us - 50.5).abs() < 1e-6);
+        assert!((stats.p50_us - 50.0).abs() < 1e-6);
+        assert!((stats.p95_us - 95.0).abs() < 1e-6);
+        assert!((stats.p99_us - 99.0).abs() < 1e-6);
+        assert!((stats.throughput - 1e6 / 50.5).abs() < 1e-6);
+
+        let empty = OperationStats::from_samples("none", &[]);
+        assert_eq!((empty.count, empty.p50_us, empty.throughput), (0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_regressions_against_baseline() {
+        let dataset = DatasetConfig::default();
+        let baseline = BenchReport {
+            dataset,
+            operations: vec![op("add_node", 100.0), op("query", 200.0)],
+        };
+        let current = BenchReport {
+            dataset,
+            operations: vec![op("add_node", 105.0), op("query", 260.0), op("traverse", 50.0)],
+        };
+
+        let regressions = current.regressions(&baseline, 0.10);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].name, "query");
+        assert!((regressions[0].change - 0.30).abs() < 1e-9);
+
+        assert!(current.regressions(&baseline, 0.50).is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Deterministic synthetic datasets.
+
+use serde::{Deserialize, Serialize};
+use synton_core::{Edge, Node, NodeType, Relation};
+
+/// Words node content is drawn from. A single word matches roughly
+/// `words_per_node / 64` of the nodes in a text search.
+const VOCABULARY: &[&str] = &[
+    "battery", "grid", "solar", "wind", "storage", "turbine", "inverter", "voltage",
+    "market", "price", "demand", "supply", "contract", "tariff", "subsidy", "forecast",
+    "carbon", "emission", "policy", "regulation", "permit", "audit", "compliance", "report",
+    "sensor", "network", "latency", "protocol", "firmware", "gateway", "telemetry", "alert",
+    "protein", "enzyme", "receptor", "pathway", "genome", "cell", "tissue", "trial",
+    "river", "forest", "glacier", "drought", "harvest", "soil", "climate", "rainfall",
+    "engine", "bearing", "gearbox", "coolant", "vibration", "failure", "repair", "warranty",
+    "customer", "invoice", "shipment", "supplier", "factory", "quality", "defect", "recall",
+];
+
+const RELATIONS: &[Relation] = &[
+    Relation::IsPartOf,
+    Relation::Causes,
+    Relation::SimilarTo,
+    Relation::HappenedAfter,
+];
+
+const NODE_TYPES: &[NodeType] = &[
+    NodeType::Entity,
+    NodeType::Concept,
+    NodeType::Fact,
+    NodeType::RawChunk,
+];
+
+/// SplitMix64 generator, so a seed always yields the same dataset.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Create a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..n`. `n` must be non-zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A value in `-1.0..1.0`.
+    pub fn signed_unit(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+}
+
+/// Shape of a synthetic dataset.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DatasetConfig {
+    /// Number of nodes.
+    pub nodes: usize,
+
+    /// Outgoing edges per node, to uniformly random targets.
+    pub edges_per_node: usize,
+
+    /// Vocabulary words in each node's content.
+    pub words_per_node: usize,
+
+    /// Embedding dimension; 0 generates nodes without embeddings.
+    pub dimension: usize,
+
+    /// Generator seed.
+    pub seed: u64,
+}
+
+impl Default for DatasetConfig {
+    fn default() -> Self {
+        Self {
+            nodes: 10_000,
+            edges_per_node: 3,
+            words_per_node: 8,
+            dimension: 0,
+            seed: 42,
+        }
+    }
+}
+
+/// Nodes and edges of a synthetic graph.
+#[derive(Debug, Clone)]
+pub struct Dataset {
+    /// Generated nodes.
+    pub nodes: Vec<Node>,
+
+    /// Generated edges between `nodes`, without self-loops.
+    pub edges: Vec<Edge>,
+}
+
+impl Dataset {
+    /// Generate a dataset. The same config always yields the same content,
+    /// edges and embeddings; node IDs are fresh on every call.
+    pub fn generate(config: &DatasetConfig) -> Self {
+        let mut rng = Rng::new(config.seed);
+
+        let nodes: Vec<Node> = (0..config.nodes)
+            .map(|i| {
+                let words: Vec<&str> = (0..config.words_per_node)
+                    .map(|_| VOCABULARY[rng.below(VOCABULARY.len())])
+                    .collect();
+                let content = format!("record {}: {}", i, words.join(" "));
+                let node = Node::new(content, NODE_TYPES[i % NODE_TYPES.len()]);
+                if config.dimension > 0 {
+                    node.with_embedding(unit_vector(&mut rng, config.dimension))
+                } else {
+                    node
+                }
+            })
+            .collect();
+
+        let mut edges = Vec::with_capacity(config.nodes * config.edges_per_node);
+        if config.nodes > 1 {
+            for (i, node) in nodes.iter().enumerate() {
+                for e in 0..config.edges_per_node {
+                    // Skip over the source so no edge is a self-loop
+                    let mut target = rng.below(config.nodes - 1);
+                    if target >= i {
+                        target += 1;
+                    }
+                    let relation = RELATIONS[e % RELATIONS.len()].clone();
+                    edges.push(Edge::new(node.id, nodes[target].id, relation));
+                }
+            }
+        }
+
+        Self { nodes, edges }
+    }
+
+    /// Single-word text queries drawn from the content vocabulary.
+    pub fn queries(count: usize, seed: u64) -> Vec<String> {
+        let mut rng = Rng::new(seed);
+        (0..count)
+            .map(|_| VOCABULARY[rng.below(VOCABULARY.len())].to_string())
+            .collect()
+    }
+}
+
+/// `count` random unit vectors of the given dimension.
+pub fn vectors(count: usize, dimension: usize, seed: u64) -> Vec<Vec<f32>> {
+    let mut rng = Rng::new(seed);
+    (0..count).map(|_| unit_vector(&mut rng, dimension)).collect()
+}
+
+fn unit_vector(rng: &mut Rng, dimension: usize) -> Vec<f32> {
+    let mut vector: Vec<f32> = (0..dimension).map(|_| rng.signed_unit()).collect();
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+    vector
+}
+
+/// Dataset sizes for size-parameterised benchmarks.
+///
+/// `SYNTON_BENCH_SIZES` (a comma-separated list such as `10000,100000`)
+/// overrides `default`, so the slow large sizes can be skipped locally.
+pub fn bench_sizes(default: &[usize]) -> Vec<usize> {
+    std::env::var("SYNTON_BENCH_SIZES")
+        .ok()
+        .map(|sizes| {
+            sizes
+                .split(',')
+                .filter_map(|size| size.trim().replace('_', "").parse().ok())
+                .collect::<Vec<usize>>()
+        })
+        .filter(|sizes| !sizes.is_empty())
+        .unwrap_or_else(|| default.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dataset_is_deterministic() {
+        let config = DatasetConfig {
+            nodes: 200,
+            dimension: 16,
+            ..Default::default()
+        };
+        let first = Dataset::generate(&config);
+        let second = Dataset::generate(&config);
+
+        assert_eq!(first.nodes.len(), 200);
+        assert_eq!(first.edges.len(), 600);
+        for (a, b) in first.nodes.iter().zip(&second.nodes) {
+            assert_eq!(a.content, b.content);
+            assert_eq!(a.embedding, b.embedding);
+        }
+
+        let index: std::collections::HashMap<_, _> =
+            first.nodes.iter().enumerate().map(|(i, n)| (n.id, i)).collect();
+        for edge in &first.edges {
+            assert_ne!(edge.source, edge.target);
+            assert!(index.contains_key(&edge.target));
+        }
+
+        let norm: f32 = first.nodes[0].embedding.as_ref().unwrap().iter().map(|x| x * x).sum();
+        assert!((norm - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_queries_match_content() {
+        let dataset = Dataset::generate(&DatasetConfig {
+            nodes: 500,
+            ..Default::default()
+        });
+        for query in Dataset::queries(10, 7) {
+            assert!(dataset.nodes.iter().any(|n| n.content.contains(query.as_str())));
+        }
+        assert_eq!(Dataset::queries(5, 7), Dataset::queries(5, 7));
+    }
+}
@@ -7,7 +7,8 @@
 //! Loads configuration from TOML files with environment variable override support.
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// ML / Embedding configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +46,42 @@ pub struct MlConfig {
 
     /// Cache size.
     pub cache_size: usize,
+
+    /// Keep embeddings in the persistent store across restarts (requires
+    /// persistence).
+    pub persistent_cache_enabled: bool,
+
+    /// Most embeddings kept in the persistent cache.
+    pub persistent_cache_size: usize,
+
+    /// Search with other languages' models when the query language's model
+    /// finds too few results.
+    pub cross_lingual_fallback: bool,
+
+    /// Dedicated embedding models keyed by language code (e.g. "zh").
+    pub languages: HashMap<String, MlLanguageConfig>,
+}
+
+/// Embedding model for one language.
+///
+/// Unset fields inherit the value from the `[ml]` section.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MlLanguageConfig {
+    /// Backend type: local, openai, ollama.
+    pub backend: Option<String>,
+
+    /// Local model name or path.
+    pub local_model: Option<String>,
+
+    /// API endpoint for OpenAI/Ollama.
+    pub api_endpoint: Option<String>,
+
+    /// API key (for OpenAI).
+    pub api_key: Option<String>,
+
+    /// Model name for API backends.
+    pub api_model: Option<String>,
 }
 
 impl Default for MlConfig {
@@ -61,6 +98,93 @@ impl Default for MlConfig {
             timeout_secs: 30,
             cache_enabled: true,
             cache_size: 10000,
+            persistent_cache_enabled: false,
+            persistent_cache_size: 100_000,
+            cross_lingual_fallback: true,
+            languages: HashMap::new(),
+        }
+    }
+}
+
+/// Natural-language query translation configuration.
+///
+/// Queries the PaQL parser rejects are translated into PaQL by a chat
+/// completion model. Unset endpoint and key inherit the `[ml]` values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueryTranslationConfig {
+    /// Translate queries the PaQL parser rejects.
+    pub enabled: bool,
+
+    /// Chat completions API endpoint (OpenAI or Ollama under `/v1`).
+    pub api_endpoint: Option<String>,
+
+    /// API key (for OpenAI).
+    pub api_key: Option<String>,
+
+    /// Completion model name.
+    pub model: String,
+
+    /// Request timeout in seconds.
+    pub timeout_secs: u64,
+}
+
+impl Default for QueryTranslationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_endpoint: None,
+            api_key: None,
+            model: "gpt-4o-mini".to_string(),
+            timeout_secs: 30,
+        }
+    }
+}
+
+/// Document summarization configuration.
+///
+/// Documents ingested with `summarize` get a summary node written by a chat
+/// completion model. Unset endpoint and key inherit the `[ml]` values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SummarizationConfig {
+    /// Summarize documents ingested with `summarize`.
+    pub enabled: bool,
+
+    /// Chat completions API endpoint (OpenAI or Ollama under `/v1`).
+    pub api_endpoint: Option<String>,
+
+    /// API key (for OpenAI).
+    pub api_key: Option<String>,
+
+    /// Completion model name.
+    pub model: String,
+
+    /// Request timeout in seconds.
+    pub timeout_secs: u64,
+
+    /// Shortest document summarized, in characters.
+    pub min_chars: usize,
+
+    /// Most characters of a document sent to the model.
+    pub max_input_chars: usize,
+
+    /// Decay rate lambda (per hour) of summary nodes, unless
+    /// `[memory.profiles.summaries]` sets their profile.
+    pub decay_lambda: f32,
+}
+
+impl Default for SummarizationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_endpoint: None,
+            api_key: None,
+            model: "gpt-4o-mini".to_string(),
+            timeout_secs: 60,
+            min_chars: 4000,
+            max_input_chars: 16_000,
+            decay_lambda: 0.0005,
         }
     }
 }
@@ -101,9 +225,15 @@ impl Default for ServerConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct StorageConfig {
+    /// Persistent store backend: rocksdb, sqlite.
+    pub backend: String,
+
     /// Path to RocksDB data directory.
     pub rocksdb_path: PathBuf,
 
+    /// Path to the SQLite database file (sqlite backend).
+    pub sqlite_path: PathBuf,
+
     /// Path to Lance data directory.
     pub lance_path: PathBuf,
 
@@ -115,20 +245,95 @@ pub struct StorageConfig {
 
     /// Enable write-ahead log.
     pub wal_enabled: bool,
+
+    /// Fsync the write-ahead log on every write (RocksDB). Requests can
+    /// still override durability per write.
+    pub sync_writes: bool,
+
+    /// Reject writes of edges whose endpoints are not stored.
+    pub referential_integrity: bool,
+
+    /// Compress nodes whose content is at least this many bytes with zstd
+    /// (0 disables compression).
+    pub compress_content_threshold: usize,
+
+    /// Format of new node IDs: v4 (random) or v7 (time-ordered).
+    pub id_format: synton_core::IdFormat,
+
+    /// Enable periodic vector index maintenance (compaction, retraining).
+    pub vector_maintenance_enabled: bool,
+
+    /// Interval for vector index maintenance (in seconds).
+    pub vector_maintenance_interval_secs: u64,
+
+    /// Enable warm-start snapshots of the in-memory vector index.
+    pub vector_snapshot_enabled: bool,
+
+    /// Path of the vector index snapshot file.
+    pub vector_snapshot_path: PathBuf,
+
+    /// Interval between vector index snapshots (in seconds).
+    pub vector_snapshot_interval_secs: u64,
+
+    /// At startup, load only the most accessed nodes (and their 1-hop
+    /// neighborhoods) instead of every stored node.
+    pub preload_enabled: bool,
+
+    /// Number of nodes to preload by access score.
+    pub preload_top_n: usize,
+
+    /// Maximum concurrent storage reads while preloading.
+    pub preload_concurrency: usize,
 }
 
 impl Default for StorageConfig {
     fn default() -> Self {
         Self {
+            backend: "rocksdb".to_string(),
             rocksdb_path: PathBuf::from("./data/rocksdb"),
+            sqlite_path: PathBuf::from("./data/synton.db"),
             lance_path: PathBuf::from("./data/lance"),
             max_open_files: 5000,
             cache_size_mb: 256,
             wal_enabled: true,
+            sync_writes: false,
+            referential_integrity: false,
+            compress_content_threshold: 0,
+            id_format: synton_core::IdFormat::V4,
+            vector_maintenance_enabled: true,
+            vector_maintenance_interval_secs: 3600, // 1 hour
+            vector_snapshot_enabled: true,
+            vector_snapshot_path: PathBuf::from("./data/vector_index.snapshot"),
+            vector_snapshot_interval_secs: 300, // 5 minutes
+            preload_enabled: false,
+            preload_top_n: 10_000,
+            preload_concurrency: 16,
         }
     }
 }
 
+impl StorageConfig {
+    /// Storage backends understood by `backend`.
+    pub const BACKENDS: &'static [&'static str] = &["rocksdb", "sqlite"];
+
+    /// Path used by the selected backend. Empty disables persistence.
+    pub fn data_path(&self) -> &Path {
+        if self.backend == "sqlite" {
+            &self.sqlite_path
+        } else {
+            &self.rocksdb_path
+        }
+    }
+
+    /// Node content compression for the store, if enabled.
+    pub fn content_compression(&self) -> Option<synton_storage::ContentCompression> {
+        (self.compress_content_threshold > 0).then(|| synton_storage::ContentCompression {
+            threshold: self.compress_content_threshold,
+            ..Default::default()
+        })
+    }
+}
+
 /// Memory management configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -145,11 +350,15 @@ pub struct MemoryConfig {
     /// Access score boost per access.
     pub access_boost: f32,
 
-    /// Enable periodic decay calculation.
+    /// Enable periodic decay calculation, pruning decayed nodes according
+    /// to the tiering policy.
     pub periodic_decay_enabled: bool,
 
     /// Interval for decay calculation (in seconds).
     pub decay_interval_secs: u64,
+
+    /// Decay overrides by namespace and node type.
+    pub profiles: synton_memory::DecayProfiles,
 }
 
 impl Default for MemoryConfig {
@@ -161,34 +370,658 @@ impl Default for MemoryConfig {
             access_boost: 0.5,
             periodic_decay_enabled: false,
             decay_interval_secs: 3600, // 1 hour
+            profiles: synton_memory::DecayProfiles::default(),
         }
     }
 }
 
-/// Logging configuration.
+/// Logging configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Log level: trace, debug, info, warn, error.
+    pub level: String,
+
+    /// Enable JSON formatted logs.
+    pub json_format: bool,
+
+    /// Enable tracing output.
+    pub tracing_enabled: bool,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            json_format: false,
+            tracing_enabled: true,
+        }
+    }
+}
+
+/// In-memory graph configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphConfig {
+    /// Run stats and traversals against copy-on-write snapshots instead of
+    /// holding the graph lock for their whole duration.
+    pub snapshot_reads: bool,
+
+    /// Edges per node and direction that traversals expand; a node with
+    /// more is a supernode whose extra edges are bundled (0 = no limit).
+    pub max_degree: usize,
+}
+
+impl Default for GraphConfig {
+    fn default() -> Self {
+        Self {
+            snapshot_reads: true,
+            max_degree: 0,
+        }
+    }
+}
+
+impl GraphConfig {
+    /// Maximum degree for the service, if limited.
+    pub fn max_degree(&self) -> Option<usize> {
+        (self.max_degree > 0).then_some(self.max_degree)
+    }
+}
+
+/// Query result cache configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueryCacheConfig {
+    /// Cache results of repeated queries.
+    pub enabled: bool,
+
+    /// Maximum number of cached results.
+    pub capacity: usize,
+
+    /// How long a cached result may be served (in seconds).
+    pub ttl_secs: u64,
+
+    /// Consistency of queries that do not request one: "eventual" serves
+    /// cached results, "strong" always executes queries.
+    pub default_consistency: synton_api::Consistency,
+}
+
+impl Default for QueryCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capacity: 1024,
+            ttl_secs: 30,
+            default_consistency: synton_api::Consistency::Eventual,
+        }
+    }
+}
+
+impl QueryCacheConfig {
+    /// Cache settings for the service, if the cache is enabled.
+    pub fn settings(&self) -> Option<synton_api::QueryCacheConfig> {
+        self.enabled.then(|| synton_api::QueryCacheConfig {
+            capacity: self.capacity,
+            ttl: std::time::Duration::from_secs(self.ttl_secs),
+            default_consistency: self.default_consistency,
+        })
+    }
+}
+
+/// Absorb deduplication window configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbsorbDedupConfig {
+    /// Coalesce duplicate nodes added within the window.
+    pub enabled: bool,
+
+    /// How long a created node absorbs duplicates (in seconds).
+    pub window_secs: u64,
+
+    /// Minimum word overlap (0.0-1.0) for near-identical content.
+    pub similarity: f32,
+}
+
+impl Default for AbsorbDedupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_secs: 300,
+            similarity: 0.9,
+        }
+    }
+}
+
+impl AbsorbDedupConfig {
+    /// Deduplication settings for the service, if the window is enabled.
+    pub fn settings(&self) -> Option<synton_api::AbsorbDedupConfig> {
+        self.enabled.then(|| synton_api::AbsorbDedupConfig {
+            window: std::time::Duration::from_secs(self.window_secs),
+            similarity: self.similarity,
+        })
+    }
+}
+
+/// Secret scrubbing configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScrubConfig {
+    /// Check absorbed and ingested content for secrets.
+    pub enabled: bool,
+
+    /// What to do with content containing a possible secret: "reject",
+    /// "redact" or "tag".
+    pub action: synton_api::ScrubAction,
+
+    /// Shannon entropy (bits per character) from which a token counts as a
+    /// secret; 0 disables entropy detection.
+    pub entropy_threshold: f64,
+
+    /// Shortest token checked by entropy.
+    pub min_token_length: usize,
+
+    /// Secret patterns checked in addition to the built-in ones.
+    pub rules: Vec<ScrubRuleConfig>,
+
+    /// Regular expressions of text never treated as a secret.
+    pub allowlist: Vec<String>,
+}
+
+/// A configured secret pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrubRuleConfig {
+    /// Name reported for findings.
+    pub name: String,
+
+    /// Regular expression; with a capture group, only the group is the secret.
+    pub pattern: String,
+}
+
+impl Default for ScrubConfig {
+    fn default() -> Self {
+        let defaults = synton_api::ScrubConfig::default();
+        Self {
+            enabled: false,
+            action: defaults.action,
+            entropy_threshold: defaults.entropy_threshold,
+            min_token_length: defaults.min_token_length,
+            rules: Vec::new(),
+            allowlist: Vec::new(),
+        }
+    }
+}
+
+impl ScrubConfig {
+    /// Scrubbing settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::ScrubConfig> {
+        self.enabled.then(|| synton_api::ScrubConfig {
+            action: self.action,
+            rules: self
+                .rules
+                .iter()
+                .map(|rule| synton_api::SecretRule {
+                    name: rule.name.clone(),
+                    pattern: rule.pattern.clone(),
+                })
+                .collect(),
+            allowlist: self.allowlist.clone(),
+            entropy_threshold: self.entropy_threshold,
+            min_token_length: self.min_token_length,
+        })
+    }
+}
+
+/// Namespace hierarchy configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamespacesConfig {
+    /// Which related namespaces a namespace-restricted query or traversal
+    /// also matches: "descendants", "ancestors", "both" or "exact".
+    pub inheritance: synton_api::NamespaceInheritance,
+
+    /// Namespaces only the listed tenants may read.
+    pub access: Vec<NamespaceAccessConfig>,
+}
+
+/// Tenants allowed to read a namespace and the namespaces below it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamespaceAccessConfig {
+    /// Namespace, such as "org/hr".
+    pub namespace: String,
+
+    /// Tenants allowed to read, as mapped from API keys under
+    /// `[metering] tenants`; "*" allows everyone.
+    pub actors: Vec<String>,
+}
+
+impl NamespacesConfig {
+    /// Namespace settings for the service.
+    pub fn settings(&self) -> synton_api::NamespaceConfig {
+        synton_api::NamespaceConfig {
+            inheritance: self.inheritance,
+            rules: self
+                .access
+                .iter()
+                .map(|access| synton_api::NamespaceRule {
+                    namespace: access.namespace.clone(),
+                    actors: access.actors.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Node usage tracking configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageConfig {
+    /// Record node accesses in the access log.
+    pub enabled: bool,
+
+    /// Most recent accesses kept per node for usage reports.
+    pub recent_events: usize,
+}
+
+impl Default for UsageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            recent_events: 20,
+        }
+    }
+}
+
+impl UsageConfig {
+    /// Usage tracking settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::UsageConfig> {
+        self.enabled.then(|| synton_api::UsageConfig {
+            recent_events: self.recent_events,
+        })
+    }
+}
+
+/// Per-API-key usage metering configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeteringConfig {
+    /// Count usage per API key (`x-api-key` header) and day.
+    pub enabled: bool,
+
+    /// Tenant each API key is metered to; unmapped keys are metered on
+    /// their own.
+    pub tenants: HashMap<String, String>,
+}
+
+impl Default for MeteringConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            tenants: HashMap::new(),
+        }
+    }
+}
+
+impl MeteringConfig {
+    /// Metering settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::MeteringConfig> {
+        self.enabled.then(|| synton_api::MeteringConfig {
+            tenants: self.tenants.clone(),
+        })
+    }
+}
+
+/// Ontology seeding configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SeedConfig {
+    /// Seed the ontology at startup unless its namespace is seeded already.
+    pub enabled: bool,
+
+    /// JSON file of the ontology to seed instead of the bundled starter
+    /// ontology.
+    pub ontology_file: Option<String>,
+
+    /// Namespace to seed into (default `starter`).
+    pub namespace: Option<String>,
+}
+
+impl SeedConfig {
+    /// Seed request for the service, if enabled.
+    pub fn request(&self) -> Result<Option<synton_api::SeedRequest>, String> {
+        if !self.enabled {
+            return Ok(None);
+        }
+        let ontology = self
+            .ontology_file
+            .as_deref()
+            .map(synton_api::Ontology::from_file)
+            .transpose()?;
+        Ok(Some(synton_api::SeedRequest {
+            ontology,
+            namespace: self.namespace.clone(),
+        }))
+    }
+}
+
+/// Mutation audit trail configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// Record every mutation in the audit trail.
+    pub enabled: bool,
+
+    /// JSON Lines file every audit event is also appended to.
+    pub log_path: Option<PathBuf>,
+
+    /// Most recent audit events kept in memory.
+    pub recent_events: usize,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            log_path: None,
+            recent_events: 10_000,
+        }
+    }
+}
+
+impl AuditConfig {
+    /// Audit trail settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::AuditConfig> {
+        self.enabled.then(|| synton_api::AuditConfig {
+            log_path: self.log_path.clone(),
+            recent_events: self.recent_events,
+        })
+    }
+}
+
+/// Edge review configuration.
+///
+/// Edges created by tools or extraction pipelines with a confidence below
+/// `min_confidence` are held in a review queue instead of joining the graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewConfig {
+    /// Hold low-confidence edges for review.
+    pub enabled: bool,
+
+    /// Extraction confidence (0.0-1.0) below which edges are held.
+    pub min_confidence: f32,
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_confidence: 0.5,
+        }
+    }
+}
+
+impl ReviewConfig {
+    /// Edge review settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::EdgeReviewConfig> {
+        self.enabled.then(|| synton_api::EdgeReviewConfig {
+            min_confidence: self.min_confidence,
+        })
+    }
+}
+
+/// Background re-embedding configuration.
+///
+/// When the configured embedding model changes, embeddings produced by the
+/// previous model are regenerated in the background, in throttled batches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReembedConfig {
+    /// Regenerate stale embeddings after a model change.
+    pub enabled: bool,
+
+    /// Nodes re-embedded per batch.
+    pub batch_size: usize,
+
+    /// Pause between batches (in milliseconds).
+    pub interval_ms: u64,
+}
+
+impl Default for ReembedConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            batch_size: 32,
+            interval_ms: 1000,
+        }
+    }
+}
+
+impl ReembedConfig {
+    /// Re-embedding settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::ReembedConfig> {
+        self.enabled.then(|| synton_api::ReembedConfig {
+            batch_size: self.batch_size,
+            interval: std::time::Duration::from_millis(self.interval_ms),
+        })
+    }
+}
+
+/// Scheduled knowledge expiry digest configuration.
+///
+/// The digest lists the nodes predicted to decay to their minimum score
+/// within `days` and is delivered to webhooks subscribed to
+/// `expiry_digest` events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpiryDigestConfig {
+    /// Compile and deliver digests on a schedule.
+    pub enabled: bool,
+
+    /// Days ahead a digest looks.
+    pub days: u32,
+
+    /// Most nodes listed in a digest.
+    pub limit: usize,
+
+    /// Interval between digests (in seconds).
+    pub interval_secs: u64,
+}
+
+impl Default for ExpiryDigestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            days: 7,
+            limit: 100,
+            interval_secs: 86400, // 1 day
+        }
+    }
+}
+
+impl ExpiryDigestConfig {
+    /// Digest settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::ExpiryDigestConfig> {
+        self.enabled.then(|| synton_api::ExpiryDigestConfig {
+            days: self.days,
+            limit: self.limit,
+            interval: std::time::Duration::from_secs(self.interval_secs),
+        })
+    }
+}
+
+/// Similarity auto-linking configuration.
+///
+/// Periodically links embedded nodes whose similarity reaches `threshold`
+/// and that are not yet connected with `similar_to` edges, up to
+/// `max_links_per_node` such edges per node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SimilarityLinksConfig {
+    /// Run auto-linking passes on a schedule.
+    pub enabled: bool,
+
+    /// Lowest similarity of a linked pair (0.0 - 1.0).
+    pub threshold: f32,
+
+    /// Most similar_to edges per node, counting existing ones.
+    pub max_links_per_node: usize,
+
+    /// Interval between passes (in seconds).
+    pub interval_secs: u64,
+}
+
+impl Default for SimilarityLinksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: 0.9,
+            max_links_per_node: 5,
+            interval_secs: 3600, // 1 hour
+        }
+    }
+}
+
+impl SimilarityLinksConfig {
+    /// Auto-linking settings for the service, if enabled.
+    pub fn settings(&self) -> Option<synton_api::SimilarityLinkConfig> {
+        self.enabled.then(|| synton_api::SimilarityLinkConfig {
+            threshold: self.threshold,
+            max_links_per_node: self.max_links_per_node,
+            interval: std::time::Duration::from_secs(self.interval_secs),
+        })
+    }
+}
+
+/// Request concurrency limit configuration.
+///
+/// Limits apply per route class; `0` in-flight means unlimited.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LimitsConfig {
+    /// Maximum concurrent read requests (queries, traversals, lookups).
+    pub read_max_in_flight: usize,
+
+    /// Maximum queued read requests.
+    pub read_max_queued: usize,
+
+    /// Maximum concurrent write requests (node/edge mutations).
+    pub write_max_in_flight: usize,
+
+    /// Maximum queued write requests.
+    pub write_max_queued: usize,
+
+    /// Maximum concurrent ingest requests (documents, bulk loads).
+    pub ingest_max_in_flight: usize,
+
+    /// Maximum queued ingest requests.
+    pub ingest_max_queued: usize,
+
+    /// How long a queued request waits for a slot (in milliseconds).
+    pub queue_timeout_ms: u64,
+
+    /// `Retry-After` value sent with 429 responses (in seconds).
+    pub retry_after_secs: u64,
+
+    /// Maximum concurrent gRPC requests per connection.
+    pub grpc_concurrency_per_connection: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self::from(&synton_api::ConcurrencyLimits::default())
+    }
+}
+
+impl From<&synton_api::ConcurrencyLimits> for LimitsConfig {
+    fn from(limits: &synton_api::ConcurrencyLimits) -> Self {
+        Self {
+            read_max_in_flight: limits.read.max_in_flight,
+            read_max_queued: limits.read.max_queued,
+            write_max_in_flight: limits.write.max_in_flight,
+            write_max_queued: limits.write.max_queued,
+            ingest_max_in_flight: limits.ingest.max_in_flight,
+            ingest_max_queued: limits.ingest.max_queued,
+            queue_timeout_ms: limits.queue_timeout_ms,
+            retry_after_secs: limits.retry_after_secs,
+            grpc_concurrency_per_connection: limits.grpc_concurrency_per_connection,
+        }
+    }
+}
+
+impl LimitsConfig {
+    /// Convert to the API-layer concurrency limits.
+    pub fn to_limits(&self) -> synton_api::ConcurrencyLimits {
+        use synton_api::RouteLimits;
+
+        synton_api::ConcurrencyLimits {
+            read: RouteLimits::new(self.read_max_in_flight, self.read_max_queued),
+            write: RouteLimits::new(self.write_max_in_flight, self.write_max_queued),
+            ingest: RouteLimits::new(self.ingest_max_in_flight, self.ingest_max_queued),
+            queue_timeout_ms: self.queue_timeout_ms,
+            retry_after_secs: self.retry_after_secs,
+            grpc_concurrency_per_connection: self.grpc_concurrency_per_connection,
+        }
+    }
+}
+
+/// Mutation hook configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Webhooks receiving a JSON POST for each mutation event.
+    pub webhooks: Vec<synton_api::WebhookConfig>,
+}
+
+/// Fault injection for resilience testing.
+///
+/// Wraps the storage, vector and embedding backends so calls randomly slow
+/// down, time out or fail, following a seeded, reproducible sequence. Never
+/// enable this in production.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
-pub struct LoggingConfig {
-    /// Log level: trace, debug, info, warn, error.
-    pub level: String,
+pub struct ChaosConfig {
+    /// Enable fault injection.
+    pub enabled: bool,
 
-    /// Enable JSON formatted logs.
-    pub json_format: bool,
+    /// Inject faults into the persistent store.
+    pub store: bool,
 
-    /// Enable tracing output.
-    pub tracing_enabled: bool,
+    /// Inject faults into the vector index.
+    pub vector: bool,
+
+    /// Inject faults into the embedding backends.
+    pub embedding: bool,
+
+    /// Seed, fault rates and durations.
+    #[serde(flatten)]
+    pub faults: synton_core::FaultConfig,
 }
 
-impl Default for LoggingConfig {
+impl Default for ChaosConfig {
     fn default() -> Self {
         Self {
-            level: "info".to_string(),
-            json_format: false,
-            tracing_enabled: true,
+            enabled: false,
+            store: true,
+            vector: true,
+            embedding: true,
+            faults: synton_core::FaultConfig::default(),
         }
     }
 }
 
+impl ChaosConfig {
+    /// Fault injector for a backend, if faults are enabled for it.
+    pub fn injector(
+        &self,
+        target: bool,
+        label: &'static str,
+    ) -> Option<std::sync::Arc<synton_core::FaultInjector>> {
+        (self.enabled && target).then(|| {
+            std::sync::Arc::new(synton_core::FaultInjector::new(self.faults.clone(), label))
+        })
+    }
+}
+
 /// Graph-RAG configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -242,6 +1075,58 @@ pub struct Config {
     #[serde(rename = "logging")]
     pub logging: LoggingConfig,
 
+    /// In-memory graph settings.
+    #[serde(rename = "graph")]
+    pub graph: GraphConfig,
+
+    /// Query result cache settings.
+    #[serde(rename = "query_cache")]
+    pub query_cache: QueryCacheConfig,
+
+    /// Absorb deduplication settings.
+    #[serde(rename = "absorb_dedup")]
+    pub absorb_dedup: AbsorbDedupConfig,
+
+    /// Secret scrubbing settings.
+    #[serde(rename = "scrub")]
+    pub scrub: ScrubConfig,
+
+    /// Namespace hierarchy settings.
+    #[serde(rename = "namespaces")]
+    pub namespaces: NamespacesConfig,
+
+    /// Node usage tracking settings.
+    #[serde(rename = "usage")]
+    pub usage: UsageConfig,
+
+    /// Per-API-key usage metering settings.
+    #[serde(rename = "metering")]
+    pub metering: MeteringConfig,
+
+    /// Ontology seeding settings.
+    #[serde(rename = "seed")]
+    pub seed: SeedConfig,
+
+    /// Mutation audit trail settings.
+    #[serde(rename = "audit")]
+    pub audit: AuditConfig,
+
+    /// Edge review settings.
+    #[serde(rename = "review")]
+    pub review: ReviewConfig,
+
+    /// Background re-embedding settings.
+    #[serde(rename = "reembed")]
+    pub reembed: ReembedConfig,
+
+    /// Scheduled expiry digest settings.
+    #[serde(rename = "expiry_digest")]
+    pub expiry_digest: ExpiryDigestConfig,
+
+    /// Similarity auto-linking settings.
+    #[serde(rename = "similarity_links")]
+    pub similarity_links: SimilarityLinksConfig,
+
     /// Graph-RAG settings.
     #[serde(rename = "graphrag")]
     pub graphrag: GraphRagConfig,
@@ -249,6 +1134,34 @@ pub struct Config {
     /// ML / Embedding settings.
     #[serde(rename = "ml")]
     pub ml: MlConfig,
+
+    /// Natural-language query translation settings.
+    #[serde(rename = "query_translation")]
+    pub query_translation: QueryTranslationConfig,
+
+    /// Document summarization settings.
+    #[serde(rename = "summarization")]
+    pub summarization: SummarizationConfig,
+
+    /// Request concurrency limits.
+    #[serde(rename = "limits")]
+    pub limits: LimitsConfig,
+
+    /// Mutation hooks.
+    #[serde(rename = "hooks")]
+    pub hooks: HooksConfig,
+
+    /// Ingestion pipelines.
+    #[serde(rename = "pipelines")]
+    pub pipelines: Vec<synton_api::PipelineDefinition>,
+
+    /// Materialized traversal views.
+    #[serde(rename = "views")]
+    pub views: Vec<synton_api::ViewDefinition>,
+
+    /// Fault injection.
+    #[serde(rename = "chaos")]
+    pub chaos: ChaosConfig,
 }
 
 
@@ -275,7 +1188,10 @@ impl Config {
     ///
     /// - `SYNTON_SERVER_HOST` overrides server.host
     /// - `SYNTON_SERVER_GRPC_PORT` overrides server.grpc_port
+    /// - `SYNTON_STORAGE_BACKEND` overrides storage.backend
     /// - `SYNTON_STORAGE_ROCKSDB_PATH` overrides storage.rocksdb_path
+    /// - `SYNTON_STORAGE_SQLITE_PATH` overrides storage.sqlite_path
+    /// - `SYNTON_STORAGE_VECTOR_SNAPSHOT_PATH` overrides storage.vector_snapshot_path
     fn apply_env_overrides(&mut self) {
         // Server overrides
         if let Ok(host) = std::env::var("SYNTON_SERVER_HOST") {
@@ -293,12 +1209,21 @@ impl Config {
         }
 
         // Storage overrides
+        if let Ok(backend) = std::env::var("SYNTON_STORAGE_BACKEND") {
+            self.storage.backend = backend;
+        }
         if let Ok(path) = std::env::var("SYNTON_STORAGE_ROCKSDB_PATH") {
             self.storage.rocksdb_path = PathBuf::from(path);
         }
+        if let Ok(path) = std::env::var("SYNTON_STORAGE_SQLITE_PATH") {
+            self.storage.sqlite_path = PathBuf::from(path);
+        }
         if let Ok(path) = std::env::var("SYNTON_STORAGE_LANCE_PATH") {
             self.storage.lance_path = PathBuf::from(path);
         }
+        if let Ok(path) = std::env::var("SYNTON_STORAGE_VECTOR_SNAPSHOT_PATH") {
+            self.storage.vector_snapshot_path = PathBuf::from(path);
+        }
 
         // Logging overrides
         if let Ok(level) = std::env::var("SYNTON_LOG_LEVEL") {
@@ -344,6 +1269,167 @@ impl Config {
             });
         }
 
+        // Validate periodic pruning interval
+        if self.memory.periodic_decay_enabled && self.memory.decay_interval_secs == 0 {
+            return Err(ConfigError::InvalidDecayInterval);
+        }
+
+        // Validate decay profiles
+        if let Err(e) = self.memory.profiles.validate() {
+            return Err(ConfigError::InvalidDecayProfile {
+                reason: e.to_string(),
+            });
+        }
+        if !(0.0..=1.0).contains(&self.summarization.decay_lambda) {
+            return Err(ConfigError::InvalidDecayProfile {
+                reason: format!(
+                    "summarization decay_lambda must be between 0 and 1, got {}",
+                    self.summarization.decay_lambda
+                ),
+            });
+        }
+
+        // Validate storage backend
+        if !StorageConfig::BACKENDS.contains(&self.storage.backend.as_str()) {
+            return Err(ConfigError::UnknownStorageBackend {
+                backend: self.storage.backend.clone(),
+            });
+        }
+
+        // Syncing writes needs the write-ahead log
+        if self.storage.sync_writes && !self.storage.wal_enabled {
+            return Err(ConfigError::SyncWithoutWal);
+        }
+
+        // Validate vector maintenance interval
+        if self.storage.vector_maintenance_enabled && self.storage.vector_maintenance_interval_secs == 0 {
+            return Err(ConfigError::InvalidMaintenanceInterval);
+        }
+
+        // Validate vector snapshot interval
+        if self.storage.vector_snapshot_enabled && self.storage.vector_snapshot_interval_secs == 0 {
+            return Err(ConfigError::InvalidSnapshotInterval);
+        }
+
+        // Validate preload settings
+        if self.storage.preload_enabled
+            && (self.storage.preload_top_n == 0 || self.storage.preload_concurrency == 0)
+        {
+            return Err(ConfigError::InvalidPreload);
+        }
+
+        // Validate query cache settings
+        if self.query_cache.enabled && (self.query_cache.capacity == 0 || self.query_cache.ttl_secs == 0) {
+            return Err(ConfigError::InvalidQueryCache);
+        }
+
+        // Validate absorb deduplication settings
+        if self.absorb_dedup.enabled
+            && (self.absorb_dedup.window_secs == 0
+                || !(self.absorb_dedup.similarity > 0.0 && self.absorb_dedup.similarity <= 1.0))
+        {
+            return Err(ConfigError::InvalidAbsorbDedup);
+        }
+
+        // Validate secret scrubbing settings
+        if self.scrub.enabled
+            && (self.scrub.min_token_length == 0
+                || self.scrub.entropy_threshold < 0.0
+                || self.scrub.rules.iter().any(|rule| rule.name.trim().is_empty()))
+        {
+            return Err(ConfigError::InvalidScrub);
+        }
+
+        // Validate namespace access rules
+        if self
+            .namespaces
+            .access
+            .iter()
+            .any(|access| access.namespace.trim_matches('/').trim().is_empty())
+        {
+            return Err(ConfigError::InvalidNamespaces);
+        }
+        // Tenants are resolved from API keys by the metering settings
+        let names_tenants = self
+            .namespaces
+            .access
+            .iter()
+            .any(|access| access.actors.iter().any(|actor| actor != "*"));
+        if names_tenants && !self.metering.enabled {
+            return Err(ConfigError::InvalidNamespaces);
+        }
+
+        // Validate edge review settings
+        if self.review.enabled && !(0.0..=1.0).contains(&self.review.min_confidence) {
+            return Err(ConfigError::InvalidReview);
+        }
+
+        // Validate re-embedding settings
+        if self.reembed.enabled && (self.reembed.batch_size == 0 || self.reembed.interval_ms == 0) {
+            return Err(ConfigError::InvalidReembed);
+        }
+
+        // Validate expiry digest settings
+        let digest = &self.expiry_digest;
+        if digest.enabled
+            && (digest.days == 0 || digest.limit == 0 || digest.interval_secs == 0)
+        {
+            return Err(ConfigError::InvalidExpiryDigest);
+        }
+
+        // Validate similarity auto-linking settings
+        let links = &self.similarity_links;
+        if links.enabled
+            && (!(0.0..=1.0).contains(&links.threshold)
+                || links.max_links_per_node == 0
+                || links.interval_secs == 0)
+        {
+            return Err(ConfigError::InvalidSimilarityLinks);
+        }
+
+        // Validate request limits
+        if self.limits.retry_after_secs == 0 {
+            return Err(ConfigError::InvalidRetryAfter);
+        }
+
+        // Validate webhooks
+        for webhook in &self.hooks.webhooks {
+            if !webhook.url.starts_with("http://") && !webhook.url.starts_with("https://") {
+                return Err(ConfigError::InvalidWebhookUrl {
+                    url: webhook.url.clone(),
+                });
+            }
+        }
+
+        // Validate ingestion pipelines
+        let mut names = std::collections::HashSet::new();
+        for pipeline in &self.pipelines {
+            pipeline.validate().map_err(ConfigError::InvalidPipeline)?;
+            if !names.insert(pipeline.name.as_str()) {
+                return Err(ConfigError::InvalidPipeline(format!(
+                    "duplicate pipeline name {:?}",
+                    pipeline.name
+                )));
+            }
+        }
+
+        // Validate materialized views
+        let mut names = std::collections::HashSet::new();
+        for view in &self.views {
+            view.validate().map_err(ConfigError::InvalidView)?;
+            if !names.insert(view.name.as_str()) {
+                return Err(ConfigError::InvalidView(format!(
+                    "duplicate view name {:?}",
+                    view.name
+                )));
+            }
+        }
+
+        // Validate fault rates
+        if self.chaos.enabled && !self.chaos.faults.is_valid() {
+            return Err(ConfigError::InvalidFaultRates);
+        }
+
         // Validate Graph-RAG weights
         let total_weight = self.graphrag.vector_weight + self.graphrag.graph_weight;
         if (total_weight - 1.0).abs() > 0.01 {
@@ -372,9 +1458,89 @@ pub enum ConfigError {
     #[error("Invalid retention threshold: {threshold}. Must be between 0.0 and 1.0")]
     InvalidRetentionThreshold { threshold: f32 },
 
+    /// Invalid decay profile override.
+    #[error("Invalid memory decay profile: {reason}")]
+    InvalidDecayProfile { reason: String },
+
     /// Invalid weights (must sum to 1.0).
     #[error("Invalid weights: vector={vector}, graph={graph}. Must sum to 1.0")]
     InvalidWeights { vector: f32, graph: f32 },
+
+    /// Unknown storage backend.
+    #[error("Unknown storage backend: {backend}. Expected rocksdb or sqlite")]
+    UnknownStorageBackend { backend: String },
+
+    /// Synced writes requested with the write-ahead log disabled.
+    #[error("Invalid storage durability: sync_writes requires wal_enabled")]
+    SyncWithoutWal,
+
+    /// Invalid periodic decay interval (must be non-zero).
+    #[error("Invalid decay interval: must be greater than 0 seconds")]
+    InvalidDecayInterval,
+
+    /// Invalid vector maintenance interval (must be non-zero).
+    #[error("Invalid vector maintenance interval: must be greater than 0 seconds")]
+    InvalidMaintenanceInterval,
+
+    /// Invalid vector snapshot interval (must be non-zero).
+    #[error("Invalid vector snapshot interval: must be greater than 0 seconds")]
+    InvalidSnapshotInterval,
+
+    /// Invalid preload settings (top N and concurrency must be non-zero).
+    #[error("Invalid storage preload: preload_top_n and preload_concurrency must be greater than 0")]
+    InvalidPreload,
+
+    /// Invalid query cache settings (capacity and TTL must be non-zero).
+    #[error("Invalid query cache: capacity and ttl_secs must be greater than 0")]
+    InvalidQueryCache,
+
+    /// Invalid absorb deduplication settings.
+    #[error("Invalid absorb_dedup: window_secs must be greater than 0 and similarity in (0.0, 1.0]")]
+    InvalidAbsorbDedup,
+
+    /// Invalid secret scrubbing settings.
+    #[error("Invalid scrub: min_token_length must be greater than 0, entropy_threshold at least 0 and every rule named")]
+    InvalidScrub,
+
+    /// Invalid namespace settings.
+    #[error("Invalid namespaces: every access rule must name a namespace, and rules naming tenants require metering to be enabled")]
+    InvalidNamespaces,
+
+    /// Invalid edge review settings (min_confidence must be in [0.0, 1.0]).
+    #[error("Invalid review: min_confidence must be between 0.0 and 1.0")]
+    InvalidReview,
+
+    /// Invalid re-embedding settings (batch size and interval must be non-zero).
+    #[error("Invalid reembed: batch_size and interval_ms must be greater than 0")]
+    InvalidReembed,
+
+    /// Invalid expiry digest settings (days, limit and interval must be non-zero).
+    #[error("Invalid expiry_digest: days, limit and interval_secs must be greater than 0")]
+    InvalidExpiryDigest,
+
+    /// Invalid similarity auto-linking settings.
+    #[error("Invalid similarity_links: threshold must be between 0.0 and 1.0, max_links_per_node and interval_secs greater than 0")]
+    InvalidSimilarityLinks,
+
+    /// Invalid Retry-After value (must be non-zero).
+    #[error("Invalid limits.retry_after_secs: must be greater than 0 seconds")]
+    InvalidRetryAfter,
+
+    /// Invalid webhook URL (must be http or https).
+    #[error("Invalid webhook URL: {url:?}. Must start with http:// or https://")]
+    InvalidWebhookUrl { url: String },
+
+    /// Invalid ingestion pipeline definition.
+    #[error("Invalid pipeline: {0}")]
+    InvalidPipeline(String),
+
+    /// Invalid materialized view definition.
+    #[error("Invalid view: {0}")]
+    InvalidView(String),
+
+    /// Invalid fault rates (each 0.0-1.0, summing to at most 1.0).
+    #[error("Invalid chaos fault rates: each must be between 0.0 and 1.0 and their sum at most 1.0")]
+    InvalidFaultRates,
 }
 
 #[cfg(test)]
@@ -423,4 +1589,490 @@ mod tests {
         let result = config.validate();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_storage_backend_selection() {
+        let mut config = Config::default();
+        assert_eq!(config.storage.data_path(), config.storage.rocksdb_path.as_path());
+
+        config.storage.backend = "sqlite".to_string();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.storage.data_path(), config.storage.sqlite_path.as_path());
+
+        config.storage.backend = "leveldb".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_storage_durability_config() {
+        let mut config: Config = toml::from_str("[storage]\nsync_writes = true\n").unwrap();
+        assert!(config.storage.sync_writes);
+        assert!(config.validate().is_ok());
+
+        config.storage.wal_enabled = false;
+        assert!(matches!(config.validate(), Err(ConfigError::SyncWithoutWal)));
+    }
+
+    #[test]
+    fn test_referential_integrity_config() {
+        assert!(!Config::default().storage.referential_integrity);
+        let config: Config = toml::from_str("[storage]\nreferential_integrity = true\n").unwrap();
+        assert!(config.storage.referential_integrity);
+    }
+
+    #[test]
+    fn test_content_compression_config() {
+        assert!(Config::default().storage.content_compression().is_none());
+        let config: Config = toml::from_str("[storage]\ncompress_content_threshold = 8192\n").unwrap();
+        assert_eq!(config.storage.content_compression().unwrap().threshold, 8192);
+    }
+
+    #[test]
+    fn test_id_format_config() {
+        assert_eq!(Config::default().storage.id_format, synton_core::IdFormat::V4);
+        let config: Config = toml::from_str("[storage]\nid_format = \"v7\"\n").unwrap();
+        assert_eq!(config.storage.id_format, synton_core::IdFormat::V7);
+        assert!(toml::from_str::<Config>("[storage]\nid_format = \"v1\"\n").is_err());
+    }
+
+    #[test]
+    fn test_vector_snapshot_config() {
+        let mut config: Config = toml::from_str(
+            "[storage]\nvector_snapshot_path = \"/var/lib/synton/vectors.snapshot\"\n",
+        )
+        .unwrap();
+        assert!(config.storage.vector_snapshot_enabled);
+        assert_eq!(
+            config.storage.vector_snapshot_path,
+            PathBuf::from("/var/lib/synton/vectors.snapshot")
+        );
+
+        config.storage.vector_snapshot_interval_secs = 0;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidSnapshotInterval)));
+    }
+
+    #[test]
+    fn test_decay_interval_validation() {
+        let mut config = Config::default();
+        config.memory.decay_interval_secs = 0;
+        assert!(config.validate().is_ok());
+
+        config.memory.periodic_decay_enabled = true;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidDecayInterval)
+        ));
+    }
+
+    #[test]
+    fn test_memory_profiles_config() {
+        let mut config: Config = toml::from_str(
+            "[memory.profiles.node_types.fact]\nlambda = 0.0005\n\
+             [memory.profiles.namespaces.scratch]\nmin_score = 0.5\n",
+        )
+        .unwrap();
+        let fact = &config.memory.profiles.node_types[&synton_core::NodeType::Fact];
+        assert_eq!(fact.lambda, 0.0005);
+        assert_eq!(config.memory.profiles.namespaces["scratch"].min_score, 0.5);
+        assert!(config.validate().is_ok());
+
+        config.memory.profiles.namespaces.get_mut("scratch").unwrap().max_score = 0.1;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidDecayProfile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_preload_config() {
+        let mut config: Config =
+            toml::from_str("[storage]\npreload_enabled = true\npreload_top_n = 500\n").unwrap();
+        assert_eq!(config.storage.preload_top_n, 500);
+        assert_eq!(config.storage.preload_concurrency, 16);
+        assert!(config.validate().is_ok());
+
+        config.storage.preload_concurrency = 0;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidPreload)));
+    }
+
+    #[test]
+    fn test_graph_max_degree_config() {
+        assert_eq!(Config::default().graph.max_degree(), None);
+
+        let config: Config = toml::from_str("[graph]\nmax_degree = 5000\n").unwrap();
+        assert_eq!(config.graph.max_degree(), Some(5000));
+        assert!(config.graph.snapshot_reads);
+    }
+
+    #[test]
+    fn test_query_cache_config() {
+        assert!(Config::default().query_cache.settings().is_none());
+
+        let mut config: Config = toml::from_str("[query_cache]\nenabled = true\nttl_secs = 5\n").unwrap();
+        let settings = config.query_cache.settings().unwrap();
+        assert_eq!(settings.capacity, 1024);
+        assert_eq!(settings.ttl, std::time::Duration::from_secs(5));
+        assert_eq!(settings.default_consistency, synton_api::Consistency::Eventual);
+        assert!(config.validate().is_ok());
+
+        let strong: Config = toml::from_str("[query_cache]\nenabled = true\ndefault_consistency = \"strong\"\n").unwrap();
+        assert_eq!(
+            strong.query_cache.settings().unwrap().default_consistency,
+            synton_api::Consistency::Strong
+        );
+
+        config.query_cache.capacity = 0;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidQueryCache)));
+    }
+
+    #[test]
+    fn test_absorb_dedup_config() {
+        assert!(Config::default().absorb_dedup.settings().is_none());
+
+        let mut config: Config = toml::from_str("[absorb_dedup]\nenabled = true\nwindow_secs = 120\n").unwrap();
+        let settings = config.absorb_dedup.settings().unwrap();
+        assert_eq!(settings.window, std::time::Duration::from_secs(120));
+        assert_eq!(settings.similarity, 0.9);
+        assert!(config.validate().is_ok());
+
+        config.absorb_dedup.similarity = 1.5;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidAbsorbDedup)));
+    }
+
+    #[test]
+    fn test_scrub_config() {
+        assert!(Config::default().scrub.settings().is_none());
+
+        let mut config: Config = toml::from_str(
+            "[scrub]\nenabled = true\naction = \"reject\"\nallowlist = [\"EXAMPLE\"]\n\n\
+             [[scrub.rules]]\nname = \"internal_token\"\npattern = \"itk_[a-z]{8}\"\n",
+        )
+        .unwrap();
+        let settings = config.scrub.settings().unwrap();
+        assert_eq!(settings.action, synton_api::ScrubAction::Reject);
+        assert_eq!(settings.rules[0].name, "internal_token");
+        assert_eq!(settings.allowlist, vec!["EXAMPLE".to_string()]);
+        assert_eq!(settings.entropy_threshold, 4.0);
+        assert!(config.validate().is_ok());
+
+        config.scrub.min_token_length = 0;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidScrub)));
+    }
+
+    #[test]
+    fn test_namespaces_config() {
+        let settings = Config::default().namespaces.settings();
+        assert_eq!(settings.inheritance, synton_api::NamespaceInheritance::Descendants);
+        assert!(settings.rules.is_empty());
+
+        let mut config: Config = toml::from_str(
+            "[namespaces]\ninheritance = \"both\"\n\n\
+             [[namespaces.access]]\nnamespace = \"org/hr\"\nactors = [\"hr-bot\"]\n",
+        )
+        .unwrap();
+        let settings = config.namespaces.settings();
+        assert_eq!(settings.inheritance, synton_api::NamespaceInheritance::Both);
+        assert_eq!(settings.rules[0].namespace, "org/hr");
+        assert_eq!(settings.rules[0].actors, vec!["hr-bot".to_string()]);
+        assert!(config.validate().is_ok());
+
+        // Tenants cannot be resolved without metering
+        config.metering.enabled = false;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidNamespaces)));
+        config.namespaces.access[0].actors = vec!["*".to_string()];
+        assert!(config.validate().is_ok());
+
+        config.namespaces.access[0].namespace = "/".to_string();
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidNamespaces)));
+    }
+
+    #[test]
+    fn test_audit_config() {
+        let settings = Config::default().audit.settings().unwrap();
+        assert_eq!(settings.log_path, None);
+        assert_eq!(settings.recent_events, 10_000);
+
+        let config: Config =
+            toml::from_str("[audit]\nlog_path = \"./data/audit.jsonl\"\n").unwrap();
+        let settings = config.audit.settings().unwrap();
+        assert_eq!(settings.log_path, Some(PathBuf::from("./data/audit.jsonl")));
+
+        let config: Config = toml::from_str("[audit]\nenabled = false\n").unwrap();
+        assert!(config.audit.settings().is_none());
+    }
+
+    #[test]
+    fn test_usage_config() {
+        let settings = Config::default().usage.settings().unwrap();
+        assert_eq!(settings.recent_events, 20);
+
+        let config: Config = toml::from_str("[usage]\nenabled = false\n").unwrap();
+        assert!(config.usage.settings().is_none());
+    }
+
+    #[test]
+    fn test_metering_config() {
+        let settings = Config::default().metering.settings().unwrap();
+        assert!(settings.tenants.is_empty());
+
+        let toml = r#"
+[metering.tenants]
+"sk-team-a" = "team-a"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let settings = config.metering.settings().unwrap();
+        assert_eq!(settings.tenants.get("sk-team-a").map(String::as_str), Some("team-a"));
+
+        let config: Config = toml::from_str("[metering]\nenabled = false\n").unwrap();
+        assert!(config.metering.settings().is_none());
+    }
+
+    #[test]
+    fn test_seed_config() {
+        assert!(Config::default().seed.request().unwrap().is_none());
+
+        let config: Config = toml::from_str("[seed]\nenabled = true\n").unwrap();
+        let request = config.seed.request().unwrap().unwrap();
+        assert!(request.ontology.is_none());
+        assert!(request.namespace.is_none());
+
+        let toml = "[seed]\nenabled = true\nontology_file = \"/nonexistent/ontology.json\"\n";
+        let config: Config = toml::from_str(toml).unwrap();
+        assert!(config.seed.request().is_err());
+    }
+
+    #[test]
+    fn test_review_config() {
+        let settings = Config::default().review.settings().unwrap();
+        assert_eq!(settings.min_confidence, 0.5);
+
+        let mut config: Config = toml::from_str("[review]\nmin_confidence = 0.7\n").unwrap();
+        assert_eq!(config.review.settings().unwrap().min_confidence, 0.7);
+        config.review.min_confidence = 1.5;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidReview)));
+    }
+
+    #[test]
+    fn test_reembed_config() {
+        let settings = Config::default().reembed.settings().unwrap();
+        assert_eq!(settings.batch_size, 32);
+        assert_eq!(settings.interval, std::time::Duration::from_secs(1));
+
+        let mut config: Config = toml::from_str("[reembed]\nbatch_size = 0\n").unwrap();
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidReembed)));
+
+        config.reembed.enabled = false;
+        assert!(config.reembed.settings().is_none());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_expiry_digest_config() {
+        assert!(Config::default().expiry_digest.settings().is_none());
+
+        let mut config: Config =
+            toml::from_str("[expiry_digest]\nenabled = true\ndays = 14\n").unwrap();
+        let settings = config.expiry_digest.settings().unwrap();
+        assert_eq!((settings.days, settings.limit), (14, 100));
+        assert_eq!(settings.interval, std::time::Duration::from_secs(86400));
+        assert!(config.validate().is_ok());
+
+        config.expiry_digest.interval_secs = 0;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidExpiryDigest)));
+    }
+
+    #[test]
+    fn test_similarity_links_config() {
+        assert!(Config::default().similarity_links.settings().is_none());
+
+        let mut config: Config =
+            toml::from_str("[similarity_links]\nenabled = true\nthreshold = 0.95\n").unwrap();
+        let settings = config.similarity_links.settings().unwrap();
+        assert_eq!((settings.threshold, settings.max_links_per_node), (0.95, 5));
+        assert_eq!(settings.interval, std::time::Duration::from_secs(3600));
+        assert!(config.validate().is_ok());
+
+        config.similarity_links.threshold = 1.5;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidSimilarityLinks)));
+    }
+
+    #[test]
+    fn test_chaos_config() {
+        let mut config: Config = toml::from_str(
+            "[chaos]\nenabled = true\nvector = false\nseed = 7\nerror_rate = 0.1\n",
+        )
+        .unwrap();
+        assert!(config.chaos.store);
+        assert_eq!(config.chaos.faults.seed, 7);
+        assert!(config.chaos.injector(config.chaos.store, "store").is_some());
+        assert!(config.chaos.injector(config.chaos.vector, "vector").is_none());
+        assert!(config.validate().is_ok());
+
+        config.chaos.faults.timeout_rate = 0.95;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidFaultRates)));
+
+        // Disabled by default
+        assert!(Config::default().chaos.injector(true, "store").is_none());
+    }
+
+    #[test]
+    fn test_ml_persistent_cache_config() {
+        assert!(!Config::default().ml.persistent_cache_enabled);
+
+        let config: Config =
+            toml::from_str("[ml]\npersistent_cache_enabled = true\npersistent_cache_size = 5000\n").unwrap();
+        assert!(config.ml.persistent_cache_enabled);
+        assert_eq!(config.ml.persistent_cache_size, 5000);
+        assert!(config.ml.cache_enabled);
+    }
+
+    #[test]
+    fn test_ml_language_config_parsing() {
+        let config: Config = toml::from_str(
+            r#"
+            [ml.languages.zh]
+            local_model = "BAAI/bge-small-zh-v1.5"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.ml.cross_lingual_fallback);
+        let zh = &config.ml.languages["zh"];
+        assert_eq!(zh.local_model.as_deref(), Some("BAAI/bge-small-zh-v1.5"));
+        assert!(zh.backend.is_none());
+    }
+
+    #[test]
+    fn test_query_translation_config() {
+        assert!(!Config::default().query_translation.enabled);
+
+        let config: Config = toml::from_str(
+            r#"
+            [query_translation]
+            enabled = true
+            api_endpoint = "http://localhost:11434/v1"
+            model = "llama3.1"
+            "#,
+        )
+        .unwrap();
+        assert!(config.query_translation.enabled);
+        assert_eq!(config.query_translation.api_endpoint.as_deref(), Some("http://localhost:11434/v1"));
+        assert_eq!(config.query_translation.model, "llama3.1");
+        assert!(config.query_translation.api_key.is_none());
+    }
+
+    #[test]
+    fn test_summarization_config() {
+        assert!(!Config::default().summarization.enabled);
+
+        let mut config: Config = toml::from_str(
+            r#"
+            [summarization]
+            enabled = true
+            model = "llama3.1"
+            min_chars = 2000
+            "#,
+        )
+        .unwrap();
+        assert!(config.summarization.enabled);
+        assert_eq!(config.summarization.model, "llama3.1");
+        assert_eq!(config.summarization.min_chars, 2000);
+        assert_eq!(config.summarization.max_input_chars, 16_000);
+        assert!(config.validate().is_ok());
+
+        config.summarization.decay_lambda = 2.0;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidDecayProfile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hooks_config_parsing() {
+        let config: Config = toml::from_str(
+            r#"
+            [[hooks.webhooks]]
+            url = "https://hooks.example.com/synton"
+            events = ["document_ingested"]
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let webhook = &config.hooks.webhooks[0];
+        assert_eq!(webhook.events, vec![synton_api::MutationEventKind::DocumentIngested]);
+        assert_eq!(webhook.max_retries, synton_api::WebhookConfig::default().max_retries);
+
+        let mut config = config;
+        config.hooks.webhooks[0].url = "ftp://example.com".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_pipelines_config_parsing() {
+        let config: Config = toml::from_str(
+            r#"
+            [[pipelines]]
+            name = "handbook"
+            namespace = "docs"
+            source = { type = "directory", path = "./handbook", extensions = ["md"] }
+            chunking = { fixed = { chunk_size = 500, overlap = 50 } }
+            enrich = [
+                { type = "metadata", values = { team = "platform" } },
+                { type = "tags", tags = ["handbook"] },
+            ]
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let pipeline = &config.pipelines[0];
+        assert!(pipeline.embed);
+        assert_eq!(pipeline.enrich.len(), 2);
+        assert_eq!(
+            pipeline.source,
+            synton_api::PipelineSource::Directory {
+                path: PathBuf::from("./handbook"),
+                extensions: vec!["md".to_string()],
+            }
+        );
+
+        let mut config = config;
+        config.pipelines.push(config.pipelines[0].clone());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_views_config_parsing() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [[views]]
+            name = "service-map"
+            start_tags = ["service"]
+            max_depth = 3
+            direction = "Both"
+            relations = ["is_part_of"]
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let view = &config.views[0];
+        assert_eq!(view.max_depth, 3);
+        assert_eq!(view.direction, synton_api::TraverseDirection::Both);
+        assert_eq!(view.relations, vec![synton_core::Relation::IsPartOf]);
+
+        config.views[0].max_depth = 0;
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidView(_))));
+    }
+
+    #[test]
+    fn test_limits_config_roundtrip() {
+        let config = LimitsConfig::default();
+        assert_eq!(config.to_limits(), synton_api::ConcurrencyLimits::default());
+
+        let mut config = Config::default();
+        config.limits.retry_after_secs = 0;
+        assert!(config.validate().is_err());
+    }
 }
@@ -15,7 +15,7 @@ mod server;
 
 use std::time::Instant;
 use tracing::{info, Level};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 
 use clap::Parser;
 
@@ -55,6 +55,11 @@ struct Args {
     /// Validate configuration and exit
     #[arg(long)]
     validate: bool,
+
+    /// Seed the starter ontology (or `[seed] ontology_file`) at startup
+    /// unless already seeded
+    #[arg(long)]
+    seed: bool,
 }
 
 #[tokio::main]
@@ -156,6 +161,10 @@ fn apply_cli_overrides(config: &mut Config, args: &Args) {
     if let Some(level) = &args.log_level {
         config.logging.level = level.clone();
     }
+
+    if args.seed {
+        config.seed.enabled = true;
+    }
 }
 
 /// Initialize logging based on configuration.
@@ -173,6 +182,13 @@ fn init_logging(config: &Config) {
         .with_default_directive(level.into())
         .from_env_lossy();
 
+    // The console layer sees every runtime event; the level only filters
+    // log output
+    #[cfg(feature = "runtime-diagnostics")]
+    let console_layer = Some(console_subscriber::spawn());
+    #[cfg(not(feature = "runtime-diagnostics"))]
+    let console_layer: Option<tracing_subscriber::layer::Identity> = None;
+
     if config.logging.json_format {
         let fmt_layer = tracing_subscriber::fmt::layer()
             .json()
@@ -182,8 +198,8 @@ fn init_logging(config: &Config) {
             .with_line_number(false);
 
         tracing_subscriber::registry()
-            .with(env_filter)
-            .with(fmt_layer)
+            .with(console_layer)
+            .with(fmt_layer.with_filter(env_filter))
             .init();
     } else {
         let fmt_layer = tracing_subscriber::fmt::layer()
@@ -193,8 +209,8 @@ fn init_logging(config: &Config) {
             .with_line_number(false);
 
         tracing_subscriber::registry()
-            .with(env_filter)
-            .with(fmt_layer)
+            .with(console_layer)
+            .with(fmt_layer.with_filter(env_filter))
             .init();
     }
 }
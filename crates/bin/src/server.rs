@@ -11,11 +11,17 @@ use tokio::sync::oneshot;
 use tracing::{error, info, warn};
 
 use crate::config::Config;
-use synton_api::SyntonDbService;
+use synton_api::{SyntonDbService, WebhookHook};
 use synton_storage::rocksdb::{RocksdbConfig, RocksdbStore};
+use synton_storage::{FaultyStore, Store};
+use synton_vector::{FaultyVectorIndex, SnapshotScheduler};
 
 #[cfg(feature = "ml")]
-use synton_ml::{BackendType, EmbeddingConfig, EmbeddingService};
+use synton_api::StoreEmbeddingCache;
+#[cfg(feature = "ml")]
+use synton_ml::{
+    BackendType, CompletionBackend, EmbeddingConfig, EmbeddingService, OpenAiCompletionBackend,
+};
 
 /// Server handle for managing running servers.
 pub struct ServerHandle {
@@ -24,6 +30,25 @@ pub struct ServerHandle {
 
     /// The REST server task handle.
     rest_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// The vector index maintenance task handle.
+    maintenance_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// The background re-embedding task handle.
+    reembed_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// The scheduled expiry digest task handle.
+    expiry_digest_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// The similarity auto-linking task handle.
+    similarity_links_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// The periodic memory pruning task handle.
+    pruning_handle: Option<tokio::task::JoinHandle<()>>,
+
+    /// The vector index snapshot task and its scheduler, for a final
+    /// snapshot on shutdown.
+    snapshot: Option<(SnapshotScheduler, tokio::task::JoinHandle<()>)>,
 }
 
 impl ServerHandle {
@@ -35,9 +60,54 @@ impl ServerHandle {
         Self {
             grpc_handle,
             rest_handle,
+            maintenance_handle: None,
+            reembed_handle: None,
+            expiry_digest_handle: None,
+            similarity_links_handle: None,
+            pruning_handle: None,
+            snapshot: None,
         }
     }
 
+    /// Attach the vector index maintenance task handle.
+    pub fn with_maintenance(mut self, handle: Option<tokio::task::JoinHandle<()>>) -> Self {
+        self.maintenance_handle = handle;
+        self
+    }
+
+    /// Attach the background re-embedding task handle.
+    pub fn with_reembedding(mut self, handle: Option<tokio::task::JoinHandle<()>>) -> Self {
+        self.reembed_handle = handle;
+        self
+    }
+
+    /// Attach the scheduled expiry digest task handle.
+    pub fn with_expiry_digest(mut self, handle: Option<tokio::task::JoinHandle<()>>) -> Self {
+        self.expiry_digest_handle = handle;
+        self
+    }
+
+    /// Attach the similarity auto-linking task handle.
+    pub fn with_similarity_links(mut self, handle: Option<tokio::task::JoinHandle<()>>) -> Self {
+        self.similarity_links_handle = handle;
+        self
+    }
+
+    /// Attach the periodic memory pruning task handle.
+    pub fn with_pruning(mut self, handle: Option<tokio::task::JoinHandle<()>>) -> Self {
+        self.pruning_handle = handle;
+        self
+    }
+
+    /// Attach the vector index snapshot task.
+    pub fn with_snapshot(
+        mut self,
+        snapshot: Option<(SnapshotScheduler, tokio::task::JoinHandle<()>)>,
+    ) -> Self {
+        self.snapshot = snapshot;
+        self
+    }
+
     /// Signal all servers to shut down.
     pub async fn shutdown(mut self) {
         // Wait for servers to finish
@@ -49,6 +119,35 @@ impl ServerHandle {
             handle.abort();
         }
 
+        if let Some(handle) = self.maintenance_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.reembed_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.expiry_digest_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.similarity_links_handle.take() {
+            handle.abort();
+        }
+
+        if let Some(handle) = self.pruning_handle.take() {
+            handle.abort();
+        }
+
+        if let Some((scheduler, handle)) = self.snapshot.take() {
+            handle.abort();
+            match scheduler.run_once().await {
+                Ok(true) => info!("Vector index snapshot saved to {}", scheduler.path().display()),
+                Ok(false) => {}
+                Err(e) => warn!("Failed to save vector index snapshot: {}", e),
+            }
+        }
+
         info!("All servers have shut down");
     }
 }
@@ -61,18 +160,24 @@ impl ServerHandle {
 pub async fn start_servers(
     config: &Config,
 ) -> Result<(ServerHandle, oneshot::Sender<()>), Box<dyn std::error::Error>> {
+    synton_core::set_id_format(config.storage.id_format);
+    if config.storage.id_format == synton_core::IdFormat::V7 {
+        info!("New nodes get time-ordered (UUIDv7) IDs.");
+    }
+
     // Initialize persistent storage if enabled
-    let store = if config.storage.rocksdb_path.as_os_str().is_empty() {
+    let store = if config.storage.data_path().as_os_str().is_empty() {
         info!("Persistent storage disabled.");
         None
     } else {
         match init_persistent_store(config) {
             Ok(store) => {
                 info!(
-                    "Persistent storage initialized: {}",
-                    config.storage.rocksdb_path.display()
+                    "Persistent storage initialized: backend={}, path={}",
+                    config.storage.backend,
+                    config.storage.data_path().display()
                 );
-                Some(Arc::new(store))
+                Some(store)
             }
             Err(e) => {
                 warn!("Failed to initialize persistent storage: {}. Running without persistence.", e);
@@ -80,12 +185,26 @@ pub async fn start_servers(
             }
         }
     };
+    // Chaos mode wraps the backends with seeded fault injection
+    let store = match config.chaos.injector(config.chaos.store, "store") {
+        Some(injector) => store.map(|store| Arc::new(FaultyStore::new(store, injector)) as Arc<dyn Store>),
+        None => store,
+    };
+    if config.chaos.enabled {
+        warn!(
+            "Chaos mode enabled: injecting faults (seed={}, error_rate={}, timeout_rate={}, latency_rate={})",
+            config.chaos.faults.seed,
+            config.chaos.faults.error_rate,
+            config.chaos.faults.timeout_rate,
+            config.chaos.faults.latency_rate
+        );
+    }
 
     // Initialize service with optional ML support and persistent storage
     #[cfg(feature = "ml")]
-    let service = {
+    let mut service = {
         if config.ml.enabled {
-            match init_embedding_service(config).await {
+            match init_embedding_service(config, store.as_ref()).await {
                 Ok(embedding) => {
                     info!(
                         "ML embedding service initialized: backend={}, dimension={}",
@@ -93,79 +212,240 @@ pub async fn start_servers(
                         embedding.dimension()
                     );
                     if let Some(store) = store {
-                        Arc::new(SyntonDbService::with_store_and_embedding(
-                            store,
-                            embedding,
-                        ))
+                        SyntonDbService::with_store_and_embedding(store, embedding)
                     } else {
-                        Arc::new(SyntonDbService::with_embedding(embedding))
+                        SyntonDbService::with_embedding(embedding)
                     }
                 }
                 Err(e) => {
                     warn!("Failed to initialize ML service: {}. Running without embeddings.", e);
                     if let Some(store) = store {
-                        Arc::new(SyntonDbService::with_store(store))
+                        SyntonDbService::with_store(store)
                     } else {
-                        Arc::new(SyntonDbService::new())
+                        SyntonDbService::new()
                     }
                 }
             }
         } else {
             info!("ML features disabled. Running without embeddings.");
             if let Some(store) = store {
-                Arc::new(SyntonDbService::with_store(store))
+                SyntonDbService::with_store(store)
             } else {
-                Arc::new(SyntonDbService::new())
+                SyntonDbService::new()
             }
         }
     };
 
     #[cfg(not(feature = "ml"))]
-    let service = {
+    let mut service = {
         if config.ml.enabled {
             info!("ML features requested but ML feature is not enabled. Recompile with --features ml to enable.");
         }
         if let Some(store) = store {
-            Arc::new(SyntonDbService::with_store(store))
+            SyntonDbService::with_store(store)
         } else {
-            Arc::new(SyntonDbService::new())
+            SyntonDbService::new()
         }
     };
 
+    if let Some(injector) = config.chaos.injector(config.chaos.vector, "vector") {
+        if let Some(index) = service.vector_index().cloned() {
+            service.set_vector_index(Arc::new(FaultyVectorIndex::new(index, injector)));
+        }
+    }
+
+    #[cfg(feature = "ml")]
+    if config.query_translation.enabled {
+        let translation = &config.query_translation;
+        match init_completion_backend(
+            config,
+            translation.api_endpoint.as_ref(),
+            translation.api_key.as_ref(),
+            &translation.model,
+            translation.timeout_secs,
+        ) {
+            Ok(backend) => {
+                info!("Query translation enabled: model={}", backend.model());
+                service.set_completion(backend);
+            }
+            Err(e) => warn!("Failed to initialize query translation: {}. Running without it.", e),
+        }
+    }
+
+    #[cfg(feature = "ml")]
+    if config.summarization.enabled {
+        let summarization = &config.summarization;
+        match init_completion_backend(
+            config,
+            summarization.api_endpoint.as_ref(),
+            summarization.api_key.as_ref(),
+            &summarization.model,
+            summarization.timeout_secs,
+        ) {
+            Ok(backend) => {
+                info!("Document summarization enabled: model={}", backend.model());
+                service.set_summarizer(
+                    backend,
+                    synton_api::SummaryConfig {
+                        min_chars: summarization.min_chars,
+                        max_input_chars: summarization.max_input_chars,
+                    },
+                );
+            }
+            Err(e) => warn!(
+                "Failed to initialize document summarization: {}. Running without it.",
+                e
+            ),
+        }
+    }
+    let service = Arc::new(service);
+
+    service.set_snapshot_reads(config.graph.snapshot_reads);
+    service.set_max_degree(config.graph.max_degree()).await;
+    service.set_query_cache(config.query_cache.settings());
+    service.set_absorb_dedup(config.absorb_dedup.settings());
+    service.set_scrub(config.scrub.settings())?;
+    service.set_namespaces(config.namespaces.settings());
+    service.set_usage_tracking(config.usage.settings());
+    service.set_metering(config.metering.settings());
+    if let Err(e) = service.set_audit(config.audit.settings()) {
+        warn!("Failed to configure the audit trail: {}", e);
+    }
+    service.set_edge_review(config.review.settings());
+    if let Err(e) = service.set_pipelines(config.pipelines.clone()) {
+        warn!("Failed to configure ingestion pipelines: {}", e);
+    }
+    if let Err(e) = service.set_views(config.views.clone()) {
+        warn!("Failed to configure materialized views: {}", e);
+    }
+    register_webhooks(config, &service);
+
+    // Select decay profiles before nodes are registered; summaries decay
+    // slower than the chunks they cover unless their profile is configured
+    let mut profiles = config.memory.profiles.clone();
+    if config.summarization.enabled && profiles.summaries.is_none() {
+        profiles.summaries = Some(synton_memory::DecayConfig {
+            lambda: config.summarization.decay_lambda,
+            ..Default::default()
+        });
+    }
+    if !profiles.is_empty() {
+        let memory = service.memory().await;
+        if let Err(e) = memory.write().await.set_profiles(profiles) {
+            warn!("Failed to apply memory decay profiles: {}", e);
+        }
+    }
+
     // Initialize service data from storage
-    if let Err(e) = service.initialize_from_store().await {
+    if config.storage.preload_enabled {
+        match service
+            .preload_hot_nodes(config.storage.preload_top_n, config.storage.preload_concurrency)
+            .await
+        {
+            Ok(report) => info!(
+                "Preloaded {} hot nodes, {} neighbors and {} edges in {}ms",
+                report.hot_nodes, report.neighbor_nodes, report.edges, report.duration_ms
+            ),
+            Err(e) => warn!("Failed to preload hot nodes from storage: {}", e),
+        }
+    } else if let Err(e) = service.initialize_from_store().await {
         warn!("Failed to initialize service from storage: {}", e);
     }
 
+    // Warm-start the vector index before serving searches
+    let snapshot = maybe_start_vector_snapshots(config, &service).await;
+
+    match config.seed.request() {
+        Ok(Some(request)) => match service.seed(request).await {
+            Ok(seeded) if seeded.seeded => info!(
+                "Seeded ontology {} into namespace {}: {} nodes, {} edges",
+                seeded.ontology, seeded.namespace, seeded.nodes, seeded.edges
+            ),
+            Ok(seeded) => info!("Namespace {} is already seeded", seeded.namespace),
+            Err(e) => warn!("Failed to seed the ontology: {}", e),
+        },
+        Ok(None) => {}
+        Err(e) => warn!("Failed to seed the ontology: {}", e),
+    }
+
+    match service.resume_jobs().await {
+        Ok(0) => {}
+        Ok(resumed) => info!("Resuming {} interrupted background jobs", resumed),
+        Err(e) => warn!("Failed to load background jobs: {}", e),
+    }
+
+    let maintenance_handle = maybe_start_vector_maintenance(config, &service);
+    let reembed_handle = config
+        .reembed
+        .settings()
+        .and_then(|settings| service.spawn_reembedding(settings));
+    let expiry_digest_handle = config
+        .expiry_digest
+        .settings()
+        .map(|settings| service.spawn_expiry_digest(settings));
+    let similarity_links_handle = config
+        .similarity_links
+        .settings()
+        .map(|settings| service.spawn_similarity_linking(settings));
+    let pruning_interval = std::time::Duration::from_secs(config.memory.decay_interval_secs);
+    let pruning_handle = config
+        .memory
+        .periodic_decay_enabled
+        .then(|| service.spawn_memory_pruning(pruning_interval));
     let grpc_handle = maybe_start_grpc(config, service.clone())?;
     let rest_handle = maybe_start_rest(config, service)?;
     let (shutdown_tx, _shutdown_rx) = oneshot::channel();
 
-    let handle = ServerHandle::new(grpc_handle, rest_handle);
+    let handle = ServerHandle::new(grpc_handle, rest_handle)
+        .with_maintenance(maintenance_handle)
+        .with_reembedding(reembed_handle)
+        .with_expiry_digest(expiry_digest_handle)
+        .with_similarity_links(similarity_links_handle)
+        .with_pruning(pruning_handle)
+        .with_snapshot(snapshot);
 
     Ok((handle, shutdown_tx))
 }
 
-/// Initialize the persistent storage backend.
-fn init_persistent_store(config: &Config) -> Result<RocksdbStore, Box<dyn std::error::Error>> {
+/// Register the webhooks from the configuration as mutation hooks.
+fn register_webhooks(config: &Config, service: &SyntonDbService) {
+    for webhook in &config.hooks.webhooks {
+        match WebhookHook::new(webhook.clone()) {
+            Ok(hook) => {
+                info!("Webhook registered: {}", webhook.url);
+                service.hooks().register(Arc::new(hook));
+            }
+            Err(e) => warn!("Failed to register webhook {}: {}", webhook.url, e),
+        }
+    }
+}
+
+/// Initialize the persistent storage backend selected in the configuration.
+fn init_persistent_store(config: &Config) -> Result<Arc<dyn Store>, Box<dyn std::error::Error>> {
+    let data_path = config.storage.data_path();
+
     // Ensure the parent directory exists
-    if let Some(parent) = config.storage.rocksdb_path.parent() {
+    if let Some(parent) = data_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
 
     // Convert path to string, handling invalid UTF-8 gracefully
-    let path = config
-        .storage
-        .rocksdb_path
+    let path = data_path
         .to_str()
-        .ok_or_else(|| {
-            format!(
-                "RocksDB path contains invalid UTF-8: {:?}",
-                config.storage.rocksdb_path
-            )
-        })?
+        .ok_or_else(|| format!("Storage path contains invalid UTF-8: {:?}", data_path))?
         .to_string();
 
+    match config.storage.backend.as_str() {
+        "sqlite" => init_sqlite_store(config, path),
+        _ => init_rocksdb_store(config, path),
+    }
+}
+
+/// Open the RocksDB store.
+fn init_rocksdb_store(
+    config: &Config,
+    path: String,
+) -> Result<Arc<dyn Store>, Box<dyn std::error::Error>> {
     let rocksdb_config = RocksdbConfig {
         path,
         write_buffer_size: config.storage.cache_size_mb * 1024 * 1024,
@@ -174,24 +454,116 @@ fn init_persistent_store(config: &Config) -> Result<RocksdbStore, Box<dyn std::e
         create_if_missing: true,
         create_missing_column_families: true,
         compression: synton_storage::rocksdb::RocksdbCompression::Lz4,
+        sync: config.storage.sync_writes,
+        disable_wal: !config.storage.wal_enabled,
+        referential_integrity: config.storage.referential_integrity,
+        content_compression: config.storage.content_compression(),
+    };
+
+    let store = RocksdbStore::open(rocksdb_config)?;
+    Ok(Arc::new(store))
+}
+
+/// Open the single-file SQLite store.
+#[cfg(feature = "sqlite")]
+fn init_sqlite_store(
+    config: &Config,
+    path: String,
+) -> Result<Arc<dyn Store>, Box<dyn std::error::Error>> {
+    use synton_storage::sqlite::{SqliteConfig, SqliteStore};
+
+    let sqlite_config = SqliteConfig {
+        path,
+        wal_enabled: config.storage.wal_enabled,
+        cache_size_mb: config.storage.cache_size_mb,
+        referential_integrity: config.storage.referential_integrity,
+        content_compression: config.storage.content_compression(),
+        ..Default::default()
     };
 
-    RocksdbStore::open(rocksdb_config).map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+    let store = SqliteStore::open(sqlite_config)?;
+    Ok(Arc::new(store))
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn init_sqlite_store(
+    _config: &Config,
+    _path: String,
+) -> Result<Arc<dyn Store>, Box<dyn std::error::Error>> {
+    Err("SQLite backend requested but the sqlite feature is not enabled. Recompile with --features sqlite to enable.".into())
+}
+
+/// Initialize a chat completion backend, such as the one translating
+/// queries into PaQL. Unset endpoint and key inherit the `[ml]` values.
+#[cfg(feature = "ml")]
+fn init_completion_backend(
+    config: &Config,
+    api_endpoint: Option<&String>,
+    api_key: Option<&String>,
+    model: &str,
+    timeout_secs: u64,
+) -> Result<Arc<dyn CompletionBackend>, Box<dyn std::error::Error>> {
+    let backend = OpenAiCompletionBackend::new(synton_ml::ApiConfig {
+        endpoint: api_endpoint
+            .cloned()
+            .unwrap_or_else(|| config.ml.api_endpoint.clone()),
+        api_key: api_key.cloned().or_else(|| config.ml.api_key.clone()),
+        model: model.to_string(),
+        timeout_secs,
+        ..Default::default()
+    })?;
+    Ok(Arc::new(backend))
 }
 
 /// Initialize the embedding service from configuration.
 #[cfg(feature = "ml")]
-async fn init_embedding_service(config: &Config) -> Result<Arc<EmbeddingService>, Box<dyn std::error::Error>> {
-    use synton_ml::{ApiConfig, LocalModelConfig};
+async fn init_embedding_service(
+    config: &Config,
+    store: Option<&Arc<dyn Store>>,
+) -> Result<Arc<EmbeddingService>, Box<dyn std::error::Error>> {
+    use synton_ml::{ApiConfig, LanguageModelConfig, LocalModelConfig};
 
-    let backend_type = match config.ml.backend.to_lowercase().as_str() {
+    let parse_backend = |backend: &str| match backend.to_lowercase().as_str() {
         "openai" => BackendType::OpenAi,
         "ollama" => BackendType::Ollama,
         _ => BackendType::Local,
     };
 
+    // Language models inherit unset fields from the [ml] section
+    let languages = config
+        .ml
+        .languages
+        .iter()
+        .map(|(lang, model)| {
+            let language_config = LanguageModelConfig {
+                backend: parse_backend(model.backend.as_deref().unwrap_or(&config.ml.backend)),
+                local: LocalModelConfig {
+                    model_name: model
+                        .local_model
+                        .clone()
+                        .unwrap_or_else(|| config.ml.local_model.clone()),
+                    ..Default::default()
+                },
+                api: ApiConfig {
+                    endpoint: model
+                        .api_endpoint
+                        .clone()
+                        .unwrap_or_else(|| config.ml.api_endpoint.clone()),
+                    api_key: model.api_key.clone().or_else(|| config.ml.api_key.clone()),
+                    model: model
+                        .api_model
+                        .clone()
+                        .unwrap_or_else(|| config.ml.api_model.clone()),
+                    timeout_secs: config.ml.timeout_secs,
+                    ..Default::default()
+                },
+            };
+            (lang.clone(), language_config)
+        })
+        .collect();
+
     let ml_config = EmbeddingConfig {
-        backend: backend_type,
+        backend: parse_backend(&config.ml.backend),
         local: LocalModelConfig {
             model_name: config.ml.local_model.clone(),
             ..Default::default()
@@ -205,13 +577,81 @@ async fn init_embedding_service(config: &Config) -> Result<Arc<EmbeddingService>
         },
         cache_enabled: config.ml.cache_enabled,
         cache_size: config.ml.cache_size,
+        languages,
+        cross_lingual_fallback: config.ml.cross_lingual_fallback,
         ..Default::default()
     };
 
-    let service = EmbeddingService::from_config(ml_config).await?;
+    let mut service = EmbeddingService::from_config(ml_config).await?;
+    if let Some(injector) = config.chaos.injector(config.chaos.embedding, "embedding") {
+        service = service.with_fault_injection(injector);
+    }
+    if config.ml.persistent_cache_enabled {
+        match store {
+            Some(store) => {
+                let cache =
+                    StoreEmbeddingCache::open(Arc::clone(store), config.ml.persistent_cache_size).await?;
+                info!("Persistent embedding cache opened with {} entries", cache.len());
+                service = service.with_persistent_cache(Arc::new(cache));
+            }
+            None => warn!("Persistent embedding cache requires persistence. Running without it."),
+        }
+    }
     Ok(Arc::new(service))
 }
 
+/// Start periodic vector index maintenance if enabled.
+fn maybe_start_vector_maintenance(
+    config: &Config,
+    service: &SyntonDbService,
+) -> Option<tokio::task::JoinHandle<()>> {
+    if !config.storage.vector_maintenance_enabled {
+        return None;
+    }
+
+    let interval = std::time::Duration::from_secs(config.storage.vector_maintenance_interval_secs);
+    let handle = service.spawn_vector_maintenance(interval)?;
+    info!(
+        "Vector index maintenance scheduled every {}s",
+        config.storage.vector_maintenance_interval_secs
+    );
+
+    Some(handle)
+}
+
+/// Restore the vector index from its snapshot and start periodic snapshots
+/// if enabled.
+async fn maybe_start_vector_snapshots(
+    config: &Config,
+    service: &SyntonDbService,
+) -> Option<(SnapshotScheduler, tokio::task::JoinHandle<()>)> {
+    let path = &config.storage.vector_snapshot_path;
+    if !config.storage.vector_snapshot_enabled || path.as_os_str().is_empty() {
+        return None;
+    }
+
+    let interval = std::time::Duration::from_secs(config.storage.vector_snapshot_interval_secs);
+    let scheduler = service.vector_snapshot_scheduler(path, interval)?;
+
+    match service.restore_vector_index(path).await {
+        Ok(0) => {}
+        Ok(count) => info!("Vector index restored from {}: {} vectors", path.display(), count),
+        Err(e) => warn!(
+            "Failed to restore vector index from {}: {}. Starting with an empty index.",
+            path.display(),
+            e
+        ),
+    }
+
+    let handle = scheduler.clone().spawn();
+    info!(
+        "Vector index snapshots scheduled every {}s",
+        config.storage.vector_snapshot_interval_secs
+    );
+
+    Some((scheduler, handle))
+}
+
 /// Start the gRPC server if enabled.
 fn maybe_start_grpc(
     config: &Config,
@@ -223,6 +663,7 @@ fn maybe_start_grpc(
 
     let grpc_addr = format!("{}:{}", config.server.host, config.server.grpc_port);
     let grpc_addr: std::net::SocketAddr = grpc_addr.parse()?;
+    let limits = config.limits.to_limits();
 
     let handle = tokio::spawn(async move {
         info!("Starting gRPC server on {}", grpc_addr);
@@ -247,7 +688,7 @@ fn maybe_start_grpc(
         }
 
         // Serve
-        let result = tonic::transport::Server::builder()
+        let result = synton_api::grpc_server_builder(&limits)
             .add_service(grpc_router)
             .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
             .await;
@@ -272,25 +713,205 @@ fn maybe_start_rest(
     }
 
     let rest_addr = format!("{}:{}", config.server.host, config.server.rest_port);
+    let limits = config.limits.to_limits();
 
     let handle = tokio::spawn(async move {
         info!("Starting REST API server on {}", rest_addr);
 
-        let state = synton_api::AppState::new(service);
+        let state = synton_api::AppState::with_limits(service, &limits);
 
         let app = axum::Router::new()
             .route("/health", axum::routing::get(synton_api::rest::health_check))
             .route("/stats", axum::routing::get(synton_api::rest::stats))
+            .route("/memory/stats", axum::routing::get(synton_api::rest::memory_stats))
+            .route("/memory/expiring", axum::routing::get(synton_api::rest::expiring_nodes))
+            .route("/activity", axum::routing::get(synton_api::rest::activity))
+            .merge(synton_api::rest::diagnostics_routes())
             .route("/nodes", axum::routing::post(synton_api::rest::add_node))
             .route("/nodes", axum::routing::get(synton_api::rest::get_all_nodes))
             .route("/nodes/:id", axum::routing::get(synton_api::rest::get_node))
             .route("/nodes/:id", axum::routing::delete(synton_api::rest::delete_node))
+            .route("/nodes/:id", axum::routing::patch(synton_api::rest::update_node))
+            .route("/nodes/update", axum::routing::post(synton_api::rest::update_nodes))
+            .route("/nodes/top-accessed", axum::routing::get(synton_api::rest::top_accessed))
+            .route("/nodes/:id/usage", axum::routing::get(synton_api::rest::node_usage))
+            .route("/nodes/:id/inspect", axum::routing::get(synton_api::rest::inspect_node))
+            .route(
+                "/nodes/:id/attachments",
+                axum::routing::post(synton_api::rest::attach_file).layer(
+                    axum::extract::DefaultBodyLimit::max(synton_api::MAX_ATTACHMENT_UPLOAD_BYTES),
+                ),
+            )
+            .route(
+                "/nodes/:id/attachments",
+                axum::routing::get(synton_api::rest::list_attachments),
+            )
+            .route(
+                "/nodes/:id/attachments/:attachment_id",
+                axum::routing::get(synton_api::rest::get_attachment)
+                    .delete(synton_api::rest::delete_attachment),
+            )
+            .route("/nodes/:id/tags", axum::routing::post(synton_api::rest::tag_node))
+            .route(
+                "/nodes/:id/tags/:tag",
+                axum::routing::delete(synton_api::rest::untag_node),
+            )
+            .route(
+                "/nodes/by-external-id/:external_id",
+                axum::routing::get(synton_api::rest::get_node_by_external_id),
+            )
             .route("/edges", axum::routing::post(synton_api::rest::add_edge))
+            .route("/review/edges", axum::routing::get(synton_api::rest::review_edges))
+            .route(
+                "/review/edges/approve",
+                axum::routing::post(synton_api::rest::approve_edge),
+            )
+            .route(
+                "/review/edges/reject",
+                axum::routing::post(synton_api::rest::reject_edge),
+            )
             .route("/query", axum::routing::post(synton_api::rest::query))
+            .route("/query/prepare", axum::routing::post(synton_api::rest::prepare_query))
+            .route("/query/execute", axum::routing::post(synton_api::rest::execute_query))
+            .route(
+                "/query/structured",
+                axum::routing::post(synton_api::rest::structured_query),
+            )
             .route("/traverse", axum::routing::post(synton_api::rest::traverse))
+            .route("/traverse/explain", axum::routing::post(synton_api::rest::explain_traverse))
+            .route("/views", axum::routing::get(synton_api::rest::list_views))
+            .route("/views", axum::routing::post(synton_api::rest::create_view))
+            .route("/views/:name", axum::routing::get(synton_api::rest::get_view))
+            .route("/views/:name", axum::routing::delete(synton_api::rest::delete_view))
+            .route("/reason/paths", axum::routing::post(synton_api::rest::reason_paths))
+            .route("/graph/match", axum::routing::post(synton_api::rest::match_pattern))
+            .route("/graph/viz", axum::routing::get(synton_api::rest::graph_viz))
             .route("/hybrid_search", axum::routing::post(synton_api::rest::hybrid_search))
+            .route("/rag/feedback", axum::routing::post(synton_api::rest::submit_feedback))
+            .route(
+                "/rag/feedback/stats",
+                axum::routing::get(synton_api::rest::feedback_stats),
+            )
             .route("/bulk", axum::routing::post(synton_api::rest::bulk_operation))
+            .route("/transactions", axum::routing::post(synton_api::rest::apply_transaction))
+            .route("/documents", axum::routing::post(synton_api::rest::ingest_document))
+            .route("/documents", axum::routing::get(synton_api::rest::list_documents))
+            .route("/documents/:id", axum::routing::get(synton_api::rest::get_document))
+            .route("/ingest/url", axum::routing::post(synton_api::rest::ingest_url))
+            .route(
+                "/ingest/file",
+                axum::routing::post(synton_api::rest::ingest_file).layer(
+                    axum::extract::DefaultBodyLimit::max(synton_ingest::MAX_DOCUMENT_BYTES),
+                ),
+            )
+            .route("/pipelines", axum::routing::get(synton_api::rest::list_pipelines))
+            .route(
+                "/pipelines/:name/run",
+                axum::routing::post(synton_api::rest::run_pipeline),
+            )
+            .route(
+                "/pipelines/:name/runs",
+                axum::routing::get(synton_api::rest::pipeline_runs),
+            )
+            .route(
+                "/admin/vector/optimize",
+                axum::routing::post(synton_api::rest::optimize_vector_index),
+            )
+            .route(
+                "/admin/vector/calibrate",
+                axum::routing::post(synton_api::rest::calibrate_vectors),
+            )
+            .route(
+                "/admin/vector/evaluate",
+                axum::routing::post(synton_api::rest::evaluate_vectors),
+            )
+            .route(
+                "/admin/vector/delta",
+                axum::routing::get(synton_api::rest::export_vector_delta),
+            )
+            .route(
+                "/admin/vector/delta",
+                axum::routing::post(synton_api::rest::apply_vector_delta).layer(
+                    axum::extract::DefaultBodyLimit::max(synton_api::MAX_IMPORT_BYTES),
+                ),
+            )
+            .route("/admin/storage/stats", axum::routing::get(synton_api::rest::storage_stats))
+            .route("/admin/self-check", axum::routing::get(synton_api::rest::self_check))
+            .route(
+                "/admin/embeddings/reembed",
+                axum::routing::get(synton_api::rest::reembed_status),
+            )
+            .route(
+                "/admin/storage/compact",
+                axum::routing::post(synton_api::rest::compact_storage),
+            )
+            .route(
+                "/admin/storage/migrate",
+                axum::routing::post(synton_api::rest::migrate_storage),
+            )
+            .route(
+                "/admin/seed",
+                axum::routing::post(synton_api::rest::seed).delete(synton_api::rest::unseed),
+            )
+            .route(
+                "/admin/storage/integrity",
+                axum::routing::post(synton_api::rest::check_integrity),
+            )
+            .route("/admin/reindex", axum::routing::post(synton_api::rest::reindex))
+            .route("/admin/limits", axum::routing::get(synton_api::rest::limits_stats))
+            .route("/admin/audit", axum::routing::get(synton_api::rest::audit_events))
+            .route("/admin/usage", axum::routing::get(synton_api::rest::usage_export))
+            .route("/jobs", axum::routing::get(synton_api::rest::list_jobs))
+            .route("/jobs/:id", axum::routing::get(synton_api::rest::get_job))
+            .route("/export", axum::routing::get(synton_api::rest::export_archive))
+            .route(
+                "/import",
+                axum::routing::post(synton_api::rest::import_archive).layer(
+                    axum::extract::DefaultBodyLimit::max(synton_api::MAX_IMPORT_BYTES),
+                ),
+            )
+            .route(
+                "/v1/files",
+                axum::routing::post(synton_api::rest::upload_file).layer(
+                    axum::extract::DefaultBodyLimit::max(synton_ingest::MAX_DOCUMENT_BYTES),
+                ),
+            )
+            .route("/v1/files/:file_id", axum::routing::delete(synton_api::rest::delete_file))
+            .route(
+                "/v1/vector_stores",
+                axum::routing::post(synton_api::rest::create_vector_store),
+            )
+            .route("/v1/vector_stores", axum::routing::get(synton_api::rest::list_vector_stores))
+            .route("/v1/vector_stores/:id", axum::routing::get(synton_api::rest::get_vector_store))
+            .route(
+                "/v1/vector_stores/:id",
+                axum::routing::delete(synton_api::rest::delete_vector_store),
+            )
+            .route(
+                "/v1/vector_stores/:id/files",
+                axum::routing::post(synton_api::rest::create_vector_store_file),
+            )
+            .route(
+                "/v1/vector_stores/:id/files",
+                axum::routing::get(synton_api::rest::list_vector_store_files),
+            )
+            .route(
+                "/v1/vector_stores/:id/search",
+                axum::routing::post(synton_api::rest::search_vector_store),
+            )
+            .route("/openapi.json", axum::routing::get(synton_api::rest::openapi_json))
+            .nest("/api/v1/instr", synton_api::create_instrument_router())
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                synton_api::limit_middleware,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                synton_api::metering_middleware,
+            ))
             .with_state(state)
+            .layer(axum::middleware::from_fn(synton_api::actor_middleware))
+            .layer(axum::middleware::from_fn(synton_api::trace_context_middleware))
             .layer(
                 tower_http::cors::CorsLayer::new()
                     .allow_origin(tower_http::cors::Any)
@@ -6,6 +6,7 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
+use synton_ml::EmbeddingService;
 
 use crate::{
     chunk::{Chunk, ChunkMetadata, ChunkType},
@@ -31,6 +32,14 @@ pub struct SemanticChunkConfig {
 
     /// Number of sentences to consider when calculating similarity.
     pub window_size: usize,
+
+    /// Minimum cosine drift (1 - similarity) between the embeddings of
+    /// adjacent windows for a topic shift (0.0-1.0). Only used in
+    /// embedding mode.
+    pub drift_threshold: f32,
+
+    /// Number of sentences per sliding window in embedding mode.
+    pub drift_window: usize,
 }
 
 impl Default for SemanticChunkConfig {
@@ -41,6 +50,8 @@ impl Default for SemanticChunkConfig {
             min_chunk_size: 100,
             boundary_threshold: 0.3,
             window_size: 2,
+            drift_threshold: 0.15,
+            drift_window: 3,
         }
     }
 }
@@ -53,6 +64,8 @@ impl SemanticChunkConfig {
             min_chunk_size: max_chunk_size / 10,
             boundary_threshold,
             window_size: 2,
+            drift_threshold: 0.15,
+            drift_window: 3,
             base: ChunkingConfig {
                 max_chunk_size,
                 min_chunk_size: max_chunk_size / 10,
@@ -62,6 +75,13 @@ impl SemanticChunkConfig {
         }
     }
 
+    /// Set the drift threshold and window used in embedding mode.
+    pub fn with_drift(mut self, threshold: f32, window: usize) -> Self {
+        self.drift_threshold = threshold;
+        self.drift_window = window;
+        self
+    }
+
     /// Validate the configuration.
     pub fn validate(&self) -> Result<()> {
         if self.max_chunk_size == 0 {
@@ -79,20 +99,39 @@ impl SemanticChunkConfig {
                 "boundary_threshold must be between 0.0 and 1.0".to_string(),
             ));
         }
+        if !(0.0..=1.0).contains(&self.drift_threshold) {
+            return Err(ChunkingError::ConfigError(
+                "drift_threshold must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+        if self.drift_window == 0 {
+            return Err(ChunkingError::ConfigError(
+                "drift_window must be greater than 0".to_string(),
+            ));
+        }
         Ok(())
     }
 }
 
 /// Semantic chunking strategy.
 ///
-/// Splits text at semantic boundaries by analyzing embedding similarity
-/// between adjacent sentences. Creates chunks where content is semantically
-/// coherent.
-#[derive(Debug, Clone)]
+/// Splits text at semantic boundaries. By default boundaries come from word
+/// overlap between adjacent sentence windows; with an embedding service
+/// attached, sentences are embedded and chunks are cut where the topic
+/// drifts most (see [`SemanticChunkConfig::drift_threshold`]).
+#[derive(Clone)]
 pub struct SemanticChunker {
     config: SemanticChunkConfig,
-    // Note: In production, this would use an embedding backend
-    // For now, we use a simple heuristic approach
+    embedding: Option<Arc<EmbeddingService>>,
+}
+
+impl std::fmt::Debug for SemanticChunker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SemanticChunker")
+            .field("config", &self.config)
+            .field("embedding", &self.embedding.is_some())
+            .finish()
+    }
 }
 
 impl SemanticChunker {
@@ -100,13 +139,55 @@ impl SemanticChunker {
     pub fn new() -> Self {
         Self {
             config: SemanticChunkConfig::default(),
+            embedding: None,
         }
     }
 
     /// Create a new semantic chunker with custom configuration.
     pub fn with_config(config: SemanticChunkConfig) -> Result<Self> {
         config.validate()?;
-        Ok(Self { config })
+        Ok(Self {
+            config,
+            embedding: None,
+        })
+    }
+
+    /// Detect boundaries from sentence embeddings instead of word overlap.
+    pub fn with_embedding(mut self, embedding: Arc<EmbeddingService>) -> Self {
+        self.embedding = Some(embedding);
+        self
+    }
+
+    /// Find topic shifts in a sequence of sentence embeddings.
+    ///
+    /// For every gap between sentences, the drift is the cosine distance
+    /// between the mean embeddings of the `drift_window` sentences before
+    /// and after it. A boundary is placed at each local drift maximum that
+    /// reaches `drift_threshold`.
+    fn find_drift_boundaries(&self, embeddings: &[Vec<f32>]) -> Vec<usize> {
+        if embeddings.len() <= 1 {
+            return Vec::new();
+        }
+
+        let window = self.config.drift_window;
+        let drift: Vec<f32> = (1..embeddings.len())
+            .map(|i| {
+                let before = mean_embedding(&embeddings[i.saturating_sub(window)..i]);
+                let after = mean_embedding(&embeddings[i..(i + window).min(embeddings.len())]);
+                1.0 - cosine_similarity(&before, &after)
+            })
+            .collect();
+
+        // drift[k] is the drift at the gap before sentence k + 1
+        (0..drift.len())
+            .filter(|&k| {
+                let d = drift[k];
+                d >= self.config.drift_threshold
+                    && (k == 0 || d >= drift[k - 1])
+                    && drift.get(k + 1).map_or(true, |&next| d > next)
+            })
+            .map(|k| k + 1)
+            .collect()
     }
 
     /// Calculate semantic similarity between two text segments.
@@ -288,7 +369,16 @@ impl ChunkingStrategy for SemanticChunker {
         }
 
         // Find semantic boundaries
-        let boundaries = self.find_boundaries(&sentences);
+        let boundaries = match &self.embedding {
+            Some(embedding) => {
+                let embeddings = embedding
+                    .embed_batch(&sentences)
+                    .await
+                    .map_err(|e| ChunkingError::EmbeddingFailed(e.to_string()))?;
+                self.find_drift_boundaries(&embeddings)
+            }
+            None => self.find_boundaries(&sentences),
+        };
 
         // Group sentences into chunks
         let chunks = self.group_into_chunks(&sentences, &boundaries)?;
@@ -305,6 +395,36 @@ impl ChunkingStrategy for SemanticChunker {
     }
 }
 
+/// Element-wise mean of a non-empty set of embeddings.
+fn mean_embedding(embeddings: &[Vec<f32>]) -> Vec<f32> {
+    let mut mean = vec![0.0; embeddings.first().map_or(0, Vec::len)];
+    for embedding in embeddings {
+        for (m, x) in mean.iter_mut().zip(embedding) {
+            *m += x;
+        }
+    }
+    let n = embeddings.len().max(1) as f32;
+    mean.iter_mut().for_each(|m| *m /= n);
+    mean
+}
+
+/// Calculate cosine similarity between two vectors.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -357,4 +477,35 @@ mod tests {
         let sim3 = chunker.calculate_similarity("cat dog mouse", "cat bird fish");
         assert!(sim3 > 0.0 && sim3 < 1.0);
     }
+
+    #[test]
+    fn test_drift_boundaries() {
+        let chunker = SemanticChunker::with_config(
+            SemanticChunkConfig::new(200, 0.3).with_drift(0.3, 2)
+        ).unwrap();
+
+        // Two topics: sentences 0-2 point one way, 3-5 another
+        let embeddings = vec![
+            vec![1.0, 0.0],
+            vec![0.9, 0.1],
+            vec![1.0, 0.1],
+            vec![0.0, 1.0],
+            vec![0.1, 0.9],
+            vec![0.1, 1.0],
+        ];
+        assert_eq!(chunker.find_drift_boundaries(&embeddings), vec![3]);
+
+        // A single topic has no boundary
+        let flat = vec![vec![1.0, 0.0]; 5];
+        assert!(chunker.find_drift_boundaries(&flat).is_empty());
+    }
+
+    #[test]
+    fn test_drift_config_validation() {
+        let config = SemanticChunkConfig::default().with_drift(1.5, 3);
+        assert!(config.validate().is_err());
+
+        let config = SemanticChunkConfig::default().with_drift(0.2, 0);
+        assert!(config.validate().is_err());
+    }
 }
@@ -4,12 +4,38 @@
 
 //! HTTP client for SYNTON-DB REST API.
 
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use reqwest::Client;
 use serde::de::DeserializeOwned;
 use uuid::Uuid;
 
-use synton_core::{Edge, Node, NodeType, Relation};
+use synton_api::{
+    CalibrateVectorRequest, EvaluateVectorRequest, ExportHeader, ExportRecord, ImportResponse,
+    Job, MigrateStorageResponse, NodeTagsResponse, ReviewEdgeRequest, ReviewEdgeResponse,
+    ReviewEdgesResponse, SeedRequest, SeedResponse, SelfCheckResponse, TagNodeRequest,
+    TransactionRequest, TransactionResponse, UnseedResponse, UpdateNodeRequest,
+    UpdateNodeResponse,
+    ApplyVectorDeltaResponse, VectorCalibration, VectorEvaluation, EXPORT_CONTENT_TYPE,
+    VECTOR_CHECKPOINT_HEADER,
+};
+use synton_core::{Edge, EdgeProvenance, Node, NodeType, Relation};
+use synton_error::{ErrorBody, ErrorCode};
+
+/// Timeout of archive exports and imports, which move the whole database.
+const ARCHIVE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Percent-encode a URL path segment or query value.
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
 
 /// API response wrapper
 #[derive(Debug)]
@@ -18,6 +44,14 @@ pub struct ApiResponse<T> {
     pub status: u16,
 }
 
+/// Error response returned by the server.
+#[derive(Debug, thiserror::Error)]
+#[error("{}", .body.report())]
+pub struct ServerError {
+    pub status: u16,
+    pub body: ErrorBody,
+}
+
 /// HTTP client for SYNTON-DB REST API.
 pub struct SyntonClient {
     base_url: String,
@@ -35,18 +69,40 @@ impl SyntonClient {
         Self { base_url, client }
     }
 
+    /// The server's base URL.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
     /// Get the full URL for an endpoint.
     fn url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
 
+    /// Decode a response, turning error statuses into [`ServerError`].
+    async fn read<T: DeserializeOwned>(response: reqwest::Response) -> Result<ApiResponse<T>> {
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            let body = serde_json::from_str::<ErrorBody>(&text).unwrap_or_else(|_| ErrorBody {
+                error: format!("{} {}", status, text.trim()),
+                code: ErrorCode::Internal,
+                causes: Vec::new(),
+                trace_id: None,
+                fields: Vec::new(),
+                current_version: None,
+            });
+            return Err(ServerError { status: status.as_u16(), body }.into());
+        }
+        let data = response.json().await?;
+        Ok(ApiResponse { data, status: status.as_u16() })
+    }
+
     /// Send a GET request.
     async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<ApiResponse<T>> {
         let url = self.url(path);
         let response = self.client.get(&url).send().await?;
-        let status = response.status().as_u16();
-        let data = response.json().await?;
-        Ok(ApiResponse { data, status })
+        Self::read(response).await
     }
 
     /// Send a POST request.
@@ -57,18 +113,25 @@ impl SyntonClient {
     ) -> Result<ApiResponse<T>> {
         let url = self.url(path);
         let response = self.client.post(&url).json(body).send().await?;
-        let status = response.status().as_u16();
-        let data = response.json().await?;
-        Ok(ApiResponse { data, status })
+        Self::read(response).await
+    }
+
+    /// Send a PATCH request.
+    async fn patch<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<ApiResponse<T>> {
+        let url = self.url(path);
+        let response = self.client.patch(&url).json(body).send().await?;
+        Self::read(response).await
     }
 
     /// Send a DELETE request.
     async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<ApiResponse<T>> {
         let url = self.url(path);
         let response = self.client.delete(&url).send().await?;
-        let status = response.status().as_u16();
-        let data = response.json().await?;
-        Ok(ApiResponse { data, status })
+        Self::read(response).await
     }
 
     /// Check health of the server.
@@ -77,23 +140,48 @@ impl SyntonClient {
         Ok(resp.data)
     }
 
+    /// Check the server's embedding backend and vector index, or `None`
+    /// if the server predates the self-check.
+    pub async fn self_check(&self) -> Result<Option<SelfCheckResponse>> {
+        match self.get::<SelfCheckResponse>("/admin/self-check").await {
+            Ok(resp) => Ok(Some(resp.data)),
+            Err(e) if e.downcast_ref::<ServerError>().map_or(false, |e| e.status == 404) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Get database statistics.
     pub async fn stats(&self) -> Result<StatsResponse> {
         let resp = self.get::<StatsResponse>("/stats").await?;
         Ok(resp.data)
     }
 
+    /// Get recent query activity and embedding cache statistics.
+    pub async fn activity(&self, limit: usize) -> Result<ActivityResponse> {
+        let resp = self
+            .get::<ActivityResponse>(&format!("/activity?limit={}", limit))
+            .await?;
+        Ok(resp.data)
+    }
+
     /// Create a new node.
-    pub async fn create_node(&self, content: String, node_type: NodeType) -> Result<Node> {
+    pub async fn create_node(
+        &self,
+        content: String,
+        node_type: NodeType,
+        tags: &[String],
+    ) -> Result<Node> {
         #[derive(serde::Serialize)]
-        struct AddNodeRequest {
+        struct AddNodeRequest<'a> {
             content: String,
             node_type: String,
+            tags: &'a [String],
         }
 
         let body = AddNodeRequest {
             content,
             node_type: format!("{:?}", node_type),
+            tags,
         };
 
         let resp = self.post::<serde_json::Value, _>("/nodes", &body).await?;
@@ -112,10 +200,11 @@ impl SyntonClient {
             };
 
             // Create node using NodeBuilder
-            let built_node = synton_core::NodeBuilder::new(content, node_type)
-                .id(Uuid::parse_str(&id)?)
-                .build()?;
-            Ok(built_node)
+            let mut builder = synton_core::NodeBuilder::new(content, node_type).id(Uuid::parse_str(&id)?);
+            for tag in node["tags"].as_array().into_iter().flatten().filter_map(|t| t.as_str()) {
+                builder = builder.tag(tag);
+            }
+            Ok(builder.build()?)
         } else {
             anyhow::bail!("Invalid response format");
         }
@@ -123,11 +212,12 @@ impl SyntonClient {
 
     /// Get a node by ID.
     pub async fn get_node(&self, id: Uuid) -> Result<Option<Node>> {
-        let resp = self.get::<serde_json::Value>(&format!("/nodes/{}", id)).await?;
-
-        if resp.status == 404 {
-            return Ok(None);
-        }
+        let resp = match self.get::<serde_json::Value>(&format!("/nodes/{}", id)).await {
+            Err(e) if e.downcast_ref::<ServerError>().map_or(false, |e| e.status == 404) => {
+                return Ok(None)
+            }
+            other => other?,
+        };
 
         if let Some(node) = resp.data.get("node") {
             if node.is_null() {
@@ -153,6 +243,142 @@ impl SyntonClient {
         Ok(None)
     }
 
+    /// Stream an export archive of the database into `out`, returning the
+    /// archive header.
+    pub async fn export_archive(
+        &self,
+        include_embeddings: bool,
+        out: &mut impl std::io::Write,
+    ) -> Result<ExportHeader> {
+        let url = self.url(&format!("/export?include_embeddings={}", include_embeddings));
+        let mut response = self.client.get(&url).timeout(ARCHIVE_TIMEOUT).send().await?;
+        if !response.status().is_success() {
+            Self::read::<serde_json::Value>(response).await?;
+            anyhow::bail!("Export failed");
+        }
+
+        // The header is the first line of the archive
+        let mut first_line = Vec::new();
+        let mut header = None;
+        while let Some(chunk) = response.chunk().await? {
+            out.write_all(&chunk)?;
+            if header.is_none() {
+                first_line.extend_from_slice(&chunk);
+                if let Some(end) = first_line.iter().position(|&b| b == b'\n') {
+                    header = match serde_json::from_slice(&first_line[..end])? {
+                        ExportRecord::Header(header) => Some(header),
+                        _ => anyhow::bail!("Archive does not start with a header"),
+                    };
+                }
+            }
+        }
+        out.flush()?;
+        header.ok_or_else(|| anyhow::anyhow!("Archive does not start with a header"))
+    }
+
+    /// Restore an export archive.
+    pub async fn import_archive(&self, archive: String) -> Result<ImportResponse> {
+        let url = self.url("/import");
+        let response = self
+            .client
+            .post(&url)
+            .header(reqwest::header::CONTENT_TYPE, EXPORT_CONTENT_TYPE)
+            .body(archive)
+            .timeout(ARCHIVE_TIMEOUT)
+            .send()
+            .await?;
+        Ok(Self::read::<ImportResponse>(response).await?.data)
+    }
+
+    /// Calibrate the similarity threshold of the vector index.
+    pub async fn calibrate_vectors(&self, request: &CalibrateVectorRequest) -> Result<VectorCalibration> {
+        Ok(self.post("/admin/vector/calibrate", request).await?.data)
+    }
+
+    /// Evaluate the recall of the vector index.
+    pub async fn evaluate_vectors(&self, request: &EvaluateVectorRequest) -> Result<VectorEvaluation> {
+        Ok(self.post("/admin/vector/evaluate", request).await?.data)
+    }
+
+    /// Export the changes of the vector index since a checkpoint, or every
+    /// vector without one, returning the delta and the checkpoint it reaches.
+    pub async fn export_vector_delta(&self, since: Option<&str>) -> Result<(Vec<u8>, String)> {
+        let mut path = "/admin/vector/delta".to_string();
+        if let Some(since) = since {
+            path.push_str(&format!("?since={}", encode_component(since)));
+        }
+        let url = self.url(&path);
+        let response = self.client.get(&url).timeout(ARCHIVE_TIMEOUT).send().await?;
+        if !response.status().is_success() {
+            Self::read::<serde_json::Value>(response).await?;
+            anyhow::bail!("Vector delta export failed");
+        }
+
+        let checkpoint = response
+            .headers()
+            .get(VECTOR_CHECKPOINT_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Vector delta has no checkpoint"))?;
+        Ok((response.bytes().await?.to_vec(), checkpoint))
+    }
+
+    /// Apply a vector delta to the vector index.
+    pub async fn apply_vector_delta(&self, delta: Vec<u8>) -> Result<ApplyVectorDeltaResponse> {
+        let url = self.url("/admin/vector/delta");
+        let response = self
+            .client
+            .post(&url)
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(delta)
+            .timeout(ARCHIVE_TIMEOUT)
+            .send()
+            .await?;
+        Ok(Self::read::<ApplyVectorDeltaResponse>(response).await?.data)
+    }
+
+    /// Rewrite stored values written in an older format version.
+    pub async fn migrate_storage(&self) -> Result<MigrateStorageResponse> {
+        Ok(self
+            .post("/admin/storage/migrate", &serde_json::json!({}))
+            .await?
+            .data)
+    }
+
+    /// Seed an ontology into a namespace.
+    pub async fn seed(&self, request: &SeedRequest) -> Result<SeedResponse> {
+        Ok(self.post("/admin/seed", request).await?.data)
+    }
+
+    /// Remove the nodes seeded into a namespace.
+    pub async fn unseed(&self, namespace: Option<&str>) -> Result<UnseedResponse> {
+        let mut request = self.client.delete(self.url("/admin/seed"));
+        if let Some(namespace) = namespace {
+            request = request.query(&[("namespace", namespace)]);
+        }
+        Ok(Self::read(request.send().await?).await?.data)
+    }
+
+    /// Enqueue a rebuild of the server's in-memory state from storage.
+    pub async fn reindex(&self) -> Result<Job> {
+        Ok(self.post("/admin/reindex", &serde_json::json!({})).await?.data)
+    }
+
+    /// Get a background job by ID.
+    pub async fn job(&self, id: Uuid) -> Result<Job> {
+        Ok(self.get(&format!("/jobs/{}", id)).await?.data)
+    }
+
+    /// Inspect a node: its memory state, edges, similar nodes and usage.
+    pub async fn inspect_node(&self, id: Uuid, similar: usize) -> Result<Option<NodeInspection>> {
+        let path = format!("/nodes/{}/inspect?similar={}", id, similar);
+        match self.get::<NodeInspection>(&path).await {
+            Ok(resp) => Ok(Some(resp.data)),
+            Err(e) if e.downcast_ref::<ServerError>().map_or(false, |e| e.status == 404) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Delete a node by ID.
     pub async fn delete_node(&self, id: Uuid) -> Result<bool> {
         #[derive(serde::Deserialize)]
@@ -164,12 +390,37 @@ impl SyntonClient {
         Ok(resp.data.deleted)
     }
 
-    /// List all nodes.
-    pub async fn list_nodes(&self) -> Result<Vec<Node>> {
-        let resp = self.get::<Vec<Node>>("/nodes").await?;
+    /// List all nodes, or only those carrying a tag.
+    pub async fn list_nodes(&self, tag: Option<&str>) -> Result<Vec<Node>> {
+        let path = match tag {
+            Some(tag) => format!("/nodes?tag={}", encode_component(tag)),
+            None => "/nodes".to_string(),
+        };
+        let resp = self.get::<Vec<Node>>(&path).await?;
         Ok(resp.data)
     }
 
+    /// Add tags to a node.
+    pub async fn tag_node(&self, id: Uuid, tags: Vec<String>) -> Result<NodeTagsResponse> {
+        let body = TagNodeRequest { tags };
+        Ok(self.post(&format!("/nodes/{}/tags", id), &body).await?.data)
+    }
+
+    /// Remove a tag from a node.
+    pub async fn untag_node(&self, id: Uuid, tag: &str) -> Result<NodeTagsResponse> {
+        let path = format!("/nodes/{}/tags/{}", id, encode_component(tag));
+        Ok(self.delete(&path).await?.data)
+    }
+
+    /// Pin a node, exempting it from decay and pruning, or unpin it.
+    pub async fn pin_node(&self, id: Uuid, pinned: bool) -> Result<Node> {
+        let body = UpdateNodeRequest::new(id).with_pinned(pinned);
+        let resp = self
+            .patch::<UpdateNodeResponse, _>(&format!("/nodes/{}", id), &body)
+            .await?;
+        Ok(resp.data.node)
+    }
+
     /// Create a new edge.
     pub async fn create_edge(
         &self,
@@ -177,6 +428,7 @@ impl SyntonClient {
         target: Uuid,
         relation: Relation,
         weight: f32,
+        provenance: EdgeProvenance,
     ) -> Result<Edge> {
         #[derive(serde::Serialize)]
         struct AddEdgeRequest {
@@ -184,6 +436,7 @@ impl SyntonClient {
             target: String,
             relation: String,
             weight: f32,
+            provenance: EdgeProvenance,
         }
 
         let body = AddEdgeRequest {
@@ -191,6 +444,7 @@ impl SyntonClient {
             target: target.to_string(),
             relation: relation.to_string(),
             weight,
+            provenance,
         };
 
         let resp = self.post::<serde_json::Value, _>("/edges", &body).await?;
@@ -211,27 +465,86 @@ impl SyntonClient {
                 _ => Relation::SimilarTo,
             };
             let weight = edge["weight"].as_f64().unwrap_or(1.0) as f32;
+            let provenance = edge
+                .get("provenance")
+                .and_then(|p| serde_json::from_value(p.clone()).ok())
+                .unwrap_or_default();
 
-            Ok(synton_core::EdgeBuilder::new(source, target, relation)
+            let mut built = synton_core::EdgeBuilder::new(source, target, relation)
                 .weight(weight)
-                .build()?)
+                .provenance(provenance)
+                .build()?;
+            built.pending_review = edge["pending_review"].as_bool().unwrap_or(false);
+            Ok(built)
         } else {
             anyhow::bail!("Invalid response format");
         }
     }
 
+    /// Apply node and edge operations atomically.
+    pub async fn apply_transaction(&self, request: &TransactionRequest) -> Result<TransactionResponse> {
+        Ok(self.post::<TransactionResponse, _>("/transactions", request).await?.data)
+    }
+
+    /// List the edges awaiting review.
+    pub async fn review_edges(&self) -> Result<ReviewEdgesResponse> {
+        Ok(self.get("/review/edges").await?.data)
+    }
+
+    /// Approve a pending edge, adding it to the graph.
+    pub async fn approve_edge(&self, source: Uuid, target: Uuid, relation: Relation) -> Result<ReviewEdgeResponse> {
+        let body = ReviewEdgeRequest { source, target, relation };
+        Ok(self.post("/review/edges/approve", &body).await?.data)
+    }
+
+    /// Reject a pending edge, deleting it.
+    pub async fn reject_edge(&self, source: Uuid, target: Uuid, relation: Relation) -> Result<ReviewEdgeResponse> {
+        let body = ReviewEdgeRequest { source, target, relation };
+        Ok(self.post("/review/edges/reject", &body).await?.data)
+    }
+
     /// Execute a query.
-    pub async fn query(&self, query: String, limit: Option<usize>) -> Result<QueryResponse> {
+    pub async fn query(
+        &self,
+        query: String,
+        limit: Option<usize>,
+        include_metadata: bool,
+    ) -> Result<QueryResponse> {
         #[derive(serde::Serialize)]
         struct QueryRequest {
             query: String,
             limit: Option<usize>,
+            include_metadata: bool,
         }
 
-        let body = QueryRequest { query, limit };
+        let body = QueryRequest {
+            query,
+            limit,
+            include_metadata,
+        };
         let resp = self.post::<QueryResponse, _>("/query", &body).await?;
         Ok(resp.data)
     }
+
+    /// Traverse forward from a node, returning the number of nodes reached.
+    pub async fn traverse(&self, start: Uuid, max_depth: usize, max_nodes: usize) -> Result<usize> {
+        #[derive(serde::Serialize)]
+        struct TraverseRequest {
+            start_id: Uuid,
+            max_depth: usize,
+            max_nodes: usize,
+            direction: &'static str,
+        }
+
+        let body = TraverseRequest {
+            start_id: start,
+            max_depth,
+            max_nodes,
+            direction: "Forward",
+        };
+        let resp = self.post::<serde_json::Value, _>("/traverse", &body).await?;
+        Ok(resp.data["nodes"].as_array().map_or(0, Vec::len))
+    }
 }
 
 /// Health check response.
@@ -247,6 +560,110 @@ pub struct StatsResponse {
     pub node_count: u64,
     pub edge_count: u64,
     pub embedded_count: u64,
+    #[serde(default)]
+    pub nodes_by_tag: BTreeMap<String, usize>,
+    #[serde(default)]
+    pub memory_stats: Option<MemoryStatsResponse>,
+}
+
+/// Memory decay statistics.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct MemoryStatsResponse {
+    pub total_nodes: usize,
+    pub active_nodes: usize,
+    pub decayed_nodes: usize,
+    pub average_score: f32,
+    pub load_factor: f32,
+}
+
+/// Recent activity response.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ActivityResponse {
+    pub recent_queries: Vec<QueryActivity>,
+    pub embedding_cache: Option<EmbeddingCacheStats>,
+}
+
+/// A recently executed query.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct QueryActivity {
+    pub query: String,
+    pub result_count: usize,
+    pub execution_time_ms: u64,
+    pub executed_at: String,
+}
+
+/// Embedding cache statistics.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct EmbeddingCacheStats {
+    pub total_embeddings: usize,
+    pub cache_hits: usize,
+    pub cache_hit_rate: f64,
+    #[serde(default)]
+    pub persistent_cache_hits: usize,
+}
+
+/// A node with its memory state, edges, similar nodes and usage.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct NodeInspection {
+    pub node: Node,
+    pub memory: NodeMemory,
+    pub outgoing: BTreeMap<String, Vec<Edge>>,
+    pub incoming: BTreeMap<String, Vec<Edge>>,
+    pub similar: Vec<SimilarNode>,
+    pub usage: NodeUsage,
+    #[serde(default)]
+    pub attachments: Vec<AttachmentInfo>,
+}
+
+/// A file attached to an inspected node.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AttachmentInfo {
+    pub id: Uuid,
+    pub name: String,
+    pub content_type: String,
+    pub size: usize,
+    pub created_at: String,
+}
+
+/// Memory state of an inspected node.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct NodeMemory {
+    pub profile: String,
+    pub access_score: f32,
+    pub current_score: f32,
+    pub strength: f32,
+    pub accessed_at: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// A node similar to an inspected node.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SimilarNode {
+    pub node_id: Uuid,
+    pub content: String,
+    pub score: f32,
+}
+
+/// Usage statistics of a node.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct NodeUsage {
+    pub total: u64,
+    pub queries: u64,
+    pub traversals: u64,
+    pub gets: u64,
+    pub last_accessed_at: Option<String>,
+    #[serde(default)]
+    pub recent: Vec<AccessEvent>,
+}
+
+/// A recorded access of a node.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AccessEvent {
+    pub kind: String,
+    #[serde(default)]
+    pub actor: Option<String>,
+    pub accessed_at: String,
 }
 
 /// Query response.
@@ -256,4 +673,26 @@ pub struct QueryResponse {
     pub total_count: usize,
     pub execution_time_ms: u64,
     pub truncated: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches: Vec<MatchExplanation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paql: Option<String>,
+}
+
+/// Why a query result matched.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct MatchExplanation {
+    pub node_id: String,
+    pub terms: Vec<TermMatch>,
+    pub similarity: Option<f32>,
+    pub space: Option<String>,
+    pub filters: Vec<String>,
+}
+
+/// A query term found in node content, as a character range.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct TermMatch {
+    pub term: String,
+    pub start: usize,
+    pub end: usize,
 }
@@ -6,12 +6,18 @@
 
 use anyhow::Result;
 use clap::{Args, Subcommand};
+use std::collections::HashMap;
 use std::io::Read;
+use std::time::Instant;
 use uuid::Uuid;
 
 use crate::client::SyntonClient;
-use crate::output::OutputFormat;
-use synton_core::{NodeType, Relation};
+use crate::doctor::{self, CheckStatus};
+use crate::output::{DashboardGrowth, OutputFormat};
+use crate::table::{ColumnMapping, Table, TableFormat};
+use synton_api::{JobStatus, NodeRef, TransactionOp, TransactionRequest};
+use synton_bench::{BenchReport, Dataset, DatasetConfig, OperationStats, Rng};
+use synton_core::{EdgeCreator, EdgeProvenance, NodeType, Relation};
 
 /// Node commands
 #[derive(Subcommand, Debug)]
@@ -24,6 +30,10 @@ pub enum NodeCommand {
         /// Node type (entity, concept, fact, raw_chunk)
         #[arg(short, long, default_value = "concept")]
         node_type: String,
+
+        /// Tag the node (repeatable)
+        #[arg(short, long = "tag")]
+        tags: Vec<String>,
     },
 
     /// Get a node by ID
@@ -47,6 +57,51 @@ pub enum NodeCommand {
         /// Maximum number of nodes to return
         #[arg(short, long, default_value = "100")]
         limit: usize,
+
+        /// Only nodes carrying this tag
+        #[arg(short, long)]
+        tag: Option<String>,
+    },
+
+    /// Add tags to a node
+    Tag {
+        /// Node ID
+        id: String,
+
+        /// Tags to add
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+
+    /// Remove a tag from a node
+    Untag {
+        /// Node ID
+        id: String,
+
+        /// Tag to remove
+        tag: String,
+    },
+
+    /// Pin a node, exempting it from memory decay and pruning
+    Pin {
+        /// Node ID
+        id: String,
+    },
+
+    /// Unpin a node, letting it decay again
+    Unpin {
+        /// Node ID
+        id: String,
+    },
+
+    /// Show a node with its memory, edges, similar nodes and recent accesses
+    Inspect {
+        /// Node ID
+        id: String,
+
+        /// Number of similar nodes to show
+        #[arg(short, long, default_value = "5")]
+        similar: usize,
     },
 }
 
@@ -61,6 +116,14 @@ pub enum QueryCommand {
         /// Maximum number of results
         #[arg(short, long)]
         limit: Option<usize>,
+
+        /// Explain why each result matched, highlighting matched terms
+        #[arg(short, long)]
+        explain: bool,
+
+        /// Only results carrying this tag
+        #[arg(short, long)]
+        tag: Option<String>,
     },
 }
 
@@ -70,6 +133,98 @@ pub struct StatsCommand {
     /// Show detailed statistics
     #[arg(short, long)]
     pub detailed: bool,
+
+    /// Continuously refresh a live dashboard until interrupted
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Refresh interval in seconds (with --watch)
+    #[arg(short, long, default_value = "2")]
+    pub interval: u64,
+
+    /// Number of recent queries to show (with --watch)
+    #[arg(long, default_value = "10")]
+    pub recent: usize,
+}
+
+/// Bench command arguments
+#[derive(Args, Debug)]
+pub struct BenchCommand {
+    /// Number of synthetic nodes to load
+    #[arg(long, default_value = "10000")]
+    pub nodes: usize,
+
+    /// Outgoing edges per synthetic node
+    #[arg(long, default_value = "3")]
+    pub edges_per_node: usize,
+
+    /// Number of text queries to time
+    #[arg(long, default_value = "200")]
+    pub queries: usize,
+
+    /// Number of traversals to time
+    #[arg(long, default_value = "200")]
+    pub traversals: usize,
+
+    /// Traversal depth
+    #[arg(long, default_value = "3")]
+    pub depth: usize,
+
+    /// Dataset seed; the same seed loads the same content and edges
+    #[arg(long, default_value = "42")]
+    pub seed: u64,
+
+    /// Save the report as JSON for use as a later baseline
+    #[arg(long)]
+    pub save: Option<String>,
+
+    /// Compare against a saved report and fail if any operation regressed
+    #[arg(long)]
+    pub baseline: Option<String>,
+
+    /// Allowed median slowdown against the baseline, in percent
+    #[arg(long, default_value = "10")]
+    pub max_regression: f64,
+}
+
+/// Import commands
+#[derive(Subcommand, Debug)]
+pub enum ImportCommand {
+    /// Import the rows of a CSV or Parquet table as nodes
+    Table(TableImportCommand),
+}
+
+/// Table import arguments
+#[derive(Args, Debug)]
+pub struct TableImportCommand {
+    /// CSV or Parquet file
+    pub file: String,
+
+    /// Table format (csv, parquet); guessed from the file extension if omitted
+    #[arg(long)]
+    pub table_format: Option<String>,
+
+    /// Map a node field to a column as field=column (repeatable). Fields:
+    /// content (required), key (names rows as edge targets), relation and
+    /// target (create an edge per row)
+    #[arg(short, long = "map", value_parser = parse_assignment, required = true)]
+    pub maps: Vec<(String, String)>,
+
+    /// Copy a column into a node attribute as attribute=column (repeatable)
+    #[arg(short, long = "attr", value_parser = parse_assignment)]
+    pub attrs: Vec<(String, String)>,
+
+    /// Node type (entity, concept, fact, raw_chunk)
+    #[arg(short = 't', long = "type", default_value = "concept")]
+    pub node_type: String,
+
+    /// Rows per request; each batch is stored atomically and embedded together
+    #[arg(long, default_value = "256")]
+    pub batch_size: usize,
+
+    /// Continue on error
+    #[arg(long)]
+    pub continue_on_error: bool,
 }
 
 /// Edge command arguments
@@ -87,6 +242,18 @@ pub struct EdgeCreateCommand {
     /// Edge weight (0.0-1.0)
     #[arg(short, long, default_value = "1.0")]
     pub weight: f32,
+
+    /// Who created the edge (user, tool, extraction_pipeline)
+    #[arg(long, default_value = "user")]
+    pub created_by: String,
+
+    /// Document the edge was extracted from
+    #[arg(long)]
+    pub source_document: Option<String>,
+
+    /// Extraction confidence (0.0-1.0)
+    #[arg(long)]
+    pub confidence: Option<f32>,
 }
 
 /// Edge list command arguments
@@ -100,6 +267,19 @@ pub struct EdgeListCommand {
     pub limit: usize,
 }
 
+/// Edge review command arguments
+#[derive(Args, Debug)]
+pub struct EdgeReviewCommand {
+    /// Source node ID
+    pub source: String,
+
+    /// Target node ID
+    pub target: String,
+
+    /// Relation type
+    pub relation: String,
+}
+
 /// Edge commands
 #[derive(Subcommand, Debug)]
 pub enum EdgeCommand {
@@ -108,6 +288,86 @@ pub enum EdgeCommand {
 
     /// List edges for a node
     List(EdgeListCommand),
+
+    /// List edges held for review because of low extraction confidence
+    Pending,
+
+    /// Approve a pending edge, adding it to the graph
+    Approve(EdgeReviewCommand),
+
+    /// Reject a pending edge, deleting it
+    Reject(EdgeReviewCommand),
+}
+
+/// Vector index commands
+#[derive(Subcommand, Debug)]
+pub enum VectorCommand {
+    /// Derive the default similarity threshold of searches from the
+    /// score distribution of stored embeddings
+    Calibrate {
+        /// Embedded nodes sampled (server default: 1000)
+        #[arg(short, long)]
+        sample: Option<usize>,
+
+        /// Percentile of pair scores taken as the threshold (server
+        /// default: 0.95)
+        #[arg(long)]
+        percentile: Option<f32>,
+    },
+
+    /// Measure the recall@k of the vector index with its current
+    /// parameters against an exact scan of stored embeddings
+    Evaluate {
+        /// Neighbors retrieved per query (server default: 10)
+        #[arg(short)]
+        k: Option<usize>,
+
+        /// Embedded nodes used as queries (server default: 100)
+        #[arg(long)]
+        queries: Option<usize>,
+    },
+
+    /// Export the vectors changed since a checkpoint as a delta file
+    Export {
+        /// Output file
+        #[arg(short, long)]
+        output: String,
+
+        /// Checkpoint printed by the previous export or import (every
+        /// vector is exported if not specified)
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// Apply a delta file written by `vector export` to the vector index
+    Import {
+        /// Input file
+        #[arg(short, long)]
+        input: String,
+    },
+}
+
+/// Migration commands
+#[derive(Subcommand, Debug)]
+pub enum MigrateCommand {
+    /// Rewrite nodes and edges stored in an older format version in the
+    /// current one
+    Storage,
+}
+
+/// OpenAPI commands
+#[derive(Subcommand, Debug)]
+pub enum OpenapiCommand {
+    /// Write the REST API's OpenAPI document (no server needed)
+    Dump {
+        /// Output file (stdout if not specified)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// Write only the JSON Schemas of the request and response models
+        #[arg(long)]
+        schemas: bool,
+    },
 }
 
 /// Execute a node command.
@@ -119,9 +379,9 @@ pub async fn execute_node(
     let output = OutputFormat::from_str(format);
 
     match cmd {
-        NodeCommand::Create { content, node_type } => {
+        NodeCommand::Create { content, node_type, tags } => {
             let node_type = parse_node_type(&node_type)?;
-            let node = client.create_node(content, node_type).await?;
+            let node = client.create_node(content, node_type, &tags).await?;
 
             output.print_node(&node);
         }
@@ -129,10 +389,7 @@ pub async fn execute_node(
             let uuid = Uuid::parse_str(&id)?;
             match client.get_node(uuid).await? {
                 Some(node) => output.print_node(&node),
-                None => {
-                    eprintln!("Node not found: {}", id);
-                    std::process::exit(1);
-                }
+                None => anyhow::bail!("Node not found: {}", id),
             }
         }
         NodeCommand::Delete { id, force } => {
@@ -148,19 +405,41 @@ pub async fn execute_node(
                 }
             }
 
-            let deleted = client.delete_node(uuid).await?;
-            if deleted {
-                println!("Node {} deleted", id);
-            } else {
-                eprintln!("Node not found: {}", id);
-                std::process::exit(1);
+            if !client.delete_node(uuid).await? {
+                anyhow::bail!("Node not found: {}", id);
             }
+            output.print_deleted(uuid);
         }
-        NodeCommand::List { limit } => {
-            let nodes = client.list_nodes().await?;
+        NodeCommand::List { limit, tag } => {
+            let nodes = client.list_nodes(tag.as_deref()).await?;
             let nodes: Vec<_> = nodes.into_iter().take(limit).collect();
             output.print_nodes(&nodes);
         }
+        NodeCommand::Tag { id, tags } => {
+            let uuid = Uuid::parse_str(&id)?;
+            let response = client.tag_node(uuid, tags).await?;
+            output.print_node_tags(&response);
+        }
+        NodeCommand::Untag { id, tag } => {
+            let uuid = Uuid::parse_str(&id)?;
+            let response = client.untag_node(uuid, &tag).await?;
+            output.print_node_tags(&response);
+        }
+        NodeCommand::Pin { id } => {
+            let uuid = Uuid::parse_str(&id)?;
+            output.print_node(&client.pin_node(uuid, true).await?);
+        }
+        NodeCommand::Unpin { id } => {
+            let uuid = Uuid::parse_str(&id)?;
+            output.print_node(&client.pin_node(uuid, false).await?);
+        }
+        NodeCommand::Inspect { id, similar } => {
+            let uuid = Uuid::parse_str(&id)?;
+            match client.inspect_node(uuid, similar).await? {
+                Some(inspection) => output.print_inspection(&inspection),
+                None => anyhow::bail!("Node not found: {}", id),
+            }
+        }
     }
 
     Ok(())
@@ -180,16 +459,43 @@ pub async fn execute_edge(
             let target_uuid = Uuid::parse_str(&args.target)?;
             let relation = parse_relation(&args.relation)?;
 
+            let mut provenance = EdgeProvenance::new(args.created_by.parse::<EdgeCreator>()?);
+            if let Some(document) = &args.source_document {
+                provenance = provenance.with_source_document(Uuid::parse_str(document)?);
+            }
+            if let Some(confidence) = args.confidence {
+                provenance = provenance.with_confidence(confidence);
+            }
+
             let edge = client
-                .create_edge(source_uuid, target_uuid, relation, args.weight)
+                .create_edge(source_uuid, target_uuid, relation, args.weight, provenance)
                 .await?;
             output.print_edge(&edge);
         }
         EdgeCommand::List(args) => {
             // Note: This endpoint isn't fully implemented in the current API
-            eprintln!("Edge listing not yet implemented in REST API");
-            eprintln!("Use gRPC client for edge traversal");
-            eprintln!("Node ID: {}", args.id);
+            anyhow::bail!(
+                "Edge listing not yet implemented in REST API; use the gRPC client for node {}",
+                args.id
+            );
+        }
+        EdgeCommand::Pending => {
+            let response = client.review_edges().await?;
+            output.print_pending_edges(&response.edges);
+        }
+        EdgeCommand::Approve(args) => {
+            let source = Uuid::parse_str(&args.source)?;
+            let target = Uuid::parse_str(&args.target)?;
+            let relation = parse_relation(&args.relation)?;
+            let response = client.approve_edge(source, target, relation).await?;
+            output.print_edge_review(&response);
+        }
+        EdgeCommand::Reject(args) => {
+            let source = Uuid::parse_str(&args.source)?;
+            let target = Uuid::parse_str(&args.target)?;
+            let relation = parse_relation(&args.relation)?;
+            let response = client.reject_edge(source, target, relation).await?;
+            output.print_edge_review(&response);
         }
     }
 
@@ -205,8 +511,17 @@ pub async fn execute_query(
     let output = OutputFormat::from_str(format);
 
     match cmd {
-        QueryCommand::Execute { query, limit } => {
-            let response = client.query(query, limit).await?;
+        QueryCommand::Execute {
+            query,
+            limit,
+            explain,
+            tag,
+        } => {
+            let query = match tag {
+                Some(tag) => format!("{} | filter tag = {:?}", query, tag),
+                None => query,
+            };
+            let response = client.query(query, limit, explain).await?;
             output.print_query_response(&response);
         }
     }
@@ -222,19 +537,205 @@ pub async fn execute_stats(
 ) -> Result<()> {
     let output = OutputFormat::from_str(format);
 
+    if cmd.watch {
+        return watch_stats(&cmd, &client, &output).await;
+    }
+
     let stats = client.stats().await?;
     output.print_stats(&stats, cmd.detailed);
 
     Ok(())
 }
 
+/// Refresh the stats dashboard every `interval` seconds until Ctrl-C.
+async fn watch_stats(cmd: &StatsCommand, client: &SyntonClient, output: &OutputFormat) -> Result<()> {
+    let interval = std::time::Duration::from_secs(cmd.interval.max(1));
+    let mut baseline: Option<(u64, u64)> = None;
+    let mut previous: Option<(u64, u64)> = None;
+
+    loop {
+        let stats = client.stats().await?;
+        // Older servers have no activity endpoint; show the dashboard without it
+        let activity = client.activity(cmd.recent).await.ok();
+
+        let current = (stats.node_count, stats.edge_count);
+        let start = *baseline.get_or_insert(current);
+        let growth = DashboardGrowth {
+            nodes_since_start: current.0 as i64 - start.0 as i64,
+            edges_since_start: current.1 as i64 - start.1 as i64,
+            nodes_since_last: previous.map_or(0, |p| current.0 as i64 - p.0 as i64),
+            edges_since_last: previous.map_or(0, |p| current.1 as i64 - p.1 as i64),
+            interval_secs: interval.as_secs(),
+        };
+        previous = Some(current);
+
+        output.print_dashboard(&stats, activity.as_ref(), &growth);
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Execute a vector index command.
+pub async fn execute_vector(cmd: VectorCommand, client: SyntonClient, format: &str) -> Result<()> {
+    let output = OutputFormat::from_str(format);
+
+    match cmd {
+        VectorCommand::Calibrate { sample, percentile } => {
+            let request = synton_api::CalibrateVectorRequest { sample, percentile };
+            let calibration = client.calibrate_vectors(&request).await?;
+            output.print_calibration(&calibration);
+        }
+        VectorCommand::Evaluate { k, queries } => {
+            let request = synton_api::EvaluateVectorRequest { k, queries };
+            let evaluation = client.evaluate_vectors(&request).await?;
+            output.print_evaluation(&evaluation);
+        }
+        VectorCommand::Export { output: path, since } => {
+            let (delta, checkpoint) = client.export_vector_delta(since.as_deref()).await?;
+            std::fs::write(&path, &delta)?;
+            output.print_vector_delta_export(delta.len(), &checkpoint, &path);
+        }
+        VectorCommand::Import { input } => {
+            let response = client.apply_vector_delta(std::fs::read(input)?).await?;
+            output.print_vector_delta_import(&response);
+        }
+    }
+
+    Ok(())
+}
+
+/// Execute a reindex command.
+///
+/// Enqueues a rebuild of the server's in-memory state from storage and,
+/// with `wait`, reports its progress until it finishes.
+pub async fn execute_reindex(client: SyntonClient, wait: bool, format: &str) -> Result<()> {
+    let output = OutputFormat::from_str(format);
+
+    let mut job = client.reindex().await?;
+    if wait {
+        while !job.status.is_finished() {
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            job = client.job(job.id).await?;
+            output.print_job_progress(&job);
+        }
+        if job.status == JobStatus::Failed {
+            anyhow::bail!("Reindex failed: {}", job.error.unwrap_or_default());
+        }
+    }
+    output.print_job(&job);
+
+    Ok(())
+}
+
+/// Execute a doctor command.
+///
+/// Prints every check with the fixes for those that did not pass, and
+/// fails if any check failed.
+pub async fn execute_doctor(
+    client: SyntonClient,
+    storage_path: Option<std::path::PathBuf>,
+    format: &str,
+) -> Result<()> {
+    let output = OutputFormat::from_str(format);
+
+    let checks = doctor::diagnose(&client, storage_path.as_deref()).await;
+    output.print_doctor(&checks);
+
+    let failed = checks
+        .iter()
+        .filter(|check| check.status == CheckStatus::Fail)
+        .count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} checks failed", failed, checks.len());
+    }
+    Ok(())
+}
+
+/// Execute a migrate command.
+pub async fn execute_migrate(
+    cmd: MigrateCommand,
+    client: SyntonClient,
+    format: &str,
+) -> Result<()> {
+    let output = OutputFormat::from_str(format);
+
+    match cmd {
+        MigrateCommand::Storage => {
+            let migration = client.migrate_storage().await?;
+            output.print_storage_migration(&migration);
+        }
+    }
+
+    Ok(())
+}
+
+/// Execute a seed command.
+///
+/// Seeds the ontology of `file`, or the server's starter ontology, unless
+/// `remove` asks to remove the nodes seeded into the namespace.
+pub async fn execute_seed(
+    client: SyntonClient,
+    file: Option<String>,
+    namespace: Option<String>,
+    remove: bool,
+    format: &str,
+) -> Result<()> {
+    let output = OutputFormat::from_str(format);
+
+    if remove {
+        let removed = client.unseed(namespace.as_deref()).await?;
+        output.print_unseed(&removed);
+        return Ok(());
+    }
+
+    let ontology = file
+        .map(synton_api::Ontology::from_file)
+        .transpose()
+        .map_err(anyhow::Error::msg)?;
+    let seeded = client
+        .seed(&synton_api::SeedRequest {
+            ontology,
+            namespace,
+        })
+        .await?;
+    output.print_seed(&seeded);
+
+    Ok(())
+}
+
 /// Execute an export command.
+///
+/// The `archive` format streams a snapshot of all nodes and edges, and
+/// optionally embeddings, from the server; `json` lists the nodes only.
 pub async fn execute_export(
     client: SyntonClient,
     format_name: &str,
     output: Option<String>,
+    include_embeddings: bool,
+    output_format: &str,
 ) -> anyhow::Result<()> {
-    let nodes = client.list_nodes().await?;
+    if format_name == "archive" {
+        match output {
+            Some(path) => {
+                let mut file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+                let header = client.export_archive(include_embeddings, &mut file).await?;
+                OutputFormat::from_str(output_format).print_archive_export_summary(&header, &path);
+            }
+            None => {
+                client
+                    .export_archive(include_embeddings, &mut std::io::stdout().lock())
+                    .await?;
+            }
+        }
+        return Ok(());
+    }
+
+    let nodes = client.list_nodes(None).await?;
 
     let data = if format_name == "json" {
         serde_json::to_string_pretty(&nodes)?
@@ -244,7 +745,7 @@ pub async fn execute_export(
 
     if let Some(path) = output {
         std::fs::write(&path, data)?;
-        eprintln!("Exported {} nodes to {}", nodes.len(), path);
+        OutputFormat::from_str(output_format).print_export_summary(nodes.len(), &path);
     } else {
         println!("{}", data);
     }
@@ -253,11 +754,15 @@ pub async fn execute_export(
 }
 
 /// Execute an import command.
+///
+/// An `archive` is restored by the server as a whole, keeping node IDs and
+/// edges; `json` recreates each listed node.
 pub async fn execute_import(
     client: SyntonClient,
     input: Option<String>,
     format_name: &str,
     continue_on_error: bool,
+    output_format: &str,
 ) -> anyhow::Result<()> {
     let data = if let Some(path) = input {
         std::fs::read_to_string(path)?
@@ -267,7 +772,10 @@ pub async fn execute_import(
         buffer
     };
 
-    if format_name == "json" {
+    if format_name == "archive" {
+        let response = client.import_archive(data).await?;
+        OutputFormat::from_str(output_format).print_archive_import_summary(&response);
+    } else if format_name == "json" {
         let nodes: Vec<serde_json::Value> = serde_json::from_str(&data)?;
 
         let mut success = 0;
@@ -277,8 +785,9 @@ pub async fn execute_import(
             let content = node["content"].as_str().unwrap_or("");
             let node_type_str = node["node_type"].as_str().unwrap_or("concept");
             let node_type = parse_node_type(node_type_str).unwrap_or(NodeType::Concept);
+            let tags: Vec<String> = serde_json::from_value(node["tags"].clone()).unwrap_or_default();
 
-            match client.create_node(content.to_string(), node_type).await {
+            match client.create_node(content.to_string(), node_type, &tags).await {
                 Ok(_) => success += 1,
                 Err(e) => {
                     failed += 1;
@@ -290,10 +799,7 @@ pub async fn execute_import(
             }
         }
 
-        eprintln!("Imported {} nodes successfully", success);
-        if failed > 0 {
-            eprintln!("Failed to import {} nodes", failed);
-        }
+        OutputFormat::from_str(output_format).print_import_summary(success, failed);
     } else {
         anyhow::bail!("Unsupported import format: {}", format_name);
     }
@@ -301,6 +807,263 @@ pub async fn execute_import(
     Ok(())
 }
 
+/// Import the rows of a CSV or Parquet table as nodes.
+///
+/// Rows are sent in batches, each applied as one transaction so the server
+/// embeds its nodes together. Edges are created once every node exists, so
+/// a row can point to a row further down the table; a target that is not
+/// the key of an imported row is taken as the ID of an existing node.
+pub async fn execute_table_import(
+    cmd: TableImportCommand,
+    client: SyntonClient,
+    output_format: &str,
+) -> Result<()> {
+    let format = match &cmd.table_format {
+        Some(name) => TableFormat::parse(name)?,
+        None => TableFormat::from_path(&cmd.file)?,
+    };
+    let node_type = parse_node_type(&cmd.node_type)?;
+    let mapping = ColumnMapping::new(&cmd.maps, cmd.attrs)?;
+    let table = Table::open(&cmd.file, format)?;
+    mapping.check_columns(&table.columns)?;
+    let batch_size = cmd.batch_size.max(1);
+
+    let (mut nodes, mut edges, mut skipped, mut failed) = (0, 0, 0, 0);
+    // Node IDs by row key, and the (source, relation, target) edges to create
+    let mut keys: HashMap<String, Uuid> = HashMap::new();
+    let mut links: Vec<(Uuid, String, String)> = Vec::new();
+
+    let mut operations = Vec::with_capacity(batch_size);
+    let mut pending: Vec<(String, Option<String>, Option<(String, String)>)> = Vec::new();
+    let mut rows = table.rows.enumerate().peekable();
+    while let Some((index, row)) = rows.next() {
+        match row {
+            Ok(row) => match mapping.content(&row) {
+                Some(content) => {
+                    let temp_id = index.to_string();
+                    operations.push(TransactionOp::AddNode {
+                        temp_id: Some(temp_id.clone()),
+                        content,
+                        node_type,
+                        attributes: mapping.attributes(&row),
+                    });
+                    pending.push((temp_id, mapping.key(&row), mapping.link(&row)));
+                }
+                None => skipped += 1,
+            },
+            Err(e) => {
+                failed += 1;
+                eprintln!("Failed to read row {}: {}", index + 1, e);
+                if !cmd.continue_on_error {
+                    return Err(e);
+                }
+            }
+        }
+
+        if operations.len() < batch_size && rows.peek().is_some() {
+            continue;
+        }
+        if operations.is_empty() {
+            continue;
+        }
+        let request = TransactionRequest {
+            operations: std::mem::take(&mut operations),
+        };
+        match client.apply_transaction(&request).await {
+            Ok(response) => {
+                nodes += response.nodes.len();
+                for (temp_id, key, link) in pending.drain(..) {
+                    let id = response.temp_ids[&temp_id];
+                    if let Some(key) = key {
+                        keys.insert(key, id);
+                    }
+                    if let Some((relation, target)) = link {
+                        links.push((id, relation, target));
+                    }
+                }
+            }
+            Err(e) => {
+                failed += pending.len();
+                pending.clear();
+                eprintln!("Failed to import a batch of rows: {}", e);
+                if !cmd.continue_on_error {
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    for batch in links.chunks(batch_size) {
+        let mut operations = Vec::with_capacity(batch.len());
+        for (source, relation, target) in batch {
+            let target = match keys.get(target) {
+                Some(&id) => Ok(id),
+                None => Uuid::parse_str(target)
+                    .map_err(|_| anyhow::anyhow!("Unknown edge target '{}'", target)),
+            };
+            let resolved = target.and_then(|target| Ok((target, parse_relation(relation)?)));
+            match resolved {
+                Ok((target, relation)) => operations.push(TransactionOp::AddEdge {
+                    source: NodeRef::Id(*source),
+                    target: NodeRef::Id(target),
+                    relation,
+                    weight: 1.0,
+                    provenance: EdgeProvenance::default(),
+                }),
+                Err(e) => {
+                    failed += 1;
+                    eprintln!("Failed to create edge: {}", e);
+                    if !cmd.continue_on_error {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        if operations.is_empty() {
+            continue;
+        }
+        let count = operations.len();
+        match client.apply_transaction(&TransactionRequest { operations }).await {
+            Ok(response) => edges += response.edges.len(),
+            Err(e) => {
+                failed += count;
+                eprintln!("Failed to create a batch of edges: {}", e);
+                if !cmd.continue_on_error {
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    OutputFormat::from_str(output_format).print_table_import_summary(nodes, edges, skipped, failed);
+    Ok(())
+}
+
+/// Load a synthetic dataset into the server and time each operation.
+///
+/// The data stays on the server, so point this at a scratch instance.
+pub async fn execute_bench(cmd: BenchCommand, client: SyntonClient, format: &str) -> Result<()> {
+    let output = OutputFormat::from_str(format);
+    let dataset_config = DatasetConfig {
+        nodes: cmd.nodes,
+        edges_per_node: cmd.edges_per_node,
+        seed: cmd.seed,
+        ..Default::default()
+    };
+    let dataset = Dataset::generate(&dataset_config);
+    let mut operations = Vec::new();
+
+    // Server IDs of the generated nodes
+    let mut ids = HashMap::with_capacity(dataset.nodes.len());
+    let mut samples = Vec::with_capacity(dataset.nodes.len());
+    for node in &dataset.nodes {
+        let start = Instant::now();
+        let created = client.create_node(node.content.to_string(), node.node_type, &[]).await?;
+        samples.push(start.elapsed());
+        ids.insert(node.id, created.id);
+    }
+    operations.push(OperationStats::from_samples("add_node", &samples));
+
+    let mut samples = Vec::with_capacity(dataset.edges.len());
+    for edge in &dataset.edges {
+        let start = Instant::now();
+        client
+            .create_edge(
+                ids[&edge.source],
+                ids[&edge.target],
+                edge.relation.clone(),
+                edge.weight,
+                EdgeProvenance::default(),
+            )
+            .await?;
+        samples.push(start.elapsed());
+    }
+    operations.push(OperationStats::from_samples("add_edge", &samples));
+
+    let mut samples = Vec::with_capacity(cmd.queries);
+    for query in Dataset::queries(cmd.queries, cmd.seed) {
+        let start = Instant::now();
+        client.query(query, Some(10), false).await?;
+        samples.push(start.elapsed());
+    }
+    operations.push(OperationStats::from_samples("text_query", &samples));
+
+    if !dataset.nodes.is_empty() {
+        let mut rng = Rng::new(cmd.seed);
+        let mut samples = Vec::with_capacity(cmd.traversals);
+        for _ in 0..cmd.traversals {
+            let node = &dataset.nodes[rng.below(dataset.nodes.len())];
+            let start = Instant::now();
+            client.traverse(ids[&node.id], cmd.depth, 10_000).await?;
+            samples.push(start.elapsed());
+        }
+        operations.push(OperationStats::from_samples(format!("traverse_depth{}", cmd.depth), &samples));
+    }
+
+    let report = BenchReport {
+        dataset: dataset_config,
+        operations,
+    };
+    if let Some(path) = &cmd.save {
+        std::fs::write(path, serde_json::to_string_pretty(&report)?)?;
+    }
+
+    let regressions = match &cmd.baseline {
+        Some(path) => {
+            let baseline: BenchReport = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+            if baseline.dataset != report.dataset {
+                eprintln!("Warning: baseline {} was recorded with a different dataset", path);
+            }
+            report.regressions(&baseline, cmd.max_regression / 100.0)
+        }
+        None => Vec::new(),
+    };
+
+    output.print_bench_report(&report, &regressions);
+
+    if !regressions.is_empty() {
+        anyhow::bail!(
+            "{} operation(s) regressed by more than {}% against the baseline",
+            regressions.len(),
+            cmd.max_regression
+        );
+    }
+
+    Ok(())
+}
+
+/// Execute an OpenAPI command.
+pub fn execute_openapi(cmd: OpenapiCommand, format: &str) -> Result<()> {
+    let OpenapiCommand::Dump { output, schemas } = cmd;
+    let spec = synton_api::openapi::openapi_spec();
+
+    let data = if schemas {
+        let schemas = spec.components.map(|c| c.schemas).unwrap_or_default();
+        serde_json::to_string_pretty(&schemas)?
+    } else {
+        spec.to_pretty_json()?
+    };
+
+    if let Some(path) = output {
+        std::fs::write(&path, data)?;
+        OutputFormat::from_str(format).print_openapi_summary(&path);
+    } else {
+        println!("{}", data);
+    }
+
+    Ok(())
+}
+
+/// Parse a `name=value` argument.
+fn parse_assignment(s: &str) -> std::result::Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() && !value.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("expected name=value, got '{}'", s)),
+    }
+}
+
 /// Parse node type from string.
 fn parse_node_type(s: &str) -> Result<NodeType> {
     match s.to_lowercase().as_str() {
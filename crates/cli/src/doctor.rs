@@ -0,0 +1,335 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Environment diagnostics.
+//!
+//! `synton-cli doctor` runs a series of checks against the server and the
+//! local machine and, for each one that does not pass, says how to fix it.
+//! Checks that need the server are skipped when it cannot be reached.
+
+use std::path::Path;
+
+use serde::Serialize;
+use synton_api::SelfCheckResponse;
+
+use crate::client::SyntonClient;
+
+/// Outcome of a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    /// Nothing to fix.
+    Ok,
+    /// Works, but something is missing or degraded.
+    Warn,
+    /// Broken.
+    Fail,
+    /// Not run because an earlier check failed.
+    Skipped,
+}
+
+/// Result of one diagnostic check.
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    /// What was checked.
+    pub name: &'static str,
+
+    /// Outcome.
+    pub status: CheckStatus,
+
+    /// What was found.
+    pub detail: String,
+
+    /// How to fix it, unless it passed.
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Ok,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn skipped(name: &'static str) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Skipped,
+            detail: "server unreachable".to_string(),
+            fix: None,
+        }
+    }
+}
+
+/// Run every check, the storage path one only if a path is given.
+pub async fn diagnose(client: &SyntonClient, storage_path: Option<&Path>) -> Vec<Check> {
+    let mut checks = Vec::new();
+    let health = client.health().await;
+    match &health {
+        Ok(health) => {
+            checks.push(Check::ok(
+                "server",
+                format!("{} is {}", client.base_url(), health.status),
+            ));
+            checks.push(version_check(env!("CARGO_PKG_VERSION"), &health.version));
+        }
+        Err(e) => {
+            checks.push(Check::fail(
+                "server",
+                format!("{} is unreachable: {:#}", client.base_url(), e),
+                "Start the server with `synton-db-server`, or point the CLI at it with \
+                 --host and --port",
+            ));
+            checks.push(Check::skipped("version"));
+        }
+    }
+
+    if let Some(path) = storage_path {
+        checks.push(storage_path_check(path));
+    }
+
+    if health.is_err() {
+        checks.push(Check::skipped("embedding"));
+        checks.push(Check::skipped("vector_index"));
+        return checks;
+    }
+    match client.self_check().await {
+        Ok(Some(report)) => {
+            checks.push(embedding_check(&report));
+            checks.push(vector_index_check(&report));
+        }
+        Ok(None) => {
+            let fix = "Upgrade the server to check its embedding backend and vector index";
+            checks.push(Check::warn("embedding", "server has no self-check", fix));
+            checks.push(Check::warn("vector_index", "server has no self-check", fix));
+        }
+        Err(e) => {
+            let detail = format!("self-check failed: {:#}", e);
+            let fix = "Check the server logs";
+            checks.push(Check::fail("embedding", detail.clone(), fix));
+            checks.push(Check::fail("vector_index", detail, fix));
+        }
+    }
+    checks
+}
+
+/// Major and minor version of a `major.minor.patch` version.
+fn major_minor(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// The CLI speaks the API of servers of the same major version, or the
+/// same minor version before 1.0.
+fn version_check(cli: &str, server: &str) -> Check {
+    let (Some(ours), Some(theirs)) = (major_minor(cli), major_minor(server)) else {
+        return Check::warn(
+            "version",
+            format!("cannot compare CLI {} with server {}", cli, server),
+            "Use a CLI built from the same release as the server",
+        );
+    };
+    let compatible = ours.0 == theirs.0 && (ours.0 > 0 || ours.1 == theirs.1);
+    if compatible {
+        Check::ok("version", format!("CLI {}, server {}", cli, server))
+    } else {
+        Check::fail(
+            "version",
+            format!("CLI {} is incompatible with server {}", cli, server),
+            format!(
+                "Install synton-cli {}.{}.x to match the server",
+                theirs.0, theirs.1
+            ),
+        )
+    }
+}
+
+/// The storage path, a RocksDB directory or SQLite file, must be writable,
+/// or creatable if it does not exist yet.
+fn storage_path_check(path: &Path) -> Check {
+    const NAME: &str = "storage_path";
+    let display = path.display();
+    let fix = format!(
+        "Create {} or give the user running the server read and write access to it \
+         (e.g. `chmod u+rw`), or point `rocksdb_path`/`sqlite_path` elsewhere",
+        display
+    );
+
+    // A relative path's last ancestor is empty, meaning the current directory
+    let existing = path
+        .ancestors()
+        .map(|p| {
+            if p.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                p
+            }
+        })
+        .find(|p| p.exists());
+    let Some(existing) = existing else {
+        return Check::fail(NAME, format!("{} has no existing parent", display), fix);
+    };
+    if existing != path && !existing.is_dir() {
+        return Check::fail(
+            NAME,
+            format!("{} is not a directory", existing.display()),
+            fix,
+        );
+    }
+
+    let writable = if existing.is_dir() {
+        dir_writable(existing)
+    } else {
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(existing)
+            .map(|_| ())
+    };
+    match writable {
+        Ok(()) if existing == path => Check::ok(NAME, format!("{} is writable", display)),
+        Ok(()) => Check::ok(
+            NAME,
+            format!("{} will be created in {}", display, existing.display()),
+        ),
+        Err(e) => Check::fail(
+            NAME,
+            format!("{} is not writable: {}", existing.display(), e),
+            fix,
+        ),
+    }
+}
+
+/// Whether a file can be created in `dir`.
+fn dir_writable(dir: &Path) -> std::io::Result<()> {
+    let probe = dir.join(format!(".synton-doctor-{}", std::process::id()));
+    std::fs::write(&probe, b"")?;
+    std::fs::remove_file(&probe)
+}
+
+/// The embedding backend must embed with the configured dimension.
+fn embedding_check(report: &SelfCheckResponse) -> Check {
+    const NAME: &str = "embedding";
+    let Some(embedding) = &report.embedding else {
+        return Check::warn(
+            NAME,
+            "no embedding backend; semantic search is disabled",
+            "Set `enabled = true` in the `[ml]` section of the server config and build the \
+             server with `--features ml`",
+        );
+    };
+    let Some(error) = &embedding.error else {
+        return Check::ok(
+            NAME,
+            format!(
+                "{} model {} answers with {} dimensions",
+                embedding.backend, embedding.model, embedding.dimension
+            ),
+        );
+    };
+
+    let fix = match embedding.backend.as_str() {
+        "ollama" => format!(
+            "Check that Ollama is running at the `[ml] api_endpoint` and has the model \
+             (`ollama pull {}`)",
+            embedding.model
+        ),
+        "openai" => format!(
+            "Check the `[ml] api_endpoint`, the API key and that model {} exists",
+            embedding.model
+        ),
+        _ => format!(
+            "Check that model {} can be downloaded, or set `[ml] local_model` to a local \
+             model directory",
+            embedding.model
+        ),
+    };
+    Check::fail(
+        NAME,
+        format!(
+            "{} model {} failed: {}",
+            embedding.backend, embedding.model, error
+        ),
+        fix,
+    )
+}
+
+/// The vector index must be ready, match the embedding dimension and hold
+/// every embedded node.
+fn vector_index_check(report: &SelfCheckResponse) -> Check {
+    const NAME: &str = "vector_index";
+    let Some(index) = &report.vector_index else {
+        return Check::warn(
+            NAME,
+            "no vector index; semantic search is disabled",
+            "Configure an embedding backend, which brings the vector index with it",
+        );
+    };
+    if let Some(error) = &index.error {
+        return Check::fail(
+            NAME,
+            format!("vector index failed: {}", error),
+            "Check the server logs, then rebuild the index with `synton-cli reindex --wait`",
+        );
+    }
+    if !index.ready {
+        return Check::fail(
+            NAME,
+            "vector index is not ready",
+            "Wait for the server to finish loading, or rebuild the index with \
+             `synton-cli reindex --wait`",
+        );
+    }
+    if let Some(embedding) = &report.embedding {
+        if embedding.dimension != index.dimension {
+            return Check::fail(
+                NAME,
+                format!(
+                    "vector index has {} dimensions but the embedding model has {}",
+                    index.dimension, embedding.dimension
+                ),
+                "Restart the server so the vector index is created for the current \
+                 embedding model, then rebuild it with `synton-cli reindex --wait`",
+            );
+        }
+    }
+
+    let vectors = index.vectors.unwrap_or_default();
+    if vectors < report.embedded_nodes {
+        return Check::warn(
+            NAME,
+            format!(
+                "{} vectors indexed for {} embedded nodes",
+                vectors, report.embedded_nodes
+            ),
+            "Rebuild the index from storage with `synton-cli reindex --wait`",
+        );
+    }
+    Check::ok(
+        NAME,
+        format!("{} vectors of {} dimensions", vectors, index.dimension),
+    )
+}
@@ -11,12 +11,19 @@
 
 mod client;
 mod commands;
+mod doctor;
 mod output;
+mod table;
 
-use clap::{Parser, Subcommand};
-use commands::{EdgeCommand, NodeCommand, QueryCommand, StatsCommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use commands::{
+    BenchCommand, EdgeCommand, ImportCommand, MigrateCommand, NodeCommand, OpenapiCommand,
+    QueryCommand, StatsCommand, VectorCommand,
+};
 
 use crate::client::SyntonClient;
+use crate::output::OutputFormat;
 
 /// SYNTON-DB CLI - Command-line interface for SYNTON-DB cognitive database
 #[derive(Parser, Debug)]
@@ -63,37 +70,109 @@ enum Commands {
     /// Database statistics
     Stats(StatsCommand),
 
+    /// Vector index operations
+    #[command(subcommand)]
+    Vector(VectorCommand),
+
+    /// Rebuild the server's in-memory graph and indexes from storage
+    Reindex {
+        /// Wait for the rebuild to finish, showing its progress
+        #[arg(long)]
+        wait: bool,
+    },
+
+    /// Storage migrations
+    #[command(subcommand)]
+    Migrate(MigrateCommand),
+
+    /// Seed an ontology into a namespace, or remove one seeded before
+    Seed {
+        /// JSON file of the ontology (the server's bundled starter
+        /// ontology if omitted)
+        #[arg(long)]
+        file: Option<String>,
+
+        /// Namespace (server default: starter)
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Remove the nodes seeded into the namespace instead
+        #[arg(long, conflicts_with = "file")]
+        remove: bool,
+    },
+
     /// Export data
     Export {
-        /// Export format (json, csv)
-        #[arg(short, long, default_value = "json")]
+        /// Export format: archive (nodes, edges and embeddings from a
+        /// consistent snapshot) or json (nodes only)
+        #[arg(short, long, default_value = "archive")]
         format: String,
 
         /// Output file (stdout if not specified)
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Include node embeddings in an archive
+        #[arg(long)]
+        include_embeddings: bool,
     },
 
+    /// Benchmark the server with a synthetic dataset
+    Bench(BenchCommand),
+
     /// Import data
+    #[command(args_conflicts_with_subcommands = true)]
     Import {
+        /// Import a table instead of an export
+        #[command(subcommand)]
+        command: Option<ImportCommand>,
+
         /// Input file (stdin if not specified)
         #[arg(short, long)]
         input: Option<String>,
 
-        /// Import format (json)
-        #[arg(short, long, default_value = "json")]
+        /// Import format (archive, json)
+        #[arg(short, long, default_value = "archive")]
         format: String,
 
         /// Continue on error
         #[arg(long)]
         continue_on_error: bool,
     },
+
+    /// OpenAPI document of the REST API, for generating client SDKs
+    #[command(subcommand)]
+    Openapi(OpenapiCommand),
+
+    /// Diagnose the environment: server reachability, CLI and server
+    /// versions, the embedding backend and the vector index
+    Doctor {
+        /// Also check that this storage path (the server's `rocksdb_path`
+        /// or `sqlite_path`) is writable
+        #[arg(long)]
+        storage_path: Option<std::path::PathBuf>,
+    },
+
+    /// Generate shell completions
+    Completions {
+        /// Target shell (bash, zsh, fish, elvish, powershell)
+        shell: Shell,
+    },
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() {
     let cli = Cli::parse();
+    let output = OutputFormat::from_str(&cli.format);
 
+    if let Err(e) = run(cli).await {
+        output.print_error(&e);
+        std::process::exit(1);
+    }
+}
+
+/// Run the parsed command.
+async fn run(cli: Cli) -> anyhow::Result<()> {
     // Initialize tracing
     if !cli.quiet {
         tracing_subscriber::fmt()
@@ -112,14 +191,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::Edge(cmd) => commands::execute_edge(cmd, client, &cli.format).await?,
         Commands::Query(cmd) => commands::execute_query(cmd, client, &cli.format).await?,
         Commands::Stats(cmd) => commands::execute_stats(cmd, client, &cli.format).await?,
-        Commands::Export { format, output } => {
-            commands::execute_export(client, &format, output).await?
+        Commands::Vector(cmd) => commands::execute_vector(cmd, client, &cli.format).await?,
+        Commands::Reindex { wait } => commands::execute_reindex(client, wait, &cli.format).await?,
+        Commands::Migrate(cmd) => commands::execute_migrate(cmd, client, &cli.format).await?,
+        Commands::Seed {
+            file,
+            namespace,
+            remove,
+        } => commands::execute_seed(client, file, namespace, remove, &cli.format).await?,
+        Commands::Export {
+            format,
+            output,
+            include_embeddings,
+        } => {
+            commands::execute_export(client, &format, output, include_embeddings, &cli.format)
+                .await?
         }
+        Commands::Bench(cmd) => commands::execute_bench(cmd, client, &cli.format).await?,
         Commands::Import {
+            command: Some(ImportCommand::Table(cmd)),
+            ..
+        } => commands::execute_table_import(cmd, client, &cli.format).await?,
+        Commands::Import {
+            command: None,
             input,
             format,
             continue_on_error,
-        } => commands::execute_import(client, input, &format, continue_on_error).await?,
+        } => {
+            commands::execute_import(client, input, &format, continue_on_error, &cli.format)
+                .await?
+        }
+        Commands::Openapi(cmd) => commands::execute_openapi(cmd, &cli.format)?,
+        Commands::Doctor { storage_path } => {
+            commands::execute_doctor(client, storage_path, &cli.format).await?
+        }
+        Commands::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "synton-cli", &mut std::io::stdout())
+        }
     }
 
     Ok(())
@@ -4,10 +4,32 @@
 
 //! Output formatting for CLI.
 
+use std::collections::BTreeMap;
+
 use serde::Serialize;
+use synton_api::{
+    ApplyVectorDeltaResponse, ExportHeader, ImportResponse, Job, MigrateStorageResponse,
+    NodeTagsResponse, ReviewEdgeResponse, SeedResponse, UnseedResponse, VectorCalibration,
+    VectorEvaluation,
+};
+use synton_bench::{BenchReport, Regression};
 use synton_core::{Edge, Node};
+use uuid::Uuid;
+
+use crate::client::{
+    ActivityResponse, MatchExplanation, NodeInspection, QueryResponse, StatsResponse, TermMatch,
+};
+use crate::doctor::{Check, CheckStatus};
 
-use crate::client::QueryResponse;
+/// Node and edge growth shown on the watch dashboard.
+#[derive(Debug, Serialize)]
+pub struct DashboardGrowth {
+    pub nodes_since_start: i64,
+    pub edges_since_start: i64,
+    pub nodes_since_last: i64,
+    pub edges_since_last: i64,
+    pub interval_secs: u64,
+}
 
 /// Output format for CLI.
 pub enum OutputFormat {
@@ -39,6 +61,25 @@ impl OutputFormat {
                 println!("  Content:   {}", node.content());
                 println!("  Created:   {}", node.meta.created_at.format("%Y-%m-%d %H:%M:%S"));
                 println!("  Access:    {:.2}", node.meta.access_score);
+                if node.meta.pinned {
+                    println!("  Pinned:    yes");
+                }
+                if !node.tags.is_empty() {
+                    println!("  Tags:      {}", join_tags(&node.tags));
+                }
+            }
+        }
+    }
+
+    pub fn print_node_tags(&self, response: &NodeTagsResponse) {
+        match self {
+            Self::Json => self.print_json(response),
+            Self::Text => {
+                if response.tags.is_empty() {
+                    println!("Node {} has no tags", response.node_id);
+                } else {
+                    println!("Node {} tags: {}", response.node_id, join_tags(&response.tags));
+                }
             }
         }
     }
@@ -59,6 +100,69 @@ impl OutputFormat {
         }
     }
 
+    pub fn print_inspection(&self, inspection: &NodeInspection) {
+        match self {
+            Self::Json => self.print_json(inspection),
+            Self::Text => {
+                let node = &inspection.node;
+                println!("Node:");
+                println!("  ID:        {}", node.id);
+                println!("  Type:      {:?}", node.node_type);
+                println!("  Content:   {}", node.content());
+                println!("  Created:   {}", node.meta.created_at.format("%Y-%m-%d %H:%M:%S"));
+
+                let memory = &inspection.memory;
+                println!("\nMemory:");
+                println!("  Profile:   {}", memory.profile);
+                println!("  Score:     {:.2} ({:.2} at last access)", memory.current_score, memory.access_score);
+                println!("  Strength:  {:.2}", memory.strength);
+                println!("  Accessed:  {}", memory.accessed_at.as_deref().unwrap_or("never"));
+                if memory.pinned {
+                    println!("  Pinned:    yes (exempt from decay and pruning)");
+                }
+
+                print_edge_groups("Outgoing", &inspection.outgoing, |edge| ("->", edge.target));
+                print_edge_groups("Incoming", &inspection.incoming, |edge| ("<-", edge.source));
+
+                println!("\nSimilar nodes ({}):", inspection.similar.len());
+                for similar in &inspection.similar {
+                    println!("  {} | {:.3} | {}", similar.node_id, similar.score, truncate(&similar.content, 40));
+                }
+
+                if !inspection.attachments.is_empty() {
+                    println!("\nAttachments ({}):", inspection.attachments.len());
+                    for attachment in &inspection.attachments {
+                        println!(
+                            "  {} | {} | {} | {} bytes",
+                            attachment.id, attachment.name, attachment.content_type, attachment.size
+                        );
+                    }
+                }
+
+                let usage = &inspection.usage;
+                println!(
+                    "\nAccesses ({}: {} queries, {} traversals, {} gets):",
+                    usage.total, usage.queries, usage.traversals, usage.gets
+                );
+                for event in &usage.recent {
+                    println!(
+                        "  {} | {:<9} | {}",
+                        event.accessed_at,
+                        event.kind,
+                        event.actor.as_deref().unwrap_or("-")
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn print_deleted(&self, id: Uuid) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({ "id": id, "deleted": true })),
+            Self::Text => println!("Node {} deleted", id),
+        }
+    }
+
     pub fn print_edge(&self, edge: &Edge) {
         match self {
             Self::Json => self.print_json(edge),
@@ -68,10 +172,289 @@ impl OutputFormat {
                 println!("  Target:   {}", edge.target);
                 println!("  Relation: {}", edge.relation);
                 println!("  Weight:   {}", edge.weight);
+                println!("  Created by: {}", edge.provenance.created_by);
+                if let Some(document) = edge.provenance.source_document {
+                    println!("  Source document: {}", document);
+                }
+                if let Some(confidence) = edge.provenance.confidence {
+                    println!("  Confidence: {:.2}", confidence);
+                }
+                if edge.is_pending_review() {
+                    println!("  Pending review: yes (not in the graph until approved)");
+                }
+            }
+        }
+    }
+
+    pub fn print_pending_edges(&self, edges: &[Edge]) {
+        match self {
+            Self::Json => self.print_json(edges),
+            Self::Text => {
+                println!("Pending edges ({}):", edges.len());
+                for edge in edges {
+                    let confidence = edge
+                        .provenance
+                        .confidence
+                        .map(|c| format!("{:.2}", c))
+                        .unwrap_or_else(|| "-".to_string());
+                    println!(
+                        "  {} -[{}]-> {} | confidence {} | {}",
+                        edge.source, edge.relation, edge.target, confidence, edge.provenance.created_by
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn print_edge_review(&self, response: &ReviewEdgeResponse) {
+        match self {
+            Self::Json => self.print_json(response),
+            Self::Text => {
+                let edge = &response.edge;
+                let outcome = if response.approved { "Approved" } else { "Rejected" };
+                println!("{} edge {} -[{}]-> {}", outcome, edge.source, edge.relation, edge.target);
+            }
+        }
+    }
+
+    pub fn print_export_summary(&self, exported: usize, path: &str) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({ "exported": exported, "path": path })),
+            Self::Text => eprintln!("Exported {} nodes to {}", exported, path),
+        }
+    }
+
+    pub fn print_archive_export_summary(&self, header: &ExportHeader, path: &str) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({ "header": header, "path": path })),
+            Self::Text => eprintln!(
+                "Exported {} nodes, {} edges and {} embeddings to {}",
+                header.nodes, header.edges, header.embeddings, path
+            ),
+        }
+    }
+
+    pub fn print_archive_import_summary(&self, response: &ImportResponse) {
+        match self {
+            Self::Json => self.print_json(response),
+            Self::Text => {
+                eprintln!(
+                    "Imported {} nodes ({} with embeddings) and {} edges",
+                    response.nodes, response.embeddings, response.edges
+                );
+                if response.skipped_nodes > 0 || response.skipped_edges > 0 {
+                    eprintln!(
+                        "Skipped {} existing nodes and {} existing edges",
+                        response.skipped_nodes, response.skipped_edges
+                    );
+                }
+                for error in &response.errors {
+                    eprintln!("Failed: {}", error);
+                }
             }
         }
     }
 
+    pub fn print_calibration(&self, calibration: &VectorCalibration) {
+        match self {
+            Self::Json => self.print_json(calibration),
+            Self::Text => {
+                let c = &calibration.calibration;
+                println!("Calibrated from {} vectors ({} pairs):", c.vectors, c.pairs);
+                println!("  Mean:      {:.3} (std dev {:.3})", c.mean, c.std_dev);
+                println!("  p50:       {:.3}", c.p50);
+                println!("  p90:       {:.3}", c.p90);
+                println!("  p99:       {:.3}", c.p99);
+                println!("  Threshold: {:.3} (p{:.0})", c.threshold, c.percentile * 100.0);
+            }
+        }
+    }
+
+    pub fn print_evaluation(&self, evaluation: &VectorEvaluation) {
+        match self {
+            Self::Json => self.print_json(evaluation),
+            Self::Text => {
+                let e = &evaluation.evaluation;
+                println!("Evaluated {} queries over {} vectors:", e.queries, e.vectors);
+                println!("  Recall@{}:  {:.3} (min {:.3})", e.k, e.recall, e.min_recall);
+                println!("  Perfect:    {}/{}", e.perfect_queries, e.queries);
+                println!(
+                    "  Latency:    {:.3} ms mean, {:.3} ms p50, {:.3} ms p99",
+                    e.mean_latency_ms, e.p50_latency_ms, e.p99_latency_ms
+                );
+                println!("  Exact scan: {:.3} ms mean", e.exact_latency_ms);
+            }
+        }
+    }
+
+    pub fn print_vector_delta_export(&self, bytes: usize, checkpoint: &str, path: &str) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({
+                "bytes": bytes,
+                "checkpoint": checkpoint,
+                "path": path,
+            })),
+            Self::Text => {
+                println!("Exported vector delta of {} bytes to {}", bytes, path);
+                println!("  Checkpoint: {}", checkpoint);
+            }
+        }
+    }
+
+    pub fn print_vector_delta_import(&self, response: &ApplyVectorDeltaResponse) {
+        match self {
+            Self::Json => self.print_json(response),
+            Self::Text => {
+                let kind = if response.full { "full" } else { "incremental" };
+                println!(
+                    "Applied {} vector delta: {} vectors written, {} removed",
+                    kind, response.upserted, response.removed
+                );
+                println!("  Vectors:    {}", response.vector_count);
+                println!("  Checkpoint: {}", response.checkpoint);
+            }
+        }
+    }
+
+    pub fn print_storage_migration(&self, migration: &MigrateStorageResponse) {
+        match self {
+            Self::Json => self.print_json(migration),
+            Self::Text => {
+                let m = &migration.migration;
+                println!("Migrated storage in {} ms:", migration.duration_ms);
+                println!(
+                    "  Nodes: {} of {} rewritten (format version {})",
+                    m.nodes_migrated, m.nodes_scanned, m.node_version
+                );
+                println!(
+                    "  Edges: {} of {} rewritten (format version {})",
+                    m.edges_migrated, m.edges_scanned, m.edge_version
+                );
+            }
+        }
+    }
+
+    pub fn print_seed(&self, seeded: &SeedResponse) {
+        match self {
+            Self::Json => self.print_json(seeded),
+            Self::Text if seeded.seeded => println!(
+                "Seeded ontology {} into namespace {}: {} nodes, {} edges",
+                seeded.ontology, seeded.namespace, seeded.nodes, seeded.edges
+            ),
+            Self::Text => println!("Namespace {} is already seeded", seeded.namespace),
+        }
+    }
+
+    pub fn print_unseed(&self, removed: &UnseedResponse) {
+        match self {
+            Self::Json => self.print_json(removed),
+            Self::Text => println!(
+                "Removed {} seeded nodes and {} edges from namespace {}",
+                removed.nodes_removed, removed.edges_removed, removed.namespace
+            ),
+        }
+    }
+
+    pub fn print_doctor(&self, checks: &[Check]) {
+        match self {
+            Self::Json => self.print_json(checks),
+            Self::Text => {
+                for check in checks {
+                    let status = match check.status {
+                        CheckStatus::Ok => "ok",
+                        CheckStatus::Warn => "warn",
+                        CheckStatus::Fail => "FAIL",
+                        CheckStatus::Skipped => "skip",
+                    };
+                    println!("[{:<4}] {:<13} {}", status, check.name, check.detail);
+                    if let Some(fix) = &check.fix {
+                        println!("       {:<13} fix: {}", "", fix);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn print_job(&self, job: &Job) {
+        match self {
+            Self::Json => self.print_json(job),
+            Self::Text => {
+                println!("Job {} ({:?}): {:?}", job.id, job.kind, job.status);
+                match (job.total, job.percent) {
+                    (Some(total), Some(percent)) => {
+                        println!("  Progress: {}/{} ({:.1}%)", job.done, total, percent)
+                    }
+                    _ => println!("  Progress: {}", job.done),
+                }
+                if let Some(result) = &job.result {
+                    if let Ok(json) = serde_json::to_string_pretty(result) {
+                        println!("  Result:   {}", json.replace('\n', "\n  "));
+                    }
+                }
+                if let Some(error) = &job.error {
+                    println!("  Error:    {}", error);
+                }
+            }
+        }
+    }
+
+    pub fn print_job_progress(&self, job: &Job) {
+        if let (Self::Text, Some(percent)) = (self, job.percent) {
+            eprint!("\r{:?}: {:.1}%", job.kind, percent);
+            if job.status.is_finished() {
+                eprintln!();
+            }
+        }
+    }
+
+    pub fn print_openapi_summary(&self, path: &str) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({ "path": path })),
+            Self::Text => eprintln!("Wrote OpenAPI document to {}", path),
+        }
+    }
+
+    pub fn print_import_summary(&self, imported: usize, failed: usize) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({ "imported": imported, "failed": failed })),
+            Self::Text => {
+                eprintln!("Imported {} nodes successfully", imported);
+                if failed > 0 {
+                    eprintln!("Failed to import {} nodes", failed);
+                }
+            }
+        }
+    }
+
+    pub fn print_table_import_summary(&self, nodes: usize, edges: usize, skipped: usize, failed: usize) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({
+                "nodes": nodes,
+                "edges": edges,
+                "skipped": skipped,
+                "failed": failed,
+            })),
+            Self::Text => {
+                eprintln!("Imported {} nodes and {} edges", nodes, edges);
+                if skipped > 0 {
+                    eprintln!("Skipped {} rows with empty content", skipped);
+                }
+                if failed > 0 {
+                    eprintln!("Failed to import {} rows or edges", failed);
+                }
+            }
+        }
+    }
+
+    /// Report a failed command on stderr, as a JSON object in JSON mode so
+    /// scripts can parse it.
+    pub fn print_error(&self, error: &anyhow::Error) {
+        match self {
+            Self::Json => eprintln!("{}", serde_json::json!({ "error": format!("{:#}", error) })),
+            Self::Text => eprintln!("Error: {:#}", error),
+        }
+    }
+
     pub fn print_stats(&self, stats: &crate::client::StatsResponse, detailed: bool) {
         match self {
             Self::Json => self.print_json(stats),
@@ -85,6 +468,94 @@ impl OutputFormat {
                     println!("\nMemory Statistics:");
                     // These would be in memory_stats field
                     println!("  (Detailed stats not yet available via REST API)");
+
+                    if !stats.nodes_by_tag.is_empty() {
+                        let mut tags: Vec<_> = stats.nodes_by_tag.iter().collect();
+                        tags.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+                        println!("\nTags:");
+                        for (tag, count) in tags {
+                            println!("  {:<24} {}", tag, count);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn print_dashboard(
+        &self,
+        stats: &StatsResponse,
+        activity: Option<&ActivityResponse>,
+        growth: &DashboardGrowth,
+    ) {
+        match self {
+            // One JSON object per refresh so the output can be piped
+            Self::Json => {
+                let snapshot = serde_json::json!({
+                    "stats": stats,
+                    "growth": growth,
+                    "activity": activity,
+                });
+                if let Ok(json) = serde_json::to_string(&snapshot) {
+                    println!("{}", json);
+                }
+            }
+            Self::Text => {
+                // Clear the screen and move the cursor home
+                print!("\x1B[2J\x1B[H");
+                println!(
+                    "SYNTON-DB Live Stats (every {}s, Ctrl-C to exit)",
+                    growth.interval_secs
+                );
+
+                println!("\nGraph:");
+                println!(
+                    "  Nodes:           {} ({:+} last, {:+} total)",
+                    stats.node_count, growth.nodes_since_last, growth.nodes_since_start
+                );
+                println!(
+                    "  Edges:           {} ({:+} last, {:+} total)",
+                    stats.edge_count, growth.edges_since_last, growth.edges_since_start
+                );
+                println!("  Embedded:        {}", stats.embedded_count);
+
+                if let Some(memory) = &stats.memory_stats {
+                    println!("\nMemory Decay:");
+                    println!("  Active:          {}", memory.active_nodes);
+                    println!("  Decayed:         {}", memory.decayed_nodes);
+                    println!("  Average score:   {:.2}", memory.average_score);
+                    println!("  Load factor:     {:.2}", memory.load_factor);
+                }
+
+                let Some(activity) = activity else {
+                    println!("\n(Activity not available from this server)");
+                    return;
+                };
+
+                println!("\nEmbedding Cache:");
+                match &activity.embedding_cache {
+                    Some(cache) => {
+                        println!(
+                            "  Hit rate:        {:.1}% ({}/{})",
+                            cache.cache_hit_rate * 100.0,
+                            cache.cache_hits,
+                            cache.total_embeddings
+                        );
+                        if cache.persistent_cache_hits > 0 {
+                            println!("  From disk:       {}", cache.persistent_cache_hits);
+                        }
+                    }
+                    None => println!("  (no embedding service)"),
+                }
+
+                println!("\nRecent Queries ({}):", activity.recent_queries.len());
+                for query in &activity.recent_queries {
+                    println!(
+                        "  {:>6}ms | {:>4} results | {}",
+                        query.execution_time_ms,
+                        query.result_count,
+                        truncate(&query.query, 50)
+                    );
                 }
             }
         }
@@ -98,6 +569,9 @@ impl OutputFormat {
                     response.total_count,
                     response.execution_time_ms
                 );
+                if let Some(paql) = &response.paql {
+                    println!("  Translated to PaQL: {}", paql);
+                }
 
                 for (i, node) in response.nodes.iter().enumerate() {
                     let id = node["id"].as_str().unwrap_or("");
@@ -109,6 +583,14 @@ impl OutputFormat {
                         node_type,
                         truncate(content, 50)
                     );
+
+                    let explanation = response
+                        .matches
+                        .iter()
+                        .find(|m| m.node_id == id);
+                    if let Some(explanation) = explanation {
+                        print_explanation(content, explanation);
+                    }
                 }
 
                 if response.truncated {
@@ -117,6 +599,105 @@ impl OutputFormat {
             }
         }
     }
+
+    pub fn print_bench_report(&self, report: &BenchReport, regressions: &[Regression]) {
+        match self {
+            Self::Json => self.print_json(&serde_json::json!({
+                "report": report,
+                "regressions": regressions,
+            })),
+            Self::Text => {
+                println!(
+                    "Benchmark ({} nodes, {} edges per node, seed {}):",
+                    report.dataset.nodes, report.dataset.edges_per_node, report.dataset.seed
+                );
+                println!(
+                    "  {:<16} {:>8} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                    "operation", "count", "mean us", "p50 us", "p95 us", "p99 us", "ops/s"
+                );
+                for op in &report.operations {
+                    println!(
+                        "  {:<16} {:>8} {:>10.0} {:>10.0} {:>10.0} {:>10.0} {:>10.0}",
+                        op.name, op.count, op.mean_us, op.p50_us, op.p95_us, op.p99_us, op.throughput
+                    );
+                }
+                for regression in regressions {
+                    println!(
+                        "  REGRESSION {}: p50 {:.0}us -> {:.0}us (+{:.1}%)",
+                        regression.name,
+                        regression.baseline_p50_us,
+                        regression.p50_us,
+                        regression.change * 100.0
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Print edges grouped by relation, each with the node at its other end.
+fn print_edge_groups(
+    label: &str,
+    groups: &BTreeMap<String, Vec<Edge>>,
+    other_end: impl Fn(&Edge) -> (&'static str, Uuid),
+) {
+    let total: usize = groups.values().map(Vec::len).sum();
+    println!("\n{} edges ({}):", label, total);
+    for (relation, edges) in groups {
+        println!("  {} ({}):", relation, edges.len());
+        for edge in edges {
+            let (arrow, node) = other_end(edge);
+            println!("    {} {} (weight {:.2})", arrow, node, edge.weight);
+        }
+    }
+}
+
+/// Print why a query result matched, below its result line.
+fn print_explanation(content: &str, explanation: &MatchExplanation) {
+    if !explanation.terms.is_empty() {
+        println!("       {}", highlight_snippet(content, &explanation.terms, SNIPPET_CHARS));
+    }
+    if let Some(similarity) = explanation.similarity {
+        let space = explanation.space.as_deref().unwrap_or("unknown");
+        println!("       similarity: {:.3} ({})", similarity, space);
+    }
+    for filter in &explanation.filters {
+        println!("       filter: {}", filter);
+    }
+}
+
+/// Characters of content shown around the first matched term.
+const SNIPPET_CHARS: usize = 80;
+
+/// A window of the content around the first matched term, with matched
+/// character ranges in bold.
+fn highlight_snippet(content: &str, terms: &[TermMatch], width: usize) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let first = terms.iter().map(|t| t.start).min().unwrap_or(0);
+    let start = first.saturating_sub(width / 4);
+    let end = (start + width).min(chars.len());
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push_str("...");
+    }
+    for (i, c) in chars.iter().enumerate().take(end).skip(start) {
+        if terms.iter().any(|t| t.start == i) {
+            snippet.push_str("\x1B[1m");
+        }
+        snippet.push(if c.is_control() { ' ' } else { *c });
+        if terms.iter().any(|t| t.end == i + 1) {
+            snippet.push_str("\x1B[0m");
+        }
+    }
+    // Close a highlight cut off by the end of the window
+    if terms.iter().any(|t| t.start < end && t.end > end) {
+        snippet.push_str("\x1B[0m");
+    }
+    if end < chars.len() {
+        snippet.push_str("...");
+    }
+    snippet
 }
 
 /// Truncate a string to a maximum length.
@@ -127,3 +708,7 @@ fn truncate(s: &str, max_len: usize) -> String {
         format!("{}...", &s[..max_len.saturating_sub(3)])
     }
 }
+
+fn join_tags<'a>(tags: impl IntoIterator<Item = &'a String>) -> String {
+    tags.into_iter().map(String::as_str).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,216 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Tabular import.
+//!
+//! Each row of a CSV or Parquet table becomes a node: one column gives its
+//! content and others are copied into its attributes. A row can also link
+//! its node to another one through a relation column and a target column
+//! holding the key of another row or the ID of an existing node. Rows are
+//! read one at a time, so tables larger than memory can be imported.
+
+use std::fs::File;
+
+use anyhow::Result;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use serde_json::{Map, Value};
+
+/// A table row: column name to cell value.
+pub type Row = Map<String, Value>;
+
+/// Format of an imported table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    /// Comma-separated values with a header row.
+    Csv,
+    /// Apache Parquet.
+    Parquet,
+}
+
+impl TableFormat {
+    /// Parse a format name.
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "parquet" => Ok(Self::Parquet),
+            _ => anyhow::bail!("Unsupported table format: {}", name),
+        }
+    }
+
+    /// Guess the format from a file extension.
+    pub fn from_path(path: &str) -> Result<Self> {
+        match std::path::Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => Self::parse(ext),
+            None => anyhow::bail!("Cannot tell the table format of {}; pass --table-format", path),
+        }
+    }
+}
+
+/// An open table: its columns and an iterator over its rows.
+pub struct Table {
+    /// Column names, in table order.
+    pub columns: Vec<String>,
+
+    /// Rows, read as they are iterated.
+    pub rows: Box<dyn Iterator<Item = Result<Row>>>,
+}
+
+impl Table {
+    /// Open a table file.
+    ///
+    /// CSV cells are read as strings; Parquet cells keep their types.
+    pub fn open(path: &str, format: TableFormat) -> Result<Self> {
+        match format {
+            TableFormat::Csv => {
+                let mut reader = csv::Reader::from_path(path)?;
+                let columns: Vec<String> = reader.headers()?.iter().map(str::to_string).collect();
+                let names = columns.clone();
+                let rows = reader.into_records().map(move |record| {
+                    let record = record?;
+                    Ok(names
+                        .iter()
+                        .zip(record.iter())
+                        .map(|(name, cell)| (name.clone(), Value::String(cell.to_string())))
+                        .collect())
+                });
+                Ok(Self {
+                    columns,
+                    rows: Box::new(rows),
+                })
+            }
+            TableFormat::Parquet => {
+                let reader = SerializedFileReader::new(File::open(path)?)?;
+                let columns = reader
+                    .metadata()
+                    .file_metadata()
+                    .schema_descr()
+                    .root_schema()
+                    .get_fields()
+                    .iter()
+                    .map(|field| field.name().to_string())
+                    .collect();
+                let rows = reader.into_iter().map(|row| {
+                    Ok(row?
+                        .get_column_iter()
+                        .map(|(name, field)| (name.clone(), field.to_json_value()))
+                        .collect())
+                });
+                Ok(Self {
+                    columns,
+                    rows: Box::new(rows),
+                })
+            }
+        }
+    }
+}
+
+/// How table columns map to nodes and edges.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMapping {
+    /// Column holding the node content.
+    pub content: String,
+
+    /// Column identifying rows as edge targets.
+    pub key: Option<String>,
+
+    /// Column holding the relation of a row's edge.
+    pub relation: Option<String>,
+
+    /// Column holding the key or node ID an edge points to.
+    pub target: Option<String>,
+
+    /// `(attribute, column)` pairs copied into node attributes.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl ColumnMapping {
+    /// Build a mapping from `field=column` pairs (fields: content, key,
+    /// relation, target) and `attribute=column` pairs.
+    pub fn new(fields: &[(String, String)], attributes: Vec<(String, String)>) -> Result<Self> {
+        let mut mapping = Self {
+            attributes,
+            ..Default::default()
+        };
+        let mut content = None;
+        for (field, column) in fields {
+            let slot = match field.as_str() {
+                "content" => &mut content,
+                "key" => &mut mapping.key,
+                "relation" => &mut mapping.relation,
+                "target" => &mut mapping.target,
+                _ => anyhow::bail!(
+                    "Unknown field '{}' (expected content, key, relation or target)",
+                    field
+                ),
+            };
+            *slot = Some(column.clone());
+        }
+        mapping.content = content.ok_or_else(|| anyhow::anyhow!("Missing --map content=<column>"))?;
+        if mapping.relation.is_some() != mapping.target.is_some() {
+            anyhow::bail!("Edges need both --map relation=<column> and --map target=<column>");
+        }
+        Ok(mapping)
+    }
+
+    /// Fail if the mapping names a column the table does not have.
+    pub fn check_columns(&self, columns: &[String]) -> Result<()> {
+        let mapped = std::iter::once(&self.content)
+            .chain(self.key.iter())
+            .chain(self.relation.iter())
+            .chain(self.target.iter())
+            .chain(self.attributes.iter().map(|(_, column)| column));
+        for column in mapped {
+            if !columns.contains(column) {
+                anyhow::bail!(
+                    "Unknown column '{}' (table columns: {})",
+                    column,
+                    columns.join(", ")
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Content of a row's node; `None` if the content cell is empty.
+    pub fn content(&self, row: &Row) -> Option<String> {
+        cell_text(row, &self.content)
+    }
+
+    /// Key of a row, if it has one.
+    pub fn key(&self, row: &Row) -> Option<String> {
+        self.key.as_deref().and_then(|column| cell_text(row, column))
+    }
+
+    /// `(relation, target)` of a row's edge, if both cells are filled.
+    pub fn link(&self, row: &Row) -> Option<(String, String)> {
+        let relation = cell_text(row, self.relation.as_deref()?)?;
+        let target = cell_text(row, self.target.as_deref()?)?;
+        Some((relation, target))
+    }
+
+    /// Node attributes of a row, skipping empty cells; `None` if none is
+    /// filled.
+    pub fn attributes(&self, row: &Row) -> Option<Value> {
+        let attributes: Map<String, Value> = self
+            .attributes
+            .iter()
+            .filter_map(|(name, column)| match row.get(column) {
+                None | Some(Value::Null) => None,
+                Some(Value::String(s)) if s.trim().is_empty() => None,
+                Some(value) => Some((name.clone(), value.clone())),
+            })
+            .collect();
+        (!attributes.is_empty()).then_some(Value::Object(attributes))
+    }
+}
+
+/// Text of a cell, trimmed; `None` if it is missing or empty.
+fn cell_text(row: &Row, column: &str) -> Option<String> {
+    let text = match row.get(column)? {
+        Value::Null => return None,
+        Value::String(s) => s.trim().to_string(),
+        value => value.to_string(),
+    };
+    (!text.is_empty()).then_some(text)
+}
@@ -0,0 +1,12 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Generated from the server's definition so the two never drift apart
+    tonic_build::configure()
+        .build_server(false)
+        .build_client(true)
+        .compile_protos(&["../api/src/proto/service.proto"], &["../api/src/proto/"])?;
+    Ok(())
+}
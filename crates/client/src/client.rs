@@ -0,0 +1,293 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Pooled gRPC client with retries and REST fallback.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use synton_core::Node;
+use tonic::transport::{Channel, Endpoint};
+
+use crate::{
+    convert::{
+        creator_to_proto, direction_to_proto, node_type_to_proto, parse_uuid, proto_edge_to_core,
+        proto_node_to_core, proto_nodes_to_core,
+    },
+    proto::{self, synton_db_client::SyntonDbClient},
+    rest::RestClient,
+    AddNodeRequest, ClientConfig, ClientError, IngestRequest, IngestResult, QueryRequest,
+    QueryResult, Result, RetrieveResult, TraverseRequest, TraverseResult,
+};
+
+/// Client for a SYNTON-DB server.
+///
+/// Requests are spread round-robin over `pool_size` connections. Calls
+/// failing with `UNAVAILABLE` or `RESOURCE_EXHAUSTED` are retried with
+/// exponential backoff; if the server is still unreachable and a REST
+/// fallback is configured, the call is sent over REST instead.
+#[derive(Debug)]
+pub struct SyntonClient {
+    config: ClientConfig,
+    pool: Vec<SyntonDbClient<Channel>>,
+    next: AtomicUsize,
+    rest: Option<RestClient>,
+}
+
+impl SyntonClient {
+    /// Create a client for `endpoint` with the default configuration.
+    ///
+    /// Connections are opened lazily on first use, so this must be called
+    /// within a Tokio runtime.
+    pub fn new(endpoint: impl Into<String>) -> Result<Self> {
+        Self::with_config(ClientConfig::new(endpoint))
+    }
+
+    /// Create a client with a custom configuration.
+    pub fn with_config(config: ClientConfig) -> Result<Self> {
+        let endpoint = Endpoint::from_shared(config.endpoint.clone())
+            .map_err(|e| ClientError::InvalidEndpoint(format!("{}: {}", config.endpoint, e)))?
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout);
+
+        let pool = (0..config.pool_size.max(1))
+            .map(|_| SyntonDbClient::new(endpoint.connect_lazy()))
+            .collect();
+
+        let rest = config
+            .rest_fallback
+            .as_deref()
+            .map(|url| RestClient::new(url, config.request_timeout))
+            .transpose()?;
+
+        Ok(Self {
+            config,
+            pool,
+            next: AtomicUsize::new(0),
+            rest,
+        })
+    }
+
+    /// Get the configuration.
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// Add a node.
+    pub async fn add_node(&self, request: AddNodeRequest) -> Result<Node> {
+        let message = proto::AddNodeRequest {
+            content: request.content.clone(),
+            node_type: node_type_to_proto(request.node_type),
+            embedding: request.embedding.clone().unwrap_or_default(),
+            attributes: request.attributes.clone(),
+        };
+
+        let result = self
+            .call("AddNode", |mut client| {
+                let message = message.clone();
+                async move { client.add_node(message).await }
+            })
+            .await;
+
+        match result {
+            Ok(response) => response
+                .node
+                .ok_or_else(|| ClientError::InvalidResponse("missing node".to_string()))
+                .and_then(proto_node_to_core),
+            Err(e) => match self.fallback(&e) {
+                Some(rest) => rest.add_node(&request).await,
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Run a PaQL or natural-language query.
+    pub async fn query(&self, request: QueryRequest) -> Result<QueryResult> {
+        let message = proto::QueryRequest {
+            query: request.query.clone(),
+            limit: request.limit.unwrap_or(0) as u32,
+            include_archived: request.include_archived,
+            rank: request.rank.clone().unwrap_or_default(),
+            min_confidence: request.min_confidence.unwrap_or(0.0),
+            recency_weight: request.recency_weight.unwrap_or(0.0),
+            ..Default::default()
+        };
+
+        let result = self
+            .call("Query", |mut client| {
+                let message = message.clone();
+                async move { client.query(message).await }
+            })
+            .await;
+
+        match result {
+            Ok(response) => Ok(QueryResult {
+                nodes: proto_nodes_to_core(response.nodes)?,
+                total_count: response.total_count as usize,
+                truncated: response.truncated,
+                approximate: response.approximate,
+            }),
+            Err(e) => match self.fallback(&e) {
+                Some(rest) => rest.query(&request).await,
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Traverse the graph from a node.
+    pub async fn traverse(&self, request: TraverseRequest) -> Result<TraverseResult> {
+        let message = proto::TraverseRequest {
+            start_id: request.start_id.to_string(),
+            max_depth: request.max_depth as u32,
+            max_nodes: request.max_nodes as u32,
+            direction: direction_to_proto(request.direction),
+            created_by: request
+                .created_by
+                .iter()
+                .copied()
+                .map(creator_to_proto)
+                .collect(),
+            max_estimated_cost: request.max_estimated_cost.unwrap_or(0),
+            follow_inverses: request.follow_inverses,
+            min_confidence: request.min_confidence.unwrap_or(0.0),
+        };
+
+        let result = self
+            .call("Traverse", |mut client| {
+                let message = message.clone();
+                async move { client.traverse(message).await }
+            })
+            .await;
+
+        match result {
+            Ok(response) => Ok(TraverseResult {
+                nodes: proto_nodes_to_core(response.nodes)?,
+                edges: response
+                    .edges
+                    .into_iter()
+                    .map(proto_edge_to_core)
+                    .collect::<Result<_>>()?,
+                depth: response.depth as usize,
+                truncated: response.truncated,
+            }),
+            Err(e) => match self.fallback(&e) {
+                Some(rest) => rest.traverse(&request).await,
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Chunk, embed and store a document.
+    pub async fn ingest(&self, request: IngestRequest) -> Result<IngestResult> {
+        let message = proto::IngestDocumentRequest {
+            title: request.title.clone().unwrap_or_default(),
+            content: request.content.clone(),
+            skip_embedding: !request.embed,
+            metadata: request.metadata.clone(),
+        };
+
+        let result = self
+            .call("IngestDocument", |mut client| {
+                let message = message.clone();
+                async move { client.ingest_document(message).await }
+            })
+            .await;
+
+        match result {
+            Ok(response) => Ok(IngestResult {
+                document_id: parse_uuid(&response.document_id)?,
+                chunk_ids: response
+                    .chunk_ids
+                    .iter()
+                    .map(|id| parse_uuid(id))
+                    .collect::<Result<_>>()?,
+                embedded: response.embedded,
+                processing_time_ms: response.processing_time_ms,
+            }),
+            Err(e) => match self.fallback(&e) {
+                Some(rest) => rest.ingest(&request).await,
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Retrieve the `k` most relevant nodes for a query with Graph-RAG
+    /// (vector search expanded over the graph).
+    pub async fn rag_retrieve(&self, query: &str, k: usize) -> Result<RetrieveResult> {
+        let message = proto::HybridSearchRequest {
+            query: query.to_string(),
+            k: k as u32,
+        };
+
+        let result = self
+            .call("HybridSearch", |mut client| {
+                let message = message.clone();
+                async move { client.hybrid_search(message).await }
+            })
+            .await;
+
+        match result {
+            Ok(response) => Ok(RetrieveResult {
+                nodes: proto_nodes_to_core(response.nodes)?,
+                query_id: parse_uuid(&response.query_id)?,
+            }),
+            Err(e) => match self.fallback(&e) {
+                Some(rest) => rest.rag_retrieve(query, k).await,
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Next pooled connection, round-robin.
+    fn client(&self) -> SyntonDbClient<Channel> {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.pool.len();
+        self.pool[index].clone()
+    }
+
+    /// Run a gRPC call, retrying while the server is unavailable or
+    /// overloaded.
+    async fn call<T, F, Fut>(&self, method: &'static str, mut f: F) -> Result<T>
+    where
+        F: FnMut(SyntonDbClient<Channel>) -> Fut,
+        Fut: Future<Output = std::result::Result<tonic::Response<T>, tonic::Status>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match f(self.client()).await {
+                Ok(response) => return Ok(response.into_inner()),
+                Err(status) if attempt < self.config.max_retries && is_retryable(&status) => {
+                    attempt += 1;
+                    let delay = self.config.backoff(attempt);
+                    tracing::debug!(
+                        method,
+                        attempt,
+                        code = ?status.code(),
+                        "Retrying gRPC call in {:?}",
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(status) => return Err(status.into()),
+            }
+        }
+    }
+
+    /// The REST fallback to use after `error`, if any.
+    fn fallback(&self, error: &ClientError) -> Option<&RestClient> {
+        let rest = self.rest.as_ref().filter(|_| error.is_unavailable())?;
+        tracing::warn!(
+            "gRPC endpoint unavailable ({}), falling back to REST",
+            error
+        );
+        Some(rest)
+    }
+}
+
+/// Whether a failed call may succeed if retried.
+fn is_retryable(status: &tonic::Status) -> bool {
+    matches!(
+        status.code(),
+        tonic::Code::Unavailable | tonic::Code::ResourceExhausted
+    )
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Client configuration.
+
+use std::time::Duration;
+
+/// Connection, retry and fallback settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientConfig {
+    /// gRPC endpoint, e.g. `http://localhost:50051`.
+    pub endpoint: String,
+
+    /// Number of connections requests are spread over (at least 1).
+    pub pool_size: usize,
+
+    /// Timeout for establishing a connection.
+    pub connect_timeout: Duration,
+
+    /// Timeout for a single request.
+    pub request_timeout: Duration,
+
+    /// Retries of a call that failed because the server was unavailable or
+    /// overloaded.
+    pub max_retries: u32,
+
+    /// Delay before the first retry; doubled for each further retry.
+    pub retry_backoff: Duration,
+
+    /// REST base URL used when the gRPC endpoint stays unavailable, e.g.
+    /// `http://localhost:8080`.
+    pub rest_fallback: Option<String>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:50051".to_string(),
+            pool_size: 4,
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(30),
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(100),
+            rest_fallback: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Create a config for the given gRPC endpoint.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the number of pooled connections.
+    pub fn with_pool_size(mut self, size: usize) -> Self {
+        self.pool_size = size.max(1);
+        self
+    }
+
+    /// Set the request timeout.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Set the retry policy.
+    pub fn with_retries(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Fall back to the REST API at `base_url` when gRPC is unavailable.
+    pub fn with_rest_fallback(mut self, base_url: impl Into<String>) -> Self {
+        self.rest_fallback = Some(base_url.into());
+        self
+    }
+
+    /// Delay before retry number `attempt` (starting at 1).
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles() {
+        let config = ClientConfig::new("http://db:50051")
+            .with_retries(5, Duration::from_millis(50))
+            .with_pool_size(0);
+        assert_eq!(config.pool_size, 1);
+        assert_eq!(config.backoff(1), Duration::from_millis(50));
+        assert_eq!(config.backoff(3), Duration::from_millis(200));
+    }
+}
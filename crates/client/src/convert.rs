@@ -0,0 +1,232 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Conversions between the wire types and the core types.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use synton_core::{
+    Edge, EdgeCreator, EdgeProvenance, Node, NodeMeta, NodeType, Relation, Source,
+    TraverseDirection,
+};
+use uuid::Uuid;
+
+use crate::{proto, ClientError, Result};
+
+/// Parse a UUID sent by the server.
+pub(crate) fn parse_uuid(s: &str) -> Result<Uuid> {
+    Uuid::parse_str(s).map_err(|_| ClientError::InvalidResponse(format!("invalid UUID '{}'", s)))
+}
+
+/// Convert a core node type to the proto enum value.
+pub(crate) fn node_type_to_proto(node_type: NodeType) -> i32 {
+    match node_type {
+        NodeType::Entity => proto::NodeType::Entity as i32,
+        NodeType::Concept => proto::NodeType::Concept as i32,
+        NodeType::Fact => proto::NodeType::Fact as i32,
+        NodeType::RawChunk => proto::NodeType::RawChunk as i32,
+    }
+}
+
+/// Convert a proto node type to the core type.
+fn proto_node_type_to_core(node_type: i32) -> NodeType {
+    match node_type {
+        2 => NodeType::Concept,  // CONCEPT
+        3 => NodeType::Fact,     // FACT
+        4 => NodeType::RawChunk, // RAW_CHUNK
+        _ => NodeType::Entity,
+    }
+}
+
+/// Convert a traversal direction to the proto enum value.
+pub(crate) fn direction_to_proto(direction: TraverseDirection) -> i32 {
+    match direction {
+        TraverseDirection::Outgoing => proto::TraverseDirection::Forward as i32,
+        TraverseDirection::Incoming => proto::TraverseDirection::Backward as i32,
+        TraverseDirection::Both => proto::TraverseDirection::Both as i32,
+    }
+}
+
+/// Convert an edge creator to the proto enum value.
+pub(crate) fn creator_to_proto(creator: EdgeCreator) -> i32 {
+    match creator {
+        EdgeCreator::User => proto::EdgeCreator::User as i32,
+        EdgeCreator::Tool => proto::EdgeCreator::Tool as i32,
+        EdgeCreator::ExtractionPipeline => proto::EdgeCreator::ExtractionPipeline as i32,
+    }
+}
+
+/// Convert a proto edge creator to the core type.
+fn proto_creator_to_core(creator: i32) -> EdgeCreator {
+    match creator {
+        1 => EdgeCreator::Tool,               // TOOL
+        2 => EdgeCreator::ExtractionPipeline, // EXTRACTION_PIPELINE
+        _ => EdgeCreator::User,
+    }
+}
+
+/// Convert a proto relation to the core type.
+fn proto_relation_to_core(relation: i32) -> Relation {
+    match relation {
+        1 => Relation::IsA,           // IS_A
+        2 => Relation::IsPartOf,      // PART_OF
+        3 => Relation::Causes,        // CAUSES
+        5 => Relation::Contradicts,   // CONTRADICTS
+        6 => Relation::HappenedAfter, // HAPPENED_AFTER
+        7 => Relation::BelongsTo,     // BELONGS_TO
+        _ => Relation::SimilarTo,
+    }
+}
+
+/// Parse the server's `Debug` rendering of a node source.
+fn parse_source(source: &str) -> Source {
+    let inner = |prefix: &str| {
+        source
+            .strip_prefix(prefix)
+            .and_then(|s| s.strip_suffix("\")"))
+            .map(str::to_string)
+    };
+
+    match source {
+        "UserInput" | "" => Source::UserInput,
+        "FileUpload" => Source::FileUpload,
+        "WebCrawl" => Source::WebCrawl,
+        "ApiImport" => Source::ApiImport,
+        "AutoExtracted" => Source::AutoExtracted,
+        _ => {
+            if let Some(url) = inner("Url(\"") {
+                Source::Url(url)
+            } else if let Some(name) = inner("File(\"") {
+                Source::File(name)
+            } else {
+                Source::Custom(inner("Custom(\"").unwrap_or_else(|| source.to_string()))
+            }
+        }
+    }
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_default()
+}
+
+fn attributes_to_json(attributes: HashMap<String, String>) -> serde_json::Value {
+    if attributes.is_empty() {
+        return serde_json::Value::Object(Default::default());
+    }
+    serde_json::to_value(attributes).unwrap_or_default()
+}
+
+/// Convert a proto node to the core type.
+pub(crate) fn proto_node_to_core(node: proto::Node) -> Result<Node> {
+    let mut meta = NodeMeta::new(parse_source(&node.source));
+    meta.created_at = timestamp(node.created_at);
+    meta.updated_at = timestamp(node.updated_at);
+    meta.access_score = node.access_score;
+    // Servers without node versions send 0
+    meta.version = node.version.max(1);
+
+    Ok(Node {
+        id: parse_uuid(&node.id)?,
+        content: node.content.into(),
+        embedding: if node.embedding.is_empty() {
+            None
+        } else {
+            Some(node.embedding)
+        },
+        meta,
+        node_type: proto_node_type_to_core(node.node_type),
+        attributes: attributes_to_json(node.attributes),
+        tags: node.tags.into_iter().collect(),
+    })
+}
+
+/// Convert a proto edge to the core type.
+pub(crate) fn proto_edge_to_core(edge: proto::Edge) -> Result<Edge> {
+    let provenance = edge
+        .provenance
+        .map(|p| EdgeProvenance {
+            created_by: proto_creator_to_core(p.created_by),
+            source_document: Uuid::parse_str(&p.source_document).ok(),
+            confidence: p.confidence,
+        })
+        .unwrap_or_default();
+
+    Ok(Edge {
+        source: parse_uuid(&edge.source)?,
+        target: parse_uuid(&edge.target)?,
+        relation: proto_relation_to_core(edge.relation),
+        weight: edge.weight,
+        vector: if edge.vector.is_empty() {
+            None
+        } else {
+            Some(edge.vector)
+        },
+        created_at: timestamp(edge.created_at),
+        expired: edge.expired,
+        replaced_by: Uuid::parse_str(&edge.replaced_by).ok(),
+        attributes: attributes_to_json(edge.attributes),
+        provenance,
+        pending_review: edge.pending_review,
+    })
+}
+
+/// Convert a list of proto nodes.
+pub(crate) fn proto_nodes_to_core(nodes: Vec<proto::Node>) -> Result<Vec<Node>> {
+    nodes.into_iter().map(proto_node_to_core).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(parse_source("WebCrawl"), Source::WebCrawl);
+        assert_eq!(
+            parse_source("Url(\"https://example.com\")"),
+            Source::Url("https://example.com".to_string())
+        );
+        assert_eq!(
+            parse_source("Custom(\"slack\")"),
+            Source::Custom("slack".to_string())
+        );
+    }
+
+    #[test]
+    fn test_proto_node_to_core() {
+        let id = Uuid::new_v4();
+        let node = proto_node_to_core(proto::Node {
+            id: id.to_string(),
+            content: "Paris".to_string(),
+            node_type: proto::NodeType::Concept as i32,
+            embedding: vec![0.5, 0.5],
+            created_at: 1_700_000_000,
+            updated_at: 1_700_000_000,
+            access_score: 2.5,
+            source: "UserInput".to_string(),
+            attributes: HashMap::from([("lang".to_string(), "fr".to_string())]),
+            tags: vec!["capital".to_string()],
+            version: 3,
+        })
+        .unwrap();
+
+        assert_eq!(node.id, id);
+        assert_eq!(node.node_type, NodeType::Concept);
+        assert_eq!(node.embedding, Some(vec![0.5, 0.5]));
+        assert_eq!(node.meta.created_at.timestamp(), 1_700_000_000);
+        assert_eq!(node.attributes["lang"], "fr");
+        assert!(node.tags.contains("capital"));
+        assert_eq!(node.meta.version, 3);
+
+        let bad = proto::Node {
+            id: "not-a-uuid".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            proto_node_to_core(bad),
+            Err(ClientError::InvalidResponse(_))
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Error types for the client.
+
+use synton_error::{Coded, ErrorBody, ErrorCode};
+
+/// Client error type.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The endpoint URL could not be parsed.
+    #[error("Invalid endpoint: {0}")]
+    InvalidEndpoint(String),
+
+    /// The gRPC call failed.
+    #[error("gRPC error ({}): {}", .0.code(), .0.message())]
+    Status(Box<tonic::Status>),
+
+    /// The REST fallback request could not be sent.
+    #[error("REST request failed: {0}")]
+    Http(String),
+
+    /// The REST fallback answered with an error.
+    #[error("{}", .body.report())]
+    Server { status: u16, body: ErrorBody },
+
+    /// The server's response could not be decoded.
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+impl ClientError {
+    /// Whether the server could not be reached, so the call may be retried
+    /// or sent over the REST fallback.
+    pub fn is_unavailable(&self) -> bool {
+        match self {
+            Self::Status(status) => status.code() == tonic::Code::Unavailable,
+            Self::Http(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<tonic::Status> for ClientError {
+    fn from(status: tonic::Status) -> Self {
+        Self::Status(Box::new(status))
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_decode() {
+            Self::InvalidResponse(e.to_string())
+        } else {
+            Self::Http(e.to_string())
+        }
+    }
+}
+
+impl Coded for ClientError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidEndpoint(_) => ErrorCode::InvalidInput,
+            Self::Status(status) => match status.code() {
+                tonic::Code::NotFound => ErrorCode::NotFound,
+                tonic::Code::InvalidArgument => ErrorCode::InvalidInput,
+                tonic::Code::AlreadyExists => ErrorCode::Conflict,
+                tonic::Code::FailedPrecondition => ErrorCode::LimitExceeded,
+                tonic::Code::ResourceExhausted => ErrorCode::Overloaded,
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => ErrorCode::Unavailable,
+                tonic::Code::Unimplemented => ErrorCode::NotImplemented,
+                _ => ErrorCode::Internal,
+            },
+            Self::Http(_) => ErrorCode::Unavailable,
+            Self::Server { body, .. } => body.code,
+            Self::InvalidResponse(_) => ErrorCode::Serialization,
+        }
+    }
+}
+
+/// Result type alias for client operations.
+pub type Result<T> = std::result::Result<T, ClientError>;
@@ -0,0 +1,47 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Rust client for SYNTON-DB.
+//!
+//! [`SyntonClient`] wraps the generated gRPC client with typed methods
+//! returning core [`Node`](synton_core::Node)s and
+//! [`Edge`](synton_core::Edge)s, a small connection pool, retries with
+//! exponential backoff and an optional REST fallback.
+//!
+//! ```no_run
+//! use synton_client::{ClientConfig, QueryRequest, SyntonClient};
+//!
+//! # async fn run() -> synton_client::Result<()> {
+//! let config = ClientConfig::new("http://localhost:50051")
+//!     .with_rest_fallback("http://localhost:8080");
+//! let client = SyntonClient::with_config(config)?;
+//!
+//! let result = client.query(QueryRequest::new("machine learning").with_limit(5)).await?;
+//! for node in result.nodes {
+//!     println!("{}", node.content());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod client;
+mod config;
+mod convert;
+mod error;
+mod request;
+mod rest;
+
+/// Generated gRPC types.
+pub mod proto {
+    tonic::include_proto!("synton.v1");
+}
+
+pub use client::SyntonClient;
+pub use config::ClientConfig;
+pub use error::{ClientError, Result};
+pub use request::{
+    AddNodeRequest, IngestRequest, IngestResult, QueryRequest, QueryResult, RetrieveResult,
+    TraverseRequest, TraverseResult,
+};
+pub use synton_core::{EdgeCreator, NodeType, TraverseDirection};
@@ -0,0 +1,290 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Typed requests and results of the client calls.
+
+use std::collections::HashMap;
+
+use synton_core::{Edge, EdgeCreator, Node, NodeType, TraverseDirection};
+use uuid::Uuid;
+
+/// Request to add a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddNodeRequest {
+    /// Node content.
+    pub content: String,
+
+    /// Node type.
+    pub node_type: NodeType,
+
+    /// Precomputed embedding; the server embeds the content if omitted.
+    pub embedding: Option<Vec<f32>>,
+
+    /// String attributes.
+    pub attributes: HashMap<String, String>,
+}
+
+impl AddNodeRequest {
+    /// Create a request for a node with the given content and type.
+    pub fn new(content: impl Into<String>, node_type: NodeType) -> Self {
+        Self {
+            content: content.into(),
+            node_type,
+            embedding: None,
+            attributes: HashMap::new(),
+        }
+    }
+
+    /// Attach a precomputed embedding.
+    pub fn with_embedding(mut self, embedding: Vec<f32>) -> Self {
+        self.embedding = Some(embedding);
+        self
+    }
+
+    /// Add an attribute.
+    pub fn with_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Request to run a PaQL or natural-language query.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QueryRequest {
+    /// Query string.
+    pub query: String,
+
+    /// Maximum number of results.
+    pub limit: Option<usize>,
+
+    /// Whether to also search archived nodes.
+    pub include_archived: bool,
+
+    /// Ranking expression, e.g. `0.6*similarity + 0.4*recency`.
+    pub rank: Option<String>,
+
+    /// Minimum node confidence.
+    pub min_confidence: Option<f32>,
+
+    /// Share of the score taken from current memory strength (0.0 - 1.0).
+    pub recency_weight: Option<f32>,
+}
+
+impl QueryRequest {
+    /// Create a query request.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum number of results.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Also search archived nodes.
+    pub fn with_archived(mut self) -> Self {
+        self.include_archived = true;
+        self
+    }
+
+    /// Order results by a ranking expression.
+    pub fn with_rank(mut self, rank: impl Into<String>) -> Self {
+        self.rank = Some(rank.into());
+        self
+    }
+
+    /// Drop nodes below a confidence.
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = Some(min_confidence);
+        self
+    }
+
+    /// Weight results by their current memory strength.
+    pub fn with_recency_weight(mut self, weight: f32) -> Self {
+        self.recency_weight = Some(weight);
+        self
+    }
+}
+
+/// Result of a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResult {
+    /// Matching nodes.
+    pub nodes: Vec<Node>,
+
+    /// Total number of matches (may exceed the returned nodes).
+    pub total_count: usize,
+
+    /// Whether the results were truncated.
+    pub truncated: bool,
+
+    /// Whether `total_count` is an estimate.
+    pub approximate: bool,
+}
+
+/// Request to traverse the graph from a node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraverseRequest {
+    /// Starting node.
+    pub start_id: Uuid,
+
+    /// Maximum depth.
+    pub max_depth: usize,
+
+    /// Maximum number of nodes returned.
+    pub max_nodes: usize,
+
+    /// Traversal direction.
+    pub direction: TraverseDirection,
+
+    /// Only follow edges made by these creators (empty = all).
+    pub created_by: Vec<EdgeCreator>,
+
+    /// Reject the traversal if its estimated cost exceeds this value.
+    pub max_estimated_cost: Option<u64>,
+
+    /// Report incoming edges as their inverse relation.
+    pub follow_inverses: bool,
+
+    /// Only follow edges with at least this extraction confidence.
+    pub min_confidence: Option<f32>,
+}
+
+impl TraverseRequest {
+    /// Create a forward traversal of depth 2 returning up to 100 nodes.
+    pub fn new(start_id: Uuid) -> Self {
+        Self {
+            start_id,
+            max_depth: 2,
+            max_nodes: 100,
+            direction: TraverseDirection::Outgoing,
+            created_by: Vec::new(),
+            max_estimated_cost: None,
+            follow_inverses: false,
+            min_confidence: None,
+        }
+    }
+
+    /// Set the maximum depth.
+    pub fn with_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set the maximum number of nodes.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Set the direction.
+    pub fn with_direction(mut self, direction: TraverseDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Only follow edges made by the given creator (may be repeated).
+    pub fn with_created_by(mut self, creator: EdgeCreator) -> Self {
+        self.created_by.push(creator);
+        self
+    }
+
+    /// Only follow edges with at least this extraction confidence.
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = Some(min_confidence);
+        self
+    }
+}
+
+/// Result of a traversal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraverseResult {
+    /// Visited nodes.
+    pub nodes: Vec<Node>,
+
+    /// Followed edges.
+    pub edges: Vec<Edge>,
+
+    /// Maximum depth reached.
+    pub depth: usize,
+
+    /// Whether the traversal hit a limit.
+    pub truncated: bool,
+}
+
+/// Request to ingest a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestRequest {
+    /// Document title.
+    pub title: Option<String>,
+
+    /// Document text.
+    pub content: String,
+
+    /// Whether to embed the chunks.
+    pub embed: bool,
+
+    /// Metadata attached to every chunk.
+    pub metadata: HashMap<String, String>,
+}
+
+impl IngestRequest {
+    /// Create a request to ingest and embed a document.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            title: None,
+            content: content.into(),
+            embed: true,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Set the title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Skip embedding the chunks.
+    pub fn without_embedding(mut self) -> Self {
+        self.embed = false;
+        self
+    }
+
+    /// Add chunk metadata.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Result of ingesting a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestResult {
+    /// Document (root node) ID.
+    pub document_id: Uuid,
+
+    /// IDs of the created chunks.
+    pub chunk_ids: Vec<Uuid>,
+
+    /// Whether the chunks were embedded.
+    pub embedded: bool,
+
+    /// Server processing time in milliseconds.
+    pub processing_time_ms: u64,
+}
+
+/// Result of a Graph-RAG retrieval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetrieveResult {
+    /// Retrieved nodes, most relevant first.
+    pub nodes: Vec<Node>,
+
+    /// ID for submitting relevance feedback on these results.
+    pub query_id: Uuid,
+}
@@ -0,0 +1,179 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! REST fallback used when the gRPC endpoint is unavailable.
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use synton_core::{Edge, Node, TraverseDirection};
+use synton_error::{ErrorBody, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    AddNodeRequest, ClientError, IngestRequest, IngestResult, QueryRequest, QueryResult, Result,
+    RetrieveResult, TraverseRequest, TraverseResult,
+};
+
+#[derive(Deserialize)]
+struct AddNodeResponse {
+    node: Node,
+}
+
+#[derive(Deserialize)]
+struct QueryResponse {
+    nodes: Vec<Node>,
+    total_count: usize,
+    truncated: bool,
+    #[serde(default)]
+    approximate: bool,
+}
+
+#[derive(Deserialize)]
+struct TraverseResponse {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    depth: usize,
+    truncated: bool,
+}
+
+#[derive(Deserialize)]
+struct ChunkInfo {
+    id: Uuid,
+}
+
+#[derive(Deserialize)]
+struct IngestDocumentResponse {
+    document_id: Uuid,
+    chunks: Vec<ChunkInfo>,
+    embedded: bool,
+    processing_time_ms: u64,
+}
+
+#[derive(Deserialize)]
+struct HybridSearchResponse {
+    nodes: Vec<Node>,
+    query_id: Uuid,
+}
+
+/// JSON client for the REST API.
+#[derive(Debug, Clone)]
+pub(crate) struct RestClient {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl RestClient {
+    /// Create a client for the REST API at `base_url`.
+    pub(crate) fn new(base_url: &str, timeout: std::time::Duration) -> Result<Self> {
+        let http = reqwest::Client::builder().timeout(timeout).build()?;
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http,
+        })
+    }
+
+    /// POST a JSON body and decode the JSON response.
+    async fn post<T: DeserializeOwned>(&self, path: &str, body: serde_json::Value) -> Result<T> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.http.post(&url).json(&body).send().await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let text = response.text().await.unwrap_or_default();
+            let body = serde_json::from_str::<ErrorBody>(&text).unwrap_or_else(|_| ErrorBody {
+                error: format!("{} {}", status, text.trim()),
+                code: ErrorCode::Internal,
+                causes: Vec::new(),
+                trace_id: None,
+                fields: Vec::new(),
+                current_version: None,
+            });
+            return Err(ClientError::Server {
+                status: status.as_u16(),
+                body,
+            });
+        }
+        Ok(response.json().await?)
+    }
+
+    pub(crate) async fn add_node(&self, request: &AddNodeRequest) -> Result<Node> {
+        let body = json!({
+            "content": request.content,
+            "node_type": request.node_type,
+            "embedding": request.embedding,
+            "attributes": request.attributes,
+        });
+        let response: AddNodeResponse = self.post("/nodes", body).await?;
+        Ok(response.node)
+    }
+
+    pub(crate) async fn query(&self, request: &QueryRequest) -> Result<QueryResult> {
+        let body = json!({
+            "query": request.query,
+            "limit": request.limit,
+            "include_metadata": false,
+            "include_archived": request.include_archived,
+            "rank": request.rank,
+            "min_confidence": request.min_confidence,
+            "recency_weight": request.recency_weight,
+        });
+        let response: QueryResponse = self.post("/query", body).await?;
+        Ok(QueryResult {
+            nodes: response.nodes,
+            total_count: response.total_count,
+            truncated: response.truncated,
+            approximate: response.approximate,
+        })
+    }
+
+    pub(crate) async fn traverse(&self, request: &TraverseRequest) -> Result<TraverseResult> {
+        let direction = match request.direction {
+            TraverseDirection::Outgoing => "Forward",
+            TraverseDirection::Incoming => "Backward",
+            TraverseDirection::Both => "Both",
+        };
+        let body = json!({
+            "start_id": request.start_id,
+            "max_depth": request.max_depth,
+            "max_nodes": request.max_nodes,
+            "direction": direction,
+            "created_by": request.created_by,
+            "max_estimated_cost": request.max_estimated_cost,
+            "follow_inverses": request.follow_inverses,
+            "min_confidence": request.min_confidence,
+        });
+        let response: TraverseResponse = self.post("/traverse", body).await?;
+        Ok(TraverseResult {
+            nodes: response.nodes,
+            edges: response.edges,
+            depth: response.depth,
+            truncated: response.truncated,
+        })
+    }
+
+    pub(crate) async fn ingest(&self, request: &IngestRequest) -> Result<IngestResult> {
+        let body = json!({
+            "title": request.title,
+            "content": request.content,
+            "embed": request.embed,
+            "metadata": (!request.metadata.is_empty()).then_some(&request.metadata),
+        });
+        let response: IngestDocumentResponse = self.post("/documents", body).await?;
+        Ok(IngestResult {
+            document_id: response.document_id,
+            chunk_ids: response.chunks.into_iter().map(|c| c.id).collect(),
+            embedded: response.embedded,
+            processing_time_ms: response.processing_time_ms,
+        })
+    }
+
+    pub(crate) async fn rag_retrieve(&self, query: &str, k: usize) -> Result<RetrieveResult> {
+        let body = json!({ "query": query, "k": k });
+        let response: HybridSearchResponse = self.post("/hybrid_search", body).await?;
+        Ok(RetrieveResult {
+            nodes: response.nodes,
+            query_id: response.query_id,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! End-to-end tests of the client against in-process servers.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use synton_api::{create_grpc_router, SyntonDbService};
+use synton_client::{
+    AddNodeRequest, ClientConfig, ClientError, IngestRequest, QueryRequest, SyntonClient,
+    TraverseRequest,
+};
+use synton_core::NodeType;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+
+/// Start a gRPC server on a free port and return its endpoint.
+async fn start_grpc_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let service = create_grpc_router(Arc::new(SyntonDbService::new()));
+
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(service)
+            .serve_with_incoming(TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+    format!("http://{}", addr)
+}
+
+/// Start a REST server on a free port and return its base URL.
+async fn start_rest_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, synton_api::create_router())
+            .await
+            .unwrap();
+    });
+    format!("http://{}", addr)
+}
+
+/// An endpoint nothing listens on.
+async fn unused_endpoint() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_grpc_round_trip() {
+    let endpoint = start_grpc_server().await;
+    let client = SyntonClient::with_config(ClientConfig::new(endpoint).with_pool_size(2)).unwrap();
+
+    let node = client
+        .add_node(
+            AddNodeRequest::new("Machine learning is a field of AI", NodeType::Concept)
+                .with_attribute("topic", "ml"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(node.node_type, NodeType::Concept);
+    assert_eq!(node.attributes["topic"], "ml");
+
+    let result = client
+        .query(QueryRequest::new("machine").with_limit(5))
+        .await
+        .unwrap();
+    assert!(result.nodes.iter().any(|n| n.id == node.id));
+
+    let traversal = client
+        .traverse(TraverseRequest::new(node.id).with_depth(1))
+        .await
+        .unwrap();
+    assert!(traversal.nodes.iter().any(|n| n.id == node.id));
+
+    let ingested = client
+        .ingest(
+            IngestRequest::new("First paragraph.\n\nSecond paragraph.")
+                .with_title("Notes")
+                .without_embedding(),
+        )
+        .await
+        .unwrap();
+    assert!(!ingested.embedded);
+    assert!(!ingested.chunk_ids.is_empty());
+}
+
+#[tokio::test]
+async fn test_grpc_errors_are_not_retried() {
+    let endpoint = start_grpc_server().await;
+    let client = SyntonClient::new(endpoint).unwrap();
+
+    let err = client
+        .query(QueryRequest::new("machine").with_rank("0.5 *"))
+        .await
+        .unwrap_err();
+    assert!(matches!(&err, ClientError::Status(s) if s.code() == tonic::Code::InvalidArgument));
+    assert!(!err.is_unavailable());
+}
+
+#[tokio::test]
+async fn test_rest_fallback_when_grpc_unavailable() {
+    let rest = start_rest_server().await;
+    let config = ClientConfig::new(unused_endpoint().await)
+        .with_retries(1, Duration::from_millis(10))
+        .with_rest_fallback(rest);
+    let client = SyntonClient::with_config(config).unwrap();
+
+    let node = client
+        .add_node(AddNodeRequest::new("Fallback node", NodeType::Fact))
+        .await
+        .unwrap();
+    let result = client.query(QueryRequest::new("fallback")).await.unwrap();
+    assert!(result.nodes.iter().any(|n| n.id == node.id));
+}
+
+#[tokio::test]
+async fn test_unavailable_without_fallback() {
+    let config = ClientConfig::new(unused_endpoint().await).with_retries(0, Duration::ZERO);
+    let client = SyntonClient::with_config(config).unwrap();
+
+    let err = client
+        .query(QueryRequest::new("anything"))
+        .await
+        .unwrap_err();
+    assert!(err.is_unavailable());
+}
@@ -1,7 +1,9 @@
 //! Persistent trace data collector for SYNTON-DB.
 //!
 //! This crate provides persistent storage for trace data using RocksDB,
-//! as well as query interfaces for retrieving stored traces.
+//! as well as query interfaces for retrieving stored traces. A
+//! [`RetentionConfig`] bounds the raw spans kept, while hourly
+//! [`SpanRollup`]s preserve per-span latency history.
 
 #![warn(missing_docs)]
 #![warn(clippy::all)]
@@ -9,11 +11,15 @@
 pub mod error;
 pub mod persistence;
 pub mod query;
+pub mod retention;
+pub mod rollup;
 
 // Re-export commonly used types
 pub use error::{CollectorError, CollectorResult};
 pub use persistence::{PersistenceBackend, RocksDbPersistence};
 pub use query::{QueryFilter, TraceQuery};
+pub use retention::{CompactionReport, RetentionConfig};
+pub use rollup::SpanRollup;
 
 use synton_instrument::{SpanId, TraceId, TraceSpan};
 
@@ -26,6 +32,9 @@ pub const DEFAULT_SPAN_COLUMN: &str = "spans";
 /// Default column family name for events.
 pub const DEFAULT_EVENT_COLUMN: &str = "events";
 
+/// Default column family name for hourly span rollups.
+pub const DEFAULT_ROLLUP_COLUMN: &str = "rollups";
+
 /// Trace collector with persistent storage.
 pub struct TraceCollector {
     /// Persistence backend.
@@ -140,6 +149,41 @@ impl TraceCollector {
     pub fn stats(&self) -> CollectorResult<persistence::StorageStats> {
         self.persistence.stats()
     }
+
+    /// Get hourly duration rollups matching a filter.
+    pub fn rollups(&self, filter: &QueryFilter) -> CollectorResult<Vec<SpanRollup>> {
+        self.persistence.rollups(filter)
+    }
+
+    /// Roll up completed hours and delete data outside the retention policy.
+    pub fn compact(&self, config: &RetentionConfig) -> CollectorResult<CompactionReport> {
+        self.persistence.compact(config, chrono::Utc::now())
+    }
+
+    /// Run [`TraceCollector::compact`] every `compaction_interval_secs` in the
+    /// background until the returned task is aborted.
+    pub fn spawn_retention(
+        self: std::sync::Arc<Self>,
+        config: RetentionConfig,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let period = std::time::Duration::from_secs(config.compaction_interval_secs.max(1));
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+
+                // Compaction scans the whole span column; keep it off the
+                // async workers
+                let collector = std::sync::Arc::clone(&self);
+                let policy = config.clone();
+                match tokio::task::spawn_blocking(move || collector.compact(&policy)).await {
+                    Ok(Ok(report)) => tracing::debug!("Trace compaction: {:?}", report),
+                    Ok(Err(e)) => tracing::warn!("Trace compaction failed: {}", e),
+                    Err(e) => tracing::warn!("Trace compaction task failed: {}", e),
+                }
+            }
+        })
+    }
 }
 
 impl Default for TraceCollector {
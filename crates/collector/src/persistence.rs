@@ -1,11 +1,20 @@
 //! Persistent storage backend for trace data.
 
-use rocksdb::{Options, DB, ColumnFamilyDescriptor, SingleThreaded};
+use chrono::{DateTime, Utc};
+use rocksdb::{
+    ColumnFamily, ColumnFamilyDescriptor, Direction, IteratorMode, Options, SingleThreaded,
+    WriteBatch, DB,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
-use crate::{DEFAULT_EVENT_COLUMN, DEFAULT_SPAN_COLUMN};
+use crate::rollup::{hour_of, rollup_key};
+use crate::{
+    CompactionReport, QueryFilter, RetentionConfig, SpanRollup, TraceQuery, DEFAULT_EVENT_COLUMN,
+    DEFAULT_ROLLUP_COLUMN, DEFAULT_SPAN_COLUMN,
+};
 use synton_instrument::{TraceEvent, TraceSpan};
 
 /// Storage statistics.
@@ -65,6 +74,7 @@ impl RocksDbPersistence {
         // Create column families
         let span_cf = ColumnFamilyDescriptor::new(DEFAULT_SPAN_COLUMN, rocksdb::Options::default());
         let event_cf = ColumnFamilyDescriptor::new(DEFAULT_EVENT_COLUMN, rocksdb::Options::default());
+        let rollup_cf = ColumnFamilyDescriptor::new(DEFAULT_ROLLUP_COLUMN, rocksdb::Options::default());
 
         let mut db_opts = Options::default();
         db_opts.create_if_missing(true);
@@ -73,7 +83,7 @@ impl RocksDbPersistence {
         let db = DB::open_cf(
             &db_opts,
             path,
-            &[span_cf.clone(), event_cf.clone()],
+            &[span_cf.clone(), event_cf.clone(), rollup_cf],
         )
         .map_err(|e| crate::CollectorError::RocksDb(e))?;
 
@@ -92,6 +102,130 @@ impl RocksDbPersistence {
     fn deserialize_span(&self, data: &[u8]) -> crate::CollectorResult<TraceSpan> {
         serde_json::from_slice(data).map_err(Into::into)
     }
+
+    /// Handle of a column family.
+    fn cf(&self, name: &str) -> crate::CollectorResult<&ColumnFamily> {
+        self.db
+            .cf_handle(name)
+            .ok_or_else(|| crate::CollectorError::NotFound(format!("column family {}", name)))
+    }
+
+    /// Apply a retention policy as of `now`.
+    ///
+    /// Completed hours that have no rollup yet are rolled up from the raw
+    /// spans still stored; then expired spans, spans over the size limit
+    /// (oldest first) and expired rollups are deleted in one batch.
+    pub fn compact(
+        &self,
+        config: &RetentionConfig,
+        now: DateTime<Utc>,
+    ) -> crate::CollectorResult<CompactionReport> {
+        let span_cf = self.cf(DEFAULT_SPAN_COLUMN)?;
+        let rollup_cf = self.cf(DEFAULT_ROLLUP_COLUMN)?;
+        let current_hour = hour_of(now);
+
+        // (start time, key, stored size) of every span
+        let mut entries = Vec::new();
+        let mut durations: HashMap<(String, DateTime<Utc>), Vec<f64>> = HashMap::new();
+        for item in self.db.iterator_cf(span_cf, IteratorMode::Start) {
+            let (key, value) = item?;
+            let size = (key.len() + value.len()) as u64;
+            let Ok(span) = self.deserialize_span(&value) else {
+                continue;
+            };
+            let hour = hour_of(span.start_time);
+            if let (true, Some(duration)) = (hour < current_hour, span.duration_ms) {
+                durations.entry((span.name, hour)).or_default().push(duration);
+            }
+            entries.push((span.start_time, key, size));
+        }
+
+        let mut report = CompactionReport::default();
+        let mut batch = WriteBatch::default();
+
+        for ((name, hour), durations) in durations {
+            // An hour is rolled up once; some of its spans may be gone since
+            if self.db.get_cf(rollup_cf, rollup_key(hour, &name))?.is_some() {
+                continue;
+            }
+            if let Some(rollup) = SpanRollup::from_durations(name, hour, durations) {
+                batch.put_cf(rollup_cf, rollup.key(), serde_json::to_vec(&rollup)?);
+                report.rollups_written += 1;
+            }
+        }
+
+        entries.sort_by_key(|(start, _, _)| *start);
+        let cutoff = config
+            .max_age_secs
+            .map(|secs| now - chrono::Duration::seconds(secs as i64));
+        let mut remaining: u64 = entries.iter().map(|(_, _, size)| size).sum();
+        for (start, key, size) in &entries {
+            let expired = cutoff.is_some_and(|cutoff| *start < cutoff);
+            let oversized = config.max_bytes.is_some_and(|max| remaining > max);
+            if !expired && !oversized {
+                // Later spans are newer and the total only shrinks
+                break;
+            }
+            batch.delete_cf(span_cf, key);
+            remaining -= size;
+            if expired {
+                report.spans_expired += 1;
+            } else {
+                report.spans_evicted += 1;
+            }
+        }
+        report.bytes_remaining = remaining;
+
+        if let Some(secs) = config.rollup_max_age_secs {
+            let oldest_kept = rollup_key(hour_of(now - chrono::Duration::seconds(secs as i64)), "");
+            for item in self.db.iterator_cf(rollup_cf, IteratorMode::Start) {
+                let (key, _) = item?;
+                if *key >= *oldest_kept {
+                    break;
+                }
+                batch.delete_cf(rollup_cf, key);
+                report.rollups_expired += 1;
+            }
+        }
+
+        self.db.write(batch)?;
+        Ok(report)
+    }
+
+    /// Hourly rollups whose name contains the filter's name and whose hour
+    /// overlaps its time range, oldest first.
+    pub fn rollups(&self, filter: &QueryFilter) -> crate::CollectorResult<Vec<SpanRollup>> {
+        let rollup_cf = self.cf(DEFAULT_ROLLUP_COLUMN)?;
+        let start = filter.start_after.map(|start| rollup_key(hour_of(start), ""));
+        let mode = match &start {
+            Some(key) => IteratorMode::From(key, Direction::Forward),
+            None => IteratorMode::Start,
+        };
+
+        let mut rollups = Vec::new();
+        for item in self.db.iterator_cf(rollup_cf, mode) {
+            let (_key, value) = item?;
+            let rollup: SpanRollup = serde_json::from_slice(&value)?;
+            if filter.end_before.is_some_and(|end| rollup.hour >= end) {
+                break;
+            }
+            if filter.name.as_ref().is_some_and(|name| !rollup.name.contains(name)) {
+                continue;
+            }
+            rollups.push(rollup);
+        }
+
+        Ok(paginate(rollups, filter))
+    }
+}
+
+/// Apply a filter's offset and limit.
+fn paginate<T>(items: Vec<T>, filter: &QueryFilter) -> Vec<T> {
+    items
+        .into_iter()
+        .skip(filter.offset.unwrap_or(0))
+        .take(filter.limit.unwrap_or(usize::MAX))
+        .collect()
 }
 
 impl PersistenceBackend for RocksDbPersistence {
@@ -191,6 +325,34 @@ impl PersistenceBackend for RocksDbPersistence {
     }
 }
 
+impl TraceQuery for RocksDbPersistence {
+    fn query(&self, filter: &QueryFilter) -> crate::CollectorResult<Vec<TraceSpan>> {
+        let spans = self
+            .query_spans(filter)?
+            .into_iter()
+            .filter(|span| filter.matches(span))
+            .collect();
+        Ok(paginate(spans, filter))
+    }
+
+    fn get(&self, trace_id: &str) -> crate::CollectorResult<Option<TraceSpan>> {
+        self.get_span(trace_id)
+    }
+
+    fn count(&self, filter: &QueryFilter) -> crate::CollectorResult<usize> {
+        let unpaged = QueryFilter {
+            limit: None,
+            offset: None,
+            ..filter.clone()
+        };
+        Ok(self.query(&unpaged)?.len())
+    }
+
+    fn rollups(&self, filter: &QueryFilter) -> crate::CollectorResult<Vec<SpanRollup>> {
+        RocksDbPersistence::rollups(self, filter)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,4 +398,75 @@ mod tests {
         let stats = persistence.stats().unwrap();
         assert_eq!(stats.span_count, 0);
     }
+
+    fn finished_span(name: &str, start: DateTime<Utc>, duration_ms: f64) -> TraceSpan {
+        let mut span = TraceSpan::new(
+            name,
+            uuid::Uuid::new_v4(),
+            None,
+            synton_instrument::SpanKind::Function,
+            std::collections::HashMap::new(),
+        );
+        span.start_time = start;
+        span.end_time = Some(start + chrono::Duration::milliseconds(duration_ms as i64));
+        span.duration_ms = Some(duration_ms);
+        span
+    }
+
+    #[test]
+    fn test_compact_rolls_up_then_expires() {
+        use chrono::TimeZone;
+
+        let (persistence, _temp_dir) = create_test_persistence();
+        let now = Utc.with_ymd_and_hms(2025, 3, 2, 12, 30, 0).unwrap();
+        let old = now - chrono::Duration::days(1);
+        for duration in [10.0, 20.0, 30.0] {
+            persistence.store_span(&finished_span("query", old, duration)).unwrap();
+        }
+        let recent = finished_span("query", now - chrono::Duration::hours(1), 5.0);
+        let current = finished_span("query", now, 1.0);
+        persistence.store_span(&recent).unwrap();
+        persistence.store_span(&current).unwrap();
+
+        let config = RetentionConfig::unlimited().with_max_age_secs(3 * 3600);
+        let report = persistence.compact(&config, now).unwrap();
+        assert_eq!(report.rollups_written, 2);
+        assert_eq!(report.spans_expired, 3);
+        assert_eq!(report.spans_evicted, 0);
+        assert_eq!(persistence.stats().unwrap().span_count, 2);
+
+        // The expired hour survives as a rollup; the current hour is open
+        let rollups = TraceQuery::rollups(&persistence, &QueryFilter::default()).unwrap();
+        assert_eq!(rollups.len(), 2);
+        assert_eq!(rollups[0].hour, hour_of(old));
+        assert_eq!((rollups[0].count, rollups[0].p50_ms, rollups[0].p99_ms), (3, 20.0, 30.0));
+
+        let filter = QueryFilter::default().with_start_after(now - chrono::Duration::hours(2));
+        assert_eq!(persistence.rollups(&filter).unwrap().len(), 1);
+
+        // Rolled-up hours are not rewritten from the remaining spans
+        let again = persistence.compact(&config, now).unwrap();
+        assert_eq!(again.rollups_written, 0);
+    }
+
+    #[test]
+    fn test_compact_evicts_oldest_over_size_limit() {
+        let (persistence, _temp_dir) = create_test_persistence();
+        let now = Utc::now();
+        let spans: Vec<_> = (0..4)
+            .map(|i| finished_span("insert", now - chrono::Duration::minutes(4 - i), 1.0))
+            .collect();
+        for span in &spans {
+            persistence.store_span(span).unwrap();
+        }
+
+        let total = persistence.compact(&RetentionConfig::unlimited(), now).unwrap().bytes_remaining;
+        let config = RetentionConfig::unlimited().with_max_bytes(total * 5 / 8);
+        let report = persistence.compact(&config, now).unwrap();
+        assert_eq!(report.spans_evicted, 2);
+        assert!(report.bytes_remaining <= total * 5 / 8);
+
+        assert!(persistence.get_span(&spans[0].id.to_string()).unwrap().is_none());
+        assert!(persistence.get_span(&spans[3].id.to_string()).unwrap().is_some());
+    }
 }
@@ -209,6 +209,12 @@ pub trait TraceQuery: Send + Sync {
 
     /// Count matching traces.
     fn count(&self, filter: &QueryFilter) -> crate::CollectorResult<usize>;
+
+    /// Hourly duration rollups matching the filter's name and time range.
+    ///
+    /// Rollups outlive the raw spans they summarize; see
+    /// [`crate::RetentionConfig`].
+    fn rollups(&self, filter: &QueryFilter) -> crate::CollectorResult<Vec<crate::SpanRollup>>;
 }
 
 #[cfg(test)]
@@ -0,0 +1,78 @@
+//! Retention policy for stored trace data.
+
+use serde::{Deserialize, Serialize};
+
+/// Limits on how much trace data is kept.
+///
+/// Compaction first rolls completed hours up into [`crate::SpanRollup`]s,
+/// then deletes raw spans older than `max_age_secs`, then the oldest
+/// remaining spans until they fit in `max_bytes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionConfig {
+    /// Maximum age of raw spans (seconds); `None` keeps them regardless of age.
+    pub max_age_secs: Option<u64>,
+
+    /// Maximum total size of raw spans (bytes); `None` for no size limit.
+    pub max_bytes: Option<u64>,
+
+    /// Maximum age of hourly rollups (seconds); `None` keeps them forever.
+    pub rollup_max_age_secs: Option<u64>,
+
+    /// How often background compaction runs (seconds).
+    pub compaction_interval_secs: u64,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            max_age_secs: Some(7 * 24 * 3600),
+            max_bytes: Some(1024 * 1024 * 1024),
+            rollup_max_age_secs: Some(90 * 24 * 3600),
+            compaction_interval_secs: 300,
+        }
+    }
+}
+
+impl RetentionConfig {
+    /// A policy that keeps everything.
+    pub fn unlimited() -> Self {
+        Self {
+            max_age_secs: None,
+            max_bytes: None,
+            rollup_max_age_secs: None,
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum age of raw spans.
+    pub fn with_max_age_secs(mut self, secs: u64) -> Self {
+        self.max_age_secs = Some(secs);
+        self
+    }
+
+    /// Set the maximum total size of raw spans.
+    pub fn with_max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = Some(bytes);
+        self
+    }
+}
+
+/// Outcome of a compaction pass.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompactionReport {
+    /// Hourly rollups written.
+    pub rollups_written: usize,
+
+    /// Raw spans deleted for exceeding the maximum age.
+    pub spans_expired: usize,
+
+    /// Raw spans deleted to stay within the size limit.
+    pub spans_evicted: usize,
+
+    /// Rollups deleted for exceeding their maximum age.
+    pub rollups_expired: usize,
+
+    /// Size of the raw spans left (bytes).
+    pub bytes_remaining: u64,
+}
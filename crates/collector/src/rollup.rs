@@ -0,0 +1,111 @@
+//! Hourly span rollups.
+//!
+//! Raw spans are expensive to keep. Before retention deletes them, each
+//! completed hour is summarized per span name (count and duration
+//! percentiles) so latency trends stay queryable long after the raw data is
+//! gone.
+
+use chrono::{DateTime, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Duration summary of all spans with one name within one hour.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpanRollup {
+    /// Span name.
+    pub name: String,
+
+    /// Start of the hour (UTC).
+    pub hour: DateTime<Utc>,
+
+    /// Number of completed spans.
+    pub count: u64,
+
+    /// Median duration (milliseconds).
+    pub p50_ms: f64,
+
+    /// 95th percentile duration (milliseconds).
+    pub p95_ms: f64,
+
+    /// 99th percentile duration (milliseconds).
+    pub p99_ms: f64,
+}
+
+impl SpanRollup {
+    /// Summarize the durations of one span name within one hour.
+    ///
+    /// Returns `None` if there are no durations.
+    pub fn from_durations(
+        name: String,
+        hour: DateTime<Utc>,
+        mut durations: Vec<f64>,
+    ) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort_by(f64::total_cmp);
+
+        Some(Self {
+            name,
+            hour,
+            count: durations.len() as u64,
+            p50_ms: percentile(&durations, 50.0),
+            p95_ms: percentile(&durations, 95.0),
+            p99_ms: percentile(&durations, 99.0),
+        })
+    }
+
+    /// Storage key; keys sort by hour, then name.
+    pub(crate) fn key(&self) -> Vec<u8> {
+        rollup_key(self.hour, &self.name)
+    }
+}
+
+/// Storage key of the rollup for `name` in the hour starting at `hour`.
+pub(crate) fn rollup_key(hour: DateTime<Utc>, name: &str) -> Vec<u8> {
+    format!("{:020}/{}", hour.timestamp(), name).into_bytes()
+}
+
+/// Start of the hour containing `time`.
+pub(crate) fn hour_of(time: DateTime<Utc>) -> DateTime<Utc> {
+    time.duration_trunc(chrono::Duration::hours(1))
+        .unwrap_or(time)
+}
+
+/// Nearest-rank percentile of sorted, non-empty values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_rollup_percentiles() {
+        let hour = Utc.with_ymd_and_hms(2025, 3, 1, 14, 0, 0).unwrap();
+        let durations: Vec<f64> = (1..=100).rev().map(f64::from).collect();
+
+        let rollup = SpanRollup::from_durations("query".to_string(), hour, durations).unwrap();
+        assert_eq!(rollup.count, 100);
+        assert_eq!(rollup.p50_ms, 50.0);
+        assert_eq!(rollup.p95_ms, 95.0);
+        assert_eq!(rollup.p99_ms, 99.0);
+
+        let single = SpanRollup::from_durations("query".to_string(), hour, vec![7.5]).unwrap();
+        assert_eq!((single.p50_ms, single.p99_ms), (7.5, 7.5));
+        assert!(SpanRollup::from_durations("query".to_string(), hour, Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_hour_and_key() {
+        let time = Utc.with_ymd_and_hms(2025, 3, 1, 14, 37, 12).unwrap();
+        let hour = hour_of(time);
+        assert_eq!(hour, Utc.with_ymd_and_hms(2025, 3, 1, 14, 0, 0).unwrap());
+
+        // Keys of earlier hours sort first
+        let earlier = rollup_key(hour - chrono::Duration::hours(1), "z");
+        assert!(earlier < rollup_key(hour, "a"));
+    }
+}
@@ -6,7 +6,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{CoreError, CoreResult, Relation};
+use crate::{CoreError, CoreResult, EdgeCreator, EdgeProvenance, Relation};
 
 /// An edge in the Tensor-Graph.
 ///
@@ -40,6 +40,14 @@ pub struct Edge {
 
     /// Additional flexible attributes
     pub attributes: serde_json::Value,
+
+    /// Who created the edge and where it came from
+    #[serde(default)]
+    pub provenance: EdgeProvenance,
+
+    /// Whether this edge awaits review before joining the graph
+    #[serde(default)]
+    pub pending_review: bool,
 }
 
 impl Edge {
@@ -55,6 +63,8 @@ impl Edge {
             expired: false,
             replaced_by: None,
             attributes: serde_json::json!({}),
+            provenance: EdgeProvenance::default(),
+            pending_review: false,
         }
     }
 
@@ -92,6 +102,12 @@ impl Edge {
         self.expired
     }
 
+    /// Check if this edge awaits review.
+    #[inline]
+    pub fn is_pending_review(&self) -> bool {
+        self.pending_review
+    }
+
     /// Mark this edge as expired.
     pub fn expire(&mut self) {
         self.expired = true;
@@ -111,7 +127,7 @@ impl Edge {
         if !(0.0..=1.0).contains(&self.weight) {
             return Err(CoreError::InvalidWeight(self.weight));
         }
-        Ok(())
+        self.provenance.validate()
     }
 
     /// Create a reverse edge (swapping source and target).
@@ -126,6 +142,20 @@ impl Edge {
             expired: false,
             replaced_by: None,
             attributes: self.attributes.clone(),
+            provenance: self.provenance.clone(),
+            pending_review: self.pending_review,
+        }
+    }
+
+    /// View this edge from its target: source and target are swapped and
+    /// the relation is replaced by its [`Relation::inverse`]. Everything
+    /// else, including the creation time, is kept.
+    pub fn inverse(&self) -> Self {
+        Self {
+            source: self.target,
+            target: self.source,
+            relation: self.relation.inverse(),
+            ..self.clone()
         }
     }
 
@@ -152,6 +182,40 @@ impl Edge {
         self.expired = expired;
         self
     }
+
+    /// Set the provenance.
+    pub fn with_provenance(mut self, provenance: EdgeProvenance) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Set the extraction confidence.
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.provenance = self.provenance.with_confidence(confidence);
+        self
+    }
+
+    /// Who created this edge.
+    #[inline]
+    pub fn created_by(&self) -> EdgeCreator {
+        self.provenance.created_by
+    }
+
+    /// Confidence that this edge holds (0.0 - 1.0).
+    ///
+    /// Edges without a recorded extraction confidence, such as those
+    /// created by users, are fully trusted.
+    #[inline]
+    pub fn confidence(&self) -> f32 {
+        self.provenance.confidence.unwrap_or(1.0)
+    }
+
+    /// Weight discounted by confidence; the factor this edge contributes
+    /// to the confidence of a path through it.
+    #[inline]
+    pub fn strength(&self) -> f32 {
+        self.weight * self.confidence()
+    }
 }
 
 /// Builder for constructing edges.
@@ -194,6 +258,12 @@ impl EdgeBuilder {
         self
     }
 
+    /// Set the provenance.
+    pub fn provenance(mut self, provenance: EdgeProvenance) -> Self {
+        self.edge.provenance = provenance;
+        self
+    }
+
     /// Build the edge, validating before returning.
     pub fn build(self) -> CoreResult<Edge> {
         self.edge.validate()?;
@@ -308,4 +378,30 @@ mod tests {
             Some(&serde_json::json!("test"))
         );
     }
+
+    #[test]
+    fn test_edge_provenance() {
+        let doc = Uuid::new_v4();
+        let edge = EdgeBuilder::new(Uuid::new_v4(), Uuid::new_v4(), Relation::IsPartOf)
+            .provenance(
+                EdgeProvenance::new(EdgeCreator::ExtractionPipeline)
+                    .with_source_document(doc)
+                    .with_confidence(0.8),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(edge.created_by(), EdgeCreator::ExtractionPipeline);
+        assert_eq!(edge.reverse().provenance.source_document, Some(doc));
+        assert_eq!(edge.confidence(), 0.8);
+        assert_eq!(edge.clone().with_weight_value(0.5).strength(), 0.4);
+
+        // Edges persisted before provenance existed default to user-created
+        let mut value = serde_json::to_value(&edge).unwrap();
+        value.as_object_mut().unwrap().remove("provenance");
+        let legacy: Edge = serde_json::from_value(value).unwrap();
+        assert_eq!(legacy.created_by(), EdgeCreator::User);
+        assert_eq!(legacy.confidence(), 1.0);
+        assert!(!legacy.is_pending_review());
+    }
 }
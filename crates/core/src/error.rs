@@ -4,6 +4,8 @@
 
 use std::fmt;
 
+use synton_error::{Coded, ErrorCode};
+
 /// Core error type for SYNTON-DB.
 #[derive(Debug, Clone, PartialEq)]
 pub enum CoreError {
@@ -42,6 +44,9 @@ pub enum CoreError {
     /// Content is empty
     EmptyContent,
 
+    /// Tag is empty, too long or contains control characters
+    InvalidTag(String),
+
     /// Self-referential edge (source == target)
     SelfReferentialEdge,
 
@@ -70,6 +75,7 @@ impl fmt::Display for CoreError {
                 write!(f, "Content too large: {} bytes (max: {} bytes)", size, max)
             }
             Self::EmptyContent => write!(f, "Content cannot be empty"),
+            Self::InvalidTag(t) => write!(f, "Invalid tag {:?}: must be 1-{} printable characters", t, crate::MAX_TAG_LEN),
             Self::SelfReferentialEdge => write!(f, "Self-referential edges are not allowed"),
             Self::SerializationError(e) => write!(f, "Serialization error: {}", e),
             Self::DeserializationError(e) => write!(f, "Deserialization error: {}", e),
@@ -80,6 +86,17 @@ impl fmt::Display for CoreError {
 
 impl std::error::Error for CoreError {}
 
+impl Coded for CoreError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::NodeNotFound(_) | Self::EdgeNotFound(_) => ErrorCode::NotFound,
+            Self::SerializationError(_) | Self::DeserializationError(_) => ErrorCode::Serialization,
+            Self::Custom(_) => ErrorCode::Internal,
+            _ => ErrorCode::InvalidInput,
+        }
+    }
+}
+
 /// Result type for core operations
 pub type CoreResult<T> = Result<T, CoreError>;
 
@@ -100,6 +117,15 @@ mod tests {
         assert!(CoreError::SelfReferentialEdge.to_string().to_lowercase().contains("self-referential"));
     }
 
+    #[test]
+    fn test_error_code() {
+        assert_eq!(CoreError::EmptyContent.code(), ErrorCode::InvalidInput);
+        assert_eq!(CoreError::NodeNotFound(uuid::Uuid::nil()).code(), ErrorCode::NotFound);
+
+        let err = synton_error::SyntonError::from(CoreError::InvalidWeight(2.0));
+        assert_eq!(err.downcast_ref::<CoreError>(), Some(&CoreError::InvalidWeight(2.0)));
+    }
+
     #[test]
     fn test_error_equality() {
         assert_eq!(CoreError::EmptyContent, CoreError::EmptyContent);
@@ -0,0 +1,208 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Deterministic fault injection for resilience testing.
+//!
+//! A [`FaultInjector`] decides for every backend call whether to let it
+//! through, delay it, time it out or fail it. Decisions depend only on the
+//! seed, the injector label and the call number, so a test that replays the
+//! same calls sees the same faults. The storage, vector and ML crates wrap
+//! their backends with it.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Fault probabilities and durations.
+///
+/// Rates are probabilities per call (0.0 - 1.0); a call gets at most one
+/// fault, checked in the order error, timeout, latency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FaultConfig {
+    /// Seed of the fault sequence
+    pub seed: u64,
+
+    /// Probability that a call fails immediately
+    pub error_rate: f64,
+
+    /// Probability that a call hangs for `timeout_ms` and then fails
+    pub timeout_rate: f64,
+
+    /// Probability that a call is delayed by `latency_ms` and then succeeds
+    pub latency_rate: f64,
+
+    /// Delay added to slow calls (in milliseconds)
+    pub latency_ms: u64,
+
+    /// How long a timed-out call hangs before failing (in milliseconds)
+    pub timeout_ms: u64,
+}
+
+impl Default for FaultConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            error_rate: 0.0,
+            timeout_rate: 0.0,
+            latency_rate: 0.0,
+            latency_ms: 100,
+            timeout_ms: 5_000,
+        }
+    }
+}
+
+impl FaultConfig {
+    /// Whether every rate is a probability.
+    pub fn is_valid(&self) -> bool {
+        let rates = [self.error_rate, self.timeout_rate, self.latency_rate];
+        rates.iter().all(|r| (0.0..=1.0).contains(r)) && rates.iter().sum::<f64>() <= 1.0 + f64::EPSILON
+    }
+}
+
+/// Fault chosen for a single call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// Delay the call, then run it
+    Latency(Duration),
+    /// Hang for the duration, then fail with a timeout
+    Timeout(Duration),
+    /// Fail without running the call
+    Error,
+}
+
+/// Deterministic source of [`Fault`]s.
+#[derive(Debug)]
+pub struct FaultInjector {
+    config: FaultConfig,
+    label: &'static str,
+    stream: u64,
+    calls: AtomicU64,
+    injected: AtomicU64,
+}
+
+impl FaultInjector {
+    /// Create an injector. The label names the wrapped backend in logs and
+    /// gives each backend its own fault sequence under a shared seed.
+    pub fn new(config: FaultConfig, label: &'static str) -> Self {
+        let stream = label
+            .bytes()
+            .fold(config.seed, |acc, b| splitmix64(acc ^ u64::from(b)));
+        Self {
+            config,
+            label,
+            stream,
+            calls: AtomicU64::new(0),
+            injected: AtomicU64::new(0),
+        }
+    }
+
+    /// Label of the wrapped backend.
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Fault configuration.
+    pub fn config(&self) -> &FaultConfig {
+        &self.config
+    }
+
+    /// Decide the fault for the next call, if any.
+    pub fn next_fault(&self) -> Option<Fault> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        let roll = unit(splitmix64(self.stream.wrapping_add(call)));
+
+        let config = &self.config;
+        let fault = if roll < config.error_rate {
+            Fault::Error
+        } else if roll < config.error_rate + config.timeout_rate {
+            Fault::Timeout(Duration::from_millis(config.timeout_ms))
+        } else if roll < config.error_rate + config.timeout_rate + config.latency_rate {
+            Fault::Latency(Duration::from_millis(config.latency_ms))
+        } else {
+            return None;
+        };
+        self.injected.fetch_add(1, Ordering::Relaxed);
+        Some(fault)
+    }
+
+    /// Number of calls seen so far.
+    pub fn calls(&self) -> u64 {
+        self.calls.load(Ordering::Relaxed)
+    }
+
+    /// Number of faults injected so far.
+    pub fn injected(&self) -> u64 {
+        self.injected.load(Ordering::Relaxed)
+    }
+}
+
+/// SplitMix64 finalizer: a well-mixed 64-bit hash of `x`.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Map a hash to the unit interval [0, 1).
+fn unit(x: u64) -> f64 {
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn faults(injector: &FaultInjector, n: usize) -> Vec<Option<Fault>> {
+        (0..n).map(|_| injector.next_fault()).collect()
+    }
+
+    #[test]
+    fn test_faults_are_deterministic() {
+        let config = FaultConfig {
+            seed: 42,
+            error_rate: 0.2,
+            timeout_rate: 0.1,
+            latency_rate: 0.3,
+            ..Default::default()
+        };
+
+        let a = FaultInjector::new(config.clone(), "store");
+        let b = FaultInjector::new(config.clone(), "store");
+        let sequence = faults(&a, 200);
+        assert_eq!(sequence, faults(&b, 200));
+        assert_eq!(a.calls(), 200);
+
+        // Other backends and seeds get a different sequence
+        assert_ne!(sequence, faults(&FaultInjector::new(config.clone(), "vector"), 200));
+        let reseeded = FaultConfig { seed: 7, ..config };
+        assert_ne!(sequence, faults(&FaultInjector::new(reseeded, "store"), 200));
+
+        let errors = sequence.iter().filter(|f| **f == Some(Fault::Error)).count();
+        assert!((20..=60).contains(&errors), "{} errors", errors);
+        assert_eq!(a.injected(), sequence.iter().filter(|f| f.is_some()).count() as u64);
+    }
+
+    #[test]
+    fn test_fault_rates() {
+        let never = FaultInjector::new(FaultConfig::default(), "ml");
+        assert!(faults(&never, 100).iter().all(Option::is_none));
+
+        let config = FaultConfig {
+            timeout_rate: 1.0,
+            timeout_ms: 20,
+            ..Default::default()
+        };
+        let always = FaultInjector::new(config.clone(), "ml");
+        assert!(faults(&always, 100)
+            .iter()
+            .all(|f| *f == Some(Fault::Timeout(Duration::from_millis(20)))));
+
+        assert!(config.is_valid());
+        assert!(!FaultConfig { error_rate: 0.5, ..config.clone() }.is_valid());
+        assert!(!FaultConfig { latency_rate: -0.1, ..Default::default() }.is_valid());
+    }
+}
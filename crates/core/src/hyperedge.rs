@@ -0,0 +1,206 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Facts relating more than two entities.
+//!
+//! A binary edge cannot say "Alice transferred project X to Bob on date D"
+//! without losing who did what. Such a fact is reified: it becomes a
+//! [`NodeType::Fact`] node with one edge to each participant, whose
+//! relation names the role the participant plays (`role:agent`,
+//! `role:recipient`, ...). The fact's predicate is kept in its
+//! [`PREDICATE_ATTRIBUTE`] attribute.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use uuid::Uuid;
+
+use crate::{CoreError, CoreResult, Edge, Node, NodeType, Relation};
+
+/// Prefix of the relation of an edge from a fact to a participant
+pub const ROLE_PREFIX: &str = "role:";
+
+/// Fact node attribute holding the predicate (e.g. `"transferred"`)
+pub const PREDICATE_ATTRIBUTE: &str = "predicate";
+
+/// Normalize a role name: trimmed and lowercased, made of letters,
+/// digits, `_` and `-`.
+pub fn normalize_role(role: &str) -> CoreResult<String> {
+    let normalized = role.trim().to_lowercase();
+    let valid = !normalized.is_empty()
+        && normalized
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(normalized)
+    } else {
+        Err(CoreError::InvalidEdgeFormat(format!(
+            "invalid role: {:?}",
+            role
+        )))
+    }
+}
+
+impl Relation {
+    /// Relation of an edge from a fact to a participant playing `role`.
+    pub fn role(role: &str) -> CoreResult<Relation> {
+        Ok(Self::Custom(format!(
+            "{}{}",
+            ROLE_PREFIX,
+            normalize_role(role)?
+        )))
+    }
+
+    /// The role named by a role relation
+    pub fn role_name(&self) -> Option<&str> {
+        match self {
+            Self::Custom(s) => s.strip_prefix(ROLE_PREFIX).filter(|role| !role.is_empty()),
+            _ => None,
+        }
+    }
+}
+
+/// A participant of a fact in a role.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RoleBinding {
+    /// Normalized role name (see [`normalize_role`])
+    pub role: String,
+
+    /// The participating node
+    pub participant: Uuid,
+}
+
+impl RoleBinding {
+    /// Bind `participant` to `role`.
+    pub fn new(role: &str, participant: Uuid) -> CoreResult<Self> {
+        Ok(Self {
+            role: normalize_role(role)?,
+            participant,
+        })
+    }
+}
+
+/// A reified fact with its role-typed participants.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hyperedge {
+    /// The fact node
+    pub fact: Node,
+
+    /// Participants in the order they were bound
+    pub roles: Vec<RoleBinding>,
+}
+
+impl Hyperedge {
+    /// Create a fact with a predicate and no participants yet.
+    pub fn new(content: impl Into<Cow<'static, str>>, predicate: impl Into<String>) -> Self {
+        let fact = Node::new(content, NodeType::Fact)
+            .with_attributes(serde_json::json!({ PREDICATE_ATTRIBUTE: predicate.into() }));
+        Self {
+            fact,
+            roles: Vec::new(),
+        }
+    }
+
+    /// Rebuild a fact from its node and edges; edges that are inactive,
+    /// not from the fact or not role relations are ignored.
+    pub fn from_edges<'a>(fact: Node, edges: impl IntoIterator<Item = &'a Edge>) -> Self {
+        let roles = edges
+            .into_iter()
+            .filter(|edge| edge.source == fact.id && edge.is_active())
+            .filter_map(|edge| {
+                edge.relation.role_name().map(|role| RoleBinding {
+                    role: role.to_string(),
+                    participant: edge.target,
+                })
+            })
+            .collect();
+        Self { fact, roles }
+    }
+
+    /// Bind a participant to a role; a role can have several participants.
+    pub fn with_role(mut self, role: &str, participant: Uuid) -> CoreResult<Self> {
+        let binding = RoleBinding::new(role, participant)?;
+        if !self.roles.contains(&binding) {
+            self.roles.push(binding);
+        }
+        Ok(self)
+    }
+
+    /// The fact's predicate, if it has one
+    pub fn predicate(&self) -> Option<&str> {
+        self.fact.attributes.get(PREDICATE_ATTRIBUTE)?.as_str()
+    }
+
+    /// Participants playing `role`.
+    pub fn participants(&self, role: &str) -> Vec<Uuid> {
+        let role = role.trim().to_lowercase();
+        self.roles
+            .iter()
+            .filter(|binding| binding.role == role)
+            .map(|binding| binding.participant)
+            .collect()
+    }
+
+    /// Whether the fact has every one of `bindings`.
+    pub fn binds_all(&self, bindings: &[RoleBinding]) -> bool {
+        bindings.iter().all(|binding| self.roles.contains(binding))
+    }
+
+    /// The edges from the fact to its participants.
+    pub fn edges(&self) -> Vec<Edge> {
+        self.roles
+            .iter()
+            .map(|binding| {
+                let relation = Relation::Custom(format!("{}{}", ROLE_PREFIX, binding.role));
+                Edge::new(self.fact.id, binding.participant, relation)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_relation() {
+        let relation = Relation::role(" Recipient ").unwrap();
+        assert_eq!(relation.to_string(), "role:recipient");
+        assert_eq!(relation.role_name(), Some("recipient"));
+        // Role relations survive a round trip through their label
+        assert_eq!("role:recipient".parse::<Relation>().unwrap(), relation);
+
+        assert_eq!(Relation::Causes.role_name(), None);
+        assert_eq!(Relation::Custom("role:".to_string()).role_name(), None);
+        assert!(Relation::role("").is_err());
+        assert!(Relation::role("two words").is_err());
+    }
+
+    #[test]
+    fn test_hyperedge_round_trip() {
+        let (alice, bob, project) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let fact = Hyperedge::new("Alice transferred project X to Bob", "transferred")
+            .with_role("agent", alice)
+            .unwrap()
+            .with_role("Recipient", bob)
+            .unwrap()
+            .with_role("theme", project)
+            .unwrap();
+
+        assert_eq!(fact.fact.node_type, NodeType::Fact);
+        assert_eq!(fact.predicate(), Some("transferred"));
+        assert_eq!(fact.participants("recipient"), vec![bob]);
+        assert!(fact.binds_all(&[RoleBinding::new("agent", alice).unwrap()]));
+        assert!(!fact.binds_all(&[RoleBinding::new("agent", bob).unwrap()]));
+
+        let mut edges = fact.edges();
+        assert_eq!(edges.len(), 3);
+        assert!(edges.iter().all(|edge| edge.source == fact.fact.id));
+
+        // Inactive and unrelated edges are not participants
+        edges[2].expire();
+        edges.push(Edge::new(fact.fact.id, alice, Relation::Causes));
+        let loaded = Hyperedge::from_edges(fact.fact.clone(), &edges);
+        assert_eq!(loaded.roles, fact.roles[..2]);
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Node ID generation.
+//!
+//! Node IDs are random UUIDv4 by default. Time-ordered UUIDv7 IDs start
+//! with their node's creation time in milliseconds, so new nodes append to
+//! the end of the key space instead of scattering writes over it, and the
+//! nodes created in a time window hold a contiguous key range. The format
+//! only affects IDs generated from then on: both kinds parse, compare and
+//! store the same way, and a database may hold a mix of them.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// Format of newly generated node IDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdFormat {
+    /// Random UUIDv4.
+    #[default]
+    V4,
+
+    /// Time-ordered UUIDv7.
+    V7,
+}
+
+/// Process-wide format of new IDs, as `IdFormat as u8`.
+static ID_FORMAT: AtomicU8 = AtomicU8::new(IdFormat::V4 as u8);
+
+impl IdFormat {
+    /// Generate an ID for something created at `time`.
+    pub fn generate(self, time: DateTime<Utc>) -> Uuid {
+        match self {
+            Self::V4 => Uuid::new_v4(),
+            Self::V7 => {
+                let millis = unix_millis(time);
+                let ts = Timestamp::from_unix(
+                    NoContext,
+                    millis / 1000,
+                    (millis % 1000) as u32 * 1_000_000,
+                );
+                Uuid::new_v7(ts)
+            }
+        }
+    }
+
+    /// Format name.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::V4 => "v4",
+            Self::V7 => "v7",
+        }
+    }
+}
+
+impl fmt::Display for IdFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for IdFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "v4" => Ok(Self::V4),
+            "v7" => Ok(Self::V7),
+            _ => Err(format!("Unknown ID format: {} (expected v4 or v7)", s)),
+        }
+    }
+}
+
+/// Set the format of IDs generated from now on by this process.
+pub fn set_id_format(format: IdFormat) {
+    ID_FORMAT.store(format as u8, Ordering::Relaxed);
+}
+
+/// Format of newly generated IDs.
+pub fn id_format() -> IdFormat {
+    if ID_FORMAT.load(Ordering::Relaxed) == IdFormat::V7 as u8 {
+        IdFormat::V7
+    } else {
+        IdFormat::V4
+    }
+}
+
+/// Creation time encoded in a time-ordered ID, to the millisecond; `None`
+/// for other IDs.
+pub fn id_time(id: &Uuid) -> Option<DateTime<Utc>> {
+    if id.get_version_num() != 7 {
+        return None;
+    }
+    let (seconds, nanos) = id.get_timestamp()?.to_unix();
+    Utc.timestamp_opt(seconds as i64, nanos).single()
+}
+
+/// Smallest and largest time-ordered ID that can be generated between
+/// `from` and `to`, inclusive. Unbounded ends take the limits of the
+/// format.
+pub fn time_ordered_id_range(
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> (Uuid, Uuid) {
+    const RANDOM_BITS: u32 = 80;
+    let low = from.map_or(0, unix_millis);
+    let high = to.map_or(MAX_MILLIS, unix_millis);
+    (
+        Uuid::from_u128((low as u128) << RANDOM_BITS),
+        Uuid::from_u128(((high as u128) << RANDOM_BITS) | ((1u128 << RANDOM_BITS) - 1)),
+    )
+}
+
+/// Largest millisecond timestamp a UUIDv7 holds (48 bits).
+const MAX_MILLIS: u64 = (1 << 48) - 1;
+
+/// Milliseconds since the Unix epoch, clamped to what a UUIDv7 holds.
+fn unix_millis(time: DateTime<Utc>) -> u64 {
+    time.timestamp_millis().clamp(0, MAX_MILLIS as i64) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_time_ordered_ids() {
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let ids: Vec<Uuid> = (0..5)
+            .map(|i| IdFormat::V7.generate(start + Duration::milliseconds(i * 10)))
+            .collect();
+
+        assert!(ids.iter().all(|id| id.get_version_num() == 7));
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ids.windows(2).all(|pair| pair[0].as_bytes() < pair[1].as_bytes()));
+        assert_eq!(id_time(&ids[2]), Some(start + Duration::milliseconds(20)));
+
+        let random = IdFormat::V4.generate(start);
+        assert_eq!(random.get_version_num(), 4);
+        assert_eq!(id_time(&random), None);
+        assert_eq!(Uuid::parse_str(&ids[0].to_string()).unwrap(), ids[0]);
+    }
+
+    #[test]
+    fn test_time_ordered_id_range() {
+        let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let end = start + Duration::seconds(1);
+        let (low, high) = time_ordered_id_range(Some(start), Some(end));
+
+        for time in [start, start + Duration::milliseconds(500), end] {
+            let id = IdFormat::V7.generate(time);
+            assert!(low <= id && id <= high);
+        }
+        assert!(IdFormat::V7.generate(start - Duration::milliseconds(1)) < low);
+        assert!(IdFormat::V7.generate(end + Duration::milliseconds(1)) > high);
+
+        let (low, high) = time_ordered_id_range(None, None);
+        assert_eq!((low, high), (Uuid::nil(), Uuid::from_u128(u128::MAX)));
+    }
+
+    #[test]
+    fn test_id_format_parse() {
+        assert_eq!("V7".parse::<IdFormat>().unwrap(), IdFormat::V7);
+        assert_eq!(IdFormat::V4.to_string(), "v4");
+        assert!("v1".parse::<IdFormat>().is_err());
+        assert_eq!(serde_json::to_string(&IdFormat::V7).unwrap(), "\"v7\"");
+    }
+}
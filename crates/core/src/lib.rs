@@ -29,8 +29,12 @@ mod error;
 mod source;
 mod filter;
 mod path;
+mod provenance;
+mod fault;
+mod hyperedge;
+mod id;
 
-pub use node::{Node, NodeMeta, NodeBuilder};
+pub use node::{normalize_tag, Node, NodeMeta, NodeBuilder, MAX_TAG_LEN};
 pub use edge::{Edge, EdgeBuilder};
 pub use relation::{Relation, RelationParseError};
 pub use node_type::NodeType;
@@ -38,6 +42,10 @@ pub use error::{CoreError, CoreResult};
 pub use source::Source;
 pub use filter::{Filter, FilterValue, TraverseDirection};
 pub use path::{ReasoningPath, PathType};
+pub use provenance::{EdgeCreator, EdgeProvenance, PROVENANCE_ATTRIBUTE};
+pub use fault::{Fault, FaultConfig, FaultInjector};
+pub use hyperedge::{normalize_role, Hyperedge, RoleBinding, PREDICATE_ATTRIBUTE, ROLE_PREFIX};
+pub use id::{id_format, id_time, set_id_format, time_ordered_id_range, IdFormat};
 
 /// Re-exports commonly used types
 pub mod prelude {
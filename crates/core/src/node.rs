@@ -3,12 +3,13 @@
 // Licensed under the Apache License, Version 2.0 (the "License");
 
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{CoreError, CoreResult, NodeType, Source};
+use crate::{id_format, CoreError, CoreResult, NodeType, Source};
 
 /// Maximum content size in bytes (10MB)
 pub const MAX_CONTENT_SIZE: usize = 10 * 1024 * 1024;
@@ -17,6 +18,22 @@ pub const MAX_CONTENT_SIZE: usize = 10 * 1024 * 1024;
 /// Default value approximately corresponds to 20% retention after 24 hours
 pub const DEFAULT_DECAY_LAMBDA: f32 = 0.0015;
 
+/// Maximum tag length in characters
+pub const MAX_TAG_LEN: usize = 128;
+
+/// Normalize a tag: trimmed and lowercased, so `Architecture ` and
+/// `architecture` are the same tag.
+pub fn normalize_tag(tag: &str) -> CoreResult<String> {
+    let normalized = tag.trim().to_lowercase();
+    if normalized.is_empty()
+        || normalized.chars().count() > MAX_TAG_LEN
+        || normalized.chars().any(char::is_control)
+    {
+        return Err(CoreError::InvalidTag(tag.to_string()));
+    }
+    Ok(normalized)
+}
+
 /// Metadata associated with a node.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NodeMeta {
@@ -43,6 +60,28 @@ pub struct NodeMeta {
 
     /// Chunk index (if this is a chunk)
     pub chunk_index: Option<usize>,
+
+    /// Detected content language (ISO 639-1 code, e.g. "en", "zh")
+    pub lang: Option<String>,
+
+    /// Model that produced the embedding (e.g. "local:BAAI/bge-small-en-v1.5")
+    pub embedding_model: Option<String>,
+
+    /// Identifier of the node in an external system (ticket ID, file path)
+    pub external_id: Option<String>,
+
+    /// Pinned nodes are exempt from memory decay and pruning
+    #[serde(default)]
+    pub pinned: bool,
+
+    /// Version, starting at 1 and bumped by every update; updates can
+    /// require an expected version (optimistic concurrency)
+    #[serde(default = "initial_version")]
+    pub version: u64,
+}
+
+fn initial_version() -> u64 {
+    1
 }
 
 impl NodeMeta {
@@ -58,9 +97,20 @@ impl NodeMeta {
             source,
             document_id: None,
             chunk_index: None,
+            lang: None,
+            embedding_model: None,
+            external_id: None,
+            pinned: false,
+            version: initial_version(),
         }
     }
 
+    /// Record an update: bump the version and the update timestamp.
+    pub fn touch(&mut self) {
+        self.updated_at = Utc::now();
+        self.version += 1;
+    }
+
     /// Validate the metadata values.
     pub fn validate(&self) -> CoreResult<()> {
         if !(0.0..=1.0).contains(&self.confidence) {
@@ -74,8 +124,12 @@ impl NodeMeta {
 
     /// Calculate decayed access score based on time passed.
     ///
-    /// Uses exponential decay: score = initial * e^(-λ * time)
+    /// Uses exponential decay: score = initial * e^(-λ * time). Pinned
+    /// nodes keep their score.
     pub fn decayed_score(&self, lambda: f32) -> f32 {
+        if self.pinned {
+            return self.access_score;
+        }
         if let Some(accessed) = self.accessed_at {
             let duration = Utc::now().signed_duration_since(accessed);
             let hours = duration.num_hours().max(0) as f32;
@@ -114,6 +168,10 @@ pub struct Node {
 
     /// Additional flexible attributes
     pub attributes: serde_json::Value,
+
+    /// Normalized labels (see [`normalize_tag`])
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tags: BTreeSet<String>,
 }
 
 impl Node {
@@ -129,13 +187,15 @@ impl Node {
         source: Source,
     ) -> Self {
         let content = content.into();
+        let meta = NodeMeta::new(source);
         Self {
-            id: Uuid::new_v4(),
+            id: id_format().generate(meta.created_at),
             content,
             embedding: None,
-            meta: NodeMeta::new(source),
+            meta,
             node_type,
             attributes: serde_json::json!({}),
+            tags: BTreeSet::new(),
         }
     }
 
@@ -163,6 +223,11 @@ impl Node {
         self.embedding.is_some()
     }
 
+    /// Check if this node carries a tag, compared after normalization.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        normalize_tag(tag).is_ok_and(|tag| self.tags.contains(&tag))
+    }
+
     /// Validate the node's data.
     pub fn validate(&self) -> CoreResult<()> {
         if self.content.is_empty() {
@@ -175,6 +240,11 @@ impl Node {
             });
         }
         self.meta.validate()?;
+        for tag in &self.tags {
+            if normalize_tag(tag).as_ref() != Ok(tag) {
+                return Err(CoreError::InvalidTag(tag.clone()));
+            }
+        }
         Ok(())
     }
 
@@ -198,7 +268,7 @@ impl Node {
     /// Check if this node should be archived based on access score.
     #[inline]
     pub fn should_archive(&self, threshold: f32) -> bool {
-        self.meta.decayed_score(DEFAULT_DECAY_LAMBDA) < threshold
+        !self.meta.pinned && self.meta.decayed_score(DEFAULT_DECAY_LAMBDA) < threshold
     }
 
     /// Set the embedding vector.
@@ -230,6 +300,41 @@ impl Node {
         self.meta.chunk_index = Some(index);
         self
     }
+
+    /// Set the content language.
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.meta.lang = Some(lang.into());
+        self
+    }
+
+    /// Set the model that produced the embedding.
+    pub fn with_embedding_model(mut self, model: impl Into<String>) -> Self {
+        self.meta.embedding_model = Some(model.into());
+        self
+    }
+
+    /// Pin or unpin the node, exempting it from decay and pruning.
+    pub fn with_pinned(mut self, pinned: bool) -> Self {
+        self.meta.pinned = pinned;
+        self
+    }
+
+    /// Set the external system identifier.
+    pub fn with_external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.meta.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Add tags, normalized; invalid tags are skipped.
+    pub fn with_tags<I, T>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        self.tags
+            .extend(tags.into_iter().filter_map(|tag| normalize_tag(tag.as_ref()).ok()));
+        self
+    }
 }
 
 /// Builder for constructing nodes.
@@ -296,6 +401,15 @@ impl NodeBuilder {
         self
     }
 
+    /// Add a tag; validation rejects it if it cannot be normalized.
+    pub fn tag(mut self, tag: impl AsRef<str>) -> Self {
+        let tag = tag.as_ref();
+        self.node
+            .tags
+            .insert(normalize_tag(tag).unwrap_or_else(|_| tag.to_string()));
+        self
+    }
+
     /// Build the node, validating before returning.
     pub fn build(self) -> CoreResult<Node> {
         self.node.validate()?;
@@ -369,6 +483,14 @@ mod tests {
         let before = node.meta.access_score;
         node.decay(DEFAULT_DECAY_LAMBDA);
         assert!(node.meta.access_score < before);
+
+        // Pinned nodes do not decay
+        let mut node = Node::new("Test", NodeType::Entity).with_pinned(true);
+        node.meta.access_score = 0.5;
+        node.meta.accessed_at = Some(Utc::now() - chrono::Duration::days(365));
+        node.decay(DEFAULT_DECAY_LAMBDA);
+        assert_eq!(node.meta.access_score, 0.5);
+        assert!(!node.should_archive(1.0));
     }
 
     #[test]
@@ -378,6 +500,54 @@ mod tests {
         assert_eq!(node.embedding_dim(), Some(3));
     }
 
+    #[test]
+    fn test_node_tags() {
+        let node = Node::new("Test", NodeType::Concept).with_tags(["Architecture ", "architecture", "db", ""]);
+        assert_eq!(node.tags.iter().collect::<Vec<_>>(), vec!["architecture", "db"]);
+        assert!(node.has_tag("ARCHITECTURE"));
+        assert!(!node.has_tag("storage"));
+
+        assert_eq!(normalize_tag("  Rust "), Ok("rust".to_string()));
+        assert!(matches!(normalize_tag("   "), Err(CoreError::InvalidTag(_))));
+        assert!(normalize_tag("a\nb").is_err());
+        assert!(normalize_tag(&"x".repeat(MAX_TAG_LEN + 1)).is_err());
+
+        assert!(NodeBuilder::new("Test", NodeType::Concept).tag("Ok").build().is_ok());
+        assert!(NodeBuilder::new("Test", NodeType::Concept).tag("a\tb").build().is_err());
+
+        // Untagged nodes serialize without tags and deserialize with none
+        let value = serde_json::to_value(Node::new("Test", NodeType::Concept)).unwrap();
+        assert!(value.get("tags").is_none());
+        let restored: Node = serde_json::from_value(value).unwrap();
+        assert!(restored.tags.is_empty());
+    }
+
+    #[test]
+    fn test_node_lang() {
+        let node = Node::new("向量数据库", NodeType::Concept).with_lang("zh");
+        assert_eq!(node.meta.lang.as_deref(), Some("zh"));
+
+        // Nodes stored before language detection have no language
+        let mut value = serde_json::to_value(&node).unwrap();
+        value["meta"].as_object_mut().unwrap().remove("lang");
+        let restored: Node = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.meta.lang, None);
+    }
+
+    #[test]
+    fn test_node_version() {
+        let mut node = Node::new("Test", NodeType::Concept);
+        assert_eq!(node.meta.version, 1);
+        node.meta.touch();
+        assert_eq!(node.meta.version, 2);
+
+        // Nodes stored before versioning start at version 1
+        let mut value = serde_json::to_value(&node).unwrap();
+        value["meta"].as_object_mut().unwrap().remove("version");
+        let restored: Node = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.meta.version, 1);
+    }
+
     #[test]
     fn test_node_builder() {
         let node = NodeBuilder::new("Builder test", NodeType::Concept)
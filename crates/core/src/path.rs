@@ -132,6 +132,12 @@ impl ReasoningPath {
         self
     }
 
+    /// Confidence propagated along the path: the product of each edge's
+    /// weight and extraction confidence.
+    pub fn propagated_confidence(&self) -> f32 {
+        self.edges.iter().map(Edge::strength).product()
+    }
+
     /// Calculate the minimum confidence along the path (from edge weights).
     pub fn calculate_min_confidence(&self) -> f32 {
         self.edges
@@ -140,6 +146,42 @@ impl ReasoningPath {
             .min_by(|a, b| a.partial_cmp(b).unwrap())
             .unwrap_or(1.0)
     }
+
+    /// Render the path as Markdown for inclusion in an LLM prompt.
+    ///
+    /// Each hop becomes a bullet `source —[relation]→ target` with the full
+    /// node content; line breaks inside content are folded into spaces.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "**{} path** ({} hops, confidence {:.2})\n",
+            self.path_type,
+            self.hops(),
+            self.confidence
+        );
+
+        if self.edges.is_empty() {
+            if let Some(node) = self.start() {
+                out.push_str(&format!("\n- {}\n", inline(node.content())));
+            }
+            return out;
+        }
+
+        out.push('\n');
+        for (edge, pair) in self.edges.iter().zip(self.nodes.windows(2)) {
+            out.push_str(&format!(
+                "- {} —[{}]→ {}\n",
+                inline(pair[0].content()),
+                edge.relation,
+                inline(pair[1].content())
+            ));
+        }
+        out
+    }
+}
+
+/// Collapse whitespace so content fits on a single Markdown line.
+fn inline(content: &str) -> String {
+    content.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 impl Default for ReasoningPath {
@@ -307,7 +349,9 @@ mod tests {
         let node2 = Node::new("B", NodeType::Entity);
         let node3 = Node::new("C", NodeType::Entity);
 
-        let edge1 = Edge::new(node1.id, node2.id, Relation::Causes);
+        let edge1 = Edge::new(node1.id, node2.id, Relation::Causes)
+            .with_weight_value(0.8)
+            .with_confidence(0.5);
         let edge2 = Edge::new(node2.id, node3.id, Relation::Causes);
 
         let path = PathBuilder::new()
@@ -320,6 +364,7 @@ mod tests {
         assert_eq!(path.len(), 3);
         assert_eq!(path.hops(), 2);
         assert_eq!(path.path_type, PathType::Causal);
+        assert_eq!(path.propagated_confidence(), 0.4);
     }
 
     #[test]
@@ -337,6 +382,30 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_path_to_markdown() {
+        let a = Node::new("Supply shortage", NodeType::Concept);
+        let b = Node::new("Production\ndelay", NodeType::Concept);
+        let c = Node::new("Q3 report", NodeType::Concept);
+        let path = PathBuilder::new()
+            .add_edges(vec![
+                Edge::new(a.id, b.id, Relation::Causes),
+                Edge::new(b.id, c.id, Relation::IsPartOf),
+            ])
+            .add_nodes(vec![a, b, c])
+            .path_type(PathType::Hybrid)
+            .confidence(0.81)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            path.to_markdown(),
+            "**hybrid path** (2 hops, confidence 0.81)\n\n\
+             - Supply shortage —[causes]→ Production delay\n\
+             - Production delay —[is_part_of]→ Q3 report\n"
+        );
+    }
+
     #[test]
     fn test_path_display() {
         let path = ReasoningPath {
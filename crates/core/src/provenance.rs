@@ -0,0 +1,150 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::{CoreError, CoreResult};
+
+/// Node attribute recording where an ingested chunk came from: an object
+/// with the document's `source` (URL or file name), `title`, `content_type`
+/// and caller-supplied `metadata`, each present only when known.
+pub const PROVENANCE_ATTRIBUTE: &str = "provenance";
+
+/// Who or what created an edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeCreator {
+    /// Created directly by a human user
+    #[default]
+    User,
+
+    /// Created by an agent through a tool call (e.g. MCP)
+    Tool,
+
+    /// Created automatically by an ingestion or extraction pipeline
+    ExtractionPipeline,
+}
+
+impl EdgeCreator {
+    /// All creator kinds
+    pub const ALL: &'static [EdgeCreator] = &[
+        EdgeCreator::User,
+        EdgeCreator::Tool,
+        EdgeCreator::ExtractionPipeline,
+    ];
+
+    /// Check if the edge was created by a human
+    #[inline]
+    pub const fn is_human(&self) -> bool {
+        matches!(self, Self::User)
+    }
+}
+
+impl fmt::Display for EdgeCreator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User => write!(f, "user"),
+            Self::Tool => write!(f, "tool"),
+            Self::ExtractionPipeline => write!(f, "extraction_pipeline"),
+        }
+    }
+}
+
+impl FromStr for EdgeCreator {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "user" | "human" => Ok(Self::User),
+            "tool" => Ok(Self::Tool),
+            "extraction_pipeline" | "extraction" | "pipeline" => Ok(Self::ExtractionPipeline),
+            other => Err(CoreError::Custom(format!("Unknown edge creator: {}", other))),
+        }
+    }
+}
+
+/// Structured provenance of an edge.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EdgeProvenance {
+    /// Who or what created the edge
+    #[serde(default)]
+    pub created_by: EdgeCreator,
+
+    /// Document the edge was extracted from, if any
+    #[serde(default)]
+    pub source_document: Option<Uuid>,
+
+    /// Confidence of the extraction (0.0 - 1.0), if machine-generated
+    #[serde(default)]
+    pub confidence: Option<f32>,
+}
+
+impl EdgeProvenance {
+    /// Create provenance for the given creator.
+    pub fn new(created_by: EdgeCreator) -> Self {
+        Self {
+            created_by,
+            ..Default::default()
+        }
+    }
+
+    /// Set the source document.
+    pub fn with_source_document(mut self, document_id: Uuid) -> Self {
+        self.source_document = Some(document_id);
+        self
+    }
+
+    /// Set the extraction confidence.
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = Some(confidence.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Validate the provenance data.
+    pub fn validate(&self) -> CoreResult<()> {
+        if let Some(confidence) = self.confidence {
+            if !(0.0..=1.0).contains(&confidence) {
+                return Err(CoreError::InvalidConfidence(confidence));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_creator_roundtrip() {
+        for creator in EdgeCreator::ALL {
+            assert_eq!(creator.to_string().parse::<EdgeCreator>().unwrap(), *creator);
+        }
+        assert_eq!("human".parse::<EdgeCreator>().unwrap(), EdgeCreator::User);
+        assert!("robot".parse::<EdgeCreator>().is_err());
+    }
+
+    #[test]
+    fn test_provenance_builder() {
+        let doc = Uuid::new_v4();
+        let provenance = EdgeProvenance::new(EdgeCreator::ExtractionPipeline)
+            .with_source_document(doc)
+            .with_confidence(1.5);
+
+        assert_eq!(provenance.source_document, Some(doc));
+        assert_eq!(provenance.confidence, Some(1.0));
+        assert!(!provenance.created_by.is_human());
+        assert!(provenance.validate().is_ok());
+    }
+
+    #[test]
+    fn test_provenance_defaults_when_missing() {
+        let provenance: EdgeProvenance = serde_json::from_str("{}").unwrap();
+        assert_eq!(provenance, EdgeProvenance::default());
+        assert!(provenance.created_by.is_human());
+    }
+}
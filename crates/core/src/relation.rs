@@ -62,6 +62,17 @@ impl Relation {
         Relation::BelongsTo,
     ];
 
+    /// Labels of the inverse views of the directional standard relations,
+    /// read from the target's side (e.g. "Car" has_part "Wheel").
+    pub const INVERSE_LABELS: &'static [(Relation, &'static str)] = &[
+        (Relation::IsPartOf, "has_part"),
+        (Relation::Causes, "caused_by"),
+        (Relation::HappenedAfter, "happened_before"),
+        (Relation::IsA, "has_instance"),
+        (Relation::LocatedAt, "location_of"),
+        (Relation::BelongsTo, "has_member"),
+    ];
+
     /// Get the reverse relation
     #[must_use]
     pub fn reverse(&self) -> Relation {
@@ -77,6 +88,44 @@ impl Relation {
         }
     }
 
+    /// Get the inverse relation, as seen from the target of an edge.
+    ///
+    /// Unlike [`Relation::reverse`], every relation has its own inverse
+    /// label (is_a <-> has_instance, is_part_of <-> has_part,
+    /// causes <-> caused_by, ...) and inverting twice gives back the
+    /// original relation. Symmetric relations are their own inverse;
+    /// custom relations invert to `inverse_of_<name>`.
+    #[must_use]
+    pub fn inverse(&self) -> Relation {
+        if self.is_symmetric() {
+            return self.clone();
+        }
+        if let Some((_, label)) = Self::INVERSE_LABELS.iter().find(|(r, _)| r == self) {
+            return Self::Custom((*label).to_string());
+        }
+        match self {
+            Self::Custom(s) => Self::INVERSE_LABELS
+                .iter()
+                .find(|(_, label)| label == s)
+                .map(|(r, _)| r.clone())
+                .or_else(|| s.strip_prefix(INVERSE_PREFIX).map(Self::from))
+                .unwrap_or_else(|| Self::Custom(format!("{}{}", INVERSE_PREFIX, s))),
+            _ => self.clone(),
+        }
+    }
+
+    /// Check if this is the inverse view of another relation
+    #[inline]
+    pub fn is_inverse(&self) -> bool {
+        match self {
+            Self::Custom(s) => {
+                s.starts_with(INVERSE_PREFIX)
+                    || Self::INVERSE_LABELS.iter().any(|(_, label)| label == s)
+            }
+            _ => false,
+        }
+    }
+
     /// Check if this is a transitive relation
     #[inline]
     pub fn is_transitive(&self) -> bool {
@@ -99,6 +148,9 @@ impl Relation {
     }
 }
 
+/// Prefix of the inverse label of a custom relation
+const INVERSE_PREFIX: &str = "inverse_of_";
+
 // Auto-generated RELATED_TO variant for reverse calculations
 impl Relation {
     const RELATED_TO: Self = Self::SimilarTo;
@@ -190,6 +242,25 @@ mod tests {
         assert_eq!(Relation::Causes.reverse(), Relation::Custom("caused_by".to_string()));
     }
 
+    #[test]
+    fn test_relation_inverse() {
+        assert_eq!(Relation::IsA.inverse(), Relation::from("has_instance"));
+        assert_eq!(Relation::IsPartOf.inverse(), Relation::from("has_part"));
+        assert_eq!(Relation::Causes.inverse(), Relation::from("caused_by"));
+        assert_eq!(Relation::SimilarTo.inverse(), Relation::SimilarTo);
+        assert_eq!(
+            Relation::from("mentors").inverse(),
+            Relation::from("inverse_of_mentors")
+        );
+
+        // Inverting twice is the identity
+        let custom = Relation::from("mentors");
+        for relation in Relation::STANDARD.iter().chain([&custom]) {
+            assert_eq!(relation.inverse().inverse(), *relation);
+            assert_eq!(relation.inverse().is_inverse(), relation.is_directional());
+        }
+    }
+
     #[test]
     fn test_relation_properties() {
         assert!(Relation::IsPartOf.is_transitive());
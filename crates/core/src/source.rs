@@ -24,6 +24,12 @@ pub enum Source {
     /// Automatically extracted from other content
     AutoExtracted,
 
+    /// Web page or document fetched from a URL
+    Url(String),
+
+    /// Uploaded file with its file name
+    File(String),
+
     /// Custom source with identifier
     Custom(String),
 }
@@ -38,7 +44,10 @@ impl Source {
     /// Check if this is an automated source
     #[inline]
     pub const fn is_automated(&self) -> bool {
-        matches!(self, Self::AutoExtracted | Self::WebCrawl | Self::ApiImport)
+        matches!(
+            self,
+            Self::AutoExtracted | Self::WebCrawl | Self::ApiImport | Self::Url(_)
+        )
     }
 }
 
@@ -50,6 +59,8 @@ impl fmt::Display for Source {
             Self::WebCrawl => write!(f, "web_crawl"),
             Self::ApiImport => write!(f, "api_import"),
             Self::AutoExtracted => write!(f, "auto_extracted"),
+            Self::Url(url) => write!(f, "url:{}", url),
+            Self::File(name) => write!(f, "file:{}", name),
             Self::Custom(s) => write!(f, "custom:{}", s),
         }
     }
@@ -70,7 +81,15 @@ impl From<&str> for Source {
             "web_crawl" => Self::WebCrawl,
             "api_import" => Self::ApiImport,
             "auto_extracted" => Self::AutoExtracted,
-            other => Self::Custom(other.to_string()),
+            other => {
+                if let Some(url) = other.strip_prefix("url:") {
+                    Self::Url(url.to_string())
+                } else if let Some(name) = other.strip_prefix("file:") {
+                    Self::File(name.to_string())
+                } else {
+                    Self::Custom(other.to_string())
+                }
+            }
         }
     }
 }
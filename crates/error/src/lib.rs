@@ -0,0 +1,450 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Shared error type for SYNTON-DB.
+//!
+//! Each crate keeps its own error enum and implements [`Coded`] for it.
+//! Converting into [`SyntonError`] keeps the original error (and therefore
+//! its cause chain) instead of flattening it to a string, and adds a stable
+//! [`ErrorCode`], context messages and an optional trace ID. [`ErrorBody`]
+//! is the wire form returned by the REST API and read back by the CLI and
+//! MCP clients.
+
+#![warn(missing_docs)]
+#![warn(clippy::all)]
+
+use std::error::Error as StdError;
+use std::fmt;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+/// Stable, machine-readable error category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// A referenced node, edge, index or handle does not exist
+    NotFound,
+    /// The request or input data is invalid
+    InvalidInput,
+    /// The operation conflicts with existing data
+    Conflict,
+    /// A configured limit was exceeded
+    LimitExceeded,
+    /// The storage backend failed
+    Storage,
+    /// Data could not be serialized or deserialized
+    Serialization,
+    /// Too many concurrent requests; retry later
+    Overloaded,
+    /// The service or a dependency is unavailable
+    Unavailable,
+    /// The operation is not implemented
+    NotImplemented,
+    /// Unexpected internal failure
+    Internal,
+}
+
+impl ErrorCode {
+    /// Snake-case name, as used on the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotFound => "not_found",
+            Self::InvalidInput => "invalid_input",
+            Self::Conflict => "conflict",
+            Self::LimitExceeded => "limit_exceeded",
+            Self::Storage => "storage",
+            Self::Serialization => "serialization",
+            Self::Overloaded => "overloaded",
+            Self::Unavailable => "unavailable",
+            Self::NotImplemented => "not_implemented",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// HTTP status code conventionally used for this category.
+    pub fn http_status(&self) -> u16 {
+        match self {
+            Self::NotFound => 404,
+            Self::InvalidInput => 400,
+            Self::Conflict => 409,
+            Self::LimitExceeded => 422,
+            Self::Overloaded => 429,
+            Self::Unavailable => 503,
+            Self::NotImplemented => 501,
+            Self::Storage | Self::Serialization | Self::Internal => 500,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Crate error types that map to an [`ErrorCode`].
+pub trait Coded {
+    /// Category of this error.
+    fn code(&self) -> ErrorCode;
+}
+
+type Shared = Arc<dyn StdError + Send + Sync + 'static>;
+
+/// Structured error crossing crate boundaries.
+///
+/// Cheap to clone: the wrapped errors are reference counted.
+#[derive(Clone)]
+pub struct SyntonError {
+    code: ErrorCode,
+    message: String,
+    /// Context messages, innermost first
+    context: Vec<String>,
+    /// Typed error this one was converted from
+    origin: Option<Shared>,
+    /// Underlying cause attached with [`SyntonError::with_source`]
+    cause: Option<Shared>,
+    trace_id: Option<String>,
+}
+
+impl SyntonError {
+    /// Create an error with a code and message.
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            context: Vec::new(),
+            origin: None,
+            cause: None,
+            trace_id: None,
+        }
+    }
+
+    /// Attach the error that caused this one.
+    pub fn with_source(mut self, source: impl StdError + Send + Sync + 'static) -> Self {
+        self.cause = Some(Arc::new(source));
+        self
+    }
+
+    /// Attach the trace ID of the request that failed.
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Wrap the error in a message describing what was being done.
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context.push(context.into());
+        self
+    }
+
+    /// Error category.
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    /// Message of the innermost error, without context.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Trace ID of the failed request, if known.
+    pub fn trace_id(&self) -> Option<&str> {
+        self.trace_id.as_deref()
+    }
+
+    /// The typed error this one was converted from, if it is a `T`.
+    pub fn downcast_ref<T: StdError + 'static>(&self) -> Option<&T> {
+        self.origin.as_deref().and_then(|e| e.downcast_ref::<T>())
+    }
+
+    /// Messages of the underlying causes, outermost first.
+    ///
+    /// Causes whose message is already part of the previous one (wrappers
+    /// that display their inner error) are skipped.
+    pub fn causes(&self) -> Vec<String> {
+        let mut causes: Vec<String> = Vec::new();
+        let mut previous = self.message.clone();
+        let mut next = StdError::source(self);
+        while let Some(err) = next {
+            let text = err.to_string();
+            if !previous.contains(&text) {
+                causes.push(text.clone());
+            }
+            previous = text;
+            next = err.source();
+        }
+        causes
+    }
+
+    /// Multi-line report with code, causes and trace ID, for terminals.
+    pub fn report(&self) -> String {
+        ErrorBody::from(self).report()
+    }
+}
+
+impl fmt::Display for SyntonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for context in self.context.iter().rev() {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl fmt::Debug for SyntonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyntonError")
+            .field("code", &self.code)
+            .field("message", &self.to_string())
+            .field("causes", &self.causes())
+            .field("trace_id", &self.trace_id)
+            .finish()
+    }
+}
+
+impl StdError for SyntonError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match (&self.cause, &self.origin) {
+            (Some(cause), _) => Some(cause.as_ref() as &(dyn StdError + 'static)),
+            (None, Some(origin)) => origin.source(),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<E> From<E> for SyntonError
+where
+    E: Coded + StdError + Send + Sync + 'static,
+{
+    fn from(e: E) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+            context: Vec::new(),
+            origin: Some(Arc::new(e)),
+            cause: None,
+            trace_id: None,
+        }
+    }
+}
+
+/// Result type using [`SyntonError`].
+pub type SyntonResult<T> = Result<T, SyntonError>;
+
+/// Adds context to results whose error converts into [`SyntonError`].
+pub trait ResultExt<T> {
+    /// Wrap the error with a message describing what was being done.
+    fn context(self, context: impl Into<String>) -> SyntonResult<T>;
+
+    /// Like [`ResultExt::context`], building the message only on error.
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> SyntonResult<T>;
+}
+
+impl<T, E: Into<SyntonError>> ResultExt<T> for Result<T, E> {
+    fn context(self, context: impl Into<String>) -> SyntonResult<T> {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> SyntonResult<T> {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+/// JSON error body returned by the REST API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorBody {
+    /// Human-readable message, including context
+    pub error: String,
+
+    /// Error category
+    #[serde(default = "default_code")]
+    pub code: ErrorCode,
+
+    /// Underlying causes, outermost first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub causes: Vec<String>,
+
+    /// Trace ID of the failed request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+
+    /// Request fields that failed validation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldError>,
+
+    /// Current version of a node whose versioned update was rejected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_version: Option<u64>,
+}
+
+fn default_code() -> ErrorCode {
+    ErrorCode::Internal
+}
+
+impl ErrorBody {
+    /// Multi-line report with code, causes and trace ID, for terminals.
+    pub fn report(&self) -> String {
+        let mut out = format!("{} [{}]", self.error, self.code);
+        for field in &self.fields {
+            out.push_str(&format!("\n  {}", field));
+        }
+        if let Some(version) = self.current_version {
+            out.push_str(&format!("\n  current version: {}", version));
+        }
+        for cause in &self.causes {
+            out.push_str(&format!("\n  caused by: {}", cause));
+        }
+        if let Some(trace_id) = &self.trace_id {
+            out.push_str(&format!("\n  trace id: {}", trace_id));
+        }
+        out
+    }
+}
+
+impl From<&SyntonError> for ErrorBody {
+    fn from(e: &SyntonError) -> Self {
+        Self {
+            error: e.to_string(),
+            code: e.code,
+            causes: e.causes(),
+            trace_id: e.trace_id.clone(),
+            fields: Vec::new(),
+            current_version: None,
+        }
+    }
+}
+
+/// A request field that failed validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    /// Path of the field, e.g. `provenance.confidence`
+    pub field: String,
+
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl FieldError {
+    /// Create a field error.
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    enum LowError {
+        Io(std::io::Error),
+    }
+
+    impl fmt::Display for LowError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Io(e) => write!(f, "I/O error: {}", e),
+            }
+        }
+    }
+
+    impl StdError for LowError {
+        fn source(&self) -> Option<&(dyn StdError + 'static)> {
+            match self {
+                Self::Io(e) => Some(e),
+            }
+        }
+    }
+
+    impl Coded for LowError {
+        fn code(&self) -> ErrorCode {
+            ErrorCode::Storage
+        }
+    }
+
+    fn io_failure() -> Result<(), LowError> {
+        Err(LowError::Io(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "data dir is read-only",
+        )))
+    }
+
+    #[test]
+    fn test_conversion_keeps_code_and_origin() {
+        let err: SyntonError = io_failure().unwrap_err().into();
+        assert_eq!(err.code(), ErrorCode::Storage);
+        assert_eq!(err.message(), "I/O error: data dir is read-only");
+        assert!(matches!(err.downcast_ref::<LowError>(), Some(LowError::Io(_))));
+
+        // The io::Error is displayed by its wrapper, so it is not repeated
+        assert!(err.causes().is_empty());
+    }
+
+    #[test]
+    fn test_context_and_causes() {
+        let err = io_failure()
+            .context("persist node")
+            .context("ingest document")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ingest document: persist node: I/O error: data dir is read-only"
+        );
+
+        let wrapped = SyntonError::new(ErrorCode::Unavailable, "snapshot failed")
+            .with_source(io_failure().unwrap_err())
+            .with_trace_id("abc123");
+        assert_eq!(wrapped.causes(), vec!["I/O error: data dir is read-only".to_string()]);
+        assert_eq!(
+            wrapped.report(),
+            "snapshot failed [unavailable]\n  caused by: I/O error: data dir is read-only\n  trace id: abc123"
+        );
+    }
+
+    #[test]
+    fn test_error_body_roundtrip() {
+        let err = SyntonError::new(ErrorCode::NotFound, "Node not found: 42").context("traverse");
+        let json = serde_json::to_value(ErrorBody::from(&err)).unwrap();
+        assert_eq!(json["code"], "not_found");
+        assert!(json.get("causes").is_none());
+
+        // Bodies from older servers only carry the message
+        let legacy: ErrorBody = serde_json::from_str(r#"{"error": "boom"}"#).unwrap();
+        assert_eq!(legacy.code, ErrorCode::Internal);
+        assert_eq!(legacy.report(), "boom [internal]");
+        assert_eq!(ErrorCode::LimitExceeded.http_status(), 422);
+
+        let invalid = ErrorBody {
+            fields: vec![FieldError::new("limit", "must be between 1 and 100")],
+            ..ErrorBody::from(&SyntonError::new(ErrorCode::InvalidInput, "Invalid request"))
+        };
+        let json = serde_json::to_value(&invalid).unwrap();
+        assert_eq!(json["fields"][0]["field"], "limit");
+        assert_eq!(
+            invalid.report(),
+            "Invalid request [invalid_input]\n  limit: must be between 1 and 100"
+        );
+
+        let conflict = ErrorBody {
+            current_version: Some(4),
+            ..ErrorBody::from(&SyntonError::new(ErrorCode::Conflict, "Version conflict"))
+        };
+        let json = serde_json::to_value(&conflict).unwrap();
+        assert_eq!(json["current_version"], 4);
+        assert_eq!(
+            conflict.report(),
+            "Version conflict [conflict]\n  current version: 4"
+        );
+    }
+}
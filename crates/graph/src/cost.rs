@@ -0,0 +1,187 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Traversal cost estimation.
+//!
+//! A traversal is predicted level by level: the first level is the start
+//! node's actual (filtered) degree, deeper levels grow by the graph's average
+//! degree scaled by the fraction of edges the traversal follows. This is a
+//! dry-run guide for spotting runaway traversals, not an exact count.
+
+use std::collections::HashMap;
+
+use synton_core::Relation;
+
+use crate::{TraversalConfig, TraverseDirection};
+
+/// Degree statistics of a graph.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DegreeStats {
+    /// Number of nodes
+    pub node_count: usize,
+
+    /// Number of edges
+    pub edge_count: usize,
+
+    /// Largest number of outgoing edges of a single node
+    pub max_out_degree: usize,
+
+    /// Largest number of incoming edges of a single node
+    pub max_in_degree: usize,
+
+    /// Number of edges per relation (empty when unknown)
+    pub relation_counts: HashMap<Relation, usize>,
+}
+
+impl DegreeStats {
+    /// Statistics known only from node and edge totals.
+    pub fn from_counts(node_count: usize, edge_count: usize) -> Self {
+        Self {
+            node_count,
+            edge_count,
+            ..Default::default()
+        }
+    }
+
+    /// Average number of edges per node in a direction.
+    pub fn avg_degree(&self, direction: TraverseDirection) -> f64 {
+        if self.node_count == 0 {
+            return 0.0;
+        }
+        let avg = self.edge_count as f64 / self.node_count as f64;
+        match direction {
+            TraverseDirection::Both => avg * 2.0,
+            _ => avg,
+        }
+    }
+
+    /// Fraction of edges a traversal follows under its relation filter.
+    ///
+    /// Creator filters are not tracked and count as following every edge.
+    pub fn selectivity(&self, config: &TraversalConfig) -> f64 {
+        if config.relation_filter.is_empty() || self.relation_counts.is_empty() || self.edge_count == 0 {
+            return 1.0;
+        }
+        let matching: usize = config
+            .relation_filter
+            .iter()
+            .filter_map(|r| self.relation_counts.get(r))
+            .sum();
+        matching as f64 / self.edge_count as f64
+    }
+}
+
+/// Predicted cost of a traversal.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CostEstimate {
+    /// Estimated newly reached nodes per depth, starting at depth 1
+    pub nodes_per_depth: Vec<u64>,
+
+    /// Estimated number of nodes returned
+    pub estimated_nodes: u64,
+
+    /// Estimated number of edges examined
+    pub estimated_edges: u64,
+
+    /// Whether the estimate was cut off by `max_nodes`
+    pub capped: bool,
+}
+
+impl CostEstimate {
+    /// Single cost figure used for guards: the estimated edges examined.
+    pub fn cost(&self) -> u64 {
+        self.estimated_edges
+    }
+}
+
+/// Estimate a breadth-first traversal from a start node with the given
+/// number of followed edges.
+pub fn estimate_traversal(
+    stats: &DegreeStats,
+    start_degree: usize,
+    config: &TraversalConfig,
+) -> CostEstimate {
+    let branching = stats.avg_degree(config.direction) * stats.selectivity(config);
+    let reachable = stats.node_count.saturating_sub(1) as f64;
+    let max_nodes = config.max_nodes as f64;
+
+    let mut estimate = CostEstimate {
+        estimated_edges: start_degree as u64,
+        ..Default::default()
+    };
+    let mut frontier = start_degree as f64;
+    let mut reached = 0.0;
+
+    for depth in 1..=config.max_depth {
+        let mut new = frontier.min(reachable - reached).max(0.0);
+        if reached + new >= max_nodes {
+            new = (max_nodes - reached).max(0.0);
+            estimate.capped = true;
+        }
+        reached += new;
+        estimate.nodes_per_depth.push(new.round() as u64);
+
+        if estimate.capped || new < 0.5 || depth == config.max_depth {
+            break;
+        }
+        let expanded = new * branching;
+        estimate.estimated_edges += expanded.round() as u64;
+        frontier = expanded;
+    }
+
+    estimate.estimated_nodes = reached.round() as u64;
+    estimate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(node_count: usize, edge_count: usize) -> DegreeStats {
+        DegreeStats::from_counts(node_count, edge_count)
+    }
+
+    #[test]
+    fn test_estimate_grows_with_depth() {
+        let stats = stats(10_000, 50_000);
+        let config = TraversalConfig::with_depth(3).with_max_nodes(100_000);
+
+        let estimate = estimate_traversal(&stats, 10, &config);
+        assert_eq!(estimate.nodes_per_depth, vec![10, 50, 250]);
+        assert_eq!(estimate.estimated_nodes, 310);
+        assert_eq!(estimate.estimated_edges, 10 + 50 + 250);
+        assert!(!estimate.capped);
+
+        let both = config.with_direction(TraverseDirection::Both);
+        assert!(estimate_traversal(&stats, 10, &both).cost() > estimate.cost());
+    }
+
+    #[test]
+    fn test_estimate_capped_by_limits() {
+        let stats = stats(50, 500);
+        let config = TraversalConfig::with_depth(5).with_max_nodes(1_000);
+
+        // The whole graph is reached before the depth limit
+        let estimate = estimate_traversal(&stats, 20, &config);
+        assert_eq!(estimate.estimated_nodes, 49);
+        assert!(!estimate.capped);
+
+        let estimate = estimate_traversal(&stats, 20, &TraversalConfig::with_depth(5).with_max_nodes(30));
+        assert_eq!(estimate.estimated_nodes, 30);
+        assert!(estimate.capped);
+
+        assert_eq!(estimate_traversal(&stats, 0, &config).estimated_nodes, 0);
+    }
+
+    #[test]
+    fn test_relation_selectivity() {
+        let mut stats = stats(100, 400);
+        stats.relation_counts.insert(Relation::Causes, 100);
+        stats.relation_counts.insert(Relation::SimilarTo, 300);
+
+        let config = TraversalConfig::with_depth(2).with_relation(Relation::Causes);
+        assert_eq!(stats.selectivity(&config), 0.25);
+        assert_eq!(stats.selectivity(&TraversalConfig::default()), 1.0);
+    }
+}
@@ -5,6 +5,7 @@
 use std::fmt;
 
 use synton_core::CoreError;
+use synton_error::{Coded, ErrorCode};
 
 /// Graph-specific errors.
 #[derive(Debug, Clone, PartialEq)]
@@ -21,9 +22,15 @@ pub enum GraphError {
     /// Invalid traversal depth
     InvalidDepth(String),
 
+    /// Invalid graph pattern
+    InvalidPattern(String),
+
     /// Storage error
     Storage(String),
 
+    /// Core data error
+    Core(CoreError),
+
     /// Custom error
     Custom(String),
 }
@@ -35,19 +42,58 @@ impl fmt::Display for GraphError {
             Self::EdgeNotFound(e) => write!(f, "Edge not found: {}", e),
             Self::CycleDetected(path) => write!(f, "Cycle detected: {:?}", path),
             Self::InvalidDepth(e) => write!(f, "Invalid depth: {}", e),
+            Self::InvalidPattern(e) => write!(f, "Invalid pattern: {}", e),
             Self::Storage(e) => write!(f, "Storage error: {}", e),
+            Self::Core(e) => write!(f, "{}", e),
             Self::Custom(e) => write!(f, "{}", e),
         }
     }
 }
 
-impl std::error::Error for GraphError {}
+impl std::error::Error for GraphError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Core(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Coded for GraphError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::NodeNotFound(_) | Self::EdgeNotFound(_) => ErrorCode::NotFound,
+            Self::CycleDetected(_) | Self::InvalidDepth(_) | Self::InvalidPattern(_) => {
+                ErrorCode::InvalidInput
+            }
+            Self::Storage(_) => ErrorCode::Storage,
+            Self::Core(e) => e.code(),
+            Self::Custom(_) => ErrorCode::Internal,
+        }
+    }
+}
 
 /// Result type for graph operations.
 pub type GraphResult<T> = Result<T, GraphError>;
 
 impl From<CoreError> for GraphError {
     fn from(e: CoreError) -> Self {
-        Self::Custom(e.to_string())
+        Self::Core(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_error::SyntonError;
+
+    #[test]
+    fn test_core_error_keeps_code() {
+        let err: SyntonError = GraphError::from(CoreError::EmptyContent).into();
+        assert_eq!(err.code(), ErrorCode::InvalidInput);
+        assert_eq!(err.to_string(), CoreError::EmptyContent.to_string());
+        assert!(matches!(err.downcast_ref::<GraphError>(), Some(GraphError::Core(_))));
+
+        assert_eq!(GraphError::NodeNotFound(uuid::Uuid::nil()).code(), ErrorCode::NotFound);
     }
 }
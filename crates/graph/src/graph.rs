@@ -3,10 +3,15 @@
 // Licensed under the Apache License, Version 2.0 (the "License);
 
 use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
 use uuid::Uuid;
 
+use crate::cost::{self, CostEstimate, DegreeStats};
+use crate::pattern::{self, MatchConfig, Pattern, PatternMatches, PatternSource};
+use crate::supernode::{EdgeBundle, Overflow, SupernodeStrategy};
 use crate::{GraphError, GraphResult};
-use synton_core::{Edge, Node, Relation};
+use synton_core::{Edge, EdgeCreator, Node, NodeType, Relation};
 
 /// Direction for graph traversal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -47,11 +52,25 @@ pub struct TraversalConfig {
     /// Filter relations to follow (empty = all relations)
     pub relation_filter: Vec<Relation>,
 
+    /// Filter edge creators to follow (empty = all creators)
+    pub creator_filter: Vec<EdgeCreator>,
+
     /// Whether to detect and avoid cycles
     pub avoid_cycles: bool,
 
     /// Whether to include the start node in results
     pub include_start: bool,
+
+    /// Whether backward steps follow incoming edges as their inverse
+    /// (e.g. `has_part` for an incoming `is_part_of`). Relation filters
+    /// then match the inverse label.
+    pub follow_inverses: bool,
+
+    /// Minimum extraction confidence of followed edges (0.0 = all edges)
+    pub min_confidence: f32,
+
+    /// How nodes with more edges than the graph's maximum degree are expanded
+    pub supernode_strategy: SupernodeStrategy,
 }
 
 impl Default for TraversalConfig {
@@ -61,8 +80,12 @@ impl Default for TraversalConfig {
             max_nodes: 100,
             direction: TraverseDirection::Forward,
             relation_filter: Vec::new(),
+            creator_filter: Vec::new(),
             avoid_cycles: true,
             include_start: false,
+            follow_inverses: false,
+            min_confidence: 0.0,
+            supernode_strategy: SupernodeStrategy::default(),
         }
     }
 }
@@ -90,6 +113,24 @@ impl TraversalConfig {
         self
     }
 
+    pub fn with_creator(mut self, creator: EdgeCreator) -> Self {
+        self.creator_filter.push(creator);
+        self
+    }
+
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Whether a traversal with this config follows the given edge.
+    pub fn follows(&self, edge: &Edge) -> bool {
+        (self.relation_filter.is_empty() || self.relation_filter.contains(&edge.relation))
+            && (self.creator_filter.is_empty()
+                || self.creator_filter.contains(&edge.created_by()))
+            && edge.confidence() >= self.min_confidence
+    }
+
     pub fn with_avoid_cycles(mut self, avoid: bool) -> Self {
         self.avoid_cycles = avoid;
         self
@@ -99,6 +140,34 @@ impl TraversalConfig {
         self.include_start = include;
         self
     }
+
+    pub fn with_follow_inverses(mut self, follow: bool) -> Self {
+        self.follow_inverses = follow;
+        self
+    }
+
+    pub fn with_supernode_strategy(mut self, strategy: SupernodeStrategy) -> Self {
+        self.supernode_strategy = strategy;
+        self
+    }
+
+    /// Edges a traversal with this config follows out of a node, given the
+    /// node's outgoing and incoming edges.
+    pub fn followed_edges(&self, outgoing: Vec<Edge>, incoming: Vec<Edge>) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        if self.direction.includes_forward() {
+            edges.extend(outgoing);
+        }
+        if self.direction.includes_backward() {
+            if self.follow_inverses {
+                edges.extend(incoming.iter().map(Edge::inverse));
+            } else {
+                edges.extend(incoming);
+            }
+        }
+        edges.retain(|e| self.follows(e));
+        edges
+    }
 }
 
 /// Result of a graph traversal operation.
@@ -108,6 +177,8 @@ pub struct TraversalResult {
     pub edges: Vec<Edge>,
     pub depth: usize,
     pub truncated: bool,
+    /// Supernodes whose expansion was sampled or skipped
+    pub supernodes: Vec<Uuid>,
 }
 
 impl TraversalResult {
@@ -117,6 +188,7 @@ impl TraversalResult {
             edges,
             depth,
             truncated: false,
+            supernodes: Vec::new(),
         }
     }
 
@@ -154,97 +226,278 @@ pub trait Graph: Send + Sync {
     async fn count_nodes(&self) -> GraphResult<usize>;
 
     async fn count_edges(&self) -> GraphResult<usize>;
+
+    /// Edges a traversal with `config` follows out of a node; see
+    /// [`TraversalConfig::followed_edges`].
+    async fn traversal_edges(&self, id: Uuid, config: &TraversalConfig) -> GraphResult<Vec<Edge>> {
+        let outgoing = if config.direction.includes_forward() {
+            self.edges(id, TraverseDirection::Forward).await?
+        } else {
+            Vec::new()
+        };
+        let incoming = if config.direction.includes_backward() {
+            self.edges(id, TraverseDirection::Backward).await?
+        } else {
+            Vec::new()
+        };
+        Ok(config.followed_edges(outgoing, incoming))
+    }
+
+    /// Degree statistics for cost estimation.
+    ///
+    /// The default only knows node and edge totals; implementations may
+    /// provide (and cache) finer statistics.
+    async fn degree_stats(&self) -> GraphResult<DegreeStats> {
+        Ok(DegreeStats::from_counts(
+            self.count_nodes().await?,
+            self.count_edges().await?,
+        ))
+    }
+
+    /// Estimate the cost of a breadth-first traversal without running it.
+    async fn estimate_traversal(
+        &self,
+        start: Uuid,
+        config: &TraversalConfig,
+    ) -> GraphResult<CostEstimate> {
+        if !self.node_exists(start).await? {
+            return Err(GraphError::NodeNotFound(start));
+        }
+        let start_degree = self.traversal_edges(start, config).await?.len();
+        let stats = self.degree_stats().await?;
+        Ok(cost::estimate_traversal(&stats, start_degree, config))
+    }
+
+    /// Summary of a node's edges beyond the graph's maximum degree in a
+    /// direction (both bundles merged for [`TraverseDirection::Both`]), or
+    /// `None` if the node is not a supernode.
+    async fn edge_bundle(&self, id: Uuid, direction: TraverseDirection) -> GraphResult<Option<EdgeBundle>> {
+        let _ = (id, direction);
+        Ok(None)
+    }
 }
 
-/// In-memory graph implementation.
-pub struct MemoryGraph {
-    nodes: std::collections::HashMap<Uuid, Node>,
-    edges: std::collections::HashMap<Uuid, Vec<Edge>>,
-    incoming: std::collections::HashMap<Uuid, Vec<Edge>>,
+/// Node and edge maps shared between a [`MemoryGraph`] and its snapshots.
+///
+/// Nodes, edge lists and overflows are stored behind `Arc` so that copying
+/// the maps on the first write after a snapshot only copies pointers, not
+/// node content, embeddings or edges. A list is copied when it is changed.
+#[derive(Debug, Clone, Default)]
+struct GraphState {
+    nodes: HashMap<Uuid, Arc<Node>>,
+    /// Node IDs per node type, for pattern matching.
+    nodes_by_type: HashMap<NodeType, HashSet<Uuid>>,
+    edges: HashMap<Uuid, Arc<Vec<Edge>>>,
+    incoming: HashMap<Uuid, Arc<Vec<Edge>>>,
+    /// Edges per node and direction kept in `edges` and `incoming`; later
+    /// ones overflow. `None` keeps every edge there.
+    max_degree: Option<usize>,
+    /// Outgoing edges beyond `max_degree`, per source node.
+    outgoing_overflow: HashMap<Uuid, Arc<Overflow>>,
+    /// Incoming edges beyond `max_degree`, per target node.
+    incoming_overflow: HashMap<Uuid, Arc<Overflow>>,
+    /// Degree statistics, computed on first use and reset by writes.
+    stats: OnceLock<DegreeStats>,
 }
 
-impl MemoryGraph {
-    pub fn new() -> Self {
-        Self {
-            nodes: Default::default(),
-            edges: Default::default(),
-            incoming: Default::default(),
+impl GraphState {
+    fn insert_node(&mut self, node: Node) {
+        self.nodes_by_type.entry(node.node_type).or_default().insert(node.id);
+        let id = node.id;
+        let node_type = node.node_type;
+        if let Some(old) = self.nodes.insert(id, Arc::new(node)) {
+            if old.node_type != node_type {
+                if let Some(ids) = self.nodes_by_type.get_mut(&old.node_type) {
+                    ids.remove(&id);
+                }
+            }
         }
+        self.stats.take();
     }
 
-    pub fn add_node(&mut self, node: Node) -> GraphResult<()> {
-        if self.nodes.contains_key(&node.id) {
-            return Err(GraphError::Custom(format!("Node {} already exists", node.id)));
+    fn insert_edge(&mut self, edge: Edge) {
+        self.stats.take();
+        let max_degree = self.max_degree.unwrap_or(usize::MAX);
+
+        let outgoing = Arc::make_mut(self.edges.entry(edge.source).or_default());
+        if outgoing.len() < max_degree {
+            outgoing.push(edge.clone());
+        } else {
+            Arc::make_mut(self.outgoing_overflow.entry(edge.source).or_default())
+                .push(edge.clone());
         }
-        self.nodes.insert(node.id, node);
-        Ok(())
-    }
 
-    pub fn add_edge(&mut self, edge: Edge) -> GraphResult<()> {
-        self.edges.entry(edge.source).or_default().push(edge.clone());
-        self.incoming.entry(edge.target).or_default().push(edge);
-        Ok(())
+        let incoming = Arc::make_mut(self.incoming.entry(edge.target).or_default());
+        if incoming.len() < max_degree {
+            incoming.push(edge);
+        } else {
+            Arc::make_mut(self.incoming_overflow.entry(edge.target).or_default()).push(edge);
+        }
     }
 
-    pub fn from_parts(nodes: Vec<Node>, edges: Vec<Edge>) -> GraphResult<Self> {
-        let mut graph = Self::new();
+    /// Remove a node and the edges to and from it, returning those edges.
+    fn remove_node(&mut self, id: Uuid) -> Option<Vec<Edge>> {
+        let node = self.nodes.remove(&id)?;
+        if let Some(ids) = self.nodes_by_type.get_mut(&node.node_type) {
+            ids.remove(&id);
+        }
+        self.stats.take();
 
-        for node in nodes {
-            graph.nodes.insert(node.id, node);
+        let mut removed = self.edges.remove(&id).map(unshare).unwrap_or_default();
+        if let Some(overflow) = self.outgoing_overflow.remove(&id) {
+            removed.extend(unshare(overflow).edges);
+        }
+        let mut incoming = self.incoming.remove(&id).map(unshare).unwrap_or_default();
+        if let Some(overflow) = self.incoming_overflow.remove(&id) {
+            incoming.extend(unshare(overflow).edges);
         }
 
-        for edge in edges {
-            graph.edges.entry(edge.source).or_default().push(edge.clone());
-            graph.incoming.entry(edge.target).or_default().push(edge);
+        let max_degree = self.max_degree.unwrap_or(usize::MAX);
+        for edge in &removed {
+            detach(
+                &mut self.incoming,
+                &mut self.incoming_overflow,
+                edge.target,
+                id,
+                max_degree,
+            );
         }
+        for edge in &incoming {
+            detach(
+                &mut self.edges,
+                &mut self.outgoing_overflow,
+                edge.source,
+                id,
+                max_degree,
+            );
+        }
+        // Self-loops are already among the outgoing edges
+        removed.extend(incoming.into_iter().filter(|edge| edge.source != id));
+        Some(removed)
+    }
 
-        Ok(graph)
+    fn set_max_degree(&mut self, max_degree: Option<usize>) {
+        self.max_degree = max_degree;
+        let max_degree = max_degree.unwrap_or(usize::MAX);
+        rebundle(&mut self.edges, &mut self.outgoing_overflow, max_degree);
+        rebundle(&mut self.incoming, &mut self.incoming_overflow, max_degree);
     }
-}
 
-impl Default for MemoryGraph {
-    fn default() -> Self {
-        Self::new()
+    /// All outgoing edges of a node, bundled ones last.
+    fn outgoing(&self, id: &Uuid) -> impl Iterator<Item = &Edge> {
+        self.edges
+            .get(id)
+            .into_iter()
+            .flat_map(|edges| edges.iter())
+            .chain(self.outgoing_overflow.get(id).into_iter().flat_map(|o| &o.edges))
     }
-}
 
-#[async_trait]
-impl Graph for MemoryGraph {
-    async fn edges(&self, id: Uuid, direction: TraverseDirection) -> GraphResult<Vec<Edge>> {
+    fn node(&self, id: &Uuid) -> Option<Node> {
+        self.nodes.get(id).map(|node| Node::clone(node))
+    }
+
+    fn edges(&self, id: Uuid, direction: TraverseDirection) -> Vec<Edge> {
         let mut result = Vec::new();
 
         if direction.includes_forward() {
-            if let Some(edges) = self.edges.get(&id) {
-                result.extend(edges.clone());
-            }
+            result.extend(self.outgoing(&id).cloned());
         }
 
         if direction.includes_backward() {
             if let Some(edges) = self.incoming.get(&id) {
-                result.extend(edges.clone());
+                result.extend(edges.iter().cloned());
+            }
+            if let Some(overflow) = self.incoming_overflow.get(&id) {
+                result.extend(overflow.edges.iter().cloned());
             }
         }
 
-        Ok(result)
+        result
     }
 
-    async fn neighbors(&self, id: Uuid, direction: TraverseDirection) -> GraphResult<Vec<Node>> {
-        let edge_ids = self.edges(id, direction).await?;
+    /// Edges a traversal with `config` follows out of a node, and whether
+    /// the node is a supernode whose expansion `config` limits.
+    fn expansion_edges(&self, id: Uuid, config: &TraversalConfig) -> (Vec<Edge>, bool) {
+        let supernode = (config.direction.includes_forward() && self.outgoing_overflow.contains_key(&id))
+            || (config.direction.includes_backward() && self.incoming_overflow.contains_key(&id));
+        let (outgoing, incoming) = match (supernode, config.supernode_strategy) {
+            (true, SupernodeStrategy::Skip) => return (Vec::new(), true),
+            (true, SupernodeStrategy::Sample) => (
+                self.edges
+                    .get(&id)
+                    .map(|edges| edges.to_vec())
+                    .unwrap_or_default(),
+                self.incoming
+                    .get(&id)
+                    .map(|edges| edges.to_vec())
+                    .unwrap_or_default(),
+            ),
+            _ => (
+                self.edges(id, TraverseDirection::Forward),
+                self.edges(id, TraverseDirection::Backward),
+            ),
+        };
+        let limited = supernode && config.supernode_strategy != SupernodeStrategy::Expand;
+        (config.followed_edges(outgoing, incoming), limited)
+    }
+
+    fn edge_bundle(&self, id: Uuid, direction: TraverseDirection) -> Option<EdgeBundle> {
+        let mut bundles = Vec::new();
+        if direction.includes_forward() {
+            bundles.extend(self.outgoing_overflow.get(&id).map(Arc::as_ref));
+        }
+        if direction.includes_backward() {
+            bundles.extend(self.incoming_overflow.get(&id).map(Arc::as_ref));
+        }
+        if bundles.is_empty() {
+            return None;
+        }
+        let mut bundle = EdgeBundle::default();
+        for overflow in bundles {
+            bundle.merge(&overflow.bundle);
+        }
+        Some(bundle)
+    }
+
+    fn neighbors(&self, id: Uuid, direction: TraverseDirection) -> Vec<Node> {
+        self.neighbors_where(id, direction, |_| true)
+    }
+
+    fn neighbors_where(
+        &self,
+        id: Uuid,
+        direction: TraverseDirection,
+        follows: impl Fn(&Edge) -> bool,
+    ) -> Vec<Node> {
         let mut neighbors = Vec::new();
-        for edge in edge_ids {
+        for edge in self.edges(id, direction).into_iter().filter(|e| follows(e)) {
             let target_id = if direction.includes_forward() {
                 edge.target
             } else {
                 edge.source
             };
-            if let Some(node) = self.nodes.get(&target_id) {
-                neighbors.push(node.clone());
+            if let Some(node) = self.node(&target_id) {
+                neighbors.push(node);
             }
         }
-        Ok(neighbors)
+        neighbors
     }
 
-    async fn bfs(&self, start: Uuid, config: TraversalConfig) -> GraphResult<TraversalResult> {
+    /// Nodes reached over the edges a traversal with `config` follows, and
+    /// whether the node is a supernode whose expansion was limited.
+    fn traversal_neighbors(&self, id: Uuid, config: &TraversalConfig) -> (Vec<Node>, bool) {
+        let (edges, limited) = self.expansion_edges(id, config);
+        let neighbors = edges
+            .into_iter()
+            .filter_map(|edge| {
+                let other = if edge.source == id { edge.target } else { edge.source };
+                self.node(&other)
+            })
+            .collect();
+        (neighbors, limited)
+    }
+
+    fn bfs(&self, start: Uuid, config: TraversalConfig) -> GraphResult<TraversalResult> {
         if !self.nodes.contains_key(&start) {
             return Err(GraphError::NodeNotFound(start));
         }
@@ -252,12 +505,13 @@ impl Graph for MemoryGraph {
         let mut visited = std::collections::HashSet::new();
         let mut queue = std::collections::VecDeque::new();
         let mut result_nodes = Vec::new();
+        let mut supernodes = Vec::new();
         let mut depth = 0;
         let mut next_depth_nodes = 0usize;
 
         if config.include_start {
-            if let Some(node) = self.nodes.get(&start) {
-                result_nodes.push(node.clone());
+            if let Some(node) = self.node(&start) {
+                result_nodes.push(node);
             }
             visited.insert(start);
         }
@@ -274,7 +528,10 @@ impl Graph for MemoryGraph {
                 break;
             }
 
-            let neighbors = self.neighbors(current_id, config.direction).await?;
+            let (neighbors, limited) = self.traversal_neighbors(current_id, &config);
+            if limited {
+                supernodes.push(current_id);
+            }
 
             for neighbor in neighbors {
                 let id = neighbor.id;
@@ -292,20 +549,22 @@ impl Graph for MemoryGraph {
             edges: Vec::new(),
             depth,
             truncated: false,
+            supernodes,
         })
     }
 
-    async fn dfs(&self, start: Uuid, config: TraversalConfig) -> GraphResult<TraversalResult> {
+    fn dfs(&self, start: Uuid, config: TraversalConfig) -> GraphResult<TraversalResult> {
         if !self.nodes.contains_key(&start) {
             return Err(GraphError::NodeNotFound(start));
         }
 
         let mut visited = std::collections::HashSet::new();
         let mut result_nodes = Vec::new();
+        let mut supernodes = Vec::new();
 
         if config.include_start {
-            if let Some(node) = self.nodes.get(&start) {
-                result_nodes.push(node.clone());
+            if let Some(node) = self.node(&start) {
+                result_nodes.push(node);
             }
             visited.insert(start);
         }
@@ -319,7 +578,10 @@ impl Graph for MemoryGraph {
                 continue;
             }
 
-            let neighbors = self.neighbors(current_id, config.direction).await?;
+            let (neighbors, limited) = self.traversal_neighbors(current_id, &config);
+            if limited {
+                supernodes.push(current_id);
+            }
 
             // Push neighbors in reverse order to process them in order
             for neighbor in neighbors.into_iter().rev() {
@@ -338,18 +600,19 @@ impl Graph for MemoryGraph {
             edges: Vec::new(),
             depth: calc_depth,
             truncated: false,
+            supernodes,
         })
     }
 
-    async fn shortest_path(&self, from: Uuid, to: Uuid, max_depth: usize) -> GraphResult<Option<Vec<Node>>> {
+    fn shortest_path(&self, from: Uuid, to: Uuid, max_depth: usize) -> Option<Vec<Node>> {
         if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
-            return Ok(None);
+            return None;
         }
 
-        let mut visited: std::collections::HashMap<Uuid, (Uuid, Option<Node>)> = std::collections::HashMap::new();
+        let mut visited: HashMap<Uuid, (Uuid, Option<Node>)> = HashMap::new();
         let mut queue = std::collections::VecDeque::new();
 
-        visited.insert(from, (Uuid::nil(), self.nodes.get(&from).cloned()));
+        visited.insert(from, (Uuid::nil(), self.node(&from)));
         queue.push_back(from);
 
         while let Some(current) = queue.pop_front() {
@@ -372,48 +635,57 @@ impl Graph for MemoryGraph {
                         break;
                     }
                 }
-                if let Some(start_node) = self.nodes.get(&from) {
-                    path.push(start_node.clone());
+                if let Some(start_node) = self.node(&from) {
+                    path.push(start_node);
                 }
                 path.reverse();
-                return Ok(Some(path));
+                return Some(path);
             }
 
-            if let Some(edges) = self.edges.get(&current) {
-                for edge in edges {
-                    if let std::collections::hash_map::Entry::Vacant(e) = visited.entry(edge.target) {
-                        e.insert((current, self.nodes.get(&edge.target).cloned()));
-                        queue.push_back(edge.target);
-                    }
+            for edge in self.outgoing(&current) {
+                if let std::collections::hash_map::Entry::Vacant(e) = visited.entry(edge.target) {
+                    e.insert((current, self.node(&edge.target)));
+                    queue.push_back(edge.target);
                 }
             }
         }
 
-        Ok(None)
-    }
-
-    async fn node_exists(&self, id: Uuid) -> GraphResult<bool> {
-        Ok(self.nodes.contains_key(&id))
+        None
     }
 
-    async fn get_node(&self, id: Uuid) -> GraphResult<Option<Node>> {
-        Ok(self.nodes.get(&id).cloned())
+    fn count_edges(&self) -> usize {
+        self.edges.values().map(|v| v.len()).sum::<usize>()
+            + self.outgoing_overflow.values().map(|o| o.edges.len()).sum::<usize>()
     }
 
-    async fn count_nodes(&self) -> GraphResult<usize> {
-        Ok(self.nodes.len())
-    }
-
-    async fn count_edges(&self) -> GraphResult<usize> {
-        Ok(self.edges.values().map(|v| v.len()).sum())
+    fn degree_stats(&self) -> &DegreeStats {
+        self.stats.get_or_init(|| {
+            let mut stats = DegreeStats {
+                node_count: self.nodes.len(),
+                ..Default::default()
+            };
+            for id in self.edges.keys() {
+                let mut degree = 0;
+                for edge in self.outgoing(id) {
+                    degree += 1;
+                    *stats.relation_counts.entry(edge.relation.clone()).or_default() += 1;
+                }
+                stats.edge_count += degree;
+                stats.max_out_degree = stats.max_out_degree.max(degree);
+            }
+            stats.max_in_degree = self
+                .incoming
+                .iter()
+                .map(|(id, edges)| {
+                    edges.len() + self.incoming_overflow.get(id).map_or(0, |o| o.edges.len())
+                })
+                .max()
+                .unwrap_or(0);
+            stats
+        })
     }
-}
 
-impl MemoryGraph {
-    fn depth_of(
-        visited: &std::collections::HashMap<Uuid, (Uuid, Option<Node>)>,
-        id: Uuid,
-    ) -> usize {
+    fn depth_of(visited: &HashMap<Uuid, (Uuid, Option<Node>)>, id: Uuid) -> usize {
         let mut depth = 0;
         let mut current = id;
         while current != Uuid::nil() {
@@ -428,6 +700,294 @@ impl MemoryGraph {
     }
 }
 
+/// Move edges past `max_degree` from adjacency lists into overflow, and
+/// overflowed edges back while there is room, keeping insertion order.
+fn rebundle(
+    adjacency: &mut HashMap<Uuid, Arc<Vec<Edge>>>,
+    overflow: &mut HashMap<Uuid, Arc<Overflow>>,
+    max_degree: usize,
+) {
+    for (id, bundled) in std::mem::take(overflow) {
+        Arc::make_mut(adjacency.entry(id).or_default()).extend(unshare(bundled).edges);
+    }
+    for (id, edges) in adjacency.iter_mut() {
+        if edges.len() > max_degree {
+            let mut bundled = Overflow::default();
+            for edge in Arc::make_mut(edges).drain(max_degree..) {
+                bundled.push(edge);
+            }
+            overflow.insert(*id, Arc::new(bundled));
+        }
+    }
+}
+
+/// Drop the edges of `node` to or from `removed`, moving overflowed edges
+/// back while there is room.
+fn detach(
+    adjacency: &mut HashMap<Uuid, Arc<Vec<Edge>>>,
+    overflow: &mut HashMap<Uuid, Arc<Overflow>>,
+    node: Uuid,
+    removed: Uuid,
+    max_degree: usize,
+) {
+    let touches = |edge: &Edge| edge.source == removed || edge.target == removed;
+    let Some(edges) = adjacency.get_mut(&node) else {
+        return;
+    };
+    let edges = Arc::make_mut(edges);
+    edges.retain(|edge| !touches(edge));
+    if let Some(bundled) = overflow.remove(&node) {
+        let mut rest = unshare(bundled)
+            .edges
+            .into_iter()
+            .filter(|edge| !touches(edge));
+        edges.extend(rest.by_ref().take(max_degree.saturating_sub(edges.len())));
+        let mut remaining = Overflow::default();
+        for edge in rest {
+            remaining.push(edge);
+        }
+        if !remaining.edges.is_empty() {
+            overflow.insert(node, Arc::new(remaining));
+        }
+    }
+}
+
+/// Take the value out of an `Arc`, copying it if a snapshot shares it.
+fn unshare<T: Clone>(value: Arc<T>) -> T {
+    Arc::try_unwrap(value).unwrap_or_else(|shared| T::clone(&shared))
+}
+
+/// In-memory graph implementation.
+///
+/// The graph is copy-on-write: [`MemoryGraph::snapshot`] is O(1) and the
+/// snapshot stays consistent while the graph keeps being modified. The first
+/// write after a snapshot copies the node and edge maps (but not the nodes
+/// themselves); later writes are in place until the next snapshot.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryGraph {
+    state: Arc<GraphState>,
+}
+
+impl MemoryGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, node: Node) -> GraphResult<()> {
+        if self.state.nodes.contains_key(&node.id) {
+            return Err(GraphError::Custom(format!("Node {} already exists", node.id)));
+        }
+        Arc::make_mut(&mut self.state).insert_node(node);
+        Ok(())
+    }
+
+    /// Replace an existing node, keeping its edges.
+    pub fn update_node(&mut self, node: Node) -> GraphResult<()> {
+        if !self.state.nodes.contains_key(&node.id) {
+            return Err(GraphError::NodeNotFound(node.id));
+        }
+        Arc::make_mut(&mut self.state).insert_node(node);
+        Ok(())
+    }
+
+    pub fn add_edge(&mut self, edge: Edge) -> GraphResult<()> {
+        Arc::make_mut(&mut self.state).insert_edge(edge);
+        Ok(())
+    }
+
+    /// Remove a node with every edge to or from it; returns the removed
+    /// edges.
+    pub fn remove_node(&mut self, id: Uuid) -> GraphResult<Vec<Edge>> {
+        if !self.state.nodes.contains_key(&id) {
+            return Err(GraphError::NodeNotFound(id));
+        }
+        let removed = Arc::make_mut(&mut self.state).remove_node(id);
+        Ok(removed.unwrap_or_default())
+    }
+
+    /// Limit the edges per node and direction that traversals expand by
+    /// default, or remove the limit with `None`.
+    ///
+    /// Edges past the limit are kept in an [`EdgeBundle`] and still returned
+    /// by [`Graph::edges`]; traversals expand such supernodes according to
+    /// [`TraversalConfig::supernode_strategy`]. Existing edges are rebundled.
+    pub fn set_max_degree(&mut self, max_degree: Option<usize>) {
+        Arc::make_mut(&mut self.state).set_max_degree(max_degree);
+    }
+
+    /// The maximum degree set with [`Self::set_max_degree`].
+    pub fn max_degree(&self) -> Option<usize> {
+        self.state.max_degree
+    }
+
+    pub fn from_parts(nodes: Vec<Node>, edges: Vec<Edge>) -> GraphResult<Self> {
+        let mut state = GraphState::default();
+
+        for node in nodes {
+            state.insert_node(node);
+        }
+
+        for edge in edges {
+            state.insert_edge(edge);
+        }
+
+        Ok(Self {
+            state: Arc::new(state),
+        })
+    }
+
+    /// Find the bindings of a pattern; see [`Pattern`].
+    pub fn match_pattern(&self, pattern: &Pattern, config: &MatchConfig) -> PatternMatches {
+        pattern::find_matches(self.state.as_ref(), pattern, config)
+    }
+
+    /// Take a read-only, point-in-time snapshot of the graph.
+    ///
+    /// This never blocks or copies; callers holding the graph behind a lock
+    /// can release the lock immediately and run long reads on the snapshot.
+    pub fn snapshot(&self) -> GraphSnapshot {
+        GraphSnapshot {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+/// A consistent, read-only view of a [`MemoryGraph`] at a point in time.
+///
+/// Later changes to the graph are not visible through the snapshot.
+#[derive(Debug, Clone)]
+pub struct GraphSnapshot {
+    state: Arc<GraphState>,
+}
+
+impl GraphSnapshot {
+    /// Iterate over all nodes in the snapshot.
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.state.nodes.values().map(|node| node.as_ref())
+    }
+
+    /// Iterate over all edges in the snapshot.
+    pub fn all_edges(&self) -> impl Iterator<Item = &Edge> {
+        self.state
+            .edges
+            .values()
+            .flat_map(|edges| edges.iter())
+            .chain(self.state.outgoing_overflow.values().flat_map(|o| &o.edges))
+    }
+
+    /// Find the bindings of a pattern; see [`Pattern`].
+    pub fn match_pattern(&self, pattern: &Pattern, config: &MatchConfig) -> PatternMatches {
+        pattern::find_matches(self.state.as_ref(), pattern, config)
+    }
+}
+
+impl PatternSource for GraphState {
+    fn node(&self, id: &Uuid) -> Option<&Node> {
+        self.nodes.get(id).map(|node| node.as_ref())
+    }
+
+    fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn node_ids(&self) -> Vec<Uuid> {
+        let mut ids: Vec<_> = self.nodes.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    fn nodes_of_type(&self, node_type: NodeType) -> Vec<Uuid> {
+        let mut ids: Vec<_> = self.nodes_by_type.get(&node_type).into_iter().flatten().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    fn type_count(&self, node_type: NodeType) -> usize {
+        self.nodes_by_type.get(&node_type).map_or(0, HashSet::len)
+    }
+
+    fn outgoing_edges(&self, id: &Uuid) -> Vec<&Edge> {
+        self.outgoing(id).collect()
+    }
+
+    fn incoming_edges(&self, id: &Uuid) -> Vec<&Edge> {
+        self.incoming
+            .get(id)
+            .into_iter()
+            .flat_map(|edges| edges.iter())
+            .chain(self.incoming_overflow.get(id).into_iter().flat_map(|o| &o.edges))
+            .collect()
+    }
+}
+
+/// Implement [`Graph`] by delegating to the shared [`GraphState`].
+macro_rules! impl_graph_for_state {
+    ($ty:ty) => {
+        #[async_trait]
+        impl Graph for $ty {
+            async fn edges(&self, id: Uuid, direction: TraverseDirection) -> GraphResult<Vec<Edge>> {
+                Ok(self.state.edges(id, direction))
+            }
+
+            async fn neighbors(&self, id: Uuid, direction: TraverseDirection) -> GraphResult<Vec<Node>> {
+                Ok(self.state.neighbors(id, direction))
+            }
+
+            async fn bfs(&self, start: Uuid, config: TraversalConfig) -> GraphResult<TraversalResult> {
+                self.state.bfs(start, config)
+            }
+
+            async fn dfs(&self, start: Uuid, config: TraversalConfig) -> GraphResult<TraversalResult> {
+                self.state.dfs(start, config)
+            }
+
+            async fn shortest_path(
+                &self,
+                from: Uuid,
+                to: Uuid,
+                max_depth: usize,
+            ) -> GraphResult<Option<Vec<Node>>> {
+                Ok(self.state.shortest_path(from, to, max_depth))
+            }
+
+            async fn node_exists(&self, id: Uuid) -> GraphResult<bool> {
+                Ok(self.state.nodes.contains_key(&id))
+            }
+
+            async fn get_node(&self, id: Uuid) -> GraphResult<Option<Node>> {
+                Ok(self.state.node(&id))
+            }
+
+            async fn count_nodes(&self) -> GraphResult<usize> {
+                Ok(self.state.nodes.len())
+            }
+
+            async fn count_edges(&self) -> GraphResult<usize> {
+                Ok(self.state.count_edges())
+            }
+
+            async fn degree_stats(&self) -> GraphResult<DegreeStats> {
+                Ok(self.state.degree_stats().clone())
+            }
+
+            async fn traversal_edges(&self, id: Uuid, config: &TraversalConfig) -> GraphResult<Vec<Edge>> {
+                Ok(self.state.expansion_edges(id, config).0)
+            }
+
+            async fn edge_bundle(
+                &self,
+                id: Uuid,
+                direction: TraverseDirection,
+            ) -> GraphResult<Option<EdgeBundle>> {
+                Ok(self.state.edge_bundle(id, direction))
+            }
+        }
+    };
+}
+
+impl_graph_for_state!(MemoryGraph);
+impl_graph_for_state!(GraphSnapshot);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -465,4 +1025,257 @@ mod tests {
         assert!(path.is_some());
         assert_eq!(path.unwrap().len(), 3);
     }
+
+    #[tokio::test]
+    async fn test_snapshot_is_isolated_from_writes() {
+        let mut graph = MemoryGraph::new();
+
+        let n1 = Node::new("A", NodeType::Entity);
+        let n2 = Node::new("B", NodeType::Entity);
+        graph.add_node(n1.clone()).unwrap();
+        graph.add_node(n2.clone()).unwrap();
+
+        let snapshot = graph.snapshot();
+
+        let n3 = Node::new("C", NodeType::Entity);
+        graph.add_node(n3.clone()).unwrap();
+        graph.add_edge(Edge::new(n1.id, n2.id, Relation::Causes)).unwrap();
+
+        assert_eq!(snapshot.count_nodes().await.unwrap(), 2);
+        assert_eq!(snapshot.count_edges().await.unwrap(), 0);
+        assert!(!snapshot.node_exists(n3.id).await.unwrap());
+        assert_eq!(snapshot.nodes().count(), 2);
+
+        assert_eq!(graph.count_nodes().await.unwrap(), 3);
+        assert_eq!(graph.count_edges().await.unwrap(), 1);
+        assert_eq!(graph.snapshot().all_edges().count(), 1);
+
+        // Writes copy only the edge lists they change
+        let snapshot = graph.snapshot();
+        graph.add_edge(Edge::new(n3.id, n2.id, Relation::Causes)).unwrap();
+        assert!(Arc::ptr_eq(&snapshot.state.edges[&n1.id], &graph.state.edges[&n1.id]));
+        assert_eq!(snapshot.state.incoming[&n2.id].len(), 1);
+        assert_eq!(graph.state.incoming[&n2.id].len(), 2);
+
+        graph.remove_node(n2.id).unwrap();
+        assert_eq!(snapshot.all_edges().count(), 1);
+        assert_eq!(graph.count_edges().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_update_node_keeps_edges() {
+        let mut graph = MemoryGraph::new();
+        let n1 = Node::new("A", NodeType::Entity);
+        let n2 = Node::new("B", NodeType::Entity);
+        graph.add_node(n1.clone()).unwrap();
+        graph.add_node(n2.clone()).unwrap();
+        graph.add_edge(Edge::new(n1.id, n2.id, Relation::Causes)).unwrap();
+
+        let mut updated = n2.clone();
+        updated.content = "B, revised".into();
+        updated.node_type = NodeType::Concept;
+        graph.update_node(updated).unwrap();
+
+        let neighbors = graph.neighbors(n1.id, TraverseDirection::Forward).await.unwrap();
+        assert_eq!(neighbors.len(), 1);
+        assert_eq!(neighbors[0].content(), "B, revised");
+        assert_eq!(neighbors[0].node_type, NodeType::Concept);
+
+        assert!(matches!(
+            graph.update_node(Node::new("missing", NodeType::Entity)),
+            Err(GraphError::NodeNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_remove_node_drops_its_edges() {
+        let mut graph = MemoryGraph::new();
+        graph.set_max_degree(Some(1));
+        let nodes: Vec<Node> = ["A", "B", "C"]
+            .into_iter()
+            .map(|content| Node::new(content, NodeType::Entity))
+            .collect();
+        for node in &nodes {
+            graph.add_node(node.clone()).unwrap();
+        }
+        let (a, b, c) = (nodes[0].id, nodes[1].id, nodes[2].id);
+        graph.add_edge(Edge::new(a, b, Relation::Causes)).unwrap();
+        graph.add_edge(Edge::new(a, c, Relation::Causes)).unwrap();
+        graph.add_edge(Edge::new(c, b, Relation::Causes)).unwrap();
+        graph.add_edge(Edge::new(b, b, Relation::IsA)).unwrap();
+        let snapshot = graph.snapshot();
+
+        let removed = graph.remove_node(b).unwrap();
+        assert_eq!(removed.len(), 3);
+        assert!(!graph.node_exists(b).await.unwrap());
+        assert_eq!(graph.count_edges().await.unwrap(), 1);
+        // The overflowed edge of A moves back into its adjacency list
+        let bundle = graph
+            .edge_bundle(a, TraverseDirection::Forward)
+            .await
+            .unwrap();
+        assert!(bundle.is_none());
+        let targets = graph
+            .neighbors(a, TraverseDirection::Forward)
+            .await
+            .unwrap();
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].id, c);
+        let edges = graph.edges(c, TraverseDirection::Both).await.unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(graph.degree_stats().await.unwrap().node_count, 2);
+        assert!(matches!(
+            graph.remove_node(b),
+            Err(GraphError::NodeNotFound(_))
+        ));
+
+        // Snapshots taken before still see the node
+        assert_eq!(snapshot.count_edges().await.unwrap(), 4);
+        assert!(snapshot.node_exists(b).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_backward_traversal_follows_inverses() {
+        let mut graph = MemoryGraph::new();
+        let car = Node::new("Car", NodeType::Entity);
+        let wheel = Node::new("Wheel", NodeType::Entity);
+        let tesla = Node::new("Tesla", NodeType::Entity);
+        for node in [&car, &wheel, &tesla] {
+            graph.add_node(node.clone()).unwrap();
+        }
+        graph.add_edge(Edge::new(wheel.id, car.id, Relation::IsPartOf)).unwrap();
+        graph.add_edge(Edge::new(tesla.id, car.id, Relation::IsA)).unwrap();
+
+        // Filtering on the inverse label finds the car's parts only
+        let config = TraversalConfig::with_depth(1)
+            .with_direction(TraverseDirection::Backward)
+            .with_relation(Relation::IsPartOf.inverse())
+            .with_follow_inverses(true);
+        let result = graph.bfs(car.id, config.clone()).await.unwrap();
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id, wheel.id);
+
+        let edges = graph.traversal_edges(car.id, &config).await.unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!((edges[0].source, edges[0].target), (car.id, wheel.id));
+        assert_eq!(edges[0].relation.to_string(), "has_part");
+
+        // Without inverses the filter matches the stored relation
+        let raw = config.with_follow_inverses(false);
+        assert!(graph.bfs(car.id, raw.clone()).await.unwrap().nodes.is_empty());
+        let raw = raw.with_relation(Relation::IsA);
+        let result = graph.bfs(car.id, raw).await.unwrap();
+        assert_eq!(result.nodes[0].id, tesla.id);
+    }
+
+    #[tokio::test]
+    async fn test_traversal_skips_low_confidence_edges() {
+        let mut graph = MemoryGraph::new();
+        let chunk = Node::new("Chunk", NodeType::RawChunk);
+        let solid = Node::new("Solid", NodeType::Entity);
+        let shaky = Node::new("Shaky", NodeType::Entity);
+        for node in [&chunk, &solid, &shaky] {
+            graph.add_node(node.clone()).unwrap();
+        }
+        graph.add_edge(Edge::new(chunk.id, solid.id, Relation::SimilarTo)).unwrap();
+        graph
+            .add_edge(Edge::new(chunk.id, shaky.id, Relation::SimilarTo).with_confidence(0.4))
+            .unwrap();
+
+        let all = graph.bfs(chunk.id, TraversalConfig::with_depth(1)).await.unwrap();
+        assert_eq!(all.nodes.len(), 2);
+
+        let config = TraversalConfig::with_depth(1).with_min_confidence(0.5);
+        let result = graph.bfs(chunk.id, config).await.unwrap();
+        assert_eq!(result.nodes.len(), 1);
+        assert_eq!(result.nodes[0].id, solid.id);
+    }
+
+    #[tokio::test]
+    async fn test_degree_stats_refresh_after_writes() {
+        let mut graph = MemoryGraph::new();
+        let hub = Node::new("Hub", NodeType::Entity);
+        graph.add_node(hub.clone()).unwrap();
+
+        let mut spokes = Vec::new();
+        for i in 0..4 {
+            let node = Node::new(format!("Spoke {}", i), NodeType::Entity);
+            graph.add_node(node.clone()).unwrap();
+            graph.add_edge(Edge::new(hub.id, node.id, Relation::SimilarTo)).unwrap();
+            spokes.push(node);
+        }
+
+        let stats = graph.degree_stats().await.unwrap();
+        assert_eq!((stats.node_count, stats.edge_count), (5, 4));
+        assert_eq!((stats.max_out_degree, stats.max_in_degree), (4, 1));
+
+        let snapshot = graph.snapshot();
+        graph.add_edge(Edge::new(spokes[0].id, hub.id, Relation::Causes)).unwrap();
+        let stats = graph.degree_stats().await.unwrap();
+        assert_eq!(stats.edge_count, 5);
+        assert_eq!(stats.relation_counts.get(&Relation::Causes), Some(&1));
+        assert_eq!(snapshot.degree_stats().await.unwrap().edge_count, 4);
+
+        let estimate = graph
+            .estimate_traversal(hub.id, &TraversalConfig::with_depth(1))
+            .await
+            .unwrap();
+        assert_eq!(estimate.estimated_nodes, 4);
+        assert!(graph
+            .estimate_traversal(Uuid::new_v4(), &TraversalConfig::default())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_supernode_expansion() {
+        let mut graph = MemoryGraph::new();
+        let rust = Node::new("Rust", NodeType::Entity);
+        let start = Node::new("Borrow checker", NodeType::Concept);
+        graph.add_node(rust.clone()).unwrap();
+        graph.add_node(start.clone()).unwrap();
+        graph.add_edge(Edge::new(start.id, rust.id, Relation::IsPartOf)).unwrap();
+        for i in 0..5 {
+            let crate_node = Node::new(format!("Crate {}", i), NodeType::Entity);
+            graph.add_node(crate_node.clone()).unwrap();
+            graph.add_edge(Edge::new(rust.id, crate_node.id, Relation::SimilarTo)).unwrap();
+        }
+
+        // Setting the limit rebundles existing edges
+        graph.set_max_degree(Some(2));
+        assert_eq!(graph.count_edges().await.unwrap(), 6);
+        assert_eq!(graph.edges(rust.id, TraverseDirection::Forward).await.unwrap().len(), 5);
+        let bundle = graph.edge_bundle(rust.id, TraverseDirection::Both).await.unwrap().unwrap();
+        assert_eq!(bundle.edge_count, 3);
+        assert_eq!(bundle.relation_counts.get(&Relation::SimilarTo), Some(&3));
+        assert!(graph.edge_bundle(start.id, TraverseDirection::Both).await.unwrap().is_none());
+
+        let config = TraversalConfig::with_depth(2);
+        let sampled = graph.bfs(start.id, config.clone()).await.unwrap();
+        assert_eq!(sampled.nodes.len(), 3);
+        assert_eq!(sampled.supernodes, vec![rust.id]);
+
+        let skip = config.clone().with_supernode_strategy(SupernodeStrategy::Skip);
+        let skipped = graph.dfs(start.id, skip.clone()).await.unwrap();
+        assert_eq!(skipped.nodes.len(), 1);
+        assert_eq!(skipped.supernodes, vec![rust.id]);
+        assert!(graph.traversal_edges(rust.id, &skip).await.unwrap().is_empty());
+
+        let expand = config.with_supernode_strategy(SupernodeStrategy::Expand);
+        let expanded = graph.bfs(start.id, expand).await.unwrap();
+        assert_eq!(expanded.nodes.len(), 6);
+        assert!(expanded.supernodes.is_empty());
+
+        // New edges past the limit land in the bundle
+        let late = Node::new("Crate 5", NodeType::Entity);
+        graph.add_node(late.clone()).unwrap();
+        graph.add_edge(Edge::new(rust.id, late.id, Relation::Causes)).unwrap();
+        let bundle = graph.edge_bundle(rust.id, TraverseDirection::Forward).await.unwrap().unwrap();
+        assert_eq!(bundle.edge_count, 4);
+        assert_eq!(graph.degree_stats().await.unwrap().max_out_degree, 6);
+
+        graph.set_max_degree(None);
+        assert!(graph.edge_bundle(rust.id, TraverseDirection::Both).await.unwrap().is_none());
+        assert!(graph.bfs(start.id, TraversalConfig::with_depth(2)).await.unwrap().supernodes.is_empty());
+    }
 }
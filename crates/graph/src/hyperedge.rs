@@ -0,0 +1,156 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Traversal of reified facts (see [`synton_core::Hyperedge`]).
+
+use std::collections::HashSet;
+
+use crate::{Graph, GraphResult, TraverseDirection};
+use synton_core::{normalize_role, Hyperedge, Node, NodeType, RoleBinding};
+use uuid::Uuid;
+
+/// Load a fact node with its participants; `None` if there is no such
+/// node or it is not a fact.
+pub async fn load_hyperedge<G: Graph + ?Sized>(
+    graph: &G,
+    fact: Uuid,
+) -> GraphResult<Option<Hyperedge>> {
+    let Some(node) = graph.get_node(fact).await? else {
+        return Ok(None);
+    };
+    if node.node_type != NodeType::Fact {
+        return Ok(None);
+    }
+    let edges = graph.edges(fact, TraverseDirection::Forward).await?;
+    Ok(Some(Hyperedge::from_edges(node, &edges)))
+}
+
+/// Facts `participant` takes part in, in any role or in `role` only.
+/// An invalid role name matches nothing.
+pub async fn facts_with_role<G: Graph + ?Sized>(
+    graph: &G,
+    participant: Uuid,
+    role: Option<&str>,
+) -> GraphResult<Vec<Node>> {
+    let role = match role.map(normalize_role) {
+        Some(Ok(role)) => Some(role),
+        Some(Err(_)) => return Ok(Vec::new()),
+        None => None,
+    };
+
+    let mut seen = HashSet::new();
+    let mut facts = Vec::new();
+    for edge in graph
+        .edges(participant, TraverseDirection::Backward)
+        .await?
+    {
+        let plays_role = edge
+            .relation
+            .role_name()
+            .is_some_and(|name| role.as_deref().map_or(true, |role| role == name));
+        if !edge.is_active() || !plays_role || !seen.insert(edge.source) {
+            continue;
+        }
+        if let Some(fact) = graph.get_node(edge.source).await? {
+            if fact.node_type == NodeType::Fact {
+                facts.push(fact);
+            }
+        }
+    }
+    Ok(facts)
+}
+
+/// Facts with every one of `bindings`, e.g. all transfers with Alice as
+/// agent and Bob as recipient. No bindings match nothing.
+pub async fn find_facts<G: Graph + ?Sized>(
+    graph: &G,
+    bindings: &[RoleBinding],
+) -> GraphResult<Vec<Hyperedge>> {
+    let Some(first) = bindings.first() else {
+        return Ok(Vec::new());
+    };
+
+    let mut found = Vec::new();
+    for fact in facts_with_role(graph, first.participant, Some(&first.role)).await? {
+        let edges = graph.edges(fact.id, TraverseDirection::Forward).await?;
+        let hyperedge = Hyperedge::from_edges(fact, &edges);
+        if hyperedge.binds_all(bindings) {
+            found.push(hyperedge);
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryGraph;
+    use synton_core::{Edge, Relation};
+
+    #[tokio::test]
+    async fn test_facts_by_role() {
+        let mut graph = MemoryGraph::new();
+        let alice = Node::new("Alice", NodeType::Entity);
+        let bob = Node::new("Bob", NodeType::Entity);
+        let project = Node::new("Project X", NodeType::Entity);
+        for node in [&alice, &bob, &project] {
+            graph.add_node(node.clone()).unwrap();
+        }
+
+        let transfer = Hyperedge::new("Alice transferred project X to Bob", "transferred")
+            .with_role("agent", alice.id)
+            .unwrap()
+            .with_role("recipient", bob.id)
+            .unwrap()
+            .with_role("theme", project.id)
+            .unwrap();
+        let returned = Hyperedge::new("Bob returned project X to Alice", "transferred")
+            .with_role("agent", bob.id)
+            .unwrap()
+            .with_role("recipient", alice.id)
+            .unwrap();
+        for fact in [&transfer, &returned] {
+            graph.add_node(fact.fact.clone()).unwrap();
+            for edge in fact.edges() {
+                graph.add_edge(edge).unwrap();
+            }
+        }
+        // Plain edges into a participant are not roles
+        graph
+            .add_edge(Edge::new(project.id, alice.id, Relation::BelongsTo))
+            .unwrap();
+
+        let loaded = load_hyperedge(&graph, transfer.fact.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.predicate(), Some("transferred"));
+        assert_eq!(loaded.participants("theme"), vec![project.id]);
+        assert!(load_hyperedge(&graph, alice.id).await.unwrap().is_none());
+
+        let ids = |facts: Vec<Node>| facts.into_iter().map(|f| f.id).collect::<HashSet<_>>();
+        let all = facts_with_role(&graph, alice.id, None).await.unwrap();
+        assert_eq!(
+            ids(all),
+            HashSet::from([transfer.fact.id, returned.fact.id])
+        );
+        let received = facts_with_role(&graph, alice.id, Some("Recipient"))
+            .await
+            .unwrap();
+        assert_eq!(ids(received), HashSet::from([returned.fact.id]));
+        assert!(facts_with_role(&graph, alice.id, Some("no role"))
+            .await
+            .unwrap()
+            .is_empty());
+
+        let bindings = [
+            RoleBinding::new("agent", alice.id).unwrap(),
+            RoleBinding::new("recipient", bob.id).unwrap(),
+        ];
+        let found = find_facts(&graph, &bindings).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].fact.id, transfer.fact.id);
+        assert!(find_facts(&graph, &[]).await.unwrap().is_empty());
+    }
+}
@@ -5,14 +5,24 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod cost;
 mod error;
 mod graph;
+mod hyperedge;
 mod path;
+mod pattern;
+mod supernode;
 mod traversal;
 
+pub use cost::{estimate_traversal, CostEstimate, DegreeStats};
 pub use error::{GraphError, GraphResult};
-pub use graph::{Graph, MemoryGraph, TraverseDirection, TraversalConfig, TraversalResult};
-pub use path::GraphPaths;
+pub use graph::{
+    Graph, GraphSnapshot, MemoryGraph, TraverseDirection, TraversalConfig, TraversalResult,
+};
+pub use hyperedge::{facts_with_role, find_facts, load_hyperedge};
+pub use path::{find_reasoning_paths, GraphPaths};
+pub use pattern::{MatchConfig, Pattern, PatternEdge, PatternMatch, PatternMatches, PatternNode};
+pub use supernode::{EdgeBundle, SupernodeStrategy};
 
 /// Re-exports commonly used types
 pub mod prelude {
@@ -32,21 +32,7 @@ pub trait GraphPaths: Graph {
                 }
             }
 
-            let path_type = classify_path_type(&edges);
-            let confidence = if edges.is_empty() {
-                1.0
-            } else {
-                edges.iter().map(|e| e.weight).fold(1.0, |a, w| a * w)
-            };
-            let explanation = generate_explanation(&nodes, &edges);
-
-            Ok(Some(ReasoningPath {
-                nodes,
-                edges,
-                confidence,
-                explanation,
-                path_type,
-            }))
+            Ok(Some(build_reasoning_path(nodes, edges)))
         } else {
             Ok(None)
         }
@@ -95,6 +81,101 @@ pub trait GraphPaths: Graph {
     }
 }
 
+/// Upper bound on partial paths expanded by [`find_reasoning_paths`].
+const MAX_PATH_EXPANSIONS: usize = 10_000;
+
+/// Find the best multi-hop reasoning chains from `from` to `to`.
+///
+/// Enumerates simple paths along outgoing edges of at most `max_hops` hops
+/// and returns up to `limit` of them, ranked by confidence and then by
+/// length. Confidence propagates multiplicatively: each edge contributes its
+/// weight times its extraction confidence, so a chain through a 0.7
+/// confidence extraction can never score above 0.7. Parallel edges with different
+/// relations yield distinct paths. Enumeration stops after a fixed number of
+/// expansions, so dense graphs return the best paths found within budget.
+pub async fn find_reasoning_paths<G: Graph + ?Sized>(
+    graph: &G,
+    from: Uuid,
+    to: Uuid,
+    max_hops: usize,
+    limit: usize,
+) -> GraphResult<Vec<ReasoningPath>> {
+    if limit == 0 || max_hops == 0 || from == to {
+        return Ok(Vec::new());
+    }
+
+    let mut found: Vec<Vec<Edge>> = Vec::new();
+    let mut stack: Vec<(Uuid, Vec<Edge>)> = vec![(from, Vec::new())];
+    let mut expansions = 0;
+
+    while let Some((current, path)) = stack.pop() {
+        expansions += 1;
+        if expansions > MAX_PATH_EXPANSIONS {
+            break;
+        }
+
+        for edge in graph.edges(current, TraverseDirection::Forward).await? {
+            let next = edge.target;
+            if next == from || path.iter().any(|e| e.target == next) {
+                continue;
+            }
+
+            let mut extended = path.clone();
+            extended.push(edge);
+            if next == to {
+                found.push(extended);
+            } else if extended.len() < max_hops {
+                stack.push((next, extended));
+            }
+        }
+    }
+
+    found.sort_by(|a, b| {
+        path_confidence(b)
+            .partial_cmp(&path_confidence(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.len().cmp(&b.len()))
+    });
+
+    let mut paths = Vec::new();
+    'paths: for edges in found {
+        if paths.len() >= limit {
+            break;
+        }
+
+        let mut nodes = Vec::with_capacity(edges.len() + 1);
+        for id in std::iter::once(from).chain(edges.iter().map(|e| e.target)) {
+            match graph.get_node(id).await? {
+                Some(node) => nodes.push(node),
+                None => continue 'paths,
+            }
+        }
+        paths.push(build_reasoning_path(nodes, edges));
+    }
+
+    Ok(paths)
+}
+
+/// Assemble a reasoning path, deriving its type, confidence and explanation.
+fn build_reasoning_path(nodes: Vec<Node>, edges: Vec<Edge>) -> ReasoningPath {
+    let path_type = classify_path_type(&edges);
+    let confidence = path_confidence(&edges);
+    let explanation = generate_explanation(&nodes, &edges);
+
+    ReasoningPath {
+        nodes,
+        edges,
+        confidence,
+        explanation,
+        path_type,
+    }
+}
+
+/// Product of the edge strengths (weight times confidence) along a path.
+fn path_confidence(edges: &[Edge]) -> f32 {
+    edges.iter().map(Edge::strength).product()
+}
+
 /// A subgraph containing nodes and their connecting edges.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SubGraph {
@@ -188,7 +269,11 @@ fn truncate(s: &str, max_len: usize) -> &str {
     if s.len() <= max_len {
         s
     } else {
-        &s[..max_len.saturating_sub(3)]
+        let mut end = max_len.saturating_sub(3);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        &s[..end]
     }
 }
 
@@ -218,6 +303,79 @@ mod tests {
         assert!(node1.is_some());
     }
 
+    #[tokio::test]
+    async fn test_find_reasoning_paths_ranks_by_confidence() {
+        let mut graph = MemoryGraph::new();
+
+        let a = Node::new("Supply shortage", NodeType::Concept);
+        let b = Node::new("Production delay", NodeType::Concept);
+        let c = Node::new("Quarterly report", NodeType::Concept);
+        let d = Node::new("Analyst note", NodeType::Concept);
+        for node in [&a, &b, &c, &d] {
+            graph.add_node(node.clone()).unwrap();
+        }
+
+        graph.add_edge(Edge::new(a.id, b.id, Relation::Causes)).unwrap();
+        graph.add_edge(Edge::new(b.id, c.id, Relation::IsPartOf)).unwrap();
+        graph
+            .add_edge(Edge::with_weight(a.id, d.id, Relation::SimilarTo, 0.5))
+            .unwrap();
+        graph
+            .add_edge(Edge::with_weight(d.id, c.id, Relation::SimilarTo, 0.5))
+            .unwrap();
+        graph.add_edge(Edge::new(c.id, a.id, Relation::Causes)).unwrap();
+
+        let paths = find_reasoning_paths(&graph, a.id, c.id, 3, 10).await.unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].path_type, PathType::Hybrid);
+        assert_eq!(paths[0].confidence, 1.0);
+        assert_eq!(paths[0].nodes[1].id, b.id);
+        assert_eq!(paths[1].confidence, 0.25);
+        assert_eq!(
+            paths[0].explanation,
+            "Supply shortage causes Production delay, which Production delay is part of Quarterly report"
+        );
+
+        let top = find_reasoning_paths(&graph, a.id, c.id, 3, 1).await.unwrap();
+        assert_eq!(top.len(), 1);
+        assert!(find_reasoning_paths(&graph, a.id, c.id, 1, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reasoning_paths_propagate_edge_confidence() {
+        let mut graph = MemoryGraph::new();
+        let chunk = Node::new("Chunk", NodeType::RawChunk);
+        let extracted = Node::new("Extracted claim", NodeType::Fact);
+        let curated = Node::new("Curated fact", NodeType::Fact);
+        let target = Node::new("Conclusion", NodeType::Concept);
+        for node in [&chunk, &extracted, &curated, &target] {
+            graph.add_node(node.clone()).unwrap();
+        }
+
+        // A full-weight edge from a 0.4 confidence extraction ranks below a
+        // curated edge of weight 0.5
+        graph
+            .add_edge(Edge::new(chunk.id, extracted.id, Relation::Causes).with_confidence(0.4))
+            .unwrap();
+        graph.add_edge(Edge::new(extracted.id, target.id, Relation::Causes)).unwrap();
+        graph
+            .add_edge(Edge::with_weight(chunk.id, curated.id, Relation::Causes, 0.5))
+            .unwrap();
+        graph.add_edge(Edge::new(curated.id, target.id, Relation::Causes)).unwrap();
+
+        let paths = find_reasoning_paths(&graph, chunk.id, target.id, 2, 10).await.unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].nodes[1].id, curated.id);
+        assert_eq!(paths[0].confidence, 0.5);
+        assert_eq!(paths[1].confidence, 0.4);
+    }
+
+    #[test]
+    fn test_truncate_respects_char_boundaries() {
+        assert_eq!(truncate("供应链短缺导致生产延迟", 20), "供应链短缺");
+        assert_eq!(truncate("short", 20), "short");
+    }
+
     #[tokio::test]
     async fn test_subgraph() {
         let mut graph = MemoryGraph::new();
@@ -0,0 +1,624 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Traversal pattern matching (motif queries).
+//!
+//! A pattern is a small graph of typed node variables joined by relations,
+//! written as chains such as
+//! `(a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c)`. Chains separated by
+//! commas share their variables, so branching motifs are written as several
+//! chains: `(a)-[:CAUSES]->(b), (a)-[:CAUSES]->(c)`.
+//!
+//! Matching binds every pattern node to a distinct graph node. It starts
+//! from the most selective node, using the node type index to pick its
+//! candidates, and extends the binding along edges from bound nodes, so
+//! only the neighbourhood of the candidates is explored.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use synton_core::{Edge, Node, NodeType, Relation};
+use uuid::Uuid;
+
+use crate::{GraphError, GraphResult};
+
+/// A node of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternNode {
+    /// Variable name, or `None` for an anonymous node `()`.
+    pub var: Option<String>,
+    /// Required node type (`None` = any type).
+    pub node_type: Option<NodeType>,
+}
+
+/// An edge of a pattern, between two pattern nodes by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternEdge {
+    /// Index of the source node.
+    pub from: usize,
+    /// Index of the target node.
+    pub to: usize,
+    /// Required relation (`None` = any relation).
+    pub relation: Option<Relation>,
+}
+
+/// A parsed graph pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    /// Pattern nodes; a variable used several times is one node.
+    pub nodes: Vec<PatternNode>,
+    /// Pattern edges.
+    pub edges: Vec<PatternEdge>,
+}
+
+impl Pattern {
+    /// Parse a pattern such as `(a:Concept)-[:CAUSES]->(b)<-[]-(c)`.
+    ///
+    /// Node types and relations are case-insensitive; relation names that
+    /// are not standard relations match custom relations.
+    pub fn parse(input: &str) -> GraphResult<Self> {
+        let mut parser = PatternParser {
+            input,
+            pos: 0,
+            pattern: Pattern {
+                nodes: Vec::new(),
+                edges: Vec::new(),
+            },
+        };
+
+        loop {
+            parser.parse_chain()?;
+            parser.skip_whitespace();
+            if parser.eat(",") {
+                continue;
+            }
+            if parser.pos < input.len() {
+                return Err(parser.error("expected ',' or end of pattern"));
+            }
+            break;
+        }
+
+        Ok(parser.pattern)
+    }
+
+    /// Index of the node bound to a variable.
+    pub fn var_index(&self, var: &str) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.var.as_deref() == Some(var))
+    }
+
+    /// Variable names, in order of first use.
+    pub fn vars(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().filter_map(|node| node.var.as_deref())
+    }
+}
+
+struct PatternParser<'a> {
+    input: &'a str,
+    pos: usize,
+    pattern: Pattern,
+}
+
+impl<'a> PatternParser<'a> {
+    fn error(&self, message: &str) -> GraphError {
+        GraphError::InvalidPattern(format!("{} at position {}", message, self.pos))
+    }
+
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> GraphResult<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", token)))
+        }
+    }
+
+    fn identifier(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return None;
+        }
+        let start = self.pos;
+        self.pos += len;
+        Some(&self.input[start..self.pos])
+    }
+
+    /// `node (edge node)*`
+    fn parse_chain(&mut self) -> GraphResult<()> {
+        let mut current = self.parse_node()?;
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if !(rest.starts_with('-') || rest.starts_with('<')) {
+                return Ok(());
+            }
+            let (relation, forward) = self.parse_edge()?;
+            let next = self.parse_node()?;
+            let (from, to) = if forward {
+                (current, next)
+            } else {
+                (next, current)
+            };
+            self.pattern.edges.push(PatternEdge { from, to, relation });
+            current = next;
+        }
+    }
+
+    /// `(var:Type)`, `(var)`, `(:Type)` or `()`; returns the node index.
+    fn parse_node(&mut self) -> GraphResult<usize> {
+        self.expect("(")?;
+        let var = self.identifier().map(str::to_string);
+        let node_type = if self.eat(":") {
+            let name = self
+                .identifier()
+                .ok_or_else(|| self.error("expected node type"))?;
+            let node_type = NodeType::from_str(name).map_err(|e| self.error(&e))?;
+            Some(node_type)
+        } else {
+            None
+        };
+        self.expect(")")?;
+
+        let existing = var.as_deref().and_then(|var| self.pattern.var_index(var));
+        match existing {
+            Some(index) => {
+                let node = &mut self.pattern.nodes[index];
+                match (node.node_type, node_type) {
+                    (Some(a), Some(b)) if a != b => {
+                        Err(self.error("variable used with two node types"))
+                    }
+                    (None, Some(_)) => {
+                        node.node_type = node_type;
+                        Ok(index)
+                    }
+                    _ => Ok(index),
+                }
+            }
+            None => {
+                self.pattern.nodes.push(PatternNode { var, node_type });
+                Ok(self.pattern.nodes.len() - 1)
+            }
+        }
+    }
+
+    /// `-[:REL]->`, `<-[:REL]-`, `-->` or `<--`; returns the relation and
+    /// whether the edge points forward.
+    fn parse_edge(&mut self) -> GraphResult<(Option<Relation>, bool)> {
+        let backward = self.eat("<");
+        self.expect("-")?;
+
+        let mut relation = None;
+        if self.eat("[") {
+            if self.eat(":") {
+                let name = self
+                    .identifier()
+                    .ok_or_else(|| self.error("expected relation"))?;
+                relation = Some(Relation::from(name));
+            }
+            self.expect("]")?;
+        }
+
+        self.expect("-")?;
+        let forward = self.eat(">");
+        match (backward, forward) {
+            (true, false) => Ok((relation, false)),
+            (false, true) => Ok((relation, true)),
+            _ => Err(self.error("edges must have exactly one direction")),
+        }
+    }
+}
+
+/// Limits of a pattern match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchConfig {
+    /// Maximum number of matches returned.
+    pub limit: usize,
+    /// Maximum number of candidate nodes and edges examined.
+    pub max_steps: usize,
+}
+
+impl Default for MatchConfig {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            max_steps: 100_000,
+        }
+    }
+}
+
+impl MatchConfig {
+    /// Set the maximum number of matches.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// One binding of a pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternMatch {
+    /// Graph node bound to each pattern node, by pattern node index.
+    pub nodes: Vec<Uuid>,
+    /// Graph edge matched by each pattern edge, by pattern edge index.
+    pub edges: Vec<Edge>,
+}
+
+impl PatternMatch {
+    /// Graph node bound to each variable of the pattern.
+    pub fn bindings(&self, pattern: &Pattern) -> BTreeMap<String, Uuid> {
+        pattern
+            .nodes
+            .iter()
+            .zip(&self.nodes)
+            .filter_map(|(node, id)| Some((node.var.clone()?, *id)))
+            .collect()
+    }
+}
+
+/// Result of a pattern match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PatternMatches {
+    /// Matches found, in a stable order.
+    pub matches: Vec<PatternMatch>,
+    /// Whether matching stopped at the match limit or the work budget.
+    pub truncated: bool,
+}
+
+/// Graph access needed by the matcher.
+pub(crate) trait PatternSource {
+    fn node(&self, id: &Uuid) -> Option<&Node>;
+
+    fn node_count(&self) -> usize;
+
+    /// All node IDs, sorted.
+    fn node_ids(&self) -> Vec<Uuid>;
+
+    /// IDs of the nodes of a type, sorted.
+    fn nodes_of_type(&self, node_type: NodeType) -> Vec<Uuid>;
+
+    /// Number of nodes of a type, from the type index.
+    fn type_count(&self, node_type: NodeType) -> usize;
+
+    fn outgoing_edges(&self, id: &Uuid) -> Vec<&Edge>;
+
+    fn incoming_edges(&self, id: &Uuid) -> Vec<&Edge>;
+}
+
+/// A step of a match plan.
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    /// Bind a node from its type index (or all nodes).
+    Anchor(usize),
+    /// Match an edge with at least one bound end, binding the other.
+    Edge(usize),
+}
+
+/// Order the steps so every edge has a bound end when it is matched,
+/// anchoring each connected part of the pattern at its most selective node.
+fn plan(pattern: &Pattern, source: &impl PatternSource) -> Vec<Step> {
+    let candidates = |index: usize| match pattern.nodes[index].node_type {
+        Some(node_type) => source.type_count(node_type),
+        None => source.node_count(),
+    };
+
+    let mut bound = vec![false; pattern.nodes.len()];
+    let mut matched = vec![false; pattern.edges.len()];
+    let mut steps = Vec::new();
+    loop {
+        let next_edge = (0..pattern.edges.len())
+            .find(|&e| !matched[e] && (bound[pattern.edges[e].from] || bound[pattern.edges[e].to]));
+        if let Some(e) = next_edge {
+            matched[e] = true;
+            bound[pattern.edges[e].from] = true;
+            bound[pattern.edges[e].to] = true;
+            steps.push(Step::Edge(e));
+            continue;
+        }
+
+        let Some(anchor) = (0..pattern.nodes.len())
+            .filter(|&n| !bound[n])
+            .min_by_key(|&n| candidates(n))
+        else {
+            return steps;
+        };
+        bound[anchor] = true;
+        steps.push(Step::Anchor(anchor));
+    }
+}
+
+/// Find the bindings of a pattern in a graph.
+pub(crate) fn find_matches(
+    source: &impl PatternSource,
+    pattern: &Pattern,
+    config: &MatchConfig,
+) -> PatternMatches {
+    let mut matcher = Matcher {
+        source,
+        pattern,
+        config,
+        steps: plan(pattern, source),
+        nodes: vec![None; pattern.nodes.len()],
+        edges: vec![None; pattern.edges.len()],
+        work: 0,
+        result: PatternMatches::default(),
+    };
+    if !pattern.nodes.is_empty() && config.limit > 0 {
+        matcher.extend(0);
+    }
+    matcher.result
+}
+
+struct Matcher<'a, S> {
+    source: &'a S,
+    pattern: &'a Pattern,
+    config: &'a MatchConfig,
+    steps: Vec<Step>,
+    nodes: Vec<Option<Uuid>>,
+    edges: Vec<Option<&'a Edge>>,
+    work: usize,
+    result: PatternMatches,
+}
+
+impl<'a, S: PatternSource> Matcher<'a, S> {
+    /// Whether matching must stop; counts one unit of work.
+    fn exhausted(&mut self) -> bool {
+        if self.result.truncated {
+            return true;
+        }
+        self.work += 1;
+        if self.work > self.config.max_steps {
+            self.result.truncated = true;
+        }
+        self.result.truncated
+    }
+
+    /// Whether a graph node can be bound to an unbound pattern node.
+    fn admits(&self, index: usize, id: Uuid) -> bool {
+        if self.nodes.contains(&Some(id)) {
+            return false;
+        }
+        match self.pattern.nodes[index].node_type {
+            Some(node_type) => self
+                .source
+                .node(&id)
+                .is_some_and(|n| n.node_type == node_type),
+            None => self.source.node(&id).is_some(),
+        }
+    }
+
+    fn extend(&mut self, step: usize) {
+        let Some(&next) = self.steps.get(step) else {
+            if self.result.matches.len() >= self.config.limit {
+                self.result.truncated = true;
+                return;
+            }
+            self.result.matches.push(PatternMatch {
+                nodes: self.nodes.iter().flatten().copied().collect(),
+                edges: self
+                    .edges
+                    .iter()
+                    .flatten()
+                    .map(|e| Edge::clone(e))
+                    .collect(),
+            });
+            return;
+        };
+
+        match next {
+            Step::Anchor(index) => {
+                let candidates = match self.pattern.nodes[index].node_type {
+                    Some(node_type) => self.source.nodes_of_type(node_type),
+                    None => self.source.node_ids(),
+                };
+                for id in candidates {
+                    if self.exhausted() {
+                        return;
+                    }
+                    if self.admits(index, id) {
+                        self.nodes[index] = Some(id);
+                        self.extend(step + 1);
+                        self.nodes[index] = None;
+                    }
+                }
+            }
+            Step::Edge(e) => {
+                let edge = &self.pattern.edges[e];
+                let (from, to) = (edge.from, edge.to);
+                // Expand from the bound end
+                let (bound, other, graph_edges) = match self.nodes[from] {
+                    Some(id) => (id, to, self.source.outgoing_edges(&id)),
+                    None => {
+                        let id = self.nodes[to].unwrap_or_default();
+                        (id, from, self.source.incoming_edges(&id))
+                    }
+                };
+                for graph_edge in graph_edges {
+                    if self.exhausted() {
+                        return;
+                    }
+                    if edge
+                        .relation
+                        .as_ref()
+                        .is_some_and(|r| *r != graph_edge.relation)
+                    {
+                        continue;
+                    }
+                    let end = if graph_edge.source == bound {
+                        graph_edge.target
+                    } else {
+                        graph_edge.source
+                    };
+                    let binds = match self.nodes[other] {
+                        Some(id) => id == end,
+                        None => self.admits(other, end),
+                    };
+                    if !binds {
+                        continue;
+                    }
+
+                    let newly_bound = self.nodes[other].is_none();
+                    self.nodes[other] = Some(end);
+                    self.edges[e] = Some(graph_edge);
+                    self.extend(step + 1);
+                    self.edges[e] = None;
+                    if newly_bound {
+                        self.nodes[other] = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryGraph;
+
+    #[test]
+    fn test_parse_pattern() {
+        let pattern = Pattern::parse("(a:Concept)-[:CAUSES]->(b)<-[]-(), (a)-->(c:fact)").unwrap();
+        assert_eq!(pattern.nodes.len(), 4);
+        assert_eq!(pattern.vars().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(pattern.nodes[0].node_type, Some(NodeType::Concept));
+        assert_eq!(pattern.nodes[3].node_type, Some(NodeType::Fact));
+        assert_eq!(
+            pattern.edges,
+            vec![
+                PatternEdge {
+                    from: 0,
+                    to: 1,
+                    relation: Some(Relation::Causes)
+                },
+                PatternEdge {
+                    from: 2,
+                    to: 1,
+                    relation: None
+                },
+                PatternEdge {
+                    from: 0,
+                    to: 3,
+                    relation: None
+                },
+            ]
+        );
+
+        for invalid in [
+            "",
+            "(a",
+            "(a:Planet)",
+            "(a)-[:causes]-(b)",
+            "(a)<-->(b)",
+            "(a:Fact), (a:Concept)",
+            "(a) (b)",
+        ] {
+            assert!(
+                matches!(Pattern::parse(invalid), Err(GraphError::InvalidPattern(_))),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_match_pattern() {
+        let cause = Node::new("Supply shortage", NodeType::Concept);
+        let effect = Node::new("Production delay", NodeType::Fact);
+        let claim = Node::new("Production is on schedule", NodeType::Fact);
+        let other = Node::new("Demand spike", NodeType::Concept);
+        let ids = [cause.id, effect.id, claim.id, other.id];
+        let graph = MemoryGraph::from_parts(
+            vec![cause, effect, claim, other],
+            vec![
+                Edge::new(ids[0], ids[1], Relation::Causes),
+                Edge::new(ids[1], ids[2], Relation::Contradicts),
+                Edge::new(ids[3], ids[1], Relation::Causes),
+                Edge::new(ids[3], ids[2], Relation::Causes),
+            ],
+        )
+        .unwrap();
+
+        let pattern = Pattern::parse("(a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c)").unwrap();
+        let found = graph.match_pattern(&pattern, &MatchConfig::default());
+        assert!(!found.truncated);
+        let mut bindings: Vec<_> = found.matches.iter().map(|m| m.bindings(&pattern)).collect();
+        bindings.sort_by_key(|b| b["a"]);
+        let mut causes = [ids[0], ids[3]];
+        causes.sort();
+        assert_eq!(bindings.len(), 2);
+        assert_eq!(bindings[0]["a"], causes[0]);
+        assert_eq!(bindings[1]["a"], causes[1]);
+        assert!(bindings
+            .iter()
+            .all(|b| b["b"] == ids[1] && b["c"] == ids[2]));
+        assert_eq!(found.matches[0].edges[1].relation, Relation::Contradicts);
+
+        // Variables bind distinct nodes; shared variables join chains
+        let pattern = Pattern::parse("(a)-[:causes]->(b), (a)-[:causes]->(c)").unwrap();
+        let found = graph.match_pattern(&pattern, &MatchConfig::default());
+        assert_eq!(found.matches.len(), 2);
+        assert!(found
+            .matches
+            .iter()
+            .all(|m| m.bindings(&pattern)["a"] == ids[3]));
+
+        // Limits truncate the result
+        let pattern = Pattern::parse("(a)-->(b)").unwrap();
+        let found = graph.match_pattern(&pattern, &MatchConfig::default().with_limit(3));
+        assert_eq!(found.matches.len(), 3);
+        assert!(found.truncated);
+        let config = MatchConfig {
+            max_steps: 2,
+            ..Default::default()
+        };
+        assert!(graph.match_pattern(&pattern, &config).truncated);
+
+        // A type change moves the node in the type index
+        let pattern = Pattern::parse("(a:Entity)").unwrap();
+        assert!(graph
+            .match_pattern(&pattern, &MatchConfig::default())
+            .matches
+            .is_empty());
+        let mut graph = graph;
+        let mut entity = graph
+            .snapshot()
+            .nodes()
+            .find(|n| n.id == ids[2])
+            .unwrap()
+            .clone();
+        entity.node_type = NodeType::Entity;
+        graph.update_node(entity).unwrap();
+        let found = graph
+            .snapshot()
+            .match_pattern(&pattern, &MatchConfig::default());
+        assert_eq!(found.matches[0].nodes, vec![ids[2]]);
+        let pattern = Pattern::parse("(b)-[:contradicts]->(c:Fact)").unwrap();
+        assert!(graph
+            .match_pattern(&pattern, &MatchConfig::default())
+            .matches
+            .is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Supernode protection.
+//!
+//! A single popular node (an entity such as "Rust") can collect tens of
+//! thousands of edges, and every traversal passing through it explodes.
+//! With a maximum degree set on a [`MemoryGraph`](crate::MemoryGraph), a
+//! node keeps its first `max_degree` edges of each direction in its
+//! adjacency list; later edges overflow into an [`EdgeBundle`]. Bundled
+//! edges are still stored and returned by edge lookups, but traversals
+//! expand the node according to a [`SupernodeStrategy`] and report it in
+//! [`TraversalResult::supernodes`](crate::TraversalResult::supernodes).
+
+use std::collections::HashMap;
+
+use synton_core::{Edge, Relation};
+
+/// How traversals expand a supernode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SupernodeStrategy {
+    /// Follow every edge, bundled ones included.
+    Expand,
+    /// Follow only the edges kept in the adjacency list, at most the
+    /// graph's maximum degree per direction.
+    #[default]
+    Sample,
+    /// Reach the node but do not expand it.
+    Skip,
+}
+
+/// Summary of the edges a supernode has beyond the maximum degree in one
+/// direction.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EdgeBundle {
+    /// Number of bundled edges
+    pub edge_count: usize,
+
+    /// Number of bundled edges per relation
+    pub relation_counts: HashMap<Relation, usize>,
+}
+
+impl EdgeBundle {
+    /// Count an edge into the bundle.
+    pub fn add(&mut self, edge: &Edge) {
+        self.edge_count += 1;
+        *self.relation_counts.entry(edge.relation.clone()).or_default() += 1;
+    }
+
+    /// Add the counts of another bundle.
+    pub fn merge(&mut self, other: &EdgeBundle) {
+        self.edge_count += other.edge_count;
+        for (relation, count) in &other.relation_counts {
+            *self.relation_counts.entry(relation.clone()).or_default() += count;
+        }
+    }
+}
+
+/// Edges of a node beyond the maximum degree in one direction.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Overflow {
+    pub(crate) edges: Vec<Edge>,
+    pub(crate) bundle: EdgeBundle,
+}
+
+impl Overflow {
+    pub(crate) fn push(&mut self, edge: Edge) {
+        self.bundle.add(&edge);
+        self.edges.push(edge);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_bundle_counts() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut overflow = Overflow::default();
+        overflow.push(Edge::new(a, b, Relation::SimilarTo));
+        overflow.push(Edge::new(a, b, Relation::SimilarTo));
+        overflow.push(Edge::new(a, b, Relation::Causes));
+        assert_eq!(overflow.edges.len(), 3);
+        assert_eq!(overflow.bundle.edge_count, 3);
+        assert_eq!(overflow.bundle.relation_counts.get(&Relation::SimilarTo), Some(&2));
+
+        let mut merged = EdgeBundle::default();
+        merged.merge(&overflow.bundle);
+        merged.merge(&overflow.bundle);
+        assert_eq!(merged.edge_count, 6);
+        assert_eq!(merged.relation_counts.get(&Relation::Causes), Some(&2));
+    }
+}
@@ -6,7 +6,7 @@
 
 use uuid::Uuid;
 
-use synton_core::{Edge, Node, NodeType, Relation};
+use synton_core::{Edge, EdgeCreator, EdgeProvenance, Node, NodeType, Relation};
 use synton_graph::{
     Graph, MemoryGraph, TraverseDirection, TraversalConfig, TraversalResult,
 };
@@ -302,6 +302,35 @@ async fn test_bfs_with_relation_filter() {
     assert!(!result.is_empty());
 }
 
+#[tokio::test]
+async fn test_bfs_with_creator_filter() {
+    let mut graph = MemoryGraph::new();
+    let start = Node::new("Start", NodeType::Entity);
+    let human = Node::new("Human link", NodeType::Concept);
+    let extracted = Node::new("Extracted link", NodeType::Concept);
+    for node in [&start, &human, &extracted] {
+        graph.add_node(node.clone()).unwrap();
+    }
+
+    graph
+        .add_edge(Edge::new(start.id, human.id, Relation::SimilarTo))
+        .unwrap();
+    graph
+        .add_edge(
+            Edge::new(start.id, extracted.id, Relation::SimilarTo)
+                .with_provenance(EdgeProvenance::new(EdgeCreator::ExtractionPipeline)),
+        )
+        .unwrap();
+
+    let config = TraversalConfig::with_depth(1).with_creator(EdgeCreator::User);
+    let result = graph.bfs(start.id, config).await.unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(result.nodes[0].id, human.id);
+
+    let all = graph.bfs(start.id, TraversalConfig::with_depth(1)).await.unwrap();
+    assert_eq!(all.len(), 2);
+}
+
 #[tokio::test]
 async fn test_bfs_directions() {
     let (graph, nodes, _) = create_test_graph().await;
@@ -491,6 +520,7 @@ fn test_traversal_config_default() {
     assert_eq!(config.max_nodes, 100);
     assert_eq!(config.direction, TraverseDirection::Forward);
     assert!(config.relation_filter.is_empty());
+    assert!(config.creator_filter.is_empty());
     assert!(config.avoid_cycles);
     assert!(!config.include_start);
 }
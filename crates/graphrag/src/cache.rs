@@ -0,0 +1,185 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Semantic cache of retrieval results.
+//!
+//! Agents often ask the same question in slightly different words, and the
+//! embeddings of such queries are nearly identical. The cache keeps recent
+//! query embeddings with their results and serves a result again when a new
+//! query with the same retrieval settings comes within a cosine similarity
+//! threshold of a cached one. Entries expire after a TTL, and every entry is
+//! dropped when the indexed data changes, tracked by a generation counter
+//! the owner bumps on each mutation.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::rag::cosine_similarity;
+use crate::{RetrievalConfig, RetrievalResult};
+
+/// A cached retrieval.
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    embedding: Vec<f32>,
+    config: RetrievalConfig,
+    result: RetrievalResult,
+    inserted: Instant,
+    generation: u64,
+}
+
+/// Retrieval results keyed by query embedding similarity.
+#[derive(Debug)]
+pub struct RetrievalCache {
+    /// Entries, most recently used last.
+    entries: Mutex<VecDeque<CacheEntry>>,
+
+    /// Minimum cosine similarity of a query to a cached one to reuse it.
+    similarity: f32,
+
+    /// How long an entry is served.
+    ttl: Duration,
+
+    /// Most entries kept; the least recently used are evicted past it.
+    capacity: usize,
+
+    /// Version of the indexed data; entries of older versions are stale.
+    generation: AtomicU64,
+}
+
+impl RetrievalCache {
+    /// Create a cache reusing results of queries at least `similarity`
+    /// similar for `ttl`, holding up to `capacity` entries.
+    pub fn new(similarity: f32, ttl: Duration, capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::new()),
+            similarity: similarity.clamp(0.0, 1.0),
+            ttl,
+            capacity,
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    fn entries(&self) -> MutexGuard<'_, VecDeque<CacheEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Cached result of the most similar query within the threshold that
+    /// used the same settings, if any is fresh.
+    pub fn get(&self, embedding: &[f32], config: &RetrievalConfig) -> Option<RetrievalResult> {
+        let generation = self.generation.load(Ordering::Acquire);
+        let mut entries = self.entries();
+        entries.retain(|entry| {
+            entry.generation == generation && entry.inserted.elapsed() < self.ttl
+        });
+
+        let (index, _) = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.config == *config)
+            .map(|(i, entry)| (i, cosine_similarity(embedding, &entry.embedding)))
+            .filter(|&(_, similarity)| similarity >= self.similarity)
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+        let entry = entries.remove(index)?;
+        let result = entry.result.clone();
+        entries.push_back(entry);
+        Some(result)
+    }
+
+    /// Cache the result of a query, unless the data changed since
+    /// `generation` was read.
+    pub fn insert(
+        &self,
+        embedding: Vec<f32>,
+        config: RetrievalConfig,
+        result: RetrievalResult,
+        generation: u64,
+    ) {
+        if self.capacity == 0 || generation != self.generation() {
+            return;
+        }
+        let mut entries = self.entries();
+        while entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(CacheEntry {
+            embedding,
+            config,
+            result,
+            inserted: Instant::now(),
+            generation,
+        });
+    }
+
+    /// Current version of the indexed data.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Record a change to the indexed data, making every entry stale.
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        self.entries().clear();
+    }
+
+    /// Number of entries held, including any not yet found stale.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(size: usize) -> RetrievalResult {
+        RetrievalResult::new(Vec::new(), Vec::new(), size)
+    }
+
+    #[test]
+    fn test_similar_queries_hit() {
+        let cache = RetrievalCache::new(0.95, Duration::from_secs(60), 2);
+        let config = RetrievalConfig::default();
+        cache.insert(vec![1.0, 0.0, 0.0], config.clone(), result(1), cache.generation());
+
+        let hit = cache.get(&[0.99, 0.05, 0.0], &config).unwrap();
+        assert_eq!(hit.context_size, 1);
+        assert!(cache.get(&[0.0, 1.0, 0.0], &config).is_none());
+        assert!(cache
+            .get(&[1.0, 0.0, 0.0], &config.clone().with_max_hops(5))
+            .is_none());
+
+        // The least recently used entry is evicted
+        cache.insert(vec![0.0, 1.0, 0.0], config.clone(), result(2), cache.generation());
+        cache.get(&[1.0, 0.0, 0.0], &config).unwrap();
+        cache.insert(vec![0.0, 0.0, 1.0], config.clone(), result(3), cache.generation());
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&[0.0, 1.0, 0.0], &config).is_none());
+        assert!(cache.get(&[1.0, 0.0, 0.0], &config).is_some());
+    }
+
+    #[test]
+    fn test_stale_entries_miss() {
+        let cache = RetrievalCache::new(0.95, Duration::from_secs(60), 8);
+        let config = RetrievalConfig::default();
+        let generation = cache.generation();
+        cache.insert(vec![1.0, 0.0], config.clone(), result(1), generation);
+        cache.invalidate();
+        assert!(cache.get(&[1.0, 0.0], &config).is_none());
+
+        // A result computed before the change is not cached
+        cache.insert(vec![1.0, 0.0], config.clone(), result(1), generation);
+        assert!(cache.is_empty());
+
+        let expired = RetrievalCache::new(0.95, Duration::ZERO, 8);
+        expired.insert(vec![1.0, 0.0], config.clone(), result(1), 0);
+        assert!(expired.get(&[1.0, 0.0], &config).is_none());
+    }
+}
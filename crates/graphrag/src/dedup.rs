@@ -0,0 +1,208 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Near-duplicate suppression for formatted context.
+//!
+//! Chunks of the same document often repeat sentences: overlapping chunk
+//! windows, boilerplate, or a summary restating its paragraphs. Each
+//! sentence is reduced to its set of word shingles, and a sentence whose
+//! shingles overlap those of an earlier kept sentence by at least the
+//! similarity threshold is dropped. Nodes are visited in retrieval order,
+//! so the best-ranked copy of a sentence is the one kept.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::retrieval::RetrievedNode;
+
+/// Words per shingle.
+const SHINGLE_SIZE: usize = 3;
+
+/// Context saved by near-duplicate suppression.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DedupStats {
+    /// Sentences removed.
+    pub sentences_removed: usize,
+
+    /// Nodes left out because all their sentences were removed.
+    pub nodes_removed: usize,
+
+    /// Approximate tokens saved.
+    pub tokens_saved: usize,
+}
+
+/// Shingle overlap at or above which a sentence counts as a duplicate, for
+/// an aggressiveness between 0.0 and 1.0. Only identical sentences are
+/// dropped at the low end; sentences sharing half their shingles at the
+/// high end.
+fn similarity_threshold(aggressiveness: f32) -> f32 {
+    1.0 - 0.5 * aggressiveness.clamp(0.0, 1.0)
+}
+
+/// Remove sentences that nearly repeat an earlier one from the content of
+/// `nodes`.
+///
+/// `aggressiveness` ranges from 0.0 (off) to 1.0 (most aggressive). Nodes
+/// left without content are dropped; the others keep their original text
+/// around the removed sentences.
+pub fn deduplicate_passages(
+    nodes: &[RetrievedNode],
+    aggressiveness: f32,
+) -> (Vec<RetrievedNode>, DedupStats) {
+    let mut stats = DedupStats::default();
+    if aggressiveness <= 0.0 {
+        return (nodes.to_vec(), stats);
+    }
+    let threshold = similarity_threshold(aggressiveness);
+
+    let mut kept: Vec<HashSet<String>> = Vec::new();
+    let mut result = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        let content = node.node.content();
+        let mut text = String::with_capacity(content.len());
+        let mut removed = 0;
+        for sentence in split_sentences(content) {
+            let shingles = shingles(sentence);
+            let duplicate = !shingles.is_empty()
+                && kept.iter().any(|other| jaccard(&shingles, other) >= threshold);
+            if duplicate {
+                stats.sentences_removed += 1;
+                removed += sentence.len();
+            } else {
+                text.push_str(sentence);
+                if !shingles.is_empty() {
+                    kept.push(shingles);
+                }
+            }
+        }
+        if removed == 0 {
+            result.push(node.clone());
+            continue;
+        }
+
+        stats.tokens_saved += removed / 4; // Rough estimate: 4 chars per token
+        let text = text.trim();
+        if text.is_empty() {
+            stats.nodes_removed += 1;
+        } else {
+            let mut node = node.clone();
+            node.node.content = Cow::Owned(text.to_string());
+            result.push(node);
+        }
+    }
+
+    (result, stats)
+}
+
+/// Split text into sentences, each keeping its terminator and the
+/// whitespace after it so the pieces concatenate back to `text`.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let boundary = match c {
+            '\n' => true,
+            '.' | '!' | '?' => !matches!(chars.peek(), Some((_, next)) if !next.is_whitespace()),
+            _ => false,
+        };
+        if boundary {
+            let mut end = i + c.len_utf8();
+            while let Some((j, next)) = chars.peek().copied() {
+                if !next.is_whitespace() {
+                    break;
+                }
+                end = j + next.len_utf8();
+                chars.next();
+            }
+            sentences.push(&text[start..end]);
+            start = end;
+        }
+    }
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+    sentences
+}
+
+/// Word shingles of a sentence, ignoring case and punctuation. A sentence
+/// shorter than a shingle is one shingle.
+fn shingles(sentence: &str) -> HashSet<String> {
+    let words: Vec<String> = sentence
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.len() <= SHINGLE_SIZE {
+        return if words.is_empty() {
+            HashSet::new()
+        } else {
+            HashSet::from([words.join(" ")])
+        };
+    }
+    words.windows(SHINGLE_SIZE).map(|window| window.join(" ")).collect()
+}
+
+/// Jaccard similarity of two shingle sets.
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let shared = a.intersection(b).count();
+    shared as f32 / (a.len() + b.len() - shared) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retrieval::test_node;
+
+    fn retrieved(content: &str) -> RetrievedNode {
+        RetrievedNode::new(test_node(content), 0.9, 0, 0.9, true)
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        let text = "First one. Second one!\nThird v1.2 here";
+        let sentences = split_sentences(text);
+        assert_eq!(sentences, vec!["First one. ", "Second one!\n", "Third v1.2 here"]);
+        assert_eq!(sentences.concat(), text);
+    }
+
+    #[test]
+    fn test_repeated_sentences_removed() {
+        let nodes = vec![
+            retrieved("RocksDB stores nodes in column families. Writes go to the WAL first."),
+            retrieved("Writes go to the WAL first. Compaction runs in the background."),
+            retrieved("rocksdb stores nodes in column families!"),
+        ];
+
+        let (deduped, stats) = deduplicate_passages(&nodes, 0.5);
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].node.content(), nodes[0].node.content());
+        assert_eq!(deduped[1].node.content(), "Compaction runs in the background.");
+        assert_eq!(deduped[1].id(), nodes[1].id());
+        assert_eq!(stats.sentences_removed, 2);
+        assert_eq!(stats.nodes_removed, 1);
+        assert!(stats.tokens_saved > 0);
+
+        let (untouched, stats) = deduplicate_passages(&nodes, 0.0);
+        assert_eq!(untouched, nodes);
+        assert_eq!(stats, DedupStats::default());
+    }
+
+    #[test]
+    fn test_aggressiveness_catches_near_duplicates() {
+        let nodes = vec![
+            retrieved("The scheduler retries failed jobs up to three times before giving up."),
+            retrieved("The scheduler retries failed jobs up to five times before giving up."),
+        ];
+
+        let (deduped, _) = deduplicate_passages(&nodes, 0.1);
+        assert_eq!(deduped.len(), 2);
+
+        let (deduped, stats) = deduplicate_passages(&nodes, 1.0);
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(stats.sentences_removed, 1);
+    }
+}
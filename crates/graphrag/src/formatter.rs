@@ -7,6 +7,7 @@
 //! Provides multiple output formats for retrieved context to optimize
 //! for different LLM use cases.
 
+use crate::dedup::{deduplicate_passages, DedupStats};
 use crate::retrieval::RetrievedNode;
 use serde::{Deserialize, Serialize};
 
@@ -30,6 +31,9 @@ pub struct FormatConfig {
 
     /// Compression level (0.0 = no compression, 1.0 = maximum).
     pub compression: f32,
+
+    /// Near-duplicate sentence suppression (0.0 = off, 1.0 = maximum).
+    pub dedup: f32,
 }
 
 impl Default for FormatConfig {
@@ -41,6 +45,7 @@ impl Default for FormatConfig {
             max_tokens: 4096,
             style: FormatStyle::Structured,
             compression: 0.0,
+            dedup: 0.5,
         }
     }
 }
@@ -74,6 +79,12 @@ impl FormatConfig {
         self.compression = level.clamp(0.0, 1.0);
         self
     }
+
+    /// Set the near-duplicate suppression level.
+    pub fn with_dedup(mut self, level: f32) -> Self {
+        self.dedup = level.clamp(0.0, 1.0);
+        self
+    }
 }
 
 /// Format style for context output.
@@ -102,6 +113,17 @@ pub trait ContextFormatter: Send + Sync {
 
     /// Get the formatter name.
     fn name(&self) -> &str;
+
+    /// Format nodes after removing sentences that nearly repeat earlier
+    /// ones, as set by `config.dedup`.
+    fn format_deduplicated(
+        &self,
+        nodes: &[RetrievedNode],
+        config: &FormatConfig,
+    ) -> (String, DedupStats) {
+        let (nodes, stats) = deduplicate_passages(nodes, config.dedup);
+        (self.format(&nodes, config), stats)
+    }
 }
 
 /// Simple flat formatter - plain text list.
@@ -491,4 +513,33 @@ mod tests {
         assert_eq!(config.max_tokens, 2048);
         assert_eq!(config.compression, 0.5);
     }
+
+    #[test]
+    fn test_format_deduplicated() {
+        let nodes = vec![
+            RetrievedNode::new(
+                crate::retrieval::test_node("Shared intro sentence here. First part."),
+                0.9,
+                0,
+                0.9,
+                true,
+            ),
+            RetrievedNode::new(
+                crate::retrieval::test_node("Shared intro sentence here. Second part."),
+                0.8,
+                0,
+                0.8,
+                true,
+            ),
+        ];
+
+        let formatter = CompactFormatter::new();
+        let (result, stats) = formatter.format_deduplicated(&nodes, &FormatConfig::default());
+        assert_eq!(result, "Shared intro sentence here. First part. Second part.");
+        assert_eq!(stats.sentences_removed, 1);
+
+        let config = FormatConfig::new().with_dedup(0.0);
+        let (result, _) = formatter.format_deduplicated(&nodes, &config);
+        assert_eq!(result.matches("Shared intro").count(), 2);
+    }
 }
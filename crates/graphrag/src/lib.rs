@@ -10,6 +10,7 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod cache;
 mod error;
 mod rag;
 mod retrieval;
@@ -17,10 +18,16 @@ mod scorer;
 mod formatter;
 mod summary;
 mod expansion;
+mod dedup;
 
+pub use cache::RetrievalCache;
+pub use dedup::{deduplicate_passages, DedupStats};
 pub use error::{GraphRagError, GraphRagResult};
 pub use rag::{GraphRag, GraphRagConfig};
-pub use retrieval::{RetrievalConfig, RetrievalMode, RetrievalResult, RetrievedContext};
+pub use retrieval::{
+    ChunkContext, ContextSource, RetrievalConfig, RetrievalMode, RetrievalResult, RetrievedContext,
+    VectorRepresentation, DEFAULT_MIN_RELEVANCE,
+};
 pub use scorer::{RelevanceScore, Scorer};
 
 // Formatter exports
@@ -33,7 +40,7 @@ pub use formatter::{
 // Summary exports
 pub use summary::{
     SummaryLevel, SummaryConfig, HierarchicalSelector,
-    ContextCompressor, CompressionStrategy, HierarchicalNode,
+    ContextCompressor, CompressionStrategy, HierarchicalNode, CHUNK_ATTRIBUTE,
 };
 
 // Expansion exports
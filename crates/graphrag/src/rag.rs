@@ -4,16 +4,22 @@
 
 use async_trait::async_trait;
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 use uuid::Uuid;
 
 use crate::{
-    error::GraphRagResult,
+    cache::RetrievalCache,
+    dedup::deduplicate_passages,
+    error::{GraphRagError, GraphRagResult},
     retrieval::{ContextSource, RetrievedContext, RetrievedNode, RetrievalResult},
-    scorer::Scorer,
-    RetrievalConfig, RetrievalMode,
+    scorer::{RelevanceScore, Scorer},
+    summary::{HierarchicalSelector, SummaryConfig, SummaryLevel},
+    ChunkContext, RetrievalConfig, RetrievalMode, VectorRepresentation, DEFAULT_MIN_RELEVANCE,
 };
-use synton_core::Node;
-use synton_graph::{Graph, TraverseDirection, TraversalConfig};
+use synton_core::{Node, Relation};
+use synton_graph::{Graph, TraverseDirection};
+use synton_memory::DecayCalculator;
+use synton_vector::{calibrate, CalibrationConfig, MultiVectorIndex, SimilarityCalibration};
 
 /// Configuration for Graph-RAG operations.
 #[derive(Debug, Clone, PartialEq)]
@@ -29,6 +35,17 @@ pub struct GraphRagConfig {
 
     /// Cache TTL in seconds.
     pub cache_ttl_secs: u64,
+
+    /// Minimum cosine similarity of a query embedding to a cached one for
+    /// the cached result to be served.
+    pub cache_similarity: f32,
+
+    /// Most retrievals kept in the cache.
+    pub cache_capacity: usize,
+
+    /// Calibrated similarity threshold of the index, used when a retrieval
+    /// sets no minimum relevance.
+    pub similarity_threshold: Option<f32>,
 }
 
 impl Default for GraphRagConfig {
@@ -38,6 +55,9 @@ impl Default for GraphRagConfig {
             scorer: Scorer::default(),
             enable_cache: true,
             cache_ttl_secs: 300, // 5 minutes
+            cache_similarity: 0.98,
+            cache_capacity: 256,
+            similarity_threshold: None,
         }
     }
 }
@@ -61,6 +81,34 @@ impl GraphRagConfig {
         self.cache_ttl_secs = ttl_secs;
         self
     }
+
+    /// Create a new config with the similarity a query needs to a cached
+    /// one to reuse its result.
+    pub fn with_cache_similarity(mut self, similarity: f32) -> Self {
+        self.cache_similarity = similarity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Create a new config with the most retrievals kept in the cache.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    /// Create a new config with a calibrated similarity threshold.
+    pub fn with_similarity_threshold(mut self, threshold: f32) -> Self {
+        self.similarity_threshold = Some(threshold.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Minimum relevance of a retrieval: its own if set, else the
+    /// calibrated threshold, else [`DEFAULT_MIN_RELEVANCE`].
+    pub fn min_relevance(&self, retrieval: &RetrievalConfig) -> f32 {
+        retrieval
+            .min_relevance
+            .or(self.similarity_threshold)
+            .unwrap_or(DEFAULT_MIN_RELEVANCE)
+    }
 }
 
 /// Graph-RAG: Combines vector search with graph traversal for enhanced retrieval.
@@ -106,6 +154,15 @@ pub struct MemoryGraphRag<G> {
 
     /// Configuration.
     config: GraphRagConfig,
+
+    /// Decay model for current memory strength.
+    decay: DecayCalculator,
+
+    /// Sub-chunk vectors for multi-vector matching.
+    multi_vectors: Option<MultiVectorIndex>,
+
+    /// Recent retrievals, served again for similar queries.
+    cache: Option<RetrievalCache>,
 }
 
 impl<G> MemoryGraphRag<G>
@@ -114,32 +171,60 @@ where
 {
     /// Create a new MemoryGraphRag.
     pub fn new(graph: G, nodes: Vec<Node>) -> Self {
-        let node_map = nodes.into_iter().map(|n| (n.id, n)).collect();
-        Self {
-            graph,
-            nodes: node_map,
-            config: GraphRagConfig::default(),
-        }
+        Self::with_config(graph, nodes, GraphRagConfig::default())
     }
 
     /// Create with custom configuration.
     pub fn with_config(graph: G, nodes: Vec<Node>, config: GraphRagConfig) -> Self {
         let node_map = nodes.into_iter().map(|n| (n.id, n)).collect();
+        let cache = config.enable_cache.then(|| {
+            RetrievalCache::new(
+                config.cache_similarity,
+                Duration::from_secs(config.cache_ttl_secs),
+                config.cache_capacity,
+            )
+        });
         Self {
             graph,
             nodes: node_map,
             config,
+            decay: DecayCalculator::new(),
+            multi_vectors: None,
+            cache,
         }
     }
 
+    /// Use a custom decay model for recency weighting.
+    pub fn with_decay(mut self, decay: DecayCalculator) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    /// Use sub-chunk vectors for retrievals with
+    /// [`VectorRepresentation::MultiVector`].
+    pub fn with_multi_vectors(mut self, index: MultiVectorIndex) -> Self {
+        self.multi_vectors = Some(index);
+        self
+    }
+
+    /// The sub-chunk vectors, if configured.
+    pub fn multi_vectors(&self) -> Option<&MultiVectorIndex> {
+        self.multi_vectors.as_ref()
+    }
+
     /// Add a node to the index.
     pub fn add_node(&mut self, node: Node) {
         self.nodes.insert(node.id, node);
+        self.invalidate_cache();
     }
 
     /// Remove a node from the index.
     pub fn remove_node(&mut self, id: Uuid) -> Option<Node> {
-        self.nodes.remove(&id)
+        let removed = self.nodes.remove(&id);
+        if removed.is_some() {
+            self.invalidate_cache();
+        }
+        removed
     }
 
     /// Get a node by ID.
@@ -153,10 +238,70 @@ where
     }
 
     /// Get mutable reference to the graph.
+    ///
+    /// The caller may change the graph, so cached retrievals are dropped.
     pub fn graph_mut(&mut self) -> &mut G {
+        self.invalidate_cache();
         &mut self.graph
     }
 
+    /// The retrieval cache, if enabled.
+    pub fn cache(&self) -> Option<&RetrievalCache> {
+        self.cache.as_ref()
+    }
+
+    /// Drop cached retrievals, for when the graph or the sub-chunk vectors
+    /// changed through a handle shared outside this index.
+    pub fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate();
+        }
+    }
+
+    /// Calibrate the similarity threshold from the embeddings of the
+    /// indexed nodes, keeping the current one if fewer than two are
+    /// embedded.
+    pub fn calibrate(&mut self, config: &CalibrationConfig) -> Option<SimilarityCalibration> {
+        let vectors: Vec<Vec<f32>> = self
+            .nodes
+            .values()
+            .filter_map(|node| node.embedding.clone())
+            .collect();
+        let calibration = calibrate(&vectors, config)?;
+        self.config.similarity_threshold = Some(calibration.threshold);
+        self.invalidate_cache();
+        Some(calibration)
+    }
+
+    /// Vector similarity of every matchable node to the query: max-sim
+    /// over the node's sub-chunk vectors with the multi-vector
+    /// representation, else the cosine similarity of its embedding.
+    async fn direct_similarities(
+        &self,
+        query_embedding: &[f32],
+        representation: VectorRepresentation,
+    ) -> GraphRagResult<Vec<(&Node, f32)>> {
+        let multi = match (&self.multi_vectors, representation) {
+            (Some(index), VectorRepresentation::MultiVector) => index
+                .scores(&[query_embedding.to_vec()])
+                .await
+                .map_err(|e| GraphRagError::VectorSearchFailed(e.to_string()))?,
+            _ => HashMap::new(),
+        };
+
+        Ok(self
+            .nodes
+            .iter()
+            .filter_map(|(id, node)| match multi.get(id) {
+                Some(&(similarity, _)) => Some((node, similarity)),
+                None => node
+                    .embedding
+                    .as_ref()
+                    .map(|embedding| (node, cosine_similarity(query_embedding, embedding))),
+            })
+            .collect())
+    }
+
     /// Format nodes as context string.
     fn format_context(&self, nodes: &[RetrievedNode]) -> String {
         nodes
@@ -185,6 +330,7 @@ where
                     rn.node.content().to_string()
                 };
                 ContextSource::new(rn.id(), preview, rn.score, rn.node.node_type)
+                    .with_provenance(&rn.node)
             })
             .collect()
     }
@@ -204,6 +350,15 @@ where
             .sum()
     }
 
+    /// Blend a score with the node's current memory strength, so recently
+    /// used knowledge outranks stale matches of equal relevance.
+    fn apply_recency(&self, score: &mut RelevanceScore, node: &Node, recency_weight: f32) {
+        if recency_weight > 0.0 {
+            score.final_score = (1.0 - recency_weight) * score.final_score
+                + recency_weight * self.decay.strength(node);
+        }
+    }
+
     /// Filter nodes by minimum relevance.
     fn filter_by_relevance(&self, nodes: Vec<RetrievedNode>, min_rel: f32) -> Vec<RetrievedNode> {
         nodes
@@ -251,20 +406,49 @@ where
         query_embedding: Vec<f32>,
         config: RetrievalConfig,
     ) -> GraphRagResult<RetrievalResult> {
-        match config.mode {
+        // Read the generation first, so a result computed while the data
+        // changes is not cached
+        let cached = self.cache.as_ref().map(|cache| {
+            let hit = cache.get(&query_embedding, &config);
+            (cache, cache.generation(), hit)
+        });
+        let key = match cached {
+            Some((_, _, Some(result))) => {
+                tracing::debug!("Serving retrieval from the semantic cache");
+                return Ok(result);
+            }
+            Some((cache, generation, None)) => {
+                Some((cache, generation, query_embedding.clone(), config.clone()))
+            }
+            None => None,
+        };
+
+        let (chunk_context, max_context_size) = (config.chunk_context, config.max_context_size);
+        let result = match config.mode {
             RetrievalMode::VectorOnly => {
-                self.vector_retrieve(query_embedding, config).await
+                self.vector_retrieve(query_embedding, config).await?
             }
             RetrievalMode::GraphOnly => {
                 // Vector-only for now (would need seed nodes for graph-only)
-                self.vector_retrieve(query_embedding, config).await
+                self.vector_retrieve(query_embedding, config).await?
             }
             RetrievalMode::Hybrid => self.hybrid_retrieve(
                 query_embedding,
                 config.max_vector_results,
                 config.max_hops,
-            ).await,
+            ).await?,
+            RetrievalMode::SpreadingActivation => {
+                self.spreading_activation_retrieve(query_embedding, config).await?
+            }
+        };
+
+        let result = self
+            .apply_chunk_context(result, chunk_context, max_context_size)
+            .await;
+        if let Some((cache, generation, embedding, config)) = key {
+            cache.insert(embedding, config, result.clone(), generation);
         }
+        Ok(result)
     }
 
     async fn retrieve_context(
@@ -272,12 +456,14 @@ where
         query_embedding: Vec<f32>,
         config: RetrievalConfig,
     ) -> GraphRagResult<RetrievedContext> {
+        let dedup = config.context_dedup;
         let result = self.retrieve(query_embedding, config).await?;
 
-        let context = self.format_context(&result.nodes);
+        let (nodes, stats) = deduplicate_passages(&result.nodes, dedup);
+        let context = self.format_context(&nodes);
         let sources = self.extract_sources(&result.nodes);
 
-        Ok(RetrievedContext::new(result, context, sources))
+        Ok(RetrievedContext::new(result, context, sources).with_dedup(stats))
     }
 
     async fn retrieve_from_seeds(
@@ -294,38 +480,45 @@ where
                 continue;
             }
 
-            // BFS traversal from seed
-            let trav_config = TraversalConfig::with_depth(config.max_hops)
-                .with_direction(TraverseDirection::Both);
-
-            if let Ok(result) = self.graph.bfs(seed_id, trav_config).await {
-                for (hop, node) in result.nodes.iter().enumerate() {
-                    if visited.insert(node.id) {
-                        let score = self.config.scorer.score_graph_only(node.id, hop);
-                        all_nodes.push(RetrievedNode::new(
-                            node.clone(),
-                            score.final_score,
-                            score.hop_distance,
-                            0.0,
-                            false,
-                        ));
-                        all_scores.push(score);
-                    }
+            for (node_id, hop, confidence) in self
+                .expand(seed_id, config.max_hops, config.min_confidence)
+                .await
+            {
+                if !visited.insert(node_id) {
+                    continue;
+                }
+                if let Ok(Some(node)) = self.graph.get_node(node_id).await {
+                    let mut score = self
+                        .config
+                        .scorer
+                        .score_graph_path(node_id, hop, confidence);
+                    self.apply_recency(&mut score, &node, config.recency_weight);
+                    all_nodes.push(RetrievedNode::new(
+                        node,
+                        score.final_score,
+                        score.hop_distance,
+                        0.0,
+                        false,
+                    ));
+                    all_scores.push(score);
                 }
             }
         }
 
         // Post-process
         let mut nodes = self.sort_by_relevance(all_nodes);
-        nodes = self.filter_by_relevance(nodes, config.min_relevance);
+        nodes = self.filter_by_relevance(nodes, self.config.min_relevance(&config));
 
         if config.deduplicate {
             nodes = self.deduplicate(nodes);
         }
 
         let context_size = self.calculate_context_size(&nodes);
+        let result = RetrievalResult::new(nodes, all_scores, context_size);
 
-        Ok(RetrievalResult::new(nodes, all_scores, context_size))
+        Ok(self
+            .apply_chunk_context(result, config.chunk_context, config.max_context_size)
+            .await)
     }
 
     async fn hybrid_retrieve(
@@ -337,29 +530,27 @@ where
         let mut all_nodes = Vec::new();
         let mut all_scores = Vec::new();
         let mut visited = HashSet::new();
-
-        // Step 1: Vector similarity search (simulated - find all nodes with embeddings)
-        for (id, node) in &self.nodes {
-            if node.embedding.is_some() {
-                // Calculate cosine similarity
-                if let Some(embedding) = &node.embedding {
-                    let similarity = cosine_similarity(&query_embedding, embedding);
-
-                    if similarity > 0.5 {
-                        // Treat as direct match
-                        visited.insert(*id);
-                        let score = self.config.scorer.score_direct(*id, similarity);
-
-                        all_nodes.push(RetrievedNode::new(
-                            node.clone(),
-                            score.final_score,
-                            0,
-                            similarity,
-                            true,
-                        ));
-                        all_scores.push(score);
-                    }
-                }
+        let min_relevance = self.config.min_relevance(&self.config.retrieval);
+
+        // Step 1: Vector similarity search (simulated - score every embedded node)
+        let similarities = self
+            .direct_similarities(&query_embedding, self.config.retrieval.representation)
+            .await?;
+        for (node, similarity) in similarities {
+            if similarity >= min_relevance {
+                // Treat as direct match
+                visited.insert(node.id);
+                let mut score = self.config.scorer.score_direct(node.id, similarity);
+                self.apply_recency(&mut score, node, self.config.retrieval.recency_weight);
+
+                all_nodes.push(RetrievedNode::new(
+                    node.clone(),
+                    score.final_score,
+                    0,
+                    similarity,
+                    true,
+                ));
+                all_scores.push(score);
             }
         }
 
@@ -371,24 +562,28 @@ where
         let top_matches: Vec<_> = all_nodes.iter().take(top_k.min(5)).map(|n| n.id()).collect();
 
         for match_id in top_matches {
-            let trav_config = TraversalConfig::with_depth(max_hops)
-                .with_direction(TraverseDirection::Both);
-
-            if let Ok(result) = self.graph.bfs(match_id, trav_config).await {
-                for node in result.nodes {
-                    if visited.insert(node.id) {
-                        let hop = 1; // Simplified - should track actual hop distance
-                        let score = self.config.scorer.score_graph_only(node.id, hop);
-
-                        all_nodes.push(RetrievedNode::new(
-                            node,
-                            score.final_score,
-                            score.hop_distance,
-                            0.0,
-                            false,
-                        ));
-                        all_scores.push(score);
-                    }
+            for (node_id, hop, confidence) in self
+                .expand(match_id, max_hops, self.config.retrieval.min_confidence)
+                .await
+            {
+                if !visited.insert(node_id) {
+                    continue;
+                }
+                if let Ok(Some(node)) = self.graph.get_node(node_id).await {
+                    let mut score = self
+                        .config
+                        .scorer
+                        .score_graph_path(node_id, hop, confidence);
+                    self.apply_recency(&mut score, &node, self.config.retrieval.recency_weight);
+
+                    all_nodes.push(RetrievedNode::new(
+                        node,
+                        score.final_score,
+                        score.hop_distance,
+                        0.0,
+                        false,
+                    ));
+                    all_scores.push(score);
                 }
             }
         }
@@ -400,7 +595,7 @@ where
             nodes = self.deduplicate(nodes);
         }
 
-        nodes = self.filter_by_relevance(nodes, self.config.retrieval.min_relevance);
+        nodes = self.filter_by_relevance(nodes, min_relevance);
 
         let context_size = self.calculate_context_size(&nodes);
 
@@ -420,37 +615,260 @@ where
     ) -> GraphRagResult<RetrievalResult> {
         let mut nodes = Vec::new();
         let mut scores = Vec::new();
+        let min_relevance = self.config.min_relevance(&config);
+
+        for (node, similarity) in self
+            .direct_similarities(&query_embedding, config.representation)
+            .await?
+        {
+            if similarity >= min_relevance {
+                let mut score = self.config.scorer.score_direct(node.id, similarity);
+                self.apply_recency(&mut score, node, config.recency_weight);
+
+                nodes.push(RetrievedNode::new(
+                    node.clone(),
+                    score.final_score,
+                    0,
+                    similarity,
+                    true,
+                ));
+                scores.push(score);
+            }
+        }
 
-        for (id, node) in &self.nodes {
-            if let Some(embedding) = &node.embedding {
-                let similarity = cosine_similarity(&query_embedding, embedding);
+        nodes = self.sort_by_relevance(nodes);
+        nodes.truncate(config.max_vector_results);
 
-                if similarity >= config.min_relevance {
-                    let score = self.config.scorer.score_direct(*id, similarity);
+        let context_size = self.calculate_context_size(&nodes);
 
-                    nodes.push(RetrievedNode::new(
-                        node.clone(),
-                        score.final_score,
-                        0,
-                        similarity,
-                        true,
-                    ));
-                    scores.push(score);
+        Ok(RetrievalResult::new(nodes, scores, context_size))
+    }
+
+    /// Spreading activation retrieval.
+    ///
+    /// The top vector matches are seeded with their similarity as
+    /// activation. Each pulse, nodes whose new activation reaches the
+    /// threshold fire: the decayed activation is split over their edges in
+    /// both directions, scaled by each edge's strength and learned relation
+    /// weight. After `max_hops` pulses, nodes rank by the activation they
+    /// accumulated, normalized to the strongest node, so nodes reached by
+    /// several associations outrank those on a single path.
+    async fn spreading_activation_retrieve(
+        &self,
+        query_embedding: Vec<f32>,
+        config: RetrievalConfig,
+    ) -> GraphRagResult<RetrievalResult> {
+        let min_relevance = self.config.min_relevance(&config);
+        let mut seeds: Vec<(&Node, f32)> = self
+            .direct_similarities(&query_embedding, config.representation)
+            .await?
+            .into_iter()
+            .filter(|&(_, similarity)| similarity >= min_relevance)
+            .collect();
+        seeds.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.id.cmp(&b.0.id)));
+        seeds.truncate(config.max_vector_results);
+
+        let similarities: HashMap<Uuid, f32> =
+            seeds.iter().map(|&(node, similarity)| (node.id, similarity)).collect();
+        let mut activation = similarities.clone();
+        let mut hops: HashMap<Uuid, usize> = seeds.iter().map(|&(node, _)| (node.id, 0)).collect();
+        let mut pulse = similarities.clone();
+
+        for hop in 1..=config.max_hops {
+            let mut next: HashMap<Uuid, f32> = HashMap::new();
+            for (&id, &energy) in &pulse {
+                if energy < config.activation_threshold {
+                    continue;
+                }
+                let Ok(edges) = self.graph.edges(id, TraverseDirection::Both).await else {
+                    continue;
+                };
+                if edges.is_empty() {
+                    continue;
                 }
+                let share = energy * config.activation_decay / edges.len() as f32;
+                for edge in edges {
+                    let other = if edge.source == id { edge.target } else { edge.source };
+                    let spread = share
+                        * edge.strength()
+                        * self.config.scorer.relation_weight(&edge.relation.to_string());
+                    *next.entry(other).or_default() += spread;
+                }
+            }
+            if next.is_empty() {
+                break;
             }
+            for (&id, &energy) in &next {
+                *activation.entry(id).or_default() += energy;
+                hops.entry(id).or_insert(hop);
+            }
+            pulse = next;
+        }
+
+        let peak = activation.values().copied().fold(0.0f32, f32::max);
+        if peak <= 0.0 {
+            return Ok(RetrievalResult::new(Vec::new(), Vec::new(), 0));
+        }
+
+        let mut nodes = Vec::new();
+        let mut scores = Vec::new();
+        for (id, total) in activation {
+            if total < config.activation_threshold {
+                continue;
+            }
+            let node = match self.nodes.get(&id) {
+                Some(node) => node.clone(),
+                None => match self.graph.get_node(id).await {
+                    Ok(Some(node)) => node,
+                    _ => continue,
+                },
+            };
+            let similarity = similarities.get(&id).copied().unwrap_or(0.0);
+            let hop_distance = hops[&id];
+            let mut score = RelevanceScore {
+                node_id: id,
+                vector_similarity: similarity,
+                graph_proximity: (total / peak).clamp(0.0, 1.0),
+                final_score: (total / peak).clamp(0.0, 1.0),
+                hop_distance,
+            };
+            self.apply_recency(&mut score, &node, config.recency_weight);
+            nodes.push(RetrievedNode::new(
+                node,
+                score.final_score,
+                hop_distance,
+                similarity,
+                hop_distance == 0,
+            ));
+            scores.push(score);
         }
 
         nodes = self.sort_by_relevance(nodes);
-        nodes.truncate(config.max_vector_results);
+        nodes.truncate(config.max_vector_results + config.max_graph_results);
 
         let context_size = self.calculate_context_size(&nodes);
 
         Ok(RetrievalResult::new(nodes, scores, context_size))
     }
+
+    /// Replace matched chunks with their enclosing chunks as configured.
+    ///
+    /// Chunks sharing a parent collapse into one entry with the best of
+    /// their scores; nodes that are not chunks are kept as they are.
+    async fn apply_chunk_context(
+        &self,
+        mut result: RetrievalResult,
+        chunk_context: ChunkContext,
+        max_context_size: usize,
+    ) -> RetrievalResult {
+        let level = match chunk_context {
+            ChunkContext::Chunks => return result,
+            ChunkContext::Parents(level) => level,
+            ChunkContext::Auto => {
+                HierarchicalSelector::new(SummaryConfig::new().with_max_tokens(max_context_size))
+                    .select_level(&result.nodes, 0.5)
+            }
+        };
+
+        let mut nodes = Vec::with_capacity(result.nodes.len());
+        for retrieved in result.nodes {
+            nodes.push(self.lift_to_level(retrieved, level).await);
+        }
+        // Nodes are in relevance order, so the best match of a parent stays
+        result.nodes = self.deduplicate(self.sort_by_relevance(nodes));
+        result.context_size = self.calculate_context_size(&result.nodes);
+        result
+    }
+
+    /// Follow `IsPartOf` edges from a chunk up to its enclosing chunk at
+    /// `level`, keeping the match's scores. Stops early at a chunk without
+    /// a parent.
+    async fn lift_to_level(&self, mut retrieved: RetrievedNode, level: SummaryLevel) -> RetrievedNode {
+        while SummaryLevel::of_chunk(&retrieved.node).is_some_and(|current| current > level) {
+            let Some(parent) = self.parent_chunk(&retrieved.node).await else {
+                break;
+            };
+            retrieved.node = parent;
+        }
+        retrieved
+    }
+
+    /// The chunk a chunk is part of: the target of one of its `IsPartOf`
+    /// edges one level up the hierarchy.
+    async fn parent_chunk(&self, chunk: &Node) -> Option<Node> {
+        let level = SummaryLevel::of_chunk(chunk)?;
+        let edges = self.graph.edges(chunk.id, TraverseDirection::Forward).await.ok()?;
+        for edge in edges.iter().filter(|edge| edge.relation == Relation::IsPartOf) {
+            if let Ok(Some(parent)) = self.graph.get_node(edge.target).await {
+                if SummaryLevel::of_chunk(&parent).is_some_and(|parent_level| parent_level < level) {
+                    return Some(parent);
+                }
+            }
+        }
+        None
+    }
+
+    /// Expand from `start` over edges in both directions, up to `max_hops`.
+    ///
+    /// Returns `(node, hop distance, path confidence)` for every reached
+    /// node, `start` included. A path's confidence is the product of its
+    /// edges' strengths, so confidence only decreases with each inferred
+    /// hop; each node keeps the best confidence over the paths found, and
+    /// paths below `min_confidence` are not followed.
+    async fn expand(
+        &self,
+        start: Uuid,
+        max_hops: usize,
+        min_confidence: f32,
+    ) -> Vec<(Uuid, usize, f32)> {
+        let mut best: HashMap<Uuid, (usize, f32)> = HashMap::new();
+        best.insert(start, (0, 1.0));
+        let mut order = vec![start];
+        let mut frontier = vec![(start, 1.0f32)];
+
+        for hop in 1..=max_hops {
+            let mut next = Vec::new();
+            for (id, confidence) in frontier {
+                let Ok(edges) = self.graph.edges(id, TraverseDirection::Both).await else {
+                    continue;
+                };
+                for edge in edges {
+                    let other = if edge.source == id { edge.target } else { edge.source };
+                    let path_confidence = confidence * edge.strength();
+                    if path_confidence < min_confidence {
+                        continue;
+                    }
+                    match best.get_mut(&other) {
+                        None => {
+                            best.insert(other, (hop, path_confidence));
+                            order.push(other);
+                        }
+                        Some((_, known)) if path_confidence > *known => {
+                            *known = path_confidence;
+                        }
+                        Some(_) => continue,
+                    }
+                    next.push((other, path_confidence));
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        order
+            .into_iter()
+            .map(|id| {
+                let (hop, confidence) = best[&id];
+                (id, hop, confidence)
+            })
+            .collect()
+    }
 }
 
 /// Calculate cosine similarity between two vectors.
-fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     if a.len() != b.len() {
         return 0.0;
     }
@@ -475,6 +893,7 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dedup::DedupStats;
     use crate::scorer::Scorer;
     use synton_core::NodeType;
     use synton_graph::MemoryGraph;
@@ -496,6 +915,67 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_semantic_cache() {
+        let mut node = Node::new("Rust ownership", NodeType::Concept);
+        node.embedding = Some(vec![1.0, 0.0, 0.0]);
+        let mut rag = MemoryGraphRag::new(MemoryGraph::new(), vec![node]);
+        let config = RetrievalConfig::vector_only().with_min_relevance(0.5);
+
+        let first = rag.retrieve(vec![1.0, 0.0, 0.0], config.clone()).await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(rag.cache().unwrap().len(), 1);
+
+        // A near-identical query is served from the cache
+        let mut other = Node::new("Rust borrowing", NodeType::Concept);
+        other.embedding = Some(vec![0.99, 0.1, 0.0]);
+        rag.nodes.insert(other.id, other.clone());
+        let cached = rag.retrieve(vec![0.99, 0.01, 0.0], config.clone()).await.unwrap();
+        assert_eq!(cached, first);
+
+        // Changing the data drops it
+        rag.add_node(other);
+        assert!(rag.cache().unwrap().is_empty());
+        let fresh = rag.retrieve(vec![0.99, 0.01, 0.0], config.clone()).await.unwrap();
+        assert_eq!(fresh.len(), 2);
+
+        let uncached = MemoryGraphRag::with_config(
+            MemoryGraph::new(),
+            Vec::new(),
+            GraphRagConfig::default().with_cache(false, 300),
+        );
+        assert!(uncached.cache().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_multi_vector_retrieve() {
+        // A long chunk whose single embedding averages two topics, and a
+        // short chunk without sub-chunk vectors
+        let mut long = Node::new("Rust ownership. Python typing.", NodeType::RawChunk);
+        long.embedding = Some(vec![0.6, 0.6, 0.6]);
+        let mut short = Node::new("Python type hints", NodeType::RawChunk);
+        short.embedding = Some(vec![0.2, 0.9, 0.4]);
+
+        let index = MultiVectorIndex::new(3);
+        index
+            .insert(long.id, vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]])
+            .await
+            .unwrap();
+        let rag = MemoryGraphRag::new(MemoryGraph::new(), vec![long.clone(), short.clone()])
+            .with_multi_vectors(index);
+
+        let query = vec![0.0, 1.0, 0.0];
+        let config = RetrievalConfig::vector_only().with_min_relevance(0.5);
+        let single = rag.vector_retrieve(query.clone(), config.clone()).await.unwrap();
+        assert_eq!(single.nodes[0].id(), short.id);
+
+        let config = config.with_representation(VectorRepresentation::MultiVector);
+        let multi = rag.vector_retrieve(query, config).await.unwrap();
+        assert_eq!(multi.nodes.len(), 2);
+        assert_eq!(multi.nodes[0].id(), long.id);
+        assert!((multi.nodes[0].vector_similarity - 1.0).abs() < 0.001);
+    }
+
     #[tokio::test]
     async fn test_graph_rag_retrieve_context() {
         let graph = MemoryGraph::new();
@@ -515,6 +995,27 @@ mod tests {
         assert_eq!(context.sources.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_retrieve_context_dedup() {
+        let mut node1 = Node::new("Overlap between chunks. Only in the first.", NodeType::Concept);
+        node1.embedding = Some(vec![1.0, 0.0, 0.0]);
+        let mut node2 = Node::new("Overlap between chunks. Only in the second.", NodeType::Concept);
+        node2.embedding = Some(vec![0.9, 0.1, 0.0]);
+        let rag = MemoryGraphRag::new(MemoryGraph::new(), vec![node1, node2]);
+
+        let config = RetrievalConfig::vector_only();
+        let context = rag.retrieve_context(vec![1.0, 0.0, 0.0], config.clone()).await.unwrap();
+        assert_eq!(context.context.matches("Overlap between chunks").count(), 1);
+        assert!(context.context.contains("Only in the second."));
+        assert_eq!(context.dedup.sentences_removed, 1);
+        assert_eq!(context.sources.len(), 2);
+
+        let config = config.with_context_dedup(0.0);
+        let context = rag.retrieve_context(vec![1.0, 0.0, 0.0], config).await.unwrap();
+        assert_eq!(context.context.matches("Overlap between chunks").count(), 2);
+        assert_eq!(context.dedup, DedupStats::default());
+    }
+
     #[test]
     fn test_cosine_similarity() {
         let a = vec![1.0, 0.0, 0.0];
@@ -530,6 +1031,35 @@ mod tests {
         assert!(sim > 0.0 && sim < 1.0);
     }
 
+    #[tokio::test]
+    async fn test_calibrated_threshold() {
+        // Embeddings sharing a large common component, as with models whose
+        // unrelated texts still score high
+        let nodes: Vec<Node> = (0..10)
+            .map(|i| {
+                let angle = i as f32 * 0.3;
+                Node::new(format!("Note {}", i), NodeType::Concept)
+                    .with_embedding(vec![angle.cos(), angle.sin(), 4.0])
+            })
+            .collect();
+        let mut rag = MemoryGraphRag::new(MemoryGraph::new(), nodes);
+        let query = vec![1.0, 0.0, 4.0];
+
+        // The default cutoff lets every node through
+        let result = rag.vector_retrieve(query.clone(), RetrievalConfig::vector_only()).await.unwrap();
+        assert_eq!(result.nodes.len(), 10);
+
+        let calibration = rag.calibrate(&CalibrationConfig::default()).unwrap();
+        assert!(calibration.threshold > DEFAULT_MIN_RELEVANCE);
+        let result = rag.vector_retrieve(query.clone(), RetrievalConfig::vector_only()).await.unwrap();
+        assert!(!result.nodes.is_empty() && result.nodes.len() < 10);
+
+        // An explicit minimum relevance overrides the calibration
+        let config = RetrievalConfig::vector_only().with_min_relevance(0.0);
+        let result = rag.vector_retrieve(query, config).await.unwrap();
+        assert_eq!(result.nodes.len(), 10);
+    }
+
     #[test]
     fn test_format_context() {
         let graph = MemoryGraph::new();
@@ -555,4 +1085,192 @@ mod tests {
         let deduped = rag.deduplicate(nodes);
         assert_eq!(deduped.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_recency_weight_prefers_recently_accessed() {
+        let mut stale = Node::new("Battery report 2019", NodeType::Fact);
+        stale.embedding = Some(vec![1.0, 0.0]);
+        stale.meta.access_score = 8.0;
+        stale.meta.accessed_at = Some(chrono::Utc::now() - chrono::Duration::days(60));
+        let mut fresh = Node::new("Battery report 2025", NodeType::Fact);
+        fresh.embedding = Some(vec![1.0, 0.0]);
+        fresh.meta.access_score = 8.0;
+        fresh.meta.accessed_at = Some(chrono::Utc::now());
+
+        let rag = MemoryGraphRag::new(MemoryGraph::new(), vec![stale.clone(), fresh.clone()]);
+        let query = vec![1.0, 0.0];
+
+        // Equal similarity: relevance alone cannot tell them apart
+        let result = rag
+            .vector_retrieve(query.clone(), RetrievalConfig::vector_only())
+            .await
+            .unwrap();
+        assert_eq!(result.nodes[0].score, result.nodes[1].score);
+
+        let config = RetrievalConfig::vector_only().with_recency_weight(0.3);
+        let result = rag.vector_retrieve(query, config).await.unwrap();
+        assert_eq!(result.nodes[0].id(), fresh.id);
+        assert!(result.nodes[0].score > result.nodes[1].score);
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_from_seeds_propagates_confidence() {
+        use synton_core::{Edge, Relation};
+
+        let seed = Node::new("Tesla", NodeType::Entity);
+        let sure = Node::new("Electric vehicles", NodeType::Concept);
+        let derived = Node::new("Battery chemistry", NodeType::Concept);
+        let guess = Node::new("Solar roofs", NodeType::Concept);
+
+        let mut graph = MemoryGraph::new();
+        for node in [&seed, &sure, &derived, &guess] {
+            graph.add_node(node.clone()).unwrap();
+        }
+        graph
+            .add_edge(Edge::new(seed.id, sure.id, Relation::IsA).with_confidence(0.9))
+            .unwrap();
+        graph
+            .add_edge(Edge::new(sure.id, derived.id, Relation::Causes).with_confidence(0.5))
+            .unwrap();
+        graph
+            .add_edge(Edge::new(seed.id, guess.id, Relation::SimilarTo).with_confidence(0.2))
+            .unwrap();
+
+        let scorer = Scorer::with_weights(0.6, 0.4).with_hop_decay(0.5);
+        let rag = MemoryGraphRag::with_config(
+            graph,
+            vec![],
+            GraphRagConfig::default().with_scorer(scorer.clone()),
+        );
+        let config = RetrievalConfig::graph_only().with_min_relevance(0.0);
+
+        let result = rag.retrieve_from_seeds(vec![seed.id], config.clone()).await.unwrap();
+        let score_of = |result: &RetrievalResult, id: Uuid| {
+            result.scores.iter().find(|s| s.node_id == id).map(|s| s.final_score)
+        };
+
+        // Confidence multiplies along the path: 0.9 * 0.5 for the two-hop node
+        let expected = scorer.score_graph_only(derived.id, 2).final_score * 0.45;
+        assert!((score_of(&result, derived.id).unwrap() - expected).abs() < 1e-6);
+        assert!(score_of(&result, guess.id).unwrap() < score_of(&result, sure.id).unwrap());
+
+        // Paths below the threshold are not followed
+        let result = rag
+            .retrieve_from_seeds(vec![seed.id], config.with_min_confidence(0.5))
+            .await
+            .unwrap();
+        assert!(score_of(&result, sure.id).is_some());
+        assert!(score_of(&result, derived.id).is_none());
+        assert!(score_of(&result, guess.id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_spreading_activation_favors_converging_associations() {
+        use synton_core::Edge;
+
+        let mut coffee = Node::new("Coffee", NodeType::Concept);
+        coffee.embedding = Some(vec![1.0, 0.0]);
+        let mut tea = Node::new("Tea", NodeType::Concept);
+        tea.embedding = Some(vec![0.9, 0.1]);
+        let caffeine = Node::new("Caffeine", NodeType::Concept);
+        let beans = Node::new("Beans", NodeType::Concept);
+        let sleep = Node::new("Sleep", NodeType::Concept);
+
+        let mut graph = MemoryGraph::new();
+        for node in [&coffee, &tea, &caffeine, &beans, &sleep] {
+            graph.add_node(node.clone()).unwrap();
+        }
+        for (source, target) in [(&coffee, &caffeine), (&tea, &caffeine), (&coffee, &beans)] {
+            graph
+                .add_edge(Edge::new(source.id, target.id, Relation::SimilarTo))
+                .unwrap();
+        }
+        graph
+            .add_edge(Edge::new(caffeine.id, sleep.id, Relation::Causes))
+            .unwrap();
+
+        let rag = MemoryGraphRag::new(graph, vec![coffee.clone(), tea.clone()]);
+        let query = vec![1.0, 0.0];
+        let config = RetrievalConfig::spreading_activation().with_max_hops(2);
+        let result = rag.retrieve(query.clone(), config.clone()).await.unwrap();
+        let rank = |result: &RetrievalResult, id: Uuid| {
+            result.nodes.iter().position(|n| n.id() == id)
+        };
+
+        // Activation from both drinks converges on caffeine, which outranks
+        // beans, reached from coffee alone
+        assert_eq!(result.nodes[0].id(), coffee.id);
+        assert!(rank(&result, caffeine.id).unwrap() < rank(&result, beans.id).unwrap());
+        let caffeine_node = &result.nodes[rank(&result, caffeine.id).unwrap()];
+        assert_eq!(caffeine_node.hop_distance, 1);
+        assert!(!caffeine_node.is_direct_match);
+        assert!(rank(&result, sleep.id).is_some());
+
+        // Nodes left below the firing threshold are dropped
+        let result = rag
+            .retrieve(query, config.with_activation_threshold(0.2))
+            .await
+            .unwrap();
+        assert!(rank(&result, caffeine.id).is_some());
+        assert!(rank(&result, sleep.id).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_chunk_context_returns_parent_sections() {
+        use crate::CHUNK_ATTRIBUTE;
+        use synton_core::Edge;
+
+        let chunk = |content: &'static str, level: usize| {
+            let mut node = Node::new(content, NodeType::Concept);
+            node.attributes = serde_json::json!({ CHUNK_ATTRIBUTE: { "level": level } });
+            node
+        };
+        let document = chunk("Pumps and valves.", 0);
+        let paragraph = chunk("Pumps move water. Valves stop it.", 1);
+        let mut pumps = chunk("Pumps move water.", 2);
+        pumps.embedding = Some(vec![1.0, 0.0]);
+        let mut valves = chunk("Valves stop it.", 2);
+        valves.embedding = Some(vec![0.9, 0.1]);
+        let mut fact = Node::new("Water is wet", NodeType::Fact);
+        fact.embedding = Some(vec![0.8, 0.2]);
+
+        let mut graph = MemoryGraph::new();
+        for node in [&document, &paragraph, &pumps, &valves, &fact] {
+            graph.add_node(node.clone()).unwrap();
+        }
+        for (child, parent) in [(&pumps, &paragraph), (&valves, &paragraph), (&paragraph, &document)] {
+            graph
+                .add_edge(Edge::new(child.id, parent.id, Relation::IsPartOf))
+                .unwrap();
+        }
+        let rag = MemoryGraphRag::new(graph, vec![pumps.clone(), valves.clone(), fact.clone()]);
+        let ids = |result: &RetrievalResult| result.nodes.iter().map(|n| n.id()).collect::<Vec<_>>();
+        let query = vec![1.0, 0.0];
+
+        let config = RetrievalConfig::vector_only();
+        let result = rag.retrieve(query.clone(), config.clone()).await.unwrap();
+        assert_eq!(ids(&result), vec![pumps.id, valves.id, fact.id]);
+
+        // Both sentences match, but their paragraph is returned once, with
+        // the best sentence's score
+        let result = rag
+            .retrieve(
+                query.clone(),
+                config.clone().with_chunk_context(ChunkContext::Parents(SummaryLevel::Paragraph)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&result), vec![paragraph.id, fact.id]);
+        assert!((result.nodes[0].vector_similarity - 1.0).abs() < 1e-6);
+        assert_eq!(result.context_size, rag.calculate_context_size(&result.nodes));
+
+        let result = rag
+            .retrieve(
+                query,
+                config.with_chunk_context(ChunkContext::Parents(SummaryLevel::Document)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(ids(&result), vec![document.id, fact.id]);
+    }
 }
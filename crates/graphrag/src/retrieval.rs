@@ -2,9 +2,12 @@
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 
+use crate::dedup::DedupStats;
 use crate::scorer::RelevanceScore;
+use crate::summary::SummaryLevel;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use synton_core::{Node, NodeType};
+use synton_core::{Node, NodeType, Source, PROVENANCE_ATTRIBUTE};
 use uuid::Uuid;
 
 /// Retrieval mode for Graph-RAG.
@@ -17,8 +20,42 @@ pub enum RetrievalMode {
     /// Combined: vector search + graph expansion.
     #[default]
     Hybrid,
+    /// Vector matches seed activation that spreads along edges, decaying
+    /// per hop; nodes rank by the activation they accumulate.
+    SpreadingActivation,
 }
 
+/// What is returned for matched chunks of hierarchically chunked documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChunkContext {
+    /// The matched chunks themselves.
+    #[default]
+    Chunks,
+    /// The enclosing chunks at the given level, found over `IsPartOf`
+    /// edges: match on sentences, return their paragraphs.
+    Parents(SummaryLevel),
+    /// The enclosing chunks at the level the [`HierarchicalSelector`]
+    /// picks for the size of the matches.
+    ///
+    /// [`HierarchicalSelector`]: crate::HierarchicalSelector
+    Auto,
+}
+
+/// How nodes are represented for vector matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VectorRepresentation {
+    /// One embedding per node.
+    #[default]
+    Single,
+    /// Several sub-chunk vectors per node, scored by max-sim; nodes without
+    /// sub-chunk vectors fall back to their embedding.
+    MultiVector,
+}
+
+/// Minimum relevance used when neither the retrieval config nor a
+/// calibration sets one.
+pub const DEFAULT_MIN_RELEVANCE: f32 = 0.5;
+
 /// Configuration for retrieval operations.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RetrievalConfig {
@@ -31,8 +68,17 @@ pub struct RetrievalConfig {
     /// Maximum hop distance for graph traversal.
     pub max_hops: usize,
 
-    /// Minimum relevance score threshold (0.0 - 1.0).
-    pub min_relevance: f32,
+    /// Minimum relevance score threshold (0.0 - 1.0). When unset, the
+    /// calibrated threshold of the index applies, else
+    /// [`DEFAULT_MIN_RELEVANCE`].
+    pub min_relevance: Option<f32>,
+
+    /// Minimum propagated confidence of graph paths (0.0 = all paths).
+    pub min_confidence: f32,
+
+    /// Share of the final score taken from each node's current memory
+    /// strength instead of its relevance (0.0 = relevance only).
+    pub recency_weight: f32,
 
     /// Whether to deduplicate results.
     pub deduplicate: bool,
@@ -40,8 +86,25 @@ pub struct RetrievalConfig {
     /// Maximum context size in tokens (approximate).
     pub max_context_size: usize,
 
+    /// Aggressiveness of near-duplicate sentence suppression in the
+    /// formatted context (0.0 = off, 1.0 = maximum).
+    pub context_dedup: f32,
+
     /// Retrieval mode.
     pub mode: RetrievalMode,
+
+    /// What to return for matched document chunks.
+    pub chunk_context: ChunkContext,
+
+    /// How nodes are represented for vector matching.
+    pub representation: VectorRepresentation,
+
+    /// Share of its activation a node passes on per hop in spreading
+    /// activation (0.0 - 1.0).
+    pub activation_decay: f32,
+
+    /// Smallest activation a node needs to fire in spreading activation.
+    pub activation_threshold: f32,
 }
 
 impl Default for RetrievalConfig {
@@ -50,10 +113,17 @@ impl Default for RetrievalConfig {
             max_vector_results: 10,
             max_graph_results: 20,
             max_hops: 2,
-            min_relevance: 0.5,
+            min_relevance: None,
+            min_confidence: 0.0,
+            recency_weight: 0.0,
             deduplicate: true,
             max_context_size: 4096,
+            context_dedup: 0.5,
             mode: RetrievalMode::Hybrid,
+            chunk_context: ChunkContext::Chunks,
+            representation: VectorRepresentation::Single,
+            activation_decay: 0.5,
+            activation_threshold: 0.01,
         }
     }
 }
@@ -83,6 +153,14 @@ impl RetrievalConfig {
         }
     }
 
+    /// Create a new config with spreading activation mode.
+    pub fn spreading_activation() -> Self {
+        Self {
+            mode: RetrievalMode::SpreadingActivation,
+            ..Default::default()
+        }
+    }
+
     /// Set the maximum vector results.
     pub fn with_max_vector_results(mut self, max: usize) -> Self {
         self.max_vector_results = max;
@@ -103,7 +181,19 @@ impl RetrievalConfig {
 
     /// Set the minimum relevance threshold.
     pub fn with_min_relevance(mut self, threshold: f32) -> Self {
-        self.min_relevance = threshold.clamp(0.0, 1.0);
+        self.min_relevance = Some(threshold.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the minimum propagated path confidence.
+    pub fn with_min_confidence(mut self, threshold: f32) -> Self {
+        self.min_confidence = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set how much current memory strength counts against relevance.
+    pub fn with_recency_weight(mut self, weight: f32) -> Self {
+        self.recency_weight = weight.clamp(0.0, 1.0);
         self
     }
 
@@ -112,6 +202,36 @@ impl RetrievalConfig {
         self.deduplicate = enabled;
         self
     }
+
+    /// Set the aggressiveness of near-duplicate sentence suppression.
+    pub fn with_context_dedup(mut self, aggressiveness: f32) -> Self {
+        self.context_dedup = aggressiveness.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set what to return for matched document chunks.
+    pub fn with_chunk_context(mut self, chunk_context: ChunkContext) -> Self {
+        self.chunk_context = chunk_context;
+        self
+    }
+
+    /// Set how nodes are represented for vector matching.
+    pub fn with_representation(mut self, representation: VectorRepresentation) -> Self {
+        self.representation = representation;
+        self
+    }
+
+    /// Set the share of activation passed on per hop.
+    pub fn with_activation_decay(mut self, decay: f32) -> Self {
+        self.activation_decay = decay.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the smallest activation of a firing node.
+    pub fn with_activation_threshold(mut self, threshold: f32) -> Self {
+        self.activation_threshold = threshold.max(0.0);
+        self
+    }
 }
 
 /// A single retrieved node with its metadata.
@@ -244,6 +364,9 @@ pub struct RetrievedContext {
 
     /// Source metadata (for citations).
     pub sources: Vec<ContextSource>,
+
+    /// Context saved by near-duplicate suppression.
+    pub dedup: DedupStats,
 }
 
 impl RetrievedContext {
@@ -253,9 +376,16 @@ impl RetrievedContext {
             result,
             context,
             sources,
+            dedup: DedupStats::default(),
         }
     }
 
+    /// Record what near-duplicate suppression saved.
+    pub fn with_dedup(mut self, dedup: DedupStats) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
     /// Get the context string.
     pub fn as_str(&self) -> &str {
         &self.context
@@ -286,6 +416,26 @@ pub struct ContextSource {
 
     /// Source type.
     pub source_type: NodeType,
+
+    /// Document the source was ingested from, for chunk nodes.
+    #[serde(default)]
+    pub document_id: Option<Uuid>,
+
+    /// Position of the chunk within its document.
+    #[serde(default)]
+    pub chunk_index: Option<usize>,
+
+    /// When the source node was ingested.
+    #[serde(default)]
+    pub ingested_at: Option<DateTime<Utc>>,
+
+    /// URL or file name of the originating document.
+    #[serde(default)]
+    pub origin: Option<String>,
+
+    /// Title of the originating document.
+    #[serde(default)]
+    pub title: Option<String>,
 }
 
 impl ContextSource {
@@ -296,8 +446,36 @@ impl ContextSource {
             preview,
             score,
             source_type,
+            document_id: None,
+            chunk_index: None,
+            ingested_at: None,
+            origin: None,
+            title: None,
         }
     }
+
+    /// Fill in ingestion provenance from the source node: its document and
+    /// chunk position, creation time, and the origin and title recorded in
+    /// its `provenance` attribute (falling back to a URL or file source).
+    pub fn with_provenance(mut self, node: &Node) -> Self {
+        let provenance = node.attributes.get(PROVENANCE_ATTRIBUTE);
+        let field = |key: &str| {
+            provenance
+                .and_then(|p| p.get(key))
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+
+        self.document_id = node.meta.document_id;
+        self.chunk_index = node.meta.chunk_index;
+        self.ingested_at = Some(node.meta.created_at);
+        self.origin = field("source").or_else(|| match &node.meta.source {
+            Source::Url(location) | Source::File(location) => Some(location.clone()),
+            _ => None,
+        });
+        self.title = field("title");
+        self
+    }
 }
 
 /// Helper function to create a test node.
@@ -316,7 +494,9 @@ mod tests {
         assert_eq!(config.max_vector_results, 10);
         assert_eq!(config.max_graph_results, 20);
         assert_eq!(config.max_hops, 2);
-        assert_eq!(config.min_relevance, 0.5);
+        assert_eq!(config.min_relevance, None);
+        assert_eq!(config.min_confidence, 0.0);
+        assert_eq!(config.recency_weight, 0.0);
         assert!(config.deduplicate);
         assert_eq!(config.mode, RetrievalMode::Hybrid);
     }
@@ -330,7 +510,7 @@ mod tests {
 
         assert_eq!(config.mode, RetrievalMode::VectorOnly);
         assert_eq!(config.max_vector_results, 20);
-        assert_eq!(config.min_relevance, 0.7);
+        assert_eq!(config.min_relevance, Some(0.7));
         assert_eq!(config.max_hops, 3);
     }
 
@@ -360,6 +540,33 @@ mod tests {
         assert!(!result.is_empty());
     }
 
+    #[test]
+    fn test_context_source_provenance() {
+        let document_id = Uuid::new_v4();
+        let mut node = Node::new("Chunk text", NodeType::Concept)
+            .with_document_id(document_id)
+            .with_chunk_index(3);
+        node.attributes = serde_json::json!({
+            PROVENANCE_ATTRIBUTE: { "source": "https://example.com/guide", "title": "Guide" }
+        });
+
+        let source = ContextSource::new(node.id, "Chunk text".to_string(), 0.8, node.node_type)
+            .with_provenance(&node);
+        assert_eq!(source.document_id, Some(document_id));
+        assert_eq!(source.chunk_index, Some(3));
+        assert_eq!(source.ingested_at, Some(node.meta.created_at));
+        assert_eq!(source.origin.as_deref(), Some("https://example.com/guide"));
+        assert_eq!(source.title.as_deref(), Some("Guide"));
+
+        // Nodes without the attribute fall back to a file or URL source
+        let mut plain = Node::new("Note", NodeType::Fact);
+        plain.meta.source = Source::File("notes.md".to_string());
+        let source = ContextSource::new(plain.id, "Note".to_string(), 0.5, plain.node_type)
+            .with_provenance(&plain);
+        assert_eq!((source.document_id, source.title), (None, None));
+        assert_eq!(source.origin.as_deref(), Some("notes.md"));
+    }
+
     #[test]
     fn test_retrieval_result_empty() {
         let result = RetrievalResult::empty();
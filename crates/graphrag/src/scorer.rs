@@ -2,8 +2,18 @@
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 
+use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Amount a single feedback event moves a relation weight.
+const RELATION_FEEDBACK_STEP: f32 = 0.05;
+
+/// Lower bound for learned relation weights.
+const MIN_RELATION_WEIGHT: f32 = 0.1;
+
+/// Upper bound for learned relation weights.
+const MAX_RELATION_WEIGHT: f32 = 2.0;
+
 /// Relevance score for a node in the context.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RelevanceScore {
@@ -75,6 +85,9 @@ pub struct Scorer {
 
     /// Decay rate per hop (default: 0.5, i.e., half relevance per hop)
     pub hop_decay_rate: f32,
+
+    /// Per-relation multipliers learned from feedback (default: 1.0)
+    pub relation_weights: HashMap<String, f32>,
 }
 
 impl Scorer {
@@ -98,6 +111,35 @@ impl Scorer {
         self
     }
 
+    /// Set the weight for a relation type.
+    pub fn with_relation_weight(mut self, relation: impl Into<String>, weight: f32) -> Self {
+        self.relation_weights.insert(
+            relation.into(),
+            weight.clamp(MIN_RELATION_WEIGHT, MAX_RELATION_WEIGHT),
+        );
+        self
+    }
+
+    /// Get the weight for a relation type (1.0 if nothing was learned yet).
+    pub fn relation_weight(&self, relation: &str) -> f32 {
+        self.relation_weights.get(relation).copied().unwrap_or(1.0)
+    }
+
+    /// Nudge a relation weight up or down based on user feedback.
+    ///
+    /// Returns the updated weight.
+    pub fn record_relation_feedback(&mut self, relation: &str, useful: bool) -> f32 {
+        let step = if useful {
+            RELATION_FEEDBACK_STEP
+        } else {
+            -RELATION_FEEDBACK_STEP
+        };
+        let weight = (self.relation_weight(relation) + step)
+            .clamp(MIN_RELATION_WEIGHT, MAX_RELATION_WEIGHT);
+        self.relation_weights.insert(relation.to_string(), weight);
+        weight
+    }
+
     /// Score a direct vector match.
     pub fn score_direct(&self, node_id: Uuid, vector_similarity: f32) -> RelevanceScore {
         RelevanceScore::direct_match(node_id, vector_similarity)
@@ -125,6 +167,19 @@ impl Scorer {
         }
     }
 
+    /// Score a node reached by following an edge of the given relation type.
+    pub fn score_via_relation(
+        &self,
+        node_id: Uuid,
+        vector_similarity: f32,
+        hop_distance: usize,
+        relation: &str,
+    ) -> RelevanceScore {
+        let mut score = self.score_traversal(node_id, vector_similarity, hop_distance);
+        score.final_score = (score.final_score * self.relation_weight(relation)).clamp(0.0, 1.0);
+        score
+    }
+
     /// Score a node without vector similarity (graph-only).
     pub fn score_graph_only(&self, node_id: Uuid, hop_distance: usize) -> RelevanceScore {
         let graph_proximity = self.hop_decay_rate.powi(hop_distance as i32);
@@ -137,6 +192,19 @@ impl Scorer {
         }
     }
 
+    /// Score a node reached over a graph path whose edges carry the given
+    /// propagated confidence (the product of their strengths).
+    pub fn score_graph_path(
+        &self,
+        node_id: Uuid,
+        hop_distance: usize,
+        confidence: f32,
+    ) -> RelevanceScore {
+        let mut score = self.score_graph_only(node_id, hop_distance);
+        score.final_score = (score.final_score * confidence).clamp(0.0, 1.0);
+        score
+    }
+
     /// Re-rank nodes based on their scores.
     pub fn rerank(&self, mut scores: Vec<RelevanceScore>) -> Vec<RelevanceScore> {
         scores.sort_by(|a, b| {
@@ -212,4 +280,29 @@ mod tests {
         assert_eq!(scores[1].node_id, id3); // 0.7
         assert_eq!(scores[2].node_id, id1); // 0.5
     }
+
+    #[test]
+    fn test_relation_feedback_adjusts_weights() {
+        let mut scorer = Scorer::with_weights(0.6, 0.4).with_hop_decay(0.5);
+        assert_eq!(scorer.relation_weight("causes"), 1.0);
+
+        scorer.record_relation_feedback("causes", true);
+        scorer.record_relation_feedback("similar_to", false);
+        assert!(scorer.relation_weight("causes") > 1.0);
+        assert!(scorer.relation_weight("similar_to") < 1.0);
+
+        let id = Uuid::new_v4();
+        let boosted = scorer.score_via_relation(id, 0.5, 1, "causes");
+        let damped = scorer.score_via_relation(id, 0.5, 1, "similar_to");
+        assert!(boosted.final_score > damped.final_score);
+    }
+
+    #[test]
+    fn test_relation_weights_are_bounded() {
+        let mut scorer = Scorer::new();
+        for _ in 0..100 {
+            scorer.record_relation_feedback("is_a", false);
+        }
+        assert_eq!(scorer.relation_weight("is_a"), MIN_RELATION_WEIGHT);
+    }
 }
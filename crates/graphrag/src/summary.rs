@@ -9,9 +9,13 @@
 
 use crate::retrieval::RetrievedNode;
 use serde::{Deserialize, Serialize};
-use synton_core::NodeType;
+use synton_core::{Node, NodeType};
 use uuid::Uuid;
 
+/// Attribute key holding the position of an ingested chunk within its
+/// document's hierarchy, as `{"level": 0 | 1 | 2, ...}`.
+pub const CHUNK_ATTRIBUTE: &str = "chunk";
+
 /// Summary level for context selection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum SummaryLevel {
@@ -43,6 +47,23 @@ impl SummaryLevel {
             Self::Sentence => "sentence",
         }
     }
+
+    /// Level of a chunk hierarchy depth (0 = document).
+    pub fn from_depth(depth: usize) -> Option<Self> {
+        match depth {
+            0 => Some(Self::Document),
+            1 => Some(Self::Paragraph),
+            2 => Some(Self::Sentence),
+            _ => None,
+        }
+    }
+
+    /// Level of an ingested chunk node, or `None` if the node is not a
+    /// chunk.
+    pub fn of_chunk(node: &Node) -> Option<Self> {
+        let depth = node.attributes.get(CHUNK_ATTRIBUTE)?.get("level")?.as_u64()?;
+        Self::from_depth(depth as usize)
+    }
 }
 
 /// Configuration for summary selection.
@@ -410,6 +431,16 @@ mod tests {
         assert!(SummaryLevel::Paragraph < SummaryLevel::Sentence);
     }
 
+    #[test]
+    fn test_summary_level_of_chunk() {
+        let mut node = crate::retrieval::test_node("A sentence.");
+        assert_eq!(SummaryLevel::of_chunk(&node), None);
+
+        node.attributes = serde_json::json!({ CHUNK_ATTRIBUTE: { "level": 2 } });
+        assert_eq!(SummaryLevel::of_chunk(&node), Some(SummaryLevel::Sentence));
+        assert_eq!(SummaryLevel::from_depth(3), None);
+    }
+
     #[test]
     fn test_hierarchical_selector() {
         let selector = HierarchicalSelector::with_level(SummaryLevel::Paragraph);
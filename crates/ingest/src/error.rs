@@ -0,0 +1,54 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Error types for the ingestion connectors.
+
+use synton_error::{Coded, ErrorCode};
+
+/// Ingestion error type.
+#[derive(Debug, thiserror::Error)]
+pub enum IngestError {
+    /// The URL could not be parsed or uses an unsupported scheme.
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+
+    /// The request to the remote server failed.
+    #[error("Fetch failed: {0}")]
+    Fetch(String),
+
+    /// The remote server answered with an error status.
+    #[error("HTTP {status} fetching {url}")]
+    HttpStatus { status: u16, url: String },
+
+    /// The document is larger than the configured limit.
+    #[error("Document exceeds {limit} bytes")]
+    TooLarge { limit: usize },
+
+    /// The document format is not supported.
+    #[error("Unsupported document type: {0}")]
+    UnsupportedType(String),
+
+    /// The PDF could not be read.
+    #[error("PDF extraction failed: {0}")]
+    Pdf(String),
+
+    /// The document contains no extractable text.
+    #[error("No text could be extracted from the document")]
+    Empty,
+}
+
+impl Coded for IngestError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::InvalidUrl(_) | Self::UnsupportedType(_) | Self::Pdf(_) | Self::Empty => {
+                ErrorCode::InvalidInput
+            }
+            Self::TooLarge { .. } => ErrorCode::LimitExceeded,
+            Self::Fetch(_) | Self::HttpStatus { .. } => ErrorCode::Unavailable,
+        }
+    }
+}
+
+/// Result type alias for ingestion operations.
+pub type Result<T> = std::result::Result<T, IngestError>;
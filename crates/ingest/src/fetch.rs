@@ -0,0 +1,137 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! HTTP fetcher for web pages and remote documents.
+
+use std::time::Duration;
+
+use crate::error::{IngestError, Result};
+use crate::{extract, ExtractedDocument, MAX_DOCUMENT_BYTES};
+
+/// Default timeout for a fetch.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Downloads URLs and extracts their text.
+#[derive(Debug, Clone)]
+pub struct UrlFetcher {
+    client: reqwest::Client,
+    max_bytes: usize,
+}
+
+impl UrlFetcher {
+    /// Create a fetcher with the given request timeout.
+    pub fn new(timeout: Duration) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!("synton-db/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| IngestError::Fetch(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            max_bytes: MAX_DOCUMENT_BYTES,
+        })
+    }
+
+    /// Create a fetcher with the default 30 second timeout.
+    pub fn with_defaults() -> Result<Self> {
+        Self::new(DEFAULT_TIMEOUT)
+    }
+
+    /// Set the largest response body accepted.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Fetch a URL and extract its text.
+    ///
+    /// Only `http` and `https` URLs are accepted. The format is taken from
+    /// the `Content-Type` header, the URL path and the body itself.
+    pub async fn fetch(&self, url: &str) -> Result<ExtractedDocument> {
+        let parsed =
+            reqwest::Url::parse(url).map_err(|e| IngestError::InvalidUrl(e.to_string()))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(IngestError::InvalidUrl(format!(
+                "unsupported scheme '{}'",
+                parsed.scheme()
+            )));
+        }
+
+        let mut response = self
+            .client
+            .get(parsed.clone())
+            .header(
+                reqwest::header::ACCEPT,
+                "text/html,application/xhtml+xml,application/pdf,text/plain;q=0.9,*/*;q=0.5",
+            )
+            .send()
+            .await
+            .map_err(|e| IngestError::Fetch(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(IngestError::HttpStatus {
+                status: status.as_u16(),
+                url: url.to_string(),
+            });
+        }
+        if response
+            .content_length()
+            .is_some_and(|len| len > self.max_bytes as u64)
+        {
+            return Err(IngestError::TooLarge {
+                limit: self.max_bytes,
+            });
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        // The length header may be missing or wrong; enforce the limit on
+        // the body as it arrives
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| IngestError::Fetch(e.to_string()))?
+        {
+            if body.len() + chunk.len() > self.max_bytes {
+                return Err(IngestError::TooLarge {
+                    limit: self.max_bytes,
+                });
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        let name = parsed
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|s| !s.is_empty());
+        tracing::debug!("Fetched {} ({} bytes, {:?})", url, body.len(), content_type);
+
+        extract(&body, content_type.as_deref(), name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_rejects_bad_urls() {
+        let fetcher = UrlFetcher::with_defaults().unwrap();
+        assert!(matches!(
+            fetcher.fetch("not a url").await,
+            Err(IngestError::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            fetcher.fetch("file:///etc/passwd").await,
+            Err(IngestError::InvalidUrl(_))
+        ));
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Readability-style HTML to text conversion.
+//!
+//! Scripts, styles and page chrome (navigation, headers, footers, sidebars,
+//! forms) are dropped. When the page marks its main content with
+//! `<article>` or `<main>`, only that content is kept. Block elements become
+//! paragraph breaks and runs of whitespace collapse to single spaces.
+
+/// Elements whose content is never part of the readable text.
+const SKIPPED: &[&str] = &[
+    "script", "style", "noscript", "template", "svg", "iframe", "title", "nav", "header", "footer",
+    "aside", "form", "button", "select",
+];
+
+/// Elements whose content is raw text, not markup.
+const RAW_TEXT: &[&str] = &["script", "style", "textarea"];
+
+/// Elements that mark the main content of a page.
+const MAIN_CONTENT: &[&str] = &["article", "main"];
+
+/// Elements that start a new paragraph.
+const BLOCKS: &[&str] = &[
+    "p",
+    "div",
+    "section",
+    "article",
+    "main",
+    "li",
+    "ul",
+    "ol",
+    "dl",
+    "dt",
+    "dd",
+    "table",
+    "tr",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "blockquote",
+    "pre",
+    "figure",
+    "figcaption",
+    "hr",
+];
+
+/// Convert an HTML page to plain text.
+pub fn html_to_text(html: &str) -> String {
+    extract(html).1
+}
+
+/// Title and readable text of an HTML page.
+pub(crate) fn extract(html: &str) -> (Option<String>, String) {
+    let lower = html.to_ascii_lowercase();
+    let scoped = MAIN_CONTENT
+        .iter()
+        .any(|tag| lower.contains(&format!("<{}", tag)));
+
+    let mut title: Option<String> = None;
+    let mut heading: Option<String> = None;
+    let mut text = String::new();
+    let mut skip_depth = 0usize;
+    let mut main_depth = 0usize;
+    let mut capture: Option<(&str, String)> = None;
+    let mut pos = 0;
+
+    while pos < html.len() {
+        let Some(offset) = html[pos..].find('<') else {
+            push_text(
+                &mut text,
+                &mut capture,
+                &html[pos..],
+                skip_depth,
+                scoped && main_depth == 0,
+            );
+            break;
+        };
+        let start = pos + offset;
+        push_text(
+            &mut text,
+            &mut capture,
+            &html[pos..start],
+            skip_depth,
+            scoped && main_depth == 0,
+        );
+
+        let Some(tag) = parse_tag(html, &lower, start) else {
+            // A stray '<' is text
+            push_text(
+                &mut text,
+                &mut capture,
+                "<",
+                skip_depth,
+                scoped && main_depth == 0,
+            );
+            pos = start + 1;
+            continue;
+        };
+        pos = tag.end;
+
+        let name = tag.name.as_str();
+        if tag.closing {
+            if SKIPPED.contains(&name) {
+                skip_depth = skip_depth.saturating_sub(1);
+            }
+            if MAIN_CONTENT.contains(&name) {
+                main_depth = main_depth.saturating_sub(1);
+            }
+            if let Some((open, captured)) = capture.take() {
+                if open == name {
+                    let captured = collapse(&captured);
+                    if !captured.is_empty() {
+                        let slot = if name == "title" {
+                            &mut title
+                        } else {
+                            &mut heading
+                        };
+                        slot.get_or_insert(captured);
+                    }
+                } else {
+                    capture = Some((open, captured));
+                }
+            }
+        } else {
+            if name == "title" && title.is_none() {
+                capture = Some(("title", String::new()));
+            } else if name == "h1" && heading.is_none() && skip_depth == 0 {
+                capture = Some(("h1", String::new()));
+            }
+            if RAW_TEXT.contains(&name) && !tag.self_closing {
+                // Jump past the content; it may contain '<' that is not markup
+                let close = format!("</{}", name);
+                pos = lower[pos..].find(&close).map_or(html.len(), |i| pos + i);
+            }
+            if SKIPPED.contains(&name) && !tag.self_closing {
+                skip_depth += 1;
+            }
+            if MAIN_CONTENT.contains(&name) && !tag.self_closing {
+                main_depth += 1;
+            }
+        }
+
+        if name == "br" {
+            text.push('\n');
+        } else if BLOCKS.contains(&name) {
+            text.push_str("\n\n");
+        }
+    }
+
+    (title.or(heading), normalize(&text))
+}
+
+/// A parsed start or end tag.
+struct Tag {
+    name: String,
+    closing: bool,
+    self_closing: bool,
+    /// Byte offset just past the tag
+    end: usize,
+}
+
+/// Parse the tag starting at `start`, skipping comments and declarations.
+/// Returns `None` if the '<' does not start markup.
+fn parse_tag(html: &str, lower: &str, start: usize) -> Option<Tag> {
+    let rest = &html[start + 1..];
+    if rest.starts_with("!--") {
+        let end = rest.find("-->").map_or(html.len(), |i| start + 1 + i + 3);
+        return Some(Tag {
+            name: String::new(),
+            closing: false,
+            self_closing: true,
+            end,
+        });
+    }
+    if rest.starts_with('!') || rest.starts_with('?') {
+        let end = rest.find('>').map_or(html.len(), |i| start + 1 + i + 1);
+        return Some(Tag {
+            name: String::new(),
+            closing: false,
+            self_closing: true,
+            end,
+        });
+    }
+
+    let closing = rest.starts_with('/');
+    let name_start = start + 1 + usize::from(closing);
+    let name_len = lower[name_start..]
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(lower.len() - name_start);
+    if name_len == 0 || !lower[name_start..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    // Find the closing '>' outside quoted attribute values
+    let mut quote: Option<u8> = None;
+    let mut end = None;
+    for (i, b) in html.as_bytes()[name_start + name_len..].iter().enumerate() {
+        match (quote, b) {
+            (Some(q), b) if *b == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(*b),
+            (None, b'>') => {
+                end = Some(name_start + name_len + i);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let end = end.unwrap_or(html.len() - 1);
+
+    Some(Tag {
+        name: lower[name_start..name_start + name_len].to_string(),
+        closing,
+        self_closing: html[..end].ends_with('/'),
+        end: end + 1,
+    })
+}
+
+/// Append a run of text, decoding entities and collapsing whitespace.
+fn push_text(
+    text: &mut String,
+    capture: &mut Option<(&str, String)>,
+    raw: &str,
+    skip_depth: usize,
+    outside_main: bool,
+) {
+    if raw.is_empty() {
+        return;
+    }
+    let decoded = decode_entities(raw);
+    if let Some((_, captured)) = capture {
+        captured.push_str(&decoded);
+    }
+    if skip_depth > 0 || outside_main {
+        return;
+    }
+    for c in decoded.chars() {
+        if c.is_whitespace() {
+            if !text.ends_with(char::is_whitespace) && !text.is_empty() {
+                text.push(' ');
+            }
+        } else {
+            text.push(c);
+        }
+    }
+}
+
+/// Collapse whitespace to single spaces and trim.
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Trim lines and keep at most one blank line between paragraphs.
+fn normalize(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank = false;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            blank = !out.is_empty();
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank { "\n\n" } else { "\n" });
+        }
+        out.push_str(line);
+        blank = false;
+    }
+    out
+}
+
+/// Decode character references.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..1 + end]).map(|c| (c, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Decode a single entity name (without `&` and `;`).
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(num) = name.strip_prefix('#') {
+        let code = match num.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => num.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '\u{2013}',
+        "mdash" => '\u{2014}',
+        "lsquo" => '\u{2018}',
+        "rsquo" => '\u{2019}',
+        "ldquo" => '\u{201C}',
+        "rdquo" => '\u{201D}',
+        "hellip" => '\u{2026}',
+        "copy" => '\u{00A9}',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text_keeps_main_content() {
+        let html = r#"<!DOCTYPE html>
+            <html><head><title>Tesla &amp; the  grid</title>
+            <style>p { color: red; }</style>
+            <script>if (a < b) { document.write("<p>ad</p>"); }</script></head>
+            <body>
+              <nav><a href="/">Home</a> | <a href="/about">About</a></nav>
+              <article>
+                <h1>Battery storage</h1>
+                <p>Tesla builds   grid-scale<br>batteries.</p>
+                <!-- related links -->
+                <p class="note">Capacity &gt; 1&#160;GWh &#x2014; and growing.</p>
+              </article>
+              <footer>Copyright</footer>
+            </body></html>"#;
+
+        let (title, text) = extract(html);
+        assert_eq!(title.as_deref(), Some("Tesla & the grid"));
+        assert_eq!(
+            text,
+            "Battery storage\n\nTesla builds grid-scale\nbatteries.\n\nCapacity > 1 GWh \u{2014} and growing."
+        );
+    }
+
+    #[test]
+    fn test_html_without_main_element() {
+        let html =
+            "<div><h1>Notes</h1><p>First <b>point</b>.</p><p>Second &unknown; point</p></div>";
+        let (title, text) = extract(html);
+        assert_eq!(title.as_deref(), Some("Notes"));
+        assert_eq!(text, "Notes\n\nFirst point.\n\nSecond &unknown; point");
+        assert_eq!(html_to_text("a < b and c"), "a < b and c");
+    }
+}
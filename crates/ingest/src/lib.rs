@@ -0,0 +1,182 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Ingestion connectors for SYNTON-DB.
+//!
+//! Connectors turn web pages and files into plain text for the document
+//! pipeline: [`UrlFetcher`] downloads a URL and [`extract`] converts HTML,
+//! PDF or plain-text bytes. Chunking and embedding happen downstream.
+
+mod error;
+mod fetch;
+mod html;
+mod pdf;
+
+pub use error::{IngestError, Result};
+pub use fetch::UrlFetcher;
+pub use html::html_to_text;
+pub use pdf::pdf_to_text;
+
+/// Largest document accepted by the connectors (20 MiB).
+pub const MAX_DOCUMENT_BYTES: usize = 20 * 1024 * 1024;
+
+/// Format of a fetched or uploaded document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// HTML page
+    Html,
+    /// PDF file
+    Pdf,
+    /// Plain text (including Markdown)
+    Text,
+}
+
+impl DocumentFormat {
+    /// Detect the format from the content's leading bytes, its MIME type and
+    /// its file name, in that order of precedence.
+    pub fn detect(bytes: &[u8], content_type: Option<&str>, name: Option<&str>) -> Option<Self> {
+        if bytes.starts_with(b"%PDF-") {
+            return Some(Self::Pdf);
+        }
+
+        let mime = content_type
+            .and_then(|ct| ct.split(';').next())
+            .map(|m| m.trim().to_ascii_lowercase());
+        match mime.as_deref() {
+            Some("text/html" | "application/xhtml+xml") => return Some(Self::Html),
+            Some("application/pdf") => return Some(Self::Pdf),
+            Some(m) if m.starts_with("text/") => return Some(Self::Text),
+            _ => {}
+        }
+
+        let extension = name
+            .and_then(|n| n.rsplit_once('.'))
+            .map(|(_, ext)| ext.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("html" | "htm" | "xhtml") => return Some(Self::Html),
+            Some("pdf") => return Some(Self::Pdf),
+            Some("txt" | "md" | "markdown" | "rst" | "csv") => return Some(Self::Text),
+            _ => {}
+        }
+
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]).to_ascii_lowercase();
+        if head.contains("<html") || head.contains("<!doctype html") {
+            Some(Self::Html)
+        } else if std::str::from_utf8(bytes).is_ok() {
+            Some(Self::Text)
+        } else {
+            None
+        }
+    }
+
+    /// Format name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Pdf => "pdf",
+            Self::Text => "text",
+        }
+    }
+
+    /// MIME type of the format.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Html => "text/html",
+            Self::Pdf => "application/pdf",
+            Self::Text => "text/plain",
+        }
+    }
+}
+
+/// Text extracted from a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedDocument {
+    /// Title found in the document (HTML `<title>` or first `<h1>`, PDF
+    /// document information).
+    pub title: Option<String>,
+
+    /// Extracted text.
+    pub text: String,
+
+    /// Detected format.
+    pub format: DocumentFormat,
+}
+
+/// Extract the text of a document.
+///
+/// Fails with [`IngestError::UnsupportedType`] for binary formats other
+/// than PDF and with [`IngestError::Empty`] if no text is left.
+pub fn extract(
+    bytes: &[u8],
+    content_type: Option<&str>,
+    name: Option<&str>,
+) -> Result<ExtractedDocument> {
+    let format = DocumentFormat::detect(bytes, content_type, name).ok_or_else(|| {
+        IngestError::UnsupportedType(content_type.or(name).unwrap_or("binary data").to_string())
+    })?;
+
+    let (title, text) = match format {
+        DocumentFormat::Html => html::extract(&String::from_utf8_lossy(bytes)),
+        DocumentFormat::Pdf => pdf::extract(bytes)?,
+        DocumentFormat::Text => {
+            let text = String::from_utf8_lossy(bytes);
+            (None, text.trim_start_matches('\u{feff}').trim().to_string())
+        }
+    };
+
+    if text.trim().is_empty() {
+        return Err(IngestError::Empty);
+    }
+
+    Ok(ExtractedDocument {
+        title,
+        text,
+        format,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            DocumentFormat::detect(b"%PDF-1.7", Some("application/octet-stream"), None),
+            Some(DocumentFormat::Pdf)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"<p>hi</p>", Some("text/html; charset=utf-8"), None),
+            Some(DocumentFormat::Html)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"# Notes", None, Some("notes.md")),
+            Some(DocumentFormat::Text)
+        );
+        assert_eq!(
+            DocumentFormat::detect(b"<!DOCTYPE html><html>", None, Some("page")),
+            Some(DocumentFormat::Html)
+        );
+        assert_eq!(
+            DocumentFormat::detect(&[0xff, 0xd8, 0xff], Some("image/jpeg"), Some("scan.jpg")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract() {
+        let doc = extract(b"\xef\xbb\xbfPlain notes\n", None, Some("notes.txt")).unwrap();
+        assert_eq!(doc.text, "Plain notes");
+        assert_eq!(doc.format, DocumentFormat::Text);
+
+        assert!(matches!(
+            extract(&[0xff, 0xd8, 0xff], Some("image/jpeg"), None),
+            Err(IngestError::UnsupportedType(t)) if t == "image/jpeg"
+        ));
+        assert!(matches!(
+            extract(b"<html><script>x()</script></html>", None, None),
+            Err(IngestError::Empty)
+        ));
+    }
+}
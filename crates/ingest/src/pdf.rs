@@ -0,0 +1,455 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Text extraction from PDF files.
+//!
+//! Content streams (plain or `FlateDecode`-compressed) are scanned for the
+//! text-showing operators (`Tj`, `TJ`, `'`, `"`); text positioning
+//! operators become line breaks. Strings are read as PDFDocEncoding or
+//! UTF-16BE. Fonts with custom encodings (CID fonts without a standard
+//! encoding), scanned pages and encrypted files yield no usable text.
+
+use std::io::Read;
+
+use flate2::read::ZlibDecoder;
+
+use crate::error::{IngestError, Result};
+
+/// Extract the text of a PDF file.
+pub fn pdf_to_text(bytes: &[u8]) -> Result<String> {
+    extract(bytes).map(|(_, text)| text)
+}
+
+/// Title (from the document information dictionary) and text of a PDF.
+pub(crate) fn extract(bytes: &[u8]) -> Result<(Option<String>, String)> {
+    if !bytes.starts_with(b"%PDF-") {
+        return Err(IngestError::Pdf("missing %PDF header".to_string()));
+    }
+    if find(bytes, b"/Encrypt", 0).is_some() {
+        return Err(IngestError::Pdf(
+            "encrypted PDFs are not supported".to_string(),
+        ));
+    }
+
+    let mut text = String::new();
+    for content in content_streams(bytes) {
+        let page = show_text(&content);
+        if !page.trim().is_empty() {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&page);
+        }
+    }
+
+    let title = find(bytes, b"/Title", 0).and_then(|i| {
+        let mut lexer = Lexer::new(&bytes[i + b"/Title".len()..]);
+        match lexer.next_token()? {
+            Token::Str(s) => Some(decode_string(&s).trim().to_string()).filter(|t| !t.is_empty()),
+            _ => None,
+        }
+    });
+
+    Ok((title, normalize(&text)))
+}
+
+/// Decoded data of the streams that may hold page content, in file order.
+fn content_streams(bytes: &[u8]) -> Vec<Vec<u8>> {
+    let mut streams = Vec::new();
+    let mut pos = 0;
+
+    while let Some(keyword) = find(bytes, b"stream", pos) {
+        pos = keyword + b"stream".len();
+
+        // Only `>> stream` followed by an end of line starts stream data
+        let before = trim_end(&bytes[..keyword]);
+        if !before.ends_with(b">>") || keyword >= 3 && &bytes[keyword - 3..keyword] == b"end" {
+            continue;
+        }
+        let data_start = match &bytes[pos..] {
+            [b'\r', b'\n', ..] => pos + 2,
+            [b'\n', ..] | [b'\r', ..] => pos + 1,
+            _ => continue,
+        };
+        let Some(data_end) = find(bytes, b"endstream", data_start) else {
+            break;
+        };
+        pos = data_end + b"endstream".len();
+
+        let dict_start = rfind(&bytes[..keyword], b"obj").map_or(0, |i| i + 3);
+        let dict: Vec<u8> = bytes[dict_start..keyword]
+            .iter()
+            .copied()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect();
+        let data = trim_end(&bytes[data_start..data_end]);
+
+        // Images, fonts, metadata and cross-reference data hold no page text
+        let skipped: [&[u8]; 6] = [
+            b"/Subtype/Image",
+            b"/FontFile",
+            b"/Length1",
+            b"/Type/Metadata",
+            b"/Type/XRef",
+            b"/Type/ObjStm",
+        ];
+        if skipped.iter().any(|key| find(&dict, key, 0).is_some()) {
+            continue;
+        }
+
+        if find(&dict, b"/Filter", 0).is_none() {
+            streams.push(data.to_vec());
+        } else if find(&dict, b"/Filter/FlateDecode", 0).is_some()
+            || find(&dict, b"/Filter[/FlateDecode]", 0).is_some()
+        {
+            let mut decoded = Vec::new();
+            match ZlibDecoder::new(data).read_to_end(&mut decoded) {
+                Ok(_) => streams.push(decoded),
+                Err(e) => tracing::debug!("Skipping undecodable PDF stream: {}", e),
+            }
+        }
+    }
+
+    streams
+}
+
+/// Text shown by the operators of a content stream.
+fn show_text(content: &[u8]) -> String {
+    let mut text = String::new();
+    let mut operands: Vec<Token> = Vec::new();
+    let mut lexer = Lexer::new(content);
+
+    while let Some(token) = lexer.next_token() {
+        let Token::Operator(op) = token else {
+            operands.push(token);
+            continue;
+        };
+
+        match op.as_slice() {
+            b"Tj" => push_operand_text(&mut text, operands.last()),
+            b"'" | b"\"" => {
+                new_line(&mut text);
+                push_operand_text(&mut text, operands.last());
+            }
+            b"TJ" => {
+                for operand in &operands {
+                    match operand {
+                        Token::Str(s) => text.push_str(&decode_string(s)),
+                        // Large negative adjustments separate words
+                        Token::Number(n) if *n < -200.0 => space(&mut text),
+                        _ => {}
+                    }
+                }
+            }
+            b"T*" | b"ET" | b"Tm" => new_line(&mut text),
+            b"Td" | b"TD" => match operands.as_slice() {
+                [.., Token::Number(_), Token::Number(ty)] if *ty != 0.0 => new_line(&mut text),
+                _ => space(&mut text),
+            },
+            _ => {}
+        }
+        operands.clear();
+    }
+
+    text
+}
+
+/// Append the text of a string operand.
+fn push_operand_text(text: &mut String, operand: Option<&Token>) {
+    if let Some(Token::Str(s)) = operand {
+        text.push_str(&decode_string(s));
+    }
+}
+
+/// Start a new line unless the current one is empty.
+fn new_line(text: &mut String) {
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+}
+
+/// Separate words unless already separated.
+fn space(text: &mut String) {
+    if !text.is_empty() && !text.ends_with(char::is_whitespace) {
+        text.push(' ');
+    }
+}
+
+/// Decode a PDF string: UTF-16BE with a byte order mark, otherwise
+/// PDFDocEncoding (read as Latin-1). Control characters are dropped.
+fn decode_string(bytes: &[u8]) -> String {
+    let decoded = match bytes {
+        [0xFE, 0xFF, rest @ ..] => {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => bytes.iter().map(|b| char::from(*b)).collect(),
+    };
+    decoded
+        .chars()
+        .filter(|c| !c.is_control() || *c == '\n' || *c == '\t')
+        .collect()
+}
+
+/// Trim lines and drop empty ones.
+fn normalize(text: &str) -> String {
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Tokens of a content stream.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Str(Vec<u8>),
+    Operator(Vec<u8>),
+    /// Names, arrays delimiters, dictionaries and other operands
+    Other,
+}
+
+/// Minimal lexer for PDF content streams.
+struct Lexer<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        // Skip whitespace and comments
+        while let Some(b) = self.peek() {
+            if b.is_ascii_whitespace() || b == 0 {
+                self.pos += 1;
+            } else if b == b'%' {
+                while self.peek().is_some_and(|b| b != b'\n' && b != b'\r') {
+                    self.pos += 1;
+                }
+            } else {
+                break;
+            }
+        }
+
+        let b = self.peek()?;
+        self.pos += 1;
+        Some(match b {
+            b'(' => Token::Str(self.literal_string()),
+            b'<' if self.peek() == Some(b'<') => {
+                self.pos += 1;
+                Token::Other
+            }
+            b'<' => Token::Str(self.hex_string()),
+            b'>' if self.peek() == Some(b'>') => {
+                self.pos += 1;
+                Token::Other
+            }
+            b'[' | b']' | b'{' | b'}' | b'>' | b')' => Token::Other,
+            b'/' => {
+                self.regular();
+                Token::Other
+            }
+            _ => {
+                self.pos -= 1;
+                let word = self.regular();
+                match std::str::from_utf8(word)
+                    .ok()
+                    .and_then(|w| w.parse::<f64>().ok())
+                {
+                    Some(n) => Token::Number(n),
+                    None => Token::Operator(word.to_vec()),
+                }
+            }
+        })
+    }
+
+    /// Consume a run of regular characters.
+    fn regular(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while let Some(b) = self.peek() {
+            if b.is_ascii_whitespace() || b"()<>[]{}/%".contains(&b) {
+                break;
+            }
+            self.pos += 1;
+        }
+        // Always make progress, even on a stray delimiter
+        if self.pos == start {
+            self.pos += 1;
+        }
+        &self.data[start..self.pos]
+    }
+
+    /// Read a literal string after its opening parenthesis.
+    fn literal_string(&mut self) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut depth = 1;
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            match b {
+                b'(' => {
+                    depth += 1;
+                    out.push(b);
+                }
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                    out.push(b);
+                }
+                b'\\' => {
+                    let Some(escaped) = self.peek() else { break };
+                    self.pos += 1;
+                    match escaped {
+                        b'n' => out.push(b'\n'),
+                        b'r' => out.push(b'\r'),
+                        b't' => out.push(b'\t'),
+                        b'b' => out.push(0x08),
+                        b'f' => out.push(0x0C),
+                        b'0'..=b'7' => {
+                            let mut code = u32::from(escaped - b'0');
+                            for _ in 0..2 {
+                                match self.peek() {
+                                    Some(d @ b'0'..=b'7') => {
+                                        code = code * 8 + u32::from(d - b'0');
+                                        self.pos += 1;
+                                    }
+                                    _ => break,
+                                }
+                            }
+                            out.push(code as u8);
+                        }
+                        // Line continuation
+                        b'\r' => {
+                            if self.peek() == Some(b'\n') {
+                                self.pos += 1;
+                            }
+                        }
+                        b'\n' => {}
+                        other => out.push(other),
+                    }
+                }
+                _ => out.push(b),
+            }
+        }
+        out
+    }
+
+    /// Read a hexadecimal string after its opening angle bracket.
+    fn hex_string(&mut self) -> Vec<u8> {
+        let mut digits = Vec::new();
+        while let Some(b) = self.peek() {
+            self.pos += 1;
+            if b == b'>' {
+                break;
+            }
+            if let Some(d) = char::from(b).to_digit(16) {
+                digits.push(d as u8);
+            }
+        }
+        if digits.len() % 2 == 1 {
+            digits.push(0);
+        }
+        digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect()
+    }
+}
+
+/// Position of the first occurrence of `needle` at or after `from`.
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack
+        .get(from..)?
+        .windows(needle.len())
+        .position(|w| w == needle)
+        .map(|i| from + i)
+}
+
+/// Position of the last occurrence of `needle`.
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+/// Strip trailing whitespace.
+fn trim_end(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |i| i + 1);
+    &bytes[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn pdf(objects: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = b"%PDF-1.4\n".to_vec();
+        for (i, object) in objects.iter().enumerate() {
+            out.extend(format!("{} 0 obj\n", i + 1).as_bytes());
+            out.extend(object);
+            out.extend(b"\nendobj\n");
+        }
+        out.extend(b"trailer\n<< /Root 1 0 R >>\n%%EOF\n");
+        out
+    }
+
+    fn stream(dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut out = format!("<< {} /Length {} >>\nstream\n", dict, data.len()).into_bytes();
+        out.extend(data);
+        out.extend(b"\nendstream");
+        out
+    }
+
+    #[test]
+    fn test_pdf_text_extraction() {
+        let page1 =
+            b"BT /F1 12 Tf 72 720 Td (Battery storage) Tj 0 -14 Td (Tesla \\(TSLA\\) builds) Tj ET";
+        let page2 =
+            b"BT [(grid)-250(scale)] TJ T* <FEFF00620061007400740065007200690065007300> Tj ET";
+        let mut compressed = ZlibEncoder::new(Vec::new(), Compression::default());
+        compressed.write_all(page2).unwrap();
+
+        let bytes = pdf(&[
+            b"<< /Title (Grid report) /Author (Ops) >>".to_vec(),
+            stream("", page1),
+            stream("/Filter /FlateDecode", &compressed.finish().unwrap()),
+            stream("/Subtype /Image /Width 1 /Height 1", b"BT (not text) Tj ET"),
+        ]);
+
+        let (title, text) = extract(&bytes).unwrap();
+        assert_eq!(title.as_deref(), Some("Grid report"));
+        assert_eq!(
+            text,
+            "Battery storage\nTesla (TSLA) builds\ngrid scale\nbatteries"
+        );
+    }
+
+    #[test]
+    fn test_pdf_rejects_invalid_input() {
+        assert!(matches!(pdf_to_text(b"<html>"), Err(IngestError::Pdf(_))));
+
+        let encrypted = pdf(&[b"<< /Encrypt 2 0 R >>".to_vec()]);
+        assert!(matches!(pdf_to_text(&encrypted), Err(IngestError::Pdf(_))));
+
+        // A scanned page has no text to extract
+        let scanned = pdf(&[stream("/Subtype /Image /Filter /DCTDecode", b"\xff\xd8")]);
+        assert_eq!(pdf_to_text(&scanned).unwrap(), "");
+    }
+}
@@ -5,12 +5,128 @@
 //! - `#[checkpoint]` - Checkpoint instrumentation macro
 
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, FnArg, ItemFn};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{bracketed, parse_macro_input, DeriveInput, FnArg, Ident, ItemFn, LitInt, LitStr, Token};
+
+/// Options accepted by `#[trace(...)]`.
+#[derive(Debug, Default)]
+struct TraceArgs {
+    /// Span name (defaults to the function name)
+    name: Option<LitStr>,
+    /// Capture no arguments at all
+    skip_args: bool,
+    /// Arguments left out of the span metadata
+    skip: Vec<Ident>,
+    /// Span kind, parsed with `SpanKind::from`
+    kind: Option<LitStr>,
+    /// Maximum characters per captured argument (0 = unlimited)
+    max_arg_len: Option<LitInt>,
+}
+
+impl Parse for TraceArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            match key.to_string().as_str() {
+                "name" => set_once(&mut args.name, &key, parse_value(input)?)?,
+                "kind" => set_once(&mut args.kind, &key, parse_value(input)?)?,
+                "max_arg_len" => {
+                    let limit: LitInt = parse_value(input)?;
+                    limit.base10_parse::<usize>()?;
+                    set_once(&mut args.max_arg_len, &key, limit)?;
+                }
+                "skip_args" => {
+                    if args.skip_args {
+                        return Err(duplicate(&key));
+                    }
+                    args.skip_args = true;
+                }
+                "skip" => {
+                    if !args.skip.is_empty() {
+                        return Err(duplicate(&key));
+                    }
+                    input.parse::<Token![=]>()?;
+                    let content;
+                    bracketed!(content in input);
+                    let names = Punctuated::<SkipName, Token![,]>::parse_terminated(&content)?;
+                    args.skip = names.into_iter().map(|n| n.0).collect();
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown trace option `{}`; expected name, skip_args, skip, kind or max_arg_len",
+                            other
+                        ),
+                    ))
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        if args.skip_args && !args.skip.is_empty() {
+            return Err(syn::Error::new(
+                args.skip[0].span(),
+                "`skip` has no effect together with `skip_args`",
+            ));
+        }
+
+        Ok(args)
+    }
+}
+
+/// Argument name in a `skip` list, written as an identifier or a string.
+struct SkipName(Ident);
+
+impl Parse for SkipName {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let lit: LitStr = input.parse()?;
+            Ok(Self(lit.parse()?))
+        } else {
+            Ok(Self(input.parse()?))
+        }
+    }
+}
+
+/// Parse `= <value>` after an option key.
+fn parse_value<T: Parse>(input: ParseStream) -> syn::Result<T> {
+    input.parse::<Token![=]>()?;
+    input.parse()
+}
+
+fn set_once<T>(slot: &mut Option<T>, key: &Ident, value: T) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(duplicate(key));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn duplicate(key: &Ident) -> syn::Error {
+    syn::Error::new(key.span(), format!("duplicate trace option `{}`", key))
+}
 
 /// Function-level instrumentation macro.
 ///
+/// Arguments are recorded in the span metadata with `{:?}`, cut to
+/// `DEFAULT_MAX_ARG_LEN` characters. Options:
+///
+/// - `name = "..."`: span name (defaults to the function name)
+/// - `skip_args`: record no arguments
+/// - `skip = [a, b]`: leave out the listed arguments
+/// - `kind = "..."`: span kind, e.g. `"database_query"`
+/// - `max_arg_len = N`: maximum characters per argument (0 = unlimited)
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -20,10 +136,26 @@ use syn::{parse_macro_input, DeriveInput, FnArg, ItemFn};
 ///     Ok(x + 1)
 /// }
 /// ```
+///
+/// With options:
+/// ```rust,ignore
+/// #[trace(name = "login", skip = [password], kind = "external_call", max_arg_len = 64)]
+/// async fn authenticate(user: &str, password: &str) -> Result<Token> {
+///     // ...
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn trace(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn trace(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as TraceArgs);
     let input = parse_macro_input!(input as ItemFn);
 
+    match expand_trace(args, input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+fn expand_trace(args: TraceArgs, input: ItemFn) -> syn::Result<TokenStream2> {
     // Extract function details
     let fn_vis = input.vis.clone();
     let fn_sig = input.sig.clone();
@@ -32,6 +164,10 @@ pub fn trace(_args: TokenStream, input: TokenStream) -> TokenStream {
 
     let fn_name = &fn_sig.ident;
     let fn_name_str = fn_name.to_string();
+    let span_name = args
+        .name
+        .clone()
+        .unwrap_or_else(|| LitStr::new(&fn_name_str, Span::call_site()));
 
     // Extract parameters for recording
     let param_names: Vec<&proc_macro2::Ident> = fn_sig
@@ -49,43 +185,77 @@ pub fn trace(_args: TokenStream, input: TokenStream) -> TokenStream {
         })
         .collect();
 
+    if let Some(unknown) = args.skip.iter().find(|s| !param_names.contains(s)) {
+        return Err(syn::Error::new(
+            unknown.span(),
+            format!("`{}` is not an argument of `{}`", unknown, fn_name_str),
+        ));
+    }
+
+    let max_arg_len = match &args.max_arg_len {
+        Some(limit) => quote! { #limit },
+        None => quote! { synton_instrument::DEFAULT_MAX_ARG_LEN },
+    };
+
     // Build parameter recording for metadata
-    let metadata_fields: Vec<TokenStream2> = param_names
-        .iter()
-        .map(|name| {
-            let name_str = name.to_string();
-            quote! {
-                args.insert(#name_str.to_string(), std::format!("{:?}", &#name).into());
-            }
-        })
-        .collect();
+    let metadata_fields: Vec<TokenStream2> = if args.skip_args {
+        Vec::new()
+    } else {
+        param_names
+            .iter()
+            .filter(|name| !args.skip.contains(name))
+            .map(|name| {
+                let name_str = name.to_string();
+                quote! {
+                    args_metadata.insert(
+                        #name_str.to_string(),
+                        synton_instrument::format_arg(&#name, #max_arg_len),
+                    );
+                }
+            })
+            .collect()
+    };
+
+    let kind = match &args.kind {
+        Some(kind) => quote! { synton_instrument::SpanKind::from(#kind) },
+        None => quote! { synton_instrument::SpanKind::Function },
+    };
+
+    let metadata = quote! {
+        synton_instrument::SpanMetadata::new(
+            #fn_name_str.to_string(),
+            module_path!().to_string(),
+            file!().to_string(),
+            line!() as u32,
+            #kind,
+        )
+    };
+    let metadata = if metadata_fields.is_empty() {
+        metadata
+    } else {
+        quote! {{
+            let mut args_metadata = std::collections::HashMap::new();
+            #(#metadata_fields)*
+            #metadata.with_args(args_metadata)
+        }}
+    };
 
-    // Check if function is async
-    let is_async = fn_sig.asyncness.is_some();
+    let enter = quote! {
+        let parent_id = synton_instrument::TraceCollector::parent_span_id();
+
+        let _guard = synton_instrument::TraceCollector::global().enter_span(
+            #span_name.to_string(),
+            parent_id,
+            #metadata,
+        );
+    };
 
     // Build the instrumented function
-    let expanded = if is_async {
+    let expanded = if fn_sig.asyncness.is_some() {
         quote! {
             #(#fn_attrs)*
             #fn_vis #fn_sig {
-                // Create span metadata
-                let mut args_metadata = std::collections::HashMap::new();
-                #(#metadata_fields)*
-
-                let span_id = synton_instrument::TraceCollector::current_span_id();
-                let parent_id = synton_instrument::TraceCollector::parent_span_id();
-
-                let _guard = synton_instrument::TraceCollector::global().enter_span(
-                    #fn_name_str.to_string(),
-                    parent_id,
-                    synton_instrument::SpanMetadata::new(
-                        #fn_name_str.to_string(),
-                        module_path!().to_string(),
-                        file!().to_string(),
-                        line!() as u32,
-                        synton_instrument::SpanKind::Function,
-                    ).with_args(args_metadata),
-                );
+                #enter
 
                 async move {
                     #fn_block
@@ -96,31 +266,14 @@ pub fn trace(_args: TokenStream, input: TokenStream) -> TokenStream {
         quote! {
             #(#fn_attrs)*
             #fn_vis #fn_sig {
-                // Create span metadata
-                let mut args_metadata = std::collections::HashMap::new();
-                #(#metadata_fields)*
-
-                let span_id = synton_instrument::TraceCollector::current_span_id();
-                let parent_id = synton_instrument::TraceCollector::parent_span_id();
-
-                let _guard = synton_instrument::TraceCollector::global().enter_span(
-                    #fn_name_str.to_string(),
-                    parent_id,
-                    synton_instrument::SpanMetadata::new(
-                        #fn_name_str.to_string(),
-                        module_path!().to_string(),
-                        file!().to_string(),
-                        line!() as u32,
-                        synton_instrument::SpanKind::Function,
-                    ).with_args(args_metadata),
-                );
+                #enter
 
                 #fn_block
             }
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
 
 /// Checkpoint instrumentation macro.
@@ -183,3 +336,58 @@ pub fn derive_trace_metadata(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(tokens: TokenStream2) -> syn::Result<TraceArgs> {
+        syn::parse2(tokens)
+    }
+
+    #[test]
+    fn test_parse_trace_options() {
+        let args = parse(quote! {
+            name = "load", skip = [password, "token"], kind = "database_query", max_arg_len = 64
+        })
+        .unwrap();
+        assert_eq!(args.name.unwrap().value(), "load");
+        assert_eq!(args.skip, vec!["password", "token"]);
+        assert_eq!(args.kind.unwrap().value(), "database_query");
+        assert_eq!(args.max_arg_len.unwrap().base10_parse::<usize>().unwrap(), 64);
+        assert!(!args.skip_args);
+
+        assert!(parse(quote! { skip_args }).unwrap().skip_args);
+        assert!(parse(quote! {}).unwrap().name.is_none());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_options() {
+        let err = parse(quote! { level = "debug" }).err().unwrap();
+        assert!(err.to_string().contains("unknown trace option `level`"));
+        assert!(parse(quote! { name = "a", name = "b" }).is_err());
+        assert!(parse(quote! { max_arg_len = -1 }).is_err());
+        assert!(parse(quote! { skip_args, skip = [x] }).is_err());
+    }
+
+    #[test]
+    fn test_expand_skips_arguments() {
+        let item: ItemFn = syn::parse2(quote! {
+            fn login(user: &str, password: &str) {}
+        })
+        .unwrap();
+        let expanded = expand_trace(parse(quote! { skip = [password] }).unwrap(), item.clone())
+            .unwrap()
+            .to_string();
+        assert!(expanded.contains("\"user\""));
+        assert!(!expanded.contains("\"password\""));
+
+        let expanded = expand_trace(parse(quote! { skip_args }).unwrap(), item.clone())
+            .unwrap()
+            .to_string();
+        assert!(!expanded.contains("with_args"));
+
+        let err = expand_trace(parse(quote! { skip = [pasword] }).unwrap(), item).unwrap_err();
+        assert!(err.to_string().contains("`pasword` is not an argument of `login`"));
+    }
+}
@@ -1,14 +1,16 @@
 //! Trace collector for aggregating and managing span data.
 
 use std::collections::HashMap;
+use std::io::{self, Write};
 use std::sync::RwLock;
 
 use dashmap::DashMap;
 use uuid::Uuid;
 
-use crate::span::{SpanId, SpanStatus, TraceEvent, TraceId, TraceSpan};
+use crate::context::TraceContext;
+use crate::span::{SpanId, SpanKind, SpanMetadata, SpanStatus, TraceEvent, TraceId, TraceSpan};
 use crate::statistics::StatisticsManager;
-use crate::views::{LifecycleView, Statistics, TimelineView};
+use crate::views::{self, LifecycleView, Statistics, StreamOptions, TimelineView};
 
 /// Global trace collector instance.
 static GLOBAL_COLLECTOR: once_cell::sync::Lazy<TraceCollector> =
@@ -20,6 +22,9 @@ const DEFAULT_MAX_SPANS: usize = 10_000;
 /// Maximum number of events to keep in memory.
 const DEFAULT_MAX_EVENTS: usize = 100_000;
 
+/// Finished spans buffered per live subscriber before it starts lagging.
+const LIVE_CHANNEL_CAPACITY: usize = 1024;
+
 /// Configuration for the trace collector.
 #[derive(Debug, Clone)]
 pub struct CollectorConfig {
@@ -83,6 +88,9 @@ pub struct TraceCollector {
 
     /// Statistics manager.
     statistics: RwLock<StatisticsManager>,
+
+    /// Finished spans, sent to live subscribers.
+    live: tokio::sync::broadcast::Sender<TraceSpan>,
 }
 
 impl TraceCollector {
@@ -99,6 +107,7 @@ impl TraceCollector {
             events: RwLock::new(Vec::new()),
             local_spans: thread_local::ThreadLocal::new(),
             statistics: RwLock::new(StatisticsManager::new()),
+            live: tokio::sync::broadcast::channel(LIVE_CHANNEL_CAPACITY).0,
         }
     }
 
@@ -160,6 +169,9 @@ impl TraceCollector {
     }
 
     /// Enter a new span.
+    ///
+    /// When called inside a [`TraceContext::scope`], the span is tagged with
+    /// the request's trace ID and defaults to the request span as its parent.
     pub fn enter_span(
         &self,
         name: String,
@@ -167,6 +179,8 @@ impl TraceCollector {
         metadata: crate::span::SpanMetadata,
     ) -> SpanId {
         let span_id = crate::span::new_span_id();
+        let context = TraceContext::current();
+        let parent_id = parent_id.or_else(|| context.and_then(|ctx| ctx.span_id));
 
         // Update parent's children if applicable
         if let Some(parent) = parent_id {
@@ -179,6 +193,7 @@ impl TraceCollector {
         let span = TraceSpan {
             id: span_id,
             parent_id,
+            trace_id: context.map(|ctx| ctx.trace_id),
             name: name.clone(),
             start_time: chrono::Utc::now(),
             end_time: None,
@@ -214,6 +229,44 @@ impl TraceCollector {
         span_id
     }
 
+    /// Start the root span for an incoming request.
+    ///
+    /// Returns the context with the request span set; run the request inside
+    /// [`TraceContext::scope`] and finish the span with [`Self::complete_span`]
+    /// or [`Self::fail_span`].
+    pub fn start_request(&self, context: TraceContext, name: impl Into<String>) -> TraceContext {
+        let name = name.into();
+        let metadata = SpanMetadata::new(
+            name.clone(),
+            module_path!().to_string(),
+            file!().to_string(),
+            line!(),
+            SpanKind::Custom("request".to_string()),
+        );
+
+        let span_id = context.scope_sync(|| self.enter_span(name, None, metadata));
+        context.with_span_id(span_id)
+    }
+
+    /// Subscribe to spans as they complete or fail.
+    ///
+    /// Only spans finished after subscribing are received. A subscriber that
+    /// falls more than 1024 spans behind skips the oldest ones and gets
+    /// [`tokio::sync::broadcast::error::RecvError::Lagged`].
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<TraceSpan> {
+        self.live.subscribe()
+    }
+
+    /// Send a finished span to live subscribers, if there are any.
+    fn publish(&self, span_id: SpanId) {
+        if self.live.receiver_count() == 0 {
+            return;
+        }
+        if let Some(span) = self.spans.get(&span_id) {
+            let _ = self.live.send(span.clone());
+        }
+    }
+
     /// Complete a span with a result.
     pub fn complete_span(&self, span_id: SpanId, result: Option<serde_json::Value>, duration_ms: f64) {
         // Update span
@@ -223,6 +276,7 @@ impl TraceCollector {
             span.status = SpanStatus::Completed;
             span.result = result;
         }
+        self.publish(span_id);
 
         // Record exit event
         if let Ok(mut events) = self.events.write() {
@@ -252,6 +306,7 @@ impl TraceCollector {
             span.duration_ms = Some(duration_ms);
             span.status = SpanStatus::Failed(error.clone());
         }
+        self.publish(span_id);
 
         // Record error event
         if let Ok(mut events) = self.events.write() {
@@ -309,6 +364,35 @@ impl TraceCollector {
         self.spans.iter().map(|s| s.clone()).collect()
     }
 
+    /// Get all spans recorded for a request-level trace ID.
+    pub fn trace_spans(&self, trace_id: TraceId) -> Vec<TraceSpan> {
+        self.spans
+            .iter()
+            .filter(|s| s.trace_id == Some(trace_id))
+            .map(|s| s.clone())
+            .collect()
+    }
+
+    /// Resolve an ID to the root span of its trace.
+    ///
+    /// Accepts either a span ID or a request-level trace ID; for the latter,
+    /// the earliest span of the trace without a parent in the trace is used.
+    pub fn resolve_root(&self, id: TraceId) -> Option<SpanId> {
+        if self.spans.contains_key(&id) {
+            return Some(id);
+        }
+
+        let spans = self.trace_spans(id);
+        spans
+            .iter()
+            .filter(|s| {
+                s.parent_id
+                    .map_or(true, |parent| !spans.iter().any(|other| other.id == parent))
+            })
+            .min_by_key(|s| s.start_time)
+            .map(|s| s.id)
+    }
+
     /// Get all root spans (spans without parents).
     pub fn root_spans(&self) -> Vec<TraceSpan> {
         self.spans
@@ -328,6 +412,9 @@ impl TraceCollector {
 
     /// Get events for a specific trace.
     pub fn trace_events(&self, root_id: SpanId) -> Vec<TraceEvent> {
+        let Some(root_id) = self.resolve_root(root_id) else {
+            return Vec::new();
+        };
         let all_spans = self.collect_trace_tree(root_id);
         let span_ids: std::collections::HashSet<SpanId> =
             all_spans.iter().map(|s| s.id).collect();
@@ -367,6 +454,7 @@ impl TraceCollector {
 
     /// Get lifecycle view for a trace.
     pub fn get_lifecycle(&self, root_id: SpanId) -> Option<LifecycleView> {
+        let root_id = self.resolve_root(root_id)?;
         let spans = self.collect_trace_tree(root_id);
 
         if spans.is_empty() {
@@ -419,6 +507,7 @@ impl TraceCollector {
 
     /// Get timeline view for a trace.
     pub fn get_timeline(&self, root_id: SpanId) -> Option<TimelineView> {
+        let root_id = self.resolve_root(root_id)?;
         let spans = self.collect_trace_tree(root_id);
 
         if spans.is_empty() {
@@ -473,26 +562,45 @@ impl TraceCollector {
 
     /// Export trace data as Mermaid flowchart.
     pub fn export_mermaid(&self, root_id: SpanId) -> Option<String> {
-        let spans = self.collect_trace_tree(root_id);
-        if spans.is_empty() {
-            return None;
-        }
-
-        let mut mermaid = String::from("flowchart TD\n");
+        let mut mermaid = Vec::new();
+        self.write_mermaid(root_id, &mut mermaid, StreamOptions::default())
+            .ok()?;
+        String::from_utf8(mermaid).ok()
+    }
 
-        for span in &spans {
-            let node_id = format!("N{}", span.id.to_string().replace('-', ""));
-            let label = format!("{}[\"{}\"]", node_id, span.name);
+    /// Stream trace data as lifecycle JSON to `writer`.
+    ///
+    /// Unlike [`export_json`](Self::export_json), the lifecycle tree is not
+    /// built in memory. Returns the number of spans written.
+    pub fn write_json<W: Write>(
+        &self,
+        root_id: SpanId,
+        writer: &mut W,
+        options: StreamOptions,
+    ) -> io::Result<usize> {
+        let root = self.trace_root(root_id)?;
+        views::write_lifecycle_json(writer, root, |id| self.get_span(id), options)
+    }
 
-            if let Some(parent_id) = span.parent_id {
-                let parent_node = format!("N{}", parent_id.to_string().replace('-', ""));
-                mermaid.push_str(&format!("    {} --> {}\n", parent_node, label));
-            } else {
-                mermaid.push_str(&format!("    {}\n", label));
-            }
-        }
+    /// Stream trace data as a Mermaid flowchart to `writer`. Returns the
+    /// number of spans written.
+    pub fn write_mermaid<W: Write>(
+        &self,
+        root_id: SpanId,
+        writer: &mut W,
+        options: StreamOptions,
+    ) -> io::Result<usize> {
+        let root = self.trace_root(root_id)?;
+        views::write_mermaid(writer, root, |id| self.get_span(id), options)
+    }
 
-        Some(mermaid)
+    /// Root span of a trace, for the streaming exporters.
+    fn trace_root(&self, root_id: SpanId) -> io::Result<TraceSpan> {
+        self.resolve_root(root_id)
+            .and_then(|root_id| self.get_span(root_id))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, format!("trace not found: {}", root_id))
+            })
     }
 
     /// Get the number of spans currently in memory.
@@ -0,0 +1,200 @@
+//! Request-scoped trace context propagation.
+//!
+//! A [`TraceContext`] carries the trace ID of an incoming request (taken from a
+//! W3C `traceparent` header, an `x-trace-id` header, or freshly generated) and
+//! the span that currently represents the request. While a future runs inside
+//! [`TraceContext::scope`], every span entered on the [`TraceCollector`] is
+//! tagged with the trace ID and parented to the request span, so the whole
+//! request can be looked up by trace ID afterwards.
+//!
+//! [`TraceCollector`]: crate::TraceCollector
+
+use std::future::Future;
+
+use uuid::Uuid;
+
+use crate::span::{new_trace_id, SpanId, TraceId};
+
+/// Header carrying a plain trace ID (UUID or 32 hex digits).
+pub const TRACE_ID_HEADER: &str = "x-trace-id";
+
+/// W3C Trace Context header.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+tokio::task_local! {
+    static CURRENT_CONTEXT: TraceContext;
+}
+
+/// Trace context bound to a single request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceContext {
+    /// Trace ID shared by all spans of the request.
+    pub trace_id: TraceId,
+
+    /// Span representing the request, used as the default parent.
+    pub span_id: Option<SpanId>,
+}
+
+impl TraceContext {
+    /// Create a context with a freshly generated trace ID.
+    pub fn new() -> Self {
+        Self::with_trace_id(new_trace_id())
+    }
+
+    /// Create a context for an existing trace ID.
+    pub fn with_trace_id(trace_id: TraceId) -> Self {
+        Self {
+            trace_id,
+            span_id: None,
+        }
+    }
+
+    /// Set the request span.
+    pub fn with_span_id(mut self, span_id: SpanId) -> Self {
+        self.span_id = Some(span_id);
+        self
+    }
+
+    /// Parse a W3C `traceparent` value (`00-<trace-id>-<parent-id>-<flags>`).
+    pub fn from_traceparent(value: &str) -> Option<Self> {
+        let mut parts = value.trim().split('-');
+        let version = parts.next()?;
+        let trace_id = parts.next()?;
+        let parent_id = parts.next()?;
+        let flags = parts.next()?;
+
+        if version.len() != 2 || parent_id.len() != 16 || flags.len() != 2 {
+            return None;
+        }
+        if version == "ff" || !is_hex(version) || !is_hex(parent_id) || !is_hex(flags) {
+            return None;
+        }
+
+        let trace_id = parse_hex_trace_id(trace_id)?;
+        Some(Self::with_trace_id(trace_id))
+    }
+
+    /// Parse an `x-trace-id` value (hyphenated UUID or 32 hex digits).
+    pub fn from_trace_id(value: &str) -> Option<Self> {
+        let value = value.trim();
+        Uuid::parse_str(value)
+            .ok()
+            .filter(|id| !id.is_nil())
+            .or_else(|| parse_hex_trace_id(value))
+            .map(Self::with_trace_id)
+    }
+
+    /// Build a context from request headers, generating a new trace if neither
+    /// header is present or valid. `traceparent` takes precedence.
+    pub fn from_headers(traceparent: Option<&str>, trace_id: Option<&str>) -> Self {
+        traceparent
+            .and_then(Self::from_traceparent)
+            .or_else(|| trace_id.and_then(Self::from_trace_id))
+            .unwrap_or_default()
+    }
+
+    /// Format the context as a W3C `traceparent` value.
+    pub fn to_traceparent(&self) -> String {
+        let span_bits = self.span_id.map(|id| id.as_u64_pair().1).unwrap_or(0);
+        format!("00-{}-{:016x}-01", self.trace_id.simple(), span_bits)
+    }
+
+    /// Get the context of the currently running request, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT_CONTEXT.try_with(|ctx| *ctx).ok()
+    }
+
+    /// Run a future with this context as the current request context.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT_CONTEXT.scope(self, future).await
+    }
+
+    /// Run a closure with this context as the current request context.
+    pub fn scope_sync<R>(self, f: impl FnOnce() -> R) -> R {
+        CURRENT_CONTEXT.sync_scope(self, f)
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn is_hex(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Parse a 32-digit hex trace ID, rejecting the all-zero ID.
+fn parse_hex_trace_id(s: &str) -> Option<TraceId> {
+    if s.len() != 32 || !is_hex(s) {
+        return None;
+    }
+    let value = u128::from_str_radix(s, 16).ok()?;
+    if value == 0 {
+        return None;
+    }
+    Some(Uuid::from_u128(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_traceparent() {
+        let ctx = TraceContext::from_traceparent(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        )
+        .unwrap();
+        assert_eq!(ctx.trace_id.simple().to_string(), "4bf92f3577b34da6a3ce929d0e0e4736");
+
+        assert!(TraceContext::from_traceparent("garbage").is_none());
+        assert!(TraceContext::from_traceparent(
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_from_trace_id() {
+        let id = Uuid::new_v4();
+        assert_eq!(TraceContext::from_trace_id(&id.to_string()).unwrap().trace_id, id);
+        assert_eq!(
+            TraceContext::from_trace_id(&id.simple().to_string()).unwrap().trace_id,
+            id
+        );
+        assert!(TraceContext::from_trace_id("not-a-trace").is_none());
+    }
+
+    #[test]
+    fn test_from_headers_precedence() {
+        let id = Uuid::new_v4();
+        let traceparent = format!("00-{}-00f067aa0ba902b7-01", id.simple());
+        let other = Uuid::new_v4().to_string();
+
+        let ctx = TraceContext::from_headers(Some(&traceparent), Some(&other));
+        assert_eq!(ctx.trace_id, id);
+
+        // Falls back to a fresh trace ID
+        let ctx = TraceContext::from_headers(None, Some("bad"));
+        assert!(!ctx.trace_id.is_nil());
+    }
+
+    #[test]
+    fn test_traceparent_roundtrip() {
+        let ctx = TraceContext::new().with_span_id(Uuid::from_u64_pair(0, 42));
+        let header = ctx.to_traceparent();
+        assert!(header.ends_with("-000000000000002a-01"));
+        assert_eq!(TraceContext::from_traceparent(&header).unwrap().trace_id, ctx.trace_id);
+    }
+
+    #[tokio::test]
+    async fn test_scope() {
+        assert!(TraceContext::current().is_none());
+
+        let ctx = TraceContext::new();
+        let inner = ctx.scope(async { TraceContext::current() }).await;
+        assert_eq!(inner, Some(ctx));
+    }
+}
@@ -7,7 +7,7 @@
 //! - Checkpoint recording via `#[checkpoint]` macro
 //! - In-memory trace collection and aggregation
 //! - Lifecycle, timeline, and statistics views
-//! - Export to JSON and Mermaid formats
+//! - Export to JSON and Mermaid formats, streamed for large traces
 //!
 //! ## Quick Start
 //!
@@ -48,11 +48,17 @@
 //! #[trace(skip_args)]
 //! #[trace(skip = ["sensitive_arg"])]
 //! #[trace(kind = "database_query")]
+//! #[trace(max_arg_len = 64)]
 //! async fn query_db(conn: &str, sensitive_arg: &str) -> Result<Vec<u8>> {
 //!     // ...
 //! }
 //! ```
 //!
+//! Options can be combined in one attribute, e.g.
+//! `#[trace(name = "load", skip = [password], max_arg_len = 64)]`.
+//! Arguments are captured with `{:?}` and cut to [`DEFAULT_MAX_ARG_LEN`]
+//! characters unless `max_arg_len` says otherwise (0 keeps them whole).
+//!
 //! ### `#[checkpoint]`
 //!
 //! Record execution checkpoints:
@@ -68,18 +74,23 @@
 #![warn(clippy::all)]
 
 pub mod collector;
+pub mod context;
 pub mod span;
 pub mod statistics;
 pub mod views;
 
 // Re-export commonly used types
 pub use collector::{CollectorConfig, TraceCollector};
+pub use context::{TraceContext, TRACEPARENT_HEADER, TRACE_ID_HEADER};
 pub use span::{
-    new_span_id, new_trace_id, SpanId, SpanKind, SpanMetadata, SpanStatus, TraceEvent, TraceId,
-    TraceSpan,
+    format_arg, new_span_id, new_trace_id, SpanId, SpanKind, SpanMetadata, SpanStatus, TraceEvent,
+    TraceId, TraceSpan, DEFAULT_MAX_ARG_LEN,
 };
 pub use statistics::{SpanNameStats, StatisticsManager, TimeWindowStats, TraceMetadata};
-pub use views::{DashboardStats, DurationRecord, ExportFormat, LifecycleView, Statistics, TimelineView, TraceSummary};
+pub use views::{
+    DashboardStats, DurationRecord, ExportFormat, LifecycleView, Statistics, StreamOptions,
+    SubtreeSummary, TimelineView, TraceSummary,
+};
 
 // Re-export macros from the macro crate
 pub use synton_instrument_macros::{checkpoint, trace, TraceMetadata};
@@ -182,6 +193,64 @@ mod tests {
         assert!(mermaid.unwrap().starts_with("flowchart TD"));
     }
 
+    #[test]
+    fn test_trace_streaming_export() {
+        let collector = TraceCollector::new();
+        let metadata = || {
+            SpanMetadata::new(
+                "test".into(),
+                "module".into(),
+                "file.rs".into(),
+                1,
+                SpanKind::Function,
+            )
+        };
+
+        // root -> 3 batches -> 2 chunks each
+        let root = collector.enter_span("ingest".into(), None, metadata());
+        for _ in 0..3 {
+            let batch = collector.enter_span("batch".into(), Some(root), metadata());
+            for _ in 0..2 {
+                let chunk = collector.enter_span("chunk".into(), Some(batch), metadata());
+                collector.fail_span(chunk, "bad chunk".into(), 5.0);
+            }
+            collector.complete_span(batch, None, 20.0);
+        }
+        collector.complete_span(root, None, 100.0);
+
+        // Unlimited export matches the in-memory lifecycle
+        let mut out = Vec::new();
+        let written = collector
+            .write_json(root, &mut out, StreamOptions::default())
+            .unwrap();
+        assert_eq!(written, 10);
+        let streamed: LifecycleView = serde_json::from_slice(&out).unwrap();
+        assert_eq!(streamed.span_count(), 10);
+        assert_eq!(streamed.max_depth(), 3);
+
+        // Chunks are cut by depth and summarized under their batch
+        let mut out = Vec::new();
+        let options = StreamOptions::default().with_max_depth(2);
+        assert_eq!(collector.write_json(root, &mut out, options).unwrap(), 4);
+        let streamed: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let batch = &streamed["children"][0];
+        assert_eq!(batch["children"].as_array().unwrap().len(), 0);
+        assert_eq!(batch["truncated"]["span_count"], 2);
+        assert_eq!(batch["truncated"]["failed_count"], 2);
+        assert_eq!(batch["truncated"]["duration_ms"], 10.0);
+
+        // A span budget summarizes whatever is left under the root
+        let mut out = Vec::new();
+        let options = StreamOptions::default().with_max_spans(1);
+        assert_eq!(collector.write_mermaid(root, &mut out, options).unwrap(), 1);
+        let mermaid = String::from_utf8(out).unwrap();
+        assert!(mermaid.starts_with("flowchart TD"));
+        assert!(mermaid.contains("9 more spans, 6 failed, 60.0 ms"));
+
+        let missing = collector.write_json(new_span_id(), &mut Vec::new(), options);
+        assert_eq!(missing.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+    }
+
     #[test]
     fn test_statistics() {
         let collector = global_collector();
@@ -201,6 +270,70 @@ mod tests {
         assert_eq!(ExportFormat::Text.as_str(), "text");
     }
 
+    #[tokio::test]
+    async fn test_request_trace_lookup() {
+        let collector = TraceCollector::new();
+        let context = TraceContext::from_trace_id("4bf92f3577b34da6a3ce929d0e0e4736").unwrap();
+
+        let context = collector.start_request(context, "POST /nodes");
+        let request_span = context.span_id.unwrap();
+
+        let child = context
+            .scope(async {
+                let metadata = SpanMetadata::new(
+                    "add_node".into(),
+                    "module".into(),
+                    "file.rs".into(),
+                    1,
+                    SpanKind::Function,
+                );
+                collector.enter_span("add_node".into(), None, metadata)
+            })
+            .await;
+
+        let child_span = collector.get_span(child).unwrap();
+        assert_eq!(child_span.parent_id, Some(request_span));
+        assert_eq!(child_span.trace_id, Some(context.trace_id));
+
+        // The trace ID resolves to the request span
+        assert_eq!(collector.resolve_root(context.trace_id), Some(request_span));
+        let lifecycle = collector.get_lifecycle(context.trace_id).unwrap();
+        assert_eq!(lifecycle.name, "POST /nodes");
+        assert_eq!(lifecycle.children.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_live_subscription() {
+        let collector = TraceCollector::new();
+        let metadata = || {
+            SpanMetadata::new(
+                "query".into(),
+                "module".into(),
+                "file.rs".into(),
+                1,
+                SpanKind::DatabaseQuery,
+            )
+        };
+
+        // Spans finished before subscribing are not replayed
+        let early = collector.enter_span("early".into(), None, metadata());
+        collector.complete_span(early, None, 1.0);
+
+        let mut live = collector.subscribe();
+        let ok = collector.enter_span("get_node".into(), None, metadata());
+        let failed = collector.enter_span("put_node".into(), None, metadata());
+        collector.complete_span(ok, None, 12.5);
+        collector.fail_span(failed, "disk full".into(), 40.0);
+
+        let span = live.recv().await.unwrap();
+        assert_eq!((span.id, span.duration_ms), (ok, Some(12.5)));
+        assert!(span.status.is_success());
+        let span = live.recv().await.unwrap();
+        assert_eq!(span.id, failed);
+        assert_eq!(span.status, SpanStatus::Failed("disk full".into()));
+        assert!(live.try_recv().is_err());
+    }
+
     #[test]
     fn test_span_kind_conversion() {
         assert!(matches!(SpanKind::from("function"), SpanKind::Function));
@@ -148,6 +148,10 @@ pub struct TraceSpan {
     /// Parent span identifier, if any.
     pub parent_id: Option<SpanId>,
 
+    /// Request-level trace this span belongs to, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<TraceId>,
+
     /// Span name (typically function name).
     pub name: String,
 
@@ -196,6 +200,7 @@ impl TraceSpan {
         Self {
             id,
             parent_id,
+            trace_id: None,
             name,
             start_time: Utc::now(),
             end_time: None,
@@ -245,6 +250,7 @@ impl TraceSpan {
         Self {
             id,
             parent_id,
+            trace_id: None,
             name: name.into(),
             start_time: Utc::now(),
             end_time: None,
@@ -501,6 +507,25 @@ pub fn new_trace_id() -> TraceId {
     Uuid::new_v4()
 }
 
+/// Default limit on the length of a captured argument, in characters.
+pub const DEFAULT_MAX_ARG_LEN: usize = 256;
+
+/// Format an argument for span metadata.
+///
+/// The `{:?}` rendering is cut to `max_len` characters and marked with a
+/// trailing `…` when longer; a `max_len` of 0 keeps it whole. Used by the
+/// `#[trace]` macro.
+pub fn format_arg<T: std::fmt::Debug + ?Sized>(value: &T, max_len: usize) -> serde_json::Value {
+    let formatted = format!("{:?}", value);
+    if max_len == 0 {
+        return formatted.into();
+    }
+    match formatted.char_indices().nth(max_len) {
+        Some((end, _)) => format!("{}…", &formatted[..end]).into(),
+        None => formatted.into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -588,6 +613,17 @@ mod tests {
         assert_ne!(id1, id2);
     }
 
+    #[test]
+    fn test_format_arg_truncates() {
+        assert_eq!(format_arg(&42, 8), serde_json::json!("42"));
+        assert_eq!(format_arg("向量数据库", 4), serde_json::json!("\"向量数…"));
+
+        let payload = vec![0u8; 1000];
+        let captured = format_arg(&payload, DEFAULT_MAX_ARG_LEN);
+        assert_eq!(captured.as_str().unwrap().chars().count(), DEFAULT_MAX_ARG_LEN + 1);
+        assert_eq!(format_arg(&payload, 0).as_str().unwrap().len(), format!("{:?}", payload).len());
+    }
+
     #[test]
     fn test_new_trace_id() {
         let id1 = new_trace_id();
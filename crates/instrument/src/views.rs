@@ -1,9 +1,12 @@
 //! View types for trace data visualization.
 
+use std::collections::HashSet;
+use std::io::{self, Write};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::span::TraceEvent;
+use crate::span::{SpanId, TraceEvent, TraceSpan};
 
 /// Lifecycle view of a trace execution.
 ///
@@ -280,6 +283,272 @@ impl ExportFormat {
     }
 }
 
+/// Limits of a streaming trace export.
+///
+/// Spans past a limit are not written; each span whose children were left
+/// out carries a [`SubtreeSummary`] of them instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamOptions {
+    /// Deepest level written (the root is level 1), or `None` for no limit.
+    pub max_depth: Option<usize>,
+
+    /// Most spans written, or `None` for no limit.
+    pub max_spans: Option<usize>,
+}
+
+impl StreamOptions {
+    /// Limit the depth of the export.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth.max(1));
+        self
+    }
+
+    /// Limit the number of spans written.
+    pub fn with_max_spans(mut self, max_spans: usize) -> Self {
+        self.max_spans = Some(max_spans.max(1));
+        self
+    }
+}
+
+/// Summary of the children of a span left out of a streaming export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubtreeSummary {
+    /// Spans left out, including all their descendants.
+    pub span_count: usize,
+
+    /// Failed spans among them.
+    pub failed_count: usize,
+
+    /// Total duration of the left-out children in milliseconds.
+    pub duration_ms: f64,
+}
+
+/// A step of a depth-first walk over a trace.
+enum Visit<'a> {
+    /// A span is written; `first` is set for the first written child of
+    /// its parent (and for the root).
+    Open { span: &'a TraceSpan, first: bool },
+    /// All written children of a span are done.
+    Close { span: &'a TraceSpan, omitted: Option<SubtreeSummary> },
+}
+
+/// A span whose children are being walked.
+struct Frame {
+    span: TraceSpan,
+    depth: usize,
+    next_child: usize,
+    written_children: usize,
+    omitted: SubtreeSummary,
+}
+
+/// Walk a trace depth-first, one span at a time, fetching spans through
+/// `lookup`. Only the path from the root to the current span is held.
+fn walk_trace<F>(
+    root: TraceSpan,
+    lookup: F,
+    options: StreamOptions,
+    mut visit: impl FnMut(Visit<'_>) -> io::Result<()>,
+) -> io::Result<usize>
+where
+    F: Fn(SpanId) -> Option<TraceSpan>,
+{
+    let max_depth = options.max_depth.unwrap_or(usize::MAX);
+    let max_spans = options.max_spans.unwrap_or(usize::MAX);
+    let mut visited = HashSet::from([root.id]);
+    let mut written = 1;
+
+    visit(Visit::Open {
+        span: &root,
+        first: true,
+    })?;
+    let mut stack = vec![Frame {
+        span: root,
+        depth: 1,
+        next_child: 0,
+        written_children: 0,
+        omitted: SubtreeSummary::default(),
+    }];
+
+    while let Some(frame) = stack.last_mut() {
+        let Some(&child_id) = frame.span.children.get(frame.next_child) else {
+            let frame = stack.pop().expect("stack is not empty");
+            let omitted = (frame.omitted.span_count > 0).then_some(frame.omitted);
+            visit(Visit::Close {
+                span: &frame.span,
+                omitted,
+            })?;
+            continue;
+        };
+        frame.next_child += 1;
+
+        if !visited.insert(child_id) {
+            continue;
+        }
+        let Some(child) = lookup(child_id) else {
+            continue;
+        };
+
+        if frame.depth >= max_depth || written >= max_spans {
+            summarize_subtree(child, &lookup, &mut visited, &mut frame.omitted);
+            continue;
+        }
+
+        written += 1;
+        visit(Visit::Open {
+            span: &child,
+            first: frame.written_children == 0,
+        })?;
+        frame.written_children += 1;
+        let depth = frame.depth + 1;
+        stack.push(Frame {
+            span: child,
+            depth,
+            next_child: 0,
+            written_children: 0,
+            omitted: SubtreeSummary::default(),
+        });
+    }
+
+    Ok(written)
+}
+
+/// Add a left-out span and its descendants to `summary`.
+fn summarize_subtree<F>(
+    span: TraceSpan,
+    lookup: &F,
+    visited: &mut HashSet<SpanId>,
+    summary: &mut SubtreeSummary,
+) where
+    F: Fn(SpanId) -> Option<TraceSpan>,
+{
+    summary.duration_ms += span.duration_ms.unwrap_or(0.0);
+    let mut to_visit = vec![span];
+    while let Some(span) = to_visit.pop() {
+        summary.span_count += 1;
+        if span.status.is_failure() {
+            summary.failed_count += 1;
+        }
+        to_visit.extend(
+            span.children
+                .iter()
+                .filter(|id| visited.insert(**id))
+                .filter_map(|id| lookup(*id)),
+        );
+    }
+}
+
+/// Fields of a span in a [`LifecycleView`], without its children.
+#[derive(Serialize)]
+struct LifecycleFields<'a> {
+    id: String,
+    name: &'a str,
+    start_time: DateTime<Utc>,
+    end_time: Option<DateTime<Utc>>,
+    duration_ms: f64,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<&'a serde_json::Value>,
+}
+
+/// Stream a trace to `writer` as [`LifecycleView`] JSON, fetching spans
+/// through `lookup`.
+///
+/// The whole tree is never built, so traces of any size can be exported.
+/// A span whose children were cut by `options` gets a `truncated` field
+/// holding their [`SubtreeSummary`]. Returns the number of spans written.
+pub fn write_lifecycle_json<W, F>(
+    writer: &mut W,
+    root: TraceSpan,
+    lookup: F,
+    options: StreamOptions,
+) -> io::Result<usize>
+where
+    W: Write,
+    F: Fn(SpanId) -> Option<TraceSpan>,
+{
+    walk_trace(root, lookup, options, |visit| match visit {
+        Visit::Open { span, first } => {
+            if !first {
+                writer.write_all(b",")?;
+            }
+            let fields = LifecycleFields {
+                id: span.id.to_string(),
+                name: &span.name,
+                start_time: span.start_time,
+                end_time: span.end_time,
+                duration_ms: span.duration_ms.unwrap_or(0.0),
+                status: format!("{:?}", span.status),
+                args: span
+                    .metadata
+                    .args
+                    .as_ref()
+                    .and_then(|a| serde_json::to_value(a).ok()),
+                result: span.result.as_ref(),
+            };
+            let object = serde_json::to_vec(&fields)?;
+            // Reopen the object to append the children
+            writer.write_all(&object[..object.len() - 1])?;
+            writer.write_all(b",\"children\":[")
+        }
+        Visit::Close { omitted, .. } => {
+            writer.write_all(b"]")?;
+            if let Some(omitted) = omitted {
+                writer.write_all(b",\"truncated\":")?;
+                serde_json::to_writer(&mut *writer, &omitted)?;
+            }
+            writer.write_all(b"}")
+        }
+    })
+}
+
+/// Stream a trace to `writer` as a Mermaid flowchart, fetching spans
+/// through `lookup`.
+///
+/// Children cut by `options` are drawn as a single summary node under
+/// their parent. Returns the number of spans written.
+pub fn write_mermaid<W, F>(
+    writer: &mut W,
+    root: TraceSpan,
+    lookup: F,
+    options: StreamOptions,
+) -> io::Result<usize>
+where
+    W: Write,
+    F: Fn(SpanId) -> Option<TraceSpan>,
+{
+    writer.write_all(b"flowchart TD\n")?;
+    walk_trace(root, lookup, options, |visit| match visit {
+        Visit::Open { span, .. } => {
+            let label = format!("{}[\"{}\"]", mermaid_node_id(span.id), span.name);
+            match span.parent_id {
+                Some(parent_id) => {
+                    writeln!(writer, "    {} --> {}", mermaid_node_id(parent_id), label)
+                }
+                None => writeln!(writer, "    {}", label),
+            }
+        }
+        Visit::Close {
+            span,
+            omitted: Some(omitted),
+        } => {
+            let node_id = mermaid_node_id(span.id);
+            writeln!(
+                writer,
+                "    {} -.-> {}_more[\"{} more spans, {} failed, {:.1} ms\"]",
+                node_id, node_id, omitted.span_count, omitted.failed_count, omitted.duration_ms
+            )
+        }
+        Visit::Close { .. } => Ok(()),
+    })
+}
+
+/// Mermaid node identifier of a span.
+fn mermaid_node_id(id: SpanId) -> String {
+    format!("N{}", id.to_string().replace('-', ""))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -13,11 +13,15 @@ use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use crate::{McpError, McpResult};
-use synton_core::{Edge, Node, NodeType, Relation};
+use synton_core::{Edge, EdgeCreator, EdgeProvenance, Node, NodeType, Relation};
+use synton_error::ErrorBody;
 
 /// Default endpoint for SYNTON-DB REST API.
 pub const DEFAULT_ENDPOINT: &str = "http://localhost:8080";
 
+/// Header used to correlate requests with server-side trace spans.
+pub const TRACE_ID_HEADER: &str = "x-trace-id";
+
 /// HTTP client for SYNTON-DB.
 #[derive(Clone)]
 pub struct SyntonDbClient {
@@ -27,6 +31,8 @@ pub struct SyntonDbClient {
     endpoint: String,
     /// In-memory node cache.
     cache: Arc<RwLock<lru::LruCache<String, serde_json::Value>>>,
+    /// Trace ID sent with every request, if any.
+    trace_id: Option<Uuid>,
 }
 
 impl SyntonDbClient {
@@ -43,6 +49,17 @@ impl SyntonDbClient {
             cache: Arc::new(RwLock::new(lru::LruCache::new(
                 std::num::NonZeroUsize::new(1024).unwrap(),
             ))),
+            trace_id: None,
+        }
+    }
+
+    /// Create a client that tags its requests with the given trace ID.
+    ///
+    /// The clone shares the HTTP connection pool and node cache.
+    pub fn with_trace_id(&self, trace_id: Uuid) -> Self {
+        Self {
+            trace_id: Some(trace_id),
+            ..self.clone()
         }
     }
 
@@ -51,18 +68,47 @@ impl SyntonDbClient {
         &self.endpoint
     }
 
+    /// Get the trace ID sent with requests, if any.
+    pub fn trace_id(&self) -> Option<Uuid> {
+        self.trace_id
+    }
+
+    /// Build a GET request, attaching the trace ID header.
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        self.traced(self.client.get(url))
+    }
+
+    /// Build a POST request, attaching the trace ID header.
+    fn post(&self, url: &str) -> reqwest::RequestBuilder {
+        self.traced(self.client.post(url))
+    }
+
+    /// Build a PATCH request, attaching the trace ID header.
+    fn patch(&self, url: &str) -> reqwest::RequestBuilder {
+        self.traced(self.client.patch(url))
+    }
+
+    /// Build a DELETE request, attaching the trace ID header.
+    fn delete(&self, url: &str) -> reqwest::RequestBuilder {
+        self.traced(self.client.delete(url))
+    }
+
+    fn traced(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.trace_id {
+            Some(trace_id) => builder.header(TRACE_ID_HEADER, trace_id.to_string()),
+            None => builder,
+        }
+    }
+
     /// Health check.
     pub async fn health(&self) -> McpResult<HealthStatus> {
         let url = format!("{}/health", self.endpoint);
-        let response = self.client.get(&url).send().await.map_err(|e| {
+        let response = self.get(&url).send().await.map_err(|e| {
             McpError::Http(format!("Failed to connect to SYNTON-DB at {}: {}", self.endpoint, e))
         })?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Health check failed: {}",
-                response.status()
-            )));
+            return Err(api_error("Health check failed", response).await);
         }
 
         let health: HealthStatus = response.json().await?;
@@ -84,7 +130,6 @@ impl SyntonDbClient {
         };
 
         let response = self
-            .client
             .post(&url)
             .json(&request)
             .send()
@@ -92,12 +137,7 @@ impl SyntonDbClient {
             .map_err(|e| McpError::Http(format!("Failed to add node: {}", e)))?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            return Err(McpError::Api(format!(
-                "Failed to add node: {} - {}",
-                status, text
-            )));
+            return Err(api_error("Failed to add node", response).await);
         }
 
         let result: AddNodeResponse = response.json().await?;
@@ -120,7 +160,6 @@ impl SyntonDbClient {
         }
 
         let response = self
-            .client
             .get(&url)
             .send()
             .await
@@ -131,10 +170,7 @@ impl SyntonDbClient {
         }
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Failed to get node: {}",
-                response.status()
-            )));
+            return Err(api_error("Failed to get node", response).await);
         }
 
         let result: GetNodeResponse = response.json().await?;
@@ -162,7 +198,6 @@ impl SyntonDbClient {
         };
 
         let response = self
-            .client
             .post(&url)
             .json(&request)
             .send()
@@ -170,10 +205,7 @@ impl SyntonDbClient {
             .map_err(|e| McpError::Http(format!("Failed to query: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Query failed: {}",
-                response.status()
-            )));
+            return Err(api_error("Query failed", response).await);
         }
 
         let result: QueryResult = response.json().await?;
@@ -181,12 +213,11 @@ impl SyntonDbClient {
     }
 
     /// Hybrid search (Graph-RAG).
-    pub async fn hybrid_search(&self, query: String, k: usize) -> McpResult<Vec<Node>> {
+    pub async fn hybrid_search(&self, query: String, k: usize) -> McpResult<HybridSearchResult> {
         let url = format!("{}/hybrid_search", self.endpoint);
         let request = HybridSearchRequest { query, k };
 
         let response = self
-            .client
             .post(&url)
             .json(&request)
             .send()
@@ -194,14 +225,174 @@ impl SyntonDbClient {
             .map_err(|e| McpError::Http(format!("Failed to hybrid search: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Hybrid search failed: {}",
-                response.status()
-            )));
+            return Err(api_error("Hybrid search failed", response).await);
         }
 
-        let result: HybridSearchResponse = response.json().await?;
-        Ok(result.nodes)
+        let result: HybridSearchResult = response.json().await?;
+        Ok(result)
+    }
+
+    /// Submit relevance feedback for a node returned by a query.
+    pub async fn feedback(
+        &self,
+        query_id: Uuid,
+        node_id: Uuid,
+        useful: bool,
+    ) -> McpResult<FeedbackResult> {
+        let url = format!("{}/rag/feedback", self.endpoint);
+        let request = FeedbackRequest {
+            query_id,
+            node_id,
+            useful,
+        };
+
+        let response = self
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| McpError::Http(format!("Failed to submit feedback: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("Feedback failed", response).await);
+        }
+
+        let result: FeedbackResult = response.json().await?;
+        Ok(result)
+    }
+
+    /// Pin a node, exempting it from decay and pruning, or unpin it. With
+    /// an expected version, the server rejects the change if the node has
+    /// moved past it.
+    pub async fn pin_node(
+        &self,
+        id: Uuid,
+        pinned: bool,
+        expected_version: Option<u64>,
+    ) -> McpResult<Node> {
+        let url = format!("{}/nodes/{}", self.endpoint, id);
+
+        let response = self
+            .patch(&url)
+            .json(&serde_json::json!({ "pinned": pinned, "expected_version": expected_version }))
+            .send()
+            .await
+            .map_err(|e| McpError::Http(format!("Failed to pin node: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("Pin failed", response).await);
+        }
+
+        let result: UpdateNodeResult = response.json().await?;
+        self.cache_node(&result.node).await;
+        Ok(result.node)
+    }
+
+    /// Change a node's content or type in place, keeping its ID and edges.
+    /// With an expected version, the server rejects the change if the node
+    /// has moved past it.
+    pub async fn update_node(
+        &self,
+        id: Uuid,
+        content: Option<String>,
+        node_type: Option<NodeType>,
+        expected_version: Option<u64>,
+    ) -> McpResult<UpdateNodeResult> {
+        let url = format!("{}/nodes/{}", self.endpoint, id);
+        let request = serde_json::json!({
+            "content": content,
+            "node_type": node_type,
+            "expected_version": expected_version,
+        });
+
+        let response = self
+            .patch(&url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| McpError::Http(format!("Failed to update node: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("Update failed", response).await);
+        }
+
+        let result: UpdateNodeResult = response.json().await?;
+        self.cache_node(&result.node).await;
+        Ok(result)
+    }
+
+    /// Delete a node and its edges. Returns whether the node existed.
+    pub async fn delete_node(&self, id: Uuid) -> McpResult<bool> {
+        let url = format!("{}/nodes/{}", self.endpoint, id);
+
+        let response = self
+            .delete(&url)
+            .json(&serde_json::json!({ "id": id }))
+            .send()
+            .await
+            .map_err(|e| McpError::Http(format!("Failed to delete node: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(api_error("Delete failed", response).await);
+        }
+
+        let result: DeleteNodeResponse = response.json().await?;
+        self.cache.write().await.pop(&format!("node:{}", id));
+        Ok(result.deleted)
+    }
+
+    /// Add and remove tags of a node. Returns all its tags afterwards.
+    pub async fn retag_node(
+        &self,
+        id: Uuid,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> McpResult<NodeTags> {
+        let mut tags = None;
+        if !add.is_empty() {
+            let url = format!("{}/nodes/{}/tags", self.endpoint, id);
+            let response = self
+                .post(&url)
+                .json(&serde_json::json!({ "tags": add }))
+                .send()
+                .await
+                .map_err(|e| McpError::Http(format!("Failed to tag node: {}", e)))?;
+
+            if !response.status().is_success() {
+                return Err(api_error("Tagging failed", response).await);
+            }
+            tags = Some(response.json().await?);
+        }
+        for tag in remove {
+            let mut url = reqwest::Url::parse(&format!("{}/nodes/{}/tags", self.endpoint, id))
+                .map_err(|e| McpError::InvalidRequest(format!("Invalid endpoint: {}", e)))?;
+            url.path_segments_mut()
+                .map_err(|_| McpError::InvalidRequest("Invalid endpoint".to_string()))?
+                .push(&tag);
+            let response = self
+                .delete(url.as_str())
+                .send()
+                .await
+                .map_err(|e| McpError::Http(format!("Failed to untag node: {}", e)))?;
+
+            if !response.status().is_success() {
+                return Err(api_error("Untagging failed", response).await);
+            }
+            tags = Some(response.json().await?);
+        }
+
+        // The cached copy has the old tags
+        self.cache.write().await.pop(&format!("node:{}", id));
+        tags.ok_or_else(|| McpError::InvalidRequest("No tags to add or remove".to_string()))
+    }
+
+    /// Replace the cached copy of a node.
+    async fn cache_node(&self, node: &Node) {
+        let mut cache = self.cache.write().await;
+        let _ = cache.put(
+            format!("node:{}", node.id),
+            serde_json::to_value(node).unwrap_or_default(),
+        );
     }
 
     /// Traverse the graph.
@@ -210,6 +401,7 @@ impl SyntonDbClient {
         start_id: Uuid,
         max_depth: usize,
         max_nodes: usize,
+        created_by: Vec<EdgeCreator>,
     ) -> McpResult<TraverseResult> {
         let url = format!("{}/traverse", self.endpoint);
         let request = TraverseRequest {
@@ -217,10 +409,10 @@ impl SyntonDbClient {
             max_depth,
             max_nodes,
             direction: TraverseDirection::Both,
+            created_by,
         };
 
         let response = self
-            .client
             .post(&url)
             .json(&request)
             .send()
@@ -228,10 +420,7 @@ impl SyntonDbClient {
             .map_err(|e| McpError::Http(format!("Failed to traverse: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Traverse failed: {}",
-                response.status()
-            )));
+            return Err(api_error("Traverse failed", response).await);
         }
 
         let result: TraverseResult = response.json().await?;
@@ -245,6 +434,7 @@ impl SyntonDbClient {
         target: Uuid,
         relation: Relation,
         weight: f32,
+        provenance: EdgeProvenance,
     ) -> McpResult<Edge> {
         let url = format!("{}/edges", self.endpoint);
         let request = AddEdgeRequest {
@@ -253,10 +443,10 @@ impl SyntonDbClient {
             relation,
             weight,
             vector: None,
+            provenance,
         };
 
         let response = self
-            .client
             .post(&url)
             .json(&request)
             .send()
@@ -264,10 +454,7 @@ impl SyntonDbClient {
             .map_err(|e| McpError::Http(format!("Failed to add edge: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Failed to add edge: {}",
-                response.status()
-            )));
+            return Err(api_error("Failed to add edge", response).await);
         }
 
         let result: AddEdgeResponse = response.json().await?;
@@ -279,17 +466,13 @@ impl SyntonDbClient {
         let url = format!("{}/stats", self.endpoint);
 
         let response = self
-            .client
             .get(&url)
             .send()
             .await
             .map_err(|e| McpError::Http(format!("Failed to get stats: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Failed to get stats: {}",
-                response.status()
-            )));
+            return Err(api_error("Failed to get stats", response).await);
         }
 
         let result: DatabaseStats = response.json().await?;
@@ -301,17 +484,13 @@ impl SyntonDbClient {
         let url = format!("{}/nodes", self.endpoint);
 
         let response = self
-            .client
             .get(&url)
             .send()
             .await
             .map_err(|e| McpError::Http(format!("Failed to get nodes: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(McpError::Api(format!(
-                "Failed to get nodes: {}",
-                response.status()
-            )));
+            return Err(api_error("Failed to get nodes", response).await);
         }
 
         let nodes: Vec<Node> = response.json().await?;
@@ -319,6 +498,19 @@ impl SyntonDbClient {
     }
 }
 
+/// Build an API error from a failed response.
+///
+/// Structured error bodies are rendered with their code, causes and trace ID
+/// so the calling model sees why the request failed.
+async fn api_error(action: &str, response: reqwest::Response) -> McpError {
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    match serde_json::from_str::<ErrorBody>(&text) {
+        Ok(body) => McpError::Api(format!("{}: {}", action, body.report())),
+        Err(_) => McpError::Api(format!("{}: {} - {}", action, status, text)),
+    }
+}
+
 impl Default for SyntonDbClient {
     fn default() -> Self {
         Self::new()
@@ -346,7 +538,7 @@ struct AddNodeRequest {
 }
 
 /// Add node response.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AddNodeResponse {
     /// Created node.
     pub node: Node,
@@ -369,7 +561,7 @@ struct QueryRequest {
 }
 
 /// Query result.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct QueryResult {
     /// Result nodes.
     pub nodes: Vec<Node>,
@@ -379,6 +571,8 @@ pub struct QueryResult {
     pub execution_time_ms: u64,
     /// Whether results were truncated.
     pub truncated: bool,
+    /// ID used to submit feedback on these results.
+    pub query_id: Uuid,
 }
 
 /// Hybrid search request.
@@ -388,12 +582,57 @@ struct HybridSearchRequest {
     k: usize,
 }
 
-/// Hybrid search response.
+/// Hybrid search result.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HybridSearchResult {
+    /// Result nodes.
+    pub nodes: Vec<Node>,
+    /// Number of results.
+    pub count: usize,
+    /// ID used to submit feedback on these results.
+    pub query_id: Uuid,
+}
+
+/// Update node result.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateNodeResult {
+    /// Node after the update.
+    pub node: Node,
+    /// Whether changed content was re-embedded.
+    #[serde(default)]
+    pub reembedded: bool,
+}
+
+/// Delete node response.
 #[derive(Debug, Deserialize)]
-#[allow(dead_code)]
-struct HybridSearchResponse {
-    nodes: Vec<Node>,
-    count: usize,
+struct DeleteNodeResponse {
+    deleted: bool,
+}
+
+/// Tags of a node after a tag change.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NodeTags {
+    /// Tagged node.
+    pub node_id: Uuid,
+    /// All tags of the node, sorted.
+    pub tags: Vec<String>,
+}
+
+/// Feedback request.
+#[derive(Debug, Serialize)]
+struct FeedbackRequest {
+    query_id: Uuid,
+    node_id: Uuid,
+    useful: bool,
+}
+
+/// Feedback result.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FeedbackResult {
+    /// Node access score after the feedback.
+    pub access_score: f32,
+    /// Adjusted relation weights by relation type.
+    pub relation_weights: std::collections::HashMap<String, f32>,
 }
 
 /// Traverse direction.
@@ -413,10 +652,11 @@ struct TraverseRequest {
     max_depth: usize,
     max_nodes: usize,
     direction: TraverseDirection,
+    created_by: Vec<EdgeCreator>,
 }
 
 /// Traverse result.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TraverseResult {
     /// Traversed nodes.
     pub nodes: Vec<Node>,
@@ -436,6 +676,7 @@ struct AddEdgeRequest {
     relation: Relation,
     weight: f32,
     vector: Option<Vec<f32>>,
+    provenance: EdgeProvenance,
 }
 
 /// Add edge response.
@@ -445,7 +686,7 @@ struct AddEdgeResponse {
 }
 
 /// Database statistics.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct DatabaseStats {
     /// Node count.
     pub node_count: usize,
@@ -458,7 +699,7 @@ pub struct DatabaseStats {
 }
 
 /// Memory statistics.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MemoryStats {
     /// Total nodes.
     pub total_nodes: usize,
@@ -490,4 +731,15 @@ mod tests {
         let client = SyntonDbClient::default();
         assert_eq!(client.endpoint(), DEFAULT_ENDPOINT);
     }
+
+    #[test]
+    fn test_client_with_trace_id() {
+        let client = SyntonDbClient::new();
+        assert!(client.trace_id().is_none());
+
+        let trace_id = Uuid::new_v4();
+        let traced = client.with_trace_id(trace_id);
+        assert_eq!(traced.trace_id(), Some(trace_id));
+        assert_eq!(traced.endpoint(), client.endpoint());
+    }
 }
@@ -0,0 +1,170 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Confirmation of destructive tool calls.
+//!
+//! With confirmation enabled, a call to a tool annotated as destructive is
+//! not carried out right away. The server replies with what the call would
+//! change and a one-time token; the change is made when the tool is called
+//! again with the same arguments and that token as `confirmation_token`.
+//! This gives the assistant a chance to show the change to the user before
+//! it overwrites or deletes their knowledge. Tokens expire after a few
+//! minutes and are bound to the tool and arguments they were issued for.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::ToolError;
+
+/// Argument carrying the confirmation token.
+pub(crate) const TOKEN_ARG: &str = "confirmation_token";
+
+/// How long a token can be redeemed.
+const TOKEN_TTL: Duration = Duration::from_secs(300);
+
+/// A call waiting for confirmation.
+#[derive(Debug)]
+struct PendingCall {
+    tool: String,
+    args: Value,
+    issued: Instant,
+}
+
+/// Destructive calls waiting for confirmation, by token.
+#[derive(Debug)]
+pub struct Confirmations {
+    pending: Mutex<HashMap<String, PendingCall>>,
+    ttl: Duration,
+}
+
+impl Default for Confirmations {
+    fn default() -> Self {
+        Self::with_ttl(TOKEN_TTL)
+    }
+}
+
+impl Confirmations {
+    /// Create a store whose tokens expire after `ttl`.
+    pub fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    fn pending(&self) -> MutexGuard<'_, HashMap<String, PendingCall>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Issue a token confirming a call of `tool` with `args`.
+    pub fn issue(&self, tool: &str, args: &Value) -> String {
+        let token = Uuid::new_v4().simple().to_string();
+        let mut pending = self.pending();
+        pending.retain(|_, call| call.issued.elapsed() < self.ttl);
+        pending.insert(
+            token.clone(),
+            PendingCall {
+                tool: tool.to_string(),
+                args: call_args(args),
+                issued: Instant::now(),
+            },
+        );
+        token
+    }
+
+    /// Redeem the token in `args` for a call of `tool`. A token is used up
+    /// by a successful redemption.
+    pub fn redeem(&self, tool: &str, args: &Value) -> Result<(), ToolError> {
+        let token = args.get(TOKEN_ARG).and_then(Value::as_str).ok_or_else(|| {
+            ToolError::new(format!("Missing or invalid '{}' argument", TOKEN_ARG))
+        })?;
+
+        let mut pending = self.pending();
+        let Some(call) = pending.get(token) else {
+            return Err(ToolError::new(
+                "Unknown or already used confirmation token; call the tool again without it \
+                 to get a new one",
+            ));
+        };
+        if call.issued.elapsed() >= self.ttl {
+            pending.remove(token);
+            return Err(ToolError::new(
+                "Confirmation token expired; call the tool again without it to get a new one",
+            ));
+        }
+        if call.tool != tool || call.args != call_args(args) {
+            return Err(ToolError::new(
+                "Confirmation token was issued for a different call; confirm with the same \
+                 tool and arguments",
+            ));
+        }
+        pending.remove(token);
+        Ok(())
+    }
+}
+
+/// Arguments that identify a call: all but the token and the output format.
+fn call_args(args: &Value) -> Value {
+    let mut args = args.clone();
+    if let Some(map) = args.as_object_mut() {
+        map.remove(TOKEN_ARG);
+        map.remove("format");
+    }
+    args
+}
+
+/// Whether a call carries a confirmation token.
+pub(crate) fn has_token(args: &Value) -> bool {
+    args.get(TOKEN_ARG).is_some_and(|token| !token.is_null())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_confirmation_round_trip() {
+        let confirmations = Confirmations::default();
+        let args = json!({"id": "a", "format": "text"});
+        let token = confirmations.issue("synton_delete_node", &args);
+
+        let confirmed = json!({"id": "a", "format": "json", TOKEN_ARG: token});
+        assert!(has_token(&confirmed));
+        assert!(confirmations
+            .redeem("synton_delete_node", &confirmed)
+            .is_ok());
+        // Tokens are single use
+        assert!(confirmations
+            .redeem("synton_delete_node", &confirmed)
+            .is_err());
+    }
+
+    #[test]
+    fn test_confirmation_rejects_other_calls() {
+        let confirmations = Confirmations::default();
+        let token = confirmations.issue("synton_delete_node", &json!({"id": "a"}));
+
+        let other = json!({"id": "b", TOKEN_ARG: token});
+        assert!(confirmations.redeem("synton_delete_node", &other).is_err());
+        let same = json!({"id": "a", TOKEN_ARG: token});
+        assert!(confirmations.redeem("synton_update_node", &same).is_err());
+        // A mismatch does not use the token up
+        assert!(confirmations.redeem("synton_delete_node", &same).is_ok());
+
+        assert!(confirmations
+            .redeem("synton_delete_node", &json!({"id": "a"}))
+            .is_err());
+
+        let expired = Confirmations::with_ttl(Duration::ZERO);
+        let token = expired.issue("synton_delete_node", &json!({"id": "a"}));
+        assert!(expired
+            .redeem("synton_delete_node", &json!({"id": "a", TOKEN_ARG: token}))
+            .is_err());
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Contextual boosting of search results.
+//!
+//! An IDE knows which project and file the user is working in. Passed as
+//! the `context` argument of the search tools, they favor knowledge about
+//! that project or file without excluding anything else: the tool fetches
+//! more candidates than requested, raises those whose attributes, tags or
+//! source match the context, and keeps the best of them. Without a match
+//! the server's order is kept.
+
+use std::path::Path;
+
+use serde_json::{json, Value};
+use synton_core::{Node, Source};
+
+use crate::ToolError;
+
+/// Candidates fetched per requested result when a context is given.
+const CANDIDATE_FACTOR: usize = 3;
+
+/// Boost of a node about the active project.
+const PROJECT_BOOST: f32 = 0.3;
+
+/// Boost of a node about the active file.
+const FILE_BOOST: f32 = 0.5;
+
+/// Boost of a node merely mentioning the active file, or about another
+/// file in its directory.
+const NEARBY_FILE_BOOST: f32 = 0.2;
+
+/// Boost of a node about the active language.
+const LANGUAGE_BOOST: f32 = 0.2;
+
+/// Candidates to fetch for `requested` results, up to `max`.
+pub(crate) fn candidates(requested: usize, max: usize) -> usize {
+    (requested * CANDIDATE_FACTOR).min(max).max(requested)
+}
+
+/// JSON schema of the `context` argument.
+pub(crate) fn context_schema() -> Value {
+    json!({
+        "type": "object",
+        "description": "Where the user is working. Results about this project, file or \
+                        language are ranked higher; nothing is filtered out",
+        "properties": {
+            "project": {
+                "type": "string",
+                "description": "Project name, matched against the 'project' and 'namespace' attributes and tags"
+            },
+            "file": {
+                "type": "string",
+                "description": "Path of the open file, matched against the 'file' and 'path' attributes, external IDs and source files"
+            },
+            "language": {
+                "type": "string",
+                "description": "Programming language, matched against the 'language' attribute and tags"
+            }
+        }
+    })
+}
+
+/// Where the user is working, as passed in the `context` argument.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ActiveContext {
+    /// Project name.
+    pub project: Option<String>,
+    /// Path of the open file.
+    pub file: Option<String>,
+    /// Programming language of the open file.
+    pub language: Option<String>,
+}
+
+impl ActiveContext {
+    /// Read the optional `context` argument of a tool call. Returns `None`
+    /// if it is absent or names nothing.
+    pub(crate) fn from_args(args: &Value) -> Result<Option<Self>, ToolError> {
+        let context = match args.get("context") {
+            None | Some(Value::Null) => return Ok(None),
+            Some(Value::Object(context)) => context,
+            Some(other) => {
+                return Err(ToolError::new(format!(
+                    "Invalid 'context' argument: {} (expected an object)",
+                    other
+                )))
+            }
+        };
+        let field = |name: &str| -> Result<Option<String>, ToolError> {
+            match context.get(name) {
+                None | Some(Value::Null) => Ok(None),
+                Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+                Some(Value::String(s)) => Ok(Some(s.trim().to_string())),
+                Some(other) => Err(ToolError::new(format!(
+                    "Invalid 'context.{}' argument: {} (expected a string)",
+                    name, other
+                ))),
+            }
+        };
+
+        let context = Self {
+            project: field("project")?,
+            file: field("file")?,
+            language: field("language")?,
+        };
+        Ok((context != Self::default()).then_some(context))
+    }
+
+    /// Boost of a node: how strongly it relates to the context.
+    pub(crate) fn boost(&self, node: &Node) -> f32 {
+        let mut boost = 0.0;
+        if let Some(project) = &self.project {
+            if attribute_is(node, &["project", "namespace"], project) || has_tag(node, project) {
+                boost += PROJECT_BOOST;
+            }
+        }
+        if let Some(file) = &self.file {
+            boost += file_boost(node, file);
+        }
+        if let Some(language) = &self.language {
+            if attribute_is(node, &["language"], language) || has_tag(node, language) {
+                boost += LANGUAGE_BOOST;
+            }
+        }
+        boost
+    }
+
+    /// Reorder `nodes`, given in the server's order, by their relevance
+    /// plus boost, and keep the first `limit`.
+    ///
+    /// Relevance falls linearly from 1.0 for the first node to near 0.0 for
+    /// the last, so a boosted node moves up past a share of the nodes
+    /// equal to its boost. Ties keep the server's order.
+    pub(crate) fn rerank(&self, nodes: Vec<Node>, limit: usize) -> Vec<Node> {
+        let count = nodes.len() as f32;
+        let mut scored: Vec<(f32, Node)> = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| (1.0 - i as f32 / count + self.boost(&node), node))
+            .collect();
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+        scored.into_iter().take(limit).map(|(_, node)| node).collect()
+    }
+}
+
+/// Whether one of the string attributes `keys` of a node equals `value`,
+/// ignoring case.
+fn attribute_is(node: &Node, keys: &[&str], value: &str) -> bool {
+    keys.iter().any(|key| {
+        node.attributes
+            .get(*key)
+            .and_then(Value::as_str)
+            .is_some_and(|attribute| attribute.eq_ignore_ascii_case(value))
+    })
+}
+
+/// Whether a node carries `label` as a tag.
+fn has_tag(node: &Node, label: &str) -> bool {
+    node.tags.contains(&label.to_lowercase())
+}
+
+/// Boost of a node for the active file: full when the node is about the
+/// file, partial when it is about a file next to it or mentions it.
+fn file_boost(node: &Node, file: &str) -> f32 {
+    let paths = ["file", "path"]
+        .iter()
+        .filter_map(|key| node.attributes.get(*key).and_then(Value::as_str))
+        .chain(node.meta.external_id.as_deref())
+        .chain(match &node.meta.source {
+            Source::File(name) => Some(name.as_str()),
+            _ => None,
+        });
+
+    let mut boost: f32 = 0.0;
+    for path in paths {
+        if same_file(path, file) {
+            return FILE_BOOST;
+        }
+        if same_directory(path, file) {
+            boost = NEARBY_FILE_BOOST;
+        }
+    }
+    let mentioned = Path::new(file)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| node.content.contains(name));
+    if mentioned {
+        boost = NEARBY_FILE_BOOST;
+    }
+    boost
+}
+
+/// Whether two paths name the same file. A relative path matches the end
+/// of an absolute one, as file paths recorded elsewhere are often relative
+/// to the project root.
+fn same_file(a: &str, b: &str) -> bool {
+    let (a, b) = (Path::new(a), Path::new(b));
+    a.file_name().is_some() && (a.ends_with(b) || b.ends_with(a))
+}
+
+/// Whether two paths name files in the same directory.
+fn same_directory(a: &str, b: &str) -> bool {
+    match (Path::new(a).parent(), Path::new(b).parent()) {
+        (Some(a), Some(b)) if a.file_name().is_some() && b.file_name().is_some() => {
+            a.ends_with(b) || b.ends_with(a)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    fn node(content: &str) -> Node {
+        Node::new(content.to_string(), NodeType::Concept)
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(ActiveContext::from_args(&json!({})).unwrap(), None);
+        assert_eq!(
+            ActiveContext::from_args(&json!({"context": {"project": " "}})).unwrap(),
+            None
+        );
+
+        let context = ActiveContext::from_args(&json!({
+            "context": {"project": "synton", "file": "src/lib.rs"}
+        }))
+        .unwrap()
+        .unwrap();
+        assert_eq!(context.project.as_deref(), Some("synton"));
+        assert_eq!(context.file.as_deref(), Some("src/lib.rs"));
+        assert_eq!(context.language, None);
+
+        assert!(ActiveContext::from_args(&json!({"context": "synton"})).is_err());
+        assert!(ActiveContext::from_args(&json!({"context": {"file": 1}})).is_err());
+    }
+
+    #[test]
+    fn test_boost() {
+        let context = ActiveContext {
+            project: Some("Synton".to_string()),
+            file: Some("/home/dev/synton/crates/api/src/rest.rs".to_string()),
+            language: Some("rust".to_string()),
+        };
+
+        assert_eq!(context.boost(&node("Unrelated")), 0.0);
+        let project = node("Roadmap").with_attributes(json!({"project": "synton"}));
+        assert_eq!(context.boost(&project), PROJECT_BOOST);
+        let mut language = node("Borrowing");
+        language.tags.insert("rust".to_string());
+        assert_eq!(context.boost(&language), LANGUAGE_BOOST);
+
+        let file = node("Routes").with_attributes(json!({"file": "crates/api/src/rest.rs"}));
+        assert_eq!(context.boost(&file), FILE_BOOST);
+        let sibling = node("Models").with_attributes(json!({"path": "crates/api/src/models.rs"}));
+        assert_eq!(context.boost(&sibling), NEARBY_FILE_BOOST);
+        let mention = node("Handlers live in rest.rs");
+        assert_eq!(context.boost(&mention), NEARBY_FILE_BOOST);
+        let other = node("Docs").with_attributes(json!({"file": "docs/rest.md"}));
+        assert_eq!(context.boost(&other), 0.0);
+    }
+
+    #[test]
+    fn test_rerank_boosts_without_filtering() {
+        let context = ActiveContext {
+            project: Some("synton".to_string()),
+            ..Default::default()
+        };
+        let nodes = vec![
+            node("first"),
+            node("second"),
+            node("third").with_attributes(json!({"project": "synton"})),
+            node("fourth"),
+            node("fifth"),
+            node("sixth"),
+        ];
+
+        let ranked = context.rerank(nodes.clone(), 4);
+        let contents: Vec<&str> = ranked.iter().map(|n| n.content()).collect();
+        assert_eq!(contents, vec!["first", "third", "second", "fourth"]);
+
+        // Without a match the server's order is kept
+        let other = ActiveContext {
+            project: Some("other".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(other.rerank(nodes.clone(), 6), nodes);
+    }
+}
@@ -14,6 +14,8 @@
 //! - Knowledge absorption with automatic vectorization
 //! - Graph traversal for exploring relationships
 //! - Hybrid Graph-RAG retrieval
+//! - Curation of stored knowledge, with optional confirmation of destructive
+//!   changes
 //! - Cross-session persistent memory
 //!
 //! # Configuration
@@ -33,18 +35,21 @@
 #![warn(clippy::all)]
 
 mod client;
+mod confirm;
+mod context;
 mod protocol;
 mod server;
 mod tools;
 
 pub use client::SyntonDbClient;
+pub use confirm::Confirmations;
 pub use protocol::{
     CallToolRequest, CallToolResponse, CallToolResult, JsonRpcError, JsonRpcNotification,
-    JsonRpcRequest, JsonRpcResponse, ListToolsRequest, ListToolsResponse, Tool, ToolContent,
-    ToolError, ToolTextContent,
+    JsonRpcRequest, JsonRpcResponse, ListToolsRequest, ListToolsResponse, Tool, ToolAnnotations,
+    ToolContent, ToolError, ToolTextContent,
 };
 pub use server::McpServer;
-pub use tools::get_all_tools;
+pub use tools::{get_all_tools, ToolFilter};
 
 /// Result type for MCP operations.
 pub type McpResult<T> = Result<T, McpError>;
@@ -8,7 +8,7 @@
 //! Enables AI coding assistants to use SYNTON-DB as persistent memory.
 
 use clap::Parser;
-use synton_mcp_server::{McpServer, SyntonDbClient};
+use synton_mcp_server::{McpServer, SyntonDbClient, ToolFilter};
 
 /// SYNTON-DB MCP Server
 ///
@@ -29,6 +29,19 @@ struct Args {
     )]
     endpoint: String,
 
+    /// Expose only these tools (comma-separated names)
+    #[arg(long, env = "SYNTONDB_MCP_TOOLS", value_delimiter = ',', global = true)]
+    tools: Vec<String>,
+
+    /// Expose only tools that do not modify the database
+    #[arg(long, env = "SYNTONDB_MCP_READ_ONLY", global = true)]
+    read_only: bool,
+
+    /// Require destructive tool calls (update, delete, retag) to be
+    /// confirmed with a token the server issues for them
+    #[arg(long, env = "SYNTONDB_MCP_CONFIRM_DESTRUCTIVE", global = true)]
+    confirm_destructive: bool,
+
     /// Enable verbose logging
     #[arg(long, short, env = "VERBOSE", global = true)]
     verbose: bool,
@@ -59,9 +72,18 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("SYNTON-DB MCP Server v{}", env!("CARGO_PKG_VERSION"));
     tracing::info!("Connecting to SYNTON-DB at: {}", args.endpoint);
 
+    let filter = if args.tools.is_empty() {
+        ToolFilter::all()
+    } else {
+        ToolFilter::only(args.tools)?
+    };
+    let filter = filter.with_read_only(args.read_only);
+
     // Create client and server
     let client = SyntonDbClient::with_endpoint(args.endpoint);
-    let server = McpServer::new(client);
+    let server = McpServer::new(client)
+        .with_tool_filter(filter)
+        .with_confirmation(args.confirm_destructive);
 
     // Run server (stdio mode)
     server.run_stdio().await?;
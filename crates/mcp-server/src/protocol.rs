@@ -255,6 +255,44 @@ pub struct Tool {
     /// JSON Schema for input parameters.
     #[serde(rename = "inputSchema")]
     pub input_schema: serde_json::Value,
+    /// Hints about the tool's behavior.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+}
+
+/// Tool behavior hints, which clients may use to decide whether a call
+/// needs confirmation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolAnnotations {
+    /// The tool does not modify the database.
+    #[serde(rename = "readOnlyHint")]
+    pub read_only_hint: bool,
+    /// The tool may delete or overwrite existing data.
+    #[serde(rename = "destructiveHint")]
+    pub destructive_hint: bool,
+    /// Repeating a call with the same arguments has no additional effect.
+    #[serde(rename = "idempotentHint")]
+    pub idempotent_hint: bool,
+}
+
+impl ToolAnnotations {
+    /// Annotations of a tool that only reads.
+    pub fn read_only() -> Self {
+        Self {
+            read_only_hint: true,
+            destructive_hint: false,
+            idempotent_hint: true,
+        }
+    }
+
+    /// Annotations of a tool that writes.
+    pub fn write(destructive: bool, idempotent: bool) -> Self {
+        Self {
+            read_only_hint: false,
+            destructive_hint: destructive,
+            idempotent_hint: idempotent,
+        }
+    }
 }
 
 /// Call tool request.
@@ -275,6 +313,20 @@ pub struct CallToolResponse {
     /// Whether the result is an error.
     #[serde(default, skip_serializing_if = "is_false")]
     pub is_error: bool,
+    /// Machine-readable result, for tools called with `format: "json"`.
+    #[serde(rename = "structuredContent", skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<serde_json::Value>,
+    /// Result metadata (e.g. the server-side trace ID).
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<serde_json::Value>,
+}
+
+impl CallToolResponse {
+    /// Attach the trace ID used for the tool's requests.
+    pub fn with_trace_id(mut self, trace_id: Uuid) -> Self {
+        self.meta = Some(serde_json::json!({ "trace_id": trace_id }));
+        self
+    }
 }
 
 fn is_false(b: &bool) -> bool {
@@ -388,6 +440,9 @@ impl std::error::Error for ToolError {}
 pub enum CallToolResult {
     /// Success with content.
     Success(Vec<ToolContent>),
+    /// Success with a machine-readable value, sent as structured content
+    /// and, for clients without structured content support, as JSON text.
+    Structured(serde_json::Value),
     /// Error.
     Error(ToolError),
 }
@@ -399,10 +454,20 @@ impl CallToolResult {
             Self::Success(content) => CallToolResponse {
                 content,
                 is_error: false,
+                structured_content: None,
+                meta: None,
+            },
+            Self::Structured(value) => CallToolResponse {
+                content: vec![ToolContent::Text(ToolTextContent::new(value.to_string()))],
+                is_error: false,
+                structured_content: Some(value),
+                meta: None,
             },
             Self::Error(err) => CallToolResponse {
                 content: vec![err.to_content()],
                 is_error: true,
+                structured_content: None,
+                meta: None,
             },
         }
     }
@@ -448,4 +513,36 @@ mod tests {
         assert_eq!(err.message, "Test error");
         assert_eq!(err.details, Some("Additional info".to_string()));
     }
+
+    #[test]
+    fn test_tool_annotations() {
+        let tool = Tool {
+            name: "synton_stats".to_string(),
+            description: "Stats".to_string(),
+            input_schema: serde_json::json!({"type": "object"}),
+            annotations: Some(ToolAnnotations::read_only()),
+        };
+        let json = serde_json::to_value(&tool).unwrap();
+        assert_eq!(
+            json["annotations"],
+            serde_json::json!({"readOnlyHint": true, "destructiveHint": false, "idempotentHint": true})
+        );
+
+        let bare = Tool { annotations: None, ..tool };
+        assert!(serde_json::to_value(&bare).unwrap().get("annotations").is_none());
+    }
+
+    #[test]
+    fn test_structured_result() {
+        let value = serde_json::json!({"count": 1});
+        let resp = CallToolResult::Structured(value.clone()).to_response();
+        assert!(!resp.is_error);
+
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["structuredContent"], value);
+        assert_eq!(json["content"][0]["text"], value.to_string());
+
+        let text = CallToolResult::Success(vec![]).to_response();
+        assert!(serde_json::to_value(&text).unwrap().get("structuredContent").is_none());
+    }
 }
@@ -13,13 +13,14 @@ use tokio::sync::RwLock;
 
 use crate::{
     client::SyntonDbClient,
+    confirm::Confirmations,
     protocol::{
         CallToolRequest, InitializeRequest, InitializeResponse, JsonRpcError,
         JsonRpcRequest, JsonRpcResponse, ListToolsRequest,
         ListToolsResponse, RequestId, ServerCapabilities, ServerInfo, ToolsCapability,
     },
-    tools::{execute_tool, get_all_tools},
-    McpError, McpResult,
+    tools::{execute_confirmed, execute_tool, is_destructive, ToolFilter},
+    CallToolResult, McpError, McpResult, ToolError,
 };
 
 /// MCP server state.
@@ -29,6 +30,10 @@ pub struct McpServerState {
     client: Arc<SyntonDbClient>,
     /// Whether initialized.
     initialized: Arc<RwLock<bool>>,
+    /// Tools exposed to clients.
+    tool_filter: Arc<ToolFilter>,
+    /// Destructive calls waiting for confirmation, if they need it.
+    confirmations: Option<Arc<Confirmations>>,
 }
 
 impl McpServerState {
@@ -37,6 +42,8 @@ impl McpServerState {
         Self {
             client: Arc::new(client),
             initialized: Arc::new(RwLock::new(false)),
+            tool_filter: Arc::new(ToolFilter::all()),
+            confirmations: None,
         }
     }
 
@@ -54,6 +61,16 @@ impl McpServerState {
     pub fn client(&self) -> &SyntonDbClient {
         &self.client
     }
+
+    /// Get the filter of exposed tools.
+    pub fn tool_filter(&self) -> &ToolFilter {
+        &self.tool_filter
+    }
+
+    /// Whether destructive tool calls must be confirmed.
+    pub fn requires_confirmation(&self) -> bool {
+        self.confirmations.is_some()
+    }
 }
 
 /// MCP server.
@@ -69,6 +86,19 @@ impl McpServer {
         }
     }
 
+    /// Expose only the tools the filter allows.
+    pub fn with_tool_filter(mut self, filter: ToolFilter) -> Self {
+        self.state.tool_filter = Arc::new(filter);
+        self
+    }
+
+    /// Require destructive tool calls to be confirmed with a token the
+    /// server issues for them.
+    pub fn with_confirmation(mut self, required: bool) -> Self {
+        self.state.confirmations = required.then(|| Arc::new(Confirmations::default()));
+        self
+    }
+
     /// Get the server state.
     pub fn state(&self) -> &McpServerState {
         &self.state
@@ -210,7 +240,7 @@ impl McpServer {
         let _list_req: ListToolsRequest = serde_json::from_value(params)
             .map_err(|e| JsonRpcError::invalid_params(format!("Invalid list params: {}", e)))?;
 
-        let tools = get_all_tools();
+        let tools = self.state.tool_filter().tools();
         let response = ListToolsResponse { tools };
 
         Ok(JsonRpcResponse::success(id, serde_json::to_value(response)?))
@@ -234,10 +264,26 @@ impl McpServer {
             JsonRpcError::invalid_params(format!("Invalid call_tool params: {}", e))
         })?;
 
-        tracing::info!("Calling tool: {}", call_req.name);
+        // Tag every request made by this tool call with one trace ID so the
+        // server-side spans can be looked up afterwards.
+        let trace_id = uuid::Uuid::new_v4();
+        tracing::info!("Calling tool: {} (trace_id={})", call_req.name, trace_id);
 
-        let result = execute_tool(self.state.client(), &call_req.name, call_req.arguments).await;
-        let response = result.to_response();
+        // Tools left out by the filter cannot be called
+        if !self.state.tool_filter().allows_name(&call_req.name) {
+            let error = ToolError::new(format!("Tool not enabled: {}", call_req.name));
+            let response = CallToolResult::Error(error).to_response();
+            return Ok(JsonRpcResponse::success(id, serde_json::to_value(response)?));
+        }
+
+        let client = self.state.client().with_trace_id(trace_id);
+        let result = match &self.state.confirmations {
+            Some(confirmations) if is_destructive(&call_req.name) => {
+                execute_confirmed(&client, confirmations, &call_req.name, call_req.arguments).await
+            }
+            _ => execute_tool(&client, &call_req.name, call_req.arguments).await,
+        };
+        let response = result.to_response().with_trace_id(trace_id);
 
         Ok(JsonRpcResponse::success(id, serde_json::to_value(response)?))
     }
@@ -254,6 +300,62 @@ mod tests {
         assert_eq!(server.state().client().endpoint(), SyntonDbClient::new().endpoint());
     }
 
+    #[tokio::test]
+    async fn test_tool_filter_enforced() {
+        let filter = ToolFilter::all().with_read_only(true);
+        let server = McpServer::new(SyntonDbClient::new()).with_tool_filter(filter);
+        server.state().mark_initialized().await;
+
+        let request = |method: &str, params| JsonRpcRequest::new(method, params);
+        let response = server
+            .handle_request(request("tools/list", serde_json::json!({})))
+            .await
+            .unwrap();
+        let tools = response.result.unwrap()["tools"].as_array().unwrap().clone();
+        assert!(tools.iter().all(|t| t["annotations"]["readOnlyHint"] == true));
+        assert!(!tools.iter().any(|t| t["name"] == "synton_absorb"));
+
+        let response = server
+            .handle_request(request(
+                "tools/call",
+                serde_json::json!({"name": "synton_absorb", "arguments": {"content": "x"}}),
+            ))
+            .await
+            .unwrap();
+        let result = response.result.unwrap();
+        assert_eq!(result["is_error"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("not enabled"));
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_enforced() {
+        let server = McpServer::new(SyntonDbClient::new()).with_confirmation(true);
+        assert!(server.state().requires_confirmation());
+        server.state().mark_initialized().await;
+
+        // A destructive call with a token that was never issued is refused
+        let id = uuid::Uuid::new_v4().to_string();
+        let response = server
+            .handle_request(JsonRpcRequest::new(
+                "tools/call",
+                serde_json::json!({
+                    "name": "synton_delete_node",
+                    "arguments": {"id": id, "confirmation_token": "made-up"}
+                }),
+            ))
+            .await
+            .unwrap();
+        let result = response.result.unwrap();
+        assert_eq!(result["is_error"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("confirmation token"));
+
+        let server = McpServer::new(SyntonDbClient::new());
+        assert!(!server.state().requires_confirmation());
+    }
+
     #[tokio::test]
     async fn test_server_state_initialized() {
         let client = SyntonDbClient::new();
@@ -6,18 +6,55 @@
 //!
 //! This module defines all available tools that can be called through the MCP protocol.
 
+use serde::Serialize;
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
-    client::SyntonDbClient, protocol::Tool, CallToolResult, ToolContent, ToolError,
-    ToolTextContent,
+    client::SyntonDbClient,
+    confirm::{self, Confirmations},
+    context::{self, ActiveContext},
+    protocol::{Tool, ToolAnnotations},
+    CallToolResult, McpError, ToolContent, ToolError, ToolTextContent,
 };
-use synton_core::{NodeType, Relation};
+use synton_core::{EdgeCreator, EdgeProvenance, NodeType, Relation};
+
+/// How a tool presents its result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable summary.
+    #[default]
+    Text,
+    /// Structured JSON with complete node and edge objects.
+    Json,
+}
+
+impl OutputFormat {
+    /// Read the optional `format` argument of a tool call.
+    fn from_args(args: &serde_json::Value) -> Result<Self, ToolError> {
+        match args.get("format") {
+            None | Some(serde_json::Value::Null) => Ok(Self::Text),
+            Some(value) => match value.as_str() {
+                Some("text") => Ok(Self::Text),
+                Some("json") => Ok(Self::Json),
+                _ => Err(ToolError::new(format!(
+                    "Invalid 'format' argument: {} (expected \"text\" or \"json\")",
+                    value
+                ))),
+            },
+        }
+    }
+}
+
+/// Largest `limit` of the query tool.
+const MAX_QUERY_LIMIT: usize = 100;
+
+/// Largest `k` of the hybrid search tool.
+const MAX_HYBRID_K: usize = 50;
 
 /// Get all available MCP tools.
 pub fn get_all_tools() -> Vec<Tool> {
-    vec![
+    let tools = vec![
         absorb_tool(),
         query_tool(),
         hybrid_search_tool(),
@@ -26,7 +63,114 @@ pub fn get_all_tools() -> Vec<Tool> {
         add_edge_tool(),
         stats_tool(),
         list_nodes_tool(),
-    ]
+        feedback_tool(),
+        pin_tool(),
+        update_node_tool(),
+        delete_node_tool(),
+        retag_tool(),
+    ];
+    tools
+        .into_iter()
+        .map(with_format_arg)
+        .map(with_confirmation_arg)
+        .collect()
+}
+
+/// Whether the named tool is annotated as destructive.
+pub(crate) fn is_destructive(name: &str) -> bool {
+    get_all_tools()
+        .iter()
+        .any(|tool| tool.name == name && tool.annotations.is_some_and(|a| a.destructive_hint))
+}
+
+/// Which tools a server exposes.
+///
+/// Read-only deployments can expose just the tools that do not modify the
+/// database, or a fixed list of tools. Tools left out are neither listed
+/// nor callable.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolFilter {
+    /// Only tools annotated as read-only.
+    read_only: bool,
+    /// Only these tools (`None` = all).
+    names: Option<Vec<String>>,
+}
+
+impl ToolFilter {
+    /// Expose every tool.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Expose only the named tools. Fails on names that are not tools.
+    pub fn only(names: Vec<String>) -> Result<Self, McpError> {
+        let tools = get_all_tools();
+        if let Some(unknown) = names.iter().find(|name| !tools.iter().any(|t| &t.name == *name)) {
+            return Err(McpError::InvalidRequest(format!("Unknown tool: {}", unknown)));
+        }
+        Ok(Self {
+            read_only: false,
+            names: Some(names),
+        })
+    }
+
+    /// Also require tools to be read-only.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Whether a tool is exposed.
+    pub fn allows(&self, tool: &Tool) -> bool {
+        let read_only = tool.annotations.is_some_and(|a| a.read_only_hint);
+        (!self.read_only || read_only)
+            && self.names.as_ref().map_or(true, |names| names.contains(&tool.name))
+    }
+
+    /// The exposed tools.
+    pub fn tools(&self) -> Vec<Tool> {
+        get_all_tools().into_iter().filter(|tool| self.allows(tool)).collect()
+    }
+
+    /// Whether the tool with the given name is exposed.
+    pub fn allows_name(&self, name: &str) -> bool {
+        get_all_tools().iter().any(|tool| tool.name == name && self.allows(tool))
+    }
+}
+
+/// Add the `format` argument shared by every tool to its input schema.
+fn with_format_arg(mut tool: Tool) -> Tool {
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            "format".to_string(),
+            json!({
+                "type": "string",
+                "enum": ["text", "json"],
+                "description": "Result format: a readable summary or structured JSON",
+                "default": "text"
+            }),
+        );
+    }
+    tool
+}
+
+/// Add the confirmation token argument to the input schema of a
+/// destructive tool.
+fn with_confirmation_arg(mut tool: Tool) -> Tool {
+    if !tool.annotations.is_some_and(|a| a.destructive_hint) {
+        return tool;
+    }
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            confirm::TOKEN_ARG.to_string(),
+            json!({
+                "type": "string",
+                "description": "Token returned when the server asks to confirm this change; \
+                                pass it, with the same arguments, once the user agreed"
+            }),
+        );
+    }
+    tool
 }
 
 /// Tool: synton_absorb
@@ -56,7 +200,8 @@ fn absorb_tool() -> Tool {
                 }
             },
             "required": ["content"]
-        })
+        }),
+        annotations: Some(ToolAnnotations::write(false, false)),
     }
 }
 
@@ -70,7 +215,8 @@ fn query_tool() -> Tool {
                      Supports semantic search, text matching, and PaQL (Prompt as Query Language). \
                      Ideal for retrieving related code concepts, architectural decisions, \
                      project context, and any previously absorbed knowledge. \
-                     Returns nodes ranked by relevance and access frequency.".to_string(),
+                     Returns nodes ranked by relevance and access frequency; pass `context` \
+                     to favor knowledge about the current project or file.".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
@@ -83,11 +229,13 @@ fn query_tool() -> Tool {
                     "description": "Maximum number of results to return",
                     "default": 10,
                     "minimum": 1,
-                    "maximum": 100
-                }
+                    "maximum": MAX_QUERY_LIMIT
+                },
+                "context": context::context_schema()
             },
             "required": ["query"]
-        })
+        }),
+        annotations: Some(ToolAnnotations::read_only()),
     }
 }
 
@@ -100,7 +248,8 @@ fn hybrid_search_tool() -> Tool {
         description: "Perform hybrid Graph-RAG search combining vector similarity \
                      and graph traversal for context-aware retrieval. \
                      Returns semantically related nodes along with their graph neighbors. \
-                     Best for discovering related concepts and exploring knowledge connections.".to_string(),
+                     Best for discovering related concepts and exploring knowledge connections. \
+                     Pass `context` to favor knowledge about the current project or file.".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
@@ -113,11 +262,13 @@ fn hybrid_search_tool() -> Tool {
                     "description": "Number of results to return",
                     "default": 10,
                     "minimum": 1,
-                    "maximum": 50
-                }
+                    "maximum": MAX_HYBRID_K
+                },
+                "context": context::context_schema()
             },
             "required": ["query"]
-        })
+        }),
+        annotations: Some(ToolAnnotations::read_only()),
     }
 }
 
@@ -140,7 +291,8 @@ fn get_node_tool() -> Tool {
                 }
             },
             "required": ["id"]
-        })
+        }),
+        annotations: Some(ToolAnnotations::read_only()),
     }
 }
 
@@ -175,10 +327,19 @@ fn traverse_tool() -> Tool {
                     "default": 50,
                     "minimum": 1,
                     "maximum": 500
+                },
+                "created_by": {
+                    "type": "array",
+                    "description": "Only follow edges made by these creators (e.g. [\"user\"] for human-curated links)",
+                    "items": {
+                        "type": "string",
+                        "enum": ["user", "tool", "extraction_pipeline"]
+                    }
                 }
             },
             "required": ["start_id"]
-        })
+        }),
+        annotations: Some(ToolAnnotations::read_only()),
     }
 }
 
@@ -224,10 +385,17 @@ fn add_edge_tool() -> Tool {
                     "default": 1.0,
                     "minimum": 0.0,
                     "maximum": 1.0
+                },
+                "confidence": {
+                    "type": "number",
+                    "description": "How confident you are that the relationship holds (0.0 - 1.0)",
+                    "minimum": 0.0,
+                    "maximum": 1.0
                 }
             },
             "required": ["source", "target"]
-        })
+        }),
+        annotations: Some(ToolAnnotations::write(false, false)),
     }
 }
 
@@ -244,7 +412,8 @@ fn stats_tool() -> Tool {
             "type": "object",
             "properties": {},
             "required": []
-        })
+        }),
+        annotations: Some(ToolAnnotations::read_only()),
     }
 }
 
@@ -261,7 +430,174 @@ fn list_nodes_tool() -> Tool {
             "type": "object",
             "properties": {},
             "required": []
-        })
+        }),
+        annotations: Some(ToolAnnotations::read_only()),
+    }
+}
+
+/// Tool: synton_feedback
+///
+/// Report whether a retrieved node was useful for a query.
+fn feedback_tool() -> Tool {
+    Tool {
+        name: "synton_feedback".to_string(),
+        description: "Report whether a node returned by synton_query or synton_hybrid_search \
+                     was useful. Useful nodes are reinforced and unhelpful ones decay faster, \
+                     and the relations connecting them are re-weighted for future retrieval. \
+                     Call this after using search results to improve future answers.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "query_id": {
+                    "type": "string",
+                    "description": "Query ID reported with the search results"
+                },
+                "node_id": {
+                    "type": "string",
+                    "description": "UUID of the node being rated"
+                },
+                "useful": {
+                    "type": "boolean",
+                    "description": "Whether the node helped answer the query"
+                }
+            },
+            "required": ["query_id", "node_id", "useful"]
+        }),
+        annotations: Some(ToolAnnotations::write(false, false)),
+    }
+}
+
+/// Tool: synton_pin
+///
+/// Pin a node so it never decays or is pruned.
+fn pin_tool() -> Tool {
+    Tool {
+        name: "synton_pin".to_string(),
+        description: "Pin a node so it never decays or is pruned from memory, or unpin it. \
+                     Use this for knowledge that must stay available indefinitely, such as \
+                     architecture decisions or security policies.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "string",
+                    "description": "Node UUID",
+                    "format": "uuid"
+                },
+                "pinned": {
+                    "type": "boolean",
+                    "description": "Pin (true) or unpin (false) the node",
+                    "default": true
+                },
+                "expected_version": {
+                    "type": "integer",
+                    "description": "Only apply if the node is still at this version, as returned \
+                                    by synton_get_node; fails if it changed since",
+                    "minimum": 1
+                }
+            },
+            "required": ["id"]
+        }),
+        annotations: Some(ToolAnnotations::write(false, true)),
+    }
+}
+
+/// Tool: synton_update_node
+///
+/// Correct the content or type of a node.
+fn update_node_tool() -> Tool {
+    Tool {
+        name: "synton_update_node".to_string(),
+        description: "Correct a node whose content is outdated or wrong, or whose type is \
+                     mistaken, keeping its ID and relationships. Changed content is re-embedded. \
+                     Use this when the user points out that stored knowledge is incorrect; \
+                     the server may ask to confirm the change first.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "string",
+                    "description": "Node UUID",
+                    "format": "uuid"
+                },
+                "content": {
+                    "type": "string",
+                    "description": "Corrected content, replacing the current content"
+                },
+                "node_type": {
+                    "type": "string",
+                    "description": "Corrected type of the node",
+                    "enum": ["entity", "concept", "fact", "raw_chunk"]
+                },
+                "expected_version": {
+                    "type": "integer",
+                    "description": "Only apply if the node is still at this version, as returned \
+                                    by synton_get_node; fails if it changed since",
+                    "minimum": 1
+                }
+            },
+            "required": ["id"]
+        }),
+        annotations: Some(ToolAnnotations::write(true, false)),
+    }
+}
+
+/// Tool: synton_delete_node
+///
+/// Delete a node and its relationships.
+fn delete_node_tool() -> Tool {
+    Tool {
+        name: "synton_delete_node".to_string(),
+        description: "Delete a node and all its relationships from SYNTON-DB. Use this only \
+                     when the user asks to forget knowledge that is wrong or no longer wanted; \
+                     prefer synton_update_node to correct it. The server may ask to confirm \
+                     the deletion first.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "string",
+                    "description": "Node UUID",
+                    "format": "uuid"
+                }
+            },
+            "required": ["id"]
+        }),
+        annotations: Some(ToolAnnotations::write(true, true)),
+    }
+}
+
+/// Tool: synton_retag
+///
+/// Add and remove tags of a node.
+fn retag_tool() -> Tool {
+    Tool {
+        name: "synton_retag".to_string(),
+        description: "Add tags to a node and remove tags from it, for example to fix a \
+                     misfiled node. Tags are normalized to lowercase. The server may ask to \
+                     confirm the change first.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "id": {
+                    "type": "string",
+                    "description": "Node UUID",
+                    "format": "uuid"
+                },
+                "add": {
+                    "type": "array",
+                    "description": "Tags to add",
+                    "items": { "type": "string" }
+                },
+                "remove": {
+                    "type": "array",
+                    "description": "Tags to remove",
+                    "items": { "type": "string" }
+                }
+            },
+            "required": ["id"]
+        }),
+        annotations: Some(ToolAnnotations::write(true, true)),
     }
 }
 
@@ -271,22 +607,141 @@ pub async fn execute_tool(
     name: &str,
     args: serde_json::Value,
 ) -> CallToolResult {
+    let format = match OutputFormat::from_args(&args) {
+        Ok(format) => format,
+        Err(e) => return CallToolResult::Error(e),
+    };
+
     match name {
-        "synton_absorb" => execute_absorb(client, args).await,
-        "synton_query" => execute_query(client, args).await,
-        "synton_hybrid_search" => execute_hybrid_search(client, args).await,
-        "synton_get_node" => execute_get_node(client, args).await,
-        "synton_traverse" => execute_traverse(client, args).await,
-        "synton_add_edge" => execute_add_edge(client, args).await,
-        "synton_stats" => execute_stats(client).await,
-        "synton_list_nodes" => execute_list_nodes(client).await,
+        "synton_absorb" => execute_absorb(client, args, format).await,
+        "synton_query" => execute_query(client, args, format).await,
+        "synton_hybrid_search" => execute_hybrid_search(client, args, format).await,
+        "synton_get_node" => execute_get_node(client, args, format).await,
+        "synton_traverse" => execute_traverse(client, args, format).await,
+        "synton_add_edge" => execute_add_edge(client, args, format).await,
+        "synton_stats" => execute_stats(client, format).await,
+        "synton_list_nodes" => execute_list_nodes(client, format).await,
+        "synton_feedback" => execute_feedback(client, args, format).await,
+        "synton_pin" => execute_pin(client, args, format).await,
+        "synton_update_node" => execute_update_node(client, args, format).await,
+        "synton_delete_node" => execute_delete_node(client, args, format).await,
+        "synton_retag" => execute_retag(client, args, format).await,
         _ => CallToolResult::Error(ToolError::new(format!("Unknown tool: {}", name))),
     }
 }
 
+/// Execute a call of a destructive tool once it is confirmed. A call
+/// without a confirmation token is not carried out; its result describes
+/// the change and carries a token to confirm it with.
+pub async fn execute_confirmed(
+    client: &SyntonDbClient,
+    confirmations: &Confirmations,
+    name: &str,
+    args: serde_json::Value,
+) -> CallToolResult {
+    if confirm::has_token(&args) {
+        return match confirmations.redeem(name, &args) {
+            Ok(()) => execute_tool(client, name, args).await,
+            Err(e) => CallToolResult::Error(e),
+        };
+    }
+
+    let format = match OutputFormat::from_args(&args) {
+        Ok(format) => format,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let change = match describe_change(client, name, &args).await {
+        Ok(change) => change,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let token = confirmations.issue(name, &args);
+
+    if format == OutputFormat::Json {
+        return structured(&json!({
+            "confirmation_required": true,
+            "change": change,
+            "confirmation_token": token,
+        }));
+    }
+    let text = format!(
+        "Not done yet, this change needs confirmation:\n\
+         {}\n\n\
+         Show it to the user. If they agree, call {} again with the same arguments and \
+         \"{}\": \"{}\" (valid for 5 minutes).",
+        change,
+        name,
+        confirm::TOKEN_ARG,
+        token
+    );
+    CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+}
+
+/// Describe what a destructive call would change, checking its arguments.
+async fn describe_change(
+    client: &SyntonDbClient,
+    name: &str,
+    args: &serde_json::Value,
+) -> Result<String, ToolError> {
+    let id = parse_uuid_arg(args, "id")?;
+    let change = match name {
+        "synton_update_node" => {
+            let update = UpdateArgs::parse(args)?;
+            let mut changes = Vec::new();
+            if let Some(content) = &update.content {
+                changes.push(format!("- New content: {}", truncate(content, 200)));
+            }
+            if let Some(node_type) = update.node_type {
+                changes.push(format!("- New type: {}", node_type));
+            }
+            format!("Update node {}:\n{}", id, changes.join("\n"))
+        }
+        "synton_delete_node" => format!("Delete node {} and all its relationships", id),
+        "synton_retag" => {
+            let (add, remove) = parse_retag_args(args)?;
+            let mut changes = Vec::new();
+            if !add.is_empty() {
+                changes.push(format!("- Add tags: {}", add.join(", ")));
+            }
+            if !remove.is_empty() {
+                changes.push(format!("- Remove tags: {}", remove.join(", ")));
+            }
+            format!("Retag node {}:\n{}", id, changes.join("\n"))
+        }
+        _ => return Err(ToolError::new(format!("Unknown tool: {}", name))),
+    };
+
+    // Show what the node holds now
+    let node = client
+        .get_node(id)
+        .await
+        .map_err(|e| ToolError::new(format!("Failed to get node: {}", e)))?
+        .ok_or_else(|| ToolError::new(format!("Node not found: {}", id)))?;
+    let tags: Vec<&str> = node.tags.iter().map(String::as_str).collect();
+    Ok(format!(
+        "{}\n\n\
+         Current node (version {}):\n\
+         - Type: {}\n\
+         - Content: {}\n\
+         - Tags: {}",
+        change,
+        node.meta.version,
+        node.node_type,
+        truncate(&node.content, 200),
+        if tags.is_empty() {
+            "none".to_string()
+        } else {
+            tags.join(", ")
+        }
+    ))
+}
+
 // Tool implementations
 
-async fn execute_absorb(client: &SyntonDbClient, args: serde_json::Value) -> CallToolResult {
+async fn execute_absorb(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
     // Parse arguments
     let content = match args.get("content") {
         Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
@@ -311,6 +766,7 @@ async fn execute_absorb(client: &SyntonDbClient, args: serde_json::Value) -> Cal
 
     // Call the API
     match client.add_node(content.clone(), node_type).await {
+        Ok(response) if format == OutputFormat::Json => structured(&response),
         Ok(response) => {
             let node = response.node;
             let created = if response.created { "created" } else { "existing" };
@@ -333,7 +789,11 @@ async fn execute_absorb(client: &SyntonDbClient, args: serde_json::Value) -> Cal
     }
 }
 
-async fn execute_query(client: &SyntonDbClient, args: serde_json::Value) -> CallToolResult {
+async fn execute_query(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
     let query = match args.get("query") {
         Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
         _ => {
@@ -348,7 +808,24 @@ async fn execute_query(client: &SyntonDbClient, args: serde_json::Value) -> Call
         .and_then(|v| v.as_u64())
         .unwrap_or(10) as usize;
 
-    match client.query(query.clone(), Some(limit)).await {
+    let active = match ActiveContext::from_args(&args) {
+        Ok(active) => active,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let fetch = match &active {
+        Some(_) => context::candidates(limit, MAX_QUERY_LIMIT),
+        None => limit,
+    };
+
+    let result = client.query(query.clone(), Some(fetch)).await.map(|mut result| {
+        if let Some(active) = &active {
+            result.truncated |= result.nodes.len() > limit;
+            result.nodes = active.rerank(result.nodes, limit);
+        }
+        result
+    });
+    match result {
+        Ok(result) if format == OutputFormat::Json => structured(&result),
         Ok(result) => {
             if result.nodes.is_empty() {
                 let text = format!(
@@ -361,10 +838,12 @@ async fn execute_query(client: &SyntonDbClient, args: serde_json::Value) -> Call
             } else {
                 let mut output = format!(
                     "Found {} result(s) for '{}':\n\
-                     Execution time: {}ms\n\n",
+                     Execution time: {}ms\n\
+                     Query ID: {}\n\n",
                     result.total_count,
                     query,
-                    result.execution_time_ms
+                    result.execution_time_ms,
+                    result.query_id
                 );
 
                 for (i, node) in result.nodes.iter().enumerate().take(limit) {
@@ -398,6 +877,7 @@ async fn execute_query(client: &SyntonDbClient, args: serde_json::Value) -> Call
 async fn execute_hybrid_search(
     client: &SyntonDbClient,
     args: serde_json::Value,
+    format: OutputFormat,
 ) -> CallToolResult {
     let query = match args.get("query") {
         Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
@@ -410,15 +890,36 @@ async fn execute_hybrid_search(
 
     let k = args.get("k").and_then(|v| v.as_u64()).unwrap_or(10) as usize;
 
-    match client.hybrid_search(query.clone(), k).await {
-        Ok(nodes) => {
-            if nodes.is_empty() {
+    let active = match ActiveContext::from_args(&args) {
+        Ok(active) => active,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let fetch = match &active {
+        Some(_) => context::candidates(k, MAX_HYBRID_K),
+        None => k,
+    };
+
+    let result = client.hybrid_search(query.clone(), fetch).await.map(|mut result| {
+        if let Some(active) = &active {
+            result.nodes = active.rerank(result.nodes, k);
+            result.count = result.nodes.len();
+        }
+        result
+    });
+    match result {
+        Ok(result) if format == OutputFormat::Json => structured(&result),
+        Ok(result) => {
+            if result.nodes.is_empty() {
                 let text = format!("Hybrid search returned no results for: '{}'", query);
                 CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
             } else {
-                let mut output = format!("Graph-RAG hybrid search results for '{}':\n\n", query);
+                let mut output = format!(
+                    "Graph-RAG hybrid search results for '{}':\n\
+                     Query ID: {}\n\n",
+                    query, result.query_id
+                );
 
-                for (i, node) in nodes.iter().enumerate() {
+                for (i, node) in result.nodes.iter().enumerate() {
                     output.push_str(&format!(
                         "{}. [{}] {}\n\
                            ID: {}\n\
@@ -441,7 +942,11 @@ async fn execute_hybrid_search(
     }
 }
 
-async fn execute_get_node(client: &SyntonDbClient, args: serde_json::Value) -> CallToolResult {
+async fn execute_get_node(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
     let id_str = match args.get("id") {
         Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
         _ => {
@@ -460,12 +965,16 @@ async fn execute_get_node(client: &SyntonDbClient, args: serde_json::Value) -> C
     };
 
     match client.get_node(id).await {
+        Ok(node) if format == OutputFormat::Json => {
+            structured(&json!({ "found": node.is_some(), "node": node }))
+        }
         Ok(Some(node)) => {
             let text = format!(
                 "Node found:\n\
                  - ID: {}\n\
                  - Type: {:?}\n\
                  - Content: {}\n\
+                 - Version: {}\n\
                  - Created: {}\n\
                  - Updated: {}\n\
                  - Access Score: {:.2}\n\
@@ -476,6 +985,7 @@ async fn execute_get_node(client: &SyntonDbClient, args: serde_json::Value) -> C
                 node.id,
                 node.node_type,
                 node.content,
+                node.meta.version,
                 node.meta.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
                 node.meta.updated_at.format("%Y-%m-%d %H:%M:%S UTC"),
                 node.meta.access_score,
@@ -494,7 +1004,11 @@ async fn execute_get_node(client: &SyntonDbClient, args: serde_json::Value) -> C
     }
 }
 
-async fn execute_traverse(client: &SyntonDbClient, args: serde_json::Value) -> CallToolResult {
+async fn execute_traverse(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
     let start_id_str = match args.get("start_id") {
         Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
         _ => {
@@ -524,7 +1038,23 @@ async fn execute_traverse(client: &SyntonDbClient, args: serde_json::Value) -> C
         .and_then(|v| v.as_u64())
         .unwrap_or(50) as usize;
 
-    match client.traverse(start_id, max_depth, max_nodes).await {
+    let mut created_by = Vec::new();
+    if let Some(creators) = args.get("created_by").and_then(|v| v.as_array()) {
+        for creator in creators {
+            match creator.as_str().map(str::parse::<EdgeCreator>) {
+                Some(Ok(creator)) => created_by.push(creator),
+                _ => {
+                    return CallToolResult::Error(ToolError::new(format!(
+                        "Invalid 'created_by' value: {}",
+                        creator
+                    )))
+                }
+            }
+        }
+    }
+
+    match client.traverse(start_id, max_depth, max_nodes, created_by).await {
+        Ok(result) if format == OutputFormat::Json => structured(&result),
         Ok(result) => {
             if result.nodes.is_empty() {
                 let text = format!("Graph traversal returned no nodes from: {}", start_id);
@@ -556,12 +1086,13 @@ async fn execute_traverse(client: &SyntonDbClient, args: serde_json::Value) -> C
                 output.push_str("Edges:\n");
                 for (i, edge) in result.edges.iter().enumerate().take(10) {
                     output.push_str(&format!(
-                        "  {}. {} --[{:?}]--> {} (weight: {:.2})\n",
+                        "  {}. {} --[{:?}]--> {} (weight: {:.2}, {})\n",
                         i + 1,
                         edge.source,
                         edge.relation,
                         edge.target,
-                        edge.weight
+                        edge.weight,
+                        format_provenance(&edge.provenance)
                     ));
                 }
 
@@ -580,7 +1111,11 @@ async fn execute_traverse(client: &SyntonDbClient, args: serde_json::Value) -> C
     }
 }
 
-async fn execute_add_edge(client: &SyntonDbClient, args: serde_json::Value) -> CallToolResult {
+async fn execute_add_edge(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
     let source_str = match args.get("source") {
         Some(serde_json::Value::String(s)) if !s.is_empty() => s.clone(),
         _ => {
@@ -640,12 +1175,24 @@ async fn execute_add_edge(client: &SyntonDbClient, args: serde_json::Value) -> C
         .and_then(|v| v.as_f64())
         .unwrap_or(1.0) as f32;
 
-    match client.add_edge(source, target, relation, weight).await {
+    // Edges created through MCP are attributed to the tool, not the user
+    let mut provenance = EdgeProvenance::new(EdgeCreator::Tool);
+    if let Some(confidence) = args.get("confidence").and_then(|v| v.as_f64()) {
+        provenance = provenance.with_confidence(confidence as f32);
+    }
+
+    match client.add_edge(source, target, relation, weight, provenance).await {
+        Ok(edge) if format == OutputFormat::Json => structured(&json!({ "edge": edge })),
         Ok(edge) => {
             let text = format!(
                 "Successfully created edge:\n\
-                 - {} --[{:?} (weight: {:.2})]--> {}",
-                edge.source, edge.relation, edge.weight, edge.target
+                 - {} --[{:?} (weight: {:.2})]--> {}\n\
+                 - Provenance: {}",
+                edge.source,
+                edge.relation,
+                edge.weight,
+                edge.target,
+                format_provenance(&edge.provenance)
             );
             CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
         }
@@ -653,8 +1200,9 @@ async fn execute_add_edge(client: &SyntonDbClient, args: serde_json::Value) -> C
     }
 }
 
-async fn execute_stats(client: &SyntonDbClient) -> CallToolResult {
+async fn execute_stats(client: &SyntonDbClient, format: OutputFormat) -> CallToolResult {
     match client.stats().await {
+        Ok(stats) if format == OutputFormat::Json => structured(&stats),
         Ok(stats) => {
             let text = format!(
                 "SYNTON-DB Statistics:\n\
@@ -679,8 +1227,11 @@ async fn execute_stats(client: &SyntonDbClient) -> CallToolResult {
     }
 }
 
-async fn execute_list_nodes(client: &SyntonDbClient) -> CallToolResult {
+async fn execute_list_nodes(client: &SyntonDbClient, format: OutputFormat) -> CallToolResult {
     match client.get_all_nodes().await {
+        Ok(nodes) if format == OutputFormat::Json => {
+            structured(&json!({ "count": nodes.len(), "nodes": nodes }))
+        }
         Ok(nodes) => {
             if nodes.is_empty() {
                 let text = "No nodes in database. Use synton_absorb to add knowledge.".to_string();
@@ -719,6 +1270,317 @@ async fn execute_list_nodes(client: &SyntonDbClient) -> CallToolResult {
     }
 }
 
+async fn execute_feedback(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
+    let query_id = match parse_uuid_arg(&args, "query_id") {
+        Ok(id) => id,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let node_id = match parse_uuid_arg(&args, "node_id") {
+        Ok(id) => id,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let useful = match args.get("useful").and_then(|v| v.as_bool()) {
+        Some(useful) => useful,
+        None => {
+            return CallToolResult::Error(ToolError::new(
+                "Missing or invalid 'useful' argument",
+            ))
+        }
+    };
+
+    match client.feedback(query_id, node_id, useful).await {
+        Ok(result) if format == OutputFormat::Json => structured(&result),
+        Ok(result) => {
+            let mut text = format!(
+                "Recorded {} feedback for node {}\n\
+                 - New access score: {:.2}",
+                if useful { "positive" } else { "negative" },
+                node_id,
+                result.access_score
+            );
+            for (relation, weight) in &result.relation_weights {
+                text.push_str(&format!("\n- Relation '{}' weight: {:.2}", relation, weight));
+            }
+            CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+        }
+        Err(e) => CallToolResult::Error(ToolError::new(format!(
+            "Failed to submit feedback: {}",
+            e
+        ))),
+    }
+}
+
+async fn execute_pin(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
+    let id = match parse_uuid_arg(&args, "id") {
+        Ok(id) => id,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let pinned = match args.get("pinned") {
+        None => true,
+        Some(value) => match value.as_bool() {
+            Some(pinned) => pinned,
+            None => {
+                return CallToolResult::Error(ToolError::new(
+                    "Invalid 'pinned' argument: expected a boolean",
+                ))
+            }
+        },
+    };
+
+    let expected_version = args.get("expected_version").and_then(|v| v.as_u64());
+
+    match client.pin_node(id, pinned, expected_version).await {
+        Ok(node) if format == OutputFormat::Json => structured(&json!({ "node": node })),
+        Ok(node) => {
+            let text = if node.meta.pinned {
+                format!(
+                    "Pinned node {} (now version {}); it is exempt from decay and pruning",
+                    node.id, node.meta.version
+                )
+            } else {
+                format!(
+                    "Unpinned node {} (now version {}); it decays like other nodes again",
+                    node.id, node.meta.version
+                )
+            };
+            CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+        }
+        Err(e) => CallToolResult::Error(ToolError::new(format!("Failed to pin node: {}", e))),
+    }
+}
+
+/// Arguments of a node update.
+struct UpdateArgs {
+    content: Option<String>,
+    node_type: Option<NodeType>,
+    expected_version: Option<u64>,
+}
+
+impl UpdateArgs {
+    /// Parse the update arguments; at least one change is required.
+    fn parse(args: &serde_json::Value) -> Result<Self, ToolError> {
+        let content = match args.get("content") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(s)) if !s.trim().is_empty() => Some(s.clone()),
+            Some(_) => return Err(ToolError::new("Invalid 'content' argument")),
+        };
+        let node_type = match args.get("node_type") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => match value.as_str().map(str::parse::<NodeType>) {
+                Some(Ok(node_type)) => Some(node_type),
+                _ => {
+                    return Err(ToolError::new(format!(
+                        "Invalid 'node_type' argument: {}",
+                        value
+                    )))
+                }
+            },
+        };
+        if content.is_none() && node_type.is_none() {
+            return Err(ToolError::new(
+                "Nothing to update: pass 'content', 'node_type' or both",
+            ));
+        }
+        Ok(Self {
+            content,
+            node_type,
+            expected_version: args.get("expected_version").and_then(|v| v.as_u64()),
+        })
+    }
+}
+
+/// Parse the tags to add and remove of a retag; at least one is required.
+fn parse_retag_args(args: &serde_json::Value) -> Result<(Vec<String>, Vec<String>), ToolError> {
+    let tags = |name: &str| -> Result<Vec<String>, ToolError> {
+        match args.get(name) {
+            None | Some(serde_json::Value::Null) => Ok(Vec::new()),
+            Some(serde_json::Value::Array(items)) => items
+                .iter()
+                .map(|item| match item.as_str() {
+                    Some(tag) if !tag.trim().is_empty() => Ok(tag.to_string()),
+                    _ => Err(ToolError::new(format!("Invalid '{}' tag: {}", name, item))),
+                })
+                .collect(),
+            Some(_) => Err(ToolError::new(format!(
+                "Invalid '{}' argument: expected an array of tags",
+                name
+            ))),
+        }
+    };
+    let (add, remove) = (tags("add")?, tags("remove")?);
+    if add.is_empty() && remove.is_empty() {
+        return Err(ToolError::new(
+            "Nothing to change: pass tags to 'add' or 'remove'",
+        ));
+    }
+    Ok((add, remove))
+}
+
+async fn execute_update_node(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
+    let id = match parse_uuid_arg(&args, "id") {
+        Ok(id) => id,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let update = match UpdateArgs::parse(&args) {
+        Ok(update) => update,
+        Err(e) => return CallToolResult::Error(e),
+    };
+
+    let result = client
+        .update_node(
+            id,
+            update.content,
+            update.node_type,
+            update.expected_version,
+        )
+        .await;
+    match result {
+        Ok(result) if format == OutputFormat::Json => structured(&result),
+        Ok(result) => {
+            let node = result.node;
+            let text = format!(
+                "Updated node {} (now version {}):\n\
+                 - Type: {}\n\
+                 - Content: {}\n\
+                 - Re-embedded: {}",
+                node.id,
+                node.meta.version,
+                node.node_type,
+                truncate(&node.content, 200),
+                result.reembedded
+            );
+            CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+        }
+        Err(e) => CallToolResult::Error(ToolError::new(format!("Failed to update node: {}", e))),
+    }
+}
+
+async fn execute_delete_node(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
+    let id = match parse_uuid_arg(&args, "id") {
+        Ok(id) => id,
+        Err(e) => return CallToolResult::Error(e),
+    };
+
+    match client.delete_node(id).await {
+        Ok(deleted) if format == OutputFormat::Json => {
+            structured(&json!({ "deleted": deleted, "id": id }))
+        }
+        Ok(true) => {
+            let text = format!("Deleted node {} and its relationships", id);
+            CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+        }
+        Ok(false) => {
+            let text = format!("Node not found: {}", id);
+            CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+        }
+        Err(e) => CallToolResult::Error(ToolError::new(format!("Failed to delete node: {}", e))),
+    }
+}
+
+async fn execute_retag(
+    client: &SyntonDbClient,
+    args: serde_json::Value,
+    format: OutputFormat,
+) -> CallToolResult {
+    let id = match parse_uuid_arg(&args, "id") {
+        Ok(id) => id,
+        Err(e) => return CallToolResult::Error(e),
+    };
+    let (add, remove) = match parse_retag_args(&args) {
+        Ok(tags) => tags,
+        Err(e) => return CallToolResult::Error(e),
+    };
+
+    match client.retag_node(id, add, remove).await {
+        Ok(result) if format == OutputFormat::Json => structured(&result),
+        Ok(result) => {
+            let tags = if result.tags.is_empty() {
+                "none".to_string()
+            } else {
+                result.tags.join(", ")
+            };
+            let text = format!("Retagged node {}; its tags are now: {}", id, tags);
+            CallToolResult::Success(vec![ToolContent::Text(ToolTextContent::new(text))])
+        }
+        Err(e) => CallToolResult::Error(ToolError::new(format!("Failed to retag node: {}", e))),
+    }
+}
+
+/// Build a JSON-format result.
+///
+/// Node embeddings are dropped; they are large and of no use to callers
+/// that only read the result.
+fn structured(value: &impl Serialize) -> CallToolResult {
+    match serde_json::to_value(value) {
+        Ok(mut value) => {
+            strip_embeddings(&mut value);
+            CallToolResult::Structured(value)
+        }
+        Err(e) => CallToolResult::Error(ToolError::new(format!(
+            "Failed to serialize result: {}",
+            e
+        ))),
+    }
+}
+
+/// Remove `embedding` fields from every node object in a JSON value.
+fn strip_embeddings(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if map.contains_key("node_type") {
+                map.remove("embedding");
+            }
+            map.values_mut().for_each(strip_embeddings);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(strip_embeddings),
+        _ => {}
+    }
+}
+
+/// Parse a required UUID argument.
+fn parse_uuid_arg(args: &serde_json::Value, name: &str) -> Result<Uuid, ToolError> {
+    let value = match args.get(name) {
+        Some(serde_json::Value::String(s)) if !s.is_empty() => s,
+        _ => {
+            return Err(ToolError::new(format!(
+                "Missing or invalid '{}' argument",
+                name
+            )))
+        }
+    };
+
+    Uuid::parse_str(value)
+        .map_err(|_| ToolError::new(format!("Invalid UUID format: '{}'", value)))
+}
+
+/// Describe where an edge came from.
+fn format_provenance(provenance: &EdgeProvenance) -> String {
+    let mut text = format!("created by {}", provenance.created_by);
+    if let Some(document) = provenance.source_document {
+        text.push_str(&format!(" from document {}", document));
+    }
+    if let Some(confidence) = provenance.confidence {
+        text.push_str(&format!(", confidence {:.2}", confidence));
+    }
+    text
+}
+
 /// Truncate a string to a maximum length, adding "..." if truncated.
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -735,7 +1597,7 @@ mod tests {
     #[test]
     fn test_get_all_tools() {
         let tools = get_all_tools();
-        assert_eq!(tools.len(), 8);
+        assert_eq!(tools.len(), 13);
 
         let tool_names: Vec<_> = tools.iter().map(|t| t.name.clone()).collect();
         assert!(tool_names.contains(&"synton_absorb".to_string()));
@@ -746,6 +1608,11 @@ mod tests {
         assert!(tool_names.contains(&"synton_add_edge".to_string()));
         assert!(tool_names.contains(&"synton_stats".to_string()));
         assert!(tool_names.contains(&"synton_list_nodes".to_string()));
+        assert!(tool_names.contains(&"synton_feedback".to_string()));
+        assert!(tool_names.contains(&"synton_pin".to_string()));
+        assert!(tool_names.contains(&"synton_update_node".to_string()));
+        assert!(tool_names.contains(&"synton_delete_node".to_string()));
+        assert!(tool_names.contains(&"synton_retag".to_string()));
     }
 
     #[test]
@@ -760,6 +1627,143 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tool_annotations() {
+        // Only the curation tools change or remove existing knowledge, and
+        // only they take a confirmation token
+        let curation = ["synton_update_node", "synton_delete_node", "synton_retag"];
+        for tool in get_all_tools() {
+            let annotations = tool.annotations.unwrap_or_else(|| panic!("{} has no annotations", tool.name));
+            let destructive = curation.contains(&tool.name.as_str());
+            assert_eq!(annotations.destructive_hint, destructive, "{}", tool.name);
+            assert_eq!(is_destructive(&tool.name), destructive, "{}", tool.name);
+            let takes_token = tool.input_schema["properties"]
+                .get(confirm::TOKEN_ARG)
+                .is_some();
+            assert_eq!(takes_token, destructive, "{}", tool.name);
+        }
+        let read_only: Vec<_> = ToolFilter::all()
+            .with_read_only(true)
+            .tools()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert!(read_only.contains(&"synton_query".to_string()));
+        assert!(!read_only.contains(&"synton_absorb".to_string()));
+        assert!(!read_only.contains(&"synton_add_edge".to_string()));
+        assert!(!read_only.contains(&"synton_pin".to_string()));
+        assert!(!read_only.contains(&"synton_delete_node".to_string()));
+    }
+
+    #[test]
+    fn test_curation_args() {
+        let update = UpdateArgs::parse(&json!({"id": "x", "node_type": "fact"})).unwrap();
+        assert_eq!(update.node_type, Some(NodeType::Fact));
+        assert!(update.content.is_none());
+        assert!(UpdateArgs::parse(&json!({"id": "x"})).is_err());
+        assert!(UpdateArgs::parse(&json!({"content": "  "})).is_err());
+        assert!(UpdateArgs::parse(&json!({"node_type": "idea"})).is_err());
+
+        let (add, remove) = parse_retag_args(&json!({"add": ["a"], "remove": ["b", "c"]})).unwrap();
+        assert_eq!(add, vec!["a"]);
+        assert_eq!(remove, vec!["b", "c"]);
+        assert!(parse_retag_args(&json!({"add": []})).is_err());
+        assert!(parse_retag_args(&json!({"add": [1]})).is_err());
+        assert!(parse_retag_args(&json!({"remove": "a"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_required() {
+        let client = SyntonDbClient::new();
+        let confirmations = Confirmations::default();
+
+        // Invalid calls fail before a token is issued
+        let result = execute_confirmed(
+            &client,
+            &confirmations,
+            "synton_retag",
+            json!({"id": Uuid::new_v4().to_string()}),
+        )
+        .await;
+        assert!(matches!(result, CallToolResult::Error(_)));
+
+        // A wrong token is rejected without calling the server
+        let result = execute_confirmed(
+            &client,
+            &confirmations,
+            "synton_delete_node",
+            json!({"id": Uuid::new_v4().to_string(), "confirmation_token": "guess"}),
+        )
+        .await;
+        match result {
+            CallToolResult::Error(e) => assert!(e.message.contains("confirmation token")),
+            other => panic!("expected an error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_tool_filter() {
+        assert_eq!(ToolFilter::all().tools().len(), get_all_tools().len());
+
+        let filter = ToolFilter::only(vec![
+            "synton_query".to_string(),
+            "synton_traverse".to_string(),
+            "synton_absorb".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(filter.tools().len(), 3);
+        assert!(filter.allows_name("synton_absorb"));
+        assert!(!filter.allows_name("synton_stats"));
+
+        // Named tools that write are dropped by the read-only filter
+        let filter = filter.with_read_only(true);
+        assert_eq!(filter.tools().len(), 2);
+        assert!(!filter.allows_name("synton_absorb"));
+        assert!(!filter.allows_name("synton_unknown"));
+
+        assert!(ToolFilter::only(vec!["synton_drop".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_format_argument() {
+        for tool in get_all_tools() {
+            assert_eq!(
+                tool.input_schema["properties"]["format"]["enum"],
+                json!(["text", "json"]),
+                "{} is missing the format argument",
+                tool.name
+            );
+        }
+
+        assert_eq!(OutputFormat::from_args(&json!({})).unwrap(), OutputFormat::Text);
+        assert_eq!(
+            OutputFormat::from_args(&json!({"format": "json"})).unwrap(),
+            OutputFormat::Json
+        );
+        assert!(OutputFormat::from_args(&json!({"format": "xml"})).is_err());
+    }
+
+    #[test]
+    fn test_structured_strips_embeddings() {
+        let node = synton_core::Node::new("Rust", NodeType::Concept).with_embedding(vec![0.1; 4]);
+        let result = structured(&json!({ "nodes": [node] }));
+
+        match result {
+            CallToolResult::Structured(value) => {
+                assert_eq!(value["nodes"][0]["content"], "Rust");
+                assert!(value["nodes"][0].get("embedding").is_none());
+            }
+            other => panic!("expected structured result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_format_provenance() {
+        let provenance = EdgeProvenance::new(EdgeCreator::Tool).with_confidence(0.5);
+        assert_eq!(format_provenance(&provenance), "created by tool, confidence 0.50");
+        assert_eq!(format_provenance(&EdgeProvenance::default()), "created by user");
+    }
+
     #[test]
     fn test_truncate() {
         assert_eq!(truncate("hello", 10), "hello");
@@ -4,10 +4,37 @@
 
 use crate::error::MemoryResult;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
+use synton_core::{Node, NodeType};
+
+/// Node attribute holding the namespace used to select a decay profile.
+pub const NAMESPACE_ATTRIBUTE: &str = "namespace";
+
+/// Node attribute marking a generated summary, which selects the summary
+/// decay profile.
+pub const SUMMARY_ATTRIBUTE: &str = "summary";
+
+/// Name of the profile of summary nodes.
+pub const SUMMARY_PROFILE: &str = "summary";
+
+/// Name of the profile used by nodes without an override.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// What happens to nodes that decay below the minimum score when pruning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieringPolicy {
+    /// Move nodes to the cold archive tier (without embeddings).
+    #[default]
+    Archive,
+    /// Permanently delete nodes.
+    Delete,
+}
 
 /// Configuration for memory decay calculations.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct DecayConfig {
     /// Decay rate lambda (per hour).
     /// Default: 0.0015 (approx 20% retention after 24h)
@@ -24,6 +51,9 @@ pub struct DecayConfig {
 
     /// Whether to clamp scores to [min_score, max_score].
     pub clamp_scores: bool,
+
+    /// What to do with nodes pruned below `min_score`.
+    pub tiering: TieringPolicy,
 }
 
 impl Default for DecayConfig {
@@ -34,6 +64,7 @@ impl Default for DecayConfig {
             max_score: 10.0,
             access_boost: 1.0,
             clamp_scores: true,
+            tiering: TieringPolicy::Archive,
         }
     }
 }
@@ -71,6 +102,12 @@ impl DecayConfig {
         self
     }
 
+    /// Set the tiering policy for pruned nodes.
+    pub fn with_tiering(mut self, tiering: TieringPolicy) -> Self {
+        self.tiering = tiering;
+        self
+    }
+
     /// Validate the configuration.
     pub fn validate(&self) -> MemoryResult<()> {
         if !(0.0..=1.0).contains(&self.lambda) {
@@ -99,6 +136,87 @@ impl DecayConfig {
     }
 }
 
+/// Decay overrides for groups of nodes.
+///
+/// A node's profile is chosen when it is registered: its namespace (the
+/// [`NAMESPACE_ATTRIBUTE`] attribute) takes precedence over being a summary
+/// (the [`SUMMARY_ATTRIBUTE`] attribute), which takes precedence over its
+/// node type; nodes matching none use the manager's default config.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecayProfiles {
+    /// Overrides keyed by namespace.
+    pub namespaces: HashMap<String, DecayConfig>,
+
+    /// Overrides keyed by node type.
+    pub node_types: HashMap<NodeType, DecayConfig>,
+
+    /// Override for generated summaries.
+    pub summaries: Option<DecayConfig>,
+}
+
+impl DecayProfiles {
+    /// Create an empty set of profiles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the decay config of a namespace.
+    pub fn with_namespace(mut self, namespace: impl Into<String>, config: DecayConfig) -> Self {
+        self.namespaces.insert(namespace.into(), config);
+        self
+    }
+
+    /// Override the decay config of a node type.
+    pub fn with_node_type(mut self, node_type: NodeType, config: DecayConfig) -> Self {
+        self.node_types.insert(node_type, config);
+        self
+    }
+
+    /// Override the decay config of generated summaries.
+    pub fn with_summaries(mut self, config: DecayConfig) -> Self {
+        self.summaries = Some(config);
+        self
+    }
+
+    /// Check if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.namespaces.is_empty() && self.node_types.is_empty() && self.summaries.is_none()
+    }
+
+    /// Select the profile for a node: its name and config, or `None` for
+    /// the default profile.
+    pub fn select(&self, node: &Node) -> Option<(String, &DecayConfig)> {
+        let namespace = node
+            .attributes
+            .get(NAMESPACE_ATTRIBUTE)
+            .and_then(|value| value.as_str());
+        if let Some((namespace, config)) =
+            namespace.and_then(|ns| self.namespaces.get_key_value(ns))
+        {
+            return Some((format!("namespace:{}", namespace), config));
+        }
+        if let Some(config) = &self.summaries {
+            if node.attributes.get(SUMMARY_ATTRIBUTE).is_some() {
+                return Some((SUMMARY_PROFILE.to_string(), config));
+            }
+        }
+
+        self.node_types
+            .get(&node.node_type)
+            .map(|config| (format!("node_type:{}", node.node_type), config))
+    }
+
+    /// Validate every profile.
+    pub fn validate(&self) -> MemoryResult<()> {
+        self.namespaces
+            .values()
+            .chain(self.node_types.values())
+            .chain(&self.summaries)
+            .try_for_each(DecayConfig::validate)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +263,46 @@ mod tests {
         let factor2 = config.decay_factor(hundred_hours);
         assert!((factor2 - 0.905).abs() < 0.001);
     }
+
+    #[test]
+    fn test_profile_selection() {
+        let profiles = DecayProfiles::new()
+            .with_node_type(NodeType::Fact, DecayConfig::new().with_lambda(0.0005).unwrap())
+            .with_namespace("scratch", DecayConfig::new().with_lambda(0.01).unwrap());
+
+        let fact = Node::new("Paris is in France", NodeType::Fact);
+        assert_eq!(profiles.select(&fact).unwrap().0, "node_type:fact");
+
+        // Namespace wins over node type
+        let scratch = fact.clone().with_attributes(serde_json::json!({"namespace": "scratch"}));
+        let (name, config) = profiles.select(&scratch).unwrap();
+        assert_eq!(name, "namespace:scratch");
+        assert_eq!(config.lambda, 0.01);
+
+        // Summaries only have a profile once one is configured
+        let summary = fact.with_attributes(serde_json::json!({ SUMMARY_ATTRIBUTE: {} }));
+        assert_eq!(profiles.select(&summary).unwrap().0, "node_type:fact");
+        let profiles = profiles.with_summaries(DecayConfig::new().with_lambda(0.0002).unwrap());
+        let (name, config) = profiles.select(&summary).unwrap();
+        assert_eq!(name, SUMMARY_PROFILE);
+        assert_eq!(config.lambda, 0.0002);
+
+        assert!(profiles.select(&Node::new("Rust", NodeType::Concept)).is_none());
+    }
+
+    #[test]
+    fn test_profiles_deserialize_partially() {
+        let profiles: DecayProfiles = serde_json::from_value(serde_json::json!({
+            "node_types": { "raw_chunk": { "lambda": 0.005 } }
+        }))
+        .unwrap();
+        let config = &profiles.node_types[&NodeType::RawChunk];
+        assert_eq!(config.lambda, 0.005);
+        assert_eq!(config.max_score, 10.0);
+        assert!(profiles.validate().is_ok());
+
+        let invalid = DecayProfiles::new()
+            .with_namespace("bad", DecayConfig::new().with_min_score(5.0).with_max_score(1.0));
+        assert!(invalid.validate().is_err());
+    }
 }
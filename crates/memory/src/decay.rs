@@ -165,10 +165,14 @@ impl DecayCalculator {
 
     /// Calculate the current score for a node.
     ///
-    /// This considers the node's access_score and time since last access.
+    /// This considers the node's access_score and time since last access;
+    /// pinned nodes do not decay.
     pub fn current_score(&self, node: &Node) -> f32 {
         let initial = node.meta.access_score;
 
+        if node.meta.pinned {
+            return initial;
+        }
         if let Some(accessed) = node.meta.accessed_at {
             let duration = chrono::Utc::now().signed_duration_since(accessed);
             let decayed = self.decayed_score(initial, duration.to_std().unwrap_or_default());
@@ -182,6 +186,15 @@ impl DecayCalculator {
         }
     }
 
+    /// Current memory strength of a node: its decayed score relative to
+    /// the maximum score, from 0.0 to 1.0.
+    pub fn strength(&self, node: &Node) -> f32 {
+        if self.config.max_score <= 0.0 {
+            return 0.0;
+        }
+        (self.current_score(node) / self.config.max_score).clamp(0.0, 1.0)
+    }
+
     /// Apply memory boost (simulate access strengthening).
     pub fn boost(&self, current_score: f32, access_count: usize) -> f32 {
         let boost = self.config.access_boost * access_count as f32;
@@ -194,9 +207,45 @@ impl DecayCalculator {
         }
     }
 
-    /// Check if a node should be pruned (score too low).
+    /// Apply a memory penalty (the inverse of [`boost`](Self::boost)).
+    pub fn penalize(&self, current_score: f32, penalty_count: usize) -> f32 {
+        let penalty = self.config.access_boost * penalty_count as f32;
+        let penalized = current_score - penalty;
+
+        if self.config.clamp_scores {
+            penalized.clamp(self.config.min_score, self.config.max_score)
+        } else {
+            penalized.max(0.0)
+        }
+    }
+
+    /// Check if a node should be pruned (score too low and not pinned).
     pub fn should_prune(&self, node: &Node) -> bool {
-        self.current_score(node) < self.config.min_score
+        !node.meta.pinned && self.current_score(node) < self.config.min_score
+    }
+
+    /// Time until a node's decaying score reaches the minimum score, where
+    /// it becomes a pruning candidate: zero if it already has, `None` if it
+    /// never will (the node is pinned, was never accessed or nothing
+    /// decays).
+    pub fn time_to_min_score(&self, node: &Node) -> Option<Duration> {
+        if node.meta.pinned {
+            return None;
+        }
+        let accessed = node.meta.accessed_at?;
+        if self.config.lambda <= 0.0 || self.config.min_score <= 0.0 {
+            return None;
+        }
+
+        let elapsed = chrono::Utc::now().signed_duration_since(accessed);
+        let score = self.decayed_score(node.meta.access_score, elapsed.to_std().unwrap_or_default());
+        if score <= self.config.min_score {
+            return Some(Duration::ZERO);
+        }
+
+        // score * e^(-λt) = min_score
+        let hours = (score / self.config.min_score).ln() as f64 / self.config.lambda as f64;
+        Some(Duration::from_secs_f64(hours * 3600.0))
     }
 
     /// Calculate the retention rate for a node.
@@ -210,6 +259,11 @@ impl DecayCalculator {
         }
     }
 
+    /// Get the decay curve type.
+    pub fn curve(&self) -> DecayCurve {
+        self.curve
+    }
+
     /// Get the calculator config.
     pub fn config(&self) -> &DecayConfig {
         &self.config
@@ -267,6 +321,14 @@ mod tests {
         assert_eq!(boosted, 8.0); // Clamped to max
     }
 
+    #[test]
+    fn test_penalize_with_clamp() {
+        let calc = DecayCalculator::new();
+
+        assert_eq!(calc.penalize(5.0, 2), 3.0); // 5 - 2*1 = 3
+        assert_eq!(calc.penalize(0.5, 1), 0.1); // Clamped to min
+    }
+
     #[test]
     fn test_current_score() {
         let calc = DecayCalculator::new();
@@ -276,6 +338,38 @@ mod tests {
         assert_eq!(score, 1.0); // Initial score
     }
 
+    #[test]
+    fn test_strength_decays_since_access() {
+        let calc = DecayCalculator::new();
+        let mut node = Node::new("test", NodeType::Concept);
+        node.meta.access_score = 5.0;
+        node.meta.accessed_at = Some(Utc::now());
+        let fresh = calc.strength(&node);
+        assert!((fresh - 0.5).abs() < 1e-3);
+
+        node.meta.accessed_at = Some(Utc::now() - chrono::Duration::days(30));
+        assert!(calc.strength(&node) < fresh);
+    }
+
+    #[test]
+    fn test_time_to_min_score() {
+        let calc = DecayCalculator::new();
+        let mut node = Node::new("test", NodeType::Concept);
+        assert_eq!(calc.time_to_min_score(&node), None);
+
+        // 1.0 decays to 0.1 after ln(10) / 0.0015 ≈ 1535 hours
+        node.meta.accessed_at = Some(Utc::now());
+        let hours = calc.time_to_min_score(&node).unwrap().as_secs_f64() / 3600.0;
+        assert!((hours - 1535.0).abs() < 1.0);
+
+        node.meta.accessed_at = Some(Utc::now() - chrono::Duration::hours(1000));
+        let hours = calc.time_to_min_score(&node).unwrap().as_secs_f64() / 3600.0;
+        assert!((hours - 535.0).abs() < 1.0);
+
+        node.meta.accessed_at = Some(Utc::now() - chrono::Duration::hours(2000));
+        assert_eq!(calc.time_to_min_score(&node), Some(Duration::ZERO));
+    }
+
     #[test]
     fn test_forgetting_curve() {
         let curve = ForgettingCurve::Standard;
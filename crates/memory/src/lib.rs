@@ -15,7 +15,10 @@ mod decay;
 mod error;
 mod manager;
 
-pub use config::DecayConfig;
+pub use config::{
+    DecayConfig, DecayProfiles, TieringPolicy, DEFAULT_PROFILE, NAMESPACE_ATTRIBUTE, SUMMARY_ATTRIBUTE,
+    SUMMARY_PROFILE,
+};
 pub use decay::{DecayCalculator, DecayCurve, ForgettingCurve};
 pub use error::{MemoryError, MemoryResult};
 pub use manager::{MemoryManager, MemoryStats, PruneResult};
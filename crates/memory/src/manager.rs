@@ -7,7 +7,7 @@ use std::time::Duration;
 use uuid::Uuid;
 
 use crate::{
-    config::DecayConfig,
+    config::{DecayConfig, DecayProfiles, TieringPolicy, DEFAULT_PROFILE, SUMMARY_PROFILE},
     decay::{DecayCalculator, DecayCurve},
     error::{MemoryError, MemoryResult},
 };
@@ -76,6 +76,10 @@ pub struct PruneResult {
     /// Number of nodes pruned.
     pub count: usize,
 
+    /// IDs of pruned nodes that were moved to the archive tier rather than
+    /// deleted.
+    pub archived_ids: Vec<Uuid>,
+
     /// Total score reclaimed.
     pub score_reclaimed: f32,
 
@@ -90,6 +94,7 @@ impl PruneResult {
         Self {
             pruned_ids,
             count,
+            archived_ids: Vec::new(),
             score_reclaimed,
             duration_ms,
         }
@@ -100,6 +105,7 @@ impl PruneResult {
         Self {
             pruned_ids: Vec::new(),
             count: 0,
+            archived_ids: Vec::new(),
             score_reclaimed: 0.0,
             duration_ms: 0,
         }
@@ -116,6 +122,14 @@ impl PruneResult {
 pub struct MemoryManager {
     calculator: DecayCalculator,
     nodes: HashMap<Uuid, Node>,
+    /// Cold tier: pruned nodes kept without embeddings.
+    archived: HashMap<Uuid, Node>,
+    /// Decay overrides by namespace, for summaries and by node type.
+    profiles: DecayProfiles,
+    /// Calculators of the override profiles, by profile name.
+    profile_calculators: HashMap<String, DecayCalculator>,
+    /// Profile selected for each node at registration (default if absent).
+    assigned: HashMap<Uuid, String>,
 }
 
 impl MemoryManager {
@@ -124,6 +138,10 @@ impl MemoryManager {
         Self {
             calculator: DecayCalculator::new(),
             nodes: HashMap::new(),
+            archived: HashMap::new(),
+            profiles: DecayProfiles::default(),
+            profile_calculators: HashMap::new(),
+            assigned: HashMap::new(),
         }
     }
 
@@ -132,6 +150,10 @@ impl MemoryManager {
         Self {
             calculator: DecayCalculator::with_config(config),
             nodes: HashMap::new(),
+            archived: HashMap::new(),
+            profiles: DecayProfiles::default(),
+            profile_calculators: HashMap::new(),
+            assigned: HashMap::new(),
         }
     }
 
@@ -140,25 +162,151 @@ impl MemoryManager {
         Self {
             calculator: DecayCalculator::new().with_curve(curve),
             nodes: HashMap::new(),
+            archived: HashMap::new(),
+            profiles: DecayProfiles::default(),
+            profile_calculators: HashMap::new(),
+            assigned: HashMap::new(),
         }
     }
 
     /// Register a node for memory tracking.
+    ///
+    /// The node's decay profile is selected here, from its namespace,
+    /// whether it is a summary, and its node type.
     pub fn register(&mut self, node: Node) -> MemoryResult<()> {
+        self.assign_profile(&node);
         self.nodes.insert(node.id, node);
         Ok(())
     }
 
-    /// Unregister a node from tracking.
+    /// Unregister a node from tracking (active or archived).
     pub fn unregister(&mut self, id: Uuid) -> Option<Node> {
-        self.nodes.remove(&id)
+        self.assigned.remove(&id);
+        self.nodes.remove(&id).or_else(|| self.archived.remove(&id))
+    }
+
+    /// Register a node that is already in the archive tier.
+    pub fn register_archived(&mut self, mut node: Node) {
+        node.embedding = None;
+        self.assign_profile(&node);
+        self.archived.insert(node.id, node);
+    }
+
+    /// Set the decay overrides by namespace and node type.
+    ///
+    /// Already registered nodes are reassigned to the new profiles.
+    pub fn set_profiles(&mut self, profiles: DecayProfiles) -> MemoryResult<()> {
+        profiles.validate()?;
+        self.profiles = profiles;
+        self.rebuild_profile_calculators();
+
+        self.assigned.clear();
+        let nodes: Vec<Node> = self
+            .nodes
+            .values()
+            .chain(self.archived.values())
+            .cloned()
+            .collect();
+        for node in &nodes {
+            self.assign_profile(node);
+        }
+        Ok(())
+    }
+
+    /// Get the decay overrides.
+    pub fn profiles(&self) -> &DecayProfiles {
+        &self.profiles
+    }
+
+    /// Name of the decay profile a node was assigned.
+    pub fn profile_of(&self, id: Uuid) -> &str {
+        self.assigned
+            .get(&id)
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Record the profile selected for a node.
+    fn assign_profile(&mut self, node: &Node) {
+        match self.profiles.select(node) {
+            Some((name, _)) => {
+                self.assigned.insert(node.id, name);
+            }
+            None => {
+                self.assigned.remove(&node.id);
+            }
+        }
+    }
+
+    /// Create a calculator for every override profile, sharing the default
+    /// calculator's curve.
+    fn rebuild_profile_calculators(&mut self) {
+        let curve = self.calculator.curve();
+        let namespaces = self
+            .profiles
+            .namespaces
+            .iter()
+            .map(|(ns, config)| (format!("namespace:{}", ns), config));
+        let node_types = self
+            .profiles
+            .node_types
+            .iter()
+            .map(|(node_type, config)| (format!("node_type:{}", node_type), config));
+        let summaries = self
+            .profiles
+            .summaries
+            .iter()
+            .map(|config| (SUMMARY_PROFILE.to_string(), config));
+
+        self.profile_calculators = namespaces
+            .chain(node_types)
+            .chain(summaries)
+            .map(|(name, config)| {
+                (name, DecayCalculator::with_config(config.clone()).with_curve(curve))
+            })
+            .collect();
+    }
+
+    /// Calculator of the profile a node was assigned.
+    fn calculator_of(&self, id: Uuid) -> &DecayCalculator {
+        self.assigned
+            .get(&id)
+            .and_then(|name| self.profile_calculators.get(name))
+            .unwrap_or(&self.calculator)
+    }
+
+    /// Check if a node is in the archive tier.
+    pub fn is_archived(&self, id: Uuid) -> bool {
+        self.archived.contains_key(&id)
+    }
+
+    /// Get an archived node without restoring it.
+    pub fn get_archived(&self, id: Uuid) -> Option<&Node> {
+        self.archived.get(&id)
+    }
+
+    /// Get all archived nodes.
+    pub fn archived_nodes(&self) -> &HashMap<Uuid, Node> {
+        &self.archived
+    }
+
+    /// Restore an archived node to the active set.
+    ///
+    /// Restoring counts as an access, so the node's score is boosted and it
+    /// is not immediately pruned again.
+    pub fn restore(&mut self, id: Uuid) -> Option<Node> {
+        let node = self.archived.remove(&id)?;
+        self.nodes.insert(id, node);
+        let _ = self.record_access(id);
+        self.nodes.get(&id).cloned()
     }
 
     /// Record an access to a node (strengthens memory).
     pub fn record_access(&mut self, id: Uuid) -> MemoryResult<()> {
+        let calculator = self.calculator_of(id).clone();
         if let Some(node) = self.nodes.get_mut(&id) {
-            let current = self.calculator.current_score(node);
-            let boosted = self.calculator.boost(current, 1);
+            let current = calculator.current_score(node);
+            let boosted = calculator.boost(current, 1);
 
             // Update the node's metadata
             node.meta.access_score = boosted;
@@ -168,6 +316,28 @@ impl MemoryManager {
         Ok(())
     }
 
+    /// Record negative feedback for a node (weakens memory).
+    ///
+    /// Unlike [`record_access`](Self::record_access) this does not refresh
+    /// the access timestamp, so the node keeps decaying from where it was.
+    pub fn record_penalty(&mut self, id: Uuid) -> MemoryResult<()> {
+        let calculator = self.calculator_of(id).clone();
+        let node = self
+            .nodes
+            .get_mut(&id)
+            .ok_or(MemoryError::NodeNotFound(id))?;
+        let current = calculator.current_score(node);
+        let penalized = calculator.penalize(current, 1);
+
+        // Re-anchor the decayed score so it is not decayed twice
+        node.meta.access_score = penalized;
+        if node.meta.accessed_at.is_some() {
+            node.meta.accessed_at = Some(chrono::Utc::now());
+        }
+
+        Ok(())
+    }
+
     /// Record multiple accesses (e.g., from a batch operation).
     pub fn record_access_batch(&mut self, ids: &[Uuid]) -> MemoryResult<()> {
         for id in ids {
@@ -180,15 +350,30 @@ impl MemoryManager {
     pub fn get_score(&self, id: Uuid) -> MemoryResult<f32> {
         self.nodes
             .get(&id)
-            .map(|node| self.calculator.current_score(node))
+            .map(|node| self.calculator_of(id).current_score(node))
             .ok_or(MemoryError::NodeNotFound(id))
     }
 
+    /// Current memory strength of a node (0.0 - 1.0).
+    ///
+    /// Uses the tracked copy when the node is registered, since that copy
+    /// carries the latest accesses; otherwise decays the given node's own
+    /// score.
+    pub fn strength(&self, node: &Node) -> f32 {
+        match self.nodes.get(&node.id) {
+            Some(tracked) => self.calculator_of(node.id).strength(tracked),
+            None => match self.profiles.select(node) {
+                Some((name, _)) => self.profile_calculators[&name].strength(node),
+                None => self.calculator.strength(node),
+            },
+        }
+    }
+
     /// Get all node scores.
     pub fn get_all_scores(&self) -> HashMap<Uuid, f32> {
         self.nodes
             .iter()
-            .map(|(id, node)| (*id, self.calculator.current_score(node)))
+            .map(|(id, node)| (*id, self.calculator_of(*id).current_score(node)))
             .collect()
     }
 
@@ -196,51 +381,110 @@ impl MemoryManager {
     pub fn get_retention(&self, id: Uuid) -> MemoryResult<f64> {
         self.nodes
             .get(&id)
-            .map(|node| self.calculator.retention(node))
+            .map(|node| self.calculator_of(id).retention(node))
             .ok_or(MemoryError::NodeNotFound(id))
     }
 
-    /// Prune nodes that have decayed below the threshold.
+    /// Active nodes whose scores decay to their profile's minimum within
+    /// `within`, with the time left, soonest first.
+    pub fn expiring(&self, within: Duration) -> Vec<(Uuid, Duration)> {
+        let mut expiring: Vec<(Uuid, Duration)> = self
+            .nodes
+            .iter()
+            .filter_map(|(id, node)| {
+                let left = self.calculator_of(*id).time_to_min_score(node)?;
+                (left <= within).then_some((*id, left))
+            })
+            .collect();
+        expiring.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        expiring
+    }
+
+    /// Prune nodes that have decayed below the threshold. Pinned nodes are
+    /// never pruned.
+    ///
+    /// Depending on the [`TieringPolicy`] of each node's profile, pruned
+    /// nodes are either moved to the archive tier (with their embeddings
+    /// dropped) or deleted.
     pub fn prune(&mut self) -> MemoryResult<PruneResult> {
         let start = std::time::Instant::now();
-        let min_score = self.calculator.config().min_score;
 
         let mut pruned_ids = Vec::new();
         let mut score_reclaimed = 0.0;
 
-        self.nodes.retain(|id, node| {
-            let score = self.calculator.current_score(node);
+        for (id, node) in &self.nodes {
+            let calculator = self.calculator_of(*id);
+            let score = calculator.current_score(node);
 
-            if score < min_score {
+            if calculator.should_prune(node) {
                 pruned_ids.push(*id);
                 score_reclaimed += score;
-                false // Remove from map
+            }
+        }
+
+        let mut archived_ids = Vec::new();
+        for id in &pruned_ids {
+            let tiering = self.calculator_of(*id).config().tiering;
+            let Some(node) = self.nodes.remove(id) else {
+                continue;
+            };
+            if tiering == TieringPolicy::Archive {
+                archived_ids.push(*id);
+                self.register_archived(node);
             } else {
-                true // Keep in map
+                self.assigned.remove(id);
             }
-        });
+        }
 
         let duration = start.elapsed().as_millis() as u64;
 
-        Ok(PruneResult::new(pruned_ids, score_reclaimed, duration))
+        let mut result = PruneResult::new(pruned_ids, score_reclaimed, duration);
+        result.archived_ids = archived_ids;
+        Ok(result)
     }
 
     /// Get memory statistics.
     pub fn stats(&self) -> MemoryStats {
-        let total_nodes = self.nodes.len();
+        Self::summarize(self.nodes.keys().map(|id| self.score_of(*id)))
+    }
+
+    /// Get memory statistics for each decay profile with tracked nodes.
+    ///
+    /// Nodes without an override are counted under `"default"`.
+    pub fn stats_by_profile(&self) -> HashMap<String, MemoryStats> {
+        let mut scores: HashMap<&str, Vec<(f32, bool)>> = HashMap::new();
+        for id in self.nodes.keys() {
+            scores
+                .entry(self.profile_of(*id))
+                .or_default()
+                .push(self.score_of(*id));
+        }
+
+        scores
+            .into_iter()
+            .map(|(name, scores)| (name.to_string(), Self::summarize(scores.into_iter())))
+            .collect()
+    }
+
+    /// Current score of a tracked node and whether it is above its
+    /// profile's minimum.
+    fn score_of(&self, id: Uuid) -> (f32, bool) {
+        let calculator = self.calculator_of(id);
+        let score = calculator.current_score(&self.nodes[&id]);
+        (score, score >= calculator.config().min_score)
+    }
 
+    /// Build statistics from `(score, active)` pairs.
+    fn summarize(scores: impl Iterator<Item = (f32, bool)>) -> MemoryStats {
+        let mut total_nodes = 0;
         let mut active_nodes = 0;
-        let mut decayed_nodes = 0;
         let mut total_score = 0.0;
 
-        for node in self.nodes.values() {
-            let score = self.calculator.current_score(node);
+        for (score, active) in scores {
+            total_nodes += 1;
             total_score += score;
-
-            if score >= self.calculator.config().min_score {
+            if active {
                 active_nodes += 1;
-            } else {
-                decayed_nodes += 1;
             }
         }
 
@@ -250,7 +494,12 @@ impl MemoryManager {
             0.0
         };
 
-        MemoryStats::new(total_nodes, active_nodes, decayed_nodes, average_score)
+        MemoryStats::new(
+            total_nodes,
+            active_nodes,
+            total_nodes - active_nodes,
+            average_score,
+        )
     }
 
     /// Get all nodes (for iteration/export).
@@ -269,9 +518,13 @@ impl MemoryManager {
     }
 
     /// Update the calculator config.
+    ///
+    /// This is the default profile; namespace and node type overrides are
+    /// kept.
     pub fn update_config(&mut self, config: DecayConfig) -> MemoryResult<()> {
         config.validate()?;
         self.calculator = DecayCalculator::with_config(config);
+        self.rebuild_profile_calculators();
         Ok(())
     }
 
@@ -362,6 +615,97 @@ mod tests {
         assert!(result.pruned_ids.contains(&id));
     }
 
+    #[tokio::test]
+    async fn test_prune_skips_pinned() {
+        let config = DecayConfig::new().with_min_score(5.0).with_max_score(10.0);
+        let mut manager = MemoryManager::with_config(config);
+
+        let mut pinned = Node::new("Architecture decision", NodeType::Concept).with_pinned(true);
+        pinned.meta.accessed_at = Some(Utc::now() - chrono::Duration::days(365));
+        let loose = Node::new("Passing thought", NodeType::Concept);
+        let (pinned_id, loose_id) = (pinned.id, loose.id);
+        manager.register(pinned).unwrap();
+        manager.register(loose).unwrap();
+
+        assert!(manager.expiring(Duration::from_secs(3600)).iter().all(|(id, _)| *id != pinned_id));
+        let result = manager.prune().unwrap();
+        assert_eq!(result.pruned_ids, vec![loose_id]);
+        assert!(manager.get_node(pinned_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_prune_archives_and_restores() {
+        let config = DecayConfig::new().with_min_score(5.0).with_max_score(10.0);
+        let mut manager = MemoryManager::with_config(config);
+
+        let node = Node::new("Cold memory", NodeType::Concept).with_embedding(vec![0.1; 4]);
+        let id = node.id;
+        manager.register(node).unwrap();
+
+        let result = manager.prune().unwrap();
+        assert_eq!(result.archived_ids, vec![id]);
+        assert!(manager.get_node(id).is_none());
+        assert!(manager.is_archived(id));
+        assert!(!manager.get_archived(id).unwrap().has_embedding());
+
+        let restored = manager.restore(id).unwrap();
+        assert!(restored.meta.access_score > 1.0);
+        assert!(manager.get_node(id).is_some());
+        assert!(!manager.is_archived(id));
+    }
+
+    #[tokio::test]
+    async fn test_prune_delete_policy() {
+        let config = DecayConfig::new()
+            .with_min_score(5.0)
+            .with_max_score(10.0)
+            .with_tiering(TieringPolicy::Delete);
+        let mut manager = MemoryManager::with_config(config);
+
+        let node = Node::new("Forgotten", NodeType::Concept);
+        let id = node.id;
+        manager.register(node).unwrap();
+
+        let result = manager.prune().unwrap();
+        assert_eq!(result.count, 1);
+        assert!(result.archived_ids.is_empty());
+        assert!(!manager.is_archived(id));
+    }
+
+    #[tokio::test]
+    async fn test_record_penalty() {
+        let mut manager = MemoryManager::new();
+        let node = Node::new("Unhelpful memory", NodeType::Concept);
+        let id = node.id;
+        manager.register(node).unwrap();
+
+        manager.record_access(id).unwrap();
+        manager.record_access(id).unwrap();
+        let boosted = manager.get_score(id).unwrap();
+
+        manager.record_penalty(id).unwrap();
+        assert!(manager.get_score(id).unwrap() < boosted);
+        assert!(manager.record_penalty(Uuid::new_v4()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_strength_uses_tracked_copy() {
+        let mut manager = MemoryManager::new();
+        let node = Node::new("Often used", NodeType::Concept);
+        let stale = node.clone();
+        manager.register(node).unwrap();
+
+        let before = manager.strength(&stale);
+        manager.record_access(stale.id).unwrap();
+        assert!(manager.strength(&stale) > before);
+
+        let untracked = Node::new("Unknown", NodeType::Concept);
+        assert_eq!(
+            manager.strength(&untracked),
+            manager.calculator().strength(&untracked)
+        );
+    }
+
     #[tokio::test]
     async fn test_stats() {
         let mut manager = MemoryManager::new();
@@ -394,4 +738,76 @@ mod tests {
         let retention = manager.get_retention(id).unwrap();
         assert!((retention - 0.22).abs() < 0.01);
     }
+
+    #[tokio::test]
+    async fn test_expiring() {
+        let mut manager = MemoryManager::new();
+        let profiles = DecayProfiles::new()
+            .with_node_type(NodeType::Fact, DecayConfig::new().with_lambda(0.0001).unwrap());
+        manager.set_profiles(profiles).unwrap();
+
+        let accessed = Some(chrono::Utc::now() - chrono::Duration::hours(1400));
+        let mut concept = Node::new("Fading", NodeType::Concept);
+        concept.meta.accessed_at = accessed;
+        let mut fact = Node::new("Durable", NodeType::Fact);
+        fact.meta.accessed_at = accessed;
+        let fresh = Node::new("Never accessed", NodeType::Concept);
+        let concept_id = concept.id;
+        for node in [concept, fact, fresh] {
+            manager.register(node).unwrap();
+        }
+
+        // The concept has ~135 hours left; the fact decays far slower
+        let expiring = manager.expiring(Duration::from_secs(7 * 24 * 3600));
+        assert_eq!(expiring.len(), 1);
+        assert_eq!(expiring[0].0, concept_id);
+        assert!(manager.expiring(Duration::from_secs(24 * 3600)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_decay_profiles() {
+        let mut manager = MemoryManager::new();
+        let profiles = DecayProfiles::new()
+            .with_node_type(NodeType::RawChunk, DecayConfig::new().with_min_score(5.0))
+            .with_node_type(NodeType::Fact, DecayConfig::new().with_lambda(0.0001).unwrap());
+        manager.set_profiles(profiles).unwrap();
+
+        let fact = Node::new("Water boils at 100C", NodeType::Fact);
+        let chunk = Node::new("some raw text", NodeType::RawChunk);
+        let concept = Node::new("Chemistry", NodeType::Concept);
+        let (fact_id, chunk_id, concept_id) = (fact.id, chunk.id, concept.id);
+        for node in [fact, chunk, concept] {
+            manager.register(node).unwrap();
+        }
+
+        assert_eq!(manager.profile_of(fact_id), "node_type:fact");
+        assert_eq!(manager.profile_of(concept_id), "default");
+
+        let by_profile = manager.stats_by_profile();
+        assert_eq!(by_profile.len(), 3);
+        assert_eq!(by_profile["node_type:raw_chunk"].decayed_nodes, 1);
+        assert_eq!(by_profile["default"].active_nodes, 1);
+        assert_eq!(manager.stats().decayed_nodes, 1);
+
+        // Only the raw chunk falls below its profile's minimum
+        let result = manager.prune().unwrap();
+        assert_eq!(result.pruned_ids, vec![chunk_id]);
+    }
+
+    #[tokio::test]
+    async fn test_set_profiles_reassigns_nodes() {
+        let mut manager = MemoryManager::new();
+        let node = Node::new("Scratch note", NodeType::Concept)
+            .with_attributes(serde_json::json!({"namespace": "scratch"}));
+        let id = node.id;
+        manager.register(node).unwrap();
+        assert_eq!(manager.profile_of(id), "default");
+
+        let profiles = DecayProfiles::new().with_namespace("scratch", DecayConfig::new());
+        manager.set_profiles(profiles).unwrap();
+        assert_eq!(manager.profile_of(id), "namespace:scratch");
+
+        manager.unregister(id);
+        assert_eq!(manager.profile_of(id), "default");
+    }
 }
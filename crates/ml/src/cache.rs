@@ -0,0 +1,80 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Persistent embedding cache.
+//!
+//! The LRU cache of [`crate::EmbeddingService`] lives in memory, so a restart
+//! pays again for embedding texts it has seen before, which is common when
+//! the same documents are ingested repeatedly. A [`PersistentEmbeddingCache`]
+//! sits under it and keeps embeddings across restarts, keyed by a hash of the
+//! model and the text (see [`cache_key`]).
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+/// Disk-backed embedding cache consulted when the in-memory cache misses.
+///
+/// Caches are best effort: implementations log storage errors and treat them
+/// as misses rather than failing the embedding.
+#[async_trait]
+pub trait PersistentEmbeddingCache: Send + Sync {
+    /// Embedding stored under a key, if any.
+    async fn get(&self, key: &str) -> Option<Vec<f32>>;
+
+    /// Store an embedding, evicting older entries when the cache is full.
+    async fn put(&self, key: &str, embedding: &[f32]);
+}
+
+/// Cache key of a text embedded by a model: the hex SHA-256 of the model
+/// identifier and the text.
+pub fn cache_key(model_id: &str, text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(model_id.as_bytes());
+    hasher.update([0]);
+    hasher.update(text.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Encode an embedding as little-endian `f32`s.
+pub fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+/// Decode an embedding encoded by [`encode_embedding`].
+pub fn decode_embedding(bytes: &[u8]) -> Option<Vec<f32>> {
+    if bytes.len() % 4 != 0 {
+        return None;
+    }
+    Some(
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key() {
+        let key = cache_key("ollama:nomic-embed-text", "Paris");
+        assert_eq!(key.len(), 64);
+        assert_eq!(key, cache_key("ollama:nomic-embed-text", "Paris"));
+        assert_ne!(key, cache_key("openai:text-embedding-3-small", "Paris"));
+        assert_ne!(key, cache_key("ollama:nomic-embed-text", "Lyon"));
+    }
+
+    #[test]
+    fn test_embedding_encoding() {
+        let embedding = vec![0.25, -1.5, 3.0];
+        assert_eq!(decode_embedding(&encode_embedding(&embedding)), Some(embedding));
+        assert_eq!(decode_embedding(&[0, 1, 2]), None);
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Text completion backends.
+//!
+//! Used where the database asks a language model for text rather than an
+//! embedding, such as translating free-form questions into PaQL.
+//! [`OpenAiCompletionBackend`] speaks the OpenAI chat completions API, which
+//! Ollama also serves under `/v1`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ApiConfig;
+use crate::error::{MlError, Result};
+
+/// Text completion backend trait.
+#[async_trait]
+pub trait CompletionBackend: Send + Sync {
+    /// Complete a conversation made of a system instruction and one user
+    /// message, returning the reply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt is empty or the backend fails.
+    async fn complete(&self, system: &str, prompt: &str) -> Result<String>;
+
+    /// Model producing the completions.
+    fn model(&self) -> &str;
+}
+
+/// OpenAI chat completions backend.
+#[derive(Clone)]
+pub struct OpenAiCompletionBackend {
+    client: reqwest::Client,
+    config: ApiConfig,
+}
+
+impl OpenAiCompletionBackend {
+    /// Create a new chat completions backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is invalid or the client cannot be created.
+    pub fn new(config: ApiConfig) -> Result<Self> {
+        config.validate()?;
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| MlError::HttpClientError(e.to_string()))?;
+
+        Ok(Self { client, config })
+    }
+}
+
+#[async_trait]
+impl CompletionBackend for OpenAiCompletionBackend {
+    async fn complete(&self, system: &str, prompt: &str) -> Result<String> {
+        let prompt = prompt.trim();
+        if prompt.is_empty() {
+            return Err(MlError::EmptyInput);
+        }
+
+        let request = ChatRequest {
+            model: &self.config.model,
+            messages: vec![
+                ChatMessage {
+                    role: "system",
+                    content: system,
+                },
+                ChatMessage {
+                    role: "user",
+                    content: prompt,
+                },
+            ],
+            temperature: 0.0,
+        };
+        let url = format!("{}/chat/completions", self.config.endpoint);
+        let api_key = self.config.get_api_key()?;
+
+        let mut retries = 0;
+        loop {
+            let mut builder = self.client.post(&url).json(&request);
+            if let Some(api_key) = &api_key {
+                builder = builder.header("Authorization", format!("Bearer {api_key}"));
+            }
+            let response = builder
+                .send()
+                .await
+                .map_err(|e| MlError::HttpClientError(e.to_string()))?;
+
+            let status = response.status();
+            let response_text = response
+                .text()
+                .await
+                .map_err(|e| MlError::HttpClientError(e.to_string()))?;
+
+            if status.is_success() {
+                return parse_reply(&response_text);
+            }
+
+            // Retry on server errors or rate limiting
+            if (status.is_server_error() || status.as_u16() == 429) && retries < self.config.max_retries {
+                retries += 1;
+                tokio::time::sleep(Duration::from_millis(self.config.retry_delay_ms as u64)).await;
+                continue;
+            }
+
+            return Err(MlError::ApiError(format!(
+                "Completion failed with HTTP {status}: {response_text}"
+            )));
+        }
+    }
+
+    fn model(&self) -> &str {
+        &self.config.model
+    }
+}
+
+/// Extract the reply from a chat completions response.
+fn parse_reply(response_text: &str) -> Result<String> {
+    let response: ChatResponse = serde_json::from_str(response_text)
+        .map_err(|e| MlError::ResponseParseError(e.to_string()))?;
+    response
+        .choices
+        .into_iter()
+        .next()
+        .and_then(|choice| choice.message.content)
+        .ok_or_else(|| MlError::ResponseParseError("completion has no content".to_string()))
+}
+
+/// Chat completions request.
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    temperature: f32,
+}
+
+/// Message of a chat completions request.
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+/// Chat completions response.
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    choices: Vec<ChatChoice>,
+}
+
+/// Choice of a chat completions response.
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatReply,
+}
+
+/// Reply message of a choice.
+#[derive(Debug, Deserialize)]
+struct ChatReply {
+    content: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_creation() {
+        let config = ApiConfig {
+            model: "gpt-4o-mini".to_string(),
+            ..ApiConfig::openai("sk-test-key".to_string())
+        };
+        let backend = OpenAiCompletionBackend::new(config).unwrap();
+        assert_eq!(backend.model(), "gpt-4o-mini");
+
+        let config = ApiConfig {
+            endpoint: String::new(),
+            ..Default::default()
+        };
+        assert!(OpenAiCompletionBackend::new(config).is_err());
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let response = r#"{"choices": [{"index": 0, "message": {"role": "assistant", "content": "tagged rust"}}]}"#;
+        assert_eq!(parse_reply(response).unwrap(), "tagged rust");
+
+        assert!(parse_reply(r#"{"choices": []}"#).is_err());
+        assert!(parse_reply("not json").is_err());
+    }
+}
@@ -4,6 +4,8 @@
 
 //! Configuration structures for the ML module.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::backend::{BackendType, DeviceType};
@@ -161,6 +163,20 @@ impl ApiConfig {
     }
 }
 
+/// Embedding model used for texts in one language.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LanguageModelConfig {
+    /// Backend type to use.
+    pub backend: BackendType,
+
+    /// Local model configuration.
+    pub local: LocalModelConfig,
+
+    /// API configuration (for OpenAI/Ollama backends).
+    pub api: ApiConfig,
+}
+
 /// Complete embedding configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -184,6 +200,15 @@ pub struct EmbeddingConfig {
 
     /// Maximum cache size (number of embeddings).
     pub cache_size: usize,
+
+    /// Dedicated models keyed by language code (e.g. "zh", "en").
+    /// Languages without an entry use the default backend above.
+    #[serde(default)]
+    pub languages: HashMap<String, LanguageModelConfig>,
+
+    /// Also search with the other models when the query language's model
+    /// finds fewer results than requested.
+    pub cross_lingual_fallback: bool,
 }
 
 impl Default for EmbeddingConfig {
@@ -195,6 +220,8 @@ impl Default for EmbeddingConfig {
             dimension_override: 0,
             cache_enabled: true,
             cache_size: 10000,
+            languages: HashMap::new(),
+            cross_lingual_fallback: true,
         }
     }
 }
@@ -230,6 +257,20 @@ impl EmbeddingConfig {
         }
     }
 
+    /// Configuration of the dedicated model for a language, if any.
+    ///
+    /// Shares the cache and dimension settings of this configuration.
+    pub fn for_language(&self, lang: &str) -> Option<Self> {
+        let model = self.languages.get(lang)?;
+        Some(Self {
+            backend: model.backend,
+            local: model.local.clone(),
+            api: model.api.clone(),
+            languages: HashMap::new(),
+            ..self.clone()
+        })
+    }
+
     /// Validate the configuration.
     pub fn validate(&self) -> MlResult<()> {
         match self.backend {
@@ -243,9 +284,39 @@ impl EmbeddingConfig {
             }
         }
 
+        // All models share one vector index, so their dimensions must match
+        for lang in self.languages.keys() {
+            let Some(config) = self.for_language(lang) else {
+                continue;
+            };
+            config.validate()?;
+            if config.dimension() != self.dimension() {
+                return Err(MlError::invalid_config(format!(
+                    "Embedding model for language '{}' has dimension {}, expected {}",
+                    lang,
+                    config.dimension(),
+                    self.dimension()
+                )));
+            }
+        }
+
         Ok(())
     }
 
+    /// Identifier of the configured model, e.g.
+    /// `local:sentence-transformers/all-MiniLM-L6-v2`.
+    ///
+    /// Embeddings are tagged with it, so vectors produced by a model that
+    /// has since been replaced can be found and re-embedded.
+    pub fn model_id(&self) -> String {
+        match self.backend {
+            BackendType::Local => format!("{}:{}", self.backend, self.local.model_name),
+            BackendType::OpenAi | BackendType::Ollama => {
+                format!("{}:{}", self.backend, self.api.model)
+            }
+        }
+    }
+
     /// Get the expected embedding dimension.
     /// Returns the dimension override if set, otherwise returns the model default.
     pub fn dimension(&self) -> usize {
@@ -342,6 +413,19 @@ mod tests {
         assert_eq!(config.dimension(), 512);
     }
 
+    #[test]
+    fn test_model_id() {
+        assert_eq!(
+            EmbeddingConfig::default().model_id(),
+            "local:sentence-transformers/all-MiniLM-L6-v2"
+        );
+        assert_eq!(
+            EmbeddingConfig::openai("test".to_string()).model_id(),
+            "openai:text-embedding-3-small"
+        );
+        assert_eq!(EmbeddingConfig::ollama().model_id(), "ollama:nomic-embed-text");
+    }
+
     #[test]
     fn test_config_validation() {
         let config = EmbeddingConfig::default();
@@ -356,6 +440,32 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_language_model_validation() {
+        let mut config = EmbeddingConfig::default();
+        config.languages.insert(
+            "zh".to_string(),
+            LanguageModelConfig {
+                local: LocalModelConfig {
+                    model_name: "BAAI/bge-small-en-v1.5".to_string(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.for_language("zh").unwrap().local.model_name,
+            "BAAI/bge-small-en-v1.5"
+        );
+        assert!(config.for_language("en").is_none());
+
+        // A 768-dimensional model cannot share the 384-dimensional index
+        config.languages.get_mut("zh").unwrap().local.model_name =
+            "BAAI/bge-base-en-v1.5".to_string();
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = EmbeddingConfig::default();
@@ -0,0 +1,129 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Fault-injecting embedding backend for resilience testing.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use synton_core::{Fault, FaultInjector};
+
+use crate::backend::{BackendType, EmbeddingBackend};
+use crate::error::{MlError, Result};
+
+/// Embedding backend that injects latency, timeouts and failures into
+/// another backend.
+///
+/// Timeouts surface as HTTP client errors and failures as embedding
+/// errors, like an unreachable or misbehaving model server.
+#[derive(Clone)]
+pub struct FaultyEmbeddingBackend {
+    inner: Arc<dyn EmbeddingBackend>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultyEmbeddingBackend {
+    /// Wrap a backend.
+    pub fn new(inner: Arc<dyn EmbeddingBackend>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+
+    /// The fault injector.
+    pub fn injector(&self) -> &Arc<FaultInjector> {
+        &self.injector
+    }
+
+    /// Apply the next fault before running `op`.
+    async fn inject(&self, op: &str) -> Result<()> {
+        let fault = match self.injector.next_fault() {
+            Some(fault) => fault,
+            None => return Ok(()),
+        };
+        tracing::debug!("Injecting {:?} into {} {}", fault, self.injector.label(), op);
+
+        match fault {
+            Fault::Latency(delay) => {
+                tokio::time::sleep(delay).await;
+                Ok(())
+            }
+            Fault::Timeout(delay) => {
+                tokio::time::sleep(delay).await;
+                Err(MlError::HttpClientError(format!("injected timeout in {}", op)))
+            }
+            Fault::Error => Err(MlError::EmbeddingFailed(format!("injected failure in {}", op))),
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingBackend for FaultyEmbeddingBackend {
+    async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        self.inject("embed").await?;
+        self.inner.embed(text).await
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        self.inject("embed_batch").await?;
+        self.inner.embed_batch(texts).await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn backend_type(&self) -> BackendType {
+        self.inner.backend_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::FaultConfig;
+
+    struct Constant;
+
+    #[async_trait]
+    impl EmbeddingBackend for Constant {
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![1.0, 0.0])
+        }
+
+        fn dimension(&self) -> usize {
+            2
+        }
+
+        fn backend_type(&self) -> BackendType {
+            BackendType::Ollama
+        }
+    }
+
+    fn faulty(config: FaultConfig) -> FaultyEmbeddingBackend {
+        FaultyEmbeddingBackend::new(Arc::new(Constant), Arc::new(FaultInjector::new(config, "embedding")))
+    }
+
+    #[tokio::test]
+    async fn test_faulty_embedding_backend() {
+        let failing = faulty(FaultConfig {
+            error_rate: 1.0,
+            ..Default::default()
+        });
+        assert!(matches!(failing.embed("text").await, Err(MlError::EmbeddingFailed(_))));
+        assert_eq!(failing.backend_type(), BackendType::Ollama);
+        assert_eq!(failing.dimension(), 2);
+
+        let hanging = faulty(FaultConfig {
+            timeout_rate: 1.0,
+            timeout_ms: 1,
+            ..Default::default()
+        });
+        assert!(matches!(
+            hanging.embed_batch(&["text".to_string()]).await,
+            Err(MlError::HttpClientError(_))
+        ));
+
+        let healthy = faulty(FaultConfig::default());
+        assert_eq!(healthy.embed("text").await.unwrap(), vec![1.0, 0.0]);
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Language detection for embedding routing.
+
+/// Minimum detector confidence for alphabetic scripts.
+const MIN_CONFIDENCE: f64 = 0.5;
+
+/// Detect the dominant language of a text.
+///
+/// Returns an ISO 639-1 code (e.g. `"en"`, `"zh"`) where one exists, the
+/// ISO 639-3 code otherwise, or `None` when the text is too short or
+/// ambiguous to tell.
+///
+/// CJK characters each carry roughly a word of meaning, so text mixing a
+/// few Chinese characters with English terms (common in technical notes)
+/// is classified by script before falling back to statistical detection.
+pub fn detect_language(text: &str) -> Option<String> {
+    let (mut han, mut kana, mut hangul) = (0usize, 0usize, 0usize);
+    for c in text.chars() {
+        match c {
+            '\u{3040}'..='\u{30FF}' => kana += 1,
+            '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' => hangul += 1,
+            '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' | '\u{F900}'..='\u{FAFF}' => han += 1,
+            _ => {}
+        }
+    }
+
+    let cjk = han + kana + hangul;
+    if cjk > 0 {
+        let latin_words = text
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|w| !w.is_empty())
+            .count();
+        if cjk * 2 >= latin_words {
+            let lang = if kana > 0 {
+                "ja"
+            } else if hangul > han {
+                "ko"
+            } else {
+                "zh"
+            };
+            return Some(lang.to_string());
+        }
+    }
+
+    let info = whatlang::detect(text)?;
+    if !info.is_reliable() && info.confidence() < MIN_CONFIDENCE {
+        return None;
+    }
+    Some(iso_639_1(info.lang()).to_string())
+}
+
+/// Two-letter code for common languages, three-letter code otherwise.
+fn iso_639_1(lang: whatlang::Lang) -> &'static str {
+    use whatlang::Lang;
+
+    match lang {
+        Lang::Eng => "en",
+        Lang::Cmn => "zh",
+        Lang::Jpn => "ja",
+        Lang::Kor => "ko",
+        Lang::Spa => "es",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Ita => "it",
+        Lang::Por => "pt",
+        Lang::Rus => "ru",
+        Lang::Ara => "ar",
+        Lang::Hin => "hi",
+        Lang::Nld => "nl",
+        Lang::Tur => "tr",
+        Lang::Vie => "vi",
+        other => other.code(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_cjk_scripts() {
+        assert_eq!(detect_language("向量数据库支持图检索").as_deref(), Some("zh"));
+        assert_eq!(detect_language("東京は日本の首都です").as_deref(), Some("ja"));
+        assert_eq!(detect_language("데이터베이스").as_deref(), Some("ko"));
+    }
+
+    #[test]
+    fn test_detect_mixed_chinese_english() {
+        assert_eq!(
+            detect_language("SYNTON-DB 是一个 graph database").as_deref(),
+            Some("zh")
+        );
+        assert_eq!(
+            detect_language("The term 图 appears once in this long English sentence about databases")
+                .as_deref(),
+            Some("en")
+        );
+    }
+
+    #[test]
+    fn test_detect_latin_and_ambiguous() {
+        assert_eq!(
+            detect_language("Machine learning is a field of artificial intelligence").as_deref(),
+            Some("en")
+        );
+        assert_eq!(
+            detect_language("Die Katze sitzt auf der Matte und schläft").as_deref(),
+            Some("de")
+        );
+        assert_eq!(detect_language("hello"), None);
+        assert_eq!(detect_language(""), None);
+    }
+}
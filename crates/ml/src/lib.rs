@@ -8,10 +8,16 @@
 //! - Local models using Candle
 //! - OpenAI API
 //! - Ollama local API
+//!
+//! and text completion through chat completion APIs.
 
 pub mod error;
 pub mod backend;
+pub mod cache;
+pub mod completion;
 pub mod config;
+pub mod fault;
+pub mod lang;
 pub mod local;
 pub mod loader;
 pub mod openai;
@@ -19,6 +25,10 @@ pub mod ollama;
 pub mod service;
 
 pub use backend::{BackendType, DeviceType, EmbeddingBackend};
-pub use config::{ApiConfig, EmbeddingConfig, LocalModelConfig};
+pub use cache::PersistentEmbeddingCache;
+pub use completion::{CompletionBackend, OpenAiCompletionBackend};
+pub use config::{ApiConfig, EmbeddingConfig, LanguageModelConfig, LocalModelConfig};
+pub use lang::detect_language;
 pub use error::{MlError, Result as MlResult};
+pub use fault::FaultyEmbeddingBackend;
 pub use service::{EmbeddingService, EmbeddingStats};
@@ -6,14 +6,18 @@
 
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
 use tokio::sync::RwLock;
 
 use crate::backend::{BackendType, EmbeddingBackend};
+use crate::cache::{cache_key, PersistentEmbeddingCache};
 use crate::config::EmbeddingConfig;
 use crate::error::{MlError, Result};
+use crate::fault::FaultyEmbeddingBackend;
+use synton_core::FaultInjector;
 
 /// Simple LRU cache implementation.
 #[derive(Clone)]
@@ -74,6 +78,7 @@ mod backend_impl {
         Local(super::super::local::LocalEmbeddingBackend),
         OpenAi(super::super::openai::OpenAiEmbeddingBackend),
         Ollama(super::super::ollama::OllamaEmbeddingBackend),
+        Faulty(super::super::fault::FaultyEmbeddingBackend),
     }
 
     impl AnyBackend {
@@ -82,6 +87,7 @@ mod backend_impl {
                 Self::Local(_) => BackendType::Local,
                 Self::OpenAi(_) => BackendType::OpenAi,
                 Self::Ollama(_) => BackendType::Ollama,
+                Self::Faulty(b) => b.backend_type(),
             }
         }
     }
@@ -93,6 +99,7 @@ mod backend_impl {
                 Self::Local(b) => b.embed(text).await,
                 Self::OpenAi(b) => b.embed(text).await,
                 Self::Ollama(b) => b.embed(text).await,
+                Self::Faulty(b) => b.embed(text).await,
             }
         }
 
@@ -101,6 +108,7 @@ mod backend_impl {
                 Self::Local(b) => b.embed_batch(texts).await,
                 Self::OpenAi(b) => b.embed_batch(texts).await,
                 Self::Ollama(b) => b.embed_batch(texts).await,
+                Self::Faulty(b) => b.embed_batch(texts).await,
             }
         }
 
@@ -109,6 +117,7 @@ mod backend_impl {
                 Self::Local(b) => b.dimension(),
                 Self::OpenAi(b) => b.dimension(),
                 Self::Ollama(b) => b.dimension(),
+                Self::Faulty(b) => b.dimension(),
             }
         }
 
@@ -140,17 +149,55 @@ pub struct EmbeddingStats {
 
     /// Cache hit rate (0.0-1.0).
     pub cache_hit_rate: f64,
+
+    /// Number of cache hits served by the persistent cache.
+    pub persistent_cache_hits: usize,
+
+    /// Backend requests running now.
+    pub in_flight: usize,
+
+    /// Most backend requests seen running at once.
+    pub peak_in_flight: usize,
+}
+
+/// Backend requests running now, and the most seen at once.
+#[derive(Debug, Default)]
+struct Concurrency {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Concurrency {
+    /// Count a request as running until the returned guard is dropped.
+    fn enter(&self) -> ConcurrencyGuard<'_> {
+        let current = self.current.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+        ConcurrencyGuard(self)
+    }
+}
+
+struct ConcurrencyGuard<'a>(&'a Concurrency);
+
+impl Drop for ConcurrencyGuard<'_> {
+    fn drop(&mut self) {
+        self.0.current.fetch_sub(1, Ordering::Relaxed);
+    }
 }
 
 /// Unified embedding service.
 ///
 /// Provides a single interface for generating embeddings using different backends.
+/// Texts in languages with a dedicated model (see [`EmbeddingConfig::languages`])
+/// are routed to that model; everything else uses the default backend.
 #[derive(Clone)]
 pub struct EmbeddingService {
     backend: Arc<AnyBackend>,
+    language_backends: HashMap<String, Arc<AnyBackend>>,
     config: EmbeddingConfig,
     cache: Arc<RwLock<LruCache<String, Vec<f32>>>>,
+    persistent_cache: Option<Arc<dyn PersistentEmbeddingCache>>,
     stats: Arc<RwLock<EmbeddingStats>>,
+    concurrency: Arc<Concurrency>,
 }
 
 impl EmbeddingService {
@@ -164,21 +211,15 @@ impl EmbeddingService {
     pub async fn from_config(config: EmbeddingConfig) -> Result<Self> {
         config.validate()?;
 
-        let backend = match config.backend {
-            BackendType::Local => {
-                let local_backend =
-                    crate::local::LocalEmbeddingBackend::new(config.local.clone()).await?;
-                AnyBackend::Local(local_backend)
-            }
-            BackendType::OpenAi => {
-                let openai_backend = crate::openai::OpenAiEmbeddingBackend::new(config.api.clone())?;
-                AnyBackend::OpenAi(openai_backend)
-            }
-            BackendType::Ollama => {
-                let ollama_backend = crate::ollama::OllamaEmbeddingBackend::new(config.api.clone())?;
-                AnyBackend::Ollama(ollama_backend)
+        let backend = Self::create_backend(&config).await?;
+
+        let mut language_backends = HashMap::new();
+        for lang in config.languages.keys() {
+            if let Some(lang_config) = config.for_language(lang) {
+                let lang_backend = Self::create_backend(&lang_config).await?;
+                language_backends.insert(lang.clone(), Arc::new(lang_backend));
             }
-        };
+        }
 
         let cache_size = if config.cache_enabled {
             config.cache_size
@@ -194,13 +235,89 @@ impl EmbeddingService {
 
         Ok(Self {
             backend: Arc::new(backend),
+            language_backends,
             config,
             cache,
+            persistent_cache: None,
             stats,
+            concurrency: Arc::default(),
         })
     }
 
-    /// Generate an embedding for a single text.
+    /// Create the backend described by a configuration.
+    async fn create_backend(config: &EmbeddingConfig) -> Result<AnyBackend> {
+        let backend = match config.backend {
+            BackendType::Local => {
+                let local_backend =
+                    crate::local::LocalEmbeddingBackend::new(config.local.clone()).await?;
+                AnyBackend::Local(local_backend)
+            }
+            BackendType::OpenAi => {
+                let openai_backend = crate::openai::OpenAiEmbeddingBackend::new(config.api.clone())?;
+                AnyBackend::OpenAi(openai_backend)
+            }
+            BackendType::Ollama => {
+                let ollama_backend = crate::ollama::OllamaEmbeddingBackend::new(config.api.clone())?;
+                AnyBackend::Ollama(ollama_backend)
+            }
+        };
+        Ok(backend)
+    }
+
+    /// Inject faults into every backend of the service.
+    ///
+    /// Cached embeddings are still served without faults.
+    pub fn with_fault_injection(mut self, injector: Arc<FaultInjector>) -> Self {
+        let wrap = |backend: Arc<AnyBackend>| {
+            Arc::new(AnyBackend::Faulty(FaultyEmbeddingBackend::new(backend, injector.clone())))
+        };
+        self.backend = wrap(self.backend);
+        self.language_backends = self
+            .language_backends
+            .into_iter()
+            .map(|(lang, backend)| (lang, wrap(backend)))
+            .collect();
+        self
+    }
+
+    /// Keep embeddings in a persistent cache under the in-memory one, so
+    /// they survive restarts.
+    pub fn with_persistent_cache(mut self, cache: Arc<dyn PersistentEmbeddingCache>) -> Self {
+        self.persistent_cache = Some(cache);
+        self
+    }
+
+    /// Model route for a language: the language itself when it has a
+    /// dedicated model, `None` for the default model.
+    pub fn route(&self, lang: Option<&str>) -> Option<&str> {
+        let lang = lang?;
+        self.language_backends
+            .get_key_value(lang)
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// All model routes, the default model (`None`) first.
+    pub fn routes(&self) -> Vec<Option<&str>> {
+        let mut langs: Vec<&str> = self.language_backends.keys().map(String::as_str).collect();
+        langs.sort_unstable();
+        std::iter::once(None).chain(langs.into_iter().map(Some)).collect()
+    }
+
+    /// Identifier of the model routed to for `lang` (see
+    /// [`EmbeddingConfig::model_id`]).
+    pub fn model_id(&self, lang: Option<&str>) -> String {
+        match self.route(lang).and_then(|lang| self.config.for_language(lang)) {
+            Some(config) => config.model_id(),
+            None => self.config.model_id(),
+        }
+    }
+
+    /// Whether any language has a dedicated model.
+    pub fn has_language_routes(&self) -> bool {
+        !self.language_backends.is_empty()
+    }
+
+    /// Generate an embedding for a single text with the default model.
     ///
     /// # Errors
     ///
@@ -208,18 +325,40 @@ impl EmbeddingService {
     /// - The input is empty
     /// - The backend fails to generate the embedding
     pub async fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        self.embed_for_language(text, None).await
+    }
+
+    /// Generate an embedding with the model routed to for `lang`.
+    ///
+    /// Languages without a dedicated model use the default backend.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The input is empty
+    /// - The backend fails to generate the embedding
+    pub async fn embed_for_language(&self, text: &str, lang: Option<&str>) -> Result<Vec<f32>> {
         let text = text.trim();
 
         if text.is_empty() {
             return Err(MlError::EmptyInput);
         }
 
+        let route = self.route(lang);
+        let backend = route
+            .and_then(|lang| self.language_backends.get(lang))
+            .unwrap_or(&self.backend);
+        // The same text embeds differently per model
+        let text_key = match route {
+            Some(lang) => format!("{}\u{0}{}", lang, text),
+            None => text.to_string(),
+        };
+
         // Check cache
         if self.config.cache_enabled {
             {
                 let cache = self.cache.read().await;
-                let text_key = &text.to_string();
-                if let Some(cached) = cache.get(text_key) {
+                if let Some(cached) = cache.get(&text_key) {
                     // Update stats
                     let mut stats = self.stats.write().await;
                     stats.total_embeddings += 1;
@@ -232,15 +371,36 @@ impl EmbeddingService {
             }
         }
 
+        // Check persistent cache
+        let persistent = self
+            .persistent_cache
+            .as_ref()
+            .map(|cache| (cache, cache_key(&self.model_id(lang), text)));
+        if let Some((cache, key)) = &persistent {
+            if let Some(embedding) = cache.get(key).await {
+                if self.config.cache_enabled {
+                    self.cache.write().await.put(text_key, embedding.clone());
+                }
+                self.record_persistent_hits(1).await;
+                return Ok(embedding);
+            }
+        }
+
         // Generate embedding
         let start = Instant::now();
-        let embedding = self.backend.embed(text).await?;
+        let embedding = {
+            let _running = self.concurrency.enter();
+            backend.embed(text).await?
+        };
         let elapsed = start.elapsed().as_secs_f64() * 1000.0;
 
         // Update cache
         if self.config.cache_enabled {
             let mut cache = self.cache.write().await;
-            cache.put(text.to_string(), embedding.clone());
+            cache.put(text_key, embedding.clone());
+        }
+        if let Some((cache, key)) = &persistent {
+            cache.put(key, &embedding).await;
         }
 
         // Update stats
@@ -272,7 +432,7 @@ impl EmbeddingService {
             }
         }
 
-        let mut results = Vec::with_capacity(texts.len());
+        let mut results = vec![None; texts.len()];
         let mut uncached_texts = Vec::new();
         let mut uncached_indices = Vec::new();
 
@@ -284,14 +444,13 @@ impl EmbeddingService {
                     let text = text.trim();
                     let text_key = &text.to_string();
                     if let Some(cached) = cache.get(text_key) {
-                        results.push(Some(cached.clone()));
+                        results[i] = Some(cached.clone());
 
                         // Update stats
                         let mut stats = self.stats.write().await;
                         stats.total_embeddings += 1;
                         stats.cache_hits += 1;
                     } else {
-                        results.push(None);
                         uncached_texts.push(text.to_string());
                         uncached_indices.push(i);
                     }
@@ -302,10 +461,41 @@ impl EmbeddingService {
             uncached_indices = (0..texts.len()).collect();
         }
 
+        // Check persistent cache for texts memory missed
+        let model_id = self.config.model_id();
+        if let Some(cache) = &self.persistent_cache {
+            let mut missed_texts = Vec::new();
+            let mut missed_indices = Vec::new();
+            let mut hits = 0;
+            for (text, idx) in uncached_texts.into_iter().zip(uncached_indices) {
+                match cache.get(&cache_key(&model_id, &text)).await {
+                    Some(embedding) => {
+                        if self.config.cache_enabled {
+                            self.cache.write().await.put(text, embedding.clone());
+                        }
+                        results[idx] = Some(embedding);
+                        hits += 1;
+                    }
+                    None => {
+                        missed_texts.push(text);
+                        missed_indices.push(idx);
+                    }
+                }
+            }
+            uncached_texts = missed_texts;
+            uncached_indices = missed_indices;
+            if hits > 0 {
+                self.record_persistent_hits(hits).await;
+            }
+        }
+
         // Generate embeddings for uncached texts
         if !uncached_texts.is_empty() {
             let start = Instant::now();
-            let new_embeddings = self.backend.embed_batch(&uncached_texts).await?;
+            let new_embeddings = {
+                let _running = self.concurrency.enter();
+                self.backend.embed_batch(&uncached_texts).await?
+            };
             let elapsed = start.elapsed().as_secs_f64() * 1000.0;
 
             // Update cache and fill results
@@ -315,6 +505,11 @@ impl EmbeddingService {
                     cache.put(text.clone(), embedding.clone());
                 }
             }
+            if let Some(cache) = &self.persistent_cache {
+                for (text, embedding) in uncached_texts.iter().zip(new_embeddings.iter()) {
+                    cache.put(&cache_key(&model_id, text), embedding).await;
+                }
+            }
 
             for (idx, embedding) in uncached_indices.into_iter().zip(new_embeddings.into_iter()) {
                 results[idx] = Some(embedding);
@@ -337,6 +532,15 @@ impl EmbeddingService {
         final_results
     }
 
+    /// Count embeddings served by the persistent cache.
+    async fn record_persistent_hits(&self, hits: usize) {
+        let mut stats = self.stats.write().await;
+        stats.total_embeddings += hits;
+        stats.cache_hits += hits;
+        stats.persistent_cache_hits += hits;
+        stats.cache_hit_rate = stats.cache_hits as f64 / stats.total_embeddings as f64;
+    }
+
     /// Get the dimension of the embeddings.
     pub fn dimension(&self) -> usize {
         self.backend.dimension()
@@ -354,7 +558,11 @@ impl EmbeddingService {
 
     /// Get the current statistics.
     pub async fn stats(&self) -> EmbeddingStats {
-        self.stats.read().await.clone()
+        EmbeddingStats {
+            in_flight: self.concurrency.current.load(Ordering::Relaxed),
+            peak_in_flight: self.concurrency.peak.load(Ordering::Relaxed),
+            ..self.stats.read().await.clone()
+        }
     }
 
     /// Clear the embedding cache.
@@ -369,11 +577,20 @@ impl EmbeddingService {
         cache.len()
     }
 
-    /// Check if the service is healthy.
+    /// Check if the service is healthy: the default backend must embed a
+    /// test text, bypassing the caches, with the configured dimension.
     pub async fn health_check(&self) -> Result<()> {
-        // Try to generate a test embedding
-        let test_text = "health check";
-        let _ = self.embed(test_text).await?;
+        let embedding = {
+            let _running = self.concurrency.enter();
+            self.backend.embed("health check").await?
+        };
+        if embedding.len() != self.dimension() {
+            return Err(MlError::EmbeddingFailed(format!(
+                "backend returned {} dimensions, expected {}",
+                embedding.len(),
+                self.dimension()
+            )));
+        }
         Ok(())
     }
 }
@@ -415,6 +632,108 @@ mod tests {
         assert_eq!(service.dimension(), 768);
     }
 
+    #[tokio::test]
+    async fn test_language_routes() {
+        let mut config = EmbeddingConfig::ollama();
+        config.languages.insert(
+            "zh".to_string(),
+            crate::config::LanguageModelConfig {
+                backend: BackendType::Ollama,
+                api: crate::config::ApiConfig {
+                    model: "nomic-embed-text".to_string(),
+                    ..crate::config::ApiConfig::ollama()
+                },
+                ..Default::default()
+            },
+        );
+        let service = EmbeddingService::from_config(config).await.unwrap();
+
+        assert!(service.has_language_routes());
+        assert_eq!(service.route(Some("zh")), Some("zh"));
+        assert_eq!(service.route(Some("en")), None);
+        assert_eq!(service.route(None), None);
+        assert_eq!(service.routes(), vec![None, Some("zh")]);
+    }
+
+    #[tokio::test]
+    async fn test_fault_injection() {
+        let config = EmbeddingConfig::ollama();
+        let faults = synton_core::FaultConfig {
+            error_rate: 1.0,
+            ..Default::default()
+        };
+        let service = EmbeddingService::from_config(config)
+            .await
+            .unwrap()
+            .with_fault_injection(Arc::new(FaultInjector::new(faults, "embedding")));
+
+        assert_eq!(service.backend_type(), BackendType::Ollama);
+        assert!(matches!(service.embed("text").await, Err(MlError::EmbeddingFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn test_in_flight_requests() {
+        let faults = synton_core::FaultConfig {
+            timeout_rate: 1.0,
+            timeout_ms: 50,
+            ..Default::default()
+        };
+        let service = EmbeddingService::from_config(EmbeddingConfig::ollama())
+            .await
+            .unwrap()
+            .with_fault_injection(Arc::new(FaultInjector::new(faults, "embedding")));
+
+        let (a, b, c) = tokio::join!(service.embed("a"), service.embed("b"), service.embed("c"));
+        assert!(a.is_err() && b.is_err() && c.is_err());
+
+        let stats = service.stats().await;
+        assert_eq!(stats.in_flight, 0);
+        assert_eq!(stats.peak_in_flight, 3);
+    }
+
+    #[tokio::test]
+    async fn test_persistent_cache() {
+        #[derive(Default)]
+        struct MapCache(std::sync::Mutex<HashMap<String, Vec<f32>>>);
+
+        #[async_trait::async_trait]
+        impl PersistentEmbeddingCache for MapCache {
+            async fn get(&self, key: &str) -> Option<Vec<f32>> {
+                self.0.lock().unwrap().get(key).cloned()
+            }
+
+            async fn put(&self, key: &str, embedding: &[f32]) {
+                self.0.lock().unwrap().insert(key.to_string(), embedding.to_vec());
+            }
+        }
+
+        // The backend always fails, so only cached texts embed
+        let config = EmbeddingConfig {
+            cache_enabled: false,
+            ..EmbeddingConfig::ollama()
+        };
+        let faults = synton_core::FaultConfig {
+            error_rate: 1.0,
+            ..Default::default()
+        };
+        let cache = Arc::new(MapCache::default());
+        let service = EmbeddingService::from_config(config)
+            .await
+            .unwrap()
+            .with_fault_injection(Arc::new(FaultInjector::new(faults, "embedding")))
+            .with_persistent_cache(cache.clone());
+        cache.put(&cache_key(&service.model_id(None), "Paris"), &[0.5, 0.5]).await;
+
+        assert_eq!(service.embed(" Paris ").await.unwrap(), vec![0.5, 0.5]);
+        let batch = service.embed_batch(&["Paris".to_string()]).await.unwrap();
+        assert_eq!(batch, vec![vec![0.5, 0.5]]);
+        assert!(service.embed("Lyon").await.is_err());
+
+        let stats = service.stats().await;
+        assert_eq!(stats.persistent_cache_hits, 2);
+        assert_eq!(stats.cache_hits, 2);
+    }
+
     #[tokio::test]
     async fn test_embed_empty_input() {
         let config = EmbeddingConfig::local("sentence-transformers/all-MiniLM-L6-v2".to_string());
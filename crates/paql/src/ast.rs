@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::rank::RankExpr;
+
 /// A parsed PaQL query.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Query {
@@ -12,10 +14,20 @@ pub struct Query {
     pub root: QueryNode,
 
     /// Maximum number of results to return.
+    #[serde(default)]
     pub limit: Option<usize>,
 
     /// Sort order for results.
+    #[serde(default)]
     pub sort_fields: Vec<SortField>,
+
+    /// Ranking expression replacing the default access-score order.
+    #[serde(default)]
+    pub rank: Option<RankExpr>,
+
+    /// Pipeline stages applied in order to the nodes the root matches.
+    #[serde(default)]
+    pub stages: Vec<Stage>,
 }
 
 impl Query {
@@ -25,6 +37,8 @@ impl Query {
             root,
             limit: None,
             sort_fields: Vec::new(),
+            rank: None,
+            stages: Vec::new(),
         }
     }
 
@@ -40,6 +54,18 @@ impl Query {
         self
     }
 
+    /// Set the ranking expression.
+    pub fn with_rank(mut self, rank: RankExpr) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    /// Append a pipeline stage.
+    pub fn with_stage(mut self, stage: Stage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
     /// Check if the query is empty.
     pub fn is_empty(&self) -> bool {
         matches!(self.root, QueryNode::Empty)
@@ -48,6 +74,7 @@ impl Query {
 
 /// A node in the query AST.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum QueryNode {
     /// Empty query node.
     Empty,
@@ -58,9 +85,13 @@ pub enum QueryNode {
     /// Semantic search (vector similarity).
     SemanticSearch { embedding: Vec<f32> },
 
-    /// Hybrid search (text + semantic).
+    /// Hybrid search (text + semantic). An empty embedding is computed
+    /// from the query text when the query runs.
     HybridSearch { query: String, embedding: Vec<f32> },
 
+    /// Nodes carrying a tag (normalized).
+    Tagged { tag: String },
+
     /// Graph traversal from a seed node.
     GraphTraversal {
         seed_id: Uuid,
@@ -68,6 +99,15 @@ pub enum QueryNode {
         max_hops: usize,
     },
 
+    /// Nodes bound by a graph pattern such as
+    /// `(a:Concept)-[:CAUSES]->(b)`: those bound to `var`, or to any named
+    /// variable without it. The pattern is parsed when the query runs.
+    Match { pattern: String, var: Option<String> },
+
+    /// Facts (see [`synton_core::Hyperedge`]) in which `participant` plays
+    /// `role` (normalized).
+    Role { role: String, participant: Uuid },
+
     /// Filtered search.
     Filter {
         input: Box<QueryNode>,
@@ -84,8 +124,33 @@ pub enum QueryNode {
     Not { input: Box<QueryNode> },
 }
 
+/// A pipeline stage, applied to the results of the previous one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    /// Replace the results with the nodes reachable from them.
+    Traverse {
+        /// Relations to follow (empty = all relations).
+        relations: Vec<String>,
+        /// Edge direction to follow.
+        direction: TraverseDirection,
+        /// Maximum number of hops from each result.
+        max_hops: usize,
+    },
+
+    /// Keep the results satisfying every filter.
+    Filter(Vec<Filter>),
+
+    /// Order the results.
+    Sort(Vec<SortField>),
+
+    /// Keep the first results.
+    Limit(usize),
+}
+
 /// Traversal direction for graph queries.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TraverseDirection {
     /// Forward (outgoing edges).
     Forward,
@@ -117,6 +182,7 @@ impl Filter {
 
 /// Filter field.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum FilterField {
     /// Node content.
     Content,
@@ -133,12 +199,16 @@ pub enum FilterField {
     /// Created timestamp.
     CreatedAt,
 
+    /// Node tags; `=` and `!=` test membership, `in` matches any of a list.
+    Tag,
+
     /// Custom field.
     Custom(String),
 }
 
 /// Comparison operation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ComparisonOp {
     /// Equals.
     Eq,
@@ -167,6 +237,7 @@ pub enum ComparisonOp {
 
 /// Filter value.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum FilterValue {
     /// String value.
     String(String),
@@ -203,6 +274,7 @@ impl SortField {
 
 /// Sort field options.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SortFieldType {
     /// Relevance score.
     Relevance,
@@ -216,12 +288,16 @@ pub enum SortFieldType {
     /// Created timestamp.
     CreatedAt,
 
+    /// Node tags; `=` and `!=` test membership, `in` matches any of a list.
+    Tag,
+
     /// Custom field.
     Custom(String),
 }
 
 /// Sort order.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SortOrder {
     /// Ascending order.
     Asc,
@@ -232,6 +308,7 @@ pub enum SortOrder {
 
 /// Binary operation for combining queries.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum BinaryOp {
     /// AND operation.
     And,
@@ -272,6 +349,16 @@ mod tests {
         assert!(query.is_empty());
     }
 
+    #[test]
+    fn test_query_with_stages() {
+        let query = Query::new(QueryNode::TextSearch {
+            query: "test".to_string(),
+        })
+        .with_stage(Stage::Limit(5));
+        assert_eq!(query.stages, vec![Stage::Limit(5)]);
+        assert!(Query::new(QueryNode::Empty).stages.is_empty());
+    }
+
     #[test]
     fn test_filter_creation() {
         let filter = Filter::new(
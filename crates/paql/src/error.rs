@@ -25,6 +25,9 @@ pub enum ParseError {
     /// Invalid sort expression.
     InvalidSort(String),
 
+    /// Invalid ranking expression.
+    InvalidRank(String),
+
     /// Query is empty.
     EmptyQuery,
 
@@ -34,6 +37,12 @@ pub enum ParseError {
     /// Invalid limit value.
     InvalidLimit(String),
 
+    /// A prepared query placeholder was not bound.
+    MissingParameter(String),
+
+    /// A bound value does not match any prepared query placeholder.
+    UnknownParameter(String),
+
     /// Custom error.
     Custom(String),
 }
@@ -49,11 +58,14 @@ impl fmt::Display for ParseError {
             Self::UnknownKeyword(kw) => write!(f, "Unknown keyword: {}", kw),
             Self::InvalidFilter(e) => write!(f, "Invalid filter: {}", e),
             Self::InvalidSort(e) => write!(f, "Invalid sort: {}", e),
+            Self::InvalidRank(e) => write!(f, "Invalid rank expression: {}", e),
             Self::EmptyQuery => write!(f, "Query is empty"),
             Self::QueryTooComplex { max_depth, actual } => {
                 write!(f, "Query too complex: max depth {}, actual {}", max_depth, actual)
             }
             Self::InvalidLimit(e) => write!(f, "Invalid limit: {}", e),
+            Self::MissingParameter(name) => write!(f, "Missing parameter: ${}", name),
+            Self::UnknownParameter(name) => write!(f, "Unknown parameter: ${}", name),
             Self::Custom(e) => write!(f, "{}", e),
         }
     }
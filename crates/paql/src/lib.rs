@@ -12,12 +12,19 @@
 mod ast;
 mod error;
 mod parser;
+mod prepared;
+mod rank;
 
-pub use ast::{BinaryOp, ComparisonOp, Query, QueryNode, SortField, SortFieldType, SortOrder};
+pub use ast::{
+    BinaryOp, ComparisonOp, Filter, FilterField, FilterValue, Query, QueryNode, SortField,
+    SortFieldType, SortOrder, Stage, TraverseDirection,
+};
 pub use error::{ParseError, ParseResult};
 pub use parser::Parser;
+pub use prepared::PreparedQuery;
+pub use rank::{RankExpr, RankOp, RankVar, RankVars};
 
 /// Re-exports commonly used types
 pub mod prelude {
-    pub use crate::{ParseError, ParseResult, Parser, Query};
+    pub use crate::{ParseError, ParseResult, Parser, PreparedQuery, Query};
 }
@@ -5,11 +5,26 @@
 use crate::{
     ast::{
         ComparisonOp, Filter, FilterField, FilterValue, Query, QueryNode, SortField, SortFieldType,
-        SortOrder, TraverseDirection,
+        SortOrder, Stage, TraverseDirection,
     },
-    error::ParseResult,
+    error::{ParseError, ParseResult},
+    rank::RankExpr,
 };
 
+/// Clauses that may follow a `rank by` expression.
+const RANK_TERMINATORS: [&str; 5] = [" limit ", " top ", " first ", " sort by ", " order by "];
+
+/// Comparison operators of filter conditions, longest first.
+const COMPARISON_OPS: [(&str, ComparisonOp); 7] = [
+    (">=", ComparisonOp::Ge),
+    ("<=", ComparisonOp::Le),
+    ("!=", ComparisonOp::Ne),
+    ("==", ComparisonOp::Eq),
+    ("=", ComparisonOp::Eq),
+    (">", ComparisonOp::Gt),
+    ("<", ComparisonOp::Lt),
+];
+
 /// PaQL query parser.
 pub struct Parser {
     /// Maximum nesting depth for queries.
@@ -28,6 +43,10 @@ impl Parser {
     }
 
     /// Parse a query string into a Query AST.
+    ///
+    /// A query may be a pipeline of `|`-separated segments: the first is a
+    /// search, each later one a stage applied to the results so far, e.g.
+    /// `similar to "X" | traverse causes 2 hops | filter confidence > 0.7`.
     pub fn parse(&self, input: &str) -> ParseResult<Query> {
         let input = input.trim();
 
@@ -35,6 +54,54 @@ impl Parser {
             return Ok(Query::new(QueryNode::Empty));
         }
 
+        let segments = split_unquoted(input, "|");
+        if segments.len() > 1 {
+            return self.parse_pipeline(&segments);
+        }
+
+        self.parse_search(input)
+    }
+
+    /// Check a query built directly as an AST, such as one sent as JSON,
+    /// against the rules the parser enforces, normalizing its tags.
+    pub fn validate(&self, mut query: Query) -> ParseResult<Query> {
+        let depth = node_depth(&query.root);
+        if depth > self.max_depth {
+            return Err(ParseError::QueryTooComplex {
+                max_depth: self.max_depth,
+                actual: depth,
+            });
+        }
+        if query.is_empty() && !query.stages.is_empty() {
+            return Err(ParseError::EmptyQuery);
+        }
+        validate_node(&mut query.root)?;
+
+        for stage in &query.stages {
+            match stage {
+                Stage::Traverse { max_hops: 0, .. } => {
+                    return Err(ParseError::InvalidSyntax("invalid hop count: 0".to_string()));
+                }
+                Stage::Filter(filters) if filters.is_empty() => {
+                    return Err(ParseError::InvalidFilter("empty filter stage".to_string()));
+                }
+                Stage::Sort(fields) if fields.is_empty() => {
+                    return Err(ParseError::InvalidSort("empty sort stage".to_string()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// Parse a search with its modifiers.
+    fn parse_search(&self, input: &str) -> ParseResult<Query> {
+        // Parse and remove the ranking clause first: its expression
+        // contains numbers and operators the other clauses must not see
+        let (input, rank) = self.extract_rank(input)?;
+        let input = input.as_str();
+
         // Parse limit if present
         let limit = self.extract_limit(input);
 
@@ -51,6 +118,96 @@ impl Parser {
             root,
             limit,
             sort_fields,
+            rank,
+            stages: Vec::new(),
+        })
+    }
+
+    fn parse_pipeline(&self, segments: &[&str]) -> ParseResult<Query> {
+        let search = segments[0].trim();
+        if search.is_empty() {
+            return Err(ParseError::EmptyQuery);
+        }
+        let mut query = self.parse_search(search)?;
+
+        // Sort and limit clauses of the search apply before the later
+        // stages; a ranking clause still orders the final results
+        if !query.sort_fields.is_empty() {
+            let fields = std::mem::take(&mut query.sort_fields);
+            query.stages.push(Stage::Sort(fields));
+        }
+        if let Some(limit) = query.limit.take() {
+            query.stages.push(Stage::Limit(limit));
+        }
+
+        for segment in &segments[1..] {
+            query.stages.push(self.parse_stage(segment)?);
+        }
+
+        Ok(query)
+    }
+
+    fn parse_stage(&self, segment: &str) -> ParseResult<Stage> {
+        let segment = segment.trim();
+        let (keyword, rest) = segment
+            .split_once(char::is_whitespace)
+            .unwrap_or((segment, ""));
+        let rest = rest.trim();
+
+        match keyword.to_lowercase().as_str() {
+            "" => Err(ParseError::InvalidSyntax("empty pipeline stage".to_string())),
+            "traverse" | "follow" => self.parse_traverse_stage(rest),
+            "filter" | "where" => {
+                let filters = self.parse_filter_conditions(rest)?;
+                if filters.is_empty() {
+                    return Err(ParseError::InvalidFilter(segment.to_string()));
+                }
+                Ok(Stage::Filter(filters))
+            }
+            "sort" | "order" => {
+                let fields = self.extract_sort(segment)?;
+                if fields.is_empty() {
+                    return Err(ParseError::InvalidSort(segment.to_string()));
+                }
+                Ok(Stage::Sort(fields))
+            }
+            "limit" | "top" | "first" => rest
+                .parse()
+                .map(Stage::Limit)
+                .map_err(|_| ParseError::InvalidLimit(rest.to_string())),
+            other => Err(ParseError::UnknownKeyword(other.to_string())),
+        }
+    }
+
+    /// Parse `[relations] [N hops] [forward|backward|both]`; by default one
+    /// forward hop along every relation.
+    fn parse_traverse_stage(&self, input: &str) -> ParseResult<Stage> {
+        let mut relations = Vec::new();
+        let mut direction = TraverseDirection::Forward;
+        let mut max_hops = 1;
+
+        for token in input.split(|c: char| c.is_whitespace() || c == ',') {
+            let token = token.to_lowercase();
+            match token.as_str() {
+                "" | "edge" | "edges" | "hop" | "hops" | "within" => {}
+                "forward" | "outgoing" => direction = TraverseDirection::Forward,
+                "backward" | "incoming" => direction = TraverseDirection::Backward,
+                "both" => direction = TraverseDirection::Both,
+                _ if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                    max_hops = token
+                        .parse()
+                        .ok()
+                        .filter(|hops| *hops > 0)
+                        .ok_or_else(|| ParseError::InvalidSyntax(format!("invalid hop count: {}", token)))?;
+                }
+                _ => relations.push(token),
+            }
+        }
+
+        Ok(Stage::Traverse {
+            relations,
+            direction,
+            max_hops,
         })
     }
 
@@ -68,6 +225,26 @@ impl Parser {
             return Ok(node);
         }
 
+        // Check for graph pattern matches
+        if let Some(pattern) = strip_keyword(input, "match ") {
+            return self.parse_match_query(pattern);
+        }
+
+        // Check for tag queries
+        if let Some(tag) = strip_keyword(input, "tagged ") {
+            return self.parse_tagged_query(tag);
+        }
+
+        // Check for fact role queries
+        if let Some(binding) = strip_keyword(input, "role ") {
+            return self.parse_role_query(binding);
+        }
+
+        // Check for similarity search
+        if let Some(node) = self.try_parse_similar_query(input) {
+            return Ok(node);
+        }
+
         // Check for combined queries (AND/OR)
         if let Some(node) = self.try_parse_combined_query(input) {
             return Ok(node);
@@ -144,13 +321,74 @@ impl Parser {
         let filters = self.parse_filter_conditions(filter_part).ok()?;
 
         Some(QueryNode::Filter {
-            input: Box::new(QueryNode::TextSearch {
-                query: base_query.trim().to_string(),
-            }),
+            input: Box::new(self.parse_query_node(base_query).ok()?),
             filters,
         })
     }
 
+    /// `tagged "name"` or `tagged name`; the tag is normalized.
+    fn parse_tagged_query(&self, input: &str) -> ParseResult<QueryNode> {
+        let input = input.trim();
+        let invalid = || ParseError::InvalidSyntax(format!("invalid tag: {}", input));
+
+        let tag = match input.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(quoted) => quoted,
+            None if !input.contains(char::is_whitespace) => input,
+            None => return Err(invalid()),
+        };
+        let tag = synton_core::normalize_tag(tag).map_err(|_| invalid())?;
+
+        Ok(QueryNode::Tagged { tag })
+    }
+
+    /// `role <name> <uuid>`: facts in which the node plays the role; the
+    /// role is normalized.
+    fn parse_role_query(&self, input: &str) -> ParseResult<QueryNode> {
+        let invalid = || ParseError::InvalidSyntax(format!("invalid role query: {}", input.trim()));
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let [role, participant] = words[..] else {
+            return Err(invalid());
+        };
+        let role = synton_core::normalize_role(role).map_err(|_| invalid())?;
+        let participant = uuid::Uuid::parse_str(participant).map_err(|_| invalid())?;
+
+        Ok(QueryNode::Role { role, participant })
+    }
+
+    /// `match <pattern> [return <var>]`. Only the shape is checked here;
+    /// the pattern itself is parsed by the graph engine.
+    fn parse_match_query(&self, input: &str) -> ParseResult<QueryNode> {
+        let input = input.trim();
+        let lower = input.to_lowercase();
+        let (pattern, var) = match lower.rfind(" return ") {
+            Some(pos) => (&input[..pos], Some(input[pos + 8..].trim())),
+            None => (input, None),
+        };
+        let pattern = pattern.trim();
+        check_match(pattern, var)?;
+
+        Ok(QueryNode::Match {
+            pattern: pattern.to_string(),
+            var: var.map(str::to_string),
+        })
+    }
+
+    /// `similar to X`, with any words before it (e.g. `find concepts`)
+    /// dropped. The query embedding is left for the executor to compute.
+    fn try_parse_similar_query(&self, input: &str) -> Option<QueryNode> {
+        let lower = input.to_lowercase();
+        let pos = if lower.starts_with("similar to ") {
+            0
+        } else {
+            lower.find(" similar to ")? + 1
+        };
+
+        Some(QueryNode::HybridSearch {
+            query: self.extract_search_term(&input[pos + 11..]),
+            embedding: Vec::new(),
+        })
+    }
+
     fn try_parse_combined_query(&self, input: &str) -> Option<QueryNode> {
         let lower = input.to_lowercase();
 
@@ -202,21 +440,70 @@ impl Parser {
         None
     }
 
+    /// Parse `field op value` conditions joined by `and`.
     fn parse_filter_conditions(&self, input: &str) -> ParseResult<Vec<Filter>> {
-        let mut filters = Vec::new();
-        let lower = input.to_lowercase();
+        split_unquoted(input, " and ")
+            .into_iter()
+            .map(|condition| self.parse_filter_condition(condition.trim()))
+            .collect()
+    }
 
-        // Simple filter parsing: "field op value"
-        // For MVP, just handle content contains
-        if lower.contains("contains") {
-            filters.push(Filter::new(
-                FilterField::Content,
-                ComparisonOp::Contains,
-                FilterValue::String(self.extract_search_term(input).to_string()),
-            ));
+    fn parse_filter_condition(&self, input: &str) -> ParseResult<Filter> {
+        let invalid = || ParseError::InvalidFilter(input.to_string());
+
+        let name_end = input
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(input.len());
+        let (name, rest) = input.split_at(name_end);
+        let rest = rest.trim_start();
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        let (op, value) = if let Some((symbol, op)) = COMPARISON_OPS
+            .iter()
+            .find(|(symbol, _)| rest.starts_with(symbol))
+        {
+            (*op, &rest[symbol.len()..])
+        } else if let Some(value) = strip_keyword(rest, "contains ") {
+            (ComparisonOp::Contains, value)
+        } else if let Some(value) = strip_keyword(rest, "in ") {
+            (ComparisonOp::In, value)
+        } else {
+            return Err(invalid());
+        };
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(invalid());
         }
 
-        Ok(filters)
+        let field = match name.to_lowercase().as_str() {
+            "content" => FilterField::Content,
+            "type" | "node_type" => FilterField::NodeType,
+            "access" | "access_score" => FilterField::AccessScore,
+            "confidence" => FilterField::Confidence,
+            "created" | "created_at" => FilterField::CreatedAt,
+            "tag" | "tags" => FilterField::Tag,
+            _ => FilterField::Custom(name.to_string()),
+        };
+
+        let value = match op {
+            ComparisonOp::In => {
+                let list = value
+                    .strip_prefix('[')
+                    .and_then(|v| v.strip_suffix(']'))
+                    .unwrap_or(value);
+                FilterValue::List(
+                    split_unquoted(list, ",")
+                        .into_iter()
+                        .map(|item| parse_filter_value(item.trim()))
+                        .collect(),
+                )
+            }
+            _ => parse_filter_value(value),
+        };
+
+        Ok(Filter::new(field, op, value))
     }
 
     fn extract_search_term(&self, input: &str) -> String {
@@ -300,6 +587,28 @@ impl Parser {
         None
     }
 
+    /// Split off a `rank by <expr>` clause, returning the remaining input.
+    fn extract_rank(&self, input: &str) -> ParseResult<(String, Option<RankExpr>)> {
+        let lower = input.to_lowercase();
+        let (clause_start, expr_start) = if lower.starts_with("rank by ") {
+            (0, 8)
+        } else if let Some(pos) = lower.find(" rank by ") {
+            (pos, pos + 9)
+        } else {
+            return Ok((input.to_string(), None));
+        };
+
+        let expr_end = RANK_TERMINATORS
+            .iter()
+            .filter_map(|kw| lower[expr_start..].find(kw).map(|pos| expr_start + pos))
+            .min()
+            .unwrap_or(input.len());
+
+        let rank = RankExpr::parse(&input[expr_start..expr_end])?;
+        let rest = format!("{}{}", &input[..clause_start], &input[expr_end..]);
+        Ok((rest, Some(rank)))
+    }
+
     fn extract_sort(&self, input: &str) -> ParseResult<Vec<SortField>> {
         let mut sort_fields = Vec::new();
         let lower = input.to_lowercase();
@@ -350,6 +659,117 @@ impl Parser {
     }
 }
 
+/// Split `input` at each occurrence of `separator` (ASCII, matched case
+/// insensitively) outside double quotes.
+fn split_unquoted<'a>(input: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+
+    for (i, c) in input.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if !quoted
+            && i >= start
+            && input
+                .get(i..i + separator.len())
+                .is_some_and(|s| s.eq_ignore_ascii_case(separator))
+        {
+            parts.push(&input[start..i]);
+            start = i + separator.len();
+        }
+    }
+
+    parts.push(&input[start..]);
+    parts
+}
+
+/// The rest of `input` after an ASCII keyword matched case insensitively.
+fn strip_keyword<'a>(input: &'a str, keyword: &str) -> Option<&'a str> {
+    input
+        .get(..keyword.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(keyword))
+        .map(|_| &input[keyword.len()..])
+}
+
+/// A filter value: a quoted string, boolean, integer, float or bare word.
+fn parse_filter_value(input: &str) -> FilterValue {
+    if let Some(text) = input.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return FilterValue::String(text.to_string());
+    }
+    match input.to_lowercase().as_str() {
+        "true" => return FilterValue::Boolean(true),
+        "false" => return FilterValue::Boolean(false),
+        _ => {}
+    }
+    if let Ok(value) = input.parse() {
+        return FilterValue::Integer(value);
+    }
+    if let Ok(value) = input.parse() {
+        return FilterValue::Float(value);
+    }
+    FilterValue::String(input.to_string())
+}
+
+/// Check the shape of a graph pattern and its return variable; the pattern
+/// itself is parsed by the graph engine.
+fn check_match(pattern: &str, var: Option<&str>) -> ParseResult<()> {
+    if !pattern.starts_with('(') {
+        return Err(ParseError::InvalidSyntax(format!("invalid pattern: {}", pattern)));
+    }
+    if let Some(var) = var {
+        if var.is_empty() || !var.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(ParseError::InvalidSyntax(format!("invalid return variable: {}", var)));
+        }
+    }
+    Ok(())
+}
+
+/// Nesting depth of a query node; a leaf is 1.
+fn node_depth(node: &QueryNode) -> usize {
+    match node {
+        QueryNode::Filter { input, .. } | QueryNode::Not { input } => 1 + node_depth(input),
+        QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+            1 + node_depth(left).max(node_depth(right))
+        }
+        _ => 1,
+    }
+}
+
+/// Check a query node built directly, normalizing its tags.
+fn validate_node(node: &mut QueryNode) -> ParseResult<()> {
+    match node {
+        QueryNode::Tagged { tag } => {
+            *tag = synton_core::normalize_tag(tag)
+                .map_err(|_| ParseError::InvalidSyntax(format!("invalid tag: {}", tag)))?;
+        }
+        QueryNode::SemanticSearch { embedding } if embedding.is_empty() => {
+            return Err(ParseError::InvalidSyntax(
+                "semantic search without an embedding".to_string(),
+            ));
+        }
+        QueryNode::GraphTraversal { max_hops: 0, .. } => {
+            return Err(ParseError::InvalidSyntax("invalid hop count: 0".to_string()));
+        }
+        QueryNode::Match { pattern, var } => check_match(pattern.trim(), var.as_deref())?,
+        QueryNode::Role { role, .. } => {
+            *role = synton_core::normalize_role(role)
+                .map_err(|_| ParseError::InvalidSyntax(format!("invalid role: {}", role)))?;
+        }
+        QueryNode::Filter { input, .. } | QueryNode::Not { input } => validate_node(input)?,
+        QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+            validate_node(left)?;
+            validate_node(right)?;
+        }
+        QueryNode::Empty
+        | QueryNode::TextSearch { .. }
+        | QueryNode::SemanticSearch { .. }
+        | QueryNode::HybridSearch { .. }
+        | QueryNode::GraphTraversal { .. } => {}
+    }
+    Ok(())
+}
+
 impl Default for Parser {
     fn default() -> Self {
         Self::new()
@@ -390,6 +810,27 @@ mod tests {
         assert_eq!(query.limit, Some(10));
     }
 
+    #[test]
+    fn test_parse_with_rank() {
+        let parser = Parser::new();
+        let query = parser
+            .parse("find AI concepts rank by 0.6*similarity + 0.4*recency limit 5")
+            .unwrap();
+
+        assert_eq!(query.limit, Some(5));
+        assert_eq!(
+            query.root,
+            QueryNode::TextSearch {
+                query: "AI concepts".to_string()
+            }
+        );
+        let rank = query.rank.unwrap();
+        assert_eq!(rank, RankExpr::parse("0.6*similarity + 0.4*recency").unwrap());
+
+        assert!(parser.parse("find AI rank by popularity").is_err());
+        assert!(parser.parse("find AI").unwrap().rank.is_none());
+    }
+
     #[test]
     fn test_parse_with_sort() {
         let parser = Parser::new();
@@ -399,4 +840,253 @@ mod tests {
         let query = result.unwrap();
         assert_eq!(query.sort_fields.len(), 1);
     }
+
+    #[test]
+    fn test_parse_filter_conditions() {
+        let parser = Parser::new();
+        let query = parser
+            .parse(r#"find pumps where confidence >= 0.8 and type in [fact, "concept"] and content contains "rock and roll""#)
+            .unwrap();
+
+        let QueryNode::Filter { input, filters } = query.root else {
+            panic!("expected a filter query");
+        };
+        assert_eq!(
+            *input,
+            QueryNode::TextSearch {
+                query: "pumps".to_string()
+            }
+        );
+        assert_eq!(
+            filters,
+            vec![
+                Filter::new(FilterField::Confidence, ComparisonOp::Ge, FilterValue::Float(0.8)),
+                Filter::new(
+                    FilterField::NodeType,
+                    ComparisonOp::In,
+                    FilterValue::List(vec![
+                        FilterValue::String("fact".to_string()),
+                        FilterValue::String("concept".to_string()),
+                    ]),
+                ),
+                Filter::new(
+                    FilterField::Content,
+                    ComparisonOp::Contains,
+                    FilterValue::String("rock and roll".to_string()),
+                ),
+            ]
+        );
+
+        // Text that does not form conditions stays part of the search
+        let query = parser.parse("notes with pictures").unwrap();
+        assert!(matches!(query.root, QueryNode::TextSearch { .. }));
+    }
+
+    #[test]
+    fn test_parse_pipeline() {
+        let parser = Parser::new();
+        let query = parser
+            .parse(r#"find concepts similar to "supply chain" limit 5 | traverse causes 2 hops | filter confidence > 0.7 | sort by created asc | limit 10"#)
+            .unwrap();
+
+        assert_eq!(
+            query.root,
+            QueryNode::HybridSearch {
+                query: "supply chain".to_string(),
+                embedding: Vec::new(),
+            }
+        );
+        assert_eq!(query.limit, None);
+        assert_eq!(
+            query.stages,
+            vec![
+                Stage::Limit(5),
+                Stage::Traverse {
+                    relations: vec!["causes".to_string()],
+                    direction: TraverseDirection::Forward,
+                    max_hops: 2,
+                },
+                Stage::Filter(vec![Filter::new(
+                    FilterField::Confidence,
+                    ComparisonOp::Gt,
+                    FilterValue::Float(0.7),
+                )]),
+                Stage::Sort(vec![SortField::new(SortFieldType::CreatedAt, SortOrder::Asc)]),
+                Stage::Limit(10),
+            ]
+        );
+
+        let query = parser.parse("pumps | follow is_part_of, located_at edges both").unwrap();
+        assert_eq!(
+            query.stages,
+            vec![Stage::Traverse {
+                relations: vec!["is_part_of".to_string(), "located_at".to_string()],
+                direction: TraverseDirection::Both,
+                max_hops: 1,
+            }]
+        );
+
+        // A quoted bar is part of the search
+        let query = parser.parse(r#""a | b""#).unwrap();
+        assert!(query.stages.is_empty());
+    }
+
+    #[test]
+    fn test_parse_tagged() {
+        let parser = Parser::new();
+        let tagged = |tag: &str| QueryNode::Tagged { tag: tag.to_string() };
+
+        assert_eq!(parser.parse(r#"tagged "Architecture""#).unwrap().root, tagged("architecture"));
+        assert_eq!(parser.parse("TAGGED db limit 5").unwrap().root, tagged("db"));
+        assert_eq!(parser.parse("tagged db limit 5").unwrap().limit, Some(5));
+
+        let query = parser.parse(r#"tagged "db" where confidence > 0.5"#).unwrap();
+        let QueryNode::Filter { input, .. } = query.root else {
+            panic!("expected a filter");
+        };
+        assert_eq!(*input, tagged("db"));
+
+        let query = parser.parse(r#"pumps | filter tag in ["a", "b"]"#).unwrap();
+        let Stage::Filter(filters) = &query.stages[0] else {
+            panic!("expected a filter stage");
+        };
+        assert_eq!(filters[0].field, FilterField::Tag);
+
+        assert!(matches!(parser.parse(r#"tagged """#), Err(ParseError::InvalidSyntax(_))));
+        assert!(matches!(parser.parse("tagged two words"), Err(ParseError::InvalidSyntax(_))));
+    }
+
+    #[test]
+    fn test_parse_match() {
+        let parser = Parser::new();
+        let query = parser
+            .parse("MATCH (a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c) return c limit 5")
+            .unwrap();
+        assert_eq!(
+            query.root,
+            QueryNode::Match {
+                pattern: "(a:Concept)-[:CAUSES]->(b)-[:CONTRADICTS]->(c)".to_string(),
+                var: Some("c".to_string()),
+            }
+        );
+        assert_eq!(query.limit, Some(5));
+
+        let query = parser.parse("match (a)-->(b) where confidence > 0.5").unwrap();
+        let QueryNode::Filter { input, .. } = query.root else {
+            panic!("expected a filter");
+        };
+        assert!(matches!(*input, QueryNode::Match { var: None, .. }));
+
+        assert!(matches!(parser.parse("match concepts"), Err(ParseError::InvalidSyntax(_))));
+        assert!(matches!(parser.parse("match (a) return a b"), Err(ParseError::InvalidSyntax(_))));
+    }
+
+    #[test]
+    fn test_parse_pipeline_errors() {
+        let parser = Parser::new();
+        assert_eq!(parser.parse("| limit 5"), Err(ParseError::EmptyQuery));
+        assert_eq!(
+            parser.parse("pumps | explode"),
+            Err(ParseError::UnknownKeyword("explode".to_string()))
+        );
+        assert!(matches!(parser.parse("pumps | limit many"), Err(ParseError::InvalidLimit(_))));
+        assert!(matches!(parser.parse("pumps | filter confidence"), Err(ParseError::InvalidFilter(_))));
+        assert!(matches!(parser.parse("pumps | traverse 0 hops"), Err(ParseError::InvalidSyntax(_))));
+        assert!(matches!(parser.parse("pumps |"), Err(ParseError::InvalidSyntax(_))));
+    }
+
+    #[test]
+    fn test_parse_role() {
+        let parser = Parser::new();
+        let participant = uuid::Uuid::new_v4();
+        let query = parser.parse(&format!("ROLE Recipient {} limit 3", participant)).unwrap();
+        assert_eq!(
+            query.root,
+            QueryNode::Role {
+                role: "recipient".to_string(),
+                participant,
+            }
+        );
+        assert_eq!(query.limit, Some(3));
+
+        let query = parser.parse(&format!("role agent {} where type = fact", participant)).unwrap();
+        let QueryNode::Filter { input, .. } = query.root else {
+            panic!("expected a filter");
+        };
+        assert!(matches!(*input, QueryNode::Role { .. }));
+
+        for invalid in ["role agent", "role agent not-a-uuid", "role two words"] {
+            assert!(matches!(parser.parse(invalid), Err(ParseError::InvalidSyntax(_))));
+        }
+
+        let built = Query::new(QueryNode::Role {
+            role: " Agent ".to_string(),
+            participant,
+        });
+        let QueryNode::Role { role, .. } = parser.validate(built).unwrap().root else {
+            panic!("expected a role query");
+        };
+        assert_eq!(role, "agent");
+    }
+
+    #[test]
+    fn test_validate_ast() {
+        let parser = Parser::with_max_depth(3);
+        let text = |query: &str| QueryNode::TextSearch {
+            query: query.to_string(),
+        };
+
+        // Parsed queries pass unchanged
+        let parsed = parser.parse("pumps where confidence > 0.5 | traverse 2 hops").unwrap();
+        assert_eq!(parser.validate(parsed.clone()), Ok(parsed));
+
+        let query = Query::new(QueryNode::Tagged {
+            tag: "Architecture".to_string(),
+        });
+        assert_eq!(
+            parser.validate(query).unwrap().root,
+            QueryNode::Tagged {
+                tag: "architecture".to_string()
+            }
+        );
+
+        let nested = QueryNode::Not {
+            input: Box::new(QueryNode::Not {
+                input: Box::new(QueryNode::Not {
+                    input: Box::new(text("pumps")),
+                }),
+            }),
+        };
+        assert_eq!(
+            parser.validate(Query::new(nested)),
+            Err(ParseError::QueryTooComplex {
+                max_depth: 3,
+                actual: 4
+            })
+        );
+
+        let invalid = [
+            Query::new(QueryNode::Empty).with_stage(Stage::Limit(5)),
+            Query::new(QueryNode::SemanticSearch {
+                embedding: Vec::new(),
+            }),
+            Query::new(QueryNode::Match {
+                pattern: "a-->b".to_string(),
+                var: None,
+            }),
+            Query::new(QueryNode::And {
+                left: Box::new(text("pumps")),
+                right: Box::new(QueryNode::Tagged { tag: String::new() }),
+            }),
+            Query::new(text("pumps")).with_stage(Stage::Filter(Vec::new())),
+            Query::new(text("pumps")).with_stage(Stage::Traverse {
+                relations: Vec::new(),
+                direction: TraverseDirection::Forward,
+                max_hops: 0,
+            }),
+        ];
+        for query in invalid {
+            assert!(parser.validate(query.clone()).is_err(), "{:?}", query);
+        }
+    }
 }
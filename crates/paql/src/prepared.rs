@@ -0,0 +1,369 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+use std::collections::HashMap;
+
+use crate::{
+    ast::{FilterValue, Query, QueryNode, Stage},
+    error::{ParseError, ParseResult},
+    parser::Parser,
+};
+
+/// Prefix of the internal token that stands in for a placeholder while the
+/// template is parsed.
+const SENTINEL_PREFIX: &str = "__paql_param_";
+
+/// Suffix of the internal placeholder token.
+const SENTINEL_SUFFIX: &str = "__";
+
+/// A PaQL query template compiled once and executed many times.
+///
+/// Templates reference values with `$name` placeholders. The template is
+/// parsed when it is prepared; binding only substitutes values into the
+/// already-compiled plan, so bound values can never change the query
+/// structure (e.g. a value containing `" or "` stays a literal search term).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedQuery {
+    /// The original template text.
+    template: String,
+
+    /// Placeholder names in order of first appearance.
+    params: Vec<String>,
+
+    /// Compiled plan with placeholder tokens in value positions.
+    plan: Query,
+}
+
+impl PreparedQuery {
+    /// Compile a query template with the given parser.
+    pub fn prepare(parser: &Parser, template: &str) -> ParseResult<Self> {
+        let (rewritten, params) = Self::extract_placeholders(template)?;
+        let plan = parser.parse(&rewritten)?;
+
+        // Placeholders must survive parsing as literal values; anything else
+        // (e.g. a seed ID or limit) would silently change the query shape
+        let mut leaves = Vec::new();
+        collect_strings(&plan.root, &mut leaves);
+        for stage in &plan.stages {
+            if let Stage::Filter(filters) = stage {
+                for filter in filters {
+                    collect_value_strings(&filter.value, &mut leaves);
+                }
+            }
+        }
+        for (index, name) in params.iter().enumerate() {
+            let token = sentinel(index);
+            if !leaves.iter().any(|leaf| leaf.contains(&token)) {
+                return Err(ParseError::InvalidSyntax(format!(
+                    "placeholder ${} is not in a value position",
+                    name
+                )));
+            }
+        }
+
+        Ok(Self {
+            template: template.to_string(),
+            params,
+            plan,
+        })
+    }
+
+    /// Get the original template text.
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Get the placeholder names in order of first appearance.
+    pub fn params(&self) -> &[String] {
+        &self.params
+    }
+
+    /// Bind values to the placeholders, producing an executable query.
+    ///
+    /// Every placeholder must be bound and no unknown names are accepted.
+    pub fn bind(&self, values: &HashMap<String, String>) -> ParseResult<Query> {
+        if let Some(name) = values.keys().find(|name| !self.params.contains(name)) {
+            return Err(ParseError::UnknownParameter(name.clone()));
+        }
+
+        let bound = self
+            .params
+            .iter()
+            .map(|name| {
+                values
+                    .get(name)
+                    .map(String::as_str)
+                    .ok_or_else(|| ParseError::MissingParameter(name.clone()))
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        let mut query = self.plan.clone();
+        substitute_node(&mut query.root, &bound);
+        for stage in &mut query.stages {
+            if let Stage::Filter(filters) = stage {
+                for filter in filters {
+                    substitute_value(&mut filter.value, &bound);
+                }
+            }
+        }
+        Ok(query)
+    }
+
+    /// Replace `$name` placeholders with internal tokens.
+    fn extract_placeholders(template: &str) -> ParseResult<(String, Vec<String>)> {
+        let mut params: Vec<String> = Vec::new();
+        let mut rewritten = String::with_capacity(template.len());
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((pos, c)) = chars.next() {
+            if c != '$' {
+                rewritten.push(c);
+                continue;
+            }
+
+            let start = pos + 1;
+            let mut end = start;
+            while let Some(&(i, next)) = chars.peek() {
+                if next.is_ascii_alphanumeric() || next == '_' {
+                    end = i + next.len_utf8();
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+
+            let name = &template[start..end];
+            if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+                return Err(ParseError::InvalidSyntax(format!(
+                    "invalid placeholder at position {}",
+                    pos
+                )));
+            }
+
+            let index = match params.iter().position(|p| p == name) {
+                Some(index) => index,
+                None => {
+                    params.push(name.to_string());
+                    params.len() - 1
+                }
+            };
+            rewritten.push_str(&sentinel(index));
+        }
+
+        Ok((rewritten, params))
+    }
+}
+
+impl Parser {
+    /// Compile a query template with `$name` placeholders.
+    pub fn prepare(&self, template: &str) -> ParseResult<PreparedQuery> {
+        PreparedQuery::prepare(self, template)
+    }
+}
+
+fn sentinel(index: usize) -> String {
+    format!("{}{}{}", SENTINEL_PREFIX, index, SENTINEL_SUFFIX)
+}
+
+fn collect_strings<'a>(node: &'a QueryNode, out: &mut Vec<&'a str>) {
+    match node {
+        QueryNode::TextSearch { query } | QueryNode::HybridSearch { query, .. } => {
+            out.push(query)
+        }
+        QueryNode::Tagged { tag } => out.push(tag),
+        QueryNode::Filter { input, filters } => {
+            collect_strings(input, out);
+            for filter in filters {
+                collect_value_strings(&filter.value, out);
+            }
+        }
+        QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+            collect_strings(left, out);
+            collect_strings(right, out);
+        }
+        QueryNode::Not { input } => collect_strings(input, out),
+        QueryNode::Empty
+        | QueryNode::SemanticSearch { .. }
+        | QueryNode::GraphTraversal { .. }
+        | QueryNode::Match { .. }
+        | QueryNode::Role { .. } => {}
+    }
+}
+
+fn collect_value_strings<'a>(value: &'a FilterValue, out: &mut Vec<&'a str>) {
+    match value {
+        FilterValue::String(s) => out.push(s),
+        FilterValue::List(values) => {
+            for value in values {
+                collect_value_strings(value, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn substitute_node(node: &mut QueryNode, values: &[&str]) {
+    match node {
+        QueryNode::TextSearch { query } | QueryNode::HybridSearch { query, .. } => {
+            *query = substitute(query, values)
+        }
+        QueryNode::Tagged { tag } => *tag = substitute(tag, values),
+        QueryNode::Filter { input, filters } => {
+            substitute_node(input, values);
+            for filter in filters {
+                substitute_value(&mut filter.value, values);
+            }
+        }
+        QueryNode::And { left, right } | QueryNode::Or { left, right } => {
+            substitute_node(left, values);
+            substitute_node(right, values);
+        }
+        QueryNode::Not { input } => substitute_node(input, values),
+        QueryNode::Empty
+        | QueryNode::SemanticSearch { .. }
+        | QueryNode::GraphTraversal { .. }
+        | QueryNode::Match { .. }
+        | QueryNode::Role { .. } => {}
+    }
+}
+
+fn substitute_value(value: &mut FilterValue, values: &[&str]) {
+    match value {
+        FilterValue::String(s) => *s = substitute(s, values),
+        FilterValue::List(list) => {
+            for value in list {
+                substitute_value(value, values);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replace placeholder tokens in a single left-to-right pass, so bound values
+/// are never themselves scanned for tokens.
+fn substitute(input: &str, values: &[&str]) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(pos) = rest.find(SENTINEL_PREFIX) {
+        output.push_str(&rest[..pos]);
+        let after = &rest[pos + SENTINEL_PREFIX.len()..];
+        let digits = after.len() - after.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let index = after[..digits].parse::<usize>().ok();
+
+        match index.and_then(|i| values.get(i)) {
+            Some(value) if after[digits..].starts_with(SENTINEL_SUFFIX) => {
+                output.push_str(value);
+                rest = &after[digits + SENTINEL_SUFFIX.len()..];
+            }
+            _ => {
+                output.push_str(SENTINEL_PREFIX);
+                rest = after;
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_prepare_and_bind() {
+        let prepared = Parser::new().prepare("find $topic").unwrap();
+        assert_eq!(prepared.params(), ["topic"]);
+
+        let query = prepared.bind(&values(&[("topic", "rust")])).unwrap();
+        assert_eq!(
+            query.root,
+            QueryNode::TextSearch {
+                query: "rust".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_bind_reuses_repeated_placeholder() {
+        let prepared = Parser::new().prepare("$a and $b or $a").unwrap();
+        assert_eq!(prepared.params(), ["a", "b"]);
+
+        let query = prepared.bind(&values(&[("a", "x"), ("b", "y")])).unwrap();
+        let QueryNode::And { left, right } = query.root else {
+            panic!("expected AND query");
+        };
+        assert_eq!(*left, QueryNode::TextSearch { query: "x".to_string() });
+        assert_eq!(*right, QueryNode::TextSearch { query: "y or x".to_string() });
+    }
+
+    #[test]
+    fn test_bound_values_do_not_change_structure() {
+        let prepared = Parser::new().prepare("find $topic").unwrap();
+        let query = prepared
+            .bind(&values(&[("topic", "cats or not dogs limit 1")]))
+            .unwrap();
+
+        assert_eq!(
+            query.root,
+            QueryNode::TextSearch {
+                query: "cats or not dogs limit 1".to_string()
+            }
+        );
+        assert!(query.limit.is_none());
+    }
+
+    #[test]
+    fn test_bind_parameter_errors() {
+        let prepared = Parser::new().prepare("find $topic").unwrap();
+
+        assert_eq!(
+            prepared.bind(&HashMap::new()),
+            Err(ParseError::MissingParameter("topic".to_string()))
+        );
+        assert_eq!(
+            prepared.bind(&values(&[("topic", "a"), ("other", "b")])),
+            Err(ParseError::UnknownParameter("other".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_bind_pipeline_filter_values() {
+        let prepared = Parser::new()
+            .prepare("similar to $topic | filter lang = $lang")
+            .unwrap();
+        let query = prepared
+            .bind(&values(&[("topic", "pumps"), ("lang", "en")]))
+            .unwrap();
+
+        assert_eq!(
+            query.root,
+            QueryNode::HybridSearch {
+                query: "pumps".to_string(),
+                embedding: Vec::new(),
+            }
+        );
+        let Stage::Filter(filters) = &query.stages[0] else {
+            panic!("expected a filter stage");
+        };
+        assert_eq!(filters[0].value, FilterValue::String("en".to_string()));
+    }
+
+    #[test]
+    fn test_prepare_rejects_invalid_placeholders() {
+        let parser = Parser::new();
+        assert!(parser.prepare("find $").is_err());
+        assert!(parser.prepare("find $1abc").is_err());
+        // Sort clauses are stripped during parsing, so the value is lost
+        assert!(parser.prepare("find concepts sort by $field").is_err());
+    }
+}
@@ -0,0 +1,432 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! User-defined ranking expressions.
+//!
+//! A `rank by` clause replaces the default access-score ordering with an
+//! arithmetic expression over per-candidate signals, e.g.
+//! `rank by 0.6*similarity + 0.3*recency + 0.1*centrality`.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ParseError, ParseResult};
+
+/// Maximum nesting depth of parentheses and unary minus.
+const MAX_RANK_DEPTH: usize = 32;
+
+/// Signal available to a ranking expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankVar {
+    /// Query/content similarity (0.0 - 1.0).
+    Similarity,
+
+    /// Freshness of the node (1.0 = just touched, decaying with age).
+    Recency,
+
+    /// Connectedness of the node relative to the other candidates (0.0 - 1.0).
+    Centrality,
+
+    /// Node confidence (0.0 - 1.0).
+    Confidence,
+
+    /// Node access score (0.0 - 10.0).
+    AccessScore,
+}
+
+impl RankVar {
+    /// All variables, in declaration order.
+    pub const ALL: [RankVar; 5] = [
+        Self::Similarity,
+        Self::Recency,
+        Self::Centrality,
+        Self::Confidence,
+        Self::AccessScore,
+    ];
+
+    /// Name used in expressions.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Similarity => "similarity",
+            Self::Recency => "recency",
+            Self::Centrality => "centrality",
+            Self::Confidence => "confidence",
+            Self::AccessScore => "access_score",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|var| var.name() == name)
+    }
+}
+
+/// Values of the ranking variables for one candidate.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RankVars {
+    /// Query/content similarity.
+    pub similarity: f64,
+    /// Freshness of the node.
+    pub recency: f64,
+    /// Relative connectedness.
+    pub centrality: f64,
+    /// Node confidence.
+    pub confidence: f64,
+    /// Node access score.
+    pub access_score: f64,
+}
+
+impl RankVars {
+    /// Value of a single variable.
+    pub fn get(&self, var: RankVar) -> f64 {
+        match var {
+            RankVar::Similarity => self.similarity,
+            RankVar::Recency => self.recency,
+            RankVar::Centrality => self.centrality,
+            RankVar::Confidence => self.confidence,
+            RankVar::AccessScore => self.access_score,
+        }
+    }
+}
+
+/// Arithmetic operator in a ranking expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankOp {
+    /// Addition.
+    Add,
+    /// Subtraction.
+    Sub,
+    /// Multiplication.
+    Mul,
+    /// Division (division by zero yields 0).
+    Div,
+}
+
+impl RankOp {
+    fn symbol(&self) -> char {
+        match self {
+            Self::Add => '+',
+            Self::Sub => '-',
+            Self::Mul => '*',
+            Self::Div => '/',
+        }
+    }
+}
+
+/// A parsed ranking expression.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankExpr {
+    /// Numeric literal.
+    Number(f64),
+
+    /// Ranking variable.
+    Var(RankVar),
+
+    /// Negation.
+    Neg(Box<RankExpr>),
+
+    /// Binary operation.
+    Binary {
+        /// Operator.
+        op: RankOp,
+        /// Left operand.
+        left: Box<RankExpr>,
+        /// Right operand.
+        right: Box<RankExpr>,
+    },
+}
+
+impl RankExpr {
+    /// Parse an expression such as `0.6*similarity + 0.4*recency`.
+    pub fn parse(input: &str) -> ParseResult<Self> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err(ParseError::InvalidRank("expression is empty".to_string()));
+        }
+
+        let mut parser = RankParser { tokens, pos: 0, depth: 0 };
+        let expr = parser.expr()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(ParseError::InvalidRank(format!("unexpected '{}'", token))),
+        }
+    }
+
+    /// Evaluate the expression for one candidate.
+    ///
+    /// Non-finite results (e.g. from overflow) evaluate to 0.
+    pub fn eval(&self, vars: &RankVars) -> f64 {
+        let value = self.eval_raw(vars);
+        if value.is_finite() {
+            value
+        } else {
+            0.0
+        }
+    }
+
+    fn eval_raw(&self, vars: &RankVars) -> f64 {
+        match self {
+            Self::Number(n) => *n,
+            Self::Var(var) => vars.get(*var),
+            Self::Neg(inner) => -inner.eval_raw(vars),
+            Self::Binary { op, left, right } => {
+                let (l, r) = (left.eval_raw(vars), right.eval_raw(vars));
+                match op {
+                    RankOp::Add => l + r,
+                    RankOp::Sub => l - r,
+                    RankOp::Mul => l * r,
+                    RankOp::Div if r == 0.0 => 0.0,
+                    RankOp::Div => l / r,
+                }
+            }
+        }
+    }
+
+    /// Check whether the expression reads a variable, so callers can skip
+    /// computing expensive signals that are not used.
+    pub fn uses(&self, var: RankVar) -> bool {
+        match self {
+            Self::Number(_) => false,
+            Self::Var(v) => *v == var,
+            Self::Neg(inner) => inner.uses(var),
+            Self::Binary { left, right, .. } => left.uses(var) || right.uses(var),
+        }
+    }
+}
+
+impl fmt::Display for RankExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Var(var) => write!(f, "{}", var.name()),
+            Self::Neg(inner) => write!(f, "-({})", inner),
+            Self::Binary { op, left, right } => write!(f, "({} {} {})", left, op.symbol(), right),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    Open,
+    Close,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Ident(name) => write!(f, "{}", name),
+            Self::Op(c) => write!(f, "{}", c),
+            Self::Open => write!(f, "("),
+            Self::Close => write!(f, ")"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> ParseResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                if d.is_ascii_digit() || d == '.' {
+                    end = i + 1;
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let literal = &input[start..end];
+            let value = literal
+                .parse()
+                .map_err(|_| ParseError::InvalidRank(format!("invalid number '{}'", literal)))?;
+            tokens.push(Token::Number(value));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                if d.is_ascii_alphanumeric() || d == '_' {
+                    end = i + 1;
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Ident(input[start..end].to_lowercase()));
+        } else {
+            chars.next();
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' => Token::Op(c),
+                '(' => Token::Open,
+                ')' => Token::Close,
+                other => {
+                    return Err(ParseError::InvalidRank(format!(
+                        "unexpected character '{}'",
+                        other
+                    )))
+                }
+            });
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent parser: `expr := term (('+'|'-') term)*`,
+/// `term := factor (('*'|'/') factor)*`,
+/// `factor := '-' factor | number | variable | '(' expr ')'`.
+struct RankParser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl RankParser {
+    fn next_op(&mut self, ops: &[char]) -> Option<RankOp> {
+        let Some(Token::Op(c)) = self.tokens.get(self.pos) else {
+            return None;
+        };
+        let op = match c {
+            '+' => RankOp::Add,
+            '-' => RankOp::Sub,
+            '*' => RankOp::Mul,
+            '/' => RankOp::Div,
+            _ => return None,
+        };
+        if !ops.contains(c) {
+            return None;
+        }
+        self.pos += 1;
+        Some(op)
+    }
+
+    fn expr(&mut self) -> ParseResult<RankExpr> {
+        let mut left = self.term()?;
+        while let Some(op) = self.next_op(&['+', '-']) {
+            let right = self.term()?;
+            left = RankExpr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn term(&mut self) -> ParseResult<RankExpr> {
+        let mut left = self.factor()?;
+        while let Some(op) = self.next_op(&['*', '/']) {
+            let right = self.factor()?;
+            left = RankExpr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn factor(&mut self) -> ParseResult<RankExpr> {
+        self.depth += 1;
+        if self.depth > MAX_RANK_DEPTH {
+            return Err(ParseError::QueryTooComplex {
+                max_depth: MAX_RANK_DEPTH,
+                actual: self.depth,
+            });
+        }
+
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        let expr = match token {
+            Some(Token::Op('-')) => RankExpr::Neg(Box::new(self.factor()?)),
+            Some(Token::Number(n)) => RankExpr::Number(n),
+            Some(Token::Ident(name)) => RankVar::from_name(&name)
+                .map(RankExpr::Var)
+                .ok_or_else(|| {
+                    let known: Vec<_> = RankVar::ALL.iter().map(RankVar::name).collect();
+                    ParseError::InvalidRank(format!(
+                        "unknown variable '{}' (expected one of {})",
+                        name,
+                        known.join(", ")
+                    ))
+                })?,
+            Some(Token::Open) => {
+                let inner = self.expr()?;
+                if self.tokens.get(self.pos) != Some(&Token::Close) {
+                    return Err(ParseError::InvalidRank("missing ')'".to_string()));
+                }
+                self.pos += 1;
+                inner
+            }
+            Some(other) => {
+                return Err(ParseError::InvalidRank(format!("unexpected '{}'", other)))
+            }
+            None => return Err(ParseError::InvalidRank("unexpected end of expression".to_string())),
+        };
+
+        self.depth -= 1;
+        Ok(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> RankVars {
+        RankVars {
+            similarity: 0.5,
+            recency: 1.0,
+            centrality: 0.2,
+            confidence: 0.9,
+            access_score: 4.0,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_eval_weighted_sum() {
+        let expr = RankExpr::parse("0.6*similarity + 0.3*recency + 0.1*centrality").unwrap();
+        assert!((expr.eval(&vars()) - (0.3 + 0.3 + 0.02)).abs() < 1e-9);
+        assert!(expr.uses(RankVar::Centrality));
+        assert!(!expr.uses(RankVar::AccessScore));
+        assert_eq!(
+            expr.to_string(),
+            "(((0.6 * similarity) + (0.3 * recency)) + (0.1 * centrality))"
+        );
+    }
+
+    #[test]
+    fn test_precedence_parens_and_division() {
+        let vars = vars();
+        assert_eq!(RankExpr::parse("1 + 2 * 3").unwrap().eval(&vars), 7.0);
+        assert_eq!(RankExpr::parse("(1 + 2) * 3").unwrap().eval(&vars), 9.0);
+        assert_eq!(RankExpr::parse("-access_score / 2").unwrap().eval(&vars), -2.0);
+        assert_eq!(RankExpr::parse("Confidence / 0").unwrap().eval(&vars), 0.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in ["", "0.6 *", "similarity +* recency", "(recency", "popularity", "1.2.3", "recency; drop"] {
+            assert!(
+                matches!(RankExpr::parse(input), Err(ParseError::InvalidRank(_))),
+                "{:?} should be rejected",
+                input
+            );
+        }
+        let deep = format!("{}1{}", "(".repeat(64), ")".repeat(64));
+        assert!(matches!(
+            RankExpr::parse(&deep),
+            Err(ParseError::QueryTooComplex { .. })
+        ));
+    }
+}
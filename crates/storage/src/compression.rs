@@ -0,0 +1,161 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Node content compression.
+//!
+//! Nodes are stored as JSON. With compression enabled, a node whose content
+//! reaches the size threshold (typically a large `raw_chunk`) is stored as a
+//! zstd frame of its JSON instead. Frames start with the zstd magic number,
+//! which JSON never does, so both encodings can be read back regardless of
+//! the current settings. Either encoding follows the format version byte
+//! (see [`crate::migration`]).
+
+use serde::{Deserialize, Serialize};
+use synton_core::Node;
+
+use crate::migration::node_migrations;
+use crate::{StorageError, StorageResult};
+
+/// Magic number every zstd frame starts with.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Node content compression settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentCompression {
+    /// Smallest content (in bytes) of a compressed node.
+    pub threshold: usize,
+    /// zstd compression level.
+    pub level: i32,
+}
+
+impl Default for ContentCompression {
+    fn default() -> Self {
+        Self {
+            threshold: 16 * 1024,
+            level: 3,
+        }
+    }
+}
+
+/// Sizes of the compressed nodes in a store.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompressionStats {
+    /// Number of stored nodes that are compressed
+    pub compressed_nodes: u64,
+    /// Size of the compressed nodes as stored (bytes)
+    pub compressed_bytes: u64,
+    /// Size of the compressed nodes before compression (bytes)
+    pub uncompressed_bytes: u64,
+}
+
+impl CompressionStats {
+    /// Count a stored node value.
+    pub(crate) fn add(&mut self, bytes: &[u8]) {
+        let Ok((_, payload)) = node_migrations().split(bytes) else {
+            return;
+        };
+        if !payload.starts_with(&ZSTD_MAGIC) {
+            return;
+        }
+        self.compressed_nodes += 1;
+        self.compressed_bytes += bytes.len() as u64;
+        self.uncompressed_bytes += zstd::zstd_safe::get_frame_content_size(payload)
+            .ok()
+            .flatten()
+            .unwrap_or(0);
+    }
+
+    /// Uncompressed size over stored size of the compressed nodes (1.0 if
+    /// none are compressed).
+    pub fn ratio(&self) -> f64 {
+        if self.compressed_bytes == 0 {
+            1.0
+        } else {
+            self.uncompressed_bytes as f64 / self.compressed_bytes as f64
+        }
+    }
+}
+
+/// Encode a node for storage in the current format version, compressing
+/// it if its content reaches the threshold.
+pub(crate) fn encode_node(
+    node: &Node,
+    compression: Option<&ContentCompression>,
+) -> StorageResult<Vec<u8>> {
+    let json = serde_json::to_vec(node).map_err(|e| StorageError::Serialization(e.to_string()))?;
+    let payload = match compression {
+        Some(compression) if node.content.len() >= compression.threshold => {
+            zstd::bulk::compress(&json, compression.level)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?
+        }
+        _ => json,
+    };
+    Ok(node_migrations().encode(&payload))
+}
+
+/// Decode a node encoded by [`encode_node`] in any supported version.
+pub(crate) fn decode_node(bytes: &[u8]) -> StorageResult<Node> {
+    let migrations = node_migrations();
+    let (version, payload) = migrations.split(bytes)?;
+    if payload.starts_with(&ZSTD_MAGIC) {
+        let json = zstd::stream::decode_all(payload)
+            .map_err(|e| StorageError::Deserialization(e.to_string()))?;
+        return migrations.deserialize(version, &json);
+    }
+    migrations.deserialize(version, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::NodeType;
+
+    #[test]
+    fn test_content_compression() {
+        let compression = ContentCompression {
+            threshold: 1024,
+            ..Default::default()
+        };
+        let small = Node::new("Paris is the capital of France", NodeType::Fact);
+        let large = Node::new("All work and no play. ".repeat(500), NodeType::RawChunk);
+
+        let stored = encode_node(&small, Some(&compression)).unwrap();
+        assert_eq!(stored[1..], serde_json::to_vec(&small).unwrap());
+        assert_eq!(decode_node(&stored).unwrap().content, small.content);
+
+        let stored = encode_node(&large, Some(&compression)).unwrap();
+        assert!(stored[1..].starts_with(&ZSTD_MAGIC));
+        assert_eq!(decode_node(&stored).unwrap().content, large.content);
+
+        let mut stats = CompressionStats::default();
+        stats.add(&stored);
+        stats.add(&encode_node(&small, Some(&compression)).unwrap());
+        assert_eq!(stats.compressed_nodes, 1);
+        assert_eq!(stats.compressed_bytes, stored.len() as u64);
+        assert_eq!(stats.uncompressed_bytes, serde_json::to_vec(&large).unwrap().len() as u64);
+        assert!(stats.ratio() > 10.0);
+
+        // Disabled compression stores JSON
+        assert_eq!(encode_node(&large, None).unwrap()[1..], serde_json::to_vec(&large).unwrap());
+    }
+
+    #[test]
+    fn test_nodes_v1_to_v2() {
+        let node = Node::new("Written before format versions", NodeType::Fact);
+        let v1 = serde_json::to_vec(&node).unwrap();
+        let v1_compressed = zstd::bulk::compress(&v1, 3).unwrap();
+        for stored in [&v1, &v1_compressed] {
+            assert_eq!(decode_node(stored).unwrap().id, node.id);
+        }
+
+        let v2 = encode_node(&node, None).unwrap();
+        assert_eq!(v2[0], node_migrations().current_version());
+        assert_eq!(decode_node(&v2).unwrap().id, node.id);
+
+        let mut stats = CompressionStats::default();
+        stats.add(&v1_compressed);
+        stats.add(&v2);
+        assert_eq!(stats.compressed_nodes, 1);
+    }
+}
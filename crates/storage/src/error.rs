@@ -5,6 +5,7 @@
 use std::fmt;
 
 use synton_core::CoreError;
+use synton_error::{Coded, ErrorCode};
 use uuid::Uuid;
 
 /// Storage layer errors.
@@ -13,12 +14,18 @@ pub enum StorageError {
     /// Underlying RocksDB error
     Rocksdb(String),
 
+    /// Underlying SQLite error
+    Sqlite(String),
+
     /// Node not found
     NodeNotFound(Uuid),
 
     /// Edge not found
     EdgeNotFound(String),
 
+    /// Edge references a node that is neither stored nor archived
+    DanglingEdge { edge: String, node: Uuid },
+
     /// Serialization failed
     Serialization(String),
 
@@ -42,8 +49,12 @@ impl fmt::Display for StorageError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Rocksdb(e) => write!(f, "RocksDB error: {}", e),
+            Self::Sqlite(e) => write!(f, "SQLite error: {}", e),
             Self::NodeNotFound(id) => write!(f, "Node not found: {}", id),
             Self::EdgeNotFound(e) => write!(f, "Edge not found: {}", e),
+            Self::DanglingEdge { edge, node } => {
+                write!(f, "Edge {} references missing node {}", edge, node)
+            }
             Self::Serialization(e) => write!(f, "Serialization error: {}", e),
             Self::Deserialization(e) => write!(f, "Deserialization error: {}", e),
             Self::InvalidOperation(e) => write!(f, "Invalid operation: {}", e),
@@ -54,7 +65,28 @@ impl fmt::Display for StorageError {
     }
 }
 
-impl std::error::Error for StorageError {}
+impl std::error::Error for StorageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Core(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Coded for StorageError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::NodeNotFound(_) | Self::EdgeNotFound(_) => ErrorCode::NotFound,
+            Self::Serialization(_) | Self::Deserialization(_) => ErrorCode::Serialization,
+            Self::InvalidOperation(_) | Self::DanglingEdge { .. } => ErrorCode::InvalidInput,
+            Self::DatabaseClosed => ErrorCode::Unavailable,
+            Self::Core(e) => e.code(),
+            Self::Rocksdb(_) | Self::Sqlite(_) | Self::Io(_) => ErrorCode::Storage,
+        }
+    }
+}
 
 impl From<std::io::Error> for StorageError {
     fn from(e: std::io::Error) -> Self {
@@ -71,3 +103,21 @@ impl From<CoreError> for StorageError {
 /// Result type for storage operations.
 pub type StorageResult<T> = Result<T, StorageError>;
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_error::SyntonError;
+
+    #[test]
+    fn test_error_codes_and_causes() {
+        let io = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+        let err: SyntonError = StorageError::from(io).into();
+        assert_eq!(err.code(), ErrorCode::Storage);
+        assert_eq!(err.to_string(), "I/O error: disk full");
+
+        let err: SyntonError = StorageError::Core(CoreError::EmptyContent).into();
+        assert_eq!(err.code(), ErrorCode::InvalidInput);
+        assert!(err.causes().is_empty());
+        assert_eq!(StorageError::DatabaseClosed.code(), ErrorCode::Unavailable);
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Fault-injecting store wrapper for resilience testing.
+
+use std::io;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use synton_core::{Edge, Fault, FaultInjector, Node};
+use uuid::Uuid;
+
+use crate::store::NodeFilter;
+use crate::{
+    ColumnFamily, Durability, MigrationReport, StorageError, StorageResult, StorageStats, Store,
+    WriteOp,
+};
+
+/// Store that injects latency, timeouts and I/O errors into another store.
+///
+/// Injected failures are [`StorageError::Io`] errors, so callers see them
+/// exactly like a failing disk.
+pub struct FaultyStore {
+    inner: Arc<dyn Store>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultyStore {
+    /// Wrap a store.
+    pub fn new(inner: Arc<dyn Store>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+
+    /// The fault injector.
+    pub fn injector(&self) -> &Arc<FaultInjector> {
+        &self.injector
+    }
+
+    /// Apply the next fault before running `op`.
+    async fn inject(&self, op: &str) -> StorageResult<()> {
+        let fault = match self.injector.next_fault() {
+            Some(fault) => fault,
+            None => return Ok(()),
+        };
+        tracing::debug!("Injecting {:?} into {} {}", fault, self.injector.label(), op);
+
+        match fault {
+            Fault::Latency(delay) => {
+                tokio::time::sleep(delay).await;
+                Ok(())
+            }
+            Fault::Timeout(delay) => {
+                tokio::time::sleep(delay).await;
+                Err(StorageError::Io(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("injected timeout in {}", op),
+                )))
+            }
+            Fault::Error => Err(StorageError::Io(io::Error::other(format!(
+                "injected failure in {}",
+                op
+            )))),
+        }
+    }
+}
+
+#[async_trait]
+impl Store for FaultyStore {
+    async fn get_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        self.inject("get_node").await?;
+        self.inner.get_node(id).await
+    }
+
+    async fn put_node(&self, node: &Node) -> StorageResult<()> {
+        self.inject("put_node").await?;
+        self.inner.put_node(node).await
+    }
+
+    async fn delete_node(&self, id: Uuid) -> StorageResult<bool> {
+        self.inject("delete_node").await?;
+        self.inner.delete_node(id).await
+    }
+
+    async fn node_exists(&self, id: Uuid) -> StorageResult<bool> {
+        self.inject("node_exists").await?;
+        self.inner.node_exists(id).await
+    }
+
+    async fn archive_node(&self, id: Uuid) -> StorageResult<bool> {
+        self.inject("archive_node").await?;
+        self.inner.archive_node(id).await
+    }
+
+    async fn restore_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        self.inject("restore_node").await?;
+        self.inner.restore_node(id).await
+    }
+
+    async fn get_archived_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        self.inject("get_archived_node").await?;
+        self.inner.get_archived_node(id).await
+    }
+
+    async fn scan_archived_nodes(
+        &self,
+        filter: Option<NodeFilter>,
+    ) -> StorageResult<BoxStream<'_, StorageResult<Node>>> {
+        self.inject("scan_archived_nodes").await?;
+        self.inner.scan_archived_nodes(filter).await
+    }
+
+    async fn get_edge(
+        &self,
+        source: Uuid,
+        target: Uuid,
+        relation: &str,
+    ) -> StorageResult<Option<Edge>> {
+        self.inject("get_edge").await?;
+        self.inner.get_edge(source, target, relation).await
+    }
+
+    async fn put_edge(&self, edge: &Edge) -> StorageResult<()> {
+        self.inject("put_edge").await?;
+        self.inner.put_edge(edge).await
+    }
+
+    async fn delete_edge(&self, source: Uuid, target: Uuid, relation: &str) -> StorageResult<bool> {
+        self.inject("delete_edge").await?;
+        self.inner.delete_edge(source, target, relation).await
+    }
+
+    async fn get_outgoing_edges(&self, source: Uuid) -> StorageResult<Vec<Edge>> {
+        self.inject("get_outgoing_edges").await?;
+        self.inner.get_outgoing_edges(source).await
+    }
+
+    async fn get_incoming_edges(&self, target: Uuid) -> StorageResult<Vec<Edge>> {
+        self.inject("get_incoming_edges").await?;
+        self.inner.get_incoming_edges(target).await
+    }
+
+    async fn batch_write(&self, ops: Vec<WriteOp>) -> StorageResult<()> {
+        self.inject("batch_write").await?;
+        self.inner.batch_write(ops).await
+    }
+
+    async fn batch_write_with_durability(
+        &self,
+        ops: Vec<WriteOp>,
+        durability: Durability,
+    ) -> StorageResult<()> {
+        self.inject("batch_write").await?;
+        self.inner.batch_write_with_durability(ops, durability).await
+    }
+
+    async fn scan_nodes(
+        &self,
+        filter: Option<NodeFilter>,
+    ) -> StorageResult<BoxStream<'_, StorageResult<Node>>> {
+        self.inject("scan_nodes").await?;
+        self.inner.scan_nodes(filter).await
+    }
+
+    async fn count_nodes(&self) -> StorageResult<usize> {
+        self.inject("count_nodes").await?;
+        self.inner.count_nodes().await
+    }
+
+    async fn count_edges(&self) -> StorageResult<usize> {
+        self.inject("count_edges").await?;
+        self.inner.count_edges().await
+    }
+
+    async fn scan_prefix(
+        &self,
+        cf: ColumnFamily,
+        prefix: &[u8],
+    ) -> StorageResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.inject("scan_prefix").await?;
+        self.inner.scan_prefix(cf, prefix).await
+    }
+
+    async fn dangling_edges(&self) -> StorageResult<Vec<Edge>> {
+        self.inject("dangling_edges").await?;
+        self.inner.dangling_edges().await
+    }
+
+    async fn get_metadata(&self, key: &str) -> StorageResult<Option<Vec<u8>>> {
+        self.inject("get_metadata").await?;
+        self.inner.get_metadata(key).await
+    }
+
+    async fn put_metadata(&self, key: &str, value: &[u8]) -> StorageResult<()> {
+        self.inject("put_metadata").await?;
+        self.inner.put_metadata(key, value).await
+    }
+
+    async fn compact(&self, cf: Option<ColumnFamily>) -> StorageResult<()> {
+        self.inject("compact").await?;
+        self.inner.compact(cf).await
+    }
+
+    async fn migrate(&self) -> StorageResult<MigrationReport> {
+        self.inject("migrate").await?;
+        self.inner.migrate().await
+    }
+
+    async fn storage_stats(&self) -> StorageResult<StorageStats> {
+        self.inject("storage_stats").await?;
+        self.inner.storage_stats().await
+    }
+
+    async fn flush(&self) -> StorageResult<()> {
+        self.inject("flush").await?;
+        self.inner.flush().await
+    }
+
+    fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::sqlite::SqliteStore;
+    use synton_core::{FaultConfig, NodeType};
+    use synton_error::{Coded, ErrorCode};
+
+    fn faulty(dir: &tempfile::TempDir, config: FaultConfig) -> FaultyStore {
+        let store = SqliteStore::open_path(dir.path().join("synton.db")).unwrap();
+        FaultyStore::new(Arc::new(store), Arc::new(FaultInjector::new(config, "store")))
+    }
+
+    #[tokio::test]
+    async fn test_injected_errors_are_retryable() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = FaultConfig {
+            seed: 3,
+            error_rate: 0.5,
+            ..Default::default()
+        };
+        let store = faulty(&dir, config);
+        let node = Node::new("flaky write", NodeType::Fact);
+
+        // Retrying eventually gets through; the sequence is fixed by the seed
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match store.put_node(&node).await {
+                Ok(()) => break,
+                Err(e) => {
+                    assert_eq!(e.code(), ErrorCode::Storage);
+                    assert!(e.to_string().contains("injected failure in put_node"));
+                }
+            }
+            assert!(attempts < 50);
+        }
+        assert_eq!(store.injector().injected(), attempts - 1);
+
+        let stored = loop {
+            if let Ok(stored) = store.get_node(node.id).await {
+                break stored;
+            }
+        };
+        assert_eq!(stored.map(|n| n.id), Some(node.id));
+    }
+
+    #[tokio::test]
+    async fn test_injected_latency_and_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let slow = faulty(
+            &dir,
+            FaultConfig {
+                latency_rate: 1.0,
+                latency_ms: 20,
+                ..Default::default()
+            },
+        );
+        let start = std::time::Instant::now();
+        assert_eq!(slow.count_nodes().await.unwrap(), 0);
+        assert!(start.elapsed() >= std::time::Duration::from_millis(20));
+
+        let hanging = faulty(
+            &dir,
+            FaultConfig {
+                timeout_rate: 1.0,
+                timeout_ms: 1,
+                ..Default::default()
+            },
+        );
+        match hanging.count_nodes().await {
+            Err(StorageError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::TimedOut),
+            other => panic!("expected timeout, got {:?}", other),
+        }
+        assert!(!hanging.is_closed());
+    }
+}
@@ -5,12 +5,23 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod compression;
 mod error;
+pub mod migration;
 mod store;
+mod fault;
 pub mod rocksdb;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 
+pub use compression::{CompressionStats, ContentCompression};
 pub use error::{StorageError, StorageResult};
-pub use store::{ColumnFamily, Store, WriteOp};
+pub use fault::FaultyStore;
+pub use migration::MigrationReport;
+pub use store::{
+    tag_index_key, tag_index_prefix, ColumnFamily, ColumnFamilyStats, Durability, StorageStats, Store,
+    WriteOp,
+};
 
 /// Re-exports commonly used types
 pub mod prelude {
@@ -0,0 +1,300 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Versioned serialization of stored nodes and edges.
+//!
+//! A stored node or edge starts with a format version byte followed by its
+//! encoding (JSON, or a zstd frame of JSON for a compressed node). Values
+//! written before formats were versioned start with `{` or the zstd magic
+//! number instead and are version 1. When a format changes, its version is
+//! bumped and a migration from the previous version is appended to the
+//! registry of the record kind. Reads apply the migrations an older value
+//! is missing, so existing data keeps loading, and [`crate::Store::migrate`]
+//! rewrites every stored value in the current version.
+
+use std::sync::OnceLock;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{StorageError, StorageResult};
+
+/// Version of values written without a version byte.
+pub const UNVERSIONED: u8 = 1;
+
+/// First byte of a JSON object.
+const JSON_START: u8 = b'{';
+
+/// First byte of a zstd frame.
+const ZSTD_START: u8 = 0x28;
+
+/// A step from one format version to the next.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version the migration reads; it writes `from + 1`.
+    pub from: u8,
+
+    /// What changed.
+    pub description: &'static str,
+
+    /// Rewrite a value of version `from` as the next version.
+    pub apply: fn(Value) -> Result<Value, String>,
+}
+
+/// Ordered migrations of one record kind.
+#[derive(Debug, Clone)]
+pub struct MigrationRegistry {
+    kind: &'static str,
+    migrations: Vec<Migration>,
+}
+
+impl MigrationRegistry {
+    /// Create a registry of the migrations of `kind`, which must migrate
+    /// from version 1, 2, ... in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the migrations are out of order or skip a version.
+    pub fn new(kind: &'static str, migrations: Vec<Migration>) -> Self {
+        for (i, migration) in migrations.iter().enumerate() {
+            assert_eq!(
+                usize::from(migration.from),
+                i + usize::from(UNVERSIONED),
+                "{} migrations must be ordered without gaps",
+                kind
+            );
+        }
+        Self { kind, migrations }
+    }
+
+    /// Version values are written in.
+    pub fn current_version(&self) -> u8 {
+        UNVERSIONED + self.migrations.len() as u8
+    }
+
+    /// Migrations registered, oldest first.
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
+
+    /// Prefix an encoded value with the current version.
+    pub fn encode(&self, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(payload.len() + 1);
+        bytes.push(self.current_version());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    /// Version of a stored value and its encoding without the version
+    /// byte. Fails for a version newer than the current one, written by a
+    /// later release.
+    pub fn split<'a>(&self, bytes: &'a [u8]) -> StorageResult<(u8, &'a [u8])> {
+        match bytes.first() {
+            None | Some(&JSON_START) | Some(&ZSTD_START) => Ok((UNVERSIONED, bytes)),
+            Some(&version) if version > UNVERSIONED && version <= self.current_version() => {
+                Ok((version, &bytes[1..]))
+            }
+            Some(&version) => Err(StorageError::Deserialization(format!(
+                "Unsupported {} format version {} (this build reads up to {})",
+                self.kind,
+                version,
+                self.current_version()
+            ))),
+        }
+    }
+
+    /// Deserialize the JSON of a value of `version`, migrating it to the
+    /// current version first if it is older.
+    pub fn deserialize<T: DeserializeOwned>(&self, version: u8, json: &[u8]) -> StorageResult<T> {
+        let invalid = |e: String| StorageError::Deserialization(e);
+        if version == self.current_version() {
+            return serde_json::from_slice(json).map_err(|e| invalid(e.to_string()));
+        }
+        let mut value: Value = serde_json::from_slice(json).map_err(|e| invalid(e.to_string()))?;
+        for migration in &self.migrations[usize::from(version - UNVERSIONED)..] {
+            value = (migration.apply)(value).map_err(|e| {
+                invalid(format!(
+                    "Failed to migrate {} from version {}: {}",
+                    self.kind, migration.from, e
+                ))
+            })?;
+        }
+        serde_json::from_value(value).map_err(|e| invalid(e.to_string()))
+    }
+}
+
+/// Migrations of stored nodes.
+pub fn node_migrations() -> &'static MigrationRegistry {
+    static REGISTRY: OnceLock<MigrationRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        MigrationRegistry::new(
+            "node",
+            vec![Migration {
+                from: 1,
+                description: "Prefix values with the format version",
+                apply: Ok,
+            }],
+        )
+    })
+}
+
+/// Migrations of stored edges.
+pub fn edge_migrations() -> &'static MigrationRegistry {
+    static REGISTRY: OnceLock<MigrationRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        MigrationRegistry::new(
+            "edge",
+            vec![Migration {
+                from: 1,
+                description: "Prefix values with the format version",
+                apply: Ok,
+            }],
+        )
+    })
+}
+
+/// Values rewritten by [`crate::Store::migrate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrationReport {
+    /// Format version nodes are stored in.
+    pub node_version: u8,
+    /// Format version edges are stored in.
+    pub edge_version: u8,
+    /// Active and archived nodes read.
+    pub nodes_scanned: u64,
+    /// Nodes rewritten from an older version.
+    pub nodes_migrated: u64,
+    /// Edges read.
+    pub edges_scanned: u64,
+    /// Edges rewritten from an older version.
+    pub edges_migrated: u64,
+}
+
+impl MigrationReport {
+    /// A report of the current versions with nothing migrated yet.
+    pub fn new() -> Self {
+        Self {
+            node_version: node_migrations().current_version(),
+            edge_version: edge_migrations().current_version(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Encode an edge for storage.
+pub(crate) fn encode_edge(edge: &synton_core::Edge) -> StorageResult<Vec<u8>> {
+    let json = serde_json::to_vec(edge).map_err(|e| StorageError::Serialization(e.to_string()))?;
+    Ok(edge_migrations().encode(&json))
+}
+
+/// Decode an edge encoded by [`encode_edge`] in any supported version.
+pub(crate) fn decode_edge(bytes: &[u8]) -> StorageResult<synton_core::Edge> {
+    let migrations = edge_migrations();
+    let (version, json) = migrations.split(bytes)?;
+    migrations.deserialize(version, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::{Edge, Relation};
+    use uuid::Uuid;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Note {
+        text: String,
+        tags: Vec<String>,
+    }
+
+    /// Version 2 renamed `body` to `text`; version 3 added `tags`.
+    fn note_migrations() -> MigrationRegistry {
+        MigrationRegistry::new(
+            "note",
+            vec![
+                Migration {
+                    from: 1,
+                    description: "Rename body to text",
+                    apply: |mut value| {
+                        let object = value.as_object_mut().ok_or("not an object")?;
+                        let body = object.remove("body").ok_or("missing body")?;
+                        object.insert("text".to_string(), body);
+                        Ok(value)
+                    },
+                },
+                Migration {
+                    from: 2,
+                    description: "Add tags",
+                    apply: |mut value| {
+                        value["tags"] = serde_json::json!([]);
+                        Ok(value)
+                    },
+                },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_migrates_older_versions_on_read() {
+        let migrations = note_migrations();
+        assert_eq!(migrations.current_version(), 3);
+        let expected = Note {
+            text: "hello".to_string(),
+            tags: Vec::new(),
+        };
+
+        // Unversioned values are version 1
+        let (version, json) = migrations.split(br#"{"body":"hello"}"#).unwrap();
+        assert_eq!(version, UNVERSIONED);
+        assert_eq!(
+            migrations.deserialize::<Note>(version, json).unwrap(),
+            expected
+        );
+
+        let v2 = [&[2u8][..], br#"{"text":"hello"}"#].concat();
+        let (version, json) = migrations.split(&v2).unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(
+            migrations.deserialize::<Note>(version, json).unwrap(),
+            expected
+        );
+
+        let current = migrations.encode(br#"{"text":"hello","tags":[]}"#);
+        let (version, json) = migrations.split(&current).unwrap();
+        assert_eq!(version, 3);
+        assert_eq!(
+            migrations.deserialize::<Note>(version, json).unwrap(),
+            expected
+        );
+
+        // Newer versions and failed migrations are errors
+        assert!(migrations.split(&[4u8, b'{', b'}']).is_err());
+        assert!(migrations
+            .deserialize::<Note>(1, br#"{"text":"hello"}"#)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "ordered without gaps")]
+    fn test_registry_rejects_gaps() {
+        let skip = Migration {
+            from: 2,
+            description: "Skips version 1",
+            apply: Ok,
+        };
+        MigrationRegistry::new("note", vec![skip]);
+    }
+
+    #[test]
+    fn test_edges_v1_to_v2() {
+        let edge = Edge::new(Uuid::new_v4(), Uuid::new_v4(), Relation::Causes);
+        let v1 = serde_json::to_vec(&edge).unwrap();
+        assert_eq!(decode_edge(&v1).unwrap(), edge);
+
+        let v2 = encode_edge(&edge).unwrap();
+        assert_eq!(v2[0], 2);
+        assert_eq!(&v2[1..], v1.as_slice());
+        assert_eq!(decode_edge(&v2).unwrap(), edge);
+    }
+}
@@ -5,15 +5,31 @@
 // copies of the Software, and to permit persons to whom the Software is
 // furnished to do so, subject to the following conditions:
 
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures::{stream::BoxStream, StreamExt};
 use uuid::Uuid;
 
-use crate::{ColumnFamily, StorageError, StorageResult, Store, WriteOp};
-use synton_core::{Edge, Node};
+use crate::compression::{decode_node, encode_node};
+use crate::migration::{
+    decode_edge, edge_migrations, encode_edge, node_migrations, MigrationRegistry, MigrationReport,
+};
+use crate::{
+    ColumnFamily, ColumnFamilyStats, CompressionStats, ContentCompression, Durability,
+    StorageError, StorageResult, StorageStats, Store, WriteOp,
+};
+use synton_core::{time_ordered_id_range, Edge, Node};
+
+/// Metadata key recording whether a node ID that is not time-ordered was
+/// ever stored (`1`) or not (`0`).
+const RANDOM_IDS_KEY: &str = "random_node_ids";
+
+/// Values rewritten per write batch by a migration.
+const MIGRATION_BATCH_SIZE: usize = 1000;
 
 /// RocksDB configuration.
 #[derive(Debug, Clone)]
@@ -25,6 +41,14 @@ pub struct RocksdbConfig {
     pub create_if_missing: bool,
     pub create_missing_column_families: bool,
     pub compression: RocksdbCompression,
+    /// Fsync the WAL on every write
+    pub sync: bool,
+    /// Skip the WAL for every write, trading crash safety for throughput
+    pub disable_wal: bool,
+    /// Reject edges whose endpoints are neither stored nor archived
+    pub referential_integrity: bool,
+    /// Compress nodes with large content; `None` stores every node as JSON
+    pub content_compression: Option<ContentCompression>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,6 +72,10 @@ impl Default for RocksdbConfig {
             create_if_missing: true,
             create_missing_column_families: true,
             compression: RocksdbCompression::Lz4,
+            sync: false,
+            disable_wal: false,
+            referential_integrity: false,
+            content_compression: None,
         }
     }
 }
@@ -59,6 +87,9 @@ impl Default for RocksdbConfig {
 pub struct RocksdbStore {
     db: Arc<rocksdb::DB>,
     config: RocksdbConfig,
+    /// Whether nodes may have IDs that are not time-ordered, which rules
+    /// out reading creation time windows as key ranges.
+    random_ids: AtomicBool,
 }
 
 impl RocksdbStore {
@@ -105,13 +136,57 @@ impl RocksdbStore {
             &config.path,
             cf_names.iter().map(|name| {
                 let mut cf_opts = rocksdb::Options::default();
-                cf_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+                // Archived nodes are rarely read, so favour ratio over speed
+                if name == ColumnFamily::Archive.as_str() {
+                    cf_opts.set_compression_type(rocksdb::DBCompressionType::Zstd);
+                } else {
+                    cf_opts.set_compression_type(rocksdb::DBCompressionType::Lz4);
+                }
                 (name.clone(), cf_opts)
             }),
         )
         .map_err(|e| StorageError::Rocksdb(e.to_string()))?;
 
-        Ok(Self { db: Arc::new(db), config })
+        let random_ids = Self::detect_random_ids(&db)?;
+        Ok(Self {
+            db: Arc::new(db),
+            config,
+            random_ids: AtomicBool::new(random_ids),
+        })
+    }
+
+    /// Whether the database may hold node IDs that are not time-ordered.
+    /// Databases written before this was recorded are assumed to.
+    fn detect_random_ids(db: &rocksdb::DB) -> StorageResult<bool> {
+        let handle = |cf: ColumnFamily| {
+            db.cf_handle(cf.as_str()).ok_or_else(|| {
+                StorageError::InvalidOperation(format!("Column family {} not found", cf))
+            })
+        };
+        let metadata = handle(ColumnFamily::Metadata)?;
+        if let Some(value) = db
+            .get_cf(metadata, RANDOM_IDS_KEY)
+            .map_err(|e| StorageError::Rocksdb(e.to_string()))?
+        {
+            return Ok(value.as_slice() != b"0");
+        }
+
+        let mut populated = false;
+        for cf in [ColumnFamily::Nodes, ColumnFamily::Archive] {
+            populated |= db.iterator_cf(handle(cf)?, rocksdb::IteratorMode::Start).next().is_some();
+        }
+        db.put_cf(metadata, RANDOM_IDS_KEY, if populated { b"1" } else { b"0" })
+            .map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+        Ok(populated)
+    }
+
+    /// Record in `batch` that a node ID is not time-ordered, the first time
+    /// one is written.
+    fn track_node_id(&self, id: Uuid, batch: &mut rocksdb::WriteBatch) -> StorageResult<()> {
+        if id.get_version_num() != 7 && !self.random_ids.swap(true, Ordering::Relaxed) {
+            batch.put_cf(self.cf(ColumnFamily::Metadata)?, RANDOM_IDS_KEY, b"1");
+        }
+        Ok(())
     }
 
     pub fn open_path<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
@@ -135,20 +210,160 @@ impl RocksdbStore {
             })
     }
 
-    fn serialize_node(node: &Node) -> StorageResult<Vec<u8>> {
-        serde_json::to_vec(node).map_err(|e| StorageError::Serialization(e.to_string()))
+    /// Write options for a write of the given durability.
+    fn write_options(&self, durability: Durability) -> rocksdb::WriteOptions {
+        let mut opts = rocksdb::WriteOptions::default();
+        match durability {
+            Durability::Default => {
+                opts.set_sync(self.config.sync);
+                opts.disable_wal(self.config.disable_wal);
+            }
+            Durability::Sync => opts.set_sync(true),
+            Durability::NoWal => opts.disable_wal(true),
+        }
+        opts
+    }
+
+    /// Whether a node is stored or archived.
+    fn node_present(&self, id: Uuid) -> StorageResult<bool> {
+        for cf in [ColumnFamily::Nodes, ColumnFamily::Archive] {
+            let present = self
+                .db
+                .get_pinned_cf(self.cf(cf)?, id.as_bytes())
+                .map_err(|e| StorageError::Rocksdb(e.to_string()))?
+                .is_some();
+            if present {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Check that the endpoints of edges written by a batch exist once the
+    /// batch is applied. `written` holds the nodes the batch puts (`true`)
+    /// or deletes (`false`).
+    fn check_endpoints(&self, edges: &[Edge], written: &HashMap<Uuid, bool>) -> StorageResult<()> {
+        for edge in edges {
+            for node in [edge.source, edge.target] {
+                let present = match written.get(&node) {
+                    Some(true) => true,
+                    // Deleting a node leaves its archived copy
+                    Some(false) => self
+                        .db
+                        .get_pinned_cf(self.cf(ColumnFamily::Archive)?, node.as_bytes())
+                        .map_err(|e| StorageError::Rocksdb(e.to_string()))?
+                        .is_some(),
+                    None => self.node_present(node)?,
+                };
+                if !present {
+                    return Err(StorageError::DanglingEdge { edge: edge.id(), node });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_node(&self, node: &Node) -> StorageResult<Vec<u8>> {
+        encode_node(node, self.config.content_compression.as_ref())
     }
 
     fn deserialize_node(bytes: &[u8]) -> StorageResult<Node> {
-        serde_json::from_slice(bytes).map_err(|e| StorageError::Deserialization(e.to_string()))
+        decode_node(bytes)
+    }
+
+    /// Read and filter every node stored in a column family.
+    ///
+    /// A filter on creation time reads only the matching key range when
+    /// every node ID is time-ordered.
+    fn collect_nodes(
+        &self,
+        cf: ColumnFamily,
+        filter: Option<&crate::store::NodeFilter>,
+    ) -> StorageResult<Vec<Node>> {
+        let cf = self.cf(cf)?;
+        let range = filter
+            .filter(|f| f.created_after.is_some() || f.created_before.is_some())
+            .filter(|_| !self.random_ids.load(Ordering::Relaxed))
+            .map(|f| time_ordered_id_range(f.created_after, f.created_before));
+        let iter = match &range {
+            Some((low, _)) => self.db.iterator_cf(
+                cf,
+                rocksdb::IteratorMode::From(low.as_bytes(), rocksdb::Direction::Forward),
+            ),
+            None => self.db.iterator_cf(cf, rocksdb::IteratorMode::Start),
+        };
+        let mut nodes = Vec::new();
+
+        for item in iter {
+            let (key, bytes) = item.map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+            if let Some((_, high)) = &range {
+                if &key[..] > high.as_bytes().as_slice() {
+                    break;
+                }
+            }
+            let node = Self::deserialize_node(&bytes)?;
+
+            if let Some(f) = filter {
+                if !f.matches(&node) {
+                    continue;
+                }
+            }
+
+            nodes.push(node);
+        }
+
+        Ok(nodes)
+    }
+
+    /// Read an integer property of a column family (0 if unavailable).
+    fn cf_property(&self, cf: &rocksdb::ColumnFamily, name: &str) -> StorageResult<u64> {
+        self.db
+            .property_int_value_cf(cf, name)
+            .map(|value| value.unwrap_or(0))
+            .map_err(|e| StorageError::Rocksdb(e.to_string()))
     }
 
     fn serialize_edge(edge: &Edge) -> StorageResult<Vec<u8>> {
-        serde_json::to_vec(edge).map_err(|e| StorageError::Serialization(e.to_string()))
+        encode_edge(edge)
     }
 
     fn deserialize_edge(bytes: &[u8]) -> StorageResult<Edge> {
-        serde_json::from_slice(bytes).map_err(|e| StorageError::Deserialization(e.to_string()))
+        decode_edge(bytes)
+    }
+
+    /// Rewrite the values of a column family stored in an older version of
+    /// `registry`'s format, returning how many were read and rewritten.
+    fn migrate_family(
+        &self,
+        family: ColumnFamily,
+        registry: &MigrationRegistry,
+        reencode: impl Fn(&[u8]) -> StorageResult<Vec<u8>>,
+    ) -> StorageResult<(u64, u64)> {
+        let cf = self.cf(family)?;
+        let write = |batch| {
+            self.db
+                .write_opt(batch, &self.write_options(Durability::Default))
+                .map_err(|e| StorageError::Rocksdb(e.to_string()))
+        };
+        let (mut scanned, mut migrated) = (0, 0);
+        let mut batch = rocksdb::WriteBatch::default();
+
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+            let (key, bytes) = item.map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+            scanned += 1;
+            if registry.split(&bytes)?.0 < registry.current_version() {
+                batch.put_cf(cf, key, reencode(&bytes)?);
+                migrated += 1;
+                if batch.len() >= MIGRATION_BATCH_SIZE {
+                    write(std::mem::take(&mut batch))?;
+                }
+            }
+        }
+        if !batch.is_empty() {
+            write(batch)?;
+        }
+
+        Ok((scanned, migrated))
     }
 }
 
@@ -169,19 +384,29 @@ impl Store for RocksdbStore {
 
     async fn put_node(&self, node: &Node) -> StorageResult<()> {
         let cf = self.cf(ColumnFamily::Nodes)?;
-        let value = Self::serialize_node(node)?;
+        let value = self.serialize_node(node)?;
+        let mut batch = rocksdb::WriteBatch::default();
+        self.track_node_id(node.id, &mut batch)?;
+        batch.put_cf(cf, node.id.as_bytes(), value);
         self.db
-            .put_cf(cf, node.id.as_bytes(), value)
+            .write_opt(batch, &self.write_options(Durability::Default))
             .map_err(|e| StorageError::Rocksdb(e.to_string()))
     }
 
     async fn delete_node(&self, id: Uuid) -> StorageResult<bool> {
-        if !self.node_exists(id).await? {
+        let archived = self.get_archived_node(id).await?.is_some();
+        if !archived && !self.node_exists(id).await? {
             return Ok(false);
         }
-        let cf = self.cf(ColumnFamily::Nodes)?;
+
+        let nodes_cf = self.cf(ColumnFamily::Nodes)?;
+        let archive_cf = self.cf(ColumnFamily::Archive)?;
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.delete_cf(nodes_cf, id.as_bytes());
+        batch.delete_cf(archive_cf, id.as_bytes());
+
         self.db
-            .delete_cf(cf, id.as_bytes())
+            .write_opt(batch, &self.write_options(Durability::Default))
             .map_err(|e| StorageError::Rocksdb(e.to_string()))?;
         Ok(true)
     }
@@ -194,6 +419,59 @@ impl Store for RocksdbStore {
             .map_err(|e| StorageError::Rocksdb(e.to_string()))
     }
 
+    async fn archive_node(&self, id: Uuid) -> StorageResult<bool> {
+        let Some(mut node) = self.get_node(id).await? else {
+            return Ok(false);
+        };
+        node.embedding = None;
+
+        let nodes_cf = self.cf(ColumnFamily::Nodes)?;
+        let archive_cf = self.cf(ColumnFamily::Archive)?;
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put_cf(archive_cf, id.as_bytes(), self.serialize_node(&node)?);
+        batch.delete_cf(nodes_cf, id.as_bytes());
+
+        self.db
+            .write_opt(batch, &self.write_options(Durability::Default))
+            .map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+        Ok(true)
+    }
+
+    async fn restore_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        let Some(node) = self.get_archived_node(id).await? else {
+            return Ok(None);
+        };
+
+        let nodes_cf = self.cf(ColumnFamily::Nodes)?;
+        let archive_cf = self.cf(ColumnFamily::Archive)?;
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put_cf(nodes_cf, id.as_bytes(), self.serialize_node(&node)?);
+        batch.delete_cf(archive_cf, id.as_bytes());
+
+        self.db
+            .write_opt(batch, &self.write_options(Durability::Default))
+            .map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+        Ok(Some(node))
+    }
+
+    async fn get_archived_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        let cf = self.cf(ColumnFamily::Archive)?;
+        match self.db.get_cf(cf, id.as_bytes()) {
+            Ok(Some(bytes)) => Ok(Some(Self::deserialize_node(&bytes)?)),
+            Ok(None) => Ok(None),
+            Err(e) => Err(StorageError::Rocksdb(e.to_string())),
+        }
+    }
+
+    async fn scan_archived_nodes(
+        &self,
+        filter: Option<crate::store::NodeFilter>,
+    ) -> StorageResult<BoxStream<'_, StorageResult<Node>>> {
+        let nodes = self.collect_nodes(ColumnFamily::Archive, filter.as_ref())?;
+        let stream = futures::stream::iter(nodes.into_iter().map(Ok)).boxed();
+        Ok(stream)
+    }
+
     async fn get_edge(
         &self,
         source: Uuid,
@@ -210,11 +488,14 @@ impl Store for RocksdbStore {
     }
 
     async fn put_edge(&self, edge: &Edge) -> StorageResult<()> {
+        if self.config.referential_integrity {
+            self.check_endpoints(std::slice::from_ref(edge), &HashMap::new())?;
+        }
         let cf = self.cf(ColumnFamily::Edges)?;
         let value = Self::serialize_edge(edge)?;
         let key = edge.id();
         self.db
-            .put_cf(cf, key.as_bytes(), value)
+            .put_cf_opt(cf, key.as_bytes(), value, &self.write_options(Durability::Default))
             .map_err(|e| StorageError::Rocksdb(e.to_string()))
     }
 
@@ -227,7 +508,7 @@ impl Store for RocksdbStore {
         let cf = self.cf(ColumnFamily::Edges)?;
         let key = format!("{}::{}::{}", source, target, relation);
         self.db
-            .delete_cf(cf, key.as_bytes())
+            .delete_cf_opt(cf, key.as_bytes(), &self.write_options(Durability::Default))
             .map_err(|e| StorageError::Rocksdb(e.to_string()))?;
         Ok(true)
     }
@@ -268,24 +549,45 @@ impl Store for RocksdbStore {
     }
 
     async fn batch_write(&self, ops: Vec<WriteOp>) -> StorageResult<()> {
+        self.batch_write_with_durability(ops, Durability::Default).await
+    }
+
+    async fn batch_write_with_durability(
+        &self,
+        ops: Vec<WriteOp>,
+        durability: Durability,
+    ) -> StorageResult<()> {
         let nodes_cf = self.cf(ColumnFamily::Nodes)?;
         let edges_cf = self.cf(ColumnFamily::Edges)?;
         
         let mut batch = rocksdb::WriteBatch::default();
+        let integrity = self.config.referential_integrity;
+        let mut written_nodes = HashMap::new();
+        let mut edges = Vec::new();
 
         for op in ops {
             match op {
                 WriteOp::PutNode(node) => {
-                    let value = Self::serialize_node(&node)?;
+                    let value = self.serialize_node(&node)?;
+                    self.track_node_id(node.id, &mut batch)?;
                     batch.put_cf(nodes_cf, node.id.as_bytes(), value);
+                    if integrity {
+                        written_nodes.insert(node.id, true);
+                    }
                 }
                 WriteOp::DeleteNode(id) => {
                     batch.delete_cf(nodes_cf, id.as_bytes());
+                    if integrity {
+                        written_nodes.insert(id, false);
+                    }
                 }
                 WriteOp::PutEdge(edge) => {
                     let value = Self::serialize_edge(&edge)?;
                     let key = edge.id();
                     batch.put_cf(edges_cf, key.as_bytes(), value);
+                    if integrity {
+                        edges.push(edge);
+                    }
                 }
                 WriteOp::DeleteEdge(source, target, relation) => {
                     let key = format!("{}::{}::{}", source, target, relation);
@@ -302,8 +604,14 @@ impl Store for RocksdbStore {
             }
         }
 
+        // Checked against the state the whole batch leaves, so edges may
+        // precede the nodes they connect
+        if integrity {
+            self.check_endpoints(&edges, &written_nodes)?;
+        }
+
         self.db
-            .write(batch)
+            .write_opt(batch, &self.write_options(durability))
             .map_err(|e| StorageError::Rocksdb(e.to_string()))
     }
 
@@ -311,23 +619,7 @@ impl Store for RocksdbStore {
         &self,
         filter: Option<crate::store::NodeFilter>,
     ) -> StorageResult<BoxStream<'_, StorageResult<Node>>> {
-        let cf = self.cf(ColumnFamily::Nodes)?;
-        let iter = self.db.iterator_cf(cf, rocksdb::IteratorMode::Start);
-        let mut nodes = Vec::new();
-
-        for item in iter {
-            let (_, bytes) = item.map_err(|e| StorageError::Rocksdb(e.to_string()))?;
-            let node = Self::deserialize_node(&bytes)?;
-
-            if let Some(ref f) = filter {
-                if !f.matches(&node) {
-                    continue;
-                }
-            }
-
-            nodes.push(node);
-        }
-
+        let nodes = self.collect_nodes(ColumnFamily::Nodes, filter.as_ref())?;
         let stream = futures::stream::iter(nodes.into_iter().map(Ok)).boxed();
         Ok(stream)
     }
@@ -342,6 +634,53 @@ impl Store for RocksdbStore {
         Ok(0)
     }
 
+    async fn scan_prefix(
+        &self,
+        cf: ColumnFamily,
+        prefix: &[u8],
+    ) -> StorageResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        let cf = self.cf(cf)?;
+        let iter = self
+            .db
+            .iterator_cf(cf, rocksdb::IteratorMode::From(prefix, rocksdb::Direction::Forward));
+
+        let mut entries = Vec::new();
+        for item in iter {
+            let (key, value) = item.map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+            if !key.starts_with(prefix) {
+                break;
+            }
+            entries.push((key.to_vec(), value.to_vec()));
+        }
+        Ok(entries)
+    }
+
+    async fn dangling_edges(&self) -> StorageResult<Vec<Edge>> {
+        let cf = self.cf(ColumnFamily::Edges)?;
+        let mut present = HashSet::new();
+        let mut missing = HashSet::new();
+        let mut dangling = Vec::new();
+
+        for item in self.db.iterator_cf(cf, rocksdb::IteratorMode::Start) {
+            let (_, bytes) = item.map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+            let edge = Self::deserialize_edge(&bytes)?;
+            for node in [edge.source, edge.target] {
+                if !present.contains(&node) && !missing.contains(&node) {
+                    if self.node_present(node)? {
+                        present.insert(node);
+                    } else {
+                        missing.insert(node);
+                    }
+                }
+            }
+            if missing.contains(&edge.source) || missing.contains(&edge.target) {
+                dangling.push(edge);
+            }
+        }
+
+        Ok(dangling)
+    }
+
     async fn get_metadata(&self, key: &str) -> StorageResult<Option<Vec<u8>>> {
         let cf = self.cf(ColumnFamily::Metadata)?;
         self.db
@@ -352,10 +691,81 @@ impl Store for RocksdbStore {
     async fn put_metadata(&self, key: &str, value: &[u8]) -> StorageResult<()> {
         let cf = self.cf(ColumnFamily::Metadata)?;
         self.db
-            .put_cf(cf, key.as_bytes(), value)
+            .put_cf_opt(cf, key.as_bytes(), value, &self.write_options(Durability::Default))
             .map_err(|e| StorageError::Rocksdb(e.to_string()))
     }
 
+    async fn compact(&self, cf: Option<ColumnFamily>) -> StorageResult<()> {
+        let families = cf.map_or_else(|| ColumnFamily::ALL.to_vec(), |cf| vec![cf]);
+        let db = self.db.clone();
+
+        // Full-range compaction blocks until done, so keep it off the runtime
+        tokio::task::spawn_blocking(move || {
+            for family in families {
+                let handle = db.cf_handle(family.as_str()).ok_or_else(|| {
+                    StorageError::InvalidOperation(format!("Column family {} not found", family))
+                })?;
+                db.compact_range_cf(handle, None::<&[u8]>, None::<&[u8]>);
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|e| StorageError::InvalidOperation(format!("Compaction task failed: {}", e)))?
+    }
+
+    async fn migrate(&self) -> StorageResult<MigrationReport> {
+        let mut report = MigrationReport::new();
+        for family in [ColumnFamily::Nodes, ColumnFamily::Archive] {
+            let (scanned, migrated) = self.migrate_family(family, node_migrations(), |bytes| {
+                self.serialize_node(&Self::deserialize_node(bytes)?)
+            })?;
+            report.nodes_scanned += scanned;
+            report.nodes_migrated += migrated;
+        }
+        let (scanned, migrated) =
+            self.migrate_family(ColumnFamily::Edges, edge_migrations(), |bytes| {
+                Self::serialize_edge(&Self::deserialize_edge(bytes)?)
+            })?;
+        report.edges_scanned = scanned;
+        report.edges_migrated = migrated;
+        Ok(report)
+    }
+
+    async fn storage_stats(&self) -> StorageResult<StorageStats> {
+        let mut column_families = Vec::with_capacity(ColumnFamily::ALL.len());
+        for &family in ColumnFamily::ALL {
+            let cf = self.cf(family)?;
+            column_families.push(ColumnFamilyStats {
+                name: family.as_str().to_string(),
+                estimated_keys: self.cf_property(cf, "rocksdb.estimate-num-keys")?,
+                sst_bytes: self.cf_property(cf, "rocksdb.total-sst-files-size")?,
+                memtable_bytes: self.cf_property(cf, "rocksdb.cur-size-all-mem-tables")?,
+                pending_compaction_bytes: self
+                    .cf_property(cf, "rocksdb.estimate-pending-compaction-bytes")?,
+            });
+        }
+
+        let running_compactions = self
+            .db
+            .property_int_value("rocksdb.num-running-compactions")
+            .map_err(|e| StorageError::Rocksdb(e.to_string()))?
+            .unwrap_or(0);
+
+        let mut compression = CompressionStats::default();
+        for family in [ColumnFamily::Nodes, ColumnFamily::Archive] {
+            for item in self.db.iterator_cf(self.cf(family)?, rocksdb::IteratorMode::Start) {
+                let (_, bytes) = item.map_err(|e| StorageError::Rocksdb(e.to_string()))?;
+                compression.add(&bytes);
+            }
+        }
+
+        Ok(StorageStats {
+            column_families,
+            running_compactions,
+            compression,
+        })
+    }
+
     async fn flush(&self) -> StorageResult<()> {
         self.db
             .flush()
@@ -392,6 +802,103 @@ mod tests {
         assert_eq!(retrieved.node_type, NodeType::Entity);
     }
 
+    #[tokio::test]
+    async fn test_rocksdb_migrate() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+        let node = Node::new("Written before versioning", NodeType::Fact);
+        let edge = Edge::new(node.id, Uuid::new_v4(), Relation::Causes);
+        let nodes_cf = store.cf(ColumnFamily::Nodes).unwrap();
+        let edges_cf = store.cf(ColumnFamily::Edges).unwrap();
+        store
+            .db
+            .put_cf(nodes_cf, node.id.as_bytes(), serde_json::to_vec(&node).unwrap())
+            .unwrap();
+        store
+            .db
+            .put_cf(edges_cf, edge.id().as_bytes(), serde_json::to_vec(&edge).unwrap())
+            .unwrap();
+        store.put_node(&Node::new("Current", NodeType::Fact)).await.unwrap();
+
+        // Version 1 values are migrated on read
+        assert_eq!(store.get_node(node.id).await.unwrap().unwrap(), node);
+        assert_eq!(store.get_outgoing_edges(node.id).await.unwrap(), vec![edge.clone()]);
+
+        let report = store.migrate().await.unwrap();
+        assert_eq!((report.nodes_scanned, report.nodes_migrated), (2, 1));
+        assert_eq!((report.edges_scanned, report.edges_migrated), (1, 1));
+        let bytes = store.db.get_cf(nodes_cf, node.id.as_bytes()).unwrap().unwrap();
+        assert_eq!(bytes[0], node_migrations().current_version());
+        assert_eq!(store.get_node(node.id).await.unwrap().unwrap(), node);
+        assert_eq!(store.migrate().await.unwrap().nodes_migrated, 0);
+    }
+
+    #[tokio::test]
+    async fn test_rocksdb_nodes_created_between() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+        let start = chrono::Utc::now() - chrono::Duration::hours(1);
+        let minutes = |m: i64| start + chrono::Duration::minutes(m);
+        let nodes: Vec<Node> = (0..5)
+            .map(|i| {
+                let mut node = Node::new(format!("Note {}", i), NodeType::Concept);
+                node.meta.created_at = minutes(i * 10);
+                node.id = synton_core::IdFormat::V7.generate(node.meta.created_at);
+                node
+            })
+            .collect();
+        for node in &nodes {
+            store.put_node(node).await.unwrap();
+        }
+        assert!(!store.random_ids.load(Ordering::Relaxed));
+
+        let window = store.nodes_created_between(minutes(5), minutes(20)).await.unwrap();
+        let ids: Vec<Uuid> = window.iter().map(|node| node.id).collect();
+        assert_eq!(ids, vec![nodes[1].id, nodes[2].id]);
+
+        // A random ID can sit anywhere in key order, so windows fall back
+        // to full scans from then on
+        let mut random = Node::new("Random", NodeType::Concept);
+        random.meta.created_at = minutes(15);
+        store.put_node(&random).await.unwrap();
+        let window = store.nodes_created_between(minutes(5), minutes(20)).await.unwrap();
+        assert_eq!(window.len(), 3);
+
+        drop(store);
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+        assert!(store.random_ids.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_rocksdb_write_durability() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = RocksdbConfig {
+            path: temp_dir.path().to_str().unwrap().to_string(),
+            sync: true,
+            ..Default::default()
+        };
+        let store = RocksdbStore::open(config.clone()).unwrap();
+
+        let bulk = Node::new("Bulk", NodeType::Entity);
+        let critical = Node::new("Critical", NodeType::Fact);
+        store
+            .batch_write_with_durability(vec![WriteOp::PutNode(bulk.clone())], Durability::NoWal)
+            .await
+            .unwrap();
+        store
+            .batch_write_with_durability(vec![WriteOp::PutNode(critical.clone())], Durability::Sync)
+            .await
+            .unwrap();
+
+        assert!(store.node_exists(bulk.id).await.unwrap());
+        store.flush().await.unwrap();
+        drop(store);
+
+        let store = RocksdbStore::open(config).unwrap();
+        assert!(store.node_exists(bulk.id).await.unwrap());
+        assert!(store.node_exists(critical.id).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_rocksdb_edge_operations() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -413,6 +920,51 @@ mod tests {
         assert_eq!(retrieved.target, target);
     }
 
+    #[tokio::test]
+    async fn test_rocksdb_scan_prefix() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+        let put = |key: &[u8], value: &[u8]| WriteOp::Put {
+            cf: ColumnFamily::AccessLog,
+            key: key.to_vec(),
+            value: value.to_vec(),
+        };
+        store
+            .batch_write(vec![put(b"b2", b"3"), put(b"a1", b"1"), put(b"b1", b"2"), put(b"c", b"4")])
+            .await
+            .unwrap();
+
+        let entries = store.scan_prefix(ColumnFamily::AccessLog, b"b").await.unwrap();
+        assert_eq!(
+            entries,
+            vec![(b"b1".to_vec(), b"2".to_vec()), (b"b2".to_vec(), b"3".to_vec())]
+        );
+        assert_eq!(store.scan_prefix(ColumnFamily::AccessLog, b"").await.unwrap().len(), 4);
+        assert!(store.scan_prefix(ColumnFamily::AccessLog, b"d").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rocksdb_nodes_with_tag() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let put = |tag: &str, id: Uuid| WriteOp::Put {
+            cf: ColumnFamily::Tags,
+            key: crate::tag_index_key(tag, id),
+            value: Vec::new(),
+        };
+        store
+            .batch_write(vec![put("db", a), put("db", b), put("dba", a)])
+            .await
+            .unwrap();
+
+        let mut expected = vec![a, b];
+        expected.sort();
+        assert_eq!(store.nodes_with_tag("db").await.unwrap(), expected);
+        assert_eq!(store.nodes_with_tag("dba").await.unwrap(), vec![a]);
+        assert!(store.nodes_with_tag("d").await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_rocksdb_metadata() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -423,4 +975,62 @@ mod tests {
         let value = store.get_metadata("test_key").await.unwrap();
         assert_eq!(value, Some(b"test_value".to_vec()));
     }
+
+    #[tokio::test]
+    async fn test_rocksdb_compaction_and_stats() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+
+        let nodes: Vec<_> = (0..100)
+            .map(|i| Node::new(format!("Node {}", i), NodeType::Fact))
+            .collect();
+        for node in &nodes {
+            store.put_node(node).await.unwrap();
+        }
+        store.flush().await.unwrap();
+        for node in &nodes[..50] {
+            store.delete_node(node.id).await.unwrap();
+        }
+
+        store.compact(Some(ColumnFamily::Nodes)).await.unwrap();
+        store.compact(None).await.unwrap();
+
+        let stats = store.storage_stats().await.unwrap();
+        assert_eq!(stats.column_families.len(), ColumnFamily::ALL.len());
+        let node_stats = &stats.column_families[0];
+        assert_eq!(node_stats.name, "nodes");
+        assert!(node_stats.sst_bytes > 0);
+        assert!(node_stats.estimated_keys <= 100);
+        assert_eq!(stats.sst_bytes(), stats.column_families.iter().map(|cf| cf.sst_bytes).sum());
+    }
+
+    #[tokio::test]
+    async fn test_rocksdb_archive_restore() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = RocksdbStore::open_path(temp_dir.path()).unwrap();
+
+        let node = Node::new("Cold memory", NodeType::Fact).with_embedding(vec![0.5; 4]);
+        let id = node.id;
+        store.put_node(&node).await.unwrap();
+
+        assert!(store.archive_node(id).await.unwrap());
+        assert!(!store.node_exists(id).await.unwrap());
+        assert!(!store.archive_node(id).await.unwrap());
+
+        let archived = store.get_archived_node(id).await.unwrap().unwrap();
+        assert!(!archived.has_embedding());
+        let scanned: Vec<_> = store
+            .scan_archived_nodes(None)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(scanned.len(), 1);
+
+        let restored = store.restore_node(id).await.unwrap().unwrap();
+        assert_eq!(restored.content(), "Cold memory");
+        assert!(store.node_exists(id).await.unwrap());
+        assert!(store.get_archived_node(id).await.unwrap().is_none());
+        assert!(store.restore_node(id).await.unwrap().is_none());
+    }
 }
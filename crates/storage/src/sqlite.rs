@@ -0,0 +1,903 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! SQLite implementation of the Store trait.
+//!
+//! Keeps the whole database in a single file, which suits desktop and
+//! MCP-only installs where RocksDB is more than needed. Nodes and edges are
+//! stored as JSON, optionally compressed, like the RocksDB backend, so
+//! records are portable between the two.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use rusqlite::{params, Connection, OptionalExtension};
+use uuid::Uuid;
+
+use crate::compression::{decode_node, encode_node};
+use crate::migration::{
+    decode_edge, edge_migrations, encode_edge, node_migrations, MigrationRegistry, MigrationReport,
+};
+use crate::store::NodeFilter;
+use crate::{
+    ColumnFamily, CompressionStats, ContentCompression, StorageError, StorageResult, StorageStats,
+    Store, WriteOp,
+};
+use synton_core::{Edge, Node};
+
+/// Schema of the single-file database.
+///
+/// Raw writes to column families without a dedicated table go to `kv`.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS nodes (
+        id BLOB PRIMARY KEY,
+        data BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS archive (
+        id BLOB PRIMARY KEY,
+        data BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS edges (
+        source BLOB NOT NULL,
+        target BLOB NOT NULL,
+        relation TEXT NOT NULL,
+        data BLOB NOT NULL,
+        PRIMARY KEY (source, target, relation)
+    );
+    CREATE INDEX IF NOT EXISTS edges_target ON edges (target);
+    CREATE TABLE IF NOT EXISTS metadata (
+        key TEXT PRIMARY KEY,
+        value BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS kv (
+        cf TEXT NOT NULL,
+        key BLOB NOT NULL,
+        value BLOB NOT NULL,
+        PRIMARY KEY (cf, key)
+    );
+";
+
+/// SQLite configuration.
+#[derive(Debug, Clone)]
+pub struct SqliteConfig {
+    /// Path to the database file.
+    pub path: String,
+    /// Use write-ahead logging for better read/write concurrency.
+    pub wal_enabled: bool,
+    /// How long to wait for a locked database (milliseconds).
+    pub busy_timeout_ms: u64,
+    /// Page cache size (in MB).
+    pub cache_size_mb: usize,
+    /// Reject edges whose endpoints are neither stored nor archived.
+    pub referential_integrity: bool,
+    /// Compress nodes with large content; `None` stores every node as JSON.
+    pub content_compression: Option<ContentCompression>,
+}
+
+impl Default for SqliteConfig {
+    fn default() -> Self {
+        Self {
+            path: "./data/synton.db".to_string(),
+            wal_enabled: true,
+            busy_timeout_ms: 5000,
+            cache_size_mb: 64,
+            referential_integrity: false,
+            content_compression: None,
+        }
+    }
+}
+
+/// SQLite implementation of the Store trait.
+pub struct SqliteStore {
+    conn: Arc<Mutex<Connection>>,
+    config: SqliteConfig,
+}
+
+impl SqliteStore {
+    /// Open (or create) the database described by `config`.
+    pub fn open(config: SqliteConfig) -> StorageResult<Self> {
+        let conn = Connection::open(&config.path).map_err(sqlite_err)?;
+        Self::init(conn, config)
+    }
+
+    /// Open (or create) a database file with default settings.
+    pub fn open_path<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
+        let config = SqliteConfig {
+            path: path.as_ref().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        Self::open(config)
+    }
+
+    /// Open a private in-memory database.
+    pub fn open_in_memory() -> StorageResult<Self> {
+        let config = SqliteConfig {
+            path: ":memory:".to_string(),
+            wal_enabled: false,
+            ..Default::default()
+        };
+        let conn = Connection::open_in_memory().map_err(sqlite_err)?;
+        Self::init(conn, config)
+    }
+
+    fn init(conn: Connection, config: SqliteConfig) -> StorageResult<Self> {
+        conn.busy_timeout(std::time::Duration::from_millis(config.busy_timeout_ms))
+            .map_err(sqlite_err)?;
+        if config.wal_enabled {
+            conn.pragma_update(None, "journal_mode", "WAL")
+                .map_err(sqlite_err)?;
+            conn.pragma_update(None, "synchronous", "NORMAL")
+                .map_err(sqlite_err)?;
+        }
+        // Negative values are in KiB
+        conn.pragma_update(None, "cache_size", -((config.cache_size_mb * 1024) as i64))
+            .map_err(sqlite_err)?;
+        conn.execute_batch(SCHEMA).map_err(sqlite_err)?;
+
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+            config,
+        })
+    }
+
+    /// Path of the database file.
+    pub fn path(&self) -> &str {
+        &self.config.path
+    }
+
+    fn conn(&self) -> StorageResult<MutexGuard<'_, Connection>> {
+        self.conn
+            .lock()
+            .map_err(|_| StorageError::Sqlite("connection mutex poisoned".to_string()))
+    }
+
+    fn serialize_node(&self, node: &Node) -> StorageResult<Vec<u8>> {
+        encode_node(node, self.config.content_compression.as_ref())
+    }
+
+    fn deserialize_node(bytes: &[u8]) -> StorageResult<Node> {
+        decode_node(bytes)
+    }
+
+    fn serialize_edge(edge: &Edge) -> StorageResult<Vec<u8>> {
+        encode_edge(edge)
+    }
+
+    fn deserialize_edge(bytes: &[u8]) -> StorageResult<Edge> {
+        decode_edge(bytes)
+    }
+
+    /// Rewrite the rows of a table whose `data` is stored in an older
+    /// version of `registry`'s format, returning how many were read and
+    /// rewritten.
+    fn migrate_table(
+        conn: &Connection,
+        table: &str,
+        registry: &MigrationRegistry,
+        reencode: impl Fn(&[u8]) -> StorageResult<Vec<u8>>,
+    ) -> StorageResult<(u64, u64)> {
+        let rows = {
+            let mut stmt = conn
+                .prepare(&format!("SELECT rowid, data FROM {}", table))
+                .map_err(sqlite_err)?;
+            let rows = stmt
+                .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?)))
+                .map_err(sqlite_err)?;
+            rows.collect::<Result<Vec<_>, _>>().map_err(sqlite_err)?
+        };
+        let mut migrated = 0;
+        for (rowid, bytes) in &rows {
+            if registry.split(bytes)?.0 < registry.current_version() {
+                conn.execute(
+                    &format!("UPDATE {} SET data = ?1 WHERE rowid = ?2", table),
+                    params![reencode(bytes)?, rowid],
+                )
+                .map_err(sqlite_err)?;
+                migrated += 1;
+            }
+        }
+        Ok((rows.len() as u64, migrated))
+    }
+
+    fn get_node_from(conn: &Connection, table: &str, id: Uuid) -> StorageResult<Option<Node>> {
+        let bytes: Option<Vec<u8>> = conn
+            .query_row(
+                &format!("SELECT data FROM {} WHERE id = ?1", table),
+                params![id.as_bytes().as_slice()],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sqlite_err)?;
+        bytes.map(|b| Self::deserialize_node(&b)).transpose()
+    }
+
+    /// Read and filter every node stored in a table.
+    fn collect_nodes(&self, table: &str, filter: Option<&NodeFilter>) -> StorageResult<Vec<Node>> {
+        let conn = self.conn()?;
+        let mut stmt = conn
+            .prepare(&format!("SELECT data FROM {}", table))
+            .map_err(sqlite_err)?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, Vec<u8>>(0))
+            .map_err(sqlite_err)?;
+
+        let mut nodes = Vec::new();
+        for bytes in rows {
+            let node = Self::deserialize_node(&bytes.map_err(sqlite_err)?)?;
+            if filter.map_or(true, |f| f.matches(&node)) {
+                nodes.push(node);
+            }
+        }
+        Ok(nodes)
+    }
+
+    fn collect_edges(&self, column: &str, id: Uuid) -> StorageResult<Vec<Edge>> {
+        let conn = self.conn()?;
+        let mut stmt = conn
+            .prepare(&format!("SELECT data FROM edges WHERE {} = ?1", column))
+            .map_err(sqlite_err)?;
+        let rows = stmt
+            .query_map(params![id.as_bytes().as_slice()], |row| row.get::<_, Vec<u8>>(0))
+            .map_err(sqlite_err)?;
+
+        let mut edges = Vec::new();
+        for bytes in rows {
+            if let Ok(edge) = Self::deserialize_edge(&bytes.map_err(sqlite_err)?) {
+                edges.push(edge);
+            }
+        }
+        Ok(edges)
+    }
+
+    fn count(&self, table: &str) -> StorageResult<usize> {
+        let conn = self.conn()?;
+        let count: i64 = conn
+            .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))
+            .map_err(sqlite_err)?;
+        Ok(count as usize)
+    }
+
+    /// Check that an edge's endpoints are stored or archived.
+    fn check_endpoints(conn: &Connection, edge: &Edge) -> StorageResult<()> {
+        for node in [edge.source, edge.target] {
+            let present: bool = conn
+                .query_row(
+                    "SELECT EXISTS(SELECT 1 FROM nodes WHERE id = ?1)
+                        OR EXISTS(SELECT 1 FROM archive WHERE id = ?1)",
+                    params![node.as_bytes().as_slice()],
+                    |row| row.get(0),
+                )
+                .map_err(sqlite_err)?;
+            if !present {
+                return Err(StorageError::DanglingEdge { edge: edge.id(), node });
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply one write operation inside an open transaction.
+    fn apply(&self, tx: &rusqlite::Transaction<'_>, op: WriteOp) -> StorageResult<()> {
+        match op {
+            WriteOp::PutNode(node) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO nodes (id, data) VALUES (?1, ?2)",
+                    params![node.id.as_bytes().as_slice(), self.serialize_node(&node)?],
+                )
+                .map_err(sqlite_err)?;
+            }
+            WriteOp::DeleteNode(id) => {
+                tx.execute("DELETE FROM nodes WHERE id = ?1", params![id.as_bytes().as_slice()])
+                    .map_err(sqlite_err)?;
+            }
+            WriteOp::PutEdge(edge) => {
+                tx.execute(
+                    "INSERT OR REPLACE INTO edges (source, target, relation, data) VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        edge.source.as_bytes().as_slice(),
+                        edge.target.as_bytes().as_slice(),
+                        edge.relation.to_string(),
+                        Self::serialize_edge(&edge)?,
+                    ],
+                )
+                .map_err(sqlite_err)?;
+            }
+            WriteOp::DeleteEdge(source, target, relation) => {
+                tx.execute(
+                    "DELETE FROM edges WHERE source = ?1 AND target = ?2 AND relation = ?3",
+                    params![source.as_bytes().as_slice(), target.as_bytes().as_slice(), relation],
+                )
+                .map_err(sqlite_err)?;
+            }
+            WriteOp::Put { cf, key, value } => match cf {
+                ColumnFamily::Nodes | ColumnFamily::Archive => {
+                    tx.execute(
+                        &format!("INSERT OR REPLACE INTO {} (id, data) VALUES (?1, ?2)", cf),
+                        params![key, value],
+                    )
+                    .map_err(sqlite_err)?;
+                }
+                ColumnFamily::Metadata => {
+                    tx.execute(
+                        "INSERT OR REPLACE INTO metadata (key, value) VALUES (?1, ?2)",
+                        params![String::from_utf8_lossy(&key), value],
+                    )
+                    .map_err(sqlite_err)?;
+                }
+                _ => {
+                    tx.execute(
+                        "INSERT OR REPLACE INTO kv (cf, key, value) VALUES (?1, ?2, ?3)",
+                        params![cf.as_str(), key, value],
+                    )
+                    .map_err(sqlite_err)?;
+                }
+            },
+            WriteOp::Delete { cf, key } => match cf {
+                ColumnFamily::Nodes | ColumnFamily::Archive => {
+                    tx.execute(&format!("DELETE FROM {} WHERE id = ?1", cf), params![key])
+                        .map_err(sqlite_err)?;
+                }
+                ColumnFamily::Metadata => {
+                    tx.execute(
+                        "DELETE FROM metadata WHERE key = ?1",
+                        params![String::from_utf8_lossy(&key)],
+                    )
+                    .map_err(sqlite_err)?;
+                }
+                _ => {
+                    tx.execute(
+                        "DELETE FROM kv WHERE cf = ?1 AND key = ?2",
+                        params![cf.as_str(), key],
+                    )
+                    .map_err(sqlite_err)?;
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+fn sqlite_err(e: rusqlite::Error) -> StorageError {
+    StorageError::Sqlite(e.to_string())
+}
+
+#[async_trait]
+impl Store for SqliteStore {
+    async fn get_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        let conn = self.conn()?;
+        Self::get_node_from(&conn, "nodes", id)
+    }
+
+    async fn put_node(&self, node: &Node) -> StorageResult<()> {
+        self.batch_write(vec![WriteOp::PutNode(node.clone())]).await
+    }
+
+    async fn delete_node(&self, id: Uuid) -> StorageResult<bool> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction().map_err(sqlite_err)?;
+        let key = id.as_bytes().as_slice();
+        let removed = tx
+            .execute("DELETE FROM nodes WHERE id = ?1", params![key])
+            .map_err(sqlite_err)?
+            + tx
+                .execute("DELETE FROM archive WHERE id = ?1", params![key])
+                .map_err(sqlite_err)?;
+        tx.commit().map_err(sqlite_err)?;
+        Ok(removed > 0)
+    }
+
+    async fn node_exists(&self, id: Uuid) -> StorageResult<bool> {
+        let conn = self.conn()?;
+        conn.query_row(
+            "SELECT 1 FROM nodes WHERE id = ?1",
+            params![id.as_bytes().as_slice()],
+            |_| Ok(()),
+        )
+        .optional()
+        .map(|row| row.is_some())
+        .map_err(sqlite_err)
+    }
+
+    async fn archive_node(&self, id: Uuid) -> StorageResult<bool> {
+        let mut conn = self.conn()?;
+        let Some(mut node) = Self::get_node_from(&conn, "nodes", id)? else {
+            return Ok(false);
+        };
+        node.embedding = None;
+
+        let tx = conn.transaction().map_err(sqlite_err)?;
+        tx.execute(
+            "INSERT OR REPLACE INTO archive (id, data) VALUES (?1, ?2)",
+            params![id.as_bytes().as_slice(), self.serialize_node(&node)?],
+        )
+        .map_err(sqlite_err)?;
+        tx.execute("DELETE FROM nodes WHERE id = ?1", params![id.as_bytes().as_slice()])
+            .map_err(sqlite_err)?;
+        tx.commit().map_err(sqlite_err)?;
+        Ok(true)
+    }
+
+    async fn restore_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        let mut conn = self.conn()?;
+        let Some(node) = Self::get_node_from(&conn, "archive", id)? else {
+            return Ok(None);
+        };
+
+        let tx = conn.transaction().map_err(sqlite_err)?;
+        tx.execute(
+            "INSERT OR REPLACE INTO nodes (id, data) VALUES (?1, ?2)",
+            params![id.as_bytes().as_slice(), self.serialize_node(&node)?],
+        )
+        .map_err(sqlite_err)?;
+        tx.execute("DELETE FROM archive WHERE id = ?1", params![id.as_bytes().as_slice()])
+            .map_err(sqlite_err)?;
+        tx.commit().map_err(sqlite_err)?;
+        Ok(Some(node))
+    }
+
+    async fn get_archived_node(&self, id: Uuid) -> StorageResult<Option<Node>> {
+        let conn = self.conn()?;
+        Self::get_node_from(&conn, "archive", id)
+    }
+
+    async fn scan_archived_nodes(
+        &self,
+        filter: Option<NodeFilter>,
+    ) -> StorageResult<BoxStream<'_, StorageResult<Node>>> {
+        let nodes = self.collect_nodes("archive", filter.as_ref())?;
+        Ok(futures::stream::iter(nodes.into_iter().map(Ok)).boxed())
+    }
+
+    async fn get_edge(
+        &self,
+        source: Uuid,
+        target: Uuid,
+        relation: &str,
+    ) -> StorageResult<Option<Edge>> {
+        let conn = self.conn()?;
+        let bytes: Option<Vec<u8>> = conn
+            .query_row(
+                "SELECT data FROM edges WHERE source = ?1 AND target = ?2 AND relation = ?3",
+                params![source.as_bytes().as_slice(), target.as_bytes().as_slice(), relation],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(sqlite_err)?;
+        bytes.map(|b| Self::deserialize_edge(&b)).transpose()
+    }
+
+    async fn put_edge(&self, edge: &Edge) -> StorageResult<()> {
+        self.batch_write(vec![WriteOp::PutEdge(edge.clone())]).await
+    }
+
+    async fn delete_edge(&self, source: Uuid, target: Uuid, relation: &str) -> StorageResult<bool> {
+        let conn = self.conn()?;
+        let removed = conn
+            .execute(
+                "DELETE FROM edges WHERE source = ?1 AND target = ?2 AND relation = ?3",
+                params![source.as_bytes().as_slice(), target.as_bytes().as_slice(), relation],
+            )
+            .map_err(sqlite_err)?;
+        Ok(removed > 0)
+    }
+
+    async fn get_outgoing_edges(&self, source: Uuid) -> StorageResult<Vec<Edge>> {
+        self.collect_edges("source", source)
+    }
+
+    async fn get_incoming_edges(&self, target: Uuid) -> StorageResult<Vec<Edge>> {
+        self.collect_edges("target", target)
+    }
+
+    async fn batch_write(&self, ops: Vec<WriteOp>) -> StorageResult<()> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction().map_err(sqlite_err)?;
+        let mut edges = Vec::new();
+        for op in ops {
+            if let (WriteOp::PutEdge(edge), true) = (&op, self.config.referential_integrity) {
+                edges.push(edge.clone());
+            }
+            self.apply(&tx, op)?;
+        }
+        // Checked once the whole batch is applied, so edges may precede the
+        // nodes they connect; failing drops the transaction
+        for edge in &edges {
+            Self::check_endpoints(&tx, edge)?;
+        }
+        tx.commit().map_err(sqlite_err)
+    }
+
+    async fn scan_nodes(
+        &self,
+        filter: Option<NodeFilter>,
+    ) -> StorageResult<BoxStream<'_, StorageResult<Node>>> {
+        let nodes = self.collect_nodes("nodes", filter.as_ref())?;
+        Ok(futures::stream::iter(nodes.into_iter().map(Ok)).boxed())
+    }
+
+    async fn count_nodes(&self) -> StorageResult<usize> {
+        self.count("nodes")
+    }
+
+    async fn count_edges(&self) -> StorageResult<usize> {
+        self.count("edges")
+    }
+
+    async fn scan_prefix(
+        &self,
+        cf: ColumnFamily,
+        prefix: &[u8],
+    ) -> StorageResult<Vec<(Vec<u8>, Vec<u8>)>> {
+        let conn = self.conn()?;
+        let mut entries: Vec<(Vec<u8>, Vec<u8>)> = match cf {
+            ColumnFamily::Nodes | ColumnFamily::Archive => {
+                let mut stmt = conn
+                    .prepare(&format!("SELECT id, data FROM {} WHERE id >= ?1", cf))
+                    .map_err(sqlite_err)?;
+                let rows = stmt
+                    .query_map(params![prefix], |row| Ok((row.get(0)?, row.get(1)?)))
+                    .map_err(sqlite_err)?;
+                rows.collect::<Result<_, _>>().map_err(sqlite_err)?
+            }
+            ColumnFamily::Metadata => {
+                let mut stmt = conn
+                    .prepare("SELECT key, value FROM metadata")
+                    .map_err(sqlite_err)?;
+                let rows = stmt
+                    .query_map([], |row| Ok((row.get::<_, String>(0)?.into_bytes(), row.get(1)?)))
+                    .map_err(sqlite_err)?;
+                rows.collect::<Result<_, _>>().map_err(sqlite_err)?
+            }
+            _ => {
+                let mut stmt = conn
+                    .prepare("SELECT key, value FROM kv WHERE cf = ?1 AND key >= ?2")
+                    .map_err(sqlite_err)?;
+                let rows = stmt
+                    .query_map(params![cf.as_str(), prefix], |row| Ok((row.get(0)?, row.get(1)?)))
+                    .map_err(sqlite_err)?;
+                rows.collect::<Result<_, _>>().map_err(sqlite_err)?
+            }
+        };
+
+        entries.retain(|(key, _)| key.starts_with(prefix));
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(entries)
+    }
+
+    async fn dangling_edges(&self) -> StorageResult<Vec<Edge>> {
+        let conn = self.conn()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT data FROM edges
+                 WHERE (NOT EXISTS (SELECT 1 FROM nodes WHERE id = edges.source)
+                        AND NOT EXISTS (SELECT 1 FROM archive WHERE id = edges.source))
+                    OR (NOT EXISTS (SELECT 1 FROM nodes WHERE id = edges.target)
+                        AND NOT EXISTS (SELECT 1 FROM archive WHERE id = edges.target))",
+            )
+            .map_err(sqlite_err)?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, Vec<u8>>(0))
+            .map_err(sqlite_err)?;
+
+        let mut edges = Vec::new();
+        for bytes in rows {
+            edges.push(Self::deserialize_edge(&bytes.map_err(sqlite_err)?)?);
+        }
+        Ok(edges)
+    }
+
+    async fn get_metadata(&self, key: &str) -> StorageResult<Option<Vec<u8>>> {
+        let conn = self.conn()?;
+        conn.query_row(
+            "SELECT value FROM metadata WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(sqlite_err)
+    }
+
+    async fn put_metadata(&self, key: &str, value: &[u8]) -> StorageResult<()> {
+        self.batch_write(vec![WriteOp::Put {
+            cf: ColumnFamily::Metadata,
+            key: key.as_bytes().to_vec(),
+            value: value.to_vec(),
+        }])
+        .await
+    }
+
+    async fn compact(&self, _cf: Option<ColumnFamily>) -> StorageResult<()> {
+        // SQLite cannot compact single tables; VACUUM rebuilds the whole file
+        let conn = self.conn()?;
+        conn.execute_batch("VACUUM").map_err(sqlite_err)
+    }
+
+    async fn migrate(&self) -> StorageResult<MigrationReport> {
+        let mut conn = self.conn()?;
+        let tx = conn.transaction().map_err(sqlite_err)?;
+        let mut report = MigrationReport::new();
+        for table in ["nodes", "archive"] {
+            let (scanned, migrated) =
+                Self::migrate_table(&tx, table, node_migrations(), |bytes| {
+                    self.serialize_node(&Self::deserialize_node(bytes)?)
+                })?;
+            report.nodes_scanned += scanned;
+            report.nodes_migrated += migrated;
+        }
+        let (scanned, migrated) = Self::migrate_table(&tx, "edges", edge_migrations(), |bytes| {
+            Self::serialize_edge(&Self::deserialize_edge(bytes)?)
+        })?;
+        report.edges_scanned = scanned;
+        report.edges_migrated = migrated;
+        tx.commit().map_err(sqlite_err)?;
+        Ok(report)
+    }
+
+    async fn flush(&self) -> StorageResult<()> {
+        if !self.config.wal_enabled {
+            return Ok(());
+        }
+        let conn = self.conn()?;
+        conn.query_row("PRAGMA wal_checkpoint(PASSIVE)", [], |_| Ok(()))
+            .map_err(sqlite_err)
+    }
+
+    async fn storage_stats(&self) -> StorageResult<StorageStats> {
+        let conn = self.conn()?;
+        let mut compression = CompressionStats::default();
+        for table in ["nodes", "archive"] {
+            let mut stmt = conn
+                .prepare(&format!("SELECT data FROM {}", table))
+                .map_err(sqlite_err)?;
+            let rows = stmt
+                .query_map([], |row| row.get::<_, Vec<u8>>(0))
+                .map_err(sqlite_err)?;
+            for bytes in rows {
+                compression.add(&bytes.map_err(sqlite_err)?);
+            }
+        }
+        Ok(StorageStats {
+            compression,
+            ..Default::default()
+        })
+    }
+
+    fn is_closed(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use synton_core::{NodeType, Relation};
+
+    #[tokio::test]
+    async fn test_sqlite_store_basic() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("synton.db");
+
+        let node = Node::new("Test content", NodeType::Entity);
+        let id = node.id;
+        {
+            let store = SqliteStore::open_path(&path).unwrap();
+            store.put_node(&node).await.unwrap();
+            store.put_metadata("schema", b"1").await.unwrap();
+            store.flush().await.unwrap();
+        }
+
+        // Data survives reopening the file
+        let store = SqliteStore::open_path(&path).unwrap();
+        let retrieved = store.get_node(id).await.unwrap().unwrap();
+        assert_eq!(retrieved.content(), "Test content");
+        assert_eq!(store.count_nodes().await.unwrap(), 1);
+        assert_eq!(store.get_metadata("schema").await.unwrap(), Some(b"1".to_vec()));
+
+        assert!(store.delete_node(id).await.unwrap());
+        assert!(!store.delete_node(id).await.unwrap());
+        assert!(!store.node_exists(id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_content_compression() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("synton.db");
+
+        let small = Node::new("Short fact", NodeType::Fact);
+        let large = Node::new("Lorem ipsum dolor sit amet. ".repeat(1000), NodeType::RawChunk);
+        {
+            let store = SqliteStore::open(SqliteConfig {
+                path: path.to_string_lossy().into_owned(),
+                content_compression: Some(ContentCompression::default()),
+                ..Default::default()
+            })
+            .unwrap();
+            store.put_node(&small).await.unwrap();
+            store.put_node(&large).await.unwrap();
+            assert!(store.archive_node(large.id).await.unwrap());
+
+            let stats = store.storage_stats().await.unwrap().compression;
+            assert_eq!(stats.compressed_nodes, 1);
+            assert!(stats.compressed_bytes < stats.uncompressed_bytes);
+        }
+
+        // Compressed nodes stay readable with compression disabled
+        let store = SqliteStore::open_path(&path).unwrap();
+        let restored = store.restore_node(large.id).await.unwrap().unwrap();
+        assert_eq!(restored.content(), large.content());
+        assert_eq!(store.get_node(small.id).await.unwrap().unwrap().content(), "Short fact");
+        assert_eq!(store.storage_stats().await.unwrap().compression.compressed_nodes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_migrate() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let node = Node::new("Written before versioning", NodeType::Fact);
+        let edge = Edge::new(node.id, Uuid::new_v4(), Relation::Causes);
+        {
+            let conn = store.conn().unwrap();
+            conn.execute(
+                "INSERT INTO nodes (id, data) VALUES (?1, ?2)",
+                params![node.id.as_bytes().to_vec(), serde_json::to_vec(&node).unwrap()],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO edges (source, target, relation, data) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    edge.source.as_bytes().to_vec(),
+                    edge.target.as_bytes().to_vec(),
+                    edge.relation.to_string(),
+                    serde_json::to_vec(&edge).unwrap()
+                ],
+            )
+            .unwrap();
+        }
+        store.put_node(&Node::new("Current", NodeType::Fact)).await.unwrap();
+
+        // Version 1 values are migrated on read
+        assert_eq!(store.get_node(node.id).await.unwrap().unwrap(), node);
+        assert_eq!(store.get_outgoing_edges(node.id).await.unwrap(), vec![edge.clone()]);
+
+        let report = store.migrate().await.unwrap();
+        assert_eq!(report.node_version, 2);
+        assert_eq!((report.nodes_scanned, report.nodes_migrated), (2, 1));
+        assert_eq!((report.edges_scanned, report.edges_migrated), (1, 1));
+        assert_eq!(store.get_node(node.id).await.unwrap().unwrap(), node);
+        assert_eq!(store.migrate().await.unwrap().nodes_migrated, 0);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_edge_operations() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        store
+            .batch_write(vec![
+                WriteOp::PutEdge(Edge::new(a, b, Relation::Causes)),
+                WriteOp::PutEdge(Edge::new(c, b, Relation::IsPartOf)),
+                WriteOp::Put {
+                    cf: ColumnFamily::AccessLog,
+                    key: a.as_bytes().to_vec(),
+                    value: b"1".to_vec(),
+                },
+            ])
+            .await
+            .unwrap();
+
+        let edge = store.get_edge(a, b, "causes").await.unwrap().unwrap();
+        assert_eq!(edge.target, b);
+        assert_eq!(store.get_outgoing_edges(a).await.unwrap().len(), 1);
+        assert_eq!(store.get_incoming_edges(b).await.unwrap().len(), 2);
+        assert_eq!(store.count_edges().await.unwrap(), 2);
+
+        assert!(store.delete_edge(a, b, "causes").await.unwrap());
+        assert!(store.get_edge(a, b, "causes").await.unwrap().is_none());
+        assert!(!store.delete_edge(a, b, "causes").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_referential_integrity() {
+        let config = SqliteConfig {
+            path: ":memory:".to_string(),
+            wal_enabled: false,
+            referential_integrity: true,
+            ..Default::default()
+        };
+        let store = SqliteStore::init(Connection::open_in_memory().unwrap(), config).unwrap();
+        let (a, b) = (Node::new("a", NodeType::Entity), Node::new("b", NodeType::Entity));
+
+        // Edges may precede their nodes within a batch
+        store
+            .batch_write(vec![
+                WriteOp::PutEdge(Edge::new(a.id, b.id, Relation::Causes)),
+                WriteOp::PutNode(a.clone()),
+                WriteOp::PutNode(b.clone()),
+            ])
+            .await
+            .unwrap();
+
+        // A missing endpoint rejects the whole batch
+        let missing = Uuid::new_v4();
+        let result = store
+            .batch_write(vec![
+                WriteOp::PutEdge(Edge::new(a.id, b.id, Relation::IsPartOf)),
+                WriteOp::PutEdge(Edge::new(a.id, missing, Relation::Causes)),
+            ])
+            .await;
+        assert!(matches!(result, Err(StorageError::DanglingEdge { node, .. }) if node == missing));
+        assert!(store.get_edge(a.id, b.id, "is_part_of").await.unwrap().is_none());
+
+        // Archived nodes are still valid endpoints
+        store.archive_node(b.id).await.unwrap();
+        store.put_edge(&Edge::new(a.id, b.id, Relation::IsPartOf)).await.unwrap();
+        assert!(store.put_edge(&Edge::new(missing, a.id, Relation::Causes)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_dangling_edges() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let (a, b) = (Node::new("a", NodeType::Entity), Node::new("b", NodeType::Entity));
+        store.put_node(&a).await.unwrap();
+        store.put_node(&b).await.unwrap();
+        store.put_edge(&Edge::new(a.id, b.id, Relation::Causes)).await.unwrap();
+        store.put_edge(&Edge::new(a.id, Uuid::new_v4(), Relation::Causes)).await.unwrap();
+        store.put_edge(&Edge::new(Uuid::new_v4(), b.id, Relation::Causes)).await.unwrap();
+
+        assert_eq!(store.dangling_edges().await.unwrap().len(), 2);
+        assert_eq!(store.remove_dangling_edges().await.unwrap().len(), 2);
+        assert!(store.dangling_edges().await.unwrap().is_empty());
+        assert_eq!(store.count_edges().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_scan_prefix() {
+        let store = SqliteStore::open_in_memory().unwrap();
+        let put = |key: &[u8], value: &[u8]| WriteOp::Put {
+            cf: ColumnFamily::AccessLog,
+            key: key.to_vec(),
+            value: value.to_vec(),
+        };
+        store
+            .batch_write(vec![put(b"b2", b"3"), put(b"a1", b"1"), put(b"b1", b"2"), put(b"c", b"4")])
+            .await
+            .unwrap();
+
+        let entries = store.scan_prefix(ColumnFamily::AccessLog, b"b").await.unwrap();
+        assert_eq!(
+            entries,
+            vec![(b"b1".to_vec(), b"2".to_vec()), (b"b2".to_vec(), b"3".to_vec())]
+        );
+        assert_eq!(store.scan_prefix(ColumnFamily::AccessLog, b"").await.unwrap().len(), 4);
+        assert!(store.scan_prefix(ColumnFamily::AccessLog, b"d").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_archive_restore() {
+        let store = SqliteStore::open_in_memory().unwrap();
+
+        let node = Node::new("Cold memory", NodeType::Fact).with_embedding(vec![0.5; 4]);
+        let id = node.id;
+        store.put_node(&node).await.unwrap();
+
+        assert!(store.archive_node(id).await.unwrap());
+        assert!(!store.node_exists(id).await.unwrap());
+        assert!(!store.archive_node(id).await.unwrap());
+
+        let archived = store.get_archived_node(id).await.unwrap().unwrap();
+        assert!(!archived.has_embedding());
+        let scanned: Vec<_> = store
+            .scan_archived_nodes(None)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(scanned.len(), 1);
+
+        let restored = store.restore_node(id).await.unwrap().unwrap();
+        assert_eq!(restored.content(), "Cold memory");
+        assert!(store.node_exists(id).await.unwrap());
+        assert!(store.restore_node(id).await.unwrap().is_none());
+    }
+}
@@ -4,9 +4,11 @@
 
 use async_trait::async_trait;
 use futures::stream::BoxStream;
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::StorageResult;
+use crate::{CompressionStats, MigrationReport, StorageResult};
 use synton_core::{Edge, Filter, Node};
 
 /// Column family names for RocksDB storage.
@@ -24,6 +26,14 @@ pub enum ColumnFamily {
     Metadata,
     /// Access log for memory decay
     AccessLog,
+    /// Cold tier for archived (decayed) nodes, stored without embeddings
+    Archive,
+    /// Tag index (tag -> node IDs)
+    Tags,
+    /// Audit trail of mutations, in time order
+    Audit,
+    /// Files attached to nodes (node ID + attachment ID -> attachment)
+    Attachments,
 }
 
 impl ColumnFamily {
@@ -35,6 +45,10 @@ impl ColumnFamily {
         Self::EdgesIn,
         Self::Metadata,
         Self::AccessLog,
+        Self::Archive,
+        Self::Tags,
+        Self::Audit,
+        Self::Attachments,
     ];
 
     /// Get the column family name as a string.
@@ -46,6 +60,10 @@ impl ColumnFamily {
             Self::EdgesIn => "edges_in",
             Self::Metadata => "metadata",
             Self::AccessLog => "access_log",
+            Self::Archive => "archive",
+            Self::Tags => "tags",
+            Self::Audit => "audit",
+            Self::Attachments => "attachments",
         }
     }
 }
@@ -73,11 +91,48 @@ impl std::str::FromStr for ColumnFamily {
             "edges_in" => Ok(Self::EdgesIn),
             "metadata" => Ok(Self::Metadata),
             "access_log" => Ok(Self::AccessLog),
+            "archive" => Ok(Self::Archive),
+            "tags" => Ok(Self::Tags),
+            "audit" => Ok(Self::Audit),
+            "attachments" => Ok(Self::Attachments),
             _ => Err(format!("Unknown column family: {}", s)),
         }
     }
 }
 
+/// Key prefix of a tag's entries in the tag index.
+///
+/// Tags never contain control characters, so the NUL separator keeps the
+/// entries of one tag apart from those of tags it is a prefix of.
+pub fn tag_index_prefix(tag: &str) -> Vec<u8> {
+    let mut key = tag.as_bytes().to_vec();
+    key.push(0);
+    key
+}
+
+/// Tag index key of a node: the tag, a NUL separator and the node ID.
+pub fn tag_index_key(tag: &str, id: Uuid) -> Vec<u8> {
+    let mut key = tag_index_prefix(tag);
+    key.extend_from_slice(id.as_bytes());
+    key
+}
+
+/// Durability of a write.
+///
+/// RocksDB applies the durability of a whole batch; the store's configured
+/// write options are used for [`Durability::Default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Durability {
+    /// The store's configured write options
+    #[default]
+    Default,
+    /// Write the WAL and fsync it before returning
+    Sync,
+    /// Skip the WAL; the write is lost if the process crashes before a flush
+    NoWal,
+}
+
 /// Write operation for batch writes.
 #[derive(Debug, Clone)]
 pub enum WriteOp {
@@ -95,6 +150,53 @@ pub enum WriteOp {
     Delete { cf: ColumnFamily, key: Vec<u8> },
 }
 
+/// Engine statistics for one column family.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnFamilyStats {
+    /// Column family name
+    pub name: String,
+    /// Estimated number of keys
+    pub estimated_keys: u64,
+    /// Total size of the SST files (bytes)
+    pub sst_bytes: u64,
+    /// Size of the memtables not yet flushed (bytes)
+    pub memtable_bytes: u64,
+    /// Estimated bytes compaction still has to rewrite
+    pub pending_compaction_bytes: u64,
+}
+
+/// Storage engine statistics.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageStats {
+    /// Per column family statistics
+    pub column_families: Vec<ColumnFamilyStats>,
+    /// Number of compactions currently running
+    pub running_compactions: u64,
+    /// Sizes of the nodes stored compressed
+    #[serde(default)]
+    pub compression: CompressionStats,
+}
+
+impl StorageStats {
+    /// Total estimated number of keys.
+    pub fn estimated_keys(&self) -> u64 {
+        self.column_families.iter().map(|cf| cf.estimated_keys).sum()
+    }
+
+    /// Total size of the SST files (bytes).
+    pub fn sst_bytes(&self) -> u64 {
+        self.column_families.iter().map(|cf| cf.sst_bytes).sum()
+    }
+
+    /// Total estimated bytes awaiting compaction.
+    pub fn pending_compaction_bytes(&self) -> u64 {
+        self.column_families
+            .iter()
+            .map(|cf| cf.pending_compaction_bytes)
+            .sum()
+    }
+}
+
 /// Node filter for scanning operations.
 #[derive(Debug, Clone, Default)]
 pub struct NodeFilter {
@@ -108,6 +210,8 @@ pub struct NodeFilter {
     pub min_access_score: Option<f32>,
     /// Created after timestamp
     pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+    /// Created before timestamp
+    pub created_before: Option<chrono::DateTime<chrono::Utc>>,
     /// Custom filters
     pub custom: Vec<Filter>,
 }
@@ -136,6 +240,17 @@ impl NodeFilter {
         self
     }
 
+    /// Keep nodes created between `from` and `to`, inclusive.
+    pub fn with_created_between(
+        mut self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.created_after = Some(from);
+        self.created_before = Some(to);
+        self
+    }
+
     /// Check if a node matches this filter.
     pub fn matches(&self, node: &Node) -> bool {
         if let Some(types) = &self.node_types {
@@ -163,6 +278,11 @@ impl NodeFilter {
                 return false;
             }
         }
+        if let Some(before) = self.created_before {
+            if node.meta.created_at > before {
+                return false;
+            }
+        }
         true
     }
 }
@@ -181,12 +301,35 @@ pub trait Store: Send + Sync {
     /// Put a node (insert or update).
     async fn put_node(&self, node: &Node) -> StorageResult<()>;
 
-    /// Delete a node.
+    /// Delete a node, whether it is active or archived.
     async fn delete_node(&self, id: Uuid) -> StorageResult<bool>;
 
     /// Check if a node exists.
     async fn node_exists(&self, id: Uuid) -> StorageResult<bool>;
 
+    // ========== Archive Operations ==========
+
+    /// Move a node to the cold archive tier.
+    ///
+    /// The node is removed from the active node set and stored without its
+    /// embedding. Returns `false` if the node does not exist.
+    async fn archive_node(&self, id: Uuid) -> StorageResult<bool>;
+
+    /// Move an archived node back to the active node set.
+    ///
+    /// The restored node has no embedding; callers should re-embed it if
+    /// needed. Returns `None` if the node is not archived.
+    async fn restore_node(&self, id: Uuid) -> StorageResult<Option<Node>>;
+
+    /// Get an archived node by ID without restoring it.
+    async fn get_archived_node(&self, id: Uuid) -> StorageResult<Option<Node>>;
+
+    /// Scan archived nodes with optional filter.
+    async fn scan_archived_nodes(
+        &self,
+        filter: Option<NodeFilter>,
+    ) -> StorageResult<BoxStream<'_, StorageResult<Node>>>;
+
     // ========== Edge Operations ==========
 
     /// Get an edge by source and target IDs.
@@ -214,6 +357,18 @@ pub trait Store: Send + Sync {
     /// Execute multiple write operations atomically.
     async fn batch_write(&self, ops: Vec<WriteOp>) -> StorageResult<()>;
 
+    /// Execute multiple write operations atomically with the given
+    /// durability. Stores without per-write options use their configured
+    /// durability.
+    async fn batch_write_with_durability(
+        &self,
+        ops: Vec<WriteOp>,
+        durability: Durability,
+    ) -> StorageResult<()> {
+        let _ = durability;
+        self.batch_write(ops).await
+    }
+
     // ========== Scan Operations ==========
 
     /// Scan nodes with optional filter.
@@ -222,12 +377,62 @@ pub trait Store: Send + Sync {
         filter: Option<NodeFilter>,
     ) -> StorageResult<BoxStream<'_, StorageResult<Node>>>;
 
+    /// Nodes created between `from` and `to`, inclusive.
+    ///
+    /// Backends keyed by node ID can read only the key range of the window
+    /// when every stored node has a time-ordered ID.
+    async fn nodes_created_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> StorageResult<Vec<Node>> {
+        let filter = NodeFilter::new().with_created_between(from, to);
+        self.scan_nodes(Some(filter)).await?.try_collect().await
+    }
+
     /// Count total nodes.
     async fn count_nodes(&self) -> StorageResult<usize>;
 
     /// Count total edges.
     async fn count_edges(&self) -> StorageResult<usize>;
 
+    /// Raw entries of a column family whose keys start with `prefix`, in
+    /// key order. An empty prefix returns the whole column family.
+    async fn scan_prefix(
+        &self,
+        cf: ColumnFamily,
+        prefix: &[u8],
+    ) -> StorageResult<Vec<(Vec<u8>, Vec<u8>)>>;
+
+    /// IDs of the nodes indexed under a normalized tag, in ID order.
+    async fn nodes_with_tag(&self, tag: &str) -> StorageResult<Vec<Uuid>> {
+        let prefix = tag_index_prefix(tag);
+        let entries = self.scan_prefix(ColumnFamily::Tags, &prefix).await?;
+        Ok(entries
+            .into_iter()
+            .filter_map(|(key, _)| Uuid::from_slice(&key[prefix.len()..]).ok())
+            .collect())
+    }
+
+    // ========== Integrity ==========
+
+    /// Edges whose source or target is neither a stored nor an archived
+    /// node.
+    async fn dangling_edges(&self) -> StorageResult<Vec<Edge>>;
+
+    /// Delete the dangling edges, returning them.
+    async fn remove_dangling_edges(&self) -> StorageResult<Vec<Edge>> {
+        let edges = self.dangling_edges().await?;
+        if !edges.is_empty() {
+            let ops = edges
+                .iter()
+                .map(|edge| WriteOp::DeleteEdge(edge.source, edge.target, edge.relation.to_string()))
+                .collect();
+            self.batch_write(ops).await?;
+        }
+        Ok(edges)
+    }
+
     // ========== Metadata Operations ==========
 
     /// Get metadata value by key.
@@ -236,6 +441,33 @@ pub trait Store: Send + Sync {
     /// Put metadata value.
     async fn put_metadata(&self, key: &str, value: &[u8]) -> StorageResult<()>;
 
+    // ========== Maintenance ==========
+
+    /// Compact stored data, reclaiming the space of deleted and overwritten
+    /// entries. `None` compacts every column family.
+    ///
+    /// Backends without manual compaction do nothing.
+    async fn compact(&self, _cf: Option<ColumnFamily>) -> StorageResult<()> {
+        Ok(())
+    }
+
+    /// Rewrite every node and edge stored in an older format version in
+    /// the current one. Reads migrate older values on the fly; this makes
+    /// the migration permanent so they no longer have to.
+    ///
+    /// Backends without versioned values have nothing to migrate.
+    async fn migrate(&self) -> StorageResult<MigrationReport> {
+        Ok(MigrationReport::new())
+    }
+
+    /// Engine statistics (key estimates, on-disk sizes, pending compaction)
+    /// and the sizes of compressed nodes, which takes reading every node.
+    ///
+    /// Backends without engine statistics report no column families.
+    async fn storage_stats(&self) -> StorageResult<StorageStats> {
+        Ok(StorageStats::default())
+    }
+
     // ========== Utility ==========
 
     /// Flush all pending writes to disk.
@@ -261,9 +493,25 @@ mod tests {
     fn test_column_family_from_str() {
         assert_eq!("nodes".parse::<ColumnFamily>().unwrap(), ColumnFamily::Nodes);
         assert_eq!("edges".parse::<ColumnFamily>().unwrap(), ColumnFamily::Edges);
+        assert_eq!("archive".parse::<ColumnFamily>().unwrap(), ColumnFamily::Archive);
+        assert_eq!("tags".parse::<ColumnFamily>().unwrap(), ColumnFamily::Tags);
+        assert_eq!("audit".parse::<ColumnFamily>().unwrap(), ColumnFamily::Audit);
+        assert_eq!(
+            "attachments".parse::<ColumnFamily>().unwrap(),
+            ColumnFamily::Attachments
+        );
         assert!("unknown".parse::<ColumnFamily>().is_err());
     }
 
+    #[test]
+    fn test_tag_index_keys() {
+        let id = Uuid::new_v4();
+        let key = tag_index_key("db", id);
+        assert!(key.starts_with(&tag_index_prefix("db")));
+        assert!(!key.starts_with(&tag_index_prefix("d")));
+        assert_eq!(&key[3..], id.as_bytes());
+    }
+
     #[test]
     fn test_node_filter() {
         let filter = NodeFilter::new()
@@ -281,5 +529,15 @@ mod tests {
         let non_matching_conf = Node::new("Test", synton_core::NodeType::Entity)
             .with_confidence(0.3);
         assert!(!filter.matches(&non_matching_conf));
+
+        let node = Node::new("Test", synton_core::NodeType::Entity);
+        let created = node.meta.created_at;
+        let window = chrono::Duration::seconds(1);
+        assert!(NodeFilter::new()
+            .with_created_between(created - window, created)
+            .matches(&node));
+        assert!(!NodeFilter::new()
+            .with_created_between(created - window * 2, created - window)
+            .matches(&node));
     }
 }
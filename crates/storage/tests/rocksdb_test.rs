@@ -442,6 +442,8 @@ async fn test_custom_config() {
         create_if_missing: true,
         create_missing_column_families: true,
         compression: RocksdbCompression::Snappy,
+        sync: false,
+        disable_wal: false,
     };
 
     let store = RocksdbStore::open(config);
@@ -0,0 +1,179 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Similarity threshold calibration.
+//!
+//! How similar two unrelated texts look depends on the embedding model:
+//! some models score random pairs near 0.1, others near 0.6, so a fixed
+//! relevance cutoff keeps everything for one model and nothing for another.
+//! Calibration scores pairs of stored vectors, which are mostly unrelated,
+//! and takes a high percentile of that background distribution as the
+//! threshold a match has to clear.
+
+use serde::{Deserialize, Serialize};
+
+use crate::index::MemoryVectorIndex;
+
+/// Settings of a calibration run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationConfig {
+    /// Percentile of the pair scores taken as the threshold (0.0 - 1.0).
+    pub percentile: f32,
+
+    /// Maximum number of vector pairs scored.
+    pub max_pairs: usize,
+}
+
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        Self {
+            percentile: 0.95,
+            max_pairs: 20_000,
+        }
+    }
+}
+
+impl CalibrationConfig {
+    /// Set the percentile taken as the threshold.
+    pub fn with_percentile(mut self, percentile: f32) -> Self {
+        self.percentile = percentile.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the maximum number of pairs scored.
+    pub fn with_max_pairs(mut self, max_pairs: usize) -> Self {
+        self.max_pairs = max_pairs;
+        self
+    }
+}
+
+/// Distribution of similarity scores between stored vectors and the
+/// threshold derived from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimilarityCalibration {
+    /// Vectors sampled.
+    pub vectors: usize,
+
+    /// Vector pairs scored.
+    pub pairs: usize,
+
+    /// Mean pair score.
+    pub mean: f32,
+
+    /// Standard deviation of the pair scores.
+    pub std_dev: f32,
+
+    /// Median pair score.
+    pub p50: f32,
+
+    /// 90th percentile pair score.
+    pub p90: f32,
+
+    /// 99th percentile pair score.
+    pub p99: f32,
+
+    /// Percentile the threshold was taken at.
+    pub percentile: f32,
+
+    /// Derived minimum relevance of a match.
+    pub threshold: f32,
+}
+
+/// Calibrate a similarity threshold from a sample of stored vectors.
+///
+/// Scores are cosine similarities clamped to 0.0 - 1.0, as search results
+/// report them. Pairs are taken at increasing index offsets, so every
+/// vector is paired about equally often. Returns `None` with fewer than two
+/// vectors of matching dimension.
+pub fn calibrate(vectors: &[Vec<f32>], config: &CalibrationConfig) -> Option<SimilarityCalibration> {
+    let dimension = vectors.first()?.len();
+    let vectors: Vec<&Vec<f32>> = vectors.iter().filter(|v| v.len() == dimension).collect();
+    let n = vectors.len();
+    if n < 2 {
+        return None;
+    }
+
+    // Offsets up to n / 2 give each unordered pair once
+    let mut scores = Vec::new();
+    'offsets: for offset in 1..=n / 2 {
+        for i in 0..n {
+            let j = (i + offset) % n;
+            if offset * 2 == n && j < i {
+                continue;
+            }
+            if scores.len() >= config.max_pairs {
+                break 'offsets;
+            }
+            let score = MemoryVectorIndex::cosine_similarity(vectors[i], vectors[j]);
+            scores.push(score.clamp(0.0, 1.0));
+        }
+    }
+    if scores.is_empty() {
+        return None;
+    }
+    scores.sort_by(f32::total_cmp);
+
+    let count = scores.len() as f32;
+    let mean = scores.iter().sum::<f32>() / count;
+    let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / count;
+    let percentile = config.percentile.clamp(0.0, 1.0);
+
+    Some(SimilarityCalibration {
+        vectors: n,
+        pairs: scores.len(),
+        mean,
+        std_dev: variance.sqrt(),
+        p50: quantile(&scores, 0.5),
+        p90: quantile(&scores, 0.9),
+        p99: quantile(&scores, 0.99),
+        percentile,
+        threshold: quantile(&scores, percentile),
+    })
+}
+
+/// Nearest-rank quantile of sorted scores.
+fn quantile(sorted: &[f32], q: f32) -> f32 {
+    let rank = (q * sorted.len() as f32).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibrate_tracks_background_similarity() {
+        // Vectors sharing a large common component look alike, as with
+        // models whose unrelated texts still score high
+        let spread: Vec<Vec<f32>> = (0..20)
+            .map(|i| {
+                let angle = i as f32 * 0.3;
+                vec![angle.cos(), angle.sin(), 0.0]
+            })
+            .collect();
+        let crowded: Vec<Vec<f32>> = spread.iter().map(|v| vec![v[0], v[1], 4.0]).collect();
+
+        let config = CalibrationConfig::default();
+        let low = calibrate(&spread, &config).unwrap();
+        let high = calibrate(&crowded, &config).unwrap();
+        assert_eq!(low.vectors, 20);
+        assert_eq!(low.pairs, 190);
+        assert!(high.threshold > low.threshold);
+        assert!(high.mean > 0.9);
+        assert!(low.p50 <= low.p90 && low.p90 <= low.threshold && low.threshold <= low.p99);
+
+        let capped = calibrate(&spread, &config.clone().with_max_pairs(25)).unwrap();
+        assert_eq!(capped.pairs, 25);
+        let lenient = calibrate(&spread, &config.with_percentile(0.5)).unwrap();
+        assert_eq!(lenient.threshold, lenient.p50);
+    }
+
+    #[test]
+    fn test_calibrate_needs_two_vectors() {
+        let config = CalibrationConfig::default();
+        assert!(calibrate(&[], &config).is_none());
+        assert!(calibrate(&[vec![1.0, 0.0]], &config).is_none());
+        assert!(calibrate(&[vec![1.0, 0.0], vec![1.0]], &config).is_none());
+    }
+}
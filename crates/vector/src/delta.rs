@@ -0,0 +1,380 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Differential backups of vector indexes.
+//!
+//! Rebuilding a large index takes hours, so an index that tracks versions
+//! can export only what changed since a checkpoint, and the export can be
+//! applied to another index, such as a replica following the storage
+//! replication stream.
+//!
+//! Every change to the index is numbered. A [`VectorCheckpoint`] names the
+//! epoch of the index contents, drawn again whenever they are replaced
+//! wholesale, and the last change included. The delta since a checkpoint
+//! holds the current value of every vector written after it and the IDs
+//! removed after it. Without a checkpoint, or from one of another epoch,
+//! the delta is full: it holds every vector and replaces the contents of
+//! the index it is applied to.
+//!
+//! Delta layout (little-endian):
+//! - magic `SVDX`, format version (`u32`), dimension (`u32`), flags (`u32`,
+//!   bit 0 set for a full delta)
+//! - epoch (16 bytes), since (`u64`), version (`u64`), upsert count
+//!   (`u64`), removal count (`u64`)
+//! - per upsert: the 16 ID bytes followed by `dimension` `f32` values
+//! - per removal: the 16 ID bytes
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::{VectorError, VectorResult};
+
+/// Magic bytes at the start of a delta.
+const MAGIC: &[u8; 4] = b"SVDX";
+
+/// Current delta format version.
+const FORMAT_VERSION: u32 = 1;
+
+/// Flag marking a full delta.
+const FULL_FLAG: u32 = 1;
+
+/// Header length: magic, version, dimension, flags, epoch, since, version
+/// and the two counts.
+const HEADER_LEN: usize = 4 + 4 + 4 + 4 + 16 + 8 + 8 + 8 + 8;
+
+/// A point in the change history of a vector index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VectorCheckpoint {
+    /// Epoch of the index contents
+    pub epoch: Uuid,
+    /// Last change included
+    pub version: u64,
+}
+
+impl fmt::Display for VectorCheckpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.epoch, self.version)
+    }
+}
+
+impl FromStr for VectorCheckpoint {
+    type Err = VectorError;
+
+    /// Parse a checkpoint written as `<epoch>:<version>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            VectorError::InvalidId(format!(
+                "invalid checkpoint '{}', expected <epoch>:<version>",
+                s
+            ))
+        };
+        let (epoch, version) = s.trim().split_once(':').ok_or_else(invalid)?;
+        Ok(Self {
+            epoch: epoch.parse().map_err(|_| invalid())?,
+            version: version.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Changes of a vector index since a checkpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorDelta {
+    /// Embedding dimension
+    pub dimension: usize,
+    /// Whether the delta holds every vector and replaces the target contents
+    pub full: bool,
+    /// Version the changes follow; 0 for a full delta
+    pub since: u64,
+    /// Checkpoint of the source index the delta brings the target up to
+    pub checkpoint: VectorCheckpoint,
+    /// Vectors written since the checkpoint, with their current values
+    pub upserts: Vec<(Uuid, Vec<f32>)>,
+    /// Vectors removed since the checkpoint
+    pub removals: Vec<Uuid>,
+}
+
+impl VectorDelta {
+    /// Serialize the delta into the delta format.
+    pub fn encode(&self) -> Vec<u8> {
+        let entry_len = 16 + self.dimension * 4;
+        let mut bytes = Vec::with_capacity(
+            HEADER_LEN + self.upserts.len() * entry_len + self.removals.len() * 16,
+        );
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        let flags = if self.full { FULL_FLAG } else { 0 };
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        bytes.extend_from_slice(self.checkpoint.epoch.as_bytes());
+        bytes.extend_from_slice(&self.since.to_le_bytes());
+        bytes.extend_from_slice(&self.checkpoint.version.to_le_bytes());
+        bytes.extend_from_slice(&(self.upserts.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.removals.len() as u64).to_le_bytes());
+
+        for (id, vector) in &self.upserts {
+            bytes.extend_from_slice(id.as_bytes());
+            for value in vector {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        for id in &self.removals {
+            bytes.extend_from_slice(id.as_bytes());
+        }
+        bytes
+    }
+
+    /// Parse a delta written by [`VectorDelta::encode`].
+    pub fn decode(bytes: &[u8]) -> VectorResult<Self> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(VectorError::Deserialization(
+                "not a vector index delta".to_string(),
+            ));
+        }
+        let u32_at = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let u64_at = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+
+        let version = u32_at(4);
+        if version != FORMAT_VERSION {
+            return Err(VectorError::Deserialization(format!(
+                "unsupported delta version {}",
+                version
+            )));
+        }
+        let dimension = u32_at(8) as usize;
+        let full = u32_at(12) & FULL_FLAG != 0;
+        let epoch = Uuid::from_bytes(bytes[16..32].try_into().unwrap());
+        let since = u64_at(32);
+        let checkpoint = VectorCheckpoint {
+            epoch,
+            version: u64_at(40),
+        };
+        let upsert_count = u64_at(48) as usize;
+        let removal_count = u64_at(56) as usize;
+
+        let entry_len = 16 + dimension * 4;
+        let body = &bytes[HEADER_LEN..];
+        let upsert_len = upsert_count.checked_mul(entry_len);
+        let expected = upsert_len.and_then(|len| len.checked_add(removal_count.checked_mul(16)?));
+        if expected != Some(body.len()) {
+            return Err(VectorError::Deserialization(format!(
+                "delta holds {} bytes, expected {} vectors of dimension {} and {} removals",
+                body.len(),
+                upsert_count,
+                dimension,
+                removal_count
+            )));
+        }
+        let (upserts, removals) = body.split_at(upsert_count * entry_len);
+
+        Ok(Self {
+            dimension,
+            full,
+            since,
+            checkpoint,
+            upserts: upserts
+                .chunks_exact(entry_len)
+                .map(|entry| {
+                    let id = Uuid::from_bytes(entry[..16].try_into().unwrap());
+                    let vector = entry[16..]
+                        .chunks_exact(4)
+                        .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                        .collect();
+                    (id, vector)
+                })
+                .collect(),
+            removals: removals
+                .chunks_exact(16)
+                .map(|id| Uuid::from_bytes(id.try_into().unwrap()))
+                .collect(),
+        })
+    }
+}
+
+/// Versions of the writes and removals of an index since its epoch began.
+#[derive(Debug)]
+pub(crate) struct ChangeLog {
+    epoch: Uuid,
+    /// Version of the last write of each vector held
+    written: HashMap<Uuid, u64>,
+    /// Version of the removal of each vector removed
+    removed: HashMap<Uuid, u64>,
+}
+
+impl ChangeLog {
+    /// Start a new epoch with nothing written.
+    pub(crate) fn new() -> Self {
+        Self {
+            epoch: Uuid::new_v4(),
+            written: HashMap::new(),
+            removed: HashMap::new(),
+        }
+    }
+
+    /// Record a write of `id` as change `version`.
+    pub(crate) fn write(&mut self, id: Uuid, version: u64) {
+        self.removed.remove(&id);
+        self.written.insert(id, version);
+    }
+
+    /// Record a removal of `id` as change `version`.
+    pub(crate) fn remove(&mut self, id: Uuid, version: u64) {
+        self.written.remove(&id);
+        self.removed.insert(id, version);
+    }
+
+    /// Start a new epoch holding `ids`, all written as change `version`.
+    pub(crate) fn reset(&mut self, ids: impl IntoIterator<Item = Uuid>, version: u64) {
+        *self = Self::new();
+        self.written = ids.into_iter().map(|id| (id, version)).collect();
+    }
+
+    /// Changes of `vectors`, at change `version`, since `checkpoint`.
+    pub(crate) fn delta(
+        &self,
+        dimension: usize,
+        vectors: &HashMap<Uuid, Vec<f32>>,
+        version: u64,
+        checkpoint: Option<VectorCheckpoint>,
+    ) -> VectorDelta {
+        let since = checkpoint
+            .filter(|c| c.epoch == self.epoch && c.version <= version)
+            .map(|c| c.version);
+        let after = since.unwrap_or(0);
+        let upserts = self
+            .written
+            .iter()
+            .filter(|(_, &written)| since.is_none() || written > after)
+            .filter_map(|(id, _)| Some((*id, vectors.get(id)?.clone())))
+            .collect();
+        let removals = match since {
+            Some(after) => self
+                .removed
+                .iter()
+                .filter(|(_, &removed)| removed > after)
+                .map(|(id, _)| *id)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        VectorDelta {
+            dimension,
+            full: since.is_none(),
+            since: after,
+            checkpoint: VectorCheckpoint {
+                epoch: self.epoch,
+                version,
+            },
+            upserts,
+            removals,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MemoryVectorIndex, VectorIndex};
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let checkpoint = VectorCheckpoint {
+            epoch: Uuid::new_v4(),
+            version: 42,
+        };
+        assert_eq!(
+            checkpoint.to_string().parse::<VectorCheckpoint>(),
+            Ok(checkpoint)
+        );
+        assert!("42".parse::<VectorCheckpoint>().is_err());
+        assert!("not-a-uuid:42".parse::<VectorCheckpoint>().is_err());
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let delta = VectorDelta {
+            dimension: 2,
+            full: false,
+            since: 3,
+            checkpoint: VectorCheckpoint {
+                epoch: Uuid::new_v4(),
+                version: 7,
+            },
+            upserts: vec![(Uuid::new_v4(), vec![0.5, -1.0])],
+            removals: vec![Uuid::new_v4(), Uuid::new_v4()],
+        };
+        let bytes = delta.encode();
+        assert_eq!(VectorDelta::decode(&bytes).unwrap(), delta);
+
+        assert!(VectorDelta::decode(&bytes[..bytes.len() - 1]).is_err());
+        assert!(VectorDelta::decode(b"SVIX").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_replicate_changes() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let source = MemoryVectorIndex::new(2);
+        let replica = MemoryVectorIndex::new(2);
+        source.insert(a, vec![1.0, 0.0]).await.unwrap();
+        source.insert(b, vec![0.0, 1.0]).await.unwrap();
+
+        // The first export is full
+        let delta = source.changes_since(None).await.unwrap();
+        assert!(delta.full);
+        assert_eq!(delta.upserts.len(), 2);
+        let checkpoint = delta.checkpoint;
+        replica.insert(c, vec![1.0, 1.0]).await.unwrap();
+        replica.apply_delta(delta).await.unwrap();
+        assert_eq!(replica.ids().await, source.ids().await);
+
+        // Later exports only hold what changed
+        source.update(a, vec![0.5, 0.5]).await.unwrap();
+        source.remove(b).await.unwrap();
+        source.insert(c, vec![1.0, 1.0]).await.unwrap();
+        source.remove(c).await.unwrap();
+        let delta = source.changes_since(Some(checkpoint)).await.unwrap();
+        assert!(!delta.full);
+        assert_eq!(delta.since, checkpoint.version);
+        assert_eq!(delta.upserts, vec![(a, vec![0.5, 0.5])]);
+        let mut removals = delta.removals.clone();
+        removals.sort();
+        let mut expected = vec![b, c];
+        expected.sort();
+        assert_eq!(removals, expected);
+
+        let checkpoint = delta.checkpoint;
+        replica.apply_delta(delta).await.unwrap();
+        let mut vectors = crate::memory_index_dump(&replica).await.unwrap();
+        vectors.sort_by_key(|(id, _)| *id);
+        assert_eq!(vectors, vec![(a, vec![0.5, 0.5])]);
+
+        let delta = source.changes_since(Some(checkpoint)).await.unwrap();
+        assert!(!delta.full && delta.upserts.is_empty() && delta.removals.is_empty());
+
+        // A checkpoint of another epoch starts over
+        let other = VectorCheckpoint {
+            epoch: Uuid::new_v4(),
+            ..checkpoint
+        };
+        assert!(source.changes_since(Some(other)).await.unwrap().full);
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_other_dimensions() {
+        let source = MemoryVectorIndex::new(3);
+        source
+            .insert(Uuid::new_v4(), vec![1.0, 0.0, 0.0])
+            .await
+            .unwrap();
+        let delta = source.changes_since(None).await.unwrap();
+
+        let replica = MemoryVectorIndex::new(2);
+        assert!(matches!(
+            replica.apply_delta(delta).await,
+            Err(VectorError::InvalidDimension { .. })
+        ));
+    }
+}
@@ -2,6 +2,8 @@
 //
 // Licensed under the Apache License, Version 2.0 (the "License");
 
+use synton_error::{Coded, ErrorCode};
+
 /// Vector index errors.
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum VectorError {
@@ -29,11 +31,28 @@ pub enum VectorError {
     #[error("Backend error: {0}")]
     Backend(String),
 
+    /// Operation not supported by the index
+    #[error("Not supported: {0}")]
+    Unsupported(String),
+
     /// Custom error
     #[error("{0}")]
     Custom(String),
 }
 
+impl Coded for VectorError {
+    fn code(&self) -> ErrorCode {
+        match self {
+            Self::IndexNotFound(_) => ErrorCode::NotFound,
+            Self::InvalidDimension { .. } | Self::InvalidId(_) => ErrorCode::InvalidInput,
+            Self::Serialization(_) | Self::Deserialization(_) => ErrorCode::Serialization,
+            Self::Backend(_) => ErrorCode::Storage,
+            Self::Unsupported(_) => ErrorCode::NotImplemented,
+            Self::Custom(_) => ErrorCode::Internal,
+        }
+    }
+}
+
 /// Result type for vector operations.
 pub type VectorResult<T> = Result<T, VectorError>;
 
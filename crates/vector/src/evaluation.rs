@@ -0,0 +1,205 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Recall evaluation of approximate indexes.
+//!
+//! HNSW and IVF trade recall for speed, and how much depends on their
+//! parameters and on the data. Evaluation takes a sample of stored vectors
+//! as queries, finds their exact nearest neighbors by scanning every stored
+//! vector, and runs the same queries through the index as configured.
+//! Recall@k is the share of the exact top `k` the index also returned.
+
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::index::{MemoryVectorIndex, VectorIndex};
+use crate::VectorResult;
+
+/// Settings of a recall evaluation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvaluationConfig {
+    /// Neighbors retrieved per query.
+    pub k: usize,
+
+    /// Maximum number of stored vectors used as queries.
+    pub queries: usize,
+}
+
+impl Default for EvaluationConfig {
+    fn default() -> Self {
+        Self { k: 10, queries: 100 }
+    }
+}
+
+impl EvaluationConfig {
+    /// Set the number of neighbors retrieved per query.
+    pub fn with_k(mut self, k: usize) -> Self {
+        self.k = k.max(1);
+        self
+    }
+
+    /// Set the maximum number of queries.
+    pub fn with_queries(mut self, queries: usize) -> Self {
+        self.queries = queries.max(1);
+        self
+    }
+}
+
+/// Measured recall and latency of an index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecallEvaluation {
+    /// Stored vectors searched.
+    pub vectors: usize,
+
+    /// Queries run.
+    pub queries: usize,
+
+    /// Neighbors retrieved per query.
+    pub k: usize,
+
+    /// Mean recall@k over the queries (0.0 - 1.0).
+    pub recall: f32,
+
+    /// Lowest recall@k of a single query.
+    pub min_recall: f32,
+
+    /// Queries whose results held the whole exact top `k`.
+    pub perfect_queries: usize,
+
+    /// Mean index search latency in milliseconds.
+    pub mean_latency_ms: f64,
+
+    /// Median index search latency in milliseconds.
+    pub p50_latency_ms: f64,
+
+    /// 99th percentile index search latency in milliseconds.
+    pub p99_latency_ms: f64,
+
+    /// Mean latency of the exact scan in milliseconds, for comparison.
+    pub exact_latency_ms: f64,
+}
+
+/// Evaluate the recall of `index` against an exact scan of `vectors`, the
+/// vectors stored in it.
+///
+/// Queries are spread evenly over `vectors`. Vectors whose dimension does
+/// not match the index are skipped. Returns `None` if none is left.
+pub async fn evaluate_recall<I: VectorIndex + ?Sized>(
+    index: &I,
+    vectors: &[(Uuid, Vec<f32>)],
+    config: &EvaluationConfig,
+) -> VectorResult<Option<RecallEvaluation>> {
+    let dimension = index.dimension();
+    let vectors: Vec<&(Uuid, Vec<f32>)> =
+        vectors.iter().filter(|(_, v)| v.len() == dimension).collect();
+    let n = vectors.len();
+    if n == 0 {
+        return Ok(None);
+    }
+    let k = config.k.max(1);
+    let queries = config.queries.clamp(1, n);
+
+    let mut recalls = Vec::with_capacity(queries);
+    let mut latencies = Vec::with_capacity(queries);
+    let mut exact_ms = 0.0;
+    for i in 0..queries {
+        let query = &vectors[i * n / queries].1;
+
+        let started = Instant::now();
+        let exact = exact_neighbors(&vectors, query, k);
+        exact_ms += started.elapsed().as_secs_f64() * 1000.0;
+
+        let started = Instant::now();
+        let results = index.search(query, k).await?;
+        latencies.push(started.elapsed().as_secs_f64() * 1000.0);
+
+        let found = exact
+            .iter()
+            .filter(|id| results.iter().any(|r| r.id == **id))
+            .count();
+        recalls.push(found as f32 / exact.len() as f32);
+    }
+    latencies.sort_by(f64::total_cmp);
+
+    Ok(Some(RecallEvaluation {
+        vectors: n,
+        queries,
+        k,
+        recall: recalls.iter().sum::<f32>() / queries as f32,
+        min_recall: recalls.iter().copied().fold(1.0, f32::min),
+        perfect_queries: recalls.iter().filter(|r| **r >= 1.0).count(),
+        mean_latency_ms: latencies.iter().sum::<f64>() / queries as f64,
+        p50_latency_ms: quantile(&latencies, 0.5),
+        p99_latency_ms: quantile(&latencies, 0.99),
+        exact_latency_ms: exact_ms / queries as f64,
+    }))
+}
+
+/// IDs of the `k` vectors most similar to `query`, by a full scan.
+fn exact_neighbors(vectors: &[&(Uuid, Vec<f32>)], query: &[f32], k: usize) -> Vec<Uuid> {
+    let mut scored: Vec<(f32, Uuid)> = vectors
+        .iter()
+        .map(|(id, v)| (MemoryVectorIndex::cosine_similarity(query, v), *id))
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    scored.truncate(k);
+    scored.into_iter().map(|(_, id)| id).collect()
+}
+
+/// Nearest-rank quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = (q * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vectors(count: usize) -> Vec<(Uuid, Vec<f32>)> {
+        (0..count)
+            .map(|i| {
+                let angle = i as f32 * 0.1;
+                (Uuid::new_v4(), vec![angle.cos(), angle.sin(), 0.5])
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_exact_index_has_full_recall() {
+        let vectors = vectors(50);
+        let index = MemoryVectorIndex::new(3);
+        index.insert_batch(vectors.clone()).await.unwrap();
+
+        let config = EvaluationConfig::default().with_k(5).with_queries(20);
+        let evaluation = evaluate_recall(&index, &vectors, &config).await.unwrap().unwrap();
+        assert_eq!((evaluation.vectors, evaluation.queries, evaluation.k), (50, 20, 5));
+        assert_eq!(evaluation.recall, 1.0);
+        assert_eq!(evaluation.perfect_queries, 20);
+        assert!(evaluation.p50_latency_ms <= evaluation.p99_latency_ms);
+    }
+
+    #[tokio::test]
+    async fn test_missing_neighbors_lower_recall() {
+        // An index holding every other vector finds about half the true
+        // neighbors
+        let vectors = vectors(40);
+        let index = MemoryVectorIndex::new(3);
+        for (id, vector) in vectors.iter().step_by(2) {
+            index.insert(*id, vector.clone()).await.unwrap();
+        }
+
+        let config = EvaluationConfig::default().with_k(4);
+        let evaluation = evaluate_recall(&index, &vectors, &config).await.unwrap().unwrap();
+        assert_eq!(evaluation.queries, 40);
+        assert!(evaluation.recall > 0.3 && evaluation.recall < 0.7);
+        assert!(evaluation.min_recall <= evaluation.recall);
+        assert_eq!(evaluation.perfect_queries, 0);
+
+        let empty = evaluate_recall(&index, &[(Uuid::new_v4(), vec![1.0])], &config).await.unwrap();
+        assert!(empty.is_none());
+    }
+}
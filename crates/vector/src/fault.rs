@@ -0,0 +1,173 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Fault-injecting vector index wrapper for resilience testing.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use synton_core::{Fault, FaultInjector, Filter};
+use uuid::Uuid;
+
+use crate::{
+    MaintenanceConfig, MaintenanceReport, SearchResult, VectorCheckpoint, VectorDelta, VectorError,
+    VectorIndex, VectorMetadata, VectorResult,
+};
+
+/// Vector index that injects latency, timeouts and backend errors into
+/// inserts, searches and removals of another index.
+///
+/// Maintenance, snapshots and deltas are passed through untouched.
+pub struct FaultyVectorIndex {
+    inner: Arc<dyn VectorIndex>,
+    injector: Arc<FaultInjector>,
+}
+
+impl FaultyVectorIndex {
+    /// Wrap an index.
+    pub fn new(inner: Arc<dyn VectorIndex>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+
+    /// The fault injector.
+    pub fn injector(&self) -> &Arc<FaultInjector> {
+        &self.injector
+    }
+
+    /// Apply the next fault before running `op`.
+    async fn inject(&self, op: &str) -> VectorResult<()> {
+        let fault = match self.injector.next_fault() {
+            Some(fault) => fault,
+            None => return Ok(()),
+        };
+        tracing::debug!("Injecting {:?} into {} {}", fault, self.injector.label(), op);
+
+        match fault {
+            Fault::Latency(delay) => {
+                tokio::time::sleep(delay).await;
+                Ok(())
+            }
+            Fault::Timeout(delay) => {
+                tokio::time::sleep(delay).await;
+                Err(VectorError::Backend(format!("injected timeout in {}", op)))
+            }
+            Fault::Error => Err(VectorError::Backend(format!("injected failure in {}", op))),
+        }
+    }
+}
+
+#[async_trait]
+impl VectorIndex for FaultyVectorIndex {
+    async fn insert(&self, id: Uuid, vector: Vec<f32>) -> VectorResult<()> {
+        self.inject("insert").await?;
+        self.inner.insert(id, vector).await
+    }
+
+    async fn insert_batch(&self, vectors: Vec<(Uuid, Vec<f32>)>) -> VectorResult<()> {
+        self.inject("insert_batch").await?;
+        self.inner.insert_batch(vectors).await
+    }
+
+    async fn search(&self, query: &[f32], k: usize) -> VectorResult<Vec<SearchResult>> {
+        self.inject("search").await?;
+        self.inner.search(query, k).await
+    }
+
+    async fn search_with_filter(
+        &self,
+        query: &[f32],
+        filter: Filter,
+        k: usize,
+    ) -> VectorResult<Vec<SearchResult>> {
+        self.inject("search_with_filter").await?;
+        self.inner.search_with_filter(query, filter, k).await
+    }
+
+    fn supports_filter(&self) -> bool {
+        self.inner.supports_filter()
+    }
+
+    async fn set_metadata(&self, id: Uuid, metadata: VectorMetadata) -> VectorResult<()> {
+        self.inner.set_metadata(id, metadata).await
+    }
+
+    async fn remove(&self, id: Uuid) -> VectorResult<()> {
+        self.inject("remove").await?;
+        self.inner.remove(id).await
+    }
+
+    async fn update(&self, id: Uuid, vector: Vec<f32>) -> VectorResult<()> {
+        self.inject("update").await?;
+        self.inner.update(id, vector).await
+    }
+
+    async fn count(&self) -> VectorResult<usize> {
+        self.inject("count").await?;
+        self.inner.count().await
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    async fn optimize(&self, config: &MaintenanceConfig) -> VectorResult<MaintenanceReport> {
+        self.inner.optimize(config).await
+    }
+
+    async fn save_snapshot(&self, path: &Path) -> VectorResult<bool> {
+        self.inner.save_snapshot(path).await
+    }
+
+    async fn load_snapshot(&self, path: &Path) -> VectorResult<usize> {
+        self.inner.load_snapshot(path).await
+    }
+
+    async fn changes_since(
+        &self,
+        checkpoint: Option<VectorCheckpoint>,
+    ) -> VectorResult<VectorDelta> {
+        self.inner.changes_since(checkpoint).await
+    }
+
+    async fn apply_delta(&self, delta: VectorDelta) -> VectorResult<()> {
+        self.inner.apply_delta(delta).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryVectorIndex;
+    use synton_core::FaultConfig;
+
+    #[tokio::test]
+    async fn test_faulty_index() {
+        let inner = Arc::new(MemoryVectorIndex::new(2));
+        let config = FaultConfig {
+            seed: 11,
+            error_rate: 0.3,
+            ..Default::default()
+        };
+        let index = FaultyVectorIndex::new(inner.clone(), Arc::new(FaultInjector::new(config, "vector")));
+
+        let mut failures = 0;
+        for i in 0..20 {
+            if let Err(e) = index.insert(Uuid::new_v4(), vec![i as f32, 1.0]).await {
+                assert!(matches!(e, VectorError::Backend(_)));
+                failures += 1;
+            }
+        }
+        assert!(failures > 0 && failures < 20);
+        assert_eq!(index.injector().injected(), failures);
+
+        // Only the calls that got through reached the wrapped index
+        assert_eq!(inner.count().await.unwrap(), 20 - failures as usize);
+        assert_eq!(index.dimension(), 2);
+    }
+}
@@ -4,11 +4,16 @@
 
 use async_trait::async_trait;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::{VectorError, VectorResult};
+use crate::delta::{ChangeLog, VectorCheckpoint, VectorDelta};
+use crate::maintenance::{MaintenanceConfig, MaintenanceReport};
+use crate::snapshot;
+use crate::{VectorError, VectorMetadata, VectorResult};
 use synton_core::Filter;
 
 /// Result of a vector search operation.
@@ -54,15 +59,34 @@ pub trait VectorIndex: Send + Sync {
     /// Search for k nearest neighbors.
     async fn search(&self, query: &[f32], k: usize) -> VectorResult<Vec<SearchResult>>;
 
-    /// Hybrid search with metadata filters.
+    /// Search for the k nearest neighbors among the vectors whose
+    /// [`VectorMetadata`] matches `filter`.
+    ///
+    /// The filter is applied before ranking, so up to `k` matching vectors
+    /// are returned however few of them there are. Indexes that cannot
+    /// filter fail with [`VectorError::Unsupported`].
     async fn search_with_filter(
         &self,
-        query: &[f32],
+        _query: &[f32],
         _filter: Filter,
-        k: usize,
+        _k: usize,
     ) -> VectorResult<Vec<SearchResult>> {
-        // Default implementation: ignores filter
-        self.search(query, k).await
+        Err(VectorError::Unsupported(
+            "this vector index cannot filter searches".to_string(),
+        ))
+    }
+
+    /// Whether [`VectorIndex::search_with_filter`] is supported.
+    fn supports_filter(&self) -> bool {
+        false
+    }
+
+    /// Set the metadata of a vector's node, matched by filtered searches.
+    ///
+    /// Metadata may be set before the vector is inserted and is dropped
+    /// when the vector is removed. Indexes that cannot filter ignore it.
+    async fn set_metadata(&self, _id: Uuid, _metadata: VectorMetadata) -> VectorResult<()> {
+        Ok(())
     }
 
     /// Delete a vector by ID.
@@ -81,6 +105,71 @@ pub trait VectorIndex: Send + Sync {
     fn is_ready(&self) -> bool {
         true
     }
+
+    /// Run maintenance (compaction, retraining, rebuilding) on the index.
+    ///
+    /// Indexes without on-disk structures have nothing to maintain. Fails
+    /// with [`VectorError::Unsupported`] if due work cannot be done.
+    async fn optimize(&self, _config: &MaintenanceConfig) -> VectorResult<MaintenanceReport> {
+        Ok(MaintenanceReport {
+            vector_count: self.count().await?,
+            ..Default::default()
+        })
+    }
+
+    /// Write a warm-start snapshot of the index to `path`.
+    ///
+    /// Returns `false` when nothing was written: the index is unchanged since
+    /// the last snapshot, or it persists itself and has no use for one.
+    async fn save_snapshot(&self, _path: &Path) -> VectorResult<bool> {
+        Ok(false)
+    }
+
+    /// Replace the index contents with a snapshot written by
+    /// [`VectorIndex::save_snapshot`], returning the number of vectors loaded.
+    ///
+    /// A missing snapshot file loads nothing.
+    async fn load_snapshot(&self, _path: &Path) -> VectorResult<usize> {
+        Ok(0)
+    }
+
+    /// Export the changes since `checkpoint`, or every vector when there is
+    /// no checkpoint or the index cannot continue from it.
+    ///
+    /// Only indexes that track versions support differential exports.
+    async fn changes_since(
+        &self,
+        _checkpoint: Option<VectorCheckpoint>,
+    ) -> VectorResult<VectorDelta> {
+        Err(VectorError::Unsupported(
+            "this vector index does not track versions".to_string(),
+        ))
+    }
+
+    /// Apply a delta exported by [`VectorIndex::changes_since`].
+    ///
+    /// By default removals and upserts are replayed one by one; a full delta
+    /// needs an index that can replace its contents.
+    async fn apply_delta(&self, delta: VectorDelta) -> VectorResult<()> {
+        if delta.dimension != self.dimension() {
+            return Err(VectorError::InvalidDimension {
+                expected: self.dimension(),
+                found: delta.dimension,
+            });
+        }
+        if delta.full {
+            return Err(VectorError::Unsupported(
+                "this vector index cannot apply a full delta".to_string(),
+            ));
+        }
+        for id in delta.removals {
+            self.remove(id).await?;
+        }
+        for (id, vector) in delta.upserts {
+            self.update(id, vector).await?;
+        }
+        Ok(())
+    }
 }
 
 /// In-memory vector index for testing and simple use cases.
@@ -88,6 +177,14 @@ pub trait VectorIndex: Send + Sync {
 pub struct MemoryVectorIndex {
     dimension: usize,
     vectors: Arc<RwLock<HashMap<Uuid, Vec<f32>>>>,
+    /// Node metadata for filtered searches, by vector ID
+    metadata: Arc<RwLock<HashMap<Uuid, VectorMetadata>>>,
+    /// Bumped on every change, under the write lock
+    version: Arc<AtomicU64>,
+    /// Version written by the last snapshot
+    saved_version: Arc<AtomicU64>,
+    /// Version of every write and removal, under the write lock
+    changes: Arc<Mutex<ChangeLog>>,
 }
 
 impl MemoryVectorIndex {
@@ -96,11 +193,89 @@ impl MemoryVectorIndex {
         Self {
             dimension,
             vectors: Arc::new(RwLock::new(HashMap::new())),
+            metadata: Arc::new(RwLock::new(HashMap::new())),
+            version: Arc::new(AtomicU64::new(0)),
+            saved_version: Arc::new(AtomicU64::new(0)),
+            changes: Arc::new(Mutex::new(ChangeLog::new())),
         }
     }
 
+    /// Number of vectors held.
+    pub(crate) async fn len(&self) -> usize {
+        self.vectors.read().await.len()
+    }
+
+    /// Whether a vector is held.
+    pub(crate) async fn contains(&self, id: Uuid) -> bool {
+        self.vectors.read().await.contains_key(&id)
+    }
+
+    /// IDs of the vectors held.
+    pub(crate) async fn ids(&self) -> std::collections::HashSet<Uuid> {
+        self.vectors.read().await.keys().copied().collect()
+    }
+
+    /// Record a change, returning its version; call while holding the
+    /// write lock.
+    fn touch(&self) -> u64 {
+        self.version.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Record a write of `id`; call while holding the write lock.
+    fn touch_written(&self, id: Uuid) {
+        let version = self.touch();
+        self.changes().write(id, version);
+    }
+
+    /// Record a removal of `id`; call while holding the write lock.
+    fn touch_removed(&self, id: Uuid) {
+        let version = self.touch();
+        self.changes().remove(id, version);
+    }
+
+    /// Replace the contents with `entries`, starting a new epoch; call
+    /// while holding the write lock. Metadata is kept: it is set by the
+    /// owner, not carried by snapshots and deltas.
+    fn replace(&self, vectors: &mut HashMap<Uuid, Vec<f32>>, entries: Vec<(Uuid, Vec<f32>)>) {
+        *vectors = entries.into_iter().collect();
+        let version = self.touch();
+        self.changes().reset(vectors.keys().copied(), version);
+    }
+
+    fn changes(&self) -> MutexGuard<'_, ChangeLog> {
+        self.changes.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn check_query(&self, query: &[f32]) -> VectorResult<()> {
+        if query.len() != self.dimension {
+            return Err(VectorError::InvalidDimension {
+                expected: self.dimension,
+                found: query.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The `k` candidates most similar to `query`, best first.
+    fn rank<'a>(
+        query: &[f32],
+        candidates: impl Iterator<Item = (&'a Uuid, &'a Vec<f32>)>,
+        k: usize,
+    ) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = candidates
+            .map(|(&id, vec)| {
+                let score = Self::cosine_similarity(query, vec);
+                SearchResult::new(id, score)
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        results.truncate(k);
+        results
+    }
+
     /// Calculate cosine similarity between two vectors.
-    fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    pub(crate) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
         if a.len() != b.len() {
             return 0.0;
         }
@@ -135,6 +310,7 @@ impl VectorIndex for MemoryVectorIndex {
         }
         let mut vectors = self.vectors.write().await;
         vectors.insert(id, vector);
+        self.touch_written(id);
         Ok(())
     }
 
@@ -148,35 +324,51 @@ impl VectorIndex for MemoryVectorIndex {
                 });
             }
             store.insert(id, vector);
+            self.touch_written(id);
         }
         Ok(())
     }
 
     async fn search(&self, query: &[f32], k: usize) -> VectorResult<Vec<SearchResult>> {
-        if query.len() != self.dimension {
-            return Err(VectorError::InvalidDimension {
-                expected: self.dimension,
-                found: query.len(),
-            });
-        }
+        self.check_query(query)?;
+        let vectors = self.vectors.read().await;
+        Ok(Self::rank(query, vectors.iter(), k))
+    }
+
+    async fn search_with_filter(
+        &self,
+        query: &[f32],
+        filter: Filter,
+        k: usize,
+    ) -> VectorResult<Vec<SearchResult>> {
+        self.check_query(query)?;
+        VectorMetadata::check_filter(&filter)?;
 
+        // Only vectors of matching nodes are scored
         let vectors = self.vectors.read().await;
-        let mut results: Vec<SearchResult> = vectors
+        let metadata = self.metadata.read().await;
+        let unknown = VectorMetadata::default();
+        let candidates = vectors
             .iter()
-            .map(|(&id, vec)| {
-                let score = Self::cosine_similarity(query, vec);
-                SearchResult::new(id, score)
-            })
-            .collect();
+            .filter(|(id, _)| metadata.get(id).unwrap_or(&unknown).matches(&filter));
+        Ok(Self::rank(query, candidates, k))
+    }
 
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-        results.truncate(k);
-        Ok(results)
+    fn supports_filter(&self) -> bool {
+        true
+    }
+
+    async fn set_metadata(&self, id: Uuid, metadata: VectorMetadata) -> VectorResult<()> {
+        self.metadata.write().await.insert(id, metadata);
+        Ok(())
     }
 
     async fn remove(&self, id: Uuid) -> VectorResult<()> {
         let mut vectors = self.vectors.write().await;
-        vectors.remove(&id);
+        if vectors.remove(&id).is_some() {
+            self.touch_removed(id);
+        }
+        self.metadata.write().await.remove(&id);
         Ok(())
     }
 
@@ -189,6 +381,7 @@ impl VectorIndex for MemoryVectorIndex {
         }
         let mut vectors = self.vectors.write().await;
         vectors.insert(id, vector);
+        self.touch_written(id);
         Ok(())
     }
 
@@ -200,6 +393,77 @@ impl VectorIndex for MemoryVectorIndex {
     fn dimension(&self) -> usize {
         self.dimension
     }
+
+    async fn save_snapshot(&self, path: &Path) -> VectorResult<bool> {
+        let (version, bytes) = {
+            let vectors = self.vectors.read().await;
+            let version = self.version.load(Ordering::Relaxed);
+            if version == self.saved_version.load(Ordering::Relaxed) {
+                return Ok(false);
+            }
+            (version, snapshot::encode(self.dimension, &vectors))
+        };
+
+        snapshot::write_atomic(path, &bytes).await?;
+        self.saved_version.store(version, Ordering::Relaxed);
+        Ok(true)
+    }
+
+    async fn load_snapshot(&self, path: &Path) -> VectorResult<usize> {
+        let Some(bytes) = snapshot::read(path).await? else {
+            return Ok(0);
+        };
+        let entries = snapshot::decode(&bytes, self.dimension)?;
+        let count = entries.len();
+
+        let mut vectors = self.vectors.write().await;
+        self.replace(&mut vectors, entries);
+        // The loaded contents are already on disk
+        self.saved_version
+            .store(self.version.load(Ordering::Relaxed), Ordering::Relaxed);
+        Ok(count)
+    }
+
+    async fn changes_since(
+        &self,
+        checkpoint: Option<VectorCheckpoint>,
+    ) -> VectorResult<VectorDelta> {
+        let vectors = self.vectors.read().await;
+        let version = self.version.load(Ordering::Relaxed);
+        Ok(self
+            .changes()
+            .delta(self.dimension, &vectors, version, checkpoint))
+    }
+
+    async fn apply_delta(&self, delta: VectorDelta) -> VectorResult<()> {
+        let found = std::iter::once(delta.dimension)
+            .chain(delta.upserts.iter().map(|(_, vector)| vector.len()))
+            .find(|&found| found != self.dimension);
+        if let Some(found) = found {
+            return Err(VectorError::InvalidDimension {
+                expected: self.dimension,
+                found,
+            });
+        }
+
+        let mut vectors = self.vectors.write().await;
+        if delta.full {
+            self.replace(&mut vectors, delta.upserts);
+            return Ok(());
+        }
+        let mut metadata = self.metadata.write().await;
+        for id in delta.removals {
+            if vectors.remove(&id).is_some() {
+                self.touch_removed(id);
+            }
+            metadata.remove(&id);
+        }
+        for (id, vector) in delta.upserts {
+            vectors.insert(id, vector);
+            self.touch_written(id);
+        }
+        Ok(())
+    }
 }
 
 /// Helper function to dump all vectors from a memory index.
@@ -212,6 +476,7 @@ pub async fn memory_index_dump(index: &MemoryVectorIndex) -> VectorResult<Vec<(U
 #[cfg(test)]
 mod tests {
     use super::*;
+    use synton_core::NodeType;
 
     #[test]
     fn test_cosine_similarity() {
@@ -245,6 +510,59 @@ mod tests {
         assert_eq!(results.len(), 0); // Empty index
     }
 
+    #[tokio::test]
+    async fn test_memory_index_filtered_search() {
+        let index = MemoryVectorIndex::new(2);
+        let fact = Uuid::new_v4();
+        let other_namespace = Uuid::new_v4();
+        let untagged = Uuid::new_v4();
+
+        // The closest vectors are the ones the filter excludes
+        for i in 0..20 {
+            let id = Uuid::new_v4();
+            index.insert(id, vec![1.0, i as f32 * 0.01]).await.unwrap();
+            let metadata = VectorMetadata::new(NodeType::Entity).with_namespace("docs");
+            index.set_metadata(id, metadata).await.unwrap();
+        }
+        index.insert(fact, vec![0.0, 1.0]).await.unwrap();
+        let metadata = VectorMetadata::new(NodeType::Fact).with_namespace("docs");
+        index.set_metadata(fact, metadata).await.unwrap();
+        index.insert(other_namespace, vec![0.5, 0.5]).await.unwrap();
+        let metadata = VectorMetadata::new(NodeType::Fact).with_namespace("ops");
+        index.set_metadata(other_namespace, metadata).await.unwrap();
+        index.insert(untagged, vec![0.9, 0.1]).await.unwrap();
+
+        let filter = Filter::and([
+            Filter::equals("node_type", "fact"),
+            Filter::equals("namespace", "docs"),
+        ]);
+        let results = index
+            .search_with_filter(&[1.0, 0.0], filter, 3)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, fact);
+
+        // Removing the vector drops its metadata
+        index.remove(fact).await.unwrap();
+        index.insert(fact, vec![0.0, 1.0]).await.unwrap();
+        let filter = Filter::equals("node_type", "fact");
+        let results = index
+            .search_with_filter(&[1.0, 0.0], filter, 3)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, other_namespace);
+
+        assert!(index.supports_filter());
+        assert!(matches!(
+            index
+                .search_with_filter(&[1.0, 0.0], Filter::equals("lang", "en"), 3)
+                .await,
+            Err(VectorError::Unsupported(_))
+        ));
+    }
+
     /// Helper function to dump all vectors from a memory index.
     /// Used for migration and testing.
     #[cfg(any(test, feature = "lance"))]
@@ -13,11 +13,13 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
+use synton_core::{Filter, FilterValue};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::{VectorError, VectorResult};
+use crate::{VectorError, VectorMetadata, VectorResult};
 use crate::index::{SearchResult, VectorIndex};
+use crate::maintenance::{MaintenanceConfig, MaintenanceReport};
 
 /// Index type for Lance vector search.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -199,6 +201,24 @@ impl HnswParams {
         self.ef_search = ef;
         self
     }
+
+    /// Derive parameters suited to an index of `count` vectors.
+    ///
+    /// Larger graphs need more links per node and a wider construction
+    /// beam to keep recall stable; the values never drop below `self`.
+    pub fn tuned_for(&self, count: usize) -> Self {
+        let (m, ef_construction) = match count {
+            0..=99_999 => (16, 200),
+            100_000..=999_999 => (24, 300),
+            _ => (32, 400),
+        };
+
+        Self {
+            m: self.m.max(m),
+            ef_construction: self.ef_construction.max(ef_construction),
+            ef_search: self.ef_search,
+        }
+    }
 }
 
 /// IVF index parameters.
@@ -255,6 +275,24 @@ struct LanceIndexInner {
     count: usize,
     /// Whether the index is ready for queries.
     ready: bool,
+    /// Number of data fragments written since the last compaction.
+    fragments: usize,
+    /// Vector count when the index was last trained/built.
+    trained_count: usize,
+    /// HNSW parameters used for the current graph.
+    hnsw_params: HnswParams,
+}
+
+impl LanceIndexInner {
+    fn new(config: &LanceIndexConfig) -> Self {
+        Self {
+            count: 0,
+            ready: true,
+            fragments: 0,
+            trained_count: 0,
+            hnsw_params: config.hnsw_params.clone().unwrap_or_default(),
+        }
+    }
 }
 
 impl LanceVectorIndex {
@@ -269,10 +307,7 @@ impl LanceVectorIndex {
             .map_err(|e| VectorError::Backend(format!("Failed to create directory: {}", e)))?;
 
         // Initialize the index
-        let inner = LanceIndexInner {
-            count: 0,
-            ready: true,
-        };
+        let inner = LanceIndexInner::new(&config);
 
         Ok(Self {
             config,
@@ -298,10 +333,8 @@ impl LanceVectorIndex {
             ..Default::default()
         };
 
-        let inner = LanceIndexInner {
-            count: 0, // Will be loaded from metadata
-            ready: true,
-        };
+        // Count will be loaded from metadata
+        let inner = LanceIndexInner::new(&config);
 
         Ok(Self {
             config,
@@ -331,13 +364,73 @@ impl LanceVectorIndex {
             }
         }
 
-        // Update count
+        // Update count; every batch write appends a new fragment
         let mut inner = self.inner.write().await;
         inner.count += vectors.len();
+        inner.fragments += 1;
 
         Ok(())
     }
 
+    /// Get the number of data fragments awaiting compaction.
+    pub async fn fragment_count(&self) -> usize {
+        self.inner.read().await.fragments
+    }
+
+    /// Compact all data fragments into a single fragment.
+    ///
+    /// Returns the number of fragments that were merged away. Fails with
+    /// [`VectorError::Unsupported`] when there is anything to merge: vectors
+    /// are not written to a Lance dataset yet, so there are no fragments on
+    /// disk to compact.
+    pub async fn compact_fragments(&self) -> VectorResult<usize> {
+        let inner = self.inner.read().await;
+        if inner.fragments <= 1 {
+            return Ok(0);
+        }
+
+        Err(VectorError::Unsupported(format!(
+            "compacting {} fragments of Lance table '{}'",
+            inner.fragments, self.config.table_name
+        )))
+    }
+
+    /// Resolve the index type to maintain, choosing one for `Auto` by data size.
+    fn effective_index_type(&self, count: usize) -> IndexType {
+        match self.config.index_type {
+            IndexType::Auto if count >= IndexType::Ivf.min_vectors() => IndexType::Ivf,
+            IndexType::Auto if count >= IndexType::Hnsw.min_vectors() => IndexType::Hnsw,
+            IndexType::Auto => IndexType::Flat,
+            other => other,
+        }
+    }
+
+    /// Retrain IVF centroids against the current data.
+    ///
+    /// Not supported yet: there is no Lance IVF index to retrain.
+    async fn retrain_ivf(&self, count: usize) -> VectorResult<()> {
+        let mut params = self.config.ivf_params.clone().unwrap_or_default();
+        // Keep roughly sqrt(n) partitions as the data grows
+        params.nlist = params.nlist.max((count as f64).sqrt() as usize);
+
+        Err(VectorError::Unsupported(format!(
+            "retraining IVF centroids on {} vectors with nlist={}, nprobe={}",
+            count, params.nlist, params.nprobe
+        )))
+    }
+
+    /// Rebuild the HNSW graph with parameters tuned for the current size.
+    ///
+    /// Not supported yet: there is no Lance HNSW index to rebuild.
+    async fn rebuild_hnsw(&self, count: usize) -> VectorResult<()> {
+        let params = self.inner.read().await.hnsw_params.tuned_for(count);
+
+        Err(VectorError::Unsupported(format!(
+            "rebuilding the HNSW index on {} vectors with M={}, ef_construction={}, ef_search={}",
+            count, params.m, params.ef_construction, params.ef_search
+        )))
+    }
+
     /// Create a vector index on the table.
     pub async fn create_index(&self, index_type: IndexType) -> VectorResult<()> {
         match index_type {
@@ -366,22 +459,96 @@ impl LanceVectorIndex {
         Ok(())
     }
 
-    /// Perform a linear scan search (exact, no index).
-    async fn linear_search(&self, _query: &[f32], _k: usize) -> VectorResult<Vec<SearchResult>> {
+    /// Perform a linear scan search (exact, no index), over the rows
+    /// matching `predicate` if given.
+    async fn linear_search(
+        &self,
+        _query: &[f32],
+        _k: usize,
+        predicate: Option<&str>,
+    ) -> VectorResult<Vec<SearchResult>> {
         // For the stub implementation, return empty results
-        // In production, this would scan the Lance dataset
+        // In production, this would scan the Lance dataset, with the
+        // predicate pushed down as a prefilter so only matching rows are
+        // ranked
+        if let Some(predicate) = predicate {
+            tracing::debug!("Prefiltering Lance search with {}", predicate);
+        }
         Ok(Vec::new())
     }
 
-    /// Get the Lance schema for vector storage.
+    /// Get the Lance schema for vector storage, with the metadata columns
+    /// that filters are pushed down to.
     fn schema(&self) -> String {
         format!(
-            r#"{{"vector": "fixed_size_list<{}, item: float>"}}"#,
+            r#"{{"vector": "fixed_size_list<{}, item: float>", "node_type": "utf8", "namespace": "utf8", "tags": "list<item: utf8>"}}"#,
             self.config.dimension
         )
     }
 }
 
+/// Quote a string as a SQL literal.
+fn sql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Translate a filter into a SQL predicate over the metadata columns, for
+/// Lance to apply before ranking.
+fn sql_predicate(filter: &Filter) -> VectorResult<String> {
+    VectorMetadata::check_filter(filter)?;
+    Ok(predicate(filter))
+}
+
+/// SQL for a filter that passed [`VectorMetadata::check_filter`].
+fn predicate(filter: &Filter) -> String {
+    let literal = |value: &FilterValue| match value {
+        FilterValue::String(value) => sql_string(value),
+        other => other.to_string(),
+    };
+    let join = |filters: &[Filter], operator: &str| {
+        if filters.is_empty() {
+            // Empty AND holds, empty OR does not
+            return (operator == " AND ").to_string();
+        }
+        let parts: Vec<String> = filters
+            .iter()
+            .map(|f| format!("({})", predicate(f)))
+            .collect();
+        parts.join(operator)
+    };
+
+    match filter {
+        Filter::Equals { field, value } if field == "tags" => {
+            format!("array_has(tags, {})", literal(value))
+        }
+        Filter::Equals { field, value } => format!("{} = {}", field, literal(value)),
+        Filter::InList { values, .. } if values.is_empty() => "false".to_string(),
+        Filter::InList { field, values } if field == "tags" => {
+            let values: Vec<String> = values.iter().map(literal).collect();
+            format!("array_has_any(tags, make_array({}))", values.join(", "))
+        }
+        Filter::InList { field, values } => {
+            let values: Vec<String> = values.iter().map(literal).collect();
+            format!("{} IN ({})", field, values.join(", "))
+        }
+        Filter::Contains { field, value } => {
+            let pattern = value
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            let pattern = sql_string(&format!("%{}%", pattern));
+            format!("{} LIKE {} ESCAPE '\\'", field, pattern)
+        }
+        Filter::And(filters) => join(filters, " AND "),
+        Filter::Or(filters) => join(filters, " OR "),
+        Filter::Not(filter) => format!("NOT ({})", predicate(filter)),
+        // Rejected by check_filter
+        Filter::GreaterThan { .. } | Filter::LessThan { .. } | Filter::Range { .. } => {
+            "false".to_string()
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl VectorIndex for LanceVectorIndex {
     async fn insert(&self, id: Uuid, vector: Vec<f32>) -> VectorResult<()> {
@@ -409,7 +576,36 @@ impl VectorIndex for LanceVectorIndex {
 
         // For now, use linear scan
         // In production, this would use Lance's native vector search
-        self.linear_search(query, k).await
+        self.linear_search(query, k, None).await
+    }
+
+    async fn search_with_filter(
+        &self,
+        query: &[f32],
+        filter: Filter,
+        k: usize,
+    ) -> VectorResult<Vec<SearchResult>> {
+        if query.len() != self.config.dimension {
+            return Err(VectorError::InvalidDimension {
+                expected: self.config.dimension,
+                found: query.len(),
+            });
+        }
+        let predicate = sql_predicate(&filter)?;
+
+        if self.inner.read().await.count == 0 {
+            return Ok(Vec::new());
+        }
+        self.linear_search(query, k, Some(&predicate)).await
+    }
+
+    fn supports_filter(&self) -> bool {
+        true
+    }
+
+    async fn set_metadata(&self, _id: Uuid, _metadata: VectorMetadata) -> VectorResult<()> {
+        // Update the metadata columns of the row in the Lance dataset
+        Ok(())
     }
 
     async fn remove(&self, _id: Uuid) -> VectorResult<()> {
@@ -442,6 +638,48 @@ impl VectorIndex for LanceVectorIndex {
         // Check if Lance connection is ready
         true
     }
+
+    async fn optimize(&self, config: &MaintenanceConfig) -> VectorResult<MaintenanceReport> {
+        // Due work fails as unsupported until vectors are written to Lance
+        let (fragments_before, count, trained_count) = {
+            let inner = self.inner.read().await;
+            (inner.fragments, inner.count, inner.trained_count)
+        };
+
+        let mut report = MaintenanceReport {
+            fragments_before,
+            fragments_after: fragments_before,
+            vector_count: count,
+            ..Default::default()
+        };
+
+        if config.should_compact(fragments_before) {
+            self.compact_fragments().await?;
+            report.compacted = true;
+            report.fragments_after = self.fragment_count().await;
+        }
+
+        let index_type = self.effective_index_type(count);
+        if config.should_retrain(count, trained_count) && count >= index_type.min_vectors() {
+            match index_type {
+                IndexType::Ivf => {
+                    self.retrain_ivf(count).await?;
+                    report.ivf_retrained = true;
+                }
+                IndexType::Hnsw => {
+                    self.rebuild_hnsw(count).await?;
+                    report.hnsw_rebuilt = true;
+                }
+                IndexType::Flat | IndexType::Auto => {}
+            }
+
+            if report.ivf_retrained || report.hnsw_rebuilt {
+                self.inner.write().await.trained_count = count;
+            }
+        }
+
+        Ok(report)
+    }
 }
 
 /// Migration tool for converting MemoryVectorIndex to LanceVectorIndex.
@@ -619,6 +857,69 @@ mod tests {
         assert_eq!(results.len(), 0); // Empty index
     }
 
+    #[test]
+    fn test_hnsw_params_tuned_for() {
+        let params = HnswParams::new();
+        let small = params.tuned_for(1_000);
+        assert_eq!(small.m, 16);
+
+        let large = params.tuned_for(2_000_000);
+        assert_eq!(large.m, 32);
+        assert_eq!(large.ef_construction, 400);
+        assert_eq!(large.ef_search, params.ef_search);
+    }
+
+    #[tokio::test]
+    async fn test_lance_optimize_is_unsupported() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = LanceIndexConfig::new(temp_dir.path(), 4)
+            .with_index_type(IndexType::Hnsw);
+        let index = LanceVectorIndex::new(config).await.unwrap();
+
+        // Nothing due on an empty index
+        let maintenance = MaintenanceConfig::new().with_max_fragments(4);
+        let report = index.optimize(&maintenance).await.unwrap();
+        assert!(!report.did_work());
+
+        for _ in 0..4 {
+            let batch = (0..250).map(|_| (Uuid::new_v4(), vec![0.5; 4])).collect();
+            index.insert_batch(batch).await.unwrap();
+        }
+        assert_eq!(index.fragment_count().await, 4);
+
+        // Due compaction and rebuilds are reported, not pretended
+        let result = index.optimize(&maintenance).await;
+        assert!(matches!(result, Err(VectorError::Unsupported(_))));
+        assert_eq!(index.fragment_count().await, 4);
+        let rebuild = MaintenanceConfig::new().with_max_fragments(100);
+        let result = index.optimize(&rebuild).await;
+        assert!(matches!(result, Err(VectorError::Unsupported(_))));
+    }
+
+    #[test]
+    fn test_sql_predicate() {
+        let filter = Filter::and([
+            Filter::equals("node_type", "fact"),
+            Filter::equals("namespace", "o'brien"),
+            Filter::in_list("tags", vec!["a".into(), "b".into()]),
+            Filter::not(Filter::contains("namespace", "50%")),
+        ]);
+        assert_eq!(
+            sql_predicate(&filter).unwrap(),
+            "(node_type = 'fact') AND (namespace = 'o''brien') AND \
+             (array_has_any(tags, make_array('a', 'b'))) AND \
+             (NOT (namespace LIKE '%50\\%%' ESCAPE '\\'))"
+        );
+        assert_eq!(
+            sql_predicate(&Filter::equals("tags", "rust")).unwrap(),
+            "array_has(tags, 'rust')"
+        );
+        assert!(matches!(
+            sql_predicate(&Filter::equals("content", "x")),
+            Err(VectorError::Unsupported(_))
+        ));
+    }
+
     #[tokio::test]
     async fn test_lance_index_dimension_check() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -5,14 +5,32 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod calibration;
+mod delta;
 mod error;
+mod evaluation;
+mod fault;
 mod index;
+mod maintenance;
+mod metadata;
+mod multi;
+mod snapshot;
+mod tiered;
 
 #[cfg(feature = "lance")]
 mod lance;
 
+pub use calibration::{calibrate, CalibrationConfig, SimilarityCalibration};
+pub use delta::{VectorCheckpoint, VectorDelta};
 pub use error::{VectorError, VectorResult};
+pub use evaluation::{evaluate_recall, EvaluationConfig, RecallEvaluation};
+pub use fault::FaultyVectorIndex;
 pub use index::{MemoryVectorIndex, SearchResult, VectorIndex, memory_index_dump};
+pub use maintenance::{MaintenanceConfig, MaintenanceReport, MaintenanceScheduler};
+pub use metadata::{VectorMetadata, FILTER_FIELDS};
+pub use multi::MultiVectorIndex;
+pub use snapshot::SnapshotScheduler;
+pub use tiered::{TieredVectorIndex, TieringConfig, TieringReport};
 
 #[cfg(feature = "lance")]
 pub use lance::{
@@ -0,0 +1,234 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Background maintenance for vector indexes.
+//!
+//! Append-heavy backends (such as Lance) accumulate small data fragments
+//! and stale index structures as vectors are written. The scheduler in this
+//! module periodically asks an index to:
+//! - compact fragments once too many have built up
+//! - retrain IVF centroids when the data has grown past a threshold
+//! - rebuild HNSW graphs with parameters tuned for the current size
+
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{VectorError, VectorIndex, VectorResult};
+
+/// Thresholds controlling when maintenance work is performed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceConfig {
+    /// Interval between scheduled maintenance runs.
+    pub interval: Duration,
+
+    /// Compact once the number of data fragments reaches this value.
+    pub max_fragments: usize,
+
+    /// Retrain/rebuild the index once the vector count has grown by this
+    /// factor since the last training (e.g. 1.5 = 50% growth).
+    pub retrain_growth_factor: f32,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(3600),
+            max_fragments: 32,
+            retrain_growth_factor: 1.5,
+        }
+    }
+}
+
+impl MaintenanceConfig {
+    /// Create a new configuration with default thresholds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a configuration that performs all maintenance work
+    /// regardless of thresholds (used for manual triggers).
+    pub fn forced() -> Self {
+        Self {
+            max_fragments: 0,
+            retrain_growth_factor: 0.0,
+            ..Default::default()
+        }
+    }
+
+    /// Set the interval between scheduled runs.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the fragment count that triggers compaction.
+    pub fn with_max_fragments(mut self, max_fragments: usize) -> Self {
+        self.max_fragments = max_fragments;
+        self
+    }
+
+    /// Set the growth factor that triggers retraining.
+    pub fn with_retrain_growth_factor(mut self, factor: f32) -> Self {
+        self.retrain_growth_factor = factor;
+        self
+    }
+
+    /// Check whether compaction is due for the given fragment count.
+    pub fn should_compact(&self, fragments: usize) -> bool {
+        fragments > 1 && fragments >= self.max_fragments
+    }
+
+    /// Check whether retraining is due given the current and last-trained counts.
+    pub fn should_retrain(&self, count: usize, trained_count: usize) -> bool {
+        if count == 0 {
+            return false;
+        }
+        if trained_count == 0 {
+            return true;
+        }
+        count as f32 >= trained_count as f32 * self.retrain_growth_factor
+    }
+}
+
+/// Outcome of a single maintenance run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceReport {
+    /// Number of data fragments before the run.
+    pub fragments_before: usize,
+
+    /// Number of data fragments after the run.
+    pub fragments_after: usize,
+
+    /// Whether fragments were compacted.
+    pub compacted: bool,
+
+    /// Whether IVF centroids were retrained.
+    pub ivf_retrained: bool,
+
+    /// Whether the HNSW graph was rebuilt.
+    pub hnsw_rebuilt: bool,
+
+    /// Number of vectors in the index.
+    pub vector_count: usize,
+
+    /// Time spent in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+impl MaintenanceReport {
+    /// Check whether any maintenance work was performed.
+    pub fn did_work(&self) -> bool {
+        self.compacted || self.ivf_retrained || self.hnsw_rebuilt
+    }
+}
+
+/// Periodically runs maintenance on a vector index.
+pub struct MaintenanceScheduler {
+    index: Arc<dyn VectorIndex>,
+    config: MaintenanceConfig,
+}
+
+impl MaintenanceScheduler {
+    /// Create a new scheduler for the given index.
+    pub fn new(index: Arc<dyn VectorIndex>, config: MaintenanceConfig) -> Self {
+        Self { index, config }
+    }
+
+    /// Get the scheduler configuration.
+    pub fn config(&self) -> &MaintenanceConfig {
+        &self.config
+    }
+
+    /// Run a single maintenance pass.
+    pub async fn run_once(&self) -> VectorResult<MaintenanceReport> {
+        let start = Instant::now();
+        tracing::info!("Starting vector index maintenance");
+
+        let mut report = self.index.optimize(&self.config).await?;
+        report.elapsed_ms = start.elapsed().as_millis() as u64;
+
+        if report.did_work() {
+            tracing::info!(
+                "Vector index maintenance complete: fragments {} -> {}, ivf_retrained={}, hnsw_rebuilt={}, {} vectors in {}ms",
+                report.fragments_before,
+                report.fragments_after,
+                report.ivf_retrained,
+                report.hnsw_rebuilt,
+                report.vector_count,
+                report.elapsed_ms
+            );
+        } else {
+            tracing::debug!("Vector index maintenance: nothing to do");
+        }
+
+        Ok(report)
+    }
+
+    /// Start the background maintenance task.
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval_timer = tokio::time::interval(self.config.interval);
+            // The first tick completes immediately; skip it so startup is not slowed.
+            interval_timer.tick().await;
+
+            loop {
+                interval_timer.tick().await;
+
+                match self.run_once().await {
+                    Ok(_) => {}
+                    // Later runs would fail the same way
+                    Err(VectorError::Unsupported(work)) => {
+                        tracing::warn!(
+                            "Stopping vector index maintenance, the index does not support {}",
+                            work
+                        );
+                        break;
+                    }
+                    Err(e) => tracing::warn!("Vector index maintenance failed: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryVectorIndex;
+
+    #[test]
+    fn test_should_compact() {
+        let config = MaintenanceConfig::new().with_max_fragments(4);
+        assert!(!config.should_compact(1));
+        assert!(!config.should_compact(3));
+        assert!(config.should_compact(4));
+
+        // Forced still skips a single fragment
+        assert!(!MaintenanceConfig::forced().should_compact(1));
+        assert!(MaintenanceConfig::forced().should_compact(2));
+    }
+
+    #[test]
+    fn test_should_retrain() {
+        let config = MaintenanceConfig::new().with_retrain_growth_factor(2.0);
+        assert!(!config.should_retrain(0, 0));
+        assert!(config.should_retrain(10, 0));
+        assert!(!config.should_retrain(15, 10));
+        assert!(config.should_retrain(20, 10));
+        assert!(MaintenanceConfig::forced().should_retrain(10, 10));
+    }
+
+    #[tokio::test]
+    async fn test_run_once_memory_index() {
+        let index = Arc::new(MemoryVectorIndex::new(4));
+        index.insert(uuid::Uuid::new_v4(), vec![1.0; 4]).await.unwrap();
+
+        let scheduler = MaintenanceScheduler::new(index, MaintenanceConfig::forced());
+        let report = scheduler.run_once().await.unwrap();
+
+        assert!(!report.did_work());
+        assert_eq!(report.vector_count, 1);
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Node metadata stored with vectors, for filtered searches.
+//!
+//! A filtered search ranks only the vectors whose metadata matches the
+//! filter, so the nearest `k` among a small subset are found even when
+//! searching everything and filtering afterwards would come back short.
+//! Filters can test these fields, with `Equals`, `InList`, `Contains`
+//! (substring, not on tags) and `And`/`Or`/`Not`:
+//!
+//! - `node_type`: the node type, e.g. `"fact"`
+//! - `namespace`: the node's namespace
+//! - `tags`: the node's tags; a condition holds if it holds for any tag
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use synton_core::{Filter, FilterValue, NodeType};
+
+use crate::{VectorError, VectorResult};
+
+/// Fields a vector filter can test.
+pub const FILTER_FIELDS: [&str; 3] = ["node_type", "namespace", "tags"];
+
+/// Metadata of the node a vector belongs to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorMetadata {
+    /// Node type
+    pub node_type: Option<NodeType>,
+
+    /// Namespace, if the node is in one
+    pub namespace: Option<String>,
+
+    /// Normalized tags
+    pub tags: BTreeSet<String>,
+}
+
+impl VectorMetadata {
+    /// Create metadata for a node of `node_type`.
+    pub fn new(node_type: NodeType) -> Self {
+        Self {
+            node_type: Some(node_type),
+            ..Default::default()
+        }
+    }
+
+    /// Set the namespace.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Set the tags.
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Check that metadata can answer every condition of `filter`.
+    pub fn check_filter(filter: &Filter) -> VectorResult<()> {
+        let unsupported = |message: String| Err(VectorError::Unsupported(message));
+        let check_field = |field: &str| {
+            if FILTER_FIELDS.contains(&field) {
+                Ok(())
+            } else {
+                unsupported(format!(
+                    "cannot filter vectors on '{}'; use one of {}",
+                    field,
+                    FILTER_FIELDS.join(", ")
+                ))
+            }
+        };
+        let check_value = |field: &str, value: &FilterValue| match value {
+            FilterValue::String(_) => Ok(()),
+            other => unsupported(format!(
+                "'{}' must be compared with a string, not {}",
+                field, other
+            )),
+        };
+
+        match filter {
+            Filter::Equals { field, value } => {
+                check_field(field)?;
+                check_value(field, value)
+            }
+            Filter::InList { field, values } => {
+                check_field(field)?;
+                values
+                    .iter()
+                    .try_for_each(|value| check_value(field, value))
+            }
+            Filter::Contains { field, .. } if field == "tags" => {
+                unsupported("tags can only be matched exactly".to_string())
+            }
+            Filter::Contains { field, .. } => check_field(field),
+            Filter::GreaterThan { field, .. }
+            | Filter::LessThan { field, .. }
+            | Filter::Range { field, .. } => {
+                unsupported(format!("cannot compare '{}' numerically", field))
+            }
+            Filter::And(filters) | Filter::Or(filters) => {
+                filters.iter().try_for_each(Self::check_filter)
+            }
+            Filter::Not(filter) => Self::check_filter(filter),
+        }
+    }
+
+    /// Whether the metadata satisfies `filter`, which must pass
+    /// [`Self::check_filter`]; other conditions never match.
+    pub fn matches(&self, filter: &Filter) -> bool {
+        match filter {
+            Filter::Equals {
+                field,
+                value: FilterValue::String(value),
+            } => self.any_value(field, |v| v == value),
+            Filter::InList { field, values } => values.iter().any(|value| match value {
+                FilterValue::String(value) => self.any_value(field, |v| v == value),
+                _ => false,
+            }),
+            Filter::Contains { field, value } if field != "tags" => {
+                self.any_value(field, |v| v.contains(value.as_str()))
+            }
+            Filter::And(filters) => filters.iter().all(|filter| self.matches(filter)),
+            Filter::Or(filters) => filters.iter().any(|filter| self.matches(filter)),
+            Filter::Not(filter) => !self.matches(filter),
+            _ => false,
+        }
+    }
+
+    /// Whether any value of `field` satisfies `predicate`.
+    fn any_value(&self, field: &str, predicate: impl Fn(&str) -> bool) -> bool {
+        match field {
+            "node_type" => self
+                .node_type
+                .is_some_and(|node_type| predicate(&node_type.to_string())),
+            "namespace" => self.namespace.as_deref().is_some_and(predicate),
+            "tags" => self.tags.iter().any(|tag| predicate(tag)),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_matches() {
+        let metadata = VectorMetadata::new(NodeType::Fact)
+            .with_namespace("org/team")
+            .with_tags(["rust", "db"]);
+
+        assert!(metadata.matches(&Filter::equals("node_type", "fact")));
+        assert!(metadata.matches(&Filter::and([
+            Filter::equals("namespace", "org/team"),
+            Filter::equals("tags", "db"),
+        ])));
+        assert!(metadata.matches(&Filter::in_list(
+            "node_type",
+            vec!["entity".into(), "fact".into()]
+        )));
+        assert!(metadata.matches(&Filter::contains("namespace", "team")));
+        assert!(!metadata.matches(&Filter::equals("tags", "go")));
+        assert!(metadata.matches(&Filter::not(Filter::equals("tags", "go"))));
+
+        // Nodes without metadata only match negations
+        let unknown = VectorMetadata::default();
+        assert!(!unknown.matches(&Filter::equals("node_type", "fact")));
+        assert!(unknown.matches(&Filter::not(Filter::equals("namespace", "org"))));
+    }
+
+    #[test]
+    fn test_check_filter() {
+        assert!(VectorMetadata::check_filter(&Filter::or([
+            Filter::equals("node_type", "fact"),
+            Filter::in_list("tags", vec!["a".into(), "b".into()]),
+        ]))
+        .is_ok());
+
+        for filter in [
+            Filter::equals("content", "x"),
+            Filter::equals("node_type", 1i64),
+            Filter::contains("tags", "ru"),
+            Filter::greater_than("namespace", 1.0),
+            Filter::not(Filter::equals("lang", "en")),
+        ] {
+            assert!(matches!(
+                VectorMetadata::check_filter(&filter),
+                Err(VectorError::Unsupported(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Multi-vector node representation.
+//!
+//! A single embedding of a long chunk averages away much of its detail. In
+//! the late-interaction style, a node can instead be represented by several
+//! vectors, one per sub-chunk, stored under `(node_id, position)`. A query
+//! scores a node by max-sim: each query vector takes its best match among
+//! the node's vectors, and the node's score is the mean over the query
+//! vectors. Results are aggregated back to one per node.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{MemoryVectorIndex, SearchResult, VectorError, VectorResult};
+
+/// Sub-chunk vectors keyed by `(node_id, position)`.
+type ChunkVectors = BTreeMap<(Uuid, u32), Vec<f32>>;
+
+/// In-memory index of sub-chunk vectors keyed by `(node_id, position)`.
+#[derive(Clone, Debug)]
+pub struct MultiVectorIndex {
+    dimension: usize,
+    vectors: Arc<RwLock<ChunkVectors>>,
+}
+
+impl MultiVectorIndex {
+    /// Create a new multi-vector index.
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension,
+            vectors: Arc::new(RwLock::new(BTreeMap::new())),
+        }
+    }
+
+    /// Get the embedding dimension.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn check_dimension(&self, vector: &[f32]) -> VectorResult<()> {
+        if vector.len() != self.dimension {
+            return Err(VectorError::InvalidDimension {
+                expected: self.dimension,
+                found: vector.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Replace the vectors of a node with `vectors`, in sub-chunk order.
+    pub async fn insert(&self, id: Uuid, vectors: Vec<Vec<f32>>) -> VectorResult<()> {
+        for vector in &vectors {
+            self.check_dimension(vector)?;
+        }
+        let mut store = self.vectors.write().await;
+        remove_node(&mut store, id);
+        for (position, vector) in vectors.into_iter().enumerate() {
+            store.insert((id, position as u32), vector);
+        }
+        Ok(())
+    }
+
+    /// Delete the vectors of a node.
+    pub async fn remove(&self, id: Uuid) -> VectorResult<()> {
+        remove_node(&mut *self.vectors.write().await, id);
+        Ok(())
+    }
+
+    /// Vectors of a node, in sub-chunk order.
+    pub async fn get(&self, id: Uuid) -> Vec<Vec<f32>> {
+        self.vectors
+            .read()
+            .await
+            .range((id, 0)..=(id, u32::MAX))
+            .map(|(_, vector)| vector.clone())
+            .collect()
+    }
+
+    /// Whether a node has vectors.
+    pub async fn contains(&self, id: Uuid) -> bool {
+        self.vectors.read().await.range((id, 0)..=(id, u32::MAX)).next().is_some()
+    }
+
+    /// Get the total number of vectors.
+    pub async fn count(&self) -> usize {
+        self.vectors.read().await.len()
+    }
+
+    /// Max-sim score of every node for the query vectors, with the position
+    /// of the best match of the first query vector.
+    pub async fn scores(&self, query: &[Vec<f32>]) -> VectorResult<HashMap<Uuid, (f32, u32)>> {
+        for vector in query {
+            self.check_dimension(vector)?;
+        }
+        if query.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // Best similarity of each query vector per node
+        let vectors = self.vectors.read().await;
+        let mut best: HashMap<Uuid, (Vec<f32>, u32)> = HashMap::new();
+        for (&(id, position), vector) in vectors.iter() {
+            let (maxima, best_position) =
+                best.entry(id).or_insert_with(|| (vec![f32::MIN; query.len()], position));
+            for (i, q) in query.iter().enumerate() {
+                let similarity = MemoryVectorIndex::cosine_similarity(q, vector);
+                if similarity > maxima[i] {
+                    maxima[i] = similarity;
+                    if i == 0 {
+                        *best_position = position;
+                    }
+                }
+            }
+        }
+
+        Ok(best
+            .into_iter()
+            .map(|(id, (maxima, position))| {
+                let score = maxima.iter().sum::<f32>() / query.len() as f32;
+                (id, (score, position))
+            })
+            .collect())
+    }
+
+    /// Search for the `k` nodes with the highest max-sim score. Each result
+    /// carries the `position` of the sub-chunk that matched best.
+    pub async fn search(&self, query: &[Vec<f32>], k: usize) -> VectorResult<Vec<SearchResult>> {
+        let mut results: Vec<SearchResult> = self
+            .scores(query)
+            .await?
+            .into_iter()
+            .map(|(id, (score, position))| {
+                SearchResult::new(id, score).with_metadata("position", position.to_string())
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        results.truncate(k);
+        Ok(results)
+    }
+}
+
+fn remove_node(vectors: &mut ChunkVectors, id: Uuid) {
+    let positions: Vec<_> = vectors
+        .range((id, 0)..=(id, u32::MAX))
+        .map(|(&key, _)| key)
+        .collect();
+    for key in positions {
+        vectors.remove(&key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_max_sim_search() {
+        let index = MultiVectorIndex::new(3);
+        let (long, short) = (Uuid::new_v4(), Uuid::new_v4());
+
+        // A long chunk covering two topics, and a chunk about one of them
+        index
+            .insert(long, vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]])
+            .await
+            .unwrap();
+        index.insert(short, vec![vec![0.7, 0.7, 0.0]]).await.unwrap();
+        assert_eq!(index.count().await, 3);
+
+        // The long chunk matches the second topic exactly on its second vector
+        let results = index.search(&[vec![0.0, 1.0, 0.0]], 10).await.unwrap();
+        assert_eq!(results[0].id, long);
+        assert!((results[0].score - 1.0).abs() < 0.001);
+        assert_eq!(results[0].metadata, vec![("position".to_string(), "1".to_string())]);
+        assert_eq!(results[1].id, short);
+
+        // Each query vector takes its best match, averaged over the query
+        let results = index
+            .search(&[vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]], 1)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, long);
+
+        // Re-inserting replaces a node's vectors
+        index.insert(long, vec![vec![0.0, 0.0, 1.0]]).await.unwrap();
+        assert_eq!(index.get(long).await, vec![vec![0.0, 0.0, 1.0]]);
+        index.remove(long).await.unwrap();
+        assert!(!index.contains(long).await);
+        assert_eq!(index.count().await, 1);
+
+        assert!(index.insert(short, vec![vec![1.0]]).await.is_err());
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Warm-start snapshots for in-memory vector indexes.
+//!
+//! An in-memory index is empty after a restart. The scheduler in this module
+//! periodically writes the index to disk so the server can reload it at
+//! startup instead of re-embedding every node.
+//!
+//! Snapshot layout (little-endian):
+//! - magic `SVIX`, format version (`u32`), dimension (`u32`), count (`u64`)
+//! - per vector: the 16 ID bytes followed by `dimension` `f32` values
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use crate::{VectorError, VectorIndex, VectorResult};
+
+/// Magic bytes at the start of a snapshot file.
+const MAGIC: &[u8; 4] = b"SVIX";
+
+/// Current snapshot format version.
+const FORMAT_VERSION: u32 = 1;
+
+/// Header length: magic, version, dimension, count.
+const HEADER_LEN: usize = 4 + 4 + 4 + 8;
+
+/// Serialize vectors into the snapshot format.
+pub(crate) fn encode(dimension: usize, vectors: &HashMap<Uuid, Vec<f32>>) -> Vec<u8> {
+    let entry_len = 16 + dimension * 4;
+    let mut bytes = Vec::with_capacity(HEADER_LEN + vectors.len() * entry_len);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(dimension as u32).to_le_bytes());
+    bytes.extend_from_slice(&(vectors.len() as u64).to_le_bytes());
+
+    for (id, vector) in vectors {
+        bytes.extend_from_slice(id.as_bytes());
+        for value in vector {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+    bytes
+}
+
+/// Parse a snapshot, checking it matches the index dimension.
+pub(crate) fn decode(bytes: &[u8], dimension: usize) -> VectorResult<Vec<(Uuid, Vec<f32>)>> {
+    if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+        return Err(VectorError::Deserialization(
+            "not a vector index snapshot".to_string(),
+        ));
+    }
+    let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+    if version != FORMAT_VERSION {
+        return Err(VectorError::Deserialization(format!(
+            "unsupported snapshot version {}",
+            version
+        )));
+    }
+    let found = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+    if found != dimension {
+        return Err(VectorError::InvalidDimension {
+            expected: dimension,
+            found,
+        });
+    }
+    let count = u64::from_le_bytes(bytes[12..20].try_into().unwrap()) as usize;
+
+    let entry_len = 16 + dimension * 4;
+    let body = &bytes[HEADER_LEN..];
+    if count.checked_mul(entry_len) != Some(body.len()) {
+        return Err(VectorError::Deserialization(format!(
+            "snapshot holds {} bytes, expected {} vectors of dimension {}",
+            body.len(),
+            count,
+            dimension
+        )));
+    }
+
+    Ok(body
+        .chunks_exact(entry_len)
+        .map(|entry| {
+            let id = Uuid::from_bytes(entry[..16].try_into().unwrap());
+            let vector = entry[16..]
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                .collect();
+            (id, vector)
+        })
+        .collect())
+}
+
+/// Write a snapshot next to `path` and move it into place, so a crash
+/// mid-write never leaves a truncated snapshot behind.
+pub(crate) async fn write_atomic(path: &Path, bytes: &[u8]) -> VectorResult<()> {
+    let backend = |e: std::io::Error| {
+        VectorError::Backend(format!("Failed to write snapshot {}: {}", path.display(), e))
+    };
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await.map_err(backend)?;
+    }
+    let tmp = path.with_extension("tmp");
+    tokio::fs::write(&tmp, bytes).await.map_err(backend)?;
+    tokio::fs::rename(&tmp, path).await.map_err(backend)
+}
+
+/// Read a snapshot file, or `None` if it does not exist.
+pub(crate) async fn read(path: &Path) -> VectorResult<Option<Vec<u8>>> {
+    match tokio::fs::read(path).await {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(VectorError::Backend(format!(
+            "Failed to read snapshot {}: {}",
+            path.display(),
+            e
+        ))),
+    }
+}
+
+/// Periodically writes a vector index snapshot.
+#[derive(Clone)]
+pub struct SnapshotScheduler {
+    index: Arc<dyn VectorIndex>,
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl SnapshotScheduler {
+    /// Create a new scheduler writing `index` to `path` every `interval`.
+    pub fn new(index: Arc<dyn VectorIndex>, path: impl Into<PathBuf>, interval: Duration) -> Self {
+        Self {
+            index,
+            path: path.into(),
+            interval,
+        }
+    }
+
+    /// Path of the snapshot file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write a snapshot if the index changed since the last one.
+    pub async fn run_once(&self) -> VectorResult<bool> {
+        let written = self.index.save_snapshot(&self.path).await?;
+        if written {
+            tracing::debug!(
+                "Vector index snapshot written to {} ({} vectors)",
+                self.path.display(),
+                self.index.count().await?
+            );
+        }
+        Ok(written)
+    }
+
+    /// Start the background snapshot task.
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval_timer = tokio::time::interval(self.interval);
+            // The first tick completes immediately; the index was just loaded.
+            interval_timer.tick().await;
+
+            loop {
+                interval_timer.tick().await;
+
+                if let Err(e) = self.run_once().await {
+                    tracing::warn!("Vector index snapshot failed: {}", e);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryVectorIndex;
+
+    #[test]
+    fn test_encode_decode() {
+        let mut vectors = HashMap::new();
+        let id = Uuid::new_v4();
+        vectors.insert(id, vec![0.5, -1.0, 2.25]);
+
+        let bytes = encode(3, &vectors);
+        assert_eq!(decode(&bytes, 3).unwrap(), vec![(id, vec![0.5, -1.0, 2.25])]);
+
+        assert!(matches!(
+            decode(&bytes, 4),
+            Err(VectorError::InvalidDimension { expected: 4, found: 3 })
+        ));
+        assert!(matches!(
+            decode(&bytes[..bytes.len() - 1], 3),
+            Err(VectorError::Deserialization(_))
+        ));
+        assert!(decode(b"not a snapshot at all", 3).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("vectors").join("index.snapshot");
+
+        let index = Arc::new(MemoryVectorIndex::new(3));
+        let id = Uuid::new_v4();
+        index.insert(id, vec![1.0, 0.0, 0.0]).await.unwrap();
+        index.insert(Uuid::new_v4(), vec![0.0, 1.0, 0.0]).await.unwrap();
+
+        let scheduler = SnapshotScheduler::new(index.clone(), &path, Duration::from_secs(60));
+        assert!(scheduler.run_once().await.unwrap());
+        // Unchanged index is not rewritten
+        assert!(!scheduler.run_once().await.unwrap());
+
+        let restored = MemoryVectorIndex::new(3);
+        assert_eq!(restored.load_snapshot(&path).await.unwrap(), 2);
+        let results = restored.search(&[1.0, 0.0, 0.0], 1).await.unwrap();
+        assert_eq!(results[0].id, id);
+        // Freshly loaded contents need no new snapshot
+        assert!(!restored.save_snapshot(&path).await.unwrap());
+
+        let missing = MemoryVectorIndex::new(3);
+        assert_eq!(missing.load_snapshot(&dir.path().join("none")).await.unwrap(), 0);
+        assert!(MemoryVectorIndex::new(4).load_snapshot(&path).await.is_err());
+    }
+}
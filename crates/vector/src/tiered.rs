@@ -0,0 +1,360 @@
+// Copyright 2025 SYNTON-DB Team
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+
+//! Hot/cold tiering of vector indexes.
+//!
+//! The cold tier (typically Lance) holds every vector and is the source of
+//! truth. The hot tier is an in-memory index holding copies of the most
+//! accessed vectors, up to a capacity, so large corpora do not need all of
+//! their vectors in RAM. Searches query both tiers and merge the results.
+//! The caller ranks vectors by access and hands the hottest to
+//! [`TieredVectorIndex::retier`], which promotes and demotes accordingly.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use synton_core::Filter;
+use uuid::Uuid;
+
+use crate::{
+    MaintenanceConfig, MaintenanceReport, MemoryVectorIndex, SearchResult, VectorCheckpoint,
+    VectorDelta, VectorIndex, VectorMetadata, VectorResult,
+};
+
+/// Hot tier settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TieringConfig {
+    /// Most vectors kept in the hot tier.
+    pub hot_capacity: usize,
+
+    /// Interval between scheduled re-tierings.
+    pub interval: Duration,
+}
+
+impl Default for TieringConfig {
+    fn default() -> Self {
+        Self {
+            hot_capacity: 100_000,
+            interval: Duration::from_secs(300),
+        }
+    }
+}
+
+/// Outcome of a re-tiering.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TieringReport {
+    /// Vectors in the hot tier afterwards.
+    pub hot: usize,
+
+    /// Vectors copied into the hot tier.
+    pub promoted: usize,
+
+    /// Vectors dropped from the hot tier.
+    pub demoted: usize,
+}
+
+/// Vector index keeping its most accessed vectors in memory on top of a
+/// complete cold index.
+///
+/// Writes go to the cold tier, and to the hot tier while it has room or
+/// already holds the vector. Counts, maintenance, snapshots and deltas are
+/// those of the cold tier; the hot tier is rebuilt by re-tiering. Metadata
+/// goes to both tiers, so promoted vectors can be filtered at once.
+pub struct TieredVectorIndex {
+    hot: MemoryVectorIndex,
+    cold: Arc<dyn VectorIndex>,
+    config: TieringConfig,
+}
+
+impl TieredVectorIndex {
+    /// Put a hot tier in front of `cold`.
+    pub fn new(cold: Arc<dyn VectorIndex>, config: TieringConfig) -> Self {
+        Self {
+            hot: MemoryVectorIndex::new(cold.dimension()),
+            cold,
+            config,
+        }
+    }
+
+    /// The tiering settings.
+    pub fn config(&self) -> &TieringConfig {
+        &self.config
+    }
+
+    /// Number of vectors in the hot tier.
+    pub async fn hot_count(&self) -> usize {
+        self.hot.len().await
+    }
+
+    /// Make `hottest`, most accessed first, the contents of the hot tier:
+    /// the first `hot_capacity` are promoted if cold and kept if hot, and
+    /// every other hot vector is demoted to the cold tier only.
+    pub async fn retier(&self, hottest: Vec<(Uuid, Vec<f32>)>) -> VectorResult<TieringReport> {
+        let current = self.hot.ids().await;
+        let mut keep = HashSet::new();
+        let mut promote = Vec::new();
+        for (id, vector) in hottest.into_iter().take(self.config.hot_capacity) {
+            if keep.insert(id) && !current.contains(&id) {
+                promote.push((id, vector));
+            }
+        }
+
+        let mut report = TieringReport::default();
+        for id in current.difference(&keep) {
+            self.hot.remove(*id).await?;
+            report.demoted += 1;
+        }
+        report.promoted = promote.len();
+        self.hot.insert_batch(promote).await?;
+        report.hot = self.hot.len().await;
+
+        tracing::debug!(
+            "Re-tiered vectors: {} hot, {} promoted, {} demoted",
+            report.hot,
+            report.promoted,
+            report.demoted
+        );
+        Ok(report)
+    }
+
+    /// Whether a new vector can join the hot tier.
+    async fn has_room(&self) -> bool {
+        self.hot.len().await < self.config.hot_capacity
+    }
+}
+
+/// Merge the results of both tiers, keeping the best score of each vector.
+fn merge(hot: Vec<SearchResult>, cold: Vec<SearchResult>, k: usize) -> Vec<SearchResult> {
+    let mut best: HashMap<Uuid, SearchResult> = HashMap::new();
+    for result in hot.into_iter().chain(cold) {
+        match best.get(&result.id) {
+            Some(existing) if existing.score >= result.score => {}
+            _ => {
+                best.insert(result.id, result);
+            }
+        }
+    }
+    let mut merged: Vec<SearchResult> = best.into_values().collect();
+    merged.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+    merged.truncate(k);
+    merged
+}
+
+#[async_trait]
+impl VectorIndex for TieredVectorIndex {
+    async fn insert(&self, id: Uuid, vector: Vec<f32>) -> VectorResult<()> {
+        self.cold.insert(id, vector.clone()).await?;
+        if self.hot.contains(id).await || self.has_room().await {
+            self.hot.insert(id, vector).await?;
+        }
+        Ok(())
+    }
+
+    async fn insert_batch(&self, vectors: Vec<(Uuid, Vec<f32>)>) -> VectorResult<()> {
+        self.cold.insert_batch(vectors.clone()).await?;
+        let room = self.config.hot_capacity.saturating_sub(self.hot.len().await);
+        let mut hot = Vec::new();
+        for (id, vector) in vectors {
+            if self.hot.contains(id).await || hot.len() < room {
+                hot.push((id, vector));
+            }
+        }
+        self.hot.insert_batch(hot).await
+    }
+
+    async fn search(&self, query: &[f32], k: usize) -> VectorResult<Vec<SearchResult>> {
+        let (hot, cold) = tokio::join!(self.hot.search(query, k), self.cold.search(query, k));
+        Ok(merge(hot?, cold?, k))
+    }
+
+    async fn search_with_filter(
+        &self,
+        query: &[f32],
+        filter: Filter,
+        k: usize,
+    ) -> VectorResult<Vec<SearchResult>> {
+        let (hot, cold) = tokio::join!(
+            self.hot.search_with_filter(query, filter.clone(), k),
+            self.cold.search_with_filter(query, filter, k)
+        );
+        Ok(merge(hot?, cold?, k))
+    }
+
+    fn supports_filter(&self) -> bool {
+        self.cold.supports_filter()
+    }
+
+    async fn set_metadata(&self, id: Uuid, metadata: VectorMetadata) -> VectorResult<()> {
+        self.cold.set_metadata(id, metadata.clone()).await?;
+        self.hot.set_metadata(id, metadata).await
+    }
+
+    async fn remove(&self, id: Uuid) -> VectorResult<()> {
+        self.cold.remove(id).await?;
+        self.hot.remove(id).await
+    }
+
+    async fn update(&self, id: Uuid, vector: Vec<f32>) -> VectorResult<()> {
+        self.cold.update(id, vector.clone()).await?;
+        if self.hot.contains(id).await {
+            self.hot.update(id, vector).await?;
+        }
+        Ok(())
+    }
+
+    async fn count(&self) -> VectorResult<usize> {
+        self.cold.count().await
+    }
+
+    fn dimension(&self) -> usize {
+        self.cold.dimension()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cold.is_ready()
+    }
+
+    async fn optimize(&self, config: &MaintenanceConfig) -> VectorResult<MaintenanceReport> {
+        self.cold.optimize(config).await
+    }
+
+    async fn save_snapshot(&self, path: &Path) -> VectorResult<bool> {
+        self.cold.save_snapshot(path).await
+    }
+
+    async fn load_snapshot(&self, path: &Path) -> VectorResult<usize> {
+        self.cold.load_snapshot(path).await
+    }
+
+    async fn changes_since(
+        &self,
+        checkpoint: Option<VectorCheckpoint>,
+    ) -> VectorResult<VectorDelta> {
+        self.cold.changes_since(checkpoint).await
+    }
+
+    async fn apply_delta(&self, delta: VectorDelta) -> VectorResult<()> {
+        // A full delta replaces the cold tier, so every hot vector may be stale
+        let hot = self.hot.ids().await;
+        let (stale, refreshed): (Vec<Uuid>, Vec<(Uuid, Vec<f32>)>) = if delta.full {
+            (hot.into_iter().collect(), Vec::new())
+        } else {
+            (
+                delta
+                    .removals
+                    .iter()
+                    .filter(|id| hot.contains(id))
+                    .copied()
+                    .collect(),
+                delta
+                    .upserts
+                    .iter()
+                    .filter(|(id, _)| hot.contains(id))
+                    .cloned()
+                    .collect(),
+            )
+        };
+
+        self.cold.apply_delta(delta).await?;
+        for id in stale {
+            self.hot.remove(id).await?;
+        }
+        self.hot.insert_batch(refreshed).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiered(hot_capacity: usize) -> (TieredVectorIndex, Arc<MemoryVectorIndex>) {
+        let cold = Arc::new(MemoryVectorIndex::new(2));
+        let config = TieringConfig {
+            hot_capacity,
+            ..Default::default()
+        };
+        (TieredVectorIndex::new(cold.clone(), config), cold)
+    }
+
+    #[tokio::test]
+    async fn test_writes_fill_hot_tier_to_capacity() {
+        let (index, cold) = tiered(2);
+        let ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        for (i, id) in ids.iter().enumerate() {
+            index.insert(*id, vec![1.0, i as f32]).await.unwrap();
+        }
+
+        assert_eq!(index.count().await.unwrap(), 3);
+        assert_eq!(cold.count().await.unwrap(), 3);
+        assert_eq!(index.hot_count().await, 2);
+
+        // Cold-only vectors are still found
+        let results = index.search(&[1.0, 2.0], 1).await.unwrap();
+        assert_eq!(results[0].id, ids[2]);
+        assert!(!index.hot.contains(ids[2]).await);
+
+        index.remove(ids[0]).await.unwrap();
+        assert_eq!(index.count().await.unwrap(), 2);
+        assert_eq!(index.hot_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retier_promotes_and_demotes() {
+        let (index, _) = tiered(1);
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        index.insert(a, vec![1.0, 0.0]).await.unwrap();
+        index.insert(b, vec![0.0, 1.0]).await.unwrap();
+        assert!(index.hot.contains(a).await);
+
+        let report = index
+            .retier(vec![(b, vec![0.0, 1.0]), (a, vec![1.0, 0.0])])
+            .await
+            .unwrap();
+        assert_eq!(report, TieringReport { hot: 1, promoted: 1, demoted: 1 });
+        assert!(index.hot.contains(b).await);
+        assert!(!index.hot.contains(a).await);
+
+        // Unchanged ranking moves nothing
+        let report = index.retier(vec![(b, vec![0.0, 1.0])]).await.unwrap();
+        assert_eq!((report.promoted, report.demoted), (0, 0));
+
+        let results = index.search(&[1.0, 0.0], 2).await.unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, a);
+    }
+
+    #[tokio::test]
+    async fn test_filtered_search_covers_both_tiers() {
+        let (index, _) = tiered(1);
+        let (hot, cold) = (Uuid::new_v4(), Uuid::new_v4());
+        index.insert(hot, vec![1.0, 0.0]).await.unwrap();
+        index.insert(cold, vec![0.9, 0.1]).await.unwrap();
+        for id in [hot, cold] {
+            let metadata = VectorMetadata::new(synton_core::NodeType::Fact);
+            index.set_metadata(id, metadata).await.unwrap();
+        }
+        assert!(index.supports_filter());
+
+        let filter = Filter::equals("node_type", "fact");
+        let results = index
+            .search_with_filter(&[1.0, 0.0], filter, 5)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+
+        // A promoted vector keeps its metadata
+        index.retier(vec![(cold, vec![0.9, 0.1])]).await.unwrap();
+        let filter = Filter::equals("node_type", "fact");
+        let hot_results = index
+            .hot
+            .search_with_filter(&[1.0, 0.0], filter, 5)
+            .await
+            .unwrap();
+        assert_eq!(hot_results[0].id, cold);
+    }
+}